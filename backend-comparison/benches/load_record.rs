@@ -33,6 +33,7 @@ impl BenchmarkConfig {
                 .map(|record| nn::Linear {
                     weight: record.weight,
                     bias: record.bias,
+                    quantization: Default::default(),
                 })
                 .collect(),
         }
@@ -0,0 +1,129 @@
+use super::{unary, Backward, Ops};
+use crate::{checkpoint::base::Checkpointer, grads::Gradients};
+use burn_tensor::{backend::Backend, ElementConversion, Shape};
+
+#[derive(Debug)]
+pub(crate) struct CumSum;
+
+impl<B: Backend, const D: usize> Backward<B, D, 1> for CumSum {
+    type State = usize;
+
+    fn backward(
+        self,
+        ops: Ops<Self::State, 1>,
+        grads: &mut Gradients,
+        _checkpointer: &mut Checkpointer,
+    ) {
+        let dim = ops.state;
+
+        unary::<B, D, D, _>(ops.parents, ops.node, grads, |grad| {
+            reverse_cumsum::<B, D>(grad, dim)
+        });
+    }
+}
+
+/// The gradient of the cumulative product is computed by dividing by the input before its first
+/// zero. The gradient of the first zero is computed from the products around it, and the gradient
+/// after it is zero since every product contains the zero.
+#[derive(Debug)]
+pub(crate) struct CumProd;
+
+impl<B: Backend, const D: usize> Backward<B, D, 1> for CumProd {
+    type State = (
+        B::FloatTensorPrimitive<D>,
+        B::FloatTensorPrimitive<D>,
+        usize,
+    );
+
+    fn backward(
+        self,
+        ops: Ops<Self::State, 1>,
+        grads: &mut Gradients,
+        _checkpointer: &mut Checkpointer,
+    ) {
+        let (input, output, dim) = ops.state;
+
+        unary::<B, D, D, _>(ops.parents, ops.node, grads, |grad| {
+            // Number of zeros up to each position
+            let zeros = B::bool_into_float(B::float_equal_elem(input.clone(), 0.elem()));
+            let num_zeros = B::float_cumsum(zeros.clone(), dim);
+            let from_zero = B::float_greater_elem(num_zeros.clone(), 0.elem());
+            let first_zero =
+                B::float_equal_elem(B::float_mul(zeros.clone(), num_zeros.clone()), 1.elem());
+            let after_zero = B::float_greater_elem(B::float_sub(num_zeros, zeros), 0.elem());
+
+            // Before the first zero, the products of the following outputs are divided by the input
+            let before = B::float_mask_fill(input.clone(), from_zero.clone(), 1.elem());
+            let grad_before = B::float_div(
+                reverse_cumsum::<B, D>(B::float_mul(grad.clone(), output), dim),
+                before.clone(),
+            );
+
+            // The first zero gets the product of the previous inputs times the products of the
+            // following inputs
+            let after = B::float_mask_fill(input, B::bool_not(after_zero.clone()), 1.elem());
+            let grad_zero = B::float_mul(
+                B::float_cumprod(before, dim),
+                reverse_cumsum::<B, D>(B::float_mul(grad, B::float_cumprod(after, dim)), dim),
+            );
+
+            let grad = B::float_mask_where(grad_before, first_zero, grad_zero);
+            B::float_mask_fill(grad, after_zero, 0.elem())
+        });
+    }
+}
+
+#[derive(Debug)]
+pub(crate) struct CumMaxMin;
+
+impl<B: Backend, const D: usize> Backward<B, D, 1> for CumMaxMin {
+    type State = (B::IntTensorPrimitive<D>, Shape<D>, usize);
+
+    fn backward(
+        self,
+        ops: Ops<Self::State, 1>,
+        grads: &mut Gradients,
+        _checkpointer: &mut Checkpointer,
+    ) {
+        unary::<B, D, D, _>(ops.parents, ops.node, grads, |grad| {
+            let (indices, shape, dim) = ops.state;
+            let device = B::float_device(&grad);
+            let zeros = B::float_zeros(shape, &device);
+
+            B::float_scatter(dim, zeros, indices, grad)
+        });
+    }
+}
+
+/// The indices of the elements selected by a cumulative maximum or minimum, i.e. the position of
+/// the last element equal to the accumulated value.
+pub(crate) fn cummaxmin_indices<B: Backend, const D: usize>(
+    input: B::FloatTensorPrimitive<D>,
+    output: B::FloatTensorPrimitive<D>,
+    dim: usize,
+) -> B::IntTensorPrimitive<D> {
+    let shape = B::float_shape(&input);
+    let device = B::float_device(&input);
+    let size = shape.dims[dim];
+
+    let mut shape_positions = [1; D];
+    shape_positions[dim] = size;
+    let positions = B::int_arange(0..size as i64, &device);
+    let positions = B::int_reshape(positions, Shape::new(shape_positions));
+    let positions = B::int_expand(positions, shape);
+
+    let mask = B::bool_not(B::float_equal(input, output));
+    let positions = B::int_mask_fill(positions, mask, 0.elem());
+
+    B::int_cummax(positions, dim)
+}
+
+/// Accumulate the sum from the end of the dimension.
+fn reverse_cumsum<B: Backend, const D: usize>(
+    tensor: B::FloatTensorPrimitive<D>,
+    dim: usize,
+) -> B::FloatTensorPrimitive<D> {
+    let tensor = B::float_cumsum(B::float_flip(tensor, &[dim]), dim);
+
+    B::float_flip(tensor, &[dim])
+}
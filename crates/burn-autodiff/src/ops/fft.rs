@@ -0,0 +1,118 @@
+use crate::{
+    checkpoint::{base::Checkpointer, strategy::CheckpointStrategy},
+    grads::Gradients,
+    graph::NodeRef,
+    ops::{Backward, Ops, OpsKind},
+    tensor::AutodiffTensor,
+    Autodiff,
+};
+use burn_tensor::{
+    backend::Backend,
+    ops::{FftOps, FloatTensor},
+};
+
+impl<B: Backend, C: CheckpointStrategy> FftOps<Autodiff<B, C>> for Autodiff<B, C> {
+    fn fft<const D: usize>(
+        re: FloatTensor<Self, D>,
+        im: FloatTensor<Self, D>,
+        dim: usize,
+        inverse: bool,
+    ) -> (FloatTensor<Self, D>, FloatTensor<Self, D>) {
+        let (output_re, output_im) = B::fft(re.primitive, im.primitive, dim, inverse);
+        let nodes = [re.node, im.node];
+        let state = (dim, inverse);
+
+        (
+            finish::<B, C, _, D>(FftRe, nodes.clone(), state, output_re),
+            finish::<B, C, _, D>(FftIm, nodes, state, output_im),
+        )
+    }
+}
+
+fn finish<B, C, O, const D: usize>(
+    backward: O,
+    nodes: [NodeRef; 2],
+    state: O::State,
+    output: B::FloatTensorPrimitive<D>,
+) -> AutodiffTensor<B, D>
+where
+    B: Backend,
+    C: CheckpointStrategy,
+    O: Backward<B, D, 2>,
+{
+    match backward.prepare::<C>(nodes).compute_bound().stateful() {
+        OpsKind::Tracked(prep) => prep.finish(state, output),
+        OpsKind::UnTracked(prep) => prep.finish(output),
+    }
+}
+
+/// The real part of the transform.
+///
+/// The transform is linear and the transpose of its matrix is the matrix of the transform with
+/// the opposite exponent, so the gradients are the transform of the output gradient in the other
+/// direction.
+#[derive(Debug)]
+struct FftRe;
+
+/// The imaginary part of the transform.
+#[derive(Debug)]
+struct FftIm;
+
+impl<B: Backend, const D: usize> Backward<B, D, 2> for FftRe {
+    type State = (usize, bool);
+
+    fn backward(
+        self,
+        ops: Ops<Self::State, 2>,
+        grads: &mut Gradients,
+        _checkpointer: &mut Checkpointer,
+    ) {
+        let grad = grads.consume::<B, D>(&ops.node);
+        let zeros = zeros_like::<B, D>(&grad);
+
+        register::<B, D>(ops.parents, ops.state, grad, zeros, grads);
+    }
+}
+
+impl<B: Backend, const D: usize> Backward<B, D, 2> for FftIm {
+    type State = (usize, bool);
+
+    fn backward(
+        self,
+        ops: Ops<Self::State, 2>,
+        grads: &mut Gradients,
+        _checkpointer: &mut Checkpointer,
+    ) {
+        let grad = grads.consume::<B, D>(&ops.node);
+        let zeros = zeros_like::<B, D>(&grad);
+
+        register::<B, D>(ops.parents, ops.state, zeros, grad, grads);
+    }
+}
+
+fn zeros_like<B: Backend, const D: usize>(
+    tensor: &B::FloatTensorPrimitive<D>,
+) -> B::FloatTensorPrimitive<D> {
+    B::float_zeros(B::float_shape(tensor), &B::float_device(tensor))
+}
+
+/// Registers the gradients of the real and the imaginary parts of the input, given the gradient
+/// of the output as a complex tensor.
+fn register<B: Backend, const D: usize>(
+    parents: [Option<NodeRef>; 2],
+    (dim, inverse): (usize, bool),
+    grad_re: B::FloatTensorPrimitive<D>,
+    grad_im: B::FloatTensorPrimitive<D>,
+    grads: &mut Gradients,
+) {
+    let [node_re, node_im] = parents;
+    let (grad_re, grad_im) = B::fft(grad_re, grad_im, dim, !inverse);
+
+    if let Some(node) = node_re {
+        grads.register::<B, D>(node.id, grad_re);
+    }
+
+    if let Some(node) = node_im {
+        grads.register::<B, D>(node.id, grad_im);
+    }
+}
@@ -0,0 +1,546 @@
+use crate::{
+    checkpoint::{base::Checkpointer, strategy::CheckpointStrategy},
+    grads::Gradients,
+    graph::NodeRef,
+    ops::{unary, Backward, Ops, OpsKind},
+    tensor::AutodiffTensor,
+    Autodiff,
+};
+use burn_tensor::{
+    backend::Backend,
+    linalg,
+    ops::{FloatTensor, LinalgOps},
+    Tensor, TensorPrimitive,
+};
+
+impl<B: Backend, C: CheckpointStrategy> LinalgOps<Autodiff<B, C>> for Autodiff<B, C> {
+    fn linalg_inv<const D: usize>(tensor: FloatTensor<Self, D>) -> FloatTensor<Self, D> {
+        let output = B::linalg_inv(tensor.primitive);
+
+        finish::<B, C, _, D>(Inv, tensor.node, output.clone(), output)
+    }
+
+    fn linalg_det<const D: usize>(tensor: FloatTensor<Self, D>) -> FloatTensor<Self, D> {
+        let output = B::linalg_det(tensor.primitive.clone());
+        let state = (tensor.primitive, output.clone());
+
+        finish::<B, C, _, D>(Det, tensor.node, state, output)
+    }
+
+    fn linalg_slogdet<const D: usize>(
+        tensor: FloatTensor<Self, D>,
+    ) -> (FloatTensor<Self, D>, FloatTensor<Self, D>) {
+        let (sign, logabsdet) = B::linalg_slogdet(tensor.primitive.clone());
+        let logabsdet = finish::<B, C, _, D>(LogAbsDet, tensor.node, tensor.primitive, logabsdet);
+
+        // The sign is piecewise constant, so it doesn't have a gradient
+        (AutodiffTensor::new(sign), logabsdet)
+    }
+
+    fn linalg_solve<const D: usize>(
+        lhs: FloatTensor<Self, D>,
+        rhs: FloatTensor<Self, D>,
+    ) -> FloatTensor<Self, D> {
+        #[derive(Debug)]
+        struct Solve;
+
+        impl<B: Backend, const D: usize> Backward<B, D, 2> for Solve {
+            type State = (B::FloatTensorPrimitive<D>, B::FloatTensorPrimitive<D>);
+
+            fn backward(
+                self,
+                ops: Ops<Self::State, 2>,
+                grads: &mut Gradients,
+                _checkpointer: &mut Checkpointer,
+            ) {
+                let (lhs, output) = ops.state;
+                let [node_lhs, node_rhs] = ops.parents;
+                let grad = grads.consume::<B, D>(&ops.node);
+
+                // The gradient of the right-hand side is needed for both inputs
+                let grad_rhs = linalg::solve(float::<B, D>(lhs).transpose(), float(grad));
+
+                if let Some(node) = node_lhs {
+                    let grad = grad_rhs.clone().matmul(float(output).transpose()).neg();
+                    grads.register::<B, D>(node.id, primitive(grad));
+                }
+
+                if let Some(node) = node_rhs {
+                    grads.register::<B, D>(node.id, primitive(grad_rhs));
+                }
+            }
+        }
+
+        match Solve
+            .prepare::<C>([lhs.node, rhs.node])
+            .compute_bound()
+            .stateful()
+        {
+            OpsKind::Tracked(prep) => {
+                let output = B::linalg_solve(lhs.primitive.clone(), rhs.primitive);
+                prep.finish((lhs.primitive, output.clone()), output)
+            }
+            OpsKind::UnTracked(prep) => prep.finish(B::linalg_solve(lhs.primitive, rhs.primitive)),
+        }
+    }
+
+    fn linalg_cholesky<const D: usize>(tensor: FloatTensor<Self, D>) -> FloatTensor<Self, D> {
+        let output = B::linalg_cholesky(tensor.primitive);
+
+        finish::<B, C, _, D>(Cholesky, tensor.node, output.clone(), output)
+    }
+
+    fn linalg_qr<const D: usize>(
+        tensor: FloatTensor<Self, D>,
+    ) -> (FloatTensor<Self, D>, FloatTensor<Self, D>) {
+        let (q, r) = B::linalg_qr(tensor.primitive.clone());
+        let state = (tensor.primitive, q.clone(), r.clone());
+
+        (
+            finish::<B, C, _, D>(QrQ, tensor.node.clone(), state.clone(), q),
+            finish::<B, C, _, D>(QrR, tensor.node, state, r),
+        )
+    }
+
+    fn linalg_svd<const D: usize>(
+        tensor: FloatTensor<Self, D>,
+    ) -> (
+        FloatTensor<Self, D>,
+        FloatTensor<Self, D>,
+        FloatTensor<Self, D>,
+    ) {
+        let (u, s, vh) = B::linalg_svd(tensor.primitive);
+        let state = (u.clone(), s.clone(), vh.clone());
+
+        (
+            finish::<B, C, _, D>(SvdU, tensor.node.clone(), state.clone(), u),
+            finish::<B, C, _, D>(SvdS, tensor.node.clone(), state.clone(), s),
+            finish::<B, C, _, D>(SvdVh, tensor.node, state, vh),
+        )
+    }
+
+    fn linalg_eigh<const D: usize>(
+        tensor: FloatTensor<Self, D>,
+    ) -> (FloatTensor<Self, D>, FloatTensor<Self, D>) {
+        let (values, vectors) = B::linalg_eigh(tensor.primitive);
+        let state = (values.clone(), vectors.clone());
+
+        (
+            finish::<B, C, _, D>(EighValues, tensor.node.clone(), state.clone(), values),
+            finish::<B, C, _, D>(EighVectors, tensor.node, state, vectors),
+        )
+    }
+}
+
+/// Register the output of an operation with a single input.
+///
+/// The decompositions returning several tensors register each of them separately: their gradients
+/// are linear in the gradients of the outputs, so the contributions of each output add up to the
+/// gradient of the input.
+fn finish<B, C, O, const D: usize>(
+    backward: O,
+    node: NodeRef,
+    state: O::State,
+    output: B::FloatTensorPrimitive<D>,
+) -> AutodiffTensor<B, D>
+where
+    B: Backend,
+    C: CheckpointStrategy,
+    O: Backward<B, D, 1>,
+{
+    match backward.prepare::<C>([node]).compute_bound().stateful() {
+        OpsKind::Tracked(prep) => prep.finish(state, output),
+        OpsKind::UnTracked(prep) => prep.finish(output),
+    }
+}
+
+/// Y = A^-1, so dA = -Y^T dY Y^T.
+#[derive(Debug)]
+struct Inv;
+
+impl<B: Backend, const D: usize> Backward<B, D, 1> for Inv {
+    type State = B::FloatTensorPrimitive<D>;
+
+    fn backward(
+        self,
+        ops: Ops<Self::State, 1>,
+        grads: &mut Gradients,
+        _checkpointer: &mut Checkpointer,
+    ) {
+        unary::<B, D, D, _>(ops.parents, ops.node, grads, |grad| {
+            let output = float::<B, D>(ops.state).transpose();
+
+            primitive(output.clone().matmul(float(grad)).matmul(output).neg())
+        });
+    }
+}
+
+/// The gradient is `det(A) A^-T`, which isn't defined for singular matrices.
+#[derive(Debug)]
+struct Det;
+
+impl<B: Backend, const D: usize> Backward<B, D, 1> for Det {
+    type State = (B::FloatTensorPrimitive<D>, B::FloatTensorPrimitive<D>);
+
+    fn backward(
+        self,
+        ops: Ops<Self::State, 1>,
+        grads: &mut Gradients,
+        _checkpointer: &mut Checkpointer,
+    ) {
+        let (input, output) = ops.state;
+
+        unary::<B, D, D, _>(ops.parents, ops.node, grads, |grad| {
+            let inverse = linalg::inv(float::<B, D>(input)).transpose();
+
+            primitive(inverse.mul(float(grad).mul(float(output))))
+        });
+    }
+}
+
+#[derive(Debug)]
+struct LogAbsDet;
+
+impl<B: Backend, const D: usize> Backward<B, D, 1> for LogAbsDet {
+    type State = B::FloatTensorPrimitive<D>;
+
+    fn backward(
+        self,
+        ops: Ops<Self::State, 1>,
+        grads: &mut Gradients,
+        _checkpointer: &mut Checkpointer,
+    ) {
+        unary::<B, D, D, _>(ops.parents, ops.node, grads, |grad| {
+            let inverse = linalg::inv(float::<B, D>(ops.state)).transpose();
+
+            primitive(inverse.mul(float(grad)))
+        });
+    }
+}
+
+/// dA = sym(L^-T phi(L^T dL) L^-1), where phi keeps the lower triangle with half the diagonal.
+#[derive(Debug)]
+struct Cholesky;
+
+impl<B: Backend, const D: usize> Backward<B, D, 1> for Cholesky {
+    type State = B::FloatTensorPrimitive<D>;
+
+    fn backward(
+        self,
+        ops: Ops<Self::State, 1>,
+        grads: &mut Gradients,
+        _checkpointer: &mut Checkpointer,
+    ) {
+        unary::<B, D, D, _>(ops.parents, ops.node, grads, |grad| {
+            let lower = float::<B, D>(ops.state);
+            let inverse = linalg::inv(lower.clone());
+
+            let phi = lower.transpose().matmul(float(grad));
+            let phi = phi.clone().tril(0).add(phi.tril(-1)).mul_scalar(0.5);
+            let grad = inverse.clone().transpose().matmul(phi).matmul(inverse);
+
+            primitive(symmetrize(grad))
+        });
+    }
+}
+
+type QrState<B, const D: usize> = (
+    <B as Backend>::FloatTensorPrimitive<D>,
+    <B as Backend>::FloatTensorPrimitive<D>,
+    <B as Backend>::FloatTensorPrimitive<D>,
+);
+
+#[derive(Debug)]
+struct QrQ;
+
+impl<B: Backend, const D: usize> Backward<B, D, 1> for QrQ {
+    type State = QrState<B, D>;
+
+    fn backward(
+        self,
+        ops: Ops<Self::State, 1>,
+        grads: &mut Gradients,
+        _checkpointer: &mut Checkpointer,
+    ) {
+        let (input, q, r) = ops.state;
+
+        unary::<B, D, D, _>(ops.parents, ops.node, grads, |grad| {
+            let r = float::<B, D>(r);
+            let grad_r = r.zeros_like();
+
+            primitive(qr_backward(float(input), float(q), r, float(grad), grad_r))
+        });
+    }
+}
+
+#[derive(Debug)]
+struct QrR;
+
+impl<B: Backend, const D: usize> Backward<B, D, 1> for QrR {
+    type State = QrState<B, D>;
+
+    fn backward(
+        self,
+        ops: Ops<Self::State, 1>,
+        grads: &mut Gradients,
+        _checkpointer: &mut Checkpointer,
+    ) {
+        let (input, q, r) = ops.state;
+
+        unary::<B, D, D, _>(ops.parents, ops.node, grads, |grad| {
+            let q = float::<B, D>(q);
+            let grad_q = q.zeros_like();
+
+            primitive(qr_backward(float(input), q, float(r), grad_q, float(grad)))
+        });
+    }
+}
+
+/// The gradient of the reduced QR decomposition.
+///
+/// For matrices with more columns than rows, `A = [X | Y]` and `R = [U | V]` where `X = Q U` is
+/// square, so the gradient of `X` is computed as a square decomposition.
+fn qr_backward<B: Backend, const D: usize>(
+    input: Tensor<B, D>,
+    q: Tensor<B, D>,
+    r: Tensor<B, D>,
+    grad_q: Tensor<B, D>,
+    grad_r: Tensor<B, D>,
+) -> Tensor<B, D> {
+    let [m, n] = matrix_dims(&input);
+
+    if m >= n {
+        return qr_backward_deep(q, r, grad_q, grad_r);
+    }
+
+    let u = r.narrow(D - 1, 0, m);
+    let y = input.narrow(D - 1, m, n - m);
+    let grad_u = grad_r.clone().narrow(D - 1, 0, m);
+    let grad_v = grad_r.narrow(D - 1, m, n - m);
+
+    let grad_y = q.clone().matmul(grad_v.clone());
+    let grad_q = grad_q.add(y.matmul(grad_v.transpose()));
+    let grad_x = qr_backward_deep(q, u, grad_q, grad_u);
+
+    Tensor::cat(vec![grad_x, grad_y], D - 1)
+}
+
+/// The gradient of the QR decomposition of matrices with at least as many rows as columns:
+/// `dA = [dQ + Q copyltu(M)] R^-T` with `M = R dR^T - dQ^T Q`.
+fn qr_backward_deep<B: Backend, const D: usize>(
+    q: Tensor<B, D>,
+    r: Tensor<B, D>,
+    grad_q: Tensor<B, D>,
+    grad_r: Tensor<B, D>,
+) -> Tensor<B, D> {
+    let m = r
+        .clone()
+        .matmul(grad_r.transpose())
+        .sub(grad_q.clone().transpose().matmul(q.clone()));
+    // Copy the lower triangle to the upper triangle
+    let m = m.clone().tril(0).add(m.tril(-1).transpose());
+
+    let grad = grad_q.add(q.matmul(m));
+
+    grad.matmul(linalg::inv(r).transpose())
+}
+
+type SvdState<B, const D: usize> = (
+    <B as Backend>::FloatTensorPrimitive<D>,
+    <B as Backend>::FloatTensorPrimitive<D>,
+    <B as Backend>::FloatTensorPrimitive<D>,
+);
+
+/// dA = U [(F o (U^T dU - dU^T U)) S] Vh + (I - U U^T) dU S^-1 Vh.
+///
+/// The gradients of the singular vectors aren't defined when singular values are repeated or
+/// zero.
+#[derive(Debug)]
+struct SvdU;
+
+impl<B: Backend, const D: usize> Backward<B, D, 1> for SvdU {
+    type State = SvdState<B, D>;
+
+    fn backward(
+        self,
+        ops: Ops<Self::State, 1>,
+        grads: &mut Gradients,
+        _checkpointer: &mut Checkpointer,
+    ) {
+        let (u, s, vh) = ops.state;
+
+        unary::<B, D, D, _>(ops.parents, ops.node, grads, |grad| {
+            let (u, s, vh, grad) = (float::<B, D>(u), float(s), float(vh), float(grad));
+            let factors = svd_factors(s.clone());
+
+            let skew = u.clone().transpose().matmul(grad.clone());
+            let skew = skew
+                .clone()
+                .sub(skew.transpose())
+                .mul(factors)
+                .mul(s.clone());
+            let inner = u.clone().matmul(skew);
+
+            let projected = grad.div(s);
+            let projected = projected
+                .clone()
+                .sub(u.clone().matmul(u.transpose().matmul(projected)));
+
+            primitive(inner.add(projected).matmul(vh))
+        });
+    }
+}
+
+/// dA = U diag(dS) Vh.
+#[derive(Debug)]
+struct SvdS;
+
+impl<B: Backend, const D: usize> Backward<B, D, 1> for SvdS {
+    type State = SvdState<B, D>;
+
+    fn backward(
+        self,
+        ops: Ops<Self::State, 1>,
+        grads: &mut Gradients,
+        _checkpointer: &mut Checkpointer,
+    ) {
+        let (u, _s, vh) = ops.state;
+
+        unary::<B, D, D, _>(ops.parents, ops.node, grads, |grad| {
+            let u = float::<B, D>(u).mul(float(grad));
+
+            primitive(u.matmul(float(vh)))
+        });
+    }
+}
+
+/// dA = U [S (F o (V^T dV - dV^T V))] Vh + U S^-1 dV^T (I - V V^T), with V = Vh^T.
+#[derive(Debug)]
+struct SvdVh;
+
+impl<B: Backend, const D: usize> Backward<B, D, 1> for SvdVh {
+    type State = SvdState<B, D>;
+
+    fn backward(
+        self,
+        ops: Ops<Self::State, 1>,
+        grads: &mut Gradients,
+        _checkpointer: &mut Checkpointer,
+    ) {
+        let (u, s, vh) = ops.state;
+
+        unary::<B, D, D, _>(ops.parents, ops.node, grads, |grad| {
+            let (u, s, vh, grad) = (float::<B, D>(u), float(s), float(vh), float(grad));
+            let factors = svd_factors(s.clone());
+            let s = s.transpose();
+
+            let skew = vh.clone().matmul(grad.clone().transpose());
+            let skew = skew
+                .clone()
+                .sub(skew.transpose())
+                .mul(factors)
+                .mul(s.clone());
+            let inner = skew.matmul(vh.clone());
+
+            let projected = grad.div(s);
+            let projected = projected
+                .clone()
+                .sub(projected.matmul(vh.clone().transpose()).matmul(vh));
+
+            primitive(u.matmul(inner.add(projected)))
+        });
+    }
+}
+
+/// The factors `F_ij = 1 / (s_j^2 - s_i^2)` for `i != j`, zero on the diagonal, from the singular
+/// values stored as a row.
+fn svd_factors<B: Backend, const D: usize>(s: Tensor<B, D>) -> Tensor<B, D> {
+    let squares = s.powf_scalar(2.0);
+
+    off_diagonal_inverse(squares.clone().sub(squares.transpose()))
+}
+
+type EighState<B, const D: usize> = (
+    <B as Backend>::FloatTensorPrimitive<D>,
+    <B as Backend>::FloatTensorPrimitive<D>,
+);
+
+/// dA = V diag(dw) V^T.
+#[derive(Debug)]
+struct EighValues;
+
+impl<B: Backend, const D: usize> Backward<B, D, 1> for EighValues {
+    type State = EighState<B, D>;
+
+    fn backward(
+        self,
+        ops: Ops<Self::State, 1>,
+        grads: &mut Gradients,
+        _checkpointer: &mut Checkpointer,
+    ) {
+        let (_values, vectors) = ops.state;
+
+        unary::<B, D, D, _>(ops.parents, ops.node, grads, |grad| {
+            let vectors = float::<B, D>(vectors);
+            let scaled = vectors.clone().mul(float(grad));
+
+            primitive(scaled.matmul(vectors.transpose()))
+        });
+    }
+}
+
+/// dA = V (F o (V^T dV)) V^T, with `F_ij = 1 / (w_j - w_i)` for `i != j`.
+///
+/// The gradient of the eigenvectors isn't defined when eigenvalues are repeated.
+#[derive(Debug)]
+struct EighVectors;
+
+impl<B: Backend, const D: usize> Backward<B, D, 1> for EighVectors {
+    type State = EighState<B, D>;
+
+    fn backward(
+        self,
+        ops: Ops<Self::State, 1>,
+        grads: &mut Gradients,
+        _checkpointer: &mut Checkpointer,
+    ) {
+        let (values, vectors) = ops.state;
+
+        unary::<B, D, D, _>(ops.parents, ops.node, grads, |grad| {
+            let (values, vectors) = (float::<B, D>(values), float::<B, D>(vectors));
+            let factors = off_diagonal_inverse(values.clone().sub(values.transpose()));
+
+            let inner = vectors.clone().transpose().matmul(float(grad)).mul(factors);
+
+            primitive(vectors.clone().matmul(inner).matmul(vectors.transpose()))
+        });
+    }
+}
+
+/// The inverse of the off-diagonal elements of square matrices, the diagonal being set to zero.
+fn off_diagonal_inverse<B: Backend, const D: usize>(tensor: Tensor<B, D>) -> Tensor<B, D> {
+    let [n, _] = matrix_dims(&tensor);
+    let identity = Tensor::<B, 2>::eye(n, &tensor.device()).unsqueeze::<D>();
+
+    let inverse = tensor.add(identity.clone()).recip();
+
+    inverse.sub(identity)
+}
+
+fn symmetrize<B: Backend, const D: usize>(tensor: Tensor<B, D>) -> Tensor<B, D> {
+    tensor.clone().add(tensor.transpose()).mul_scalar(0.5)
+}
+
+fn matrix_dims<B: Backend, const D: usize>(tensor: &Tensor<B, D>) -> [usize; 2] {
+    let dims = tensor.dims();
+
+    [dims[D - 2], dims[D - 1]]
+}
+
+fn float<B: Backend, const D: usize>(tensor: B::FloatTensorPrimitive<D>) -> Tensor<B, D> {
+    Tensor::from_primitive(TensorPrimitive::Float(tensor))
+}
+
+fn primitive<B: Backend, const D: usize>(tensor: Tensor<B, D>) -> B::FloatTensorPrimitive<D> {
+    tensor.into_primitive().tensor()
+}
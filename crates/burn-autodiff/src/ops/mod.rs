@@ -2,11 +2,14 @@ mod activation;
 mod backward;
 mod base;
 mod bool_tensor;
+mod fft;
 mod int_tensor;
+mod linalg;
 mod module;
 mod qtensor;
 mod tensor;
 
+pub(crate) mod cumulative;
 pub(crate) mod maxmin;
 pub(crate) mod sort;
 
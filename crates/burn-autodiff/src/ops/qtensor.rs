@@ -1,31 +1,109 @@
+use std::marker::PhantomData;
+
 use burn_tensor::{
     backend::Backend,
     ops::{FloatTensor, QTensorOps, QuantizedTensor},
-    Device, QuantizationStrategy, Shape, TensorData,
+    Device, ElementConversion, QuantizationStrategy, Shape, TensorData,
 };
 
-use crate::{checkpoint::strategy::CheckpointStrategy, Autodiff};
+use crate::{
+    checkpoint::{
+        base::Checkpointer, retro_forward::RetroForward, state::BackwardStates,
+        strategy::CheckpointStrategy,
+    },
+    grads::Gradients,
+    graph::NodeID,
+    ops::{unary, Backward, Ops, OpsKind},
+    tensor::AutodiffTensor,
+    Autodiff,
+};
 
 impl<B: Backend, C: CheckpointStrategy> QTensorOps<Self> for Autodiff<B, C> {
     fn q_from_data<const D: usize>(
-        _data: TensorData,
-        _device: &Device<Self>,
+        data: TensorData,
+        device: &Device<Self>,
     ) -> QuantizedTensor<Self, D> {
-        todo!()
+        B::q_from_data(data, device)
     }
 
     fn quantize<const D: usize>(
-        _tensor: FloatTensor<Self, D>,
-        _strategy: &QuantizationStrategy,
+        tensor: FloatTensor<Self, D>,
+        strategy: &QuantizationStrategy,
     ) -> QuantizedTensor<Self, D> {
-        todo!() // required for QAT
+        // Quantized tensors are not tracked, use `fake_quantize` to train with quantization.
+        B::quantize(tensor.primitive, strategy)
     }
 
     fn dequantize<const D: usize>(
-        _tensor: QuantizedTensor<Self, D>,
-        _strategy: &QuantizationStrategy,
+        tensor: QuantizedTensor<Self, D>,
+        strategy: &QuantizationStrategy,
     ) -> FloatTensor<Self, D> {
-        todo!()
+        AutodiffTensor::new(B::dequantize(tensor, strategy))
+    }
+
+    fn fake_quantize<const D: usize>(
+        tensor: FloatTensor<Self, D>,
+        strategy: &QuantizationStrategy,
+    ) -> FloatTensor<Self, D> {
+        #[derive(Debug)]
+        struct FakeQuantize;
+
+        #[derive(new, Debug)]
+        struct RetroFakeQuantize<B: Backend, const D: usize> {
+            input_id: NodeID,
+            strategy: QuantizationStrategy,
+            _backend: PhantomData<B>,
+        }
+
+        impl<B: Backend, const D: usize> RetroForward for RetroFakeQuantize<B, D> {
+            fn forward(&self, states: &mut BackwardStates, out_node: NodeID) {
+                let input = states.get_state::<B::FloatTensorPrimitive<D>>(&self.input_id);
+                let out = B::fake_quantize(input, &self.strategy);
+                states.save(out_node, out)
+            }
+        }
+
+        impl<B: Backend, const D: usize> Backward<B, D, 1> for FakeQuantize {
+            type State = (NodeID, QuantizationStrategy);
+
+            fn backward(
+                self,
+                ops: Ops<Self::State, 1>,
+                grads: &mut Gradients,
+                checkpointer: &mut Checkpointer,
+            ) {
+                let (input_id, strategy) = ops.state;
+                let input: B::FloatTensorPrimitive<D> = checkpointer.retrieve_node_output(input_id);
+
+                // Straight-through estimator: the rounding is ignored, but the gradient of the
+                // values clamped to the quantization range is zero.
+                unary::<B, D, D, _>(ops.parents, ops.node, grads, |grad| {
+                    let (lower, upper) = quantization_bounds::<B, D>(&input, &strategy);
+                    let below = B::float_lower(input.clone(), lower);
+                    let above = B::float_greater(input, upper);
+                    let grad = B::float_mask_fill(grad, below, 0.elem());
+
+                    B::float_mask_fill(grad, above, 0.elem())
+                });
+            }
+        }
+
+        match FakeQuantize
+            .prepare::<C>([tensor.node.clone()])
+            .memory_bound()
+            .retro_forward(RetroFakeQuantize::<B, D>::new(
+                tensor.node.id,
+                strategy.clone(),
+            ))
+            .parents([&tensor])
+            .stateful()
+        {
+            OpsKind::Tracked(mut prep) => {
+                let state = (prep.checkpoint(&tensor), strategy.clone());
+                prep.finish(state, B::fake_quantize(tensor.primitive, strategy))
+            }
+            OpsKind::UnTracked(prep) => prep.finish(B::fake_quantize(tensor.primitive, strategy)),
+        }
     }
 
     fn q_shape<const D: usize>(tensor: &QuantizedTensor<Self, D>) -> Shape<D> {
@@ -50,3 +128,22 @@ impl<B: Backend, C: CheckpointStrategy> QTensorOps<Self> for Autodiff<B, C> {
         B::q_into_data(tensor, strategy).await
     }
 }
+
+/// Computes the lowest and highest values that can be represented by the quantization strategy
+/// for each element of the tensor.
+fn quantization_bounds<B: Backend, const D: usize>(
+    tensor: &B::FloatTensorPrimitive<D>,
+    strategy: &QuantizationStrategy,
+) -> (B::FloatTensorPrimitive<D>, B::FloatTensorPrimitive<D>) {
+    let shape = B::float_shape(tensor);
+    let device = B::float_device(tensor);
+    let num_elements = shape.num_elements();
+    let (min, max) = strategy.value_range();
+
+    let bound = |value: i32| {
+        let values = strategy.dequantize(&vec![value; num_elements], &shape.dims);
+        B::float_from_data(TensorData::new(values, shape.clone()), &device)
+    };
+
+    (bound(min), bound(max))
+}
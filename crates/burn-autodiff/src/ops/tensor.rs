@@ -20,6 +20,7 @@ use burn_tensor::{
     Device, ElementConversion, Shape, Tensor, TensorData, TensorPrimitive,
 };
 
+use super::cumulative::{cummaxmin_indices, CumMaxMin, CumProd, CumSum};
 use super::maxmin::MaxMinDim;
 
 impl<B: Backend, C: CheckpointStrategy> FloatTensorOps<Self> for Autodiff<B, C> {
@@ -488,6 +489,98 @@ impl<B: Backend, C: CheckpointStrategy> FloatTensorOps<Self> for Autodiff<B, C>
             .stateless(B::float_remainder_scalar(lhs.primitive, rhs))
     }
 
+    fn float_fmod<const D: usize>(
+        lhs: FloatTensor<Self, D>,
+        rhs: FloatTensor<Self, D>,
+    ) -> FloatTensor<Self, D> {
+        #[derive(Debug)]
+        struct Fmod;
+
+        retro_binary!(RetroFmod, B::float_fmod);
+
+        impl<B: Backend, const D: usize> Backward<B, D, 2> for Fmod {
+            type State = (Option<(NodeID, NodeID)>, BinaryOpsBroadcast<D>);
+
+            fn backward(
+                self,
+                ops: Ops<Self::State, 2>,
+                grads: &mut Gradients,
+                checkpointer: &mut Checkpointer,
+            ) {
+                let (state, broadcast) = ops.state;
+                let quotient = state.map(|(lhs, rhs)| {
+                    let lhs = checkpointer.retrieve_node_output(lhs);
+                    let rhs = checkpointer.retrieve_node_output(rhs);
+
+                    B::float_trunc(B::float_div(lhs, rhs))
+                });
+
+                binary::<B, D, D, D, _, _>(
+                    ops.parents,
+                    ops.node,
+                    grads,
+                    |grad| broadcast.backward_lhs::<B>(grad),
+                    |grad| {
+                        let grad = B::float_mul(grad, B::float_neg(quotient.unwrap()));
+
+                        broadcast.backward_rhs::<B>(grad)
+                    },
+                );
+            }
+        }
+
+        let rhs_tracked = rhs.is_tracked();
+        let broadcast = BinaryOpsBroadcast::new::<B>(&lhs.primitive, &rhs.primitive);
+
+        match Fmod
+            .prepare::<C>([lhs.node.clone(), rhs.node.clone()])
+            .memory_bound()
+            .retro_forward(RetroFmod::<B, D>::new(lhs.node.id, rhs.node.id))
+            .parents([&lhs, &rhs])
+            .stateful()
+        {
+            OpsKind::Tracked(mut prep) => {
+                let state = rhs_tracked.then(|| (prep.checkpoint(&lhs), prep.checkpoint(&rhs)));
+
+                prep.finish(
+                    (state, broadcast),
+                    B::float_fmod(lhs.primitive, rhs.primitive),
+                )
+            }
+            OpsKind::UnTracked(prep) => prep.finish(B::float_fmod(lhs.primitive, rhs.primitive)),
+        }
+    }
+
+    fn float_fmod_scalar<const D: usize>(
+        lhs: FloatTensor<Self, D>,
+        rhs: FloatElem<B>,
+    ) -> FloatTensor<Self, D> {
+        #[derive(Debug)]
+        struct FmodScalar;
+
+        retro_unary_scalar!(RetroFmodScalar, B::float_fmod_scalar);
+
+        impl<B: Backend, const D: usize> Backward<B, D, 1> for FmodScalar {
+            type State = ();
+
+            fn backward(
+                self,
+                ops: Ops<Self::State, 1>,
+                grads: &mut Gradients,
+                _checkpointer: &mut Checkpointer,
+            ) {
+                unary::<B, D, D, _>(ops.parents, ops.node, grads, |grad| grad);
+            }
+        }
+
+        FmodScalar
+            .prepare::<C>([lhs.node.clone()])
+            .memory_bound()
+            .retro_forward(RetroFmodScalar::<B, D>::new(lhs.node.id, rhs))
+            .parents([&lhs])
+            .stateless(B::float_fmod_scalar(lhs.primitive, rhs))
+    }
+
     fn float_matmul<const D: usize>(
         lhs: FloatTensor<Self, D>,
         rhs: FloatTensor<Self, D>,
@@ -1578,6 +1671,75 @@ impl<B: Backend, C: CheckpointStrategy> FloatTensorOps<Self> for Autodiff<B, C>
         }
     }
 
+    fn float_cumsum<const D: usize>(
+        tensor: FloatTensor<Self, D>,
+        dim: usize,
+    ) -> FloatTensor<Self, D> {
+        match CumSum
+            .prepare::<C>([tensor.node])
+            .compute_bound()
+            .stateful()
+        {
+            OpsKind::Tracked(prep) => prep.finish(dim, B::float_cumsum(tensor.primitive, dim)),
+            OpsKind::UnTracked(prep) => prep.finish(B::float_cumsum(tensor.primitive, dim)),
+        }
+    }
+
+    fn float_cumprod<const D: usize>(
+        tensor: FloatTensor<Self, D>,
+        dim: usize,
+    ) -> FloatTensor<Self, D> {
+        match CumProd
+            .prepare::<C>([tensor.node])
+            .compute_bound()
+            .stateful()
+        {
+            OpsKind::Tracked(prep) => {
+                let output = B::float_cumprod(tensor.primitive.clone(), dim);
+                prep.finish((tensor.primitive, output.clone(), dim), output)
+            }
+            OpsKind::UnTracked(prep) => prep.finish(B::float_cumprod(tensor.primitive, dim)),
+        }
+    }
+
+    fn float_cummax<const D: usize>(
+        tensor: FloatTensor<Self, D>,
+        dim: usize,
+    ) -> FloatTensor<Self, D> {
+        match CumMaxMin
+            .prepare::<C>([tensor.node])
+            .compute_bound()
+            .stateful()
+        {
+            OpsKind::Tracked(prep) => {
+                let shape = B::float_shape(&tensor.primitive);
+                let output = B::float_cummax(tensor.primitive.clone(), dim);
+                let indices = cummaxmin_indices::<B, D>(tensor.primitive, output.clone(), dim);
+                prep.finish((indices, shape, dim), output)
+            }
+            OpsKind::UnTracked(prep) => prep.finish(B::float_cummax(tensor.primitive, dim)),
+        }
+    }
+
+    fn float_cummin<const D: usize>(
+        tensor: FloatTensor<Self, D>,
+        dim: usize,
+    ) -> FloatTensor<Self, D> {
+        match CumMaxMin
+            .prepare::<C>([tensor.node])
+            .compute_bound()
+            .stateful()
+        {
+            OpsKind::Tracked(prep) => {
+                let shape = B::float_shape(&tensor.primitive);
+                let output = B::float_cummin(tensor.primitive.clone(), dim);
+                let indices = cummaxmin_indices::<B, D>(tensor.primitive, output.clone(), dim);
+                prep.finish((indices, shape, dim), output)
+            }
+            OpsKind::UnTracked(prep) => prep.finish(B::float_cummin(tensor.primitive, dim)),
+        }
+    }
+
     fn float_argmax<const D: usize>(tensor: FloatTensor<Self, D>, dim: usize) -> IntTensor<B, D> {
         B::float_argmax(tensor.primitive, dim)
     }
@@ -2000,6 +2162,125 @@ impl<B: Backend, C: CheckpointStrategy> FloatTensorOps<Self> for Autodiff<B, C>
         }
     }
 
+    fn float_floor<const D: usize>(tensor: FloatTensor<Self, D>) -> FloatTensor<Self, D> {
+        #[derive(Debug)]
+        struct Floor;
+
+        retro_unary!(RetroFloor, B::float_floor);
+
+        impl<B: Backend, const D: usize> Backward<B, D, 1> for Floor {
+            type State = ();
+
+            fn backward(
+                self,
+                ops: Ops<Self::State, 1>,
+                grads: &mut Gradients,
+                _checkpointer: &mut Checkpointer,
+            ) {
+                // The function is piecewise constant, so its derivative is zero almost everywhere.
+                unary::<B, D, D, _>(ops.parents, ops.node, grads, |grad| {
+                    B::float_mul_scalar(grad, 0.elem())
+                });
+            }
+        }
+
+        Floor
+            .prepare::<C>([tensor.node.clone()])
+            .memory_bound()
+            .retro_forward(RetroFloor::<B, D>::new(tensor.node.id))
+            .parents([&tensor])
+            .stateless(B::float_floor(tensor.primitive))
+    }
+
+    fn float_ceil<const D: usize>(tensor: FloatTensor<Self, D>) -> FloatTensor<Self, D> {
+        #[derive(Debug)]
+        struct Ceil;
+
+        retro_unary!(RetroCeil, B::float_ceil);
+
+        impl<B: Backend, const D: usize> Backward<B, D, 1> for Ceil {
+            type State = ();
+
+            fn backward(
+                self,
+                ops: Ops<Self::State, 1>,
+                grads: &mut Gradients,
+                _checkpointer: &mut Checkpointer,
+            ) {
+                // The function is piecewise constant, so its derivative is zero almost everywhere.
+                unary::<B, D, D, _>(ops.parents, ops.node, grads, |grad| {
+                    B::float_mul_scalar(grad, 0.elem())
+                });
+            }
+        }
+
+        Ceil.prepare::<C>([tensor.node.clone()])
+            .memory_bound()
+            .retro_forward(RetroCeil::<B, D>::new(tensor.node.id))
+            .parents([&tensor])
+            .stateless(B::float_ceil(tensor.primitive))
+    }
+
+    fn float_round<const D: usize>(tensor: FloatTensor<Self, D>) -> FloatTensor<Self, D> {
+        #[derive(Debug)]
+        struct Round;
+
+        retro_unary!(RetroRound, B::float_round);
+
+        impl<B: Backend, const D: usize> Backward<B, D, 1> for Round {
+            type State = ();
+
+            fn backward(
+                self,
+                ops: Ops<Self::State, 1>,
+                grads: &mut Gradients,
+                _checkpointer: &mut Checkpointer,
+            ) {
+                // The function is piecewise constant, so its derivative is zero almost everywhere.
+                unary::<B, D, D, _>(ops.parents, ops.node, grads, |grad| {
+                    B::float_mul_scalar(grad, 0.elem())
+                });
+            }
+        }
+
+        Round
+            .prepare::<C>([tensor.node.clone()])
+            .memory_bound()
+            .retro_forward(RetroRound::<B, D>::new(tensor.node.id))
+            .parents([&tensor])
+            .stateless(B::float_round(tensor.primitive))
+    }
+
+    fn float_trunc<const D: usize>(tensor: FloatTensor<Self, D>) -> FloatTensor<Self, D> {
+        #[derive(Debug)]
+        struct Trunc;
+
+        retro_unary!(RetroTrunc, B::float_trunc);
+
+        impl<B: Backend, const D: usize> Backward<B, D, 1> for Trunc {
+            type State = ();
+
+            fn backward(
+                self,
+                ops: Ops<Self::State, 1>,
+                grads: &mut Gradients,
+                _checkpointer: &mut Checkpointer,
+            ) {
+                // The function is piecewise constant, so its derivative is zero almost everywhere.
+                unary::<B, D, D, _>(ops.parents, ops.node, grads, |grad| {
+                    B::float_mul_scalar(grad, 0.elem())
+                });
+            }
+        }
+
+        Trunc
+            .prepare::<C>([tensor.node.clone()])
+            .memory_bound()
+            .retro_forward(RetroTrunc::<B, D>::new(tensor.node.id))
+            .parents([&tensor])
+            .stateless(B::float_trunc(tensor.primitive))
+    }
+
     fn float_cat<const D: usize>(
         tensors: Vec<FloatTensor<Self, D>>,
         dim: usize,
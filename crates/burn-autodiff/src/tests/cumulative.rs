@@ -0,0 +1,97 @@
+#[burn_tensor_testgen::testgen(ad_cumulative)]
+mod tests {
+    use super::*;
+    use burn_tensor::TensorData;
+
+    #[test]
+    fn should_diff_cumsum() {
+        let device = Default::default();
+        let tensor_1 =
+            TestAutodiffTensor::<2>::from_floats([[1.0, 2.0, 3.0], [4.0, 5.0, 6.0]], &device)
+                .require_grad();
+        let weights = TestAutodiffTensor::from_floats([[1.0, 2.0, 3.0], [4.0, 5.0, 6.0]], &device);
+
+        let tensor_2 = tensor_1.clone().cumsum(1).mul(weights);
+        let grads = tensor_2.backward();
+
+        let grad_1 = tensor_1.grad(&grads).unwrap();
+
+        let expected = TensorData::from([[6.0, 5.0, 3.0], [15.0, 11.0, 6.0]]);
+        grad_1.to_data().assert_approx_eq(&expected, 5);
+    }
+
+    #[test]
+    fn should_diff_cumprod() {
+        let device = Default::default();
+        let tensor_1 =
+            TestAutodiffTensor::<2>::from_floats([[1.0, 2.0, 3.0], [2.0, -1.0, 0.5]], &device)
+                .require_grad();
+
+        let tensor_2 = tensor_1.clone().cumprod(1);
+        let grads = tensor_2.backward();
+
+        let grad_1 = tensor_1.grad(&grads).unwrap();
+
+        let expected = TensorData::from([[9.0, 4.0, 2.0], [-0.5, 3.0, -2.0]]);
+        grad_1.to_data().assert_approx_eq(&expected, 5);
+    }
+
+    #[test]
+    fn should_diff_cumprod_with_zeros() {
+        let device = Default::default();
+        let tensor_1 = TestAutodiffTensor::<2>::from_floats(
+            [[2.0, 0.0, 3.0, 0.0], [0.0, 2.0, 3.0, 4.0]],
+            &device,
+        )
+        .require_grad();
+
+        let tensor_2 = tensor_1.clone().cumprod(1);
+        let grads = tensor_2.backward();
+
+        let grad_1 = tensor_1.grad(&grads).unwrap();
+
+        let expected = TensorData::from([[1.0, 8.0, 0.0, 0.0], [33.0, 0.0, 0.0, 0.0]]);
+        grad_1.to_data().assert_approx_eq(&expected, 5);
+    }
+
+    #[test]
+    fn should_diff_cummax() {
+        let device = Default::default();
+        let tensor_1 = TestAutodiffTensor::<2>::from_floats(
+            [[1.0, 3.0, 2.0, 5.0, 4.0], [2.0, 2.0, 1.0, 0.0, 3.0]],
+            &device,
+        )
+        .require_grad();
+        let weights = TestAutodiffTensor::from_floats(
+            [[1.0, 2.0, 3.0, 4.0, 5.0], [1.0, 2.0, 3.0, 4.0, 5.0]],
+            &device,
+        );
+
+        let tensor_2 = tensor_1.clone().cummax(1).mul(weights);
+        let grads = tensor_2.backward();
+
+        let grad_1 = tensor_1.grad(&grads).unwrap();
+
+        // With ties, the gradient flows to the last element equal to the maximum
+        let expected = TensorData::from([[1.0, 5.0, 0.0, 9.0, 0.0], [1.0, 9.0, 0.0, 0.0, 5.0]]);
+        grad_1.to_data().assert_approx_eq(&expected, 5);
+    }
+
+    #[test]
+    fn should_diff_cummin() {
+        let device = Default::default();
+        let tensor_1 =
+            TestAutodiffTensor::<2>::from_floats([[3.0, 0.0], [1.0, 4.0], [2.0, -1.0]], &device)
+                .require_grad();
+        let weights =
+            TestAutodiffTensor::from_floats([[1.0, 2.0], [3.0, 4.0], [5.0, 6.0]], &device);
+
+        let tensor_2 = tensor_1.clone().cummin(0).mul(weights);
+        let grads = tensor_2.backward();
+
+        let grad_1 = tensor_1.grad(&grads).unwrap();
+
+        let expected = TensorData::from([[1.0, 6.0], [8.0, 0.0], [0.0, 6.0]]);
+        grad_1.to_data().assert_approx_eq(&expected, 5);
+    }
+}
@@ -0,0 +1,29 @@
+#[burn_tensor_testgen::testgen(ad_fake_quantize)]
+mod tests {
+    use super::*;
+    use burn_tensor::{AffineQuantization, Quantization, QuantizationStrategy, TensorData};
+
+    #[test]
+    fn should_diff_fake_quantize() {
+        let data = TensorData::from([-2.0, -0.5, 0.3, 0.9, 2.0]);
+        let weights = TensorData::from([1.0, 2.0, 3.0, 4.0, 5.0]);
+        let strategy =
+            QuantizationStrategy::PerTensorAffineInt8(AffineQuantization::new(-1.0, 1.0));
+
+        let device = Default::default();
+        let x = TestAutodiffTensor::<1>::from_data(data, &device).require_grad();
+        let w = TestAutodiffTensor::<1>::from_data(weights, &device);
+
+        let y = x.clone().fake_quantize(&strategy);
+
+        let loss = (y.clone() * w).sum();
+        let grads = loss.backward();
+        let grad = x.grad(&grads).unwrap();
+
+        y.to_data()
+            .assert_approx_eq(&TensorData::from([-1.0, -0.5, 0.3, 0.9, 1.0]), 2);
+        // The gradient of the values outside of the quantization range is zero.
+        grad.to_data()
+            .assert_eq(&TensorData::from([0.0, 2.0, 3.0, 4.0, 0.0]), false);
+    }
+}
@@ -0,0 +1,67 @@
+#[burn_tensor_testgen::testgen(ad_fft)]
+mod tests {
+    use super::*;
+    use burn_tensor::{signal, TensorData};
+
+    #[test]
+    fn should_diff_fft() {
+        let device = Default::default();
+        let re = TestAutodiffTensor::<1>::from_floats([1.0, 2.0, 3.0], &device).require_grad();
+        let im = TestAutodiffTensor::<1>::from_floats([0.0, 1.0, -1.0], &device).require_grad();
+        let weights_re = TestAutodiffTensor::from_floats([1.0, 2.0, 3.0], &device);
+        let weights_im = TestAutodiffTensor::from_floats([-1.0, 0.0, 2.0], &device);
+
+        let (output_re, output_im) = signal::fft(re.clone(), im.clone(), 0);
+        let output = output_re.mul(weights_re).add(output_im.mul(weights_im));
+        let grads = output.backward();
+
+        let grad_re = re.grad(&grads).unwrap();
+        let grad_im = im.grad(&grads).unwrap();
+
+        grad_re
+            .to_data()
+            .assert_approx_eq(&TensorData::from([6.0, 0.2321, -3.2321]), 3);
+        grad_im
+            .to_data()
+            .assert_approx_eq(&TensorData::from([1.0, -2.866, -1.134]), 3);
+    }
+
+    #[test]
+    fn should_diff_rfft_power() {
+        let device = Default::default();
+        let signal =
+            TestAutodiffTensor::<1>::from_floats([1.0, 2.0, 3.0, 4.0], &device).require_grad();
+        let weights = TestAutodiffTensor::from_floats([1.0, 2.0, 3.0], &device);
+
+        let (re, im) = signal::rfft(signal.clone(), 0);
+        let power = re.powf_scalar(2.0).add(im.powf_scalar(2.0));
+        let output = power.mul(weights);
+        let grads = output.backward();
+
+        let grad = signal.grad(&grads).unwrap();
+
+        grad.to_data()
+            .assert_approx_eq(&TensorData::from([0.0, 24.0, 16.0, 40.0]), 3);
+    }
+
+    #[test]
+    fn should_diff_irfft() {
+        let device = Default::default();
+        let re = TestAutodiffTensor::<1>::from_floats([1.0, 2.0, 0.5], &device).require_grad();
+        let im = TestAutodiffTensor::<1>::from_floats([0.0, 1.0, 0.0], &device).require_grad();
+        let weights = TestAutodiffTensor::from_floats([1.0, -1.0, 2.0, 3.0], &device);
+
+        let output = signal::irfft(re.clone(), im.clone(), 0, 4).mul(weights);
+        let grads = output.backward();
+
+        let grad_re = re.grad(&grads).unwrap();
+        let grad_im = im.grad(&grads).unwrap();
+
+        grad_re
+            .to_data()
+            .assert_approx_eq(&TensorData::from([1.25, -0.5, 0.25]), 3);
+        grad_im
+            .to_data()
+            .assert_approx_eq(&TensorData::from([0.0, 2.0, 0.0]), 3);
+    }
+}
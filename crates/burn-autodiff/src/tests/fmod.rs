@@ -0,0 +1,65 @@
+#[burn_tensor_testgen::testgen(ad_fmod)]
+mod tests {
+    use super::*;
+    use burn_tensor::TensorData;
+
+    #[test]
+    fn should_diff_fmod() {
+        let data_1 = TensorData::from([5.5, -5.5, 7.0]);
+        let data_2 = TensorData::from([2.0, 2.0, -3.0]);
+
+        let device = Default::default();
+        let tensor_1 = TestAutodiffTensor::<1>::from_data(data_1, &device).require_grad();
+        let tensor_2 = TestAutodiffTensor::from_data(data_2, &device).require_grad();
+
+        let tensor_3 = tensor_1.clone().fmod(tensor_2.clone());
+        let grads = tensor_3.backward();
+
+        let grad_1 = tensor_1.grad(&grads).unwrap();
+        let grad_2 = tensor_2.grad(&grads).unwrap();
+
+        grad_1
+            .to_data()
+            .assert_approx_eq(&TensorData::from([1.0, 1.0, 1.0]), 3);
+        grad_2
+            .to_data()
+            .assert_approx_eq(&TensorData::from([-2.0, 2.0, 2.0]), 3);
+    }
+
+    #[test]
+    fn should_diff_fmod_broadcast() {
+        let data_1 = TensorData::from([[5.0, -5.0], [3.5, 1.0]]);
+        let data_2 = TensorData::from([[2.0, 3.0]]);
+
+        let device = Default::default();
+        let tensor_1 = TestAutodiffTensor::<2>::from_data(data_1, &device).require_grad();
+        let tensor_2 = TestAutodiffTensor::from_data(data_2, &device).require_grad();
+
+        let tensor_3 = tensor_1.clone().fmod(tensor_2.clone());
+        let grads = tensor_3.backward();
+
+        let grad_1 = tensor_1.grad(&grads).unwrap();
+        let grad_2 = tensor_2.grad(&grads).unwrap();
+
+        grad_1
+            .to_data()
+            .assert_approx_eq(&TensorData::from([[1.0, 1.0], [1.0, 1.0]]), 3);
+        grad_2
+            .to_data()
+            .assert_approx_eq(&TensorData::from([[-3.0, 1.0]]), 3);
+    }
+
+    #[test]
+    fn should_diff_fmod_scalar() {
+        let data = TensorData::from([-3.0, 2.5, 7.0]);
+
+        let tensor = TestAutodiffTensor::<1>::from_data(data, &Default::default()).require_grad();
+        let tensor_out = tensor.clone().fmod_scalar(2.0);
+
+        let grads = tensor_out.backward();
+        let grad = tensor.grad(&grads).unwrap();
+
+        grad.to_data()
+            .assert_eq(&TensorData::from([1.0, 1.0, 1.0]), false);
+    }
+}
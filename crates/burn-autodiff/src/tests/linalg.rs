@@ -0,0 +1,215 @@
+#[burn_tensor_testgen::testgen(ad_linalg)]
+mod tests {
+    use super::*;
+    use burn_tensor::{linalg, TensorData};
+
+    #[test]
+    fn should_diff_inv() {
+        let device = Default::default();
+        let tensor_1 =
+            TestAutodiffTensor::<2>::from_floats([[2.0, 1.0], [1.0, 3.0]], &device).require_grad();
+        let weights = TestAutodiffTensor::from_floats([[1.0, 2.0], [3.0, 4.0]], &device);
+
+        let tensor_2 = linalg::inv(tensor_1.clone()).mul(weights);
+        let grads = tensor_2.backward();
+
+        let grad_1 = tensor_1.grad(&grads).unwrap();
+
+        let expected = TensorData::from([[0.08, -0.16], [-0.36, -0.28]]);
+        grad_1.to_data().assert_approx_eq(&expected, 4);
+    }
+
+    #[test]
+    fn should_diff_det() {
+        let device = Default::default();
+        let tensor_1 = TestAutodiffTensor::<2>::from_floats(
+            [[2.0, 1.0, 0.0], [1.0, 3.0, 1.0], [0.0, 1.0, 4.0]],
+            &device,
+        )
+        .require_grad();
+
+        let tensor_2 = linalg::det(tensor_1.clone());
+        let grads = tensor_2.backward();
+
+        let grad_1 = tensor_1.grad(&grads).unwrap();
+
+        let expected = TensorData::from([[11.0, -4.0, 1.0], [-4.0, 8.0, -2.0], [1.0, -2.0, 5.0]]);
+        grad_1.to_data().assert_approx_eq(&expected, 3);
+    }
+
+    #[test]
+    fn should_diff_slogdet() {
+        let device = Default::default();
+        let tensor_1 =
+            TestAutodiffTensor::<2>::from_floats([[1.0, 2.0], [3.0, 4.0]], &device).require_grad();
+
+        let (_sign, tensor_2) = linalg::slogdet(tensor_1.clone());
+        let grads = tensor_2.backward();
+
+        let grad_1 = tensor_1.grad(&grads).unwrap();
+
+        let expected = TensorData::from([[-2.0, 1.5], [1.0, -0.5]]);
+        grad_1.to_data().assert_approx_eq(&expected, 4);
+    }
+
+    #[test]
+    fn should_diff_solve() {
+        let device = Default::default();
+        let tensor_1 =
+            TestAutodiffTensor::<2>::from_floats([[3.0, 1.0], [1.0, 2.0]], &device).require_grad();
+        let tensor_2 =
+            TestAutodiffTensor::from_floats([[9.0, 1.0], [8.0, 2.0]], &device).require_grad();
+        let weights = TestAutodiffTensor::from_floats([[1.0, 2.0], [3.0, 4.0]], &device);
+
+        let tensor_3 = linalg::solve(tensor_1.clone(), tensor_2.clone()).mul(weights);
+        let grads = tensor_3.backward();
+
+        let grad_1 = tensor_1.grad(&grads).unwrap();
+        let grad_2 = tensor_2.grad(&grads).unwrap();
+
+        let expected = TensorData::from([[0.4, 0.6], [-3.2, -6.8]]);
+        grad_1.to_data().assert_approx_eq(&expected, 4);
+        let expected = TensorData::from([[-0.2, 0.0], [1.6, 2.0]]);
+        grad_2.to_data().assert_approx_eq(&expected, 4);
+    }
+
+    #[test]
+    fn should_diff_cholesky() {
+        let device = Default::default();
+        let tensor_1 = TestAutodiffTensor::<2>::from_floats(
+            [[4.0, 2.0, 0.0], [2.0, 3.0, 1.0], [0.0, 1.0, 2.0]],
+            &device,
+        )
+        .require_grad();
+        let weights = TestAutodiffTensor::from_floats(
+            [[1.0, 0.0, 0.0], [2.0, 3.0, 0.0], [4.0, 5.0, 6.0]],
+            &device,
+        );
+
+        let tensor_2 = linalg::cholesky(tensor_1.clone()).mul(weights);
+        let grads = tensor_2.backward();
+
+        let grad_1 = tensor_1.grad(&grads).unwrap();
+
+        let expected = TensorData::from([
+            [0.19729, 0.10543, 0.72849],
+            [0.10543, 0.78915, 0.54302],
+            [0.72849, 0.54302, 2.44949],
+        ]);
+        grad_1.to_data().assert_approx_eq(&expected, 3);
+    }
+
+    #[test]
+    fn should_diff_qr() {
+        let device = Default::default();
+        let tensor_1 =
+            TestAutodiffTensor::<2>::from_floats([[1.0, 2.0], [3.0, 4.0], [5.0, 6.0]], &device)
+                .require_grad();
+        let weights_q =
+            TestAutodiffTensor::from_floats([[1.0, -1.0], [2.0, 0.0], [0.0, 1.0]], &device);
+        let weights_r = TestAutodiffTensor::from_floats([[1.0, 2.0], [0.0, 3.0]], &device);
+
+        // The squares don't depend on the signs of the columns of Q and the rows of R
+        let (q, r) = linalg::qr(tensor_1.clone());
+        let tensor_2 = q
+            .powf_scalar(2.0)
+            .mul(weights_q)
+            .sum()
+            .add(r.powf_scalar(2.0).mul(weights_r).sum());
+        let grads = tensor_2.backward();
+
+        let grad_1 = tensor_1.grad(&grads).unwrap();
+
+        let expected = TensorData::from([
+            [0.92136, 8.98571],
+            [4.45932, 17.45714],
+            [11.14014, 22.92857],
+        ]);
+        grad_1.to_data().assert_approx_eq(&expected, 3);
+    }
+
+    #[test]
+    fn should_diff_qr_wide() {
+        let device = Default::default();
+        let tensor_1 =
+            TestAutodiffTensor::<2>::from_floats([[1.0, 2.0, 3.0], [4.0, 5.0, 7.0]], &device)
+                .require_grad();
+        let weights_q = TestAutodiffTensor::from_floats([[1.0, -1.0], [2.0, 0.0]], &device);
+        let weights_r =
+            TestAutodiffTensor::from_floats([[1.0, 2.0, 3.0], [0.0, 1.0, 2.0]], &device);
+
+        let (q, r) = linalg::qr(tensor_1.clone());
+        let tensor_2 = q
+            .powf_scalar(2.0)
+            .mul(weights_q)
+            .sum()
+            .add(r.powf_scalar(2.0).mul(weights_r).sum());
+        let grads = tensor_2.backward();
+
+        let grad_1 = tensor_1.grad(&grads).unwrap();
+
+        let expected =
+            TensorData::from([[8.11765, 6.58824, 15.64706], [6.47059, 20.35294, 42.58824]]);
+        grad_1.to_data().assert_approx_eq(&expected, 3);
+    }
+
+    #[test]
+    fn should_diff_svd() {
+        let device = Default::default();
+        let tensor_1 =
+            TestAutodiffTensor::<2>::from_floats([[1.0, 2.0], [3.0, 4.0], [5.0, 7.0]], &device)
+                .require_grad();
+        let weights_u =
+            TestAutodiffTensor::from_floats([[1.0, -1.0], [2.0, 0.0], [0.0, 1.0]], &device);
+        let weights_s = TestAutodiffTensor::from_floats([[1.0, 2.0]], &device);
+        let weights_vh = TestAutodiffTensor::from_floats([[1.0, 2.0], [-1.0, 1.0]], &device);
+
+        // The squares don't depend on the signs of the singular vectors
+        let (u, s, vh) = linalg::svd(tensor_1.clone());
+        let tensor_2 = u
+            .powf_scalar(2.0)
+            .mul(weights_u)
+            .sum()
+            .add(s.mul(weights_s).sum())
+            .add(vh.powf_scalar(2.0).mul(weights_vh).sum());
+        let grads = tensor_2.backward();
+
+        let grad_1 = tensor_1.grad(&grads).unwrap();
+
+        let expected =
+            TensorData::from([[-1.0821, 1.11102], [1.7628, -0.4968], [0.02934, 0.90541]]);
+        grad_1.to_data().assert_approx_eq(&expected, 3);
+    }
+
+    #[test]
+    fn should_diff_eigh() {
+        let device = Default::default();
+        let tensor_1 = TestAutodiffTensor::<2>::from_floats(
+            [[2.0, 1.0, 0.0], [1.0, 3.0, 1.0], [0.0, 1.0, 5.0]],
+            &device,
+        )
+        .require_grad();
+        let weights_values = TestAutodiffTensor::from_floats([[1.0, 2.0, 3.0]], &device);
+        let weights_vectors = TestAutodiffTensor::from_floats(
+            [[1.0, 0.0, 2.0], [0.0, 1.0, -1.0], [1.0, 1.0, 0.0]],
+            &device,
+        );
+
+        // The squares don't depend on the signs of the eigenvectors
+        let (values, vectors) = linalg::eigh(tensor_1.clone());
+        let tensor_2 = values
+            .mul(weights_values)
+            .sum()
+            .add(vectors.powf_scalar(2.0).mul(weights_vectors).sum());
+        let grads = tensor_2.backward();
+
+        let grad_1 = tensor_1.grad(&grads).unwrap();
+
+        let expected = TensorData::from([
+            [0.94569, 0.41591, 0.17683],
+            [0.37554, 2.16712, 0.09175],
+            [0.03842, 0.44823, 2.88719],
+        ]);
+        grad_1.to_data().assert_approx_eq(&expected, 3);
+    }
+}
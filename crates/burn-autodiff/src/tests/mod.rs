@@ -21,14 +21,19 @@ mod conv_transpose2d;
 mod conv_transpose3d;
 mod cos;
 mod cross_entropy;
+mod cumulative;
 mod div;
 mod erf;
 mod exp;
 mod expand;
+mod fake_quantize;
+mod fft;
 mod flip;
+mod fmod;
 mod gather_scatter;
 mod gelu;
 mod gradients;
+mod linalg;
 mod log;
 mod log1p;
 mod log_sigmoid;
@@ -49,6 +54,7 @@ mod recip;
 mod relu;
 mod repeat;
 mod reshape;
+mod rounding;
 mod select;
 mod sigmoid;
 mod sign;
@@ -103,6 +109,7 @@ macro_rules! testgen_all {
         burn_autodiff::testgen_ad_cat!();
         burn_autodiff::testgen_ad_cos!();
         burn_autodiff::testgen_ad_cross_entropy_loss!();
+        burn_autodiff::testgen_ad_cumulative!();
         burn_autodiff::testgen_ad_div!();
         burn_autodiff::testgen_ad_erf!();
         burn_autodiff::testgen_ad_exp!();
@@ -111,6 +118,8 @@ macro_rules! testgen_all {
         burn_autodiff::testgen_ad_select!();
         burn_autodiff::testgen_ad_log!();
         burn_autodiff::testgen_ad_log1p!();
+        burn_autodiff::testgen_ad_linalg!();
+        burn_autodiff::testgen_ad_fft!();
         burn_autodiff::testgen_ad_mask!();
         burn_autodiff::testgen_ad_matmul!();
         burn_autodiff::testgen_ad_mul!();
@@ -131,6 +140,9 @@ macro_rules! testgen_all {
         burn_autodiff::testgen_ad_flip!();
         burn_autodiff::testgen_ad_nonzero!();
         burn_autodiff::testgen_ad_sign!();
+        burn_autodiff::testgen_ad_rounding!();
+        burn_autodiff::testgen_ad_fmod!();
+        burn_autodiff::testgen_ad_fake_quantize!();
         burn_autodiff::testgen_ad_expand!();
         burn_autodiff::testgen_ad_sort!();
         burn_autodiff::testgen_ad_repeat!();
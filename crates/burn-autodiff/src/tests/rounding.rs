@@ -0,0 +1,44 @@
+#[burn_tensor_testgen::testgen(ad_rounding)]
+mod tests {
+    use super::*;
+    use burn_tensor::TensorData;
+
+    /// The rounding functions have a zero gradient, so the gradient of `x * f(x)` is `f(x)`.
+    fn assert_zero_grad<F>(func: F, expected: [f32; 4])
+    where
+        F: Fn(TestAutodiffTensor<1>) -> TestAutodiffTensor<1>,
+    {
+        let data = TensorData::from([-1.5, -0.4, 0.5, 2.6]);
+
+        let device = Default::default();
+        let x = TestAutodiffTensor::<1>::from_data(data, &device).require_grad();
+
+        let y = func(x.clone()).mul(x.clone());
+
+        let grads = y.backward();
+        let grad = x.grad(&grads).unwrap();
+
+        grad.to_data()
+            .assert_approx_eq(&TensorData::from(expected), 3);
+    }
+
+    #[test]
+    fn should_diff_floor() {
+        assert_zero_grad(|x| x.floor(), [-2.0, -1.0, 0.0, 2.0]);
+    }
+
+    #[test]
+    fn should_diff_ceil() {
+        assert_zero_grad(|x| x.ceil(), [-1.0, 0.0, 1.0, 3.0]);
+    }
+
+    #[test]
+    fn should_diff_round() {
+        assert_zero_grad(|x| x.round(), [-2.0, 0.0, 0.0, 3.0]);
+    }
+
+    #[test]
+    fn should_diff_trunc() {
+        assert_zero_grad(|x| x.trunc(), [-1.0, 0.0, 0.0, 2.0]);
+    }
+}
@@ -153,3 +153,60 @@ pub fn expand<E: CandleElement, const D1: usize, const D2: usize>(
 pub fn sign<E: CandleElement, const D: usize>(tensor: CandleTensor<E, D>) -> CandleTensor<E, D> {
     CandleTensor::new(tensor.tensor.sign().unwrap())
 }
+
+pub fn cumsum<E: CandleElement, const D: usize>(
+    tensor: CandleTensor<E, D>,
+    dim: usize,
+) -> CandleTensor<E, D> {
+    scan(tensor, dim, |previous, current| previous.add(current))
+}
+
+pub fn cumprod<E: CandleElement, const D: usize>(
+    tensor: CandleTensor<E, D>,
+    dim: usize,
+) -> CandleTensor<E, D> {
+    scan(tensor, dim, |previous, current| previous.mul(current))
+}
+
+pub fn cummax<E: CandleElement, const D: usize>(
+    tensor: CandleTensor<E, D>,
+    dim: usize,
+) -> CandleTensor<E, D> {
+    scan(tensor, dim, |previous, current| previous.maximum(current))
+}
+
+pub fn cummin<E: CandleElement, const D: usize>(
+    tensor: CandleTensor<E, D>,
+    dim: usize,
+) -> CandleTensor<E, D> {
+    scan(tensor, dim, |previous, current| previous.minimum(current))
+}
+
+/// Inclusive scan along a dimension in `log2(n)` steps, each element being combined with the one
+/// `offset` positions before it, the offset doubling at each step.
+///
+/// Candle only provides `cumsum`, computed with a matrix multiplication by a triangular matrix.
+fn scan<E: CandleElement, const D: usize>(
+    tensor: CandleTensor<E, D>,
+    dim: usize,
+    combine: impl Fn(
+        &candle_core::Tensor,
+        &candle_core::Tensor,
+    ) -> candle_core::Result<candle_core::Tensor>,
+) -> CandleTensor<E, D> {
+    let mut tensor = tensor.tensor;
+    let size = tensor.dim(dim).unwrap();
+    let mut offset = 1;
+
+    while offset < size {
+        let head = tensor.narrow(dim, 0, offset).unwrap();
+        let previous = tensor.narrow(dim, 0, size - offset).unwrap();
+        let current = tensor.narrow(dim, offset, size - offset).unwrap();
+        let combined = combine(&previous, &current).unwrap();
+
+        tensor = candle_core::Tensor::cat(&[head, combined], dim).unwrap();
+        offset *= 2;
+    }
+
+    CandleTensor::new(tensor)
+}
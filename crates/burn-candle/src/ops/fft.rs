@@ -0,0 +1,8 @@
+use burn_tensor::ops::FftOps;
+
+use crate::{
+    element::{FloatCandleElement, IntCandleElement},
+    Candle,
+};
+
+impl<F: FloatCandleElement, I: IntCandleElement> FftOps<Self> for Candle<F, I> {}
@@ -289,6 +289,47 @@ impl<F: FloatCandleElement, I: IntCandleElement> IntTensorOps<Self> for Candle<F
         panic!("Not supported by Candle")
     }
 
+    fn int_floor_div<const D: usize>(
+        lhs: IntTensor<Self, D>,
+        rhs: IntTensor<Self, D>,
+    ) -> IntTensor<Self, D> {
+        // The division rounds towards zero, so the quotient is off by one when the remainder is
+        // not zero and its sign differs from the divisor.
+        let quotient = lhs.tensor.broadcast_div(&rhs.tensor).unwrap();
+        let product = quotient.broadcast_mul(&rhs.tensor).unwrap();
+        let remainder = lhs.tensor.broadcast_sub(&product).unwrap();
+        let correction = remainder
+            .broadcast_mul(&rhs.tensor)
+            .unwrap()
+            .lt(0.0)
+            .unwrap()
+            .to_dtype(I::DTYPE)
+            .unwrap();
+
+        CandleTensor::new((quotient - correction).unwrap())
+    }
+
+    fn int_floor_div_scalar<const D: usize>(
+        lhs: IntTensor<Self, D>,
+        rhs: IntElem<Self>,
+    ) -> IntTensor<Self, D> {
+        // Scalar division has the same problem as int_div_scalar, so the divisor is broadcast
+        // to a tensor instead.
+        let rhs = candle_core::Tensor::full(rhs, lhs.tensor.shape(), lhs.tensor.device()).unwrap();
+
+        Self::int_floor_div(lhs, CandleTensor::new(rhs))
+    }
+
+    fn int_remainder<const D: usize>(
+        lhs: IntTensor<Self, D>,
+        rhs: IntTensor<Self, D>,
+    ) -> IntTensor<Self, D> {
+        let quotient = Self::int_floor_div(lhs.clone(), rhs.clone());
+        let product = quotient.tensor.broadcast_mul(&rhs.tensor).unwrap();
+
+        CandleTensor::new(lhs.tensor.broadcast_sub(&product).unwrap())
+    }
+
     fn int_remainder_scalar<const D: usize>(
         lhs: IntTensor<Self, D>,
         rhs: IntElem<Self>,
@@ -334,6 +375,22 @@ impl<F: FloatCandleElement, I: IntCandleElement> IntTensorOps<Self> for Candle<F
         panic!("Not supported by Candle")
     }
 
+    fn int_cumsum<const D: usize>(tensor: IntTensor<Self, D>, dim: usize) -> IntTensor<Self, D> {
+        super::base::cumsum(tensor, dim)
+    }
+
+    fn int_cumprod<const D: usize>(tensor: IntTensor<Self, D>, dim: usize) -> IntTensor<Self, D> {
+        super::base::cumprod(tensor, dim)
+    }
+
+    fn int_cummax<const D: usize>(tensor: IntTensor<Self, D>, dim: usize) -> IntTensor<Self, D> {
+        super::base::cummax(tensor, dim)
+    }
+
+    fn int_cummin<const D: usize>(tensor: IntTensor<Self, D>, dim: usize) -> IntTensor<Self, D> {
+        super::base::cummin(tensor, dim)
+    }
+
     fn int_argmax<const D: usize>(tensor: IntTensor<Self, D>, dim: usize) -> IntTensor<Self, D> {
         CandleTensor::new(
             tensor
@@ -150,6 +150,30 @@ impl<F: FloatCandleElement, I: IntCandleElement> FloatTensorOps<Self> for Candle
         CandleTensor::new((lhs.tensor - product).unwrap())
     }
 
+    fn float_fmod<const D: usize>(
+        lhs: FloatTensor<Self, D>,
+        rhs: FloatTensor<Self, D>,
+    ) -> FloatTensor<Self, D> {
+        let quotient = Self::float_trunc::<D>(CandleTensor::new(
+            lhs.tensor.broadcast_div(&rhs.tensor).unwrap(),
+        ));
+        let product = quotient.tensor.broadcast_mul(&rhs.tensor).unwrap();
+
+        CandleTensor::new(lhs.tensor.broadcast_sub(&product).unwrap())
+    }
+
+    fn float_fmod_scalar<const D: usize>(
+        lhs: FloatTensor<Self, D>,
+        rhs: FloatElem<Self>,
+    ) -> FloatTensor<Self, D> {
+        let rhs_val = rhs.elem::<f64>();
+        let quotient =
+            Self::float_trunc::<D>(CandleTensor::new((lhs.tensor.clone() / rhs_val).unwrap()));
+        let product = quotient.tensor * rhs_val;
+
+        CandleTensor::new((lhs.tensor - product).unwrap())
+    }
+
     fn float_matmul<const D: usize>(
         lhs: FloatTensor<Self, D>,
         rhs: FloatTensor<Self, D>,
@@ -423,6 +447,49 @@ impl<F: FloatCandleElement, I: IntCandleElement> FloatTensorOps<Self> for Candle
         CandleTensor::new(tensor.tensor.erf().unwrap())
     }
 
+    fn float_floor<const D: usize>(tensor: FloatTensor<Self, D>) -> FloatTensor<Self, D> {
+        CandleTensor::new(tensor.tensor.floor().unwrap())
+    }
+
+    fn float_ceil<const D: usize>(tensor: FloatTensor<Self, D>) -> FloatTensor<Self, D> {
+        CandleTensor::new(tensor.tensor.ceil().unwrap())
+    }
+
+    fn float_round<const D: usize>(tensor: FloatTensor<Self, D>) -> FloatTensor<Self, D> {
+        // Candle rounds halfway values away from zero, those are rounded to the nearest even
+        // integer instead.
+        let rounded = tensor.tensor.round().unwrap();
+        let halfway = (&rounded - &tensor.tensor)
+            .unwrap()
+            .abs()
+            .unwrap()
+            .eq(0.5)
+            .unwrap();
+        let even = tensor
+            .tensor
+            .affine(0.5, 0.0)
+            .unwrap()
+            .round()
+            .unwrap()
+            .affine(2.0, 0.0)
+            .unwrap();
+
+        CandleTensor::new(halfway.where_cond(&even, &rounded).unwrap())
+    }
+
+    fn float_trunc<const D: usize>(tensor: FloatTensor<Self, D>) -> FloatTensor<Self, D> {
+        let negative = tensor.tensor.lt(0.0).unwrap();
+
+        CandleTensor::new(
+            negative
+                .where_cond(
+                    &tensor.tensor.ceil().unwrap(),
+                    &tensor.tensor.floor().unwrap(),
+                )
+                .unwrap(),
+        )
+    }
+
     fn float_cat<const D: usize>(
         tensors: Vec<FloatTensor<Self, D>>,
         dim: usize,
@@ -430,6 +497,34 @@ impl<F: FloatCandleElement, I: IntCandleElement> FloatTensorOps<Self> for Candle
         super::base::cat(tensors, dim)
     }
 
+    fn float_cumsum<const D: usize>(
+        tensor: FloatTensor<Self, D>,
+        dim: usize,
+    ) -> FloatTensor<Self, D> {
+        super::base::cumsum(tensor, dim)
+    }
+
+    fn float_cumprod<const D: usize>(
+        tensor: FloatTensor<Self, D>,
+        dim: usize,
+    ) -> FloatTensor<Self, D> {
+        super::base::cumprod(tensor, dim)
+    }
+
+    fn float_cummax<const D: usize>(
+        tensor: FloatTensor<Self, D>,
+        dim: usize,
+    ) -> FloatTensor<Self, D> {
+        super::base::cummax(tensor, dim)
+    }
+
+    fn float_cummin<const D: usize>(
+        tensor: FloatTensor<Self, D>,
+        dim: usize,
+    ) -> FloatTensor<Self, D> {
+        super::base::cummin(tensor, dim)
+    }
+
     fn float_argmax<const D: usize>(
         tensor: FloatTensor<Self, D>,
         dim: usize,
@@ -1,6 +1,9 @@
 use super::ParamId;
 use crate::{
-    quantization::{Calibration, Quantizer},
+    quantization::{
+        ActivationCalibration, Calibration, DynamicQuantization, LayerQuantization,
+        QuantizationScheme, Quantizer,
+    },
     record::Record,
     tensor::backend::{AutodiffBackend, Backend},
 };
@@ -208,6 +211,41 @@ pub trait Module<B: Backend>: Clone + Send + core::fmt::Debug {
     fn quantize_weights<C: Calibration>(self, quantizer: &mut Quantizer<C>) -> Self {
         self.map(quantizer)
     }
+
+    /// Quantize the weights and activations of the module with static quantization.
+    ///
+    /// Observers are inserted at the input and output of the layers supporting quantization,
+    /// then the `calibrate` function runs the calibration pass, i.e. the forward pass on
+    /// representative inputs, to record the activation ranges. Once the weights are quantized,
+    /// the calibrated layers run on quantized tensors and their outputs stay quantized.
+    fn quantize_static<C, F>(
+        self,
+        quantizer: &mut Quantizer<C>,
+        activation: QuantizationScheme,
+        calibrate: F,
+    ) -> Self
+    where
+        C: Calibration,
+        F: FnOnce(&Self),
+    {
+        let module = self.map(&mut ActivationCalibration { scheme: activation });
+        calibrate(&module);
+
+        module.quantize_weights(quantizer)
+    }
+
+    /// Quantize the weights of the module with dynamic quantization of the activations.
+    ///
+    /// The input of the layers supporting quantization is quantized with its range computed for
+    /// each batch at runtime, while their output stays in floating point precision.
+    fn quantize_dynamic<C: Calibration>(
+        self,
+        quantizer: &mut Quantizer<C>,
+        activation: QuantizationScheme,
+    ) -> Self {
+        self.map(&mut DynamicQuantization { scheme: activation })
+            .quantize_weights(quantizer)
+    }
 }
 
 /// Module visitor trait.
@@ -242,6 +280,10 @@ pub trait ModuleMapper<B: Backend> {
     ) -> Tensor<B, D, Bool> {
         tensor
     }
+    /// Map the quantization applied in the forward pass of a layer.
+    fn map_quantization(&mut self, quantization: LayerQuantization) -> LayerQuantization {
+        quantization
+    }
 }
 
 /// Module with auto-differentiation backend.
@@ -208,6 +208,26 @@ impl<T: Parameter> Param<T> {
         }
     }
 
+    /// Whether the parameter is initialized, i.e. its value is computed.
+    pub(crate) fn is_initialized(&self) -> bool {
+        self.state.get().is_some()
+    }
+
+    /// Override the device and the gradient requirement with which an uninitialized parameter
+    /// will be initialized, which avoids initializing it on another device first.
+    ///
+    /// Initialized parameters are returned as is.
+    pub(crate) fn lazy_with(self, device: T::Device, is_require_grad: bool) -> Self {
+        if let Some(init) = &self.initialization {
+            if let Some(value) = init.write().unwrap().as_mut() {
+                value.device = device;
+                value.is_require_grad = is_require_grad;
+            }
+        }
+
+        self
+    }
+
     /// Override the gradient requirement for the current parameter.
     pub fn set_require_grad(self, require_grad: bool) -> Self {
         let initialization = match &self.initialization {
@@ -103,11 +103,16 @@ impl<const D: usize, B: Backend> Module<B> for Param<Tensor<B, D>> {
     }
 
     fn load_record(self, record: Self::Record) -> Self {
-        let (new_id, mut new_value) = record.consume();
-
         let expected_device = self.lazy_device();
         let expected_require_grad = self.lazy_is_require_grad();
 
+        // Uninitialized records (e.g. memory mapped) are initialized directly on the module device.
+        if !record.is_initialized() {
+            return record.lazy_with(expected_device, expected_require_grad);
+        }
+
+        let (new_id, mut new_value) = record.consume();
+
         // Make sure we load the record into the same module device.
         if new_value.device() != expected_device {
             new_value = new_value.to_device(&expected_device).detach();
@@ -183,10 +188,15 @@ impl<const D: usize, B: Backend> Module<B> for Param<Tensor<B, D, Int>> {
     }
 
     fn load_record(self, record: Self::Record) -> Self {
-        let (new_id, mut new_value) = record.consume();
-
         let expected_device = self.lazy_device();
 
+        // Uninitialized records (e.g. memory mapped) are initialized directly on the module device.
+        if !record.is_initialized() {
+            return record.lazy_with(expected_device, false);
+        }
+
+        let (new_id, mut new_value) = record.consume();
+
         // Make sure we load the record into the same module device.
         if new_value.device() != expected_device {
             new_value = new_value.to_device(&expected_device);
@@ -250,10 +260,15 @@ impl<const D: usize, B: Backend> Module<B> for Param<Tensor<B, D, Bool>> {
     }
 
     fn load_record(self, record: Self::Record) -> Self {
-        let (new_id, mut new_value) = record.consume();
-
         let expected_device = self.lazy_device();
 
+        // Uninitialized records (e.g. memory mapped) are initialized directly on the module device.
+        if !record.is_initialized() {
+            return record.lazy_with(expected_device, false);
+        }
+
+        let (new_id, mut new_value) = record.consume();
+
         // Make sure we load the record into the same module device.
         if new_value.device() != expected_device {
             new_value = new_value.to_device(&expected_device);
@@ -6,8 +6,10 @@ use crate::config::Config;
 use crate::module::{Content, DisplaySettings, Ignored, Module, ModuleDisplay, Param};
 use crate::nn::Initializer;
 use crate::nn::PaddingConfig2d;
+use crate::quantization::LayerQuantization;
 use crate::tensor::backend::Backend;
-use crate::tensor::module::conv2d;
+use crate::tensor::module::{conv2d, quantized_conv2d};
+use crate::tensor::ops::conv::calculate_conv_output_size;
 use crate::tensor::ops::ConvOptions;
 use crate::tensor::Tensor;
 
@@ -62,6 +64,9 @@ pub struct Conv2d<B: Backend> {
     pub groups: usize,
     /// The padding configuration.
     pub padding: Ignored<PaddingConfig2d>,
+    /// Quantization applied in the forward pass.
+    #[module(skip)]
+    pub(crate) quantization: LayerQuantization,
 }
 
 impl Conv2dConfig {
@@ -102,6 +107,7 @@ impl Conv2dConfig {
             dilation: self.dilation,
             padding: Ignored(self.padding.clone()),
             groups: self.groups,
+            quantization: LayerQuantization::default(),
         }
     }
 }
@@ -133,6 +139,12 @@ impl<B: Backend> ModuleDisplay for Conv2d<B> {
 }
 
 impl<B: Backend> Conv2d<B> {
+    /// The quantization applied in the forward pass, set by a
+    /// [module mapper](crate::module::ModuleMapper::map_quantization).
+    pub fn quantization(&self) -> &LayerQuantization {
+        &self.quantization
+    }
+
     /// Applies the forward pass on the input tensor.
     ///
     /// See [conv2d](crate::tensor::module::conv2d) for more information.
@@ -142,16 +154,53 @@ impl<B: Backend> Conv2d<B> {
     /// - input: `[batch_size, channels_in, height_in, width_in]`
     /// - output: `[batch_size, channels_out, height_out, width_out]`
     pub fn forward(&self, input: Tensor<B, 4>) -> Tensor<B, 4> {
-        let [_batch_size, _channels_in, height_in, width_in] = input.dims();
+        let [batch_size, _channels_in, height_in, width_in] = input.dims();
         let padding =
             self.padding
                 .calculate_padding_2d(height_in, width_in, &self.kernel_size, &self.stride);
-        conv2d(
-            input,
-            self.weight.val(),
-            self.bias.as_ref().map(|bias| bias.val()),
-            ConvOptions::new(self.stride, padding, self.dilation, self.groups),
-        )
+        let options = ConvOptions::new(self.stride, padding, self.dilation, self.groups);
+        let bias = self.bias.as_ref().map(|bias| bias.val());
+
+        match &self.quantization {
+            LayerQuantization::None => conv2d(input, self.weight.val(), bias, options),
+            LayerQuantization::FakeQuantize(quantization) => conv2d(
+                quantization.input(input),
+                quantization.weight(self.weight.val()),
+                bias,
+                options,
+            ),
+            LayerQuantization::Calibrate(quantization) => {
+                quantization.input.observe(&input);
+                let output = conv2d(input, self.weight.val(), bias, options);
+                quantization.output.observe(&output);
+                output
+            }
+            LayerQuantization::Static(quantization) => {
+                let [channels_out, _, _, _] = self.weight.dims();
+                let [height_out, width_out] = [(0, height_in), (1, width_in)].map(|(i, size)| {
+                    calculate_conv_output_size(
+                        self.kernel_size[i],
+                        self.stride[i],
+                        padding[i],
+                        self.dilation[i],
+                        size,
+                    )
+                });
+                let strategy =
+                    quantization.output(batch_size * channels_out * height_out * width_out);
+
+                quantized_conv2d(
+                    quantization.input(input),
+                    self.weight.val(),
+                    bias,
+                    options,
+                    strategy,
+                )
+            }
+            LayerQuantization::Dynamic(quantization) => {
+                conv2d(quantization.input(input), self.weight.val(), bias, options)
+            }
+        }
     }
 }
 
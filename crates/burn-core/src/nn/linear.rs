@@ -3,7 +3,8 @@ use crate as burn;
 use crate::config::Config;
 use crate::module::Param;
 use crate::module::{Content, DisplaySettings, Module, ModuleDisplay};
-use crate::tensor::{backend::Backend, Tensor};
+use crate::quantization::LayerQuantization;
+use crate::tensor::{backend::Backend, module::quantized_linear, Tensor};
 
 use super::Initializer;
 
@@ -38,6 +39,9 @@ pub struct Linear<B: Backend> {
     /// Vector of size `d_output` initialized from a uniform distribution:
     ///     `U(-k, k)`, where `k = sqrt(1 / d_input)`
     pub bias: Option<Param<Tensor<B, 1>>>,
+    /// Quantization applied in the forward pass.
+    #[module(skip)]
+    pub(crate) quantization: LayerQuantization,
 }
 
 impl LinearConfig {
@@ -58,11 +62,21 @@ impl LinearConfig {
             None
         };
 
-        Linear { weight, bias }
+        Linear {
+            weight,
+            bias,
+            quantization: LayerQuantization::default(),
+        }
     }
 }
 
 impl<B: Backend> Linear<B> {
+    /// The quantization applied in the forward pass, set by a
+    /// [module mapper](crate::module::ModuleMapper::map_quantization).
+    pub fn quantization(&self) -> &LayerQuantization {
+        &self.quantization
+    }
+
     /// Applies the forward pass on the input tensor.
     ///
     /// # Shapes
@@ -75,7 +89,37 @@ impl<B: Backend> Linear<B> {
             return Self::forward::<2>(self, input.unsqueeze()).flatten(0, 1);
         }
 
-        let output = input.matmul(self.weight.val().unsqueeze());
+        match &self.quantization {
+            LayerQuantization::None => self.linear(input, self.weight.val()),
+            LayerQuantization::FakeQuantize(quantization) => self.linear(
+                quantization.input(input),
+                quantization.weight(self.weight.val()),
+            ),
+            LayerQuantization::Calibrate(quantization) => {
+                quantization.input.observe(&input);
+                let output = self.linear(input, self.weight.val());
+                quantization.output.observe(&output);
+                output
+            }
+            LayerQuantization::Static(quantization) => {
+                let [d_input, d_output] = self.weight.dims();
+                let num_elements = input.shape().num_elements() / d_input * d_output;
+
+                quantized_linear(
+                    quantization.input(input),
+                    self.weight.val().unsqueeze(),
+                    self.bias.as_ref().map(|bias| bias.val()),
+                    quantization.output(num_elements),
+                )
+            }
+            LayerQuantization::Dynamic(quantization) => {
+                self.linear(quantization.input(input), self.weight.val())
+            }
+        }
+    }
+
+    fn linear<const D: usize>(&self, input: Tensor<B, D>, weight: Tensor<B, 2>) -> Tensor<B, D> {
+        let output = input.matmul(weight.unsqueeze());
 
         match &self.bias {
             Some(bias) => output + bias.val().unsqueeze(),
@@ -23,6 +23,10 @@ pub struct GruConfig {
     /// Gru initializer
     #[config(default = "Initializer::XavierNormal{gain:1.0}")]
     pub initializer: Initializer,
+    /// If the reset gate should be applied after the linear transformation of the hidden state
+    /// by the new gate, like the `linear_before_reset` of ONNX and the Gru of PyTorch.
+    #[config(default = false)]
+    pub reset_after: bool,
 }
 
 /// The Gru (Gated recurrent unit) module. This implementation is for a unidirectional, stateless, Gru.
@@ -41,6 +45,8 @@ pub struct Gru<B: Backend> {
     pub new_gate: GateController<B>,
     /// The size of the hidden state.
     pub d_hidden: usize,
+    /// If the reset gate is applied after the linear transformation of the hidden state.
+    pub reset_after: bool,
 }
 
 impl<B: Backend> ModuleDisplay for Gru<B> {
@@ -94,6 +100,7 @@ impl GruConfig {
             reset_gate,
             new_gate,
             d_hidden: self.d_hidden,
+            reset_after: self.reset_after,
         }
     }
 }
@@ -104,31 +111,27 @@ impl<B: Backend> Gru<B> {
     ///
     /// # Shapes
     /// - batched_input: `[batch_size, sequence_length, input_size]`.
-    /// - state: An optional tensor representing an initial cell state with the same dimensions
-    ///          as batched_input. If none is provided, one will be generated.
+    /// - state: An optional tensor representing the initial hidden state, with shape
+    ///          `[batch_size, hidden_size]`. If none is provided, it is initialized to zeros.
     /// - output: `[batch_size, sequence_length, hidden_size]`.
     pub fn forward(
         &self,
         batched_input: Tensor<B, 3>,
-        state: Option<Tensor<B, 3>>,
+        state: Option<Tensor<B, 2>>,
     ) -> Tensor<B, 3> {
+        let device = batched_input.device();
         let [batch_size, seq_length, _] = batched_input.shape().dims;
 
-        let mut hidden_state = match state {
+        let mut batched_hidden_state =
+            Tensor::empty([batch_size, seq_length, self.d_hidden], &device);
+
+        let mut hidden_t = match state {
             Some(state) => state,
-            None => Tensor::zeros(
-                [batch_size, seq_length, self.d_hidden],
-                &batched_input.device(),
-            ),
+            None => Tensor::zeros([batch_size, self.d_hidden], &device),
         };
 
-        for (t, (input_t, hidden_t)) in batched_input
-            .iter_dim(1)
-            .zip(hidden_state.clone().iter_dim(1))
-            .enumerate()
-        {
+        for (t, input_t) in batched_input.iter_dim(1).enumerate() {
             let input_t = input_t.squeeze(1);
-            let hidden_t = hidden_t.squeeze(1);
             // u(pdate)g(ate) tensors
             let biased_ug_input_sum = self.gate_product(&input_t, &hidden_t, &self.update_gate);
             let update_values = activation::sigmoid(biased_ug_input_sum); // Colloquially referred to as z(t)
@@ -136,29 +139,37 @@ impl<B: Backend> Gru<B> {
             // r(eset)g(ate) tensors
             let biased_rg_input_sum = self.gate_product(&input_t, &hidden_t, &self.reset_gate);
             let reset_values = activation::sigmoid(biased_rg_input_sum); // Colloquially referred to as r(t)
-            let reset_t = hidden_t.clone().mul(reset_values); // Passed as input to new_gate
 
             // n(ew)g(ate) tensor
-            let biased_ng_input_sum = self.gate_product(&input_t, &reset_t, &self.new_gate);
+            let biased_ng_input_sum = match self.reset_after {
+                // Wx*X + bx + r(t) * (Wh*H + bh)
+                true => {
+                    let input_product = self.new_gate.input_transform.forward(input_t);
+                    let hidden_product = self.new_gate.hidden_transform.forward(hidden_t.clone());
+                    input_product + hidden_product.mul(reset_values)
+                }
+                false => {
+                    let reset_t = hidden_t.clone().mul(reset_values); // Passed as input to new_gate
+                    self.gate_product(&input_t, &reset_t, &self.new_gate)
+                }
+            };
             let candidate_state = biased_ng_input_sum.tanh(); // Colloquially referred to as g(t)
 
             // calculate linear interpolation between previous hidden state and candidate state:
             // g(t) * (1 - z(t)) + z(t) * hidden_t
-            let state_vector = candidate_state
+            hidden_t = candidate_state
                 .clone()
                 .mul(update_values.clone().sub_scalar(1).mul_scalar(-1)) // (1 - z(t)) = -(z(t) - 1)
                 + update_values.clone().mul(hidden_t);
 
-            let current_shape = state_vector.shape().dims;
-            let unsqueezed_shape = [current_shape[0], 1, current_shape[1]];
-            let reshaped_state_vector = state_vector.reshape(unsqueezed_shape);
-            hidden_state = hidden_state.slice_assign(
+            // store the hidden state for this timestep
+            batched_hidden_state = batched_hidden_state.slice_assign(
                 [0..batch_size, t..(t + 1), 0..self.d_hidden],
-                reshaped_state_vector,
+                hidden_t.clone().unsqueeze_dim(1),
             );
         }
 
-        hidden_state
+        batched_hidden_state
     }
 
     /// Helper function for performing weighted matrix product for a gate and adds
@@ -207,46 +218,38 @@ mod tests {
     use crate::tensor::{Distribution, TensorData};
     use crate::{module::Param, nn::LinearRecord, TestBackend};
 
-    /// Test forward pass with simple input vector.
-    ///
-    /// z_t = sigmoid(0.5*0.1 + 0.5*0) = 0.5125
-    /// r_t = sigmoid(0.6*0.1 + 0.*0) = 0.5150
-    /// g_t = tanh(0.7*0.1 + 0.7*0) = 0.0699
-    ///
-    /// h_t = z_t * h' + (1 - z_t) * g_t = 0.0341
-    #[test]
-    fn tests_forward_single_input_single_feature() {
-        TestBackend::seed(0);
+    fn create_gate_controller(
+        weights: f32,
+        biases: f32,
+        d_input: usize,
+        d_output: usize,
+        bias: bool,
+        initializer: Initializer,
+        device: &<TestBackend as Backend>::Device,
+    ) -> GateController<TestBackend> {
+        let record_1 = LinearRecord {
+            weight: Param::from_data(TensorData::from([[weights]]), device),
+            bias: Some(Param::from_data(TensorData::from([biases]), device)),
+        };
+        let record_2 = LinearRecord {
+            weight: Param::from_data(TensorData::from([[weights]]), device),
+            bias: Some(Param::from_data(TensorData::from([biases]), device)),
+        };
+        gate_controller::GateController::create_with_weights(
+            d_input,
+            d_output,
+            bias,
+            initializer,
+            record_1,
+            record_2,
+        )
+    }
+
+    /// Create a Gru with a single feature and the same weight for the input and hidden transforms
+    /// of each gate: 0.5 for the update gate, 0.6 for the reset gate and 0.7 for the new gate.
+    fn create_gru(device: &<TestBackend as Backend>::Device) -> Gru<TestBackend> {
         let config = GruConfig::new(1, 1, false);
-        let device = Default::default();
-        let mut gru = config.init::<TestBackend>(&device);
-
-        fn create_gate_controller(
-            weights: f32,
-            biases: f32,
-            d_input: usize,
-            d_output: usize,
-            bias: bool,
-            initializer: Initializer,
-            device: &<TestBackend as Backend>::Device,
-        ) -> GateController<TestBackend> {
-            let record_1 = LinearRecord {
-                weight: Param::from_data(TensorData::from([[weights]]), device),
-                bias: Some(Param::from_data(TensorData::from([biases]), device)),
-            };
-            let record_2 = LinearRecord {
-                weight: Param::from_data(TensorData::from([[weights]]), device),
-                bias: Some(Param::from_data(TensorData::from([biases]), device)),
-            };
-            gate_controller::GateController::create_with_weights(
-                d_input,
-                d_output,
-                bias,
-                initializer,
-                record_1,
-                record_2,
-            )
-        }
+        let mut gru = config.init::<TestBackend>(device);
 
         gru.update_gate = create_gate_controller(
             0.5,
@@ -255,7 +258,7 @@ mod tests {
             1,
             false,
             Initializer::XavierNormal { gain: 1.0 },
-            &device,
+            device,
         );
         gru.reset_gate = create_gate_controller(
             0.6,
@@ -264,7 +267,7 @@ mod tests {
             1,
             false,
             Initializer::XavierNormal { gain: 1.0 },
-            &device,
+            device,
         );
         gru.new_gate = create_gate_controller(
             0.7,
@@ -273,9 +276,25 @@ mod tests {
             1,
             false,
             Initializer::XavierNormal { gain: 1.0 },
-            &device,
+            device,
         );
 
+        gru
+    }
+
+    /// Test forward pass with simple input vector.
+    ///
+    /// z_t = sigmoid(0.5*0.1 + 0.5*0) = 0.5125
+    /// r_t = sigmoid(0.6*0.1 + 0.*0) = 0.5150
+    /// g_t = tanh(0.7*0.1 + 0.7*0) = 0.0699
+    ///
+    /// h_t = z_t * h' + (1 - z_t) * g_t = 0.0341
+    #[test]
+    fn tests_forward_single_input_single_feature() {
+        TestBackend::seed(0);
+        let device = Default::default();
+        let gru = create_gru(&device);
+
         let input = Tensor::<TestBackend, 3>::from_data(TensorData::from([[[0.1]]]), &device);
 
         let state = gru.forward(input, None);
@@ -288,6 +307,58 @@ mod tests {
         output.to_data().assert_approx_eq(&expected, 3);
     }
 
+    /// Test that the hidden state of each timestep is used by the next one, starting from the
+    /// initial state.
+    #[test]
+    fn tests_forward_sequence_with_initial_state() {
+        TestBackend::seed(0);
+        let device = Default::default();
+        let gru = create_gru(&device);
+
+        let input =
+            Tensor::<TestBackend, 3>::from_data(TensorData::from([[[0.1], [0.2]]]), &device);
+
+        let output = gru.forward(input.clone(), None);
+        let expected = TensorData::from([[[0.0341], [0.0894]]]);
+        output.to_data().assert_approx_eq(&expected, 3);
+
+        let state = Tensor::<TestBackend, 2>::from_data(TensorData::from([[0.1]]), &device);
+        let output = gru.forward(input, Some(state));
+        let expected = TensorData::from([[[0.1032], [0.1375]]]);
+        output.to_data().assert_approx_eq(&expected, 3);
+    }
+
+    /// Test that the reset gate is applied to the hidden product of the new gate, biases included.
+    ///
+    /// z_t = sigmoid(0.5*0.1 + 0.5*0.1) = 0.5250
+    /// r_t = sigmoid(0.6*0.1 + 0.6*0.1) = 0.5300
+    /// g_t = tanh(0.7*0.1 + 0.1 + r_t * (0.7*0.1 + 0.1)) = 0.2544
+    ///
+    /// h_t = z_t * h' + (1 - z_t) * g_t = 0.1733
+    #[test]
+    fn tests_forward_reset_after() {
+        TestBackend::seed(0);
+        let device = Default::default();
+        let mut gru = create_gru(&device);
+        gru.reset_after = true;
+        gru.new_gate = create_gate_controller(
+            0.7,
+            0.1,
+            1,
+            1,
+            true,
+            Initializer::XavierNormal { gain: 1.0 },
+            &device,
+        );
+
+        let input = Tensor::<TestBackend, 3>::from_data(TensorData::from([[[0.1]]]), &device);
+        let state = Tensor::<TestBackend, 2>::from_data(TensorData::from([[0.1]]), &device);
+        let output = gru.forward(input, Some(state));
+
+        let expected = TensorData::from([[[0.1733]]]);
+        output.to_data().assert_approx_eq(&expected, 3);
+    }
+
     #[test]
     fn test_batched_forward_pass() {
         let device = Default::default();
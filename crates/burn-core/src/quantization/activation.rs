@@ -0,0 +1,280 @@
+use burn_tensor::{backend::Backend, QuantizationStrategy, Tensor};
+
+use crate::module::ModuleMapper;
+
+use super::{Calibration, LayerQuantization, MinMaxCalibration, Observer, QuantizationScheme};
+
+/// Static quantization of the input and output of a layer.
+///
+/// The ranges of the activations are recorded by [observers](Observer) during a calibration pass,
+/// after which the layer runs on quantized tensors: the input is quantized (unless it already is)
+/// and the output is quantized with the observed output range.
+#[derive(Clone, Debug)]
+pub struct StaticQuantization {
+    /// Observer of the layer input.
+    pub input: Observer,
+    /// Observer of the layer output.
+    pub output: Observer,
+}
+
+impl StaticQuantization {
+    /// Create the observers of the input and output of a layer for the given scheme.
+    pub fn new(scheme: QuantizationScheme) -> Self {
+        Self {
+            input: Observer::new(scheme.clone()),
+            output: Observer::new(scheme),
+        }
+    }
+
+    /// Returns true if the ranges of the input and output have been observed.
+    pub fn is_calibrated(&self) -> bool {
+        self.input.has_observed() && self.output.has_observed()
+    }
+
+    /// Quantize the input of the layer with the observed range, unless it is already quantized.
+    ///
+    /// # Panics
+    ///
+    /// If the layer has not been calibrated.
+    pub fn input<B: Backend, const D: usize>(&self, input: Tensor<B, D>) -> Tensor<B, D> {
+        if input.is_quantized() {
+            return input;
+        }
+
+        let strategy = Self::strategy(&self.input, input.shape().num_elements());
+        input.quantize(strategy)
+    }
+
+    /// The quantization strategy of the output for the given number of elements.
+    ///
+    /// # Panics
+    ///
+    /// If the layer has not been calibrated.
+    pub fn output(&self, num_elements: usize) -> QuantizationStrategy {
+        Self::strategy(&self.output, num_elements)
+    }
+
+    fn strategy(observer: &Observer, num_elements: usize) -> QuantizationStrategy {
+        observer
+            .strategy(num_elements)
+            .expect("The layer should be calibrated before being statically quantized")
+    }
+}
+
+/// Dynamic quantization of the input of a layer.
+///
+/// The quantization range of the input is computed for each batch at runtime, so no calibration
+/// is needed. The output of the layer is in floating point precision.
+#[derive(Clone, Debug)]
+pub struct DynamicQuantization {
+    /// Quantization scheme of the input.
+    pub scheme: QuantizationScheme,
+}
+
+impl DynamicQuantization {
+    /// Quantize the input of the layer with its current range.
+    pub fn input<B: Backend, const D: usize>(&self, input: Tensor<B, D>) -> Tensor<B, D> {
+        let calibration = MinMaxCalibration {
+            scheme: self.scheme.clone(),
+        };
+        let strategy = calibration.configure(&input);
+
+        input.quantize(strategy)
+    }
+}
+
+impl<B: Backend> ModuleMapper<B> for DynamicQuantization {
+    fn map_quantization(&mut self, _quantization: LayerQuantization) -> LayerQuantization {
+        LayerQuantization::Dynamic(self.clone())
+    }
+}
+
+/// Inserts [observers](Observer) at the input and output of the layers supporting quantization.
+///
+/// The observed ranges are recorded during the calibration pass, i.e. when running the forward
+/// pass on representative inputs. The [quantizer](super::Quantizer) then converts the calibrated
+/// layers to [static quantization](StaticQuantization).
+pub struct ActivationCalibration {
+    /// Quantization scheme of the activations.
+    pub scheme: QuantizationScheme,
+}
+
+impl<B: Backend> ModuleMapper<B> for ActivationCalibration {
+    fn map_quantization(&mut self, _quantization: LayerQuantization) -> LayerQuantization {
+        LayerQuantization::Calibrate(StaticQuantization::new(self.scheme.clone()))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate as burn;
+    use crate::{
+        module::Module,
+        nn::{
+            conv::{Conv2d, Conv2dConfig},
+            Linear, LinearConfig, Relu,
+        },
+        quantization::{QuantizationType, Quantizer},
+        TestBackend,
+    };
+    use burn_tensor::{DType, Distribution};
+
+    #[derive(Module, Debug)]
+    struct Mlp<B: Backend> {
+        fc1: Linear<B>,
+        activation: Relu,
+        fc2: Linear<B>,
+    }
+
+    impl<B: Backend> Mlp<B> {
+        fn new(device: &B::Device) -> Self {
+            Self {
+                fc1: LinearConfig::new(8, 16).init(device),
+                activation: Relu::new(),
+                fc2: LinearConfig::new(16, 4).init(device),
+            }
+        }
+
+        fn forward(&self, input: Tensor<B, 2>) -> Tensor<B, 2> {
+            let x = self.fc1.forward(input);
+            let x = self.activation.forward(x);
+            self.fc2.forward(x)
+        }
+    }
+
+    fn quantizer(weight: QuantizationScheme) -> Quantizer<MinMaxCalibration> {
+        Quantizer {
+            calibration: MinMaxCalibration { scheme: weight },
+        }
+    }
+
+    fn activation() -> QuantizationScheme {
+        QuantizationScheme::PerTensorAffine(QuantizationType::QInt8)
+    }
+
+    fn random<const D: usize>(shape: [usize; D]) -> Tensor<TestBackend, D> {
+        Tensor::random(shape, Distribution::Default, &Default::default())
+    }
+
+    #[test]
+    fn static_quantization_should_run_end_to_end_on_quantized_tensors() {
+        let model = Mlp::<TestBackend>::new(&Default::default());
+        let batches = [random([6, 8]), random([6, 8])];
+        let expected = model.forward(batches[0].clone());
+
+        let mut quantizer = quantizer(QuantizationScheme::PerTensorSymmetric(
+            QuantizationType::QInt8,
+        ));
+        let model = model.quantize_static(&mut quantizer, activation(), |model| {
+            batches.iter().for_each(|batch| {
+                model.forward(batch.clone());
+            })
+        });
+
+        assert!(matches!(
+            model.fc1.quantization,
+            LayerQuantization::Static(_)
+        ));
+        assert!(matches!(
+            model.fc2.quantization,
+            LayerQuantization::Static(_)
+        ));
+        let hidden = model
+            .activation
+            .forward(model.fc1.forward(batches[0].clone()));
+        assert!(hidden.is_quantized());
+
+        let output = model.forward(batches[0].clone());
+        assert!(matches!(output.to_data().dtype, DType::QFloat(_)));
+        output
+            .dequantize()
+            .into_data()
+            .assert_approx_eq_diff(&expected.into_data(), 0.1);
+    }
+
+    #[test]
+    fn static_quantization_should_support_per_channel_weights() {
+        let linear = LinearConfig::new(8, 4).init::<TestBackend>(&Default::default());
+        let input = random([3, 2, 8]);
+        let expected = linear.forward(input.clone());
+
+        // The scheme also applies to the bias, so the channels are on the first axis
+        let mut quantizer = quantizer(QuantizationScheme::PerChannelSymmetric(
+            QuantizationType::QInt8,
+            0,
+        ));
+        let linear = linear.quantize_static(&mut quantizer, activation(), |linear| {
+            linear.forward(input.clone());
+        });
+
+        let output = linear.forward(input);
+        assert!(output.is_quantized());
+        output
+            .dequantize()
+            .into_data()
+            .assert_approx_eq_diff(&expected.into_data(), 0.1);
+    }
+
+    #[test]
+    fn static_quantization_should_quantize_conv2d() {
+        let conv: Conv2d<TestBackend> = Conv2dConfig::new([2, 4], [3, 3]).init(&Default::default());
+        let input = random([2, 2, 5, 5]);
+        let expected = conv.forward(input.clone());
+
+        let mut quantizer = quantizer(QuantizationScheme::PerChannelAffine(
+            QuantizationType::QInt8,
+            0,
+        ));
+        let conv = conv.quantize_static(&mut quantizer, activation(), |conv| {
+            conv.forward(input.clone());
+        });
+
+        let output = conv.forward(input);
+        assert!(output.is_quantized());
+        output
+            .dequantize()
+            .into_data()
+            .assert_approx_eq_diff(&expected.into_data(), 0.1);
+    }
+
+    #[test]
+    fn static_quantization_should_keep_uncalibrated_layers_in_float() {
+        let model = Mlp::<TestBackend>::new(&Default::default());
+
+        let mut quantizer = quantizer(QuantizationScheme::PerTensorSymmetric(
+            QuantizationType::QInt8,
+        ));
+        let model = model.quantize_static(&mut quantizer, activation(), |model| {
+            model.fc1.forward(random([6, 8]));
+        });
+
+        assert!(matches!(
+            model.fc1.quantization,
+            LayerQuantization::Static(_)
+        ));
+        assert!(matches!(model.fc2.quantization, LayerQuantization::None));
+    }
+
+    #[test]
+    fn dynamic_quantization_should_output_float_tensors() {
+        let model = Mlp::<TestBackend>::new(&Default::default());
+        let input = random([6, 8]);
+        let expected = model.forward(input.clone());
+
+        let mut quantizer = quantizer(QuantizationScheme::PerTensorSymmetric(
+            QuantizationType::QInt8,
+        ));
+        let model = model.quantize_dynamic(&mut quantizer, activation());
+
+        assert!(matches!(
+            model.fc1.quantization,
+            LayerQuantization::Dynamic(_)
+        ));
+        let output = model.forward(input);
+        assert!(!output.is_quantized());
+        output
+            .into_data()
+            .assert_approx_eq_diff(&expected.into_data(), 0.1);
+    }
+}
@@ -1,14 +1,35 @@
-use burn_tensor::{
-    backend::Backend, AffineQuantization, ElementConversion, Quantization, QuantizationStrategy,
-    SymmetricQuantization, Tensor,
-};
+use alloc::{vec, vec::Vec};
+use burn_tensor::{backend::Backend, ElementConversion, QuantizationStrategy, Tensor};
 
-use super::{QuantizationScheme, QuantizationType};
+use super::QuantizationScheme;
+use crate::module::ParamId;
 
 /// Calibration method used to compute the quantization range mapping.
 pub trait Calibration {
     /// Configure the quantization strategy.
+    ///
+    /// # Panics
+    ///
+    /// When the quantization scheme is not supported for its data type (see
+    /// [QuantizationScheme::validate]).
     fn configure<B: Backend, const D: usize>(&self, tensor: &Tensor<B, D>) -> QuantizationStrategy;
+
+    /// Configure the quantization strategy of a module parameter.
+    ///
+    /// Calibration methods accumulating statistics across calls keep them separately for each
+    /// parameter. By default, the parameter is configured as any other tensor.
+    ///
+    /// # Panics
+    ///
+    /// When the quantization scheme is not supported for its data type (see
+    /// [QuantizationScheme::validate]).
+    fn configure_param<B: Backend, const D: usize>(
+        &self,
+        _id: &ParamId,
+        tensor: &Tensor<B, D>,
+    ) -> QuantizationStrategy {
+        self.configure(tensor)
+    }
 }
 
 /// Computes the quantization range mapping based on the running min and max values.
@@ -19,29 +40,96 @@ pub struct MinMaxCalibration {
 
 impl Calibration for MinMaxCalibration {
     fn configure<B: Backend, const D: usize>(&self, tensor: &Tensor<B, D>) -> QuantizationStrategy {
-        let min = tensor.clone().min().into_scalar().elem::<f32>();
-        let max = tensor.clone().max().into_scalar().elem::<f32>();
-
-        match &self.scheme {
-            QuantizationScheme::PerTensorAffine(dtype) => match dtype {
-                QuantizationType::QInt8 => {
-                    QuantizationStrategy::PerTensorAffineInt8(AffineQuantization::new(min, max))
-                }
-            },
-            QuantizationScheme::PerTensorSymmetric(dtype) => match dtype {
-                QuantizationType::QInt8 => QuantizationStrategy::PerTensorSymmetricInt8(
-                    SymmetricQuantization::new(min, max),
-                ),
-            },
+        let (min, max) = min_max_ranges(&self.scheme, tensor);
+        self.scheme
+            .strategy(&min, &max, tensor.shape().num_elements())
+            .unwrap_or_else(|err| panic!("{err}"))
+    }
+}
+
+/// Computes the min and max values of each range quantized with the same parameters.
+pub(crate) fn min_max_ranges<B: Backend, const D: usize>(
+    scheme: &QuantizationScheme,
+    tensor: &Tensor<B, D>,
+) -> (Vec<f32>, Vec<f32>) {
+    match scheme {
+        QuantizationScheme::PerTensorAffine(_) | QuantizationScheme::PerTensorSymmetric(_) => {
+            let (min, max) = min_max(tensor);
+            (vec![min], vec![max])
+        }
+        QuantizationScheme::PerChannelAffine(_, axis)
+        | QuantizationScheme::PerChannelSymmetric(_, axis) => min_max_per_channel(tensor, *axis),
+        QuantizationScheme::PerGroupAffine(_, group_size)
+        | QuantizationScheme::PerGroupSymmetric(_, group_size) => {
+            min_max_per_group(tensor, *group_size)
         }
     }
 }
 
+/// Computes the min and max values of the tensor.
+fn min_max<B: Backend, const D: usize>(tensor: &Tensor<B, D>) -> (f32, f32) {
+    let min = tensor.clone().min().into_scalar().elem::<f32>();
+    let max = tensor.clone().max().into_scalar().elem::<f32>();
+    (min, max)
+}
+
+/// Computes the min and max values of each channel along the given axis.
+fn min_max_per_channel<B: Backend, const D: usize>(
+    tensor: &Tensor<B, D>,
+    axis: usize,
+) -> (Vec<f32>, Vec<f32>) {
+    let channels = tensor.dims()[axis];
+    let channel_size = tensor.shape().num_elements() / channels;
+    let tensor = tensor
+        .clone()
+        .swap_dims(0, axis)
+        .reshape([channels, channel_size]);
+
+    let min = tensor
+        .clone()
+        .min_dim(1)
+        .into_data()
+        .iter::<f32>()
+        .collect();
+    let max = tensor.max_dim(1).into_data().iter::<f32>().collect();
+    (min, max)
+}
+
+/// Computes the min and max values of each contiguous group of values.
+///
+/// # Panics
+///
+/// If the group size does not divide the number of elements of the tensor.
+fn min_max_per_group<B: Backend, const D: usize>(
+    tensor: &Tensor<B, D>,
+    group_size: usize,
+) -> (Vec<f32>, Vec<f32>) {
+    let num_elements = tensor.shape().num_elements();
+    assert!(
+        group_size > 0 && num_elements % group_size == 0,
+        "Quantization group size {group_size} must divide the number of elements ({num_elements})"
+    );
+    let tensor = tensor
+        .clone()
+        .reshape([num_elements / group_size, group_size]);
+
+    let min = tensor
+        .clone()
+        .min_dim(1)
+        .into_data()
+        .iter::<f32>()
+        .collect();
+    let max = tensor.max_dim(1).into_data().iter::<f32>().collect();
+    (min, max)
+}
+
 #[cfg(test)]
 mod tests {
 
     use super::*;
+    use crate::quantization::QuantizationType;
     use crate::TestBackend;
+    use burn_tensor::{Quantization, SymmetricQuantization};
 
     #[test]
     fn min_max_calibration_per_tensor_affine_int8() {
@@ -77,4 +165,168 @@ mod tests {
             panic!("Wrong quantization strategy");
         }
     }
+
+    #[test]
+    fn min_max_calibration_per_channel_affine_int8() {
+        let device = <TestBackend as Backend>::Device::default();
+        let tensor = Tensor::<TestBackend, 2>::from_floats(
+            [[-1.8, -1.0, 0.0, 0.5], [-0.5, 0.0, 1.0, 2.5]],
+            &device,
+        );
+        let calibration = MinMaxCalibration {
+            scheme: QuantizationScheme::PerChannelAffine(QuantizationType::QInt8, 0),
+        };
+
+        let strategy = calibration.configure(&tensor);
+
+        if let QuantizationStrategy::PerChannelAffineInt8(q) = strategy {
+            assert_eq!(q.axis, 0);
+            assert_eq!(q.channels.len(), 2);
+            assert_eq!(q.channels[0].scale, 0.009_019_608);
+            assert_eq!(q.channels[0].offset, 72);
+            assert_eq!(q.channels[1].scale, 0.011_764_706);
+            assert_eq!(q.channels[1].offset, -86);
+        } else {
+            panic!("Wrong quantization strategy");
+        }
+    }
+
+    #[test]
+    fn min_max_calibration_per_channel_symmetric_int8() {
+        let device = <TestBackend as Backend>::Device::default();
+        let tensor = Tensor::<TestBackend, 2>::from_floats(
+            [[-1.8, -1.0, 0.0, 0.5], [-0.5, 0.0, 1.0, 2.5]],
+            &device,
+        );
+        let calibration = MinMaxCalibration {
+            scheme: QuantizationScheme::PerChannelSymmetric(QuantizationType::QInt8, 1),
+        };
+
+        let strategy = calibration.configure(&tensor);
+
+        if let QuantizationStrategy::PerChannelSymmetricInt8(q) = strategy {
+            assert_eq!(q.axis, 1);
+            let expected = [
+                SymmetricQuantization::new(-1.8, -0.5),
+                SymmetricQuantization::new(-1.0, 0.0),
+                SymmetricQuantization::new(0.0, 1.0),
+                SymmetricQuantization::new(0.5, 2.5),
+            ];
+            assert_eq!(q.channels, expected);
+        } else {
+            panic!("Wrong quantization strategy");
+        }
+    }
+
+    #[test]
+    fn min_max_calibration_per_tensor_affine_uint8() {
+        let device = <TestBackend as Backend>::Device::default();
+        let tensor = Tensor::<TestBackend, 1>::from_floats([-1.8, -1.0, 0.0, 0.5], &device);
+        let calibration = MinMaxCalibration {
+            scheme: QuantizationScheme::PerTensorAffine(QuantizationType::QUInt8),
+        };
+
+        let strategy = calibration.configure(&tensor);
+
+        if let QuantizationStrategy::PerTensorAffineUInt8(q) = strategy {
+            assert_eq!(q.scale, 0.009_019_608);
+            assert_eq!(q.offset, 200);
+        } else {
+            panic!("Wrong quantization strategy");
+        }
+    }
+
+    #[test]
+    fn min_max_calibration_per_group_symmetric_int4() {
+        let device = <TestBackend as Backend>::Device::default();
+        let tensor = Tensor::<TestBackend, 2>::from_floats(
+            [[-1.8, -1.0, 0.0, 0.5], [-0.5, 0.0, 1.0, 2.5]],
+            &device,
+        );
+        let calibration = MinMaxCalibration {
+            scheme: QuantizationScheme::PerGroupSymmetric(QuantizationType::QInt4, 2),
+        };
+
+        let strategy = calibration.configure(&tensor);
+
+        if let QuantizationStrategy::PerGroupSymmetricInt4(q) = strategy {
+            assert_eq!(q.group_size, 2);
+            let expected = [
+                SymmetricQuantization::new(-1.8, -1.0),
+                SymmetricQuantization::new(0.0, 0.5),
+                SymmetricQuantization::new(-0.5, 0.0),
+                SymmetricQuantization::new(1.0, 2.5),
+            ];
+            assert_eq!(q.groups, expected);
+        } else {
+            panic!("Wrong quantization strategy");
+        }
+    }
+
+    #[test]
+    fn min_max_calibration_per_group_affine_uint4() {
+        let device = <TestBackend as Backend>::Device::default();
+        let tensor = Tensor::<TestBackend, 2>::from_floats(
+            [[-1.8, -1.0, 0.0, 0.5], [-0.5, 0.0, 1.0, 2.5]],
+            &device,
+        );
+        let calibration = MinMaxCalibration {
+            scheme: QuantizationScheme::PerGroupAffine(QuantizationType::QUInt4, 4),
+        };
+
+        let strategy = calibration.configure(&tensor);
+
+        if let QuantizationStrategy::PerGroupAffineUInt4(q) = &strategy {
+            assert_eq!(q.group_size, 4);
+            assert_eq!(q.groups[0].scale, 0.153_333_33);
+            assert_eq!(q.groups[0].offset, 12);
+            assert_eq!(q.groups[1].scale, 0.2);
+            assert_eq!(q.groups[1].offset, 3);
+        } else {
+            panic!("Wrong quantization strategy");
+        }
+
+        // Two 4-bit values are packed in each byte, followed by the quantization parameters
+        let params = strategy.params_to_bytes().len();
+        let data = tensor.quantize(strategy).into_data();
+        assert_eq!(data.as_bytes().len(), 4 + params);
+    }
+
+    #[test]
+    #[should_panic = "Symmetric quantization is only valid for signed integers."]
+    fn min_max_calibration_symmetric_unsigned_should_panic() {
+        let device = <TestBackend as Backend>::Device::default();
+        let tensor = Tensor::<TestBackend, 1>::from_floats([-1.8, -1.0, 0.0, 0.5], &device);
+        let calibration = MinMaxCalibration {
+            scheme: QuantizationScheme::PerTensorSymmetric(QuantizationType::QUInt8),
+        };
+
+        calibration.configure(&tensor);
+    }
+
+    #[test]
+    fn per_channel_quantization_should_be_more_precise_than_per_tensor() {
+        let device = <TestBackend as Backend>::Device::default();
+        let tensor = Tensor::<TestBackend, 2>::from_floats(
+            [[-0.01, 0.002, 0.005], [-10.0, 2.0, 5.0]],
+            &device,
+        );
+        let per_tensor = MinMaxCalibration {
+            scheme: QuantizationScheme::PerTensorSymmetric(QuantizationType::QInt8),
+        };
+        let per_channel = MinMaxCalibration {
+            scheme: QuantizationScheme::PerChannelSymmetric(QuantizationType::QInt8, 0),
+        };
+
+        let error = |calibration: MinMaxCalibration| {
+            let strategy = calibration.configure(&tensor);
+            (tensor.clone().quantize(strategy).dequantize() - tensor.clone())
+                .abs()
+                .slice([0..1, 0..3])
+                .sum()
+                .into_scalar()
+        };
+
+        assert!(error(per_channel) < error(per_tensor));
+    }
 }
@@ -0,0 +1,800 @@
+use alloc::{sync::Arc, vec, vec::Vec};
+use burn_common::stub::Mutex;
+use burn_tensor::{backend::Backend, QuantizationStrategy, Tensor};
+use hashbrown::HashMap;
+use num_traits::Float;
+
+use super::{Calibration, QuantizationScheme, QuantizationType};
+use crate::module::ParamId;
+
+/// Default number of histogram bins.
+const DEFAULT_BINS: usize = 2048;
+
+/// Computes the quantization range mapping by clipping the values outside of the given percentile
+/// of the observed values.
+///
+/// The values are accumulated in a histogram across calls to [configure](Calibration::configure),
+/// so a calibration dataset can be streamed batch by batch. The values of each module parameter
+/// are accumulated in their own histograms.
+///
+/// Per-group schemes aren't supported, since each group would need its own histogram.
+#[derive(Clone, Debug)]
+pub struct PercentileCalibration {
+    /// Quantization scheme to be used.
+    pub scheme: QuantizationScheme,
+    /// Percentage of the values to keep in the quantization range, in `(0, 100]`.
+    pub percentile: f32,
+    histograms: Histograms,
+}
+
+/// Computes the quantization range mapping which minimizes the Kullback-Leibler divergence
+/// between the distribution of the observed values and their quantized distribution.
+///
+/// This follows the entropy calibration of TensorRT, where the threshold is searched over the
+/// histogram of the absolute values. The values are accumulated in a histogram across calls to
+/// [configure](Calibration::configure), so a calibration dataset can be streamed batch by batch.
+/// The values of each module parameter are accumulated in their own histograms.
+///
+/// Per-group schemes aren't supported, since each group would need its own histogram.
+#[derive(Clone, Debug)]
+pub struct EntropyCalibration {
+    /// Quantization scheme to be used.
+    pub scheme: QuantizationScheme,
+    histograms: Histograms,
+}
+
+/// Computes the quantization range mapping which minimizes the mean squared error between the
+/// observed values and their quantized values.
+///
+/// The error is estimated from the histogram of the values, accumulated across calls to
+/// [configure](Calibration::configure), so a calibration dataset can be streamed batch by batch.
+/// The values of each module parameter are accumulated in their own histograms.
+///
+/// Per-group schemes aren't supported, since each group would need its own histogram.
+#[derive(Clone, Debug)]
+pub struct MseCalibration {
+    /// Quantization scheme to be used.
+    pub scheme: QuantizationScheme,
+    histograms: Histograms,
+}
+
+impl PercentileCalibration {
+    /// Create a new percentile calibration keeping the given percentage of the values.
+    pub fn new(scheme: QuantizationScheme, percentile: f32) -> Self {
+        assert!(
+            percentile > 0.0 && percentile <= 100.0,
+            "Percentile should be in range (0, 100], got {percentile}"
+        );
+        check_scheme(&scheme);
+        Self {
+            scheme,
+            percentile,
+            histograms: Histograms::new(DEFAULT_BINS, false),
+        }
+    }
+
+    /// Set the number of histogram bins.
+    pub fn with_bins(mut self, bins: usize) -> Self {
+        self.histograms = Histograms::new(bins, false);
+        self
+    }
+
+    /// Clear the accumulated statistics.
+    pub fn reset(&self) {
+        self.histograms.reset();
+    }
+}
+
+impl EntropyCalibration {
+    /// Create a new entropy calibration.
+    pub fn new(scheme: QuantizationScheme) -> Self {
+        check_scheme(&scheme);
+        Self {
+            scheme,
+            histograms: Histograms::new(DEFAULT_BINS, true),
+        }
+    }
+
+    /// Set the number of histogram bins.
+    pub fn with_bins(mut self, bins: usize) -> Self {
+        self.histograms = Histograms::new(bins, true);
+        self
+    }
+
+    /// Clear the accumulated statistics.
+    pub fn reset(&self) {
+        self.histograms.reset();
+    }
+}
+
+impl MseCalibration {
+    /// Create a new mean squared error calibration.
+    pub fn new(scheme: QuantizationScheme) -> Self {
+        check_scheme(&scheme);
+        Self {
+            scheme,
+            histograms: Histograms::new(DEFAULT_BINS, false),
+        }
+    }
+
+    /// Set the number of histogram bins.
+    pub fn with_bins(mut self, bins: usize) -> Self {
+        self.histograms = Histograms::new(bins, false);
+        self
+    }
+
+    /// Clear the accumulated statistics.
+    pub fn reset(&self) {
+        self.histograms.reset();
+    }
+}
+
+impl PercentileCalibration {
+    fn calibrate<B: Backend, const D: usize>(
+        &self,
+        id: Option<&ParamId>,
+        tensor: &Tensor<B, D>,
+    ) -> QuantizationStrategy {
+        // The values outside of the percentile are clipped evenly from both tails
+        let tail = (1.0 - self.percentile as f64 / 100.0) / 2.0;
+
+        self.histograms
+            .calibrate(&self.scheme, id, tensor, |stats| {
+                let total = stats.histogram.total();
+                let lower = stats.histogram.quantile(total * tail);
+                let upper = stats.histogram.quantile(total * (1.0 - tail));
+                (lower.max(stats.min), upper.min(stats.max))
+            })
+    }
+}
+
+impl Calibration for PercentileCalibration {
+    fn configure<B: Backend, const D: usize>(&self, tensor: &Tensor<B, D>) -> QuantizationStrategy {
+        self.calibrate(None, tensor)
+    }
+
+    fn configure_param<B: Backend, const D: usize>(
+        &self,
+        id: &ParamId,
+        tensor: &Tensor<B, D>,
+    ) -> QuantizationStrategy {
+        self.calibrate(Some(id), tensor)
+    }
+}
+
+impl EntropyCalibration {
+    fn calibrate<B: Backend, const D: usize>(
+        &self,
+        id: Option<&ParamId>,
+        tensor: &Tensor<B, D>,
+    ) -> QuantizationStrategy {
+        // Number of quantization levels for the absolute values
+        let levels = 1 << (bits(&self.scheme) - 1);
+
+        self.histograms
+            .calibrate(&self.scheme, id, tensor, |stats| {
+                let threshold = stats.histogram.entropy_threshold(levels);
+                (stats.min.max(-threshold), stats.max.min(threshold))
+            })
+    }
+}
+
+impl Calibration for EntropyCalibration {
+    fn configure<B: Backend, const D: usize>(&self, tensor: &Tensor<B, D>) -> QuantizationStrategy {
+        self.calibrate(None, tensor)
+    }
+
+    fn configure_param<B: Backend, const D: usize>(
+        &self,
+        id: &ParamId,
+        tensor: &Tensor<B, D>,
+    ) -> QuantizationStrategy {
+        self.calibrate(Some(id), tensor)
+    }
+}
+
+impl MseCalibration {
+    fn calibrate<B: Backend, const D: usize>(
+        &self,
+        id: Option<&ParamId>,
+        tensor: &Tensor<B, D>,
+    ) -> QuantizationStrategy {
+        let levels = 1 << bits(&self.scheme);
+        let symmetric = matches!(
+            self.scheme,
+            QuantizationScheme::PerTensorSymmetric(_) | QuantizationScheme::PerChannelSymmetric(..)
+        );
+
+        self.histograms
+            .calibrate(&self.scheme, id, tensor, |stats| {
+                stats.histogram.mse_range(levels, symmetric)
+            })
+    }
+}
+
+impl Calibration for MseCalibration {
+    fn configure<B: Backend, const D: usize>(&self, tensor: &Tensor<B, D>) -> QuantizationStrategy {
+        self.calibrate(None, tensor)
+    }
+
+    fn configure_param<B: Backend, const D: usize>(
+        &self,
+        id: &ParamId,
+        tensor: &Tensor<B, D>,
+    ) -> QuantizationStrategy {
+        self.calibrate(Some(id), tensor)
+    }
+}
+
+/// Checks that the scheme can be calibrated with histograms.
+///
+/// A histogram is accumulated for each quantization range, so the small groups of per-group
+/// schemes would need far more memory than the values themselves.
+fn check_scheme(scheme: &QuantizationScheme) {
+    assert!(
+        !matches!(
+            scheme,
+            QuantizationScheme::PerGroupAffine(..) | QuantizationScheme::PerGroupSymmetric(..)
+        ),
+        "Histogram calibration doesn't support per-group quantization, got {scheme:?}"
+    );
+}
+
+/// Number of bits of the quantized data type of the scheme.
+fn bits(scheme: &QuantizationScheme) -> u32 {
+    let dtype = match scheme {
+        QuantizationScheme::PerTensorAffine(dtype)
+        | QuantizationScheme::PerTensorSymmetric(dtype)
+        | QuantizationScheme::PerChannelAffine(dtype, _)
+        | QuantizationScheme::PerChannelSymmetric(dtype, _)
+        | QuantizationScheme::PerGroupAffine(dtype, _)
+        | QuantizationScheme::PerGroupSymmetric(dtype, _) => dtype,
+    };
+
+    match dtype {
+        QuantizationType::QInt8 | QuantizationType::QUInt8 => 8,
+        QuantizationType::QInt4 | QuantizationType::QUInt4 => 4,
+    }
+}
+
+/// Statistics accumulated for each range quantized with the same parameters.
+///
+/// The statistics of module parameters are kept separately, while the tensors calibrated without
+/// a parameter id share the same statistics.
+#[derive(Clone, Debug)]
+struct Histograms {
+    bins: usize,
+    /// If the histograms are computed over the absolute values.
+    absolute: bool,
+    stats: Arc<Mutex<HashMap<Option<ParamId>, Vec<RangeStatistics>>>>,
+}
+
+#[derive(Clone, Debug)]
+struct RangeStatistics {
+    min: f32,
+    max: f32,
+    histogram: Histogram,
+}
+
+impl Histograms {
+    fn new(bins: usize, absolute: bool) -> Self {
+        assert!(bins > 0, "The number of histogram bins should be positive");
+        Self {
+            bins,
+            absolute,
+            stats: Arc::new(Mutex::new(HashMap::new())),
+        }
+    }
+
+    fn reset(&self) {
+        self.stats.lock().unwrap().clear();
+    }
+
+    /// Accumulate the values of the tensor and compute the quantization strategy from the range
+    /// selected for each histogram.
+    fn calibrate<B: Backend, const D: usize, F>(
+        &self,
+        scheme: &QuantizationScheme,
+        id: Option<&ParamId>,
+        tensor: &Tensor<B, D>,
+        select: F,
+    ) -> QuantizationStrategy
+    where
+        F: Fn(&RangeStatistics) -> (f32, f32),
+    {
+        check_scheme(scheme);
+
+        let shape = tensor.shape();
+        let values = tensor.to_data().iter::<f32>().collect::<Vec<_>>();
+        let ranges = split_ranges(scheme, &shape.dims, values);
+
+        let mut stats = self.stats.lock().unwrap();
+        let stats = stats.entry(id.cloned()).or_default();
+        if stats.is_empty() {
+            *stats = ranges
+                .iter()
+                .map(|values| self.statistics(values))
+                .collect();
+        } else {
+            assert_eq!(
+                stats.len(),
+                ranges.len(),
+                "Calibrated tensors should have the same number of quantization ranges"
+            );
+            stats
+                .iter_mut()
+                .zip(ranges.iter())
+                .for_each(|(stats, values)| self.update(stats, values));
+        }
+
+        let (min, max): (Vec<_>, Vec<_>) = stats.iter().map(select).unzip();
+        scheme
+            .strategy(&min, &max, shape.num_elements())
+            .unwrap_or_else(|err| panic!("{err}"))
+    }
+
+    fn statistics(&self, values: &[f32]) -> RangeStatistics {
+        let (min, max) = min_max(values.iter().copied());
+        let (hist_min, hist_max) = self.histogram_range(values, min, max);
+        let mut histogram = Histogram::new(self.bins, hist_min, hist_max);
+        histogram.update(values.iter().map(|v| self.value(*v)));
+
+        RangeStatistics {
+            min,
+            max,
+            histogram,
+        }
+    }
+
+    fn update(&self, stats: &mut RangeStatistics, values: &[f32]) {
+        let (min, max) = min_max(values.iter().copied());
+        stats.min = stats.min.min(min);
+        stats.max = stats.max.max(max);
+
+        let (hist_min, hist_max) = self.histogram_range(values, min, max);
+        stats.histogram.extend(hist_min, hist_max);
+        stats
+            .histogram
+            .update(values.iter().map(|v| self.value(*v)));
+    }
+
+    fn histogram_range(&self, values: &[f32], min: f32, max: f32) -> (f32, f32) {
+        if self.absolute {
+            (0.0, min_max(values.iter().map(|v| v.abs())).1)
+        } else {
+            (min, max)
+        }
+    }
+
+    fn value(&self, value: f32) -> f32 {
+        if self.absolute {
+            value.abs()
+        } else {
+            value
+        }
+    }
+}
+
+/// Split the values of a tensor for each range quantized with the same parameters.
+fn split_ranges(scheme: &QuantizationScheme, dims: &[usize], values: Vec<f32>) -> Vec<Vec<f32>> {
+    match scheme {
+        QuantizationScheme::PerTensorAffine(_) | QuantizationScheme::PerTensorSymmetric(_) => {
+            vec![values]
+        }
+        QuantizationScheme::PerChannelAffine(_, axis)
+        | QuantizationScheme::PerChannelSymmetric(_, axis) => {
+            let channels = dims[*axis];
+            let channel_size: usize = dims[axis + 1..].iter().product();
+            let mut ranges = vec![Vec::new(); channels];
+            for (i, value) in values.into_iter().enumerate() {
+                ranges[i / channel_size % channels].push(value);
+            }
+            ranges
+        }
+        QuantizationScheme::PerGroupAffine(..) | QuantizationScheme::PerGroupSymmetric(..) => {
+            unreachable!("Per-group schemes are rejected by the histogram calibration")
+        }
+    }
+}
+
+fn min_max<I: Iterator<Item = f32>>(values: I) -> (f32, f32) {
+    values.fold((f32::MAX, f32::MIN), |(min, max), value| {
+        (min.min(value), max.max(value))
+    })
+}
+
+/// Histogram of values in the range `[min, max]` with bins of equal width.
+#[derive(Clone, Debug)]
+struct Histogram {
+    min: f32,
+    max: f32,
+    counts: Vec<f64>,
+}
+
+impl Histogram {
+    fn new(bins: usize, min: f32, max: f32) -> Self {
+        Self {
+            min,
+            max,
+            counts: vec![0.0; bins],
+        }
+    }
+
+    fn width(&self) -> f32 {
+        (self.max - self.min) / self.counts.len() as f32
+    }
+
+    fn edge(&self, index: usize) -> f32 {
+        self.min + index as f32 * self.width()
+    }
+
+    fn center(&self, index: usize) -> f64 {
+        (self.min as f64) + (index as f64 + 0.5) * self.width() as f64
+    }
+
+    fn total(&self) -> f64 {
+        self.counts.iter().sum()
+    }
+
+    fn bin(&self, value: f32) -> usize {
+        let width = self.width();
+        if width > 0.0 {
+            (((value - self.min) / width) as usize).min(self.counts.len() - 1)
+        } else {
+            0
+        }
+    }
+
+    fn update<I: Iterator<Item = f32>>(&mut self, values: I) {
+        for value in values {
+            let bin = self.bin(value);
+            self.counts[bin] += 1.0;
+        }
+    }
+
+    /// Extend the histogram range to include `[min, max]`, redistributing the counts of the
+    /// current bins to the overlapping new bins.
+    fn extend(&mut self, min: f32, max: f32) {
+        let min = min.min(self.min);
+        let max = max.max(self.max);
+        if min == self.min && max == self.max {
+            return;
+        }
+
+        let previous = core::mem::replace(self, Histogram::new(self.counts.len(), min, max));
+        let previous_width = previous.width();
+        let width = self.width();
+
+        for (index, count) in previous.counts.iter().enumerate() {
+            if *count == 0.0 {
+                continue;
+            }
+            if previous_width == 0.0 {
+                let bin = self.bin(previous.min);
+                self.counts[bin] += count;
+                continue;
+            }
+
+            let start = previous.edge(index);
+            let end = start + previous_width;
+            for bin in self.bin(start)..=self.bin(end) {
+                let overlap = (self.edge(bin) + width).min(end) - self.edge(bin).max(start);
+                if overlap > 0.0 {
+                    self.counts[bin] += count * (overlap / previous_width) as f64;
+                }
+            }
+        }
+    }
+
+    /// The value below which the given count of values falls.
+    fn quantile(&self, count: f64) -> f32 {
+        let mut cumulative = 0.0;
+        for (index, bin_count) in self.counts.iter().enumerate() {
+            cumulative += bin_count;
+            if cumulative >= count && *bin_count > 0.0 {
+                // Interpolate within the bin
+                let fraction = 1.0 - (cumulative - count) / bin_count;
+                return self.edge(index) + fraction as f32 * self.width();
+            }
+        }
+        self.max
+    }
+
+    /// Search the threshold minimizing the KL divergence between the distribution of the values
+    /// and their distribution quantized to the given number of levels.
+    fn entropy_threshold(&self, levels: usize) -> f32 {
+        let bins = self.counts.len();
+        if bins <= levels {
+            return self.max;
+        }
+
+        let mut best = (f64::MAX, bins);
+        for end in levels..=bins {
+            // Reference distribution with the outliers merged into the last bin
+            let mut reference = self.counts[..end].to_vec();
+            reference[end - 1] += self.counts[end..].iter().sum::<f64>();
+
+            // Quantized distribution, expanded back to the reference bins
+            let mut candidate = vec![0.0; end];
+            for level in 0..levels {
+                let start = level * end / levels;
+                let stop = (level + 1) * end / levels;
+                let chunk = &self.counts[start..stop];
+                let nonzero = chunk.iter().filter(|count| **count > 0.0).count();
+                if nonzero == 0 {
+                    continue;
+                }
+                let mean = chunk.iter().sum::<f64>() / nonzero as f64;
+                for (index, count) in chunk.iter().enumerate() {
+                    if *count > 0.0 {
+                        candidate[start + index] = mean;
+                    }
+                }
+            }
+
+            let divergence = kl_divergence(&reference, &candidate);
+            if divergence < best.0 {
+                best = (divergence, end);
+            }
+        }
+
+        self.edge(best.1)
+    }
+
+    /// Search the range minimizing the mean squared error of the values quantized to the given
+    /// number of levels, by greedily shrinking the range one bin at a time from either side.
+    fn mse_range(&self, levels: usize, symmetric: bool) -> (f32, f32) {
+        let bins = self.counts.len();
+
+        // Prefix sums of the counts and of their first and second moments
+        let mut prefix = vec![[0.0f64; 3]; bins + 1];
+        for index in 0..bins {
+            let center = self.center(index);
+            let count = self.counts[index];
+            let [n, s, q] = prefix[index];
+            prefix[index + 1] = [n + count, s + count * center, q + count * center * center];
+        }
+        let sum = |start: usize, end: usize| {
+            let [n0, s0, q0] = prefix[start];
+            let [n1, s1, q1] = prefix[end];
+            [n1 - n0, s1 - s0, q1 - q0]
+        };
+
+        let error = |lower: f32, upper: f32| {
+            let (lower, upper) = if symmetric {
+                let bound = lower.abs().max(upper.abs());
+                (-bound, bound)
+            } else {
+                (lower, upper)
+            };
+            let (lower, upper) = (lower as f64, upper as f64);
+            let step = (upper - lower) / (levels - 1) as f64;
+
+            let first = self.bin(lower as f32);
+            let last = self.bin(upper as f32) + 1;
+            let first = first.min(last);
+
+            // Values below and above the range are clamped to its bounds
+            let [n, s, q] = sum(0, first);
+            let below = q - 2.0 * lower * s + lower * lower * n;
+            let [n, s, q] = sum(last, bins);
+            let above = q - 2.0 * upper * s + upper * upper * n;
+            // Values within the range have a uniform rounding error
+            let [n, _, _] = sum(first, last);
+            let within = n * step * step / 12.0;
+
+            below + above + within
+        };
+
+        let (mut start, mut end) = (0, bins);
+        let mut best = (error(self.edge(start), self.edge(end)), start, end);
+        while end - start > 1 {
+            let shrink_start = error(self.edge(start + 1), self.edge(end));
+            let shrink_end = error(self.edge(start), self.edge(end - 1));
+            let current = if shrink_start < shrink_end {
+                start += 1;
+                shrink_start
+            } else {
+                end -= 1;
+                shrink_end
+            };
+            if current < best.0 {
+                best = (current, start, end);
+            }
+        }
+
+        (self.edge(best.1), self.edge(best.2))
+    }
+}
+
+/// Computes the KL divergence between two unnormalized distributions.
+fn kl_divergence(reference: &[f64], candidate: &[f64]) -> f64 {
+    let reference_total: f64 = reference.iter().sum();
+    let candidate_total: f64 = candidate.iter().sum();
+    if reference_total == 0.0 || candidate_total == 0.0 {
+        return f64::MAX;
+    }
+
+    reference
+        .iter()
+        .zip(candidate)
+        .filter(|(p, _)| **p > 0.0)
+        .map(|(p, q)| {
+            let p = p / reference_total;
+            let q = (q / candidate_total).max(f64::EPSILON);
+            p * Float::ln(p / q)
+        })
+        .sum()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::module::Module;
+    use crate::nn::LinearConfig;
+    use crate::quantization::{MinMaxCalibration, Quantizer};
+    use crate::TestBackend;
+    use burn_tensor::{Distribution, Int, Tensor};
+
+    /// Normally distributed values with a single outlier.
+    fn values_with_outlier(device: &<TestBackend as Backend>::Device) -> Tensor<TestBackend, 1> {
+        TestBackend::seed(0);
+        let values =
+            Tensor::<TestBackend, 1>::random([4095], Distribution::Normal(0.0, 1.0), device);
+        Tensor::cat(vec![values, Tensor::from_floats([100.0], device)], 0)
+    }
+
+    fn scale(strategy: QuantizationStrategy) -> f32 {
+        match strategy {
+            QuantizationStrategy::PerTensorAffineInt8(q) => q.scale,
+            QuantizationStrategy::PerTensorSymmetricInt8(q) => q.scale,
+            _ => panic!("Wrong quantization strategy"),
+        }
+    }
+
+    #[test]
+    fn percentile_calibration_should_clip_outliers() {
+        let device = Default::default();
+        let tensor = values_with_outlier(&device);
+        let scheme = QuantizationScheme::PerTensorAffine(QuantizationType::QInt8);
+        let calibration = PercentileCalibration::new(scheme.clone(), 99.9);
+
+        let strategy = calibration.configure(&tensor);
+        let min_max = MinMaxCalibration { scheme }.configure(&tensor);
+
+        // The range covers roughly [-3.3, 3.3] instead of [-3.3, 100]
+        let scale = scale(strategy);
+        assert!(scale < 8.0 / 255.0, "scale {scale} should clip the outlier");
+        assert!(
+            scale > 4.0 / 255.0,
+            "scale {scale} should keep the normal values"
+        );
+        assert!(scale < self::scale(min_max) / 10.0);
+    }
+
+    #[test]
+    fn percentile_calibration_should_clip_both_tails() {
+        let device = Default::default();
+        let tensor = Tensor::<TestBackend, 1, Int>::arange(-50..50, &device).float();
+        let scheme = QuantizationScheme::PerTensorAffine(QuantizationType::QInt8);
+        let calibration = PercentileCalibration::new(scheme, 90.0).with_bins(100);
+
+        // 5% of the values are clipped from each tail, i.e. the range is roughly [-45, 45]
+        let scale = scale(calibration.configure(&tensor));
+        let range = scale * 255.0;
+        assert!(
+            (range - 90.0).abs() < 2.0,
+            "range {range} should be close to 90"
+        );
+    }
+
+    #[test]
+    fn percentile_calibration_should_quantize_linear_weight_and_bias() {
+        let device = Default::default();
+        let linear = LinearConfig::new(8, 4).init::<TestBackend>(&device);
+        let weight = linear.weight.val();
+        let bias = linear.bias.as_ref().unwrap().val();
+
+        // The weight has 8 channels on the first axis and the bias 4
+        let scheme = QuantizationScheme::PerChannelSymmetric(QuantizationType::QInt8, 0);
+        let calibration = PercentileCalibration::new(scheme, 100.0).with_bins(256);
+        let linear = linear.quantize_weights(&mut Quantizer { calibration });
+
+        let quantized_weight = linear.weight.val();
+        let quantized_bias = linear.bias.unwrap().val();
+        assert!(quantized_weight.is_quantized());
+        assert!(quantized_bias.is_quantized());
+        quantized_weight
+            .dequantize()
+            .into_data()
+            .assert_approx_eq_diff(&weight.into_data(), 0.01);
+        quantized_bias
+            .dequantize()
+            .into_data()
+            .assert_approx_eq_diff(&bias.into_data(), 0.01);
+    }
+
+    #[test]
+    fn percentile_calibration_should_accumulate_across_calls() {
+        let device = Default::default();
+        let scheme = QuantizationScheme::PerTensorAffine(QuantizationType::QInt8);
+        let calibration = PercentileCalibration::new(scheme.clone(), 100.0).with_bins(64);
+
+        calibration.configure(&Tensor::<TestBackend, 1>::from_floats(
+            [-1.0, -0.5, 0.0, 0.5],
+            &device,
+        ));
+        let strategy = calibration.configure(&Tensor::<TestBackend, 1>::from_floats(
+            [0.0, 1.0, 1.5, 2.0],
+            &device,
+        ));
+
+        let expected = scheme.strategy(&[-1.0], &[2.0], 4).unwrap();
+        assert_eq!(strategy, expected);
+
+        calibration.reset();
+        let strategy = calibration.configure(&Tensor::<TestBackend, 1>::from_floats(
+            [0.0, 1.0, 1.5, 2.0],
+            &device,
+        ));
+        let expected = scheme.strategy(&[0.0], &[2.0], 4).unwrap();
+        assert_eq!(strategy, expected);
+    }
+
+    #[test]
+    fn percentile_calibration_per_channel() {
+        let device = Default::default();
+        let tensor =
+            Tensor::<TestBackend, 2>::from_floats([[-1.0, 0.0, 1.0], [-4.0, 0.0, 2.0]], &device);
+        let scheme = QuantizationScheme::PerChannelSymmetric(QuantizationType::QInt8, 0);
+        let calibration = PercentileCalibration::new(scheme.clone(), 100.0).with_bins(16);
+
+        let strategy = calibration.configure(&tensor);
+
+        let expected = scheme.strategy(&[-1.0, -4.0], &[1.0, 2.0], 6).unwrap();
+        assert_eq!(strategy, expected);
+    }
+
+    #[test]
+    #[should_panic]
+    fn histogram_calibration_should_reject_per_group_schemes() {
+        let scheme = QuantizationScheme::PerGroupSymmetric(QuantizationType::QInt8, 32);
+        EntropyCalibration::new(scheme);
+    }
+
+    #[test]
+    fn entropy_calibration_should_clip_outliers() {
+        let device = Default::default();
+        let tensor = values_with_outlier(&device);
+        let scheme = QuantizationScheme::PerTensorSymmetric(QuantizationType::QInt8);
+        let calibration = EntropyCalibration::new(scheme.clone());
+
+        let strategy = calibration.configure(&tensor);
+        let min_max = MinMaxCalibration { scheme }.configure(&tensor);
+
+        let scale = scale(strategy);
+        assert!(scale < self::scale(min_max) / 10.0);
+        assert!(
+            scale > 2.0 / 127.0,
+            "scale {scale} should keep the normal values"
+        );
+    }
+
+    #[test]
+    fn mse_calibration_should_reduce_quantization_error() {
+        let device = Default::default();
+        let tensor = values_with_outlier(&device);
+        let scheme = QuantizationScheme::PerTensorAffine(QuantizationType::QInt8);
+
+        let error = |strategy: QuantizationStrategy| {
+            (tensor.clone().quantize(strategy).dequantize() - tensor.clone())
+                .powf_scalar(2.0)
+                .sum()
+                .into_scalar()
+        };
+        let mse = error(MseCalibration::new(scheme.clone()).configure(&tensor));
+        let min_max = error(MinMaxCalibration { scheme }.configure(&tensor));
+
+        assert!(mse < min_max, "{mse} should be lower than {min_max}");
+    }
+}
@@ -0,0 +1,82 @@
+use alloc::format;
+
+use crate::{
+    module::{
+        AutodiffModule, ConstantRecord, Content, Devices, Module, ModuleDisplay,
+        ModuleDisplayDefault, ModuleMapper, ModuleVisitor,
+    },
+    tensor::backend::{AutodiffBackend, Backend},
+};
+
+use super::{DynamicQuantization, FakeQuantization, StaticQuantization};
+
+/// Quantization applied in the forward pass of a layer.
+///
+/// Layers supporting quantization, like [Linear](crate::nn::Linear) and
+/// [Conv2d](crate::nn::conv::Conv2d), hold this state which is set by a
+/// [module mapper](ModuleMapper::map_quantization). It is not part of the module record, so the
+/// field holding it must be annotated with `#[module(skip)]`.
+#[derive(Clone, Debug, Default)]
+pub enum LayerQuantization {
+    /// The layer runs in floating point precision.
+    #[default]
+    None,
+    /// The weight and input of the layer are fake quantized, used for quantization aware training.
+    FakeQuantize(FakeQuantization),
+    /// The ranges of the input and output are observed in floating point precision, used for the
+    /// calibration pass of static quantization.
+    Calibrate(StaticQuantization),
+    /// The layer runs on quantized tensors, with the input and output quantized with their
+    /// calibrated ranges.
+    Static(StaticQuantization),
+    /// The input is quantized with its range computed for each batch.
+    Dynamic(DynamicQuantization),
+}
+
+impl<B: Backend> Module<B> for LayerQuantization {
+    type Record = ConstantRecord;
+
+    fn visit<V: ModuleVisitor<B>>(&self, _visitor: &mut V) {
+        // Nothing to do
+    }
+
+    fn map<M: ModuleMapper<B>>(self, mapper: &mut M) -> Self {
+        mapper.map_quantization(self)
+    }
+
+    fn load_record(self, _record: Self::Record) -> Self {
+        self
+    }
+
+    fn into_record(self) -> Self::Record {
+        ConstantRecord::new()
+    }
+
+    fn to_device(self, _: &B::Device) -> Self {
+        self
+    }
+
+    fn fork(self, _: &B::Device) -> Self {
+        self
+    }
+
+    fn collect_devices(&self, devices: Devices<B>) -> Devices<B> {
+        devices
+    }
+}
+
+impl ModuleDisplayDefault for LayerQuantization {
+    fn content(&self, content: Content) -> Option<Content> {
+        content.add_single(&format!("{:?}", self)).optional()
+    }
+}
+
+impl ModuleDisplay for LayerQuantization {}
+
+impl<B: AutodiffBackend> AutodiffModule<B> for LayerQuantization {
+    type InnerModule = LayerQuantization;
+
+    fn valid(&self) -> Self::InnerModule {
+        self.clone()
+    }
+}
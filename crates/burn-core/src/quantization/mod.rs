@@ -1,7 +1,17 @@
+mod activation;
 mod calibration;
+mod histogram;
+mod layer;
+mod observer;
+mod qat;
 mod quantize;
 mod scheme;
 
+pub use activation::*;
 pub use calibration::*;
+pub use histogram::*;
+pub use layer::*;
+pub use observer::*;
+pub use qat::*;
 pub use quantize::*;
 pub use scheme::*;
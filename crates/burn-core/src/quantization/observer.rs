@@ -0,0 +1,83 @@
+use alloc::{sync::Arc, vec::Vec};
+use burn_common::stub::Mutex;
+use burn_tensor::{backend::Backend, QuantizationStrategy, Tensor};
+
+use super::{min_max_ranges, QuantizationScheme};
+
+/// The min and max values of each quantization range.
+type Ranges = (Vec<f32>, Vec<f32>);
+
+/// Records the range of the values flowing through a layer to compute their quantization
+/// strategy.
+///
+/// # Notes
+///
+/// The observed ranges are shared between the clones of the observer.
+#[derive(Clone, Debug)]
+pub struct Observer {
+    scheme: QuantizationScheme,
+    ranges: Arc<Mutex<Option<Ranges>>>,
+}
+
+impl Observer {
+    /// Create a new observer for the given quantization scheme.
+    pub fn new(scheme: QuantizationScheme) -> Self {
+        Self {
+            scheme,
+            ranges: Arc::new(Mutex::new(None)),
+        }
+    }
+
+    /// The quantization scheme of the observer.
+    pub fn scheme(&self) -> &QuantizationScheme {
+        &self.scheme
+    }
+
+    /// Returns true if values have been observed.
+    pub fn has_observed(&self) -> bool {
+        self.ranges.lock().unwrap().is_some()
+    }
+
+    /// Update the running min and max values with the values of the tensor.
+    ///
+    /// # Panics
+    ///
+    /// If the number of channels or groups differs from the previously observed tensors.
+    pub fn observe<B: Backend, const D: usize>(&self, tensor: &Tensor<B, D>) {
+        let (min, max) = min_max_ranges(&self.scheme, tensor);
+        let mut ranges = self.ranges.lock().unwrap();
+
+        match ranges.as_mut() {
+            Some((running_min, running_max)) => {
+                assert_eq!(
+                    running_min.len(),
+                    min.len(),
+                    "Observed tensors should have the same number of quantization ranges"
+                );
+                running_min
+                    .iter_mut()
+                    .zip(min)
+                    .for_each(|(running, value)| *running = running.min(value));
+                running_max
+                    .iter_mut()
+                    .zip(max)
+                    .for_each(|(running, value)| *running = running.max(value));
+            }
+            None => *ranges = Some((min, max)),
+        }
+    }
+
+    /// Compute the quantization strategy from the observed ranges for a tensor with the given
+    /// number of elements.
+    ///
+    /// Returns `None` when no values have been observed yet.
+    pub fn strategy(&self, num_elements: usize) -> Option<QuantizationStrategy> {
+        let ranges = self.ranges.lock().unwrap();
+
+        ranges.as_ref().map(|(min, max)| {
+            self.scheme
+                .strategy(min, max, num_elements)
+                .unwrap_or_else(|err| panic!("{err}"))
+        })
+    }
+}
@@ -0,0 +1,185 @@
+use burn_tensor::{backend::Backend, Tensor};
+
+use crate::module::ModuleMapper;
+
+use super::{
+    Calibration, LayerQuantization, MinMaxCalibration, Observer, QuantizationScheme, Quantizer,
+};
+
+/// Fake quantization of the weight and input of a layer.
+///
+/// The weight is fake quantized with its current range, while the input range is recorded by an
+/// [observer](Observer) when training (i.e., when the backend has autodiff enabled).
+#[derive(Clone, Debug)]
+pub struct FakeQuantization {
+    /// Quantization scheme of the weight.
+    pub weight: QuantizationScheme,
+    /// Observer of the layer input.
+    pub input: Observer,
+}
+
+impl FakeQuantization {
+    /// Fake quantize the weight of the layer.
+    pub fn weight<B: Backend, const D: usize>(&self, weight: Tensor<B, D>) -> Tensor<B, D> {
+        let calibration = MinMaxCalibration {
+            scheme: self.weight.clone(),
+        };
+        let strategy = calibration.configure(&weight);
+
+        weight.fake_quantize(&strategy)
+    }
+
+    /// Fake quantize the input of the layer, updating the observed range when training.
+    pub fn input<B: Backend, const D: usize>(&self, input: Tensor<B, D>) -> Tensor<B, D> {
+        if B::ad_enabled() {
+            self.input.observe(&input);
+        }
+
+        match self.input.strategy(input.shape().num_elements()) {
+            Some(strategy) => input.fake_quantize(&strategy),
+            None => input,
+        }
+    }
+}
+
+/// Prepares a module for quantization aware training.
+///
+/// The layers supporting quantization fake quantize their weight and input in the forward pass,
+/// so the model learns to be robust to the quantization error. Once trained, the module can be
+/// converted with the [quantizer](QuantizationAwareTraining::quantizer).
+///
+/// # Notes
+///
+/// Per-channel schemes are applied on the given axis for all layers, the output channels of the
+/// [Linear](crate::nn::Linear) weight being on axis 1 and those of the
+/// [Conv2d](crate::nn::conv::Conv2d) weight on axis 0.
+pub struct QuantizationAwareTraining {
+    /// Quantization scheme of the weights.
+    pub weight: QuantizationScheme,
+    /// Quantization scheme of the activations.
+    pub activation: QuantizationScheme,
+}
+
+impl QuantizationAwareTraining {
+    /// The quantizer converting the trained module to quantized weights with the same scheme.
+    pub fn quantizer(&self) -> Quantizer<MinMaxCalibration> {
+        Quantizer {
+            calibration: MinMaxCalibration {
+                scheme: self.weight.clone(),
+            },
+        }
+    }
+}
+
+impl<B: Backend> ModuleMapper<B> for QuantizationAwareTraining {
+    fn map_quantization(&mut self, _quantization: LayerQuantization) -> LayerQuantization {
+        LayerQuantization::FakeQuantize(FakeQuantization {
+            weight: self.weight.clone(),
+            input: Observer::new(self.activation.clone()),
+        })
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{
+        module::{AutodiffModule, Module},
+        nn::{Linear, LinearConfig},
+        quantization::QuantizationType,
+        TestAutodiffBackend,
+    };
+    use burn_tensor::{DType, Distribution};
+
+    fn qat() -> QuantizationAwareTraining {
+        QuantizationAwareTraining {
+            weight: QuantizationScheme::PerTensorSymmetric(QuantizationType::QInt8),
+            activation: QuantizationScheme::PerTensorAffine(QuantizationType::QInt8),
+        }
+    }
+
+    #[test]
+    fn qat_should_fake_quantize_linear() {
+        let device = Default::default();
+        let linear: Linear<TestAutodiffBackend> = LinearConfig::new(8, 4).init(&device);
+        let input =
+            Tensor::<TestAutodiffBackend, 2>::random([6, 8], Distribution::Default, &device);
+
+        let linear = linear.map(&mut qat());
+        let output = linear.forward(input.clone());
+
+        let LayerQuantization::FakeQuantize(quantization) = &linear.quantization else {
+            panic!("Linear layer should be prepared for quantization aware training");
+        };
+        let weight = quantization.weight(linear.weight.val());
+        let expected =
+            quantization.input(input).matmul(weight) + linear.bias.unwrap().val().unsqueeze();
+        output
+            .into_data()
+            .assert_approx_eq(&expected.into_data(), 5);
+    }
+
+    #[test]
+    fn qat_should_pass_gradients_straight_through() {
+        let device = Default::default();
+        let linear: Linear<TestAutodiffBackend> = LinearConfig::new(8, 4).init(&device);
+        let input =
+            Tensor::<TestAutodiffBackend, 2>::random([6, 8], Distribution::Default, &device);
+
+        let linear = linear.map(&mut qat());
+        let grads = linear.forward(input).sum().backward();
+
+        let grad = linear.weight.grad(&grads).unwrap();
+        assert!(grad.abs().sum().into_scalar() > 0.0);
+    }
+
+    #[test]
+    fn qat_should_convert_to_quantized_module() {
+        let device = Default::default();
+        let linear: Linear<TestAutodiffBackend> = LinearConfig::new(8, 4).init(&device);
+        let input =
+            Tensor::<TestAutodiffBackend, 2>::random([6, 8], Distribution::Default, &device);
+
+        let mut qat = qat();
+        let linear = linear.map(&mut qat);
+        // Training pass to observe the range of the input
+        let _ = linear.forward(input.clone());
+
+        let linear = linear.valid();
+        let expected = linear.forward(input.clone().inner());
+        let linear = linear.quantize_weights(&mut qat.quantizer());
+
+        assert!(matches!(linear.quantization, LayerQuantization::None));
+        assert!(matches!(
+            linear.weight.val().into_data().dtype,
+            DType::QFloat(_)
+        ));
+        // The quantized module only differs by the fake quantization of the input
+        let output = linear.forward(input.inner());
+        output
+            .into_data()
+            .assert_approx_eq_diff(&expected.into_data(), 0.1);
+    }
+
+    #[test]
+    fn observer_should_accumulate_running_range() {
+        let device = Default::default();
+        let observer = Observer::new(QuantizationScheme::PerTensorAffine(QuantizationType::QInt8));
+
+        assert!(observer.strategy(4).is_none());
+
+        observer.observe(&Tensor::<TestAutodiffBackend, 1>::from_floats(
+            [-1.0, 0.0, 0.5, 0.2],
+            &device,
+        ));
+        observer.observe(&Tensor::<TestAutodiffBackend, 1>::from_floats(
+            [-0.5, 0.0, 2.0, 0.2],
+            &device,
+        ));
+
+        let expected = QuantizationScheme::PerTensorAffine(QuantizationType::QInt8)
+            .strategy(&[-1.0], &[2.0], 4)
+            .unwrap();
+        assert_eq!(observer.strategy(4), Some(expected));
+    }
+}
@@ -2,7 +2,7 @@ use burn_tensor::{backend::Backend, Tensor};
 
 use crate::module::{ModuleMapper, ParamId};
 
-use super::Calibration;
+use super::{Calibration, LayerQuantization};
 
 /// Describes how to quantize a module.
 pub struct Quantizer<C: Calibration> {
@@ -11,8 +11,22 @@ pub struct Quantizer<C: Calibration> {
 }
 
 impl<B: Backend, C: Calibration> ModuleMapper<B> for Quantizer<C> {
-    fn map_float<const D: usize>(&mut self, _id: &ParamId, tensor: Tensor<B, D>) -> Tensor<B, D> {
-        let strategy = self.calibration.configure(&tensor);
+    fn map_float<const D: usize>(&mut self, id: &ParamId, tensor: Tensor<B, D>) -> Tensor<B, D> {
+        let strategy = self.calibration.configure_param(id, &tensor);
         tensor.quantize(strategy)
     }
+
+    fn map_quantization(&mut self, quantization: LayerQuantization) -> LayerQuantization {
+        match quantization {
+            // The weights are quantized, which ends quantization aware training
+            LayerQuantization::FakeQuantize(_) => LayerQuantization::None,
+            // The activations are quantized with the ranges observed during calibration
+            LayerQuantization::Calibrate(quantization) if quantization.is_calibrated() => {
+                LayerQuantization::Static(quantization)
+            }
+            // The layer was not reached by the calibration pass
+            LayerQuantization::Calibrate(_) => LayerQuantization::None,
+            quantization => quantization,
+        }
+    }
 }
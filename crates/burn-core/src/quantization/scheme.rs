@@ -1,17 +1,276 @@
+use alloc::vec::Vec;
+use burn_tensor::{
+    AffineQuantization, PerChannelQuantization, PerGroupQuantization, Quantization,
+    QuantizationStrategy, SymmetricQuantization,
+};
+use num_traits::PrimInt;
+
 /// Quantization data type.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
 pub enum QuantizationType {
     /// 8-bit signed integer.
     QInt8,
+    /// 8-bit unsigned integer.
+    QUInt8,
+    /// 4-bit signed integer, packed two values per byte.
+    QInt4,
+    /// 4-bit unsigned integer, packed two values per byte.
+    QUInt4,
 }
 
 /// Quantization scheme.
+#[derive(Clone, Debug, PartialEq, Eq)]
 pub enum QuantizationScheme {
     /// Per-tensor affine/asymmetric quantization.
     PerTensorAffine(QuantizationType),
     /// Per-tensor symmetric quantization.
     PerTensorSymmetric(QuantizationType),
-    // /// Per-channel affine/asymmetric quantization.
-    // PerChannelAffine,
-    // /// Per-channel symmetric quantization.
-    // PerChannelSymmetric,
+    /// Per-channel affine/asymmetric quantization along the given axis.
+    PerChannelAffine(QuantizationType, usize),
+    /// Per-channel symmetric quantization along the given axis.
+    PerChannelSymmetric(QuantizationType, usize),
+    /// Per-group affine/asymmetric quantization with the given group size.
+    PerGroupAffine(QuantizationType, usize),
+    /// Per-group symmetric quantization with the given group size.
+    PerGroupSymmetric(QuantizationType, usize),
+}
+
+/// Error returned when a quantization scheme is not supported for its data type.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum QuantizationSchemeError {
+    /// Symmetric quantization with an unsigned data type.
+    UnsignedSymmetric(QuantizationType),
+    /// Per-channel quantization with a 4-bit data type.
+    PerChannel4Bit(QuantizationType),
+}
+
+impl core::fmt::Display for QuantizationSchemeError {
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        match self {
+            Self::UnsignedSymmetric(_) => {
+                f.write_str("Symmetric quantization is only valid for signed integers.")
+            }
+            Self::PerChannel4Bit(_) => f.write_str(
+                "Per-channel 4-bit quantization is not supported, use per-group quantization instead.",
+            ),
+        }
+    }
+}
+
+// TODO: Move from std to core after Error is core (see https://github.com/rust-lang/rust/issues/103765)
+#[cfg(feature = "std")]
+impl std::error::Error for QuantizationSchemeError {}
+
+impl QuantizationScheme {
+    /// Checks that the scheme is supported for its data type.
+    ///
+    /// Symmetric schemes require a signed data type and per-channel schemes an 8-bit data type.
+    pub fn validate(&self) -> Result<(), QuantizationSchemeError> {
+        match self {
+            QuantizationScheme::PerTensorSymmetric(dtype)
+            | QuantizationScheme::PerChannelSymmetric(dtype, _)
+            | QuantizationScheme::PerGroupSymmetric(dtype, _)
+                if matches!(dtype, QuantizationType::QUInt8 | QuantizationType::QUInt4) =>
+            {
+                Err(QuantizationSchemeError::UnsignedSymmetric(*dtype))
+            }
+            QuantizationScheme::PerChannelAffine(dtype, _)
+            | QuantizationScheme::PerChannelSymmetric(dtype, _)
+                if matches!(dtype, QuantizationType::QInt4 | QuantizationType::QUInt4) =>
+            {
+                Err(QuantizationSchemeError::PerChannel4Bit(*dtype))
+            }
+            _ => Ok(()),
+        }
+    }
+
+    /// Creates the quantization strategy mapping the given ranges to the quantized data type.
+    ///
+    /// There should be a single range for per-tensor schemes, one range per channel for
+    /// per-channel schemes and one range per group for per-group schemes.
+    ///
+    /// The ranges are extended to include zero, so that zero is exactly representable and the
+    /// zero-point offset of affine schemes fits in the quantized range.
+    ///
+    /// Returns an error when the scheme is not supported for its data type (see
+    /// [validate](Self::validate)).
+    pub fn strategy(
+        &self,
+        min: &[f32],
+        max: &[f32],
+        num_elements: usize,
+    ) -> Result<QuantizationStrategy, QuantizationSchemeError> {
+        self.validate()?;
+
+        let min = min.iter().map(|min| min.min(0.0)).collect::<Vec<_>>();
+        let max = max.iter().map(|max| max.max(0.0)).collect::<Vec<_>>();
+
+        fn affine<Q: PrimInt, const B: usize>(
+            min: &[f32],
+            max: &[f32],
+        ) -> Vec<AffineQuantization<f32, Q, i32, B>> {
+            min.iter()
+                .zip(max)
+                .map(|(min, max)| AffineQuantization::new(*min, *max))
+                .collect()
+        }
+        fn symmetric<const B: usize>(
+            min: &[f32],
+            max: &[f32],
+        ) -> Vec<SymmetricQuantization<f32, i8, B>> {
+            min.iter()
+                .zip(max)
+                .map(|(min, max)| SymmetricQuantization::new(*min, *max))
+                .collect()
+        }
+
+        let strategy = match (self, self.dtype()) {
+            (QuantizationScheme::PerTensorAffine(_), QuantizationType::QInt8) => {
+                QuantizationStrategy::PerTensorAffineInt8(AffineQuantization::new(min[0], max[0]))
+            }
+            (QuantizationScheme::PerTensorAffine(_), QuantizationType::QUInt8) => {
+                QuantizationStrategy::PerTensorAffineUInt8(AffineQuantization::new(min[0], max[0]))
+            }
+            // 4-bit values are quantized with a single group for the whole tensor
+            (QuantizationScheme::PerTensorAffine(_), QuantizationType::QInt4) => {
+                QuantizationStrategy::PerGroupAffineInt4(PerGroupQuantization::new(
+                    num_elements,
+                    affine(&min[..1], &max[..1]),
+                ))
+            }
+            (QuantizationScheme::PerTensorAffine(_), QuantizationType::QUInt4) => {
+                QuantizationStrategy::PerGroupAffineUInt4(PerGroupQuantization::new(
+                    num_elements,
+                    affine(&min[..1], &max[..1]),
+                ))
+            }
+            (QuantizationScheme::PerTensorSymmetric(_), QuantizationType::QInt8) => {
+                QuantizationStrategy::PerTensorSymmetricInt8(SymmetricQuantization::new(
+                    min[0], max[0],
+                ))
+            }
+            (QuantizationScheme::PerTensorSymmetric(_), QuantizationType::QInt4) => {
+                QuantizationStrategy::PerGroupSymmetricInt4(PerGroupQuantization::new(
+                    num_elements,
+                    symmetric(&min[..1], &max[..1]),
+                ))
+            }
+            (QuantizationScheme::PerChannelAffine(_, axis), QuantizationType::QInt8) => {
+                QuantizationStrategy::PerChannelAffineInt8(PerChannelQuantization::new(
+                    *axis,
+                    affine(&min, &max),
+                ))
+            }
+            (QuantizationScheme::PerChannelAffine(_, axis), QuantizationType::QUInt8) => {
+                QuantizationStrategy::PerChannelAffineUInt8(PerChannelQuantization::new(
+                    *axis,
+                    affine(&min, &max),
+                ))
+            }
+            (QuantizationScheme::PerChannelSymmetric(_, axis), QuantizationType::QInt8) => {
+                QuantizationStrategy::PerChannelSymmetricInt8(PerChannelQuantization::new(
+                    *axis,
+                    symmetric(&min, &max),
+                ))
+            }
+            (QuantizationScheme::PerGroupAffine(_, size), QuantizationType::QInt8) => {
+                QuantizationStrategy::PerGroupAffineInt8(PerGroupQuantization::new(
+                    *size,
+                    affine(&min, &max),
+                ))
+            }
+            (QuantizationScheme::PerGroupAffine(_, size), QuantizationType::QUInt8) => {
+                QuantizationStrategy::PerGroupAffineUInt8(PerGroupQuantization::new(
+                    *size,
+                    affine(&min, &max),
+                ))
+            }
+            (QuantizationScheme::PerGroupAffine(_, size), QuantizationType::QInt4) => {
+                QuantizationStrategy::PerGroupAffineInt4(PerGroupQuantization::new(
+                    *size,
+                    affine(&min, &max),
+                ))
+            }
+            (QuantizationScheme::PerGroupAffine(_, size), QuantizationType::QUInt4) => {
+                QuantizationStrategy::PerGroupAffineUInt4(PerGroupQuantization::new(
+                    *size,
+                    affine(&min, &max),
+                ))
+            }
+            (QuantizationScheme::PerGroupSymmetric(_, size), QuantizationType::QInt8) => {
+                QuantizationStrategy::PerGroupSymmetricInt8(PerGroupQuantization::new(
+                    *size,
+                    symmetric(&min, &max),
+                ))
+            }
+            (QuantizationScheme::PerGroupSymmetric(_, size), QuantizationType::QInt4) => {
+                QuantizationStrategy::PerGroupSymmetricInt4(PerGroupQuantization::new(
+                    *size,
+                    symmetric(&min, &max),
+                ))
+            }
+            _ => unreachable!("the scheme is validated"),
+        };
+
+        Ok(strategy)
+    }
+
+    /// Returns the quantization data type of the scheme.
+    pub fn dtype(&self) -> QuantizationType {
+        match self {
+            QuantizationScheme::PerTensorAffine(dtype)
+            | QuantizationScheme::PerTensorSymmetric(dtype)
+            | QuantizationScheme::PerChannelAffine(dtype, _)
+            | QuantizationScheme::PerChannelSymmetric(dtype, _)
+            | QuantizationScheme::PerGroupAffine(dtype, _)
+            | QuantizationScheme::PerGroupSymmetric(dtype, _) => *dtype,
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn strategy_should_reject_symmetric_unsigned() {
+        let scheme = QuantizationScheme::PerTensorSymmetric(QuantizationType::QUInt8);
+
+        assert_eq!(
+            scheme.strategy(&[-1.0], &[1.0], 4),
+            Err(QuantizationSchemeError::UnsignedSymmetric(
+                QuantizationType::QUInt8
+            ))
+        );
+    }
+
+    #[test]
+    fn strategy_should_reject_per_channel_4bit() {
+        let scheme = QuantizationScheme::PerChannelAffine(QuantizationType::QInt4, 0);
+
+        assert_eq!(
+            scheme.strategy(&[-1.0, -2.0], &[1.0, 2.0], 4),
+            Err(QuantizationSchemeError::PerChannel4Bit(
+                QuantizationType::QInt4
+            ))
+        );
+    }
+
+    #[test]
+    fn strategy_should_map_each_channel() {
+        let scheme = QuantizationScheme::PerChannelSymmetric(QuantizationType::QInt8, 1);
+
+        let strategy = scheme.strategy(&[-1.0, -2.0], &[1.0, 2.0], 4).unwrap();
+
+        assert_eq!(
+            strategy,
+            QuantizationStrategy::PerChannelSymmetricInt8(PerChannelQuantization::new(
+                1,
+                alloc::vec![
+                    SymmetricQuantization::new(-1.0, 1.0),
+                    SymmetricQuantization::new(-2.0, 2.0)
+                ]
+            ))
+        );
+    }
 }
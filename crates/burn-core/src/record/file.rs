@@ -1,9 +1,16 @@
-use super::{bin_config, PrecisionSettings, Recorder, RecorderError};
+use super::{
+    bin_config,
+    lazy::with_lazy_source,
+    sharded::{with_shard_reader, with_shard_writer, ShardIndex, ShardReader, ShardWriter},
+    PrecisionSettings, Recorder, RecorderError,
+};
 use burn_tensor::backend::Backend;
 use core::marker::PhantomData;
 use flate2::{read::GzDecoder, write::GzEncoder, Compression};
+use memmap2::Mmap;
 use serde::{de::DeserializeOwned, Serialize};
 use std::io::{BufReader, BufWriter};
+use std::sync::Arc;
 use std::{fs::File, path::PathBuf};
 
 /// Recorder trait specialized to save and load data to and from files.
@@ -12,6 +19,19 @@ pub trait FileRecorder<B: Backend>:
 {
     /// File extension of the format used by the recorder.
     fn file_extension() -> &'static str;
+
+    /// Removes the files of a record, e.g. an old checkpoint.
+    ///
+    /// Nothing is removed if the record does not exist.
+    fn remove_files(&self, mut file: PathBuf) -> Result<(), RecorderError> {
+        file.set_extension(Self::file_extension());
+
+        if file.exists() {
+            std::fs::remove_file(file).map_err(|err| RecorderError::Unknown(err.to_string()))?;
+        }
+
+        Ok(())
+    }
 }
 
 /// Default [file recorder](FileRecorder).
@@ -53,6 +73,70 @@ pub struct NamedMpkFileRecorder<S: PrecisionSettings> {
     _settings: PhantomData<S>,
 }
 
+/// File recorder using the [named msgpack](rmp_serde) format, which loads the parameters lazily
+/// from the memory mapped file.
+///
+/// Loading only indexes the tensors of the file instead of reading their values, so the
+/// parameters of the loaded record are [uninitialized](crate::module::Param::uninitialized).
+/// When the record is loaded into a module, each parameter is then materialized directly on the
+/// device of the module the first time it is used, one tensor at a time. This avoids holding
+/// the whole checkpoint in memory while the tensors are created, which is useful for large
+/// checkpoints.
+///
+/// The files are the same as the ones of the [NamedMpkFileRecorder], so they can be used
+/// interchangeably. Note that the file should not be modified while the parameters are loaded.
+#[derive(new, Debug, Default, Clone)]
+pub struct LazyNamedMpkFileRecorder<S: PrecisionSettings> {
+    _settings: PhantomData<S>,
+}
+
+/// The default maximum size of a shard of the [sharded file recorder](ShardedFileRecorder).
+pub const DEFAULT_MAX_SHARD_SIZE: u64 = 5 * 1024 * 1024 * 1024;
+
+/// File recorder that splits the tensors of a record across multiple shard files, which is useful
+/// for checkpoints that are too large to be handled as a single file.
+///
+/// The tensor values are written to shards of a [maximum size](Self::with_max_shard_size) next to
+/// a [pretty json](serde_json) index file, similar to the `model.safetensors.index.json` files of
+/// the Hugging Face Hub. For example, saving a record to `model` writes the index
+/// `model.index.json` and the shards `model-00001-of-00002.bin` and `model-00002-of-00002.bin`.
+///
+/// The index holds the record with the tensors replaced by their location in the shards, along
+/// with a `weight_map` mapping the [id](crate::module::ParamId) of each parameter to its shard.
+///
+/// Loading only reads the index: the shards are memory mapped when one of their tensors is
+/// deserialized, and the parameters are [loaded lazily](LazyNamedMpkFileRecorder), so the values
+/// are only read when the parameters are used.
+#[derive(Debug, Clone)]
+pub struct ShardedFileRecorder<S: PrecisionSettings> {
+    max_shard_size: u64,
+    _settings: PhantomData<S>,
+}
+
+impl<S: PrecisionSettings> ShardedFileRecorder<S> {
+    /// Creates a new sharded file recorder with the [default maximum shard size](DEFAULT_MAX_SHARD_SIZE).
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Sets the maximum size of a shard in bytes.
+    ///
+    /// A tensor larger than the maximum size is written to its own shard.
+    pub fn with_max_shard_size(mut self, max_shard_size: u64) -> Self {
+        self.max_shard_size = max_shard_size;
+        self
+    }
+}
+
+impl<S: PrecisionSettings> Default for ShardedFileRecorder<S> {
+    fn default() -> Self {
+        Self {
+            max_shard_size: DEFAULT_MAX_SHARD_SIZE,
+            _settings: PhantomData,
+        }
+    }
+}
+
 impl<S: PrecisionSettings, B: Backend> FileRecorder<B> for BinGzFileRecorder<S> {
     fn file_extension() -> &'static str {
         "bin.gz"
@@ -86,6 +170,12 @@ impl<S: PrecisionSettings, B: Backend> FileRecorder<B> for NamedMpkFileRecorder<
     }
 }
 
+impl<S: PrecisionSettings, B: Backend> FileRecorder<B> for LazyNamedMpkFileRecorder<S> {
+    fn file_extension() -> &'static str {
+        "mpk"
+    }
+}
+
 macro_rules! str2reader {
     (
         $file:expr
@@ -297,6 +387,106 @@ impl<S: PrecisionSettings, B: Backend> Recorder<B> for NamedMpkFileRecorder<S> {
     }
 }
 
+impl<S: PrecisionSettings, B: Backend> Recorder<B> for LazyNamedMpkFileRecorder<S> {
+    type Settings = S;
+    type RecordArgs = PathBuf;
+    type RecordOutput = ();
+    type LoadArgs = PathBuf;
+
+    fn save_item<I: Serialize>(
+        &self,
+        item: I,
+        mut file: Self::RecordArgs,
+    ) -> Result<(), RecorderError> {
+        let mut writer = str2writer!(file)?;
+
+        rmp_serde::encode::write_named(&mut writer, &item)
+            .map_err(|err| RecorderError::Unknown(err.to_string()))?;
+
+        Ok(())
+    }
+
+    fn load_item<I: DeserializeOwned>(&self, mut file: Self::LoadArgs) -> Result<I, RecorderError> {
+        let file = str2reader!(file)?.into_inner();
+        // SAFETY: the file should not be modified while it is mapped, which is the case of any
+        // memory mapped file loader.
+        let mmap =
+            unsafe { Mmap::map(&file) }.map_err(|err| RecorderError::Unknown(err.to_string()))?;
+
+        // The tensor values are borrowed from the mapped file instead of being copied
+        let state = with_lazy_source(Arc::new(mmap), |bytes| rmp_serde::from_slice(bytes))
+            .map_err(|err| RecorderError::Unknown(err.to_string()))?;
+
+        Ok(state)
+    }
+}
+
+impl<S: PrecisionSettings, B: Backend> FileRecorder<B> for ShardedFileRecorder<S> {
+    fn file_extension() -> &'static str {
+        "index.json"
+    }
+
+    fn remove_files(&self, mut file: PathBuf) -> Result<(), RecorderError> {
+        let reader = match str2reader!(file) {
+            Ok(reader) => reader,
+            Err(RecorderError::FileNotFound(_)) => return Ok(()),
+            Err(err) => return Err(err),
+        };
+        let index: ShardIndex = serde_json::from_reader(reader)
+            .map_err(|err| RecorderError::Unknown(err.to_string()))?;
+
+        for path in index.shard_paths(&file).into_iter().chain([file]) {
+            if path.exists() {
+                std::fs::remove_file(path)
+                    .map_err(|err| RecorderError::Unknown(err.to_string()))?;
+            }
+        }
+
+        Ok(())
+    }
+}
+
+impl<S: PrecisionSettings, B: Backend> Recorder<B> for ShardedFileRecorder<S> {
+    type Settings = S;
+    type RecordArgs = PathBuf;
+    type RecordOutput = ();
+    type LoadArgs = PathBuf;
+
+    fn save_item<I: Serialize>(
+        &self,
+        item: I,
+        mut file: Self::RecordArgs,
+    ) -> Result<(), RecorderError> {
+        let writer = str2writer!(file)?;
+
+        // The tensor values are written to the shards while the record is serialized
+        let shards = ShardWriter::new(&file, self.max_shard_size);
+        let (record, shards) = with_shard_writer(shards, || serde_json::to_value(&item));
+        let record = record.map_err(|err| RecorderError::Unknown(err.to_string()))?;
+        let index = shards
+            .finish(record)
+            .map_err(|err| RecorderError::Unknown(err.to_string()))?;
+
+        serde_json::to_writer_pretty(writer, &index)
+            .map_err(|err| RecorderError::Unknown(err.to_string()))?;
+
+        Ok(())
+    }
+
+    fn load_item<I: DeserializeOwned>(&self, mut file: Self::LoadArgs) -> Result<I, RecorderError> {
+        let reader = str2reader!(file)?;
+        let index: ShardIndex = serde_json::from_reader(reader)
+            .map_err(|err| RecorderError::Unknown(err.to_string()))?;
+
+        // Only the shards of the deserialized tensors are memory mapped
+        let shards = ShardReader::new(&file, index.weight_map);
+        let state = with_shard_reader(shards, || serde_json::from_value(index.record))
+            .map_err(|err| RecorderError::Unknown(err.to_string()))?;
+
+        Ok(state)
+    }
+}
+
 #[cfg(test)]
 mod tests {
 
@@ -309,6 +499,7 @@ mod tests {
             conv::{Conv2d, Conv2dConfig},
             Linear, LinearConfig,
         },
+        quantization::{MinMaxCalibration, QuantizationScheme, QuantizationType, Quantizer},
         record::{BinBytesRecorder, FullPrecisionSettings},
         TestBackend,
     };
@@ -352,18 +543,196 @@ mod tests {
         test_can_save_and_load(NamedMpkFileRecorder::<FullPrecisionSettings>::default())
     }
 
+    #[test]
+    fn test_can_save_and_load_quantized_int4_mpk_format() {
+        test_can_save_and_load_quantized(
+            QuantizationScheme::PerGroupAffine(QuantizationType::QInt4, 8),
+            "int4",
+        )
+    }
+
+    #[test]
+    fn test_can_save_and_load_quantized_uint8_mpk_format() {
+        test_can_save_and_load_quantized(
+            QuantizationScheme::PerTensorAffine(QuantizationType::QUInt8),
+            "uint8",
+        )
+    }
+
+    #[test]
+    fn test_can_save_and_load_lazy_mpk_format() {
+        let device = Default::default();
+        let path = file_path().with_file_name("burn_test_file_recorder_lazy");
+
+        test_can_save_and_load_model(
+            LazyNamedMpkFileRecorder::<FullPrecisionSettings>::default(),
+            create_model(&device),
+            path,
+        )
+    }
+
+    #[test]
+    fn test_lazy_mpk_format_should_materialize_params_when_used() {
+        let device = Default::default();
+        let model_before = create_model(&device);
+        let path = file_path().with_file_name("burn_test_file_recorder_lazy_params");
+        NamedMpkFileRecorder::<FullPrecisionSettings>::default()
+            .record(model_before.clone().into_record(), path.clone())
+            .unwrap();
+
+        let record: ModelRecord<TestBackend> =
+            LazyNamedMpkFileRecorder::<FullPrecisionSettings>::default()
+                .load(path, &device)
+                .unwrap();
+        let model_after = create_model(&device).load_record(record);
+
+        assert!(!model_after.linear1.weight.is_initialized());
+        model_after
+            .linear1
+            .weight
+            .val()
+            .into_data()
+            .assert_eq(&model_before.linear1.weight.val().into_data(), true);
+        assert!(model_after.linear1.weight.is_initialized());
+    }
+
+    #[test]
+    fn test_can_save_and_load_quantized_lazy_mpk_format() {
+        let device = Default::default();
+        let mut quantizer = Quantizer {
+            calibration: MinMaxCalibration {
+                scheme: QuantizationScheme::PerGroupSymmetric(QuantizationType::QInt4, 8),
+            },
+        };
+        let model = create_model(&device).quantize_weights(&mut quantizer);
+        let path = file_path().with_file_name("burn_test_file_recorder_lazy_int4");
+
+        test_can_save_and_load_model(
+            LazyNamedMpkFileRecorder::<FullPrecisionSettings>::default(),
+            model,
+            path,
+        )
+    }
+
+    #[test]
+    fn test_can_save_and_load_sharded_format() {
+        let device = Default::default();
+        let dir = tempfile::tempdir().unwrap();
+
+        test_can_save_and_load_model(
+            ShardedFileRecorder::<FullPrecisionSettings>::new().with_max_shard_size(1024),
+            create_model(&device),
+            dir.path().join("model"),
+        )
+    }
+
+    #[test]
+    fn test_sharded_format_should_split_tensors_by_size() {
+        let device = Default::default();
+        let dir = tempfile::tempdir().unwrap();
+        let model = create_model(&device);
+        let recorder =
+            ShardedFileRecorder::<FullPrecisionSettings>::new().with_max_shard_size(1024);
+        recorder
+            .record(model.clone().into_record(), dir.path().join("model"))
+            .unwrap();
+
+        let index_path = dir.path().join("model.index.json");
+        let index: ShardIndex = serde_json::from_reader(File::open(&index_path).unwrap()).unwrap();
+        // The conv2d tensors fit in the first shard, and each linear tensor has its own shard
+        assert_eq!(
+            index.weight_map[&model.linear1.weight.id.to_string()],
+            "model-00002-of-00003.bin"
+        );
+        let shards = index.shard_paths(&index_path);
+        assert_eq!(shards.len(), 3);
+        assert!(shards.iter().all(|shard| shard.exists()));
+
+        FileRecorder::<TestBackend>::remove_files(&recorder, dir.path().join("model")).unwrap();
+        assert!(!index_path.exists());
+        assert!(shards.iter().all(|shard| !shard.exists()));
+    }
+
+    #[test]
+    fn test_sharded_format_should_only_read_needed_shards() {
+        #[derive(Module, Debug)]
+        pub struct Head<B: Backend> {
+            linear1: Linear<B>,
+        }
+
+        let device = Default::default();
+        let dir = tempfile::tempdir().unwrap();
+        let model = create_model(&device);
+        let recorder =
+            ShardedFileRecorder::<FullPrecisionSettings>::new().with_max_shard_size(1024);
+        recorder
+            .record(model.clone().into_record(), dir.path().join("model"))
+            .unwrap();
+
+        // Remove the shards that only hold the conv2d tensors
+        let index_path = dir.path().join("model.index.json");
+        let index: ShardIndex = serde_json::from_reader(File::open(&index_path).unwrap()).unwrap();
+        let linear_shards = [
+            &model.linear1.weight.id,
+            &model.linear1.bias.as_ref().unwrap().id,
+        ]
+        .map(|id| dir.path().join(&index.weight_map[&id.to_string()]));
+        for shard in index.shard_paths(&index_path) {
+            if !linear_shards.contains(&shard) {
+                std::fs::remove_file(shard).unwrap();
+            }
+        }
+
+        let record: HeadRecord<TestBackend> =
+            recorder.load(dir.path().join("model"), &device).unwrap();
+        let head = Head {
+            linear1: LinearConfig::new(32, 32).init(&device),
+        }
+        .load_record(record);
+
+        head.linear1
+            .weight
+            .val()
+            .into_data()
+            .assert_eq(&model.linear1.weight.val().into_data(), true);
+    }
+
+    fn test_can_save_and_load_quantized(scheme: QuantizationScheme, name: &str) {
+        let device = Default::default();
+        let mut quantizer = Quantizer {
+            calibration: MinMaxCalibration { scheme },
+        };
+        let model = create_model(&device).quantize_weights(&mut quantizer);
+        let path = file_path().with_file_name(format!("burn_test_file_recorder_{name}"));
+
+        test_can_save_and_load_model(
+            NamedMpkFileRecorder::<FullPrecisionSettings>::default(),
+            model,
+            path,
+        )
+    }
+
     fn test_can_save_and_load<Recorder>(recorder: Recorder)
     where
         Recorder: FileRecorder<TestBackend>,
     {
         let device = Default::default();
-        let model_before = create_model(&device);
+        test_can_save_and_load_model(recorder, create_model(&device), file_path())
+    }
+
+    fn test_can_save_and_load_model<Recorder>(
+        recorder: Recorder,
+        model_before: Model<TestBackend>,
+        path: PathBuf,
+    ) where
+        Recorder: FileRecorder<TestBackend>,
+    {
+        let device = Default::default();
         recorder
-            .record(model_before.clone().into_record(), file_path())
+            .record(model_before.clone().into_record(), path.clone())
             .unwrap();
 
-        let model_after =
-            create_model(&device).load_record(recorder.load(file_path(), &device).unwrap());
+        let model_after = create_model(&device).load_record(recorder.load(path, &device).unwrap());
 
         let byte_recorder = BinBytesRecorder::<FullPrecisionSettings>::default();
         let model_bytes_before = byte_recorder
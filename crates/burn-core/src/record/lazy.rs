@@ -0,0 +1,190 @@
+use std::cell::RefCell;
+use std::sync::Arc;
+
+use burn_tensor::{DType, TensorData};
+use memmap2::Mmap;
+use serde::{de::Error, Deserialize, Serialize};
+
+thread_local! {
+    /// The memory mapped file of the record being deserialized, if it is loaded lazily.
+    static SOURCE: RefCell<Option<LazySource>> = const { RefCell::new(None) };
+}
+
+/// The memory mapped file of a record loaded lazily.
+#[derive(Clone)]
+pub(crate) enum LazySource {
+    /// The deserializer borrows the tensor values from the file.
+    Borrowed(Arc<Mmap>),
+    /// The tensors are serialized as their [location](MappedTensor) in the file.
+    Mapped(Arc<Mmap>),
+}
+
+/// Resets the source even if the deserialization panics.
+struct Guard;
+
+impl Drop for Guard {
+    fn drop(&mut self) {
+        SOURCE.with(|current| current.borrow_mut().take());
+    }
+}
+
+fn set_source(source: LazySource) -> Guard {
+    SOURCE.with(|current| *current.borrow_mut() = Some(source));
+    Guard
+}
+
+/// Deserializes the tensors of a record as [lazy tensor data](LazyTensorData) referencing the
+/// memory mapped file instead of copying their values.
+///
+/// The deserializer must borrow its bytes from the memory mapped file (e.g.
+/// [rmp_serde::from_slice]).
+pub(crate) fn with_lazy_source<T>(source: Arc<Mmap>, func: impl FnOnce(&[u8]) -> T) -> T {
+    let _guard = set_source(LazySource::Borrowed(source.clone()));
+
+    func(&source)
+}
+
+/// Deserializes the tensors of a record serialized as their [location](MappedTensor) in the
+/// memory mapped file, so their values are only read when the tensors are used.
+///
+/// This lets the readers of other file formats (e.g. Safetensors) load the tensors without
+/// copying their values. The tensors serialized with their values, e.g. after being transformed
+/// to match a module, are loaded as usual.
+pub fn with_mapped_source<T>(source: Arc<Mmap>, func: impl FnOnce() -> T) -> T {
+    let _guard = set_source(LazySource::Mapped(source));
+
+    func()
+}
+
+/// Returns the memory mapped file of the record being deserialized, if it is loaded lazily.
+pub(crate) fn lazy_source() -> Option<LazySource> {
+    SOURCE.with(|current| current.borrow().clone())
+}
+
+/// The location of the values of a tensor in a [memory mapped file](with_mapped_source), which is
+/// serialized in place of the tensor data.
+#[derive(new, Debug, Clone, Serialize, Deserialize)]
+pub struct MappedTensor {
+    /// The offset of the values in the file, in bytes.
+    pub offset: usize,
+    /// The length of the values, in bytes.
+    pub len: usize,
+    /// The shape of the tensor.
+    pub shape: Vec<usize>,
+    /// The data type of the values, stored in little endian.
+    pub dtype: DType,
+}
+
+/// A [mapped tensor](MappedTensor), or the fields of a [TensorData] serialized with its values.
+#[derive(Deserialize)]
+struct MappedTensorData {
+    offset: Option<usize>,
+    len: Option<usize>,
+    bytes: Option<Vec<u8>>,
+    shape: Vec<usize>,
+    dtype: DType,
+}
+
+/// Tensor data whose values are only read from the memory mapped file when it is materialized.
+#[derive(new, Clone)]
+pub(crate) struct LazyTensorData {
+    source: Arc<Mmap>,
+    offset: usize,
+    len: usize,
+    shape: Vec<usize>,
+    dtype: DType,
+}
+
+/// The fields of [TensorData] with the values borrowed from the deserializer.
+#[derive(Deserialize)]
+struct BorrowedTensorData<'a> {
+    #[serde(borrow)]
+    bytes: &'a [u8],
+    shape: Vec<usize>,
+    dtype: DType,
+}
+
+impl LazyTensorData {
+    /// Deserializes the location of the tensor values borrowed from the memory mapped source.
+    pub(crate) fn deserialize<'de, De>(
+        source: Arc<Mmap>,
+        deserializer: De,
+    ) -> Result<Self, De::Error>
+    where
+        De: serde::Deserializer<'de>,
+    {
+        let data = BorrowedTensorData::deserialize(deserializer)?;
+
+        let start = source.as_ptr() as usize;
+        let offset = (data.bytes.as_ptr() as usize)
+            .checked_sub(start)
+            .filter(|offset| offset + data.bytes.len() <= source.len())
+            .ok_or_else(|| De::Error::custom("Tensor values are not borrowed from the source"))?;
+
+        Ok(Self {
+            offset,
+            len: data.bytes.len(),
+            shape: data.shape,
+            dtype: data.dtype,
+            source,
+        })
+    }
+
+    /// Deserializes a [mapped tensor](MappedTensor), or returns the tensor data when it is
+    /// serialized with its values.
+    pub(crate) fn deserialize_mapped<'de, De>(
+        source: Arc<Mmap>,
+        deserializer: De,
+    ) -> Result<Result<Self, TensorData>, De::Error>
+    where
+        De: serde::Deserializer<'de>,
+    {
+        let data = MappedTensorData::deserialize(deserializer)?;
+
+        match (data.offset, data.len, data.bytes) {
+            (Some(offset), Some(len), _) => {
+                if offset > source.len() || len > source.len() - offset {
+                    return Err(De::Error::custom(
+                        "Tensor values are out of the bounds of the source",
+                    ));
+                }
+
+                Ok(Ok(Self {
+                    source,
+                    offset,
+                    len,
+                    shape: data.shape,
+                    dtype: data.dtype,
+                }))
+            }
+            (_, _, Some(bytes)) => Ok(Err(TensorData {
+                bytes,
+                shape: data.shape,
+                dtype: data.dtype,
+            })),
+            _ => Err(De::Error::custom(
+                "Expected the location or the values of the tensor",
+            )),
+        }
+    }
+
+    /// Reads the tensor values from the memory mapped source.
+    pub(crate) fn read(&self) -> TensorData {
+        TensorData {
+            bytes: self.source[self.offset..self.offset + self.len].to_vec(),
+            shape: self.shape.clone(),
+            dtype: self.dtype,
+        }
+    }
+}
+
+impl core::fmt::Debug for LazyTensorData {
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        f.debug_struct("LazyTensorData")
+            .field("offset", &self.offset)
+            .field("len", &self.len)
+            .field("shape", &self.shape)
+            .field("dtype", &self.dtype)
+            .finish()
+    }
+}
@@ -14,7 +14,13 @@ pub use settings::*;
 #[cfg(feature = "std")]
 mod file;
 #[cfg(feature = "std")]
+mod lazy;
+#[cfg(feature = "std")]
+mod sharded;
+#[cfg(feature = "std")]
 pub use file::*;
+#[cfg(feature = "std")]
+pub use lazy::{with_mapped_source, MappedTensor};
 
 pub use primitive::ParamSerde;
 
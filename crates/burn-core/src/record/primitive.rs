@@ -16,7 +16,7 @@ use burn_tensor::{backend::Backend, Bool, Element, Int, Tensor};
 use hashbrown::HashMap;
 use serde::{
     de::{Error, SeqAccess, Visitor},
-    ser::SerializeTuple,
+    ser::{SerializeStruct, SerializeTuple},
     Deserialize, Serialize,
 };
 
@@ -165,12 +165,30 @@ where
 }
 
 /// (De)serialize parameters into a clean format.
-#[derive(new, Debug, Clone, Serialize, Deserialize)]
+#[derive(new, Debug, Clone, Deserialize)]
 pub struct ParamSerde<T> {
     id: String,
     param: T,
 }
 
+impl<T: Serialize> Serialize for ParamSerde<T> {
+    fn serialize<Se>(&self, serializer: Se) -> Result<Se::Ok, Se::Error>
+    where
+        Se: serde::Serializer,
+    {
+        let mut state = serializer.serialize_struct("ParamSerde", 2)?;
+        state.serialize_field("id", &self.id)?;
+
+        // The tensor values are indexed by the parameter id when the record is sharded
+        #[cfg(feature = "std")]
+        super::sharded::with_param_id(&self.id, || state.serialize_field("param", &self.param))?;
+        #[cfg(not(feature = "std"))]
+        state.serialize_field("param", &self.param)?;
+
+        state.end()
+    }
+}
+
 impl<B, const D: usize> Record<B> for Param<Tensor<B, D>>
 where
     B: Backend,
@@ -183,6 +201,19 @@ where
     }
 
     fn from_item<S: PrecisionSettings>(item: Self::Item<S>, device: &B::Device) -> Self {
+        if item.param.is_lazy() {
+            // The tensor is materialized on the device of the module when the record is loaded.
+            let param = item.param;
+            return Param::uninitialized(
+                ParamId::from(item.id),
+                move |device, require_grad| {
+                    Tensor::from_item(param.clone(), device).set_require_grad(require_grad)
+                },
+                device.clone(),
+                true,
+            );
+        }
+
         Param::initialized(
             ParamId::from(item.id),
             Tensor::from_item(item.param, device).require_grad(), // Same behavior as when we create a new
@@ -203,6 +234,16 @@ where
     }
 
     fn from_item<S: PrecisionSettings>(item: Self::Item<S>, device: &B::Device) -> Self {
+        if item.param.is_lazy() {
+            let param = item.param;
+            return Param::uninitialized(
+                ParamId::from(item.id),
+                move |device, _require_grad| Tensor::from_item(param.clone(), device),
+                device.clone(),
+                false,
+            );
+        }
+
         Param::initialized(
             ParamId::from(item.id),
             Tensor::from_item(item.param, device),
@@ -222,6 +263,16 @@ where
     }
 
     fn from_item<S: PrecisionSettings>(item: Self::Item<S>, device: &B::Device) -> Self {
+        if item.param.is_lazy() {
+            let param = item.param;
+            return Param::uninitialized(
+                ParamId::from(item.id),
+                move |device, _require_grad| Tensor::from_item::<S>(param.clone(), device),
+                device.clone(),
+                false,
+            );
+        }
+
         Param::initialized(
             ParamId::from(item.id),
             Tensor::from_item::<S>(item.param, device),
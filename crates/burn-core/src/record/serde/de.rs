@@ -250,22 +250,23 @@ impl<'de, A: BurnModuleAdapter> serde::Deserializer<'de> for Deserializer<A> {
         }
     }
 
-    fn deserialize_unit<V>(self, _visitor: V) -> Result<V::Value, Self::Error>
+    fn deserialize_unit<V>(self, visitor: V) -> Result<V::Value, Self::Error>
     where
         V: Visitor<'de>,
     {
-        unimplemented!("deserialize_unit is not implemented")
+        visitor.visit_unit()
     }
 
     fn deserialize_unit_struct<V>(
         self,
         _name: &'static str,
-        _visitor: V,
+        visitor: V,
     ) -> Result<V::Value, Self::Error>
     where
         V: Visitor<'de>,
     {
-        unimplemented!("deserialize_unit_struct is not implemented")
+        // Such as `PhantomData`, nothing to deserialize
+        visitor.visit_unit()
     }
 
     fn deserialize_newtype_struct<V>(
@@ -383,6 +384,7 @@ impl<'de, A: BurnModuleAdapter> serde::Deserializer<'de> for Deserializer<A> {
             let result = cloned_visitor.visit_enum(ProbeEnumAccess::<A>::new(
                 self.value.clone().unwrap(),
                 variant.to_owned(),
+                variants,
                 self.default_for_missing_fields,
             ));
 
@@ -625,15 +627,22 @@ where
 struct ProbeEnumAccess<A: BurnModuleAdapter> {
     value: NestedValue,
     current_variant: String,
+    variants: &'static [&'static str],
     default_for_missing_fields: bool,
     phantom: std::marker::PhantomData<A>,
 }
 
 impl<A: BurnModuleAdapter> ProbeEnumAccess<A> {
-    fn new(value: NestedValue, current_variant: String, default_for_missing_fields: bool) -> Self {
+    fn new(
+        value: NestedValue,
+        current_variant: String,
+        variants: &'static [&'static str],
+        default_for_missing_fields: bool,
+    ) -> Self {
         ProbeEnumAccess {
             value,
             current_variant,
+            variants,
             default_for_missing_fields,
             phantom: std::marker::PhantomData,
         }
@@ -666,28 +675,48 @@ where
     where
         T: DeserializeSeed<'de>,
     {
+        let value = match self.value {
+            // Newtype variants serialized with their variant name
+            NestedValue::Map(mut map)
+                if map.len() == 1 && self.variants.iter().any(|v| map.contains_key(*v)) =>
+            {
+                map.remove(&self.current_variant)
+                    .ok_or_else(|| Error::Other("Wrong variant".to_string()))?
+            }
+            value => value,
+        };
+
         let value = seed.deserialize(
-            NestedValueWrapper::<A>::new(self.value, self.default_for_missing_fields)
+            NestedValueWrapper::<A>::new(value, self.default_for_missing_fields)
                 .into_deserializer(),
         )?;
         Ok(value)
     }
 
     fn unit_variant(self) -> Result<(), Self::Error> {
-        // Support tensor `DType` deserialization
+        // Support unit variants serialized with their enum name, such as the tensor `DType` and
+        // its `QuantizationKind`
         match self.value {
-            NestedValue::Map(value) if value.contains_key("DType") => {
-                match value.get("DType") {
+            NestedValue::Map(value)
+                if value.len() == 1 && !self.variants.iter().any(|v| value.contains_key(*v)) =>
+            {
+                match value.into_values().next() {
                     Some(NestedValue::String(variant)) => {
-                        if *variant == self.current_variant {
+                        if variant == self.current_variant {
                             Ok(())
                         } else {
                             Err(Error::Other("Wrong variant".to_string())) // wrong match
                         }
                     }
-                    _ => panic!("expected DType variant as string"),
+                    _ => panic!("expected unit variant as string"),
                 }
             }
+            // Newtype variant serialized with its variant name
+            NestedValue::Map(value)
+                if value.len() == 1 && self.variants.iter().any(|v| value.contains_key(*v)) =>
+            {
+                Err(Error::Other("Wrong variant".to_string()))
+            }
             _ => unimplemented!(
                 "unit variant is not implemented because it is not used in the burn module"
             ),
@@ -921,8 +950,26 @@ impl<'de> serde::Deserializer<'de> for DefaultDeserializer {
         visitor.visit_map(DefaultMapAccess::new())
     }
 
+    fn deserialize_unit<V>(self, visitor: V) -> Result<V::Value, Self::Error>
+    where
+        V: Visitor<'de>,
+    {
+        visitor.visit_unit()
+    }
+
+    fn deserialize_unit_struct<V>(
+        self,
+        _name: &'static str,
+        visitor: V,
+    ) -> Result<V::Value, Self::Error>
+    where
+        V: Visitor<'de>,
+    {
+        visitor.visit_unit()
+    }
+
     forward_to_deserialize_any! {
-        u128 bytes byte_buf unit unit_struct newtype_struct
+        u128 bytes byte_buf newtype_struct
         enum identifier ignored_any
     }
 }
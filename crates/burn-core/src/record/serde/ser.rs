@@ -117,8 +117,8 @@ impl SerializerTrait for Serializer {
     fn serialize_u32(self, _v: u32) -> Result<Self::Ok, Self::Error> {
         unimplemented!()
     }
-    fn serialize_bool(self, _v: bool) -> Result<Self::Ok, Self::Error> {
-        unimplemented!()
+    fn serialize_bool(self, v: bool) -> Result<Self::Ok, Self::Error> {
+        Ok(NestedValue::Bool(v))
     }
 
     fn serialize_i8(self, _v: i8) -> Result<Self::Ok, Self::Error> {
@@ -137,11 +137,12 @@ impl SerializerTrait for Serializer {
     }
 
     fn serialize_unit(self) -> Result<Self::Ok, Self::Error> {
-        unimplemented!()
+        Ok(NestedValue::Default(None))
     }
 
     fn serialize_unit_struct(self, _name: &'static str) -> Result<Self::Ok, Self::Error> {
-        unimplemented!()
+        // Such as `PhantomData`, nothing to serialize
+        Ok(NestedValue::Default(None))
     }
 
     fn serialize_unit_variant(
@@ -160,13 +161,17 @@ impl SerializerTrait for Serializer {
         self,
         _name: &'static str,
         _variant_index: u32,
-        _variant: &'static str,
-        _value: &T,
+        variant: &'static str,
+        value: &T,
     ) -> Result<Self::Ok, Self::Error>
     where
         T: Serialize + ?Sized,
     {
-        unimplemented!()
+        // The variant name is kept to select the variant when deserializing
+        Ok(NestedValue::Map(HashMap::from([(
+            variant.to_string(),
+            value.serialize(Serializer::new())?,
+        )])))
     }
 
     fn serialize_tuple(self, _len: usize) -> Result<Self::SerializeTuple, Self::Error> {
@@ -385,4 +390,31 @@ mod tests {
         // 1.0f32 is represented with 4 bytes [0, 0, 128, 63]
         assert_eq!(serialized_str.len(), 140);
     }
+
+    #[test]
+    fn test_quantized_data_serde() {
+        use crate::record::serde::{adapter::DefaultAdapter, de::Deserializer};
+        use burn_tensor::{
+            PerGroupQuantization, Quantization, QuantizationStrategy, SymmetricQuantization,
+            TensorData,
+        };
+
+        let strategy = QuantizationStrategy::PerGroupSymmetricInt8(PerGroupQuantization::new(
+            2,
+            vec![
+                SymmetricQuantization::new(-1.0, 1.0),
+                SymmetricQuantization::new(-2.0, 2.0),
+            ],
+        ));
+        let data = TensorData::quantized(vec![-127i8, 0, 64, 127], [4], strategy);
+
+        let serialized = data
+            .serialize(Serializer::new())
+            .expect("Should serialize item successfully");
+        let deserialized =
+            TensorData::deserialize(Deserializer::<DefaultAdapter>::new(serialized, false))
+                .expect("Should deserialize item successfully");
+
+        assert_eq!(deserialized, data);
+    }
 }
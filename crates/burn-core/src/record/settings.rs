@@ -3,7 +3,7 @@ use serde::{de::DeserializeOwned, Serialize};
 
 /// Settings allowing to control the precision when (de)serializing items.
 pub trait PrecisionSettings:
-    Send + Sync + core::fmt::Debug + core::default::Default + Clone
+    Send + Sync + core::fmt::Debug + core::default::Default + Clone + 'static
 {
     /// Float element type.
     type FloatElem: Element + Serialize + DeserializeOwned;
@@ -0,0 +1,347 @@
+use std::cell::RefCell;
+use std::collections::{BTreeMap, HashMap};
+use std::fs::File;
+use std::io::{self, BufWriter, Write};
+use std::path::{Path, PathBuf};
+use std::sync::Arc;
+use std::thread::LocalKey;
+
+use burn_tensor::{DType, TensorData};
+use memmap2::Mmap;
+use serde::{de::Error, Deserialize, Serialize};
+
+use super::lazy::LazyTensorData;
+
+/// File extension of the shards.
+const SHARD_EXTENSION: &str = "bin";
+
+thread_local! {
+    /// The shards the tensor values of the record being serialized are written to.
+    static WRITER: RefCell<Option<ShardWriter>> = const { RefCell::new(None) };
+
+    /// The shards the tensor values of the record being deserialized are read from.
+    static READER: RefCell<Option<ShardReader>> = const { RefCell::new(None) };
+}
+
+/// Resets a thread local context even if the (de)serialization panics.
+struct Reset<T: 'static>(&'static LocalKey<RefCell<Option<T>>>);
+
+impl<T> Drop for Reset<T> {
+    fn drop(&mut self) {
+        self.0.with(|current| current.borrow_mut().take());
+    }
+}
+
+/// Serializes the tensors of a record as [references](TensorRef) to their values, which are
+/// written to the shards instead.
+///
+/// Returns the writer once the serialization is done, so the shards can be
+/// [finished](ShardWriter::finish).
+pub(crate) fn with_shard_writer<T>(
+    writer: ShardWriter,
+    func: impl FnOnce() -> T,
+) -> (T, ShardWriter) {
+    WRITER.with(|current| *current.borrow_mut() = Some(writer));
+    let _reset = Reset(&WRITER);
+
+    let output = func();
+    let writer = WRITER
+        .with(|current| current.borrow_mut().take())
+        .expect("The shard writer should be set during the serialization");
+
+    (output, writer)
+}
+
+/// Deserializes the tensors of a record as [lazy tensor data](LazyTensorData) referencing the
+/// memory mapped shards.
+pub(crate) fn with_shard_reader<T>(reader: ShardReader, func: impl FnOnce() -> T) -> T {
+    READER.with(|current| *current.borrow_mut() = Some(reader));
+    let _reset = Reset(&READER);
+
+    func()
+}
+
+/// Serializes the tensors of a parameter with its id as key in the [index](ShardIndex).
+pub(crate) fn with_param_id<T>(id: &str, func: impl FnOnce() -> T) -> T {
+    let is_writing = WRITER.with(|current| match current.borrow_mut().as_mut() {
+        Some(writer) => {
+            writer.param_id = Some(id.to_string());
+            true
+        }
+        None => false,
+    });
+
+    let output = func();
+
+    if is_writing {
+        WRITER.with(|current| {
+            if let Some(writer) = current.borrow_mut().as_mut() {
+                writer.param_id = None;
+            }
+        });
+    }
+
+    output
+}
+
+/// Whether the tensors of the record being serialized are written to shards.
+pub(crate) fn is_writing_shards() -> bool {
+    WRITER.with(|current| current.borrow().is_some())
+}
+
+/// Whether the tensors of the record being deserialized are read from shards.
+pub(crate) fn is_reading_shards() -> bool {
+    READER.with(|current| current.borrow().is_some())
+}
+
+/// Writes the tensor values to the current shard, returning the reference serialized in place of
+/// the tensor data.
+pub(crate) fn write_tensor(data: &TensorData) -> io::Result<TensorRef> {
+    WRITER.with(|current| match current.borrow_mut().as_mut() {
+        Some(writer) => writer.write(data),
+        None => Err(io::Error::other("No shard writer is set")),
+    })
+}
+
+/// Deserializes a tensor reference and locates its values in the memory mapped shard.
+pub(crate) fn read_tensor<'de, De>(deserializer: De) -> Result<LazyTensorData, De::Error>
+where
+    De: serde::Deserializer<'de>,
+{
+    let reference = TensorRef::deserialize(deserializer)?;
+
+    READER
+        .with(|current| match current.borrow_mut().as_mut() {
+            Some(reader) => reader.read(reference),
+            None => Err(io::Error::other("No shard reader is set")),
+        })
+        .map_err(De::Error::custom)
+}
+
+/// The location of the values of a tensor in the shards, which is serialized in place of the
+/// tensor data.
+#[derive(Serialize, Deserialize)]
+pub(crate) struct TensorRef {
+    key: String,
+    offset: usize,
+    len: usize,
+    shape: Vec<usize>,
+    dtype: DType,
+}
+
+/// The index of a sharded record, similar to the `model.safetensors.index.json` files of the
+/// Hugging Face Hub.
+#[derive(Serialize, Deserialize)]
+pub(crate) struct ShardIndex {
+    /// The metadata of the shards.
+    pub metadata: ShardMetadata,
+    /// The shard of each tensor, keyed by the [id](crate::module::ParamId) of its parameter.
+    pub weight_map: BTreeMap<String, String>,
+    /// The record, with the tensors serialized as [references](TensorRef) to the shards.
+    pub record: serde_json::Value,
+}
+
+/// The metadata of the shards of a record.
+#[derive(Serialize, Deserialize)]
+pub(crate) struct ShardMetadata {
+    /// The total size of the tensor values in bytes.
+    pub total_size: u64,
+}
+
+impl ShardIndex {
+    /// The paths of the shards of the record.
+    pub(crate) fn shard_paths(&self, index: &Path) -> Vec<PathBuf> {
+        let mut shards: Vec<_> = self.weight_map.values().collect();
+        shards.sort();
+        shards.dedup();
+
+        let directory = index.parent().unwrap_or(Path::new(""));
+        shards
+            .into_iter()
+            .map(|shard| directory.join(shard))
+            .collect()
+    }
+}
+
+/// A shard being written.
+struct Shard {
+    path: PathBuf,
+    writer: BufWriter<File>,
+    size: u64,
+}
+
+/// Writes the tensor values of a record to shards, starting a new shard when the current one
+/// would exceed the maximum shard size.
+pub(crate) struct ShardWriter {
+    directory: PathBuf,
+    name: String,
+    max_shard_size: u64,
+    shards: Vec<Shard>,
+    weight_map: BTreeMap<String, usize>,
+    param_id: Option<String>,
+}
+
+impl ShardWriter {
+    /// Creates a writer for the shards of the given index file, which are written next to it.
+    ///
+    /// The shards are named after the index file, e.g. `model-00001-of-00003.bin` for
+    /// `model.index.json`.
+    pub(crate) fn new(index: &Path, max_shard_size: u64) -> Self {
+        let directory = index.parent().unwrap_or(Path::new("")).to_path_buf();
+        // Remove the `.index.json` extension
+        let name = index
+            .with_extension("")
+            .with_extension("")
+            .file_name()
+            .map(|name| name.to_string_lossy().into_owned())
+            .unwrap_or_default();
+
+        Self {
+            directory,
+            name,
+            max_shard_size,
+            shards: Vec::new(),
+            weight_map: BTreeMap::new(),
+            param_id: None,
+        }
+    }
+
+    fn write(&mut self, data: &TensorData) -> io::Result<TensorRef> {
+        let len = data.bytes.len() as u64;
+
+        let is_full = match self.shards.last() {
+            Some(shard) => shard.size > 0 && shard.size + len > self.max_shard_size,
+            None => true,
+        };
+        if is_full {
+            let path = self.directory.join(format!(
+                "{}-{:05}.{SHARD_EXTENSION}",
+                self.name,
+                self.shards.len() + 1
+            ));
+            let writer = BufWriter::new(File::create(&path)?);
+            self.shards.push(Shard {
+                path,
+                writer,
+                size: 0,
+            });
+        }
+
+        let key = self.key();
+        let index = self.shards.len() - 1;
+        let shard = &mut self.shards[index];
+        shard.writer.write_all(&data.bytes)?;
+
+        let offset = shard.size as usize;
+        shard.size += len;
+        self.weight_map.insert(key.clone(), index);
+
+        Ok(TensorRef {
+            key,
+            offset,
+            len: data.bytes.len(),
+            shape: data.shape.clone(),
+            dtype: data.dtype,
+        })
+    }
+
+    /// The key of the next tensor, which is the id of its parameter if it is unique.
+    fn key(&self) -> String {
+        let count = self.weight_map.len();
+
+        match &self.param_id {
+            Some(id) if !self.weight_map.contains_key(id) => id.clone(),
+            Some(id) => format!("{id}.{count}"),
+            None => format!("tensor.{count}"),
+        }
+    }
+
+    /// Flushes the shards and names them with the total number of shards, returning the index of
+    /// the record.
+    pub(crate) fn finish(self, record: serde_json::Value) -> io::Result<ShardIndex> {
+        let num_shards = self.shards.len();
+        let mut names = Vec::with_capacity(num_shards);
+        let mut total_size = 0;
+
+        for (i, mut shard) in self.shards.into_iter().enumerate() {
+            shard.writer.flush()?;
+            total_size += shard.size;
+
+            let name = format!(
+                "{}-{:05}-of-{:05}.{SHARD_EXTENSION}",
+                self.name,
+                i + 1,
+                num_shards
+            );
+            std::fs::rename(&shard.path, self.directory.join(&name))?;
+            names.push(name);
+        }
+
+        let weight_map = self
+            .weight_map
+            .into_iter()
+            .map(|(key, index)| (key, names[index].clone()))
+            .collect();
+
+        Ok(ShardIndex {
+            metadata: ShardMetadata { total_size },
+            weight_map,
+            record,
+        })
+    }
+}
+
+/// Reads the tensor values of a record from the shards, which are only memory mapped when one of
+/// their tensors is deserialized.
+pub(crate) struct ShardReader {
+    directory: PathBuf,
+    weight_map: BTreeMap<String, String>,
+    shards: HashMap<String, Arc<Mmap>>,
+}
+
+impl ShardReader {
+    /// Creates a reader for the shards of the given index file.
+    pub(crate) fn new(index: &Path, weight_map: BTreeMap<String, String>) -> Self {
+        Self {
+            directory: index.parent().unwrap_or(Path::new("")).to_path_buf(),
+            weight_map,
+            shards: HashMap::new(),
+        }
+    }
+
+    fn read(&mut self, reference: TensorRef) -> io::Result<LazyTensorData> {
+        let name = self.weight_map.get(&reference.key).ok_or_else(|| {
+            io::Error::other(format!(
+                "Tensor {} is missing from the weight map",
+                reference.key
+            ))
+        })?;
+
+        let source = match self.shards.get(name) {
+            Some(source) => source.clone(),
+            None => {
+                let file = File::open(self.directory.join(name))?;
+                // SAFETY: the file should not be modified while it is mapped, which is the case
+                // of any memory mapped file loader.
+                let source = Arc::new(unsafe { Mmap::map(&file)? });
+                self.shards.insert(name.clone(), source.clone());
+                source
+            }
+        };
+
+        if reference.offset + reference.len > source.len() {
+            return Err(io::Error::other(format!(
+                "The values of tensor {} are out of the bounds of shard {name}",
+                reference.key
+            )));
+        }
+
+        Ok(LazyTensorData::new(
+            source,
+            reference.offset,
+            reference.len,
+            reference.shape,
+            reference.dtype,
+        ))
+    }
+}
@@ -4,6 +4,8 @@ use super::{PrecisionSettings, Record};
 use burn_tensor::{backend::Backend, Bool, DType, Element, Int, Tensor, TensorData};
 use serde::{Deserialize, Serialize};
 
+#[cfg(feature = "std")]
+use super::lazy::{LazySource, LazyTensorData};
 #[cfg(not(feature = "record-backward-compat"))]
 use alloc::format;
 #[cfg(feature = "record-backward-compat")]
@@ -43,35 +45,183 @@ where
                 e
             ))
         })?;
-        let data = if let DType::QFloat(_) = data.dtype {
-            data // do not convert quantized tensors
-        } else {
-            data.convert::<E>()
-        };
-        Ok(data)
+        Ok(convert_data::<E>(data))
+    }
+}
+
+/// Converts the data to the element type `E`, unless it is quantized.
+#[cfg(any(feature = "std", not(feature = "record-backward-compat")))]
+fn convert_data<E: Element>(data: TensorData) -> TensorData {
+    if let DType::QFloat(_) = data.dtype {
+        data // do not convert quantized tensors
+    } else {
+        data.convert::<E>()
+    }
+}
+
+/// Deserialize the value into [`TensorRecordData`], which references the memory mapped file
+/// instead of copying the values when the record is [loaded lazily](super::lazy) or
+/// [sharded](super::sharded).
+fn deserialize_record_data<'de, E, De>(deserializer: De) -> Result<TensorRecordData, De::Error>
+where
+    E: Element + Deserialize<'de>,
+    De: serde::Deserializer<'de>,
+{
+    #[cfg(feature = "std")]
+    if super::sharded::is_reading_shards() {
+        return super::sharded::read_tensor(deserializer).map(TensorRecordData::Lazy);
+    }
+
+    #[cfg(feature = "std")]
+    match super::lazy::lazy_source() {
+        Some(LazySource::Borrowed(source)) => {
+            return LazyTensorData::deserialize(source, deserializer).map(TensorRecordData::Lazy);
+        }
+        Some(LazySource::Mapped(source)) => {
+            return LazyTensorData::deserialize_mapped(source, deserializer).map(
+                |data| match data {
+                    Ok(lazy) => TensorRecordData::Lazy(lazy),
+                    Err(data) => TensorRecordData::Loaded(convert_data::<E>(data)),
+                },
+            );
+        }
+        None => {}
+    }
+
+    deserialize_data::<E, De>(deserializer).map(TensorRecordData::Loaded)
+}
+
+/// The values of a tensor record.
+#[derive(Clone, Debug)]
+enum TensorRecordData {
+    /// The values are loaded in memory.
+    Loaded(TensorData),
+    /// The values are read from a memory mapped file when the tensor is materialized.
+    #[cfg(feature = "std")]
+    Lazy(LazyTensorData),
+}
+
+impl TensorRecordData {
+    /// Returns the tensor data, converted to the element type `E` when it is read lazily.
+    fn into_data<E: Element>(self) -> TensorData {
+        match self {
+            Self::Loaded(data) => data,
+            #[cfg(feature = "std")]
+            Self::Lazy(lazy) => convert_data::<E>(lazy.read()),
+        }
+    }
+
+    fn is_lazy(&self) -> bool {
+        match self {
+            Self::Loaded(_) => false,
+            #[cfg(feature = "std")]
+            Self::Lazy(_) => true,
+        }
+    }
+
+    fn serialize<E: Element, Se: serde::Serializer>(
+        &self,
+        serializer: Se,
+    ) -> Result<Se::Ok, Se::Error> {
+        match self {
+            Self::Loaded(data) => serialize_data(data, serializer),
+            #[cfg(feature = "std")]
+            Self::Lazy(_) => serialize_data(&self.clone().into_data::<E>(), serializer),
+        }
     }
 }
 
+/// Serialize the [`TensorData`], or a reference to its values written to the current shard when
+/// the record is [sharded](super::sharded).
+fn serialize_data<Se: serde::Serializer>(
+    data: &TensorData,
+    serializer: Se,
+) -> Result<Se::Ok, Se::Error> {
+    #[cfg(feature = "std")]
+    if super::sharded::is_writing_shards() {
+        let reference = super::sharded::write_tensor(data).map_err(serde::ser::Error::custom)?;
+        return reference.serialize(serializer);
+    }
+
+    data.serialize(serializer)
+}
+
 /// This struct implements serde to lazily serialize and deserialize a float tensor
 /// using the given [record settings](RecordSettings).
-#[derive(new, Clone, Debug)]
+#[derive(Clone, Debug)]
 pub struct FloatTensorSerde<S: PrecisionSettings> {
-    data: TensorData,
+    data: TensorRecordData,
     _e: PhantomData<S::FloatElem>,
 }
 
 /// This struct implements serde to lazily serialize and deserialize an int tensor
 /// using the given [record settings](RecordSettings).
-#[derive(new, Clone, Debug)]
+#[derive(Clone, Debug)]
 pub struct IntTensorSerde<S: PrecisionSettings> {
-    data: TensorData,
+    data: TensorRecordData,
     _e: PhantomData<S::IntElem>,
 }
 
 /// This struct implements serde to lazily serialize and deserialize an bool tensor.
-#[derive(new, Clone, Debug)]
+#[derive(Clone, Debug)]
 pub struct BoolTensorSerde {
-    data: TensorData,
+    data: TensorRecordData,
+}
+
+impl<S: PrecisionSettings> FloatTensorSerde<S> {
+    /// Create a new float tensor record from its data.
+    pub fn new(data: TensorData) -> Self {
+        Self {
+            data: TensorRecordData::Loaded(data),
+            _e: PhantomData,
+        }
+    }
+
+    /// Whether the values are read from a memory mapped file when the tensor is materialized.
+    pub(crate) fn is_lazy(&self) -> bool {
+        self.data.is_lazy()
+    }
+
+    fn into_data(self) -> TensorData {
+        self.data.into_data::<S::FloatElem>()
+    }
+}
+
+impl<S: PrecisionSettings> IntTensorSerde<S> {
+    /// Create a new int tensor record from its data.
+    pub fn new(data: TensorData) -> Self {
+        Self {
+            data: TensorRecordData::Loaded(data),
+            _e: PhantomData,
+        }
+    }
+
+    /// Whether the values are read from a memory mapped file when the tensor is materialized.
+    pub(crate) fn is_lazy(&self) -> bool {
+        self.data.is_lazy()
+    }
+
+    fn into_data(self) -> TensorData {
+        self.data.into_data::<S::IntElem>()
+    }
+}
+
+impl BoolTensorSerde {
+    /// Create a new bool tensor record from its data.
+    pub fn new(data: TensorData) -> Self {
+        Self {
+            data: TensorRecordData::Loaded(data),
+        }
+    }
+
+    /// Whether the values are read from a memory mapped file when the tensor is materialized.
+    pub(crate) fn is_lazy(&self) -> bool {
+        self.data.is_lazy()
+    }
+
+    fn into_data(self) -> TensorData {
+        self.data.into_data::<bool>()
+    }
 }
 
 // --- SERDE IMPLEMENTATIONS --- //
@@ -81,7 +231,7 @@ impl<S: PrecisionSettings> Serialize for FloatTensorSerde<S> {
     where
         Se: serde::Serializer,
     {
-        self.data.serialize(serializer)
+        self.data.serialize::<S::FloatElem, Se>(serializer)
     }
 }
 
@@ -90,9 +240,12 @@ impl<'de, S: PrecisionSettings> Deserialize<'de> for FloatTensorSerde<S> {
     where
         De: serde::Deserializer<'de>,
     {
-        let data = deserialize_data::<S::FloatElem, De>(deserializer)?;
+        let data = deserialize_record_data::<S::FloatElem, De>(deserializer)?;
 
-        Ok(Self::new(data))
+        Ok(Self {
+            data,
+            _e: PhantomData,
+        })
     }
 }
 
@@ -101,7 +254,7 @@ impl<S: PrecisionSettings> Serialize for IntTensorSerde<S> {
     where
         Se: serde::Serializer,
     {
-        self.data.serialize(serializer)
+        self.data.serialize::<S::IntElem, Se>(serializer)
     }
 }
 
@@ -110,9 +263,12 @@ impl<'de, S: PrecisionSettings> Deserialize<'de> for IntTensorSerde<S> {
     where
         De: serde::Deserializer<'de>,
     {
-        let data = deserialize_data::<S::IntElem, De>(deserializer)?;
+        let data = deserialize_record_data::<S::IntElem, De>(deserializer)?;
 
-        Ok(Self::new(data))
+        Ok(Self {
+            data,
+            _e: PhantomData,
+        })
     }
 }
 
@@ -121,7 +277,7 @@ impl Serialize for BoolTensorSerde {
     where
         Se: serde::Serializer,
     {
-        self.data.serialize(serializer)
+        self.data.serialize::<bool, Se>(serializer)
     }
 }
 
@@ -130,9 +286,9 @@ impl<'de> Deserialize<'de> for BoolTensorSerde {
     where
         De: serde::Deserializer<'de>,
     {
-        let data = deserialize_data::<bool, De>(deserializer)?;
+        let data = deserialize_record_data::<bool, De>(deserializer)?;
 
-        Ok(Self::new(data))
+        Ok(Self { data })
     }
 }
 
@@ -152,10 +308,11 @@ impl<B: Backend, const D: usize> Record<B> for Tensor<B, D> {
     }
 
     fn from_item<S: PrecisionSettings>(item: Self::Item<S>, device: &B::Device) -> Self {
-        let data = if let DType::QFloat(_) = item.data.dtype {
-            item.data // do not convert quantized tensors
+        let data = item.into_data();
+        let data = if let DType::QFloat(_) = data.dtype {
+            data // do not convert quantized tensors
         } else {
-            item.data.convert::<B::FloatElem>()
+            data.convert::<B::FloatElem>()
         };
         Tensor::from_data(data, device)
     }
@@ -169,7 +326,7 @@ impl<B: Backend, const D: usize> Record<B> for Tensor<B, D, Int> {
     }
 
     fn from_item<S: PrecisionSettings>(item: Self::Item<S>, device: &B::Device) -> Self {
-        Tensor::from_data(item.data.convert::<B::IntElem>(), device)
+        Tensor::from_data(item.into_data().convert::<B::IntElem>(), device)
     }
 }
 
@@ -181,6 +338,6 @@ impl<B: Backend, const D: usize> Record<B> for Tensor<B, D, Bool> {
     }
 
     fn from_item<S: PrecisionSettings>(item: Self::Item<S>, device: &B::Device) -> Self {
-        Tensor::from_data(item.data, device)
+        Tensor::from_data(item.into_data(), device)
     }
 }
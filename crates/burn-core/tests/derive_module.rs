@@ -78,6 +78,24 @@ impl<B: Backend> ModuleComposed<B> {
     }
 }
 
+#[derive(Module, Debug)]
+struct ModuleSkipped<B: Backend> {
+    weight_basic: Param<Tensor<B, 2>>,
+    #[module(skip)]
+    skipped: ModuleBasic<B>,
+}
+
+impl<B: Backend> ModuleSkipped<B> {
+    fn new(device: &B::Device) -> Self {
+        let basic = ModuleBasic::new(device);
+
+        Self {
+            weight_basic: basic.weight_basic,
+            skipped: ModuleBasic::new(device),
+        }
+    }
+}
+
 mod state {
     use super::*;
 
@@ -151,6 +169,23 @@ mod state {
         );
     }
 
+    #[test]
+    fn should_load_from_record_without_skipped_fields() {
+        let device = <TestBackend as Backend>::Device::default();
+        let module_1 = ModuleSkipped::<TestBackend>::new(&device);
+        let module_2 = ModuleSkipped::<TestBackend>::new(&device);
+        let skipped = module_2.skipped.weight_basic.to_data();
+
+        let ModuleSkippedRecord { weight_basic } = module_1.clone().into_record();
+        let module_2 = module_2.load_record(ModuleSkippedRecord { weight_basic });
+
+        assert_eq!(
+            module_1.weight_basic.to_data(),
+            module_2.weight_basic.to_data()
+        );
+        assert_eq!(module_2.skipped.weight_basic.to_data(), skipped);
+    }
+
     #[test]
     #[should_panic(expected = "Can't parse record from a different variant")]
     fn should_panic_load_from_incorrect_enum_variant() {
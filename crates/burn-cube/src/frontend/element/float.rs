@@ -1,6 +1,8 @@
 use half::{bf16, f16};
 
-use crate::frontend::{Ceil, Cos, Erf, Exp, Floor, Log, Log1p, Powf, Recip, Sin, Sqrt, Tanh};
+use crate::frontend::{
+    Ceil, Cos, Erf, Exp, Floor, Log, Log1p, Powf, Recip, Round, Sin, Sqrt, Tanh, Trunc,
+};
 use crate::frontend::{CubeContext, CubePrimitive, CubeType, ExpandElement, Numeric};
 use crate::ir::{Elem, FloatKind, Item, Variable, Vectorization};
 
@@ -23,6 +25,8 @@ pub trait Float:
     + Sqrt
     + Floor
     + Ceil
+    + Round
+    + Trunc
     + Erf
     + Recip
     + core::ops::Index<UInt, Output = Self>
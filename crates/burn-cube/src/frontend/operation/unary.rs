@@ -97,6 +97,26 @@ impl_unary_func!(
     F32,
     F64
 );
+impl_unary_func!(
+    Round,
+    round,
+    __expand_round,
+    Operator::Round,
+    F16,
+    BF16,
+    F32,
+    F64
+);
+impl_unary_func!(
+    Trunc,
+    trunc,
+    __expand_trunc,
+    Operator::Trunc,
+    F16,
+    BF16,
+    F32,
+    F64
+);
 impl_unary_func!(Erf, erf, __expand_erf, Operator::Erf, F16, BF16, F32, F64);
 impl_unary_func!(
     Recip,
@@ -301,6 +301,18 @@ macro_rules! cpa {
             cpa!(unary $input, $out)
         ));
     };
+    // out = round(input)
+    ($scope:expr, $out:ident = round($input:expr)) => {
+        $scope.register($crate::ir::Operator::Round(
+            cpa!(unary $input, $out)
+        ));
+    };
+    // out = trunc(input)
+    ($scope:expr, $out:ident = trunc($input:expr)) => {
+        $scope.register($crate::ir::Operator::Trunc(
+            cpa!(unary $input, $out)
+        ));
+    };
     // out = erf(input)
     ($scope:expr, $out:ident = erf($input:expr)) => {
         $scope.register($crate::ir::Operator::Erf(
@@ -41,6 +41,8 @@ pub enum Operator {
     Sqrt(UnaryOperator),
     Floor(UnaryOperator),
     Ceil(UnaryOperator),
+    Round(UnaryOperator),
+    Trunc(UnaryOperator),
     Erf(UnaryOperator),
     Recip(UnaryOperator),
     Equal(BinaryOperator),
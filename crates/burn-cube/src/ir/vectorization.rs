@@ -41,6 +41,8 @@ impl Operator {
             Operator::Div(op) => Operator::Div(op.vectorize(vectorization)),
             Operator::Floor(op) => Operator::Floor(op.vectorize(vectorization)),
             Operator::Ceil(op) => Operator::Ceil(op.vectorize(vectorization)),
+            Operator::Round(op) => Operator::Round(op.vectorize(vectorization)),
+            Operator::Trunc(op) => Operator::Trunc(op.vectorize(vectorization)),
             Operator::Abs(op) => Operator::Abs(op.vectorize(vectorization)),
             Operator::Exp(op) => Operator::Exp(op.vectorize(vectorization)),
             Operator::Log(op) => Operator::Log(op.vectorize(vectorization)),
@@ -75,6 +75,16 @@ pub fn ceil_op<F: Float>(a: F) -> F {
     F::ceil(a)
 }
 
+#[cube]
+pub fn round_op<F: Float>(a: F) -> F {
+    F::round(a)
+}
+
+#[cube]
+pub fn trunc_op<F: Float>(a: F) -> F {
+    F::trunc(a)
+}
+
 #[cube]
 pub fn erf_op<F: Float>(a: F) -> F {
     F::erf(a)
@@ -287,6 +297,8 @@ mod tests {
     unary_test!(cube_can_recip, recip_op::__expand::<F32>, "Recip");
     unary_test!(cube_can_floor, floor_op::__expand::<F32>, "Floor");
     unary_test!(cube_can_ceil, ceil_op::__expand::<F32>, "Ceil");
+    unary_test!(cube_can_round, round_op::__expand::<F32>, "Round");
+    unary_test!(cube_can_trunc, trunc_op::__expand::<F32>, "Trunc");
     binary_test!(cube_can_eq, equal_op::__expand::<F32>, "Equal", ref_ops_cmp);
     binary_test!(
         cube_can_ne,
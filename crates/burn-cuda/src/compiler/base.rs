@@ -362,6 +362,8 @@ impl CudaCompiler {
             }),
             gpu::Operator::Floor(op) => Instruction::Floor(self.compile_unary(op)),
             gpu::Operator::Ceil(op) => Instruction::Ceil(self.compile_unary(op)),
+            gpu::Operator::Round(op) => Instruction::Round(self.compile_unary(op)),
+            gpu::Operator::Trunc(op) => Instruction::Trunc(self.compile_unary(op)),
             gpu::Operator::Remainder(_op) => todo!(),
             gpu::Operator::Fma(op) => Instruction::Fma {
                 a: self.compile_variable(op.a),
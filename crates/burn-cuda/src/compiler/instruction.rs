@@ -114,6 +114,8 @@ pub enum Instruction {
     SyncThreads,
     Ceil(UnaryInstruction),
     Floor(UnaryInstruction),
+    Round(UnaryInstruction),
+    Trunc(UnaryInstruction),
     Wrap(WarpInstruction),
     Wmma(WmmaInstruction),
 }
@@ -245,6 +247,8 @@ for (uint {i} = {start}; {i} < {end}; {i}++) {{
             Instruction::SyncThreads => f.write_str("__syncthreads();\n"),
             Instruction::Ceil(it) => Ceil::format(f, &it.input, &it.out),
             Instruction::Floor(it) => Floor::format(f, &it.input, &it.out),
+            Instruction::Round(it) => Round::format(f, &it.input, &it.out),
+            Instruction::Trunc(it) => Trunc::format(f, &it.input, &it.out),
             Instruction::SliceLength { input, out } => {
                 f.write_fmt(format_args!("{out} = {input}_length;\n"))
             }
@@ -169,6 +169,8 @@ function!(Exp, "exp");
 function!(Erf, "erff");
 function!(Ceil, "ceil");
 function!(Floor, "floor");
+function!(Round, "rint");
+function!(Trunc, "trunc");
 
 pub struct Not;
 
@@ -132,7 +132,7 @@ impl ModuleCodegen for StructModuleCodegen {
     }
 
     fn gen_into_record(&self) -> TokenStream {
-        let body = self.gen_fields_fn(|name| {
+        let body = self.gen_record_fields_fn(|name| {
             quote! {
                 #name: burn::module::Module::<B>::into_record(self.#name),
             }
@@ -148,16 +148,23 @@ impl ModuleCodegen for StructModuleCodegen {
     }
 
     fn gen_load_record(&self) -> TokenStream {
-        let body = self.gen_fields_fn(|name| {
+        let body = self.gen_record_fields_fn(|name| {
             quote! {
                 #name: burn::module::Module::<B>::load_record(self.#name, record.#name),
             }
         });
 
+        // The fields skipped from the record are kept as is.
+        let skipped = match self.fields.iter().any(|field| field.is_skipped()) {
+            true => quote! { ..self },
+            false => quote! {},
+        };
+
         quote! {
             fn load_record(self, record: Self::Record) -> Self {
                 Self {
                     #body
+                    #skipped
                 }
             }
         }
@@ -225,4 +232,18 @@ impl StructModuleCodegen {
 
         body
     }
+
+    /// Same as [gen_fields_fn](Self::gen_fields_fn), but only for the fields of the record.
+    fn gen_record_fields_fn<F>(&self, func: F) -> TokenStream
+    where
+        F: Fn(Ident) -> TokenStream,
+    {
+        let mut body = quote! {};
+
+        for field in self.fields.iter().filter(|field| !field.is_skipped()) {
+            body.extend(func(field.ident()));
+        }
+
+        body
+    }
 }
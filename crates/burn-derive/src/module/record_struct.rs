@@ -14,7 +14,7 @@ impl ModuleRecordCodegen for StructModuleRecordCodegen {
     fn gen_record_type(&self, record_name: &Ident, generics: &Generics) -> TokenStream {
         let mut fields = quote! {};
 
-        for field in self.fields.iter() {
+        for field in self.fields.iter().filter(|field| !field.is_skipped()) {
             let ty = &field.field.ty;
             let name = &field.field.ident;
 
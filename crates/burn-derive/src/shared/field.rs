@@ -80,6 +80,23 @@ impl FieldTypeAnalyzer {
             })
     }
 
+    /// Returns true if the field is annotated with `#[module(skip)]`, i.e. it is not part of the
+    /// module record.
+    pub fn is_skipped(&self) -> bool {
+        self.field.attrs.iter().any(|attr| {
+            attr.path().is_ident("module")
+                && attr
+                    .parse_nested_meta(|meta| {
+                        if meta.path.is_ident("skip") {
+                            Ok(())
+                        } else {
+                            Err(meta.error("unsupported attribute"))
+                        }
+                    })
+                    .is_ok()
+        })
+    }
+
     pub fn attributes(&self) -> impl Iterator<Item = AttributeAnalyzer> {
         self.field
             .attrs
@@ -2,7 +2,7 @@ use std::future::Future;
 
 use crate::{
     stream::{execution::Operation, StreamId},
-    FusionBackend, FusionDevice, FusionHandle, FusionRuntime, FusionTensor,
+    FusionBackend, FusionDevice, FusionHandle, FusionRuntime, FusionTensor, Trace,
 };
 use burn_tensor::{
     repr::{OperationDescription, TensorDescription, TensorId},
@@ -87,4 +87,11 @@ where
         B: FusionBackend<FusionRuntime = R>;
     /// Drop the tensor with the given [tensor id](TensorId).
     fn register_orphan(&self, id: &TensorId);
+    /// Start recording the operations and the tensors registered on this client, until
+    /// [end_trace](FusionClient::end_trace) is called.
+    ///
+    /// The operations registered from other threads on the same device are recorded as well.
+    fn start_trace(&self);
+    /// Stop recording and return the [trace](Trace), which is empty if no trace was started.
+    fn end_trace(&self) -> Trace<R>;
 }
@@ -1,10 +1,10 @@
 use super::FusionClient;
 use crate::{
     stream::{execution::Operation, StreamId},
-    FusionBackend, FusionDevice, FusionHandle, FusionRuntime, FusionServer, FusionTensor,
+    FusionBackend, FusionDevice, FusionHandle, FusionRuntime, FusionServer, FusionTensor, Trace,
 };
 use burn_tensor::{
-    repr::{OperationDescription, TensorDescription, TensorId},
+    repr::{OperationDescription, TensorDescription, TensorId, TensorStatus},
     DType,
 };
 use spin::Mutex;
@@ -72,6 +72,15 @@ where
     ) -> FusionTensor<R> {
         let mut server = self.server.lock();
         let id = server.create_empty_handle();
+        if let Some(trace) = server.trace.as_mut() {
+            let tensor = TensorDescription {
+                id: *id,
+                shape: shape.clone(),
+                status: TensorStatus::ReadOnly,
+                dtype,
+            };
+            trace.tensors.push((tensor, handle.clone()));
+        }
         server.handles.register_handle(*id.as_ref(), handle);
         core::mem::drop(server);
 
@@ -180,4 +189,12 @@ where
     fn register_orphan(&self, id: &TensorId) {
         self.server.lock().drop_tensor_handle(*id);
     }
+
+    fn start_trace(&self) {
+        self.server.lock().trace = Some(Trace::default());
+    }
+
+    fn end_trace(&self) -> Trace<R> {
+        self.server.lock().trace.take().unwrap_or_default()
+    }
 }
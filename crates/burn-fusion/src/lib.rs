@@ -19,6 +19,7 @@ mod fusion;
 mod ops;
 mod server;
 mod tensor;
+mod trace;
 
 pub(crate) use server::*;
 
@@ -26,3 +27,4 @@ pub use backend::*;
 pub use bridge::*;
 pub use fusion::*;
 pub use tensor::*;
+pub use trace::*;
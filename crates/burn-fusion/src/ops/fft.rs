@@ -0,0 +1,54 @@
+use crate::{client::FusionClient, stream::execution::Operation, Fusion, FusionBackend};
+use burn_tensor::{
+    ops::{FftOps, FloatTensor},
+    repr::*,
+    Element,
+};
+use std::marker::PhantomData;
+
+impl<B: FusionBackend> FftOps<Self> for Fusion<B> {
+    fn fft<const D: usize>(
+        re: FloatTensor<Self, D>,
+        im: FloatTensor<Self, D>,
+        dim: usize,
+        inverse: bool,
+    ) -> (FloatTensor<Self, D>, FloatTensor<Self, D>) {
+        #[derive(new)]
+        struct FourierOps<B: FusionBackend, const D: usize> {
+            desc: FftDescription,
+            _b: PhantomData<B>,
+        }
+
+        impl<const D: usize, B: FusionBackend> Operation<B::FusionRuntime> for FourierOps<B, D> {
+            fn execute(self: Box<Self>, handles: &mut HandleContainer<B::Handle>) {
+                let re = handles.get_float_tensor::<B, D>(&self.desc.re);
+                let im = handles.get_float_tensor::<B, D>(&self.desc.im);
+                let (out_re, out_im) = B::fft(re, im, self.desc.dim, self.desc.inverse);
+
+                handles.register_float_tensor::<B, D>(&self.desc.out_re.id, out_re);
+                handles.register_float_tensor::<B, D>(&self.desc.out_im.id, out_im);
+            }
+        }
+
+        let streams = vec![re.stream, im.stream];
+        let client = re.client.clone();
+        let out_re = client.tensor_uninitialized(re.shape.clone(), B::FloatElem::dtype());
+        let out_im = client.tensor_uninitialized(re.shape.clone(), B::FloatElem::dtype());
+
+        let desc = FftDescription {
+            re: re.into_description(),
+            im: im.into_description(),
+            dim,
+            inverse,
+            out_re: out_re.to_description_out(),
+            out_im: out_im.to_description_out(),
+        };
+        client.register(
+            streams,
+            OperationDescription::Float(FloatOperationDescription::Fft(desc.clone())),
+            FourierOps::<B, D>::new(desc),
+        );
+
+        (out_re, out_im)
+    }
+}
@@ -0,0 +1,285 @@
+use crate::{
+    binary_float_ops, client::FusionClient, stream::execution::Operation, unary_float_ops, Fusion,
+    FusionBackend,
+};
+use burn_tensor::{
+    ops::{FloatTensor, LinalgOps},
+    repr::*,
+    Element,
+};
+use std::marker::PhantomData;
+
+impl<B: FusionBackend> LinalgOps<Self> for Fusion<B> {
+    fn linalg_inv<const D: usize>(tensor: FloatTensor<Self, D>) -> FloatTensor<Self, D> {
+        unary_float_ops!(InvOps, B::linalg_inv);
+
+        let stream = tensor.stream;
+        let out = tensor
+            .client
+            .tensor_uninitialized(tensor.shape.clone(), B::FloatElem::dtype());
+
+        let desc = UnaryOperationDescription {
+            input: tensor.into_description(),
+            out: out.to_description_out(),
+        };
+        out.client.register(
+            vec![stream],
+            OperationDescription::Float(FloatOperationDescription::LinalgInv(desc.clone())),
+            InvOps::<B, D>::new(desc),
+        );
+
+        out
+    }
+
+    fn linalg_det<const D: usize>(tensor: FloatTensor<Self, D>) -> FloatTensor<Self, D> {
+        unary_float_ops!(DetOps, B::linalg_det);
+
+        let stream = tensor.stream;
+        let out = tensor
+            .client
+            .tensor_uninitialized(batch_shape(&tensor.shape, [1, 1]), B::FloatElem::dtype());
+
+        let desc = UnaryOperationDescription {
+            input: tensor.into_description(),
+            out: out.to_description_out(),
+        };
+        out.client.register(
+            vec![stream],
+            OperationDescription::Float(FloatOperationDescription::LinalgDet(desc.clone())),
+            DetOps::<B, D>::new(desc),
+        );
+
+        out
+    }
+
+    fn linalg_slogdet<const D: usize>(
+        tensor: FloatTensor<Self, D>,
+    ) -> (FloatTensor<Self, D>, FloatTensor<Self, D>) {
+        #[derive(new)]
+        struct SlogdetOps<B: FusionBackend, const D: usize> {
+            desc: LinalgSlogdetDescription,
+            _b: PhantomData<B>,
+        }
+
+        impl<const D: usize, B: FusionBackend> Operation<B::FusionRuntime> for SlogdetOps<B, D> {
+            fn execute(self: Box<Self>, handles: &mut HandleContainer<B::Handle>) {
+                let tensor = handles.get_float_tensor::<B, D>(&self.desc.tensor);
+                let (sign, logabsdet) = B::linalg_slogdet(tensor);
+
+                handles.register_float_tensor::<B, D>(&self.desc.sign.id, sign);
+                handles.register_float_tensor::<B, D>(&self.desc.logabsdet.id, logabsdet);
+            }
+        }
+
+        let stream = tensor.stream;
+        let client = tensor.client.clone();
+        let shape = batch_shape(&tensor.shape, [1, 1]);
+        let sign = client.tensor_uninitialized(shape.clone(), B::FloatElem::dtype());
+        let logabsdet = client.tensor_uninitialized(shape, B::FloatElem::dtype());
+
+        let desc = LinalgSlogdetDescription {
+            tensor: tensor.into_description(),
+            sign: sign.to_description_out(),
+            logabsdet: logabsdet.to_description_out(),
+        };
+        client.register(
+            vec![stream],
+            OperationDescription::Float(FloatOperationDescription::LinalgSlogdet(desc.clone())),
+            SlogdetOps::<B, D>::new(desc),
+        );
+
+        (sign, logabsdet)
+    }
+
+    fn linalg_solve<const D: usize>(
+        lhs: FloatTensor<Self, D>,
+        rhs: FloatTensor<Self, D>,
+    ) -> FloatTensor<Self, D> {
+        binary_float_ops!(SolveOps, B::linalg_solve);
+
+        let stream_1 = lhs.stream;
+        let stream_2 = rhs.stream;
+        let out = lhs
+            .client
+            .tensor_uninitialized(rhs.shape.clone(), B::FloatElem::dtype());
+
+        let desc = BinaryOperationDescription {
+            lhs: lhs.into_description(),
+            rhs: rhs.into_description(),
+            out: out.to_description_out(),
+        };
+        out.client.register(
+            vec![stream_1, stream_2],
+            OperationDescription::Float(FloatOperationDescription::LinalgSolve(desc.clone())),
+            SolveOps::<B, D>::new(desc),
+        );
+
+        out
+    }
+
+    fn linalg_cholesky<const D: usize>(tensor: FloatTensor<Self, D>) -> FloatTensor<Self, D> {
+        unary_float_ops!(CholeskyOps, B::linalg_cholesky);
+
+        let stream = tensor.stream;
+        let out = tensor
+            .client
+            .tensor_uninitialized(tensor.shape.clone(), B::FloatElem::dtype());
+
+        let desc = UnaryOperationDescription {
+            input: tensor.into_description(),
+            out: out.to_description_out(),
+        };
+        out.client.register(
+            vec![stream],
+            OperationDescription::Float(FloatOperationDescription::LinalgCholesky(desc.clone())),
+            CholeskyOps::<B, D>::new(desc),
+        );
+
+        out
+    }
+
+    fn linalg_qr<const D: usize>(
+        tensor: FloatTensor<Self, D>,
+    ) -> (FloatTensor<Self, D>, FloatTensor<Self, D>) {
+        #[derive(new)]
+        struct QrOps<B: FusionBackend, const D: usize> {
+            desc: LinalgQrDescription,
+            _b: PhantomData<B>,
+        }
+
+        impl<const D: usize, B: FusionBackend> Operation<B::FusionRuntime> for QrOps<B, D> {
+            fn execute(self: Box<Self>, handles: &mut HandleContainer<B::Handle>) {
+                let tensor = handles.get_float_tensor::<B, D>(&self.desc.tensor);
+                let (q, r) = B::linalg_qr(tensor);
+
+                handles.register_float_tensor::<B, D>(&self.desc.q.id, q);
+                handles.register_float_tensor::<B, D>(&self.desc.r.id, r);
+            }
+        }
+
+        let stream = tensor.stream;
+        let client = tensor.client.clone();
+        let [m, n] = matrix_dims(&tensor.shape);
+        let k = usize::min(m, n);
+        let q =
+            client.tensor_uninitialized(batch_shape(&tensor.shape, [m, k]), B::FloatElem::dtype());
+        let r =
+            client.tensor_uninitialized(batch_shape(&tensor.shape, [k, n]), B::FloatElem::dtype());
+
+        let desc = LinalgQrDescription {
+            tensor: tensor.into_description(),
+            q: q.to_description_out(),
+            r: r.to_description_out(),
+        };
+        client.register(
+            vec![stream],
+            OperationDescription::Float(FloatOperationDescription::LinalgQr(desc.clone())),
+            QrOps::<B, D>::new(desc),
+        );
+
+        (q, r)
+    }
+
+    fn linalg_svd<const D: usize>(
+        tensor: FloatTensor<Self, D>,
+    ) -> (
+        FloatTensor<Self, D>,
+        FloatTensor<Self, D>,
+        FloatTensor<Self, D>,
+    ) {
+        #[derive(new)]
+        struct SvdOps<B: FusionBackend, const D: usize> {
+            desc: LinalgSvdDescription,
+            _b: PhantomData<B>,
+        }
+
+        impl<const D: usize, B: FusionBackend> Operation<B::FusionRuntime> for SvdOps<B, D> {
+            fn execute(self: Box<Self>, handles: &mut HandleContainer<B::Handle>) {
+                let tensor = handles.get_float_tensor::<B, D>(&self.desc.tensor);
+                let (u, s, vh) = B::linalg_svd(tensor);
+
+                handles.register_float_tensor::<B, D>(&self.desc.u.id, u);
+                handles.register_float_tensor::<B, D>(&self.desc.s.id, s);
+                handles.register_float_tensor::<B, D>(&self.desc.vh.id, vh);
+            }
+        }
+
+        let stream = tensor.stream;
+        let client = tensor.client.clone();
+        let [m, n] = matrix_dims(&tensor.shape);
+        let k = usize::min(m, n);
+        let u =
+            client.tensor_uninitialized(batch_shape(&tensor.shape, [m, k]), B::FloatElem::dtype());
+        let s =
+            client.tensor_uninitialized(batch_shape(&tensor.shape, [1, k]), B::FloatElem::dtype());
+        let vh =
+            client.tensor_uninitialized(batch_shape(&tensor.shape, [k, n]), B::FloatElem::dtype());
+
+        let desc = LinalgSvdDescription {
+            tensor: tensor.into_description(),
+            u: u.to_description_out(),
+            s: s.to_description_out(),
+            vh: vh.to_description_out(),
+        };
+        client.register(
+            vec![stream],
+            OperationDescription::Float(FloatOperationDescription::LinalgSvd(desc.clone())),
+            SvdOps::<B, D>::new(desc),
+        );
+
+        (u, s, vh)
+    }
+
+    fn linalg_eigh<const D: usize>(
+        tensor: FloatTensor<Self, D>,
+    ) -> (FloatTensor<Self, D>, FloatTensor<Self, D>) {
+        #[derive(new)]
+        struct EighOps<B: FusionBackend, const D: usize> {
+            desc: LinalgEighDescription,
+            _b: PhantomData<B>,
+        }
+
+        impl<const D: usize, B: FusionBackend> Operation<B::FusionRuntime> for EighOps<B, D> {
+            fn execute(self: Box<Self>, handles: &mut HandleContainer<B::Handle>) {
+                let tensor = handles.get_float_tensor::<B, D>(&self.desc.tensor);
+                let (values, vectors) = B::linalg_eigh(tensor);
+
+                handles.register_float_tensor::<B, D>(&self.desc.values.id, values);
+                handles.register_float_tensor::<B, D>(&self.desc.vectors.id, vectors);
+            }
+        }
+
+        let stream = tensor.stream;
+        let client = tensor.client.clone();
+        let [_, n] = matrix_dims(&tensor.shape);
+        let values =
+            client.tensor_uninitialized(batch_shape(&tensor.shape, [1, n]), B::FloatElem::dtype());
+        let vectors = client.tensor_uninitialized(tensor.shape.clone(), B::FloatElem::dtype());
+
+        let desc = LinalgEighDescription {
+            tensor: tensor.into_description(),
+            values: values.to_description_out(),
+            vectors: vectors.to_description_out(),
+        };
+        client.register(
+            vec![stream],
+            OperationDescription::Float(FloatOperationDescription::LinalgEigh(desc.clone())),
+            EighOps::<B, D>::new(desc),
+        );
+
+        (values, vectors)
+    }
+}
+
+/// The sizes of the matrices stored in the last two dimensions.
+fn matrix_dims(shape: &[usize]) -> [usize; 2] {
+    let rank = shape.len();
+    [shape[rank - 2], shape[rank - 1]]
+}
+
+/// The shape of the batch of matrices with the given sizes.
+fn batch_shape(shape: &[usize], dims: [usize; 2]) -> Vec<usize> {
+    let mut shape = shape[..shape.len() - 2].to_vec();
+    shape.extend(dims);
+    shape
+}
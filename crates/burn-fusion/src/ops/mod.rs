@@ -1,8 +1,10 @@
 mod activation;
 mod binary;
 mod boolean;
+mod fft;
 mod float;
 mod int;
+mod linalg;
 mod module;
 mod qtensor;
 mod unary;
@@ -1,6 +1,6 @@
 use crate::{
     stream::{execution::Operation, MultiStream, StreamId},
-    FusionBackend, FusionRuntime,
+    FusionBackend, FusionRuntime, Trace,
 };
 use burn_tensor::repr::{HandleContainer, OperationDescription, TensorDescription, TensorId};
 use std::sync::Arc;
@@ -8,6 +8,7 @@ use std::sync::Arc;
 pub struct FusionServer<R: FusionRuntime> {
     streams: MultiStream<R>,
     pub(crate) handles: HandleContainer<R::FusionHandle>,
+    pub(crate) trace: Option<Trace<R>>,
 }
 
 impl<R> FusionServer<R>
@@ -18,6 +19,7 @@ where
         Self {
             streams: MultiStream::new(device.clone()),
             handles: HandleContainer::new(),
+            trace: None,
         }
     }
 
@@ -27,6 +29,10 @@ where
         desc: OperationDescription,
         operation: Box<dyn Operation<R>>,
     ) {
+        if let Some(trace) = self.trace.as_mut() {
+            trace.operations.push(desc.clone());
+        }
+
         self.streams
             .register(streams, desc, operation, &mut self.handles)
     }
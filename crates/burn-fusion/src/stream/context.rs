@@ -425,6 +425,108 @@ impl RelativeOps for FloatOperationDescription {
                     out: desc.out.to_relative(converter),
                 })
             }
+            FloatOperationDescription::Floor(desc) => {
+                FloatOperationDescription::Floor(UnaryOperationDescription {
+                    input: desc.input.to_relative(converter),
+                    out: desc.out.to_relative(converter),
+                })
+            }
+            FloatOperationDescription::Ceil(desc) => {
+                FloatOperationDescription::Ceil(UnaryOperationDescription {
+                    input: desc.input.to_relative(converter),
+                    out: desc.out.to_relative(converter),
+                })
+            }
+            FloatOperationDescription::Round(desc) => {
+                FloatOperationDescription::Round(UnaryOperationDescription {
+                    input: desc.input.to_relative(converter),
+                    out: desc.out.to_relative(converter),
+                })
+            }
+            FloatOperationDescription::Trunc(desc) => {
+                FloatOperationDescription::Trunc(UnaryOperationDescription {
+                    input: desc.input.to_relative(converter),
+                    out: desc.out.to_relative(converter),
+                })
+            }
+            FloatOperationDescription::Fmod(desc) => {
+                FloatOperationDescription::Fmod(BinaryOperationDescription {
+                    lhs: desc.lhs.to_relative(converter),
+                    rhs: desc.rhs.to_relative(converter),
+                    out: desc.out.to_relative(converter),
+                })
+            }
+            FloatOperationDescription::FmodScalar(desc) => {
+                FloatOperationDescription::FmodScalar(ScalarOperationDescription {
+                    lhs: desc.lhs.to_relative(converter),
+                    rhs: converter.relative_float(&desc.rhs),
+                    out: desc.out.to_relative(converter),
+                })
+            }
+            FloatOperationDescription::Fft(desc) => {
+                FloatOperationDescription::Fft(FftDescription {
+                    re: desc.re.to_relative(converter),
+                    im: desc.im.to_relative(converter),
+                    dim: desc.dim,
+                    inverse: desc.inverse,
+                    out_re: desc.out_re.to_relative(converter),
+                    out_im: desc.out_im.to_relative(converter),
+                })
+            }
+            FloatOperationDescription::LinalgInv(desc) => {
+                FloatOperationDescription::LinalgInv(UnaryOperationDescription {
+                    input: desc.input.to_relative(converter),
+                    out: desc.out.to_relative(converter),
+                })
+            }
+            FloatOperationDescription::LinalgDet(desc) => {
+                FloatOperationDescription::LinalgDet(UnaryOperationDescription {
+                    input: desc.input.to_relative(converter),
+                    out: desc.out.to_relative(converter),
+                })
+            }
+            FloatOperationDescription::LinalgSlogdet(desc) => {
+                FloatOperationDescription::LinalgSlogdet(LinalgSlogdetDescription {
+                    tensor: desc.tensor.to_relative(converter),
+                    sign: desc.sign.to_relative(converter),
+                    logabsdet: desc.logabsdet.to_relative(converter),
+                })
+            }
+            FloatOperationDescription::LinalgSolve(desc) => {
+                FloatOperationDescription::LinalgSolve(BinaryOperationDescription {
+                    lhs: desc.lhs.to_relative(converter),
+                    rhs: desc.rhs.to_relative(converter),
+                    out: desc.out.to_relative(converter),
+                })
+            }
+            FloatOperationDescription::LinalgCholesky(desc) => {
+                FloatOperationDescription::LinalgCholesky(UnaryOperationDescription {
+                    input: desc.input.to_relative(converter),
+                    out: desc.out.to_relative(converter),
+                })
+            }
+            FloatOperationDescription::LinalgQr(desc) => {
+                FloatOperationDescription::LinalgQr(LinalgQrDescription {
+                    tensor: desc.tensor.to_relative(converter),
+                    q: desc.q.to_relative(converter),
+                    r: desc.r.to_relative(converter),
+                })
+            }
+            FloatOperationDescription::LinalgSvd(desc) => {
+                FloatOperationDescription::LinalgSvd(LinalgSvdDescription {
+                    tensor: desc.tensor.to_relative(converter),
+                    u: desc.u.to_relative(converter),
+                    s: desc.s.to_relative(converter),
+                    vh: desc.vh.to_relative(converter),
+                })
+            }
+            FloatOperationDescription::LinalgEigh(desc) => {
+                FloatOperationDescription::LinalgEigh(LinalgEighDescription {
+                    tensor: desc.tensor.to_relative(converter),
+                    values: desc.values.to_relative(converter),
+                    vectors: desc.vectors.to_relative(converter),
+                })
+            }
         }
     }
 }
@@ -463,6 +565,27 @@ impl RelativeOps for IntOperationDescription {
                     out: desc.out.to_relative(converter),
                 })
             }
+            IntOperationDescription::FloorDiv(desc) => {
+                IntOperationDescription::FloorDiv(BinaryOperationDescription {
+                    lhs: desc.lhs.to_relative(converter),
+                    rhs: desc.rhs.to_relative(converter),
+                    out: desc.out.to_relative(converter),
+                })
+            }
+            IntOperationDescription::FloorDivScalar(desc) => {
+                IntOperationDescription::FloorDivScalar(ScalarOperationDescription {
+                    lhs: desc.lhs.to_relative(converter),
+                    rhs: converter.relative_int(&desc.rhs),
+                    out: desc.out.to_relative(converter),
+                })
+            }
+            IntOperationDescription::Remainder(desc) => {
+                IntOperationDescription::Remainder(BinaryOperationDescription {
+                    lhs: desc.lhs.to_relative(converter),
+                    rhs: desc.rhs.to_relative(converter),
+                    out: desc.out.to_relative(converter),
+                })
+            }
         }
     }
 }
@@ -781,6 +904,34 @@ impl<E: Element> RelativeOpsScalar<E> for NumericOperationDescription<E> {
                     out: desc.out.to_relative(converter),
                 })
             }
+            NumericOperationDescription::CumSum(desc) => {
+                NumericOperationDescription::CumSum(ScalarOperationDescription {
+                    lhs: desc.lhs.to_relative(converter),
+                    rhs: desc.rhs, // Dim should stay the same.
+                    out: desc.out.to_relative(converter),
+                })
+            }
+            NumericOperationDescription::CumProd(desc) => {
+                NumericOperationDescription::CumProd(ScalarOperationDescription {
+                    lhs: desc.lhs.to_relative(converter),
+                    rhs: desc.rhs, // Dim should stay the same.
+                    out: desc.out.to_relative(converter),
+                })
+            }
+            NumericOperationDescription::CumMax(desc) => {
+                NumericOperationDescription::CumMax(ScalarOperationDescription {
+                    lhs: desc.lhs.to_relative(converter),
+                    rhs: desc.rhs, // Dim should stay the same.
+                    out: desc.out.to_relative(converter),
+                })
+            }
+            NumericOperationDescription::CumMin(desc) => {
+                NumericOperationDescription::CumMin(ScalarOperationDescription {
+                    lhs: desc.lhs.to_relative(converter),
+                    rhs: desc.rhs, // Dim should stay the same.
+                    out: desc.out.to_relative(converter),
+                })
+            }
         }
     }
 }
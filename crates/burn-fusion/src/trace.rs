@@ -0,0 +1,40 @@
+use crate::{FusionHandle, FusionRuntime};
+use burn_tensor::repr::{OperationDescription, TensorDescription};
+
+/// The operations registered on a [fusion client](crate::client::FusionClient) between
+/// [start_trace](crate::client::FusionClient::start_trace) and
+/// [end_trace](crate::client::FusionClient::end_trace).
+///
+/// Since the outputs of the operations are always new tensors, the trace describes a
+/// computational graph where each tensor is identified by its [id](burn_tensor::repr::TensorId).
+pub struct Trace<R: FusionRuntime> {
+    /// The registered operations, in registration order.
+    pub operations: Vec<OperationDescription>,
+    /// The tensors created from a handle (e.g. from data), with their handle.
+    pub tensors: Vec<(TensorDescription, FusionHandle<R>)>,
+}
+
+impl<R: FusionRuntime> Default for Trace<R> {
+    fn default() -> Self {
+        Self {
+            operations: Vec::new(),
+            tensors: Vec::new(),
+        }
+    }
+}
+
+impl<R: FusionRuntime> core::fmt::Debug for Trace<R> {
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        f.debug_struct("Trace")
+            .field("operations", &self.operations)
+            .field(
+                "tensors",
+                &self
+                    .tensors
+                    .iter()
+                    .map(|(tensor, _)| tensor)
+                    .collect::<Vec<_>>(),
+            )
+            .finish()
+    }
+}
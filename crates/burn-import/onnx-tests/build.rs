@@ -1,4 +1,4 @@
-use burn_import::onnx::{ModelGen, RecordType};
+use burn_import::onnx::{ModelGen, Pass, RecordType};
 
 fn main() {
     // Re-run this build script if the onnx-tests directory changes.
@@ -88,9 +88,42 @@ fn main() {
         .input("tests/constant_of_shape/constant_of_shape.onnx")
         .input("tests/constant_of_shape/constant_of_shape_full_like.onnx")
         .input("tests/range/range.onnx")
+        .input("tests/if/if_else.onnx")
+        .input("tests/loop/loop_cond.onnx")
+        .input("tests/scan/scan.onnx")
+        .input("tests/lstm/lstm.onnx")
+        .input("tests/lstm/lstm_bidirectional.onnx")
+        .input("tests/gru/gru.onnx")
+        .input("tests/gru/gru_linear_before_reset.onnx")
+        .input("tests/rnn/rnn.onnx")
+        .input("tests/pad/pad.onnx")
+        .input("tests/split/split.onnx")
+        .input("tests/tile/tile.onnx")
+        .input("tests/top_k/top_k.onnx")
+        .input("tests/trilu/trilu.onnx")
+        .input("tests/one_hot/one_hot.onnx")
+        .input("tests/cumsum/cumsum.onnx")
+        .input("tests/nonzero/nonzero.onnx")
+        .input("tests/instance_norm/instance_norm.onnx")
+        .input("tests/group_norm/group_norm.onnx")
+        .input("tests/hard_sigmoid/hard_sigmoid.onnx")
+        .input("tests/hard_swish/hard_swish.onnx")
+        .input("tests/elu/elu.onnx")
+        .input("tests/selu/selu.onnx")
+        .input("tests/softplus/softplus.onnx")
+        .input("tests/dynamic_shape/dynamic_shape.onnx")
+        .input("tests/external_data/external_data.onnx")
+        .input("tests/opset_upgrade/opset_upgrade.onnx")
         .out_dir("model/")
         .run_from_script();
 
+    // Generate the model with the optimization passes of the ONNX graph.
+    ModelGen::new()
+        .input("tests/optimize/optimize.onnx")
+        .out_dir("model/")
+        .passes(&Pass::ALL)
+        .run_from_script();
+
     // The following tests are used to generate the model with different record types.
     // (e.g. bincode, pretty_json, etc.) Do not need to add new tests here, just use the default
     // record type to the ModelGen::new() call above.
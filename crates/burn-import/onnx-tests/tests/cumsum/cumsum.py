@@ -0,0 +1,46 @@
+#!/usr/bin/env python3
+
+# used to generate model: onnx-tests/tests/cumsum/cumsum.onnx
+
+import numpy as np
+import onnx
+from onnx import helper, numpy_helper, TensorProto
+from onnx.reference import ReferenceEvaluator
+
+
+def main():
+    initializers = [
+        numpy_helper.from_array(np.array(1, dtype=np.int64), 'axis'),
+        numpy_helper.from_array(np.array(-2, dtype=np.int64), 'axis_reverse'),
+    ]
+
+    graph_def = helper.make_graph(
+        nodes=[
+            helper.make_node('CumSum', inputs=['X', 'axis'], outputs=['Y']),
+            helper.make_node(
+                'CumSum', inputs=['X', 'axis_reverse'], outputs=['Z'], exclusive=1, reverse=1
+            ),
+        ],
+        name='CumSumGraph',
+        inputs=[helper.make_tensor_value_info('X', TensorProto.FLOAT, [2, 3])],
+        outputs=[
+            helper.make_tensor_value_info('Y', TensorProto.FLOAT, [2, 3]),
+            helper.make_tensor_value_info('Z', TensorProto.FLOAT, [2, 3]),
+        ],
+        initializer=initializers,
+    )
+
+    model_def = helper.make_model(
+        graph_def, producer_name='cumsum', opset_imports=[helper.make_opsetid('', 14)]
+    )
+
+    onnx.save(model_def, 'cumsum.onnx')
+
+    x = np.array([[1.0, 2.0, 3.0], [4.0, 5.0, 6.0]], dtype=np.float32)
+    y, z = ReferenceEvaluator(model_def).run(None, {'X': x})
+    print(f'Y: {y}')
+    print(f'Z: {z}')
+
+
+if __name__ == '__main__':
+    main()
@@ -0,0 +1,82 @@
+#!/usr/bin/env python3
+
+# used to generate model: onnx-tests/tests/dynamic_shape/dynamic_shape.onnx
+
+import numpy as np
+import onnx
+from onnx import helper, TensorProto
+from onnx.reference import ReferenceEvaluator
+
+
+def main():
+    nodes = [
+        helper.make_node('Shape', ['X'], ['S']),
+        helper.make_node('Gather', ['S', 'zero'], ['B']),
+        helper.make_node('Unsqueeze', ['B', 'axes'], ['B1']),
+        # Flatten all the dimensions but the batch
+        helper.make_node('Concat', ['B1', 'neg_one'], ['C1'], axis=0),
+        helper.make_node('Reshape', ['X', 'C1'], ['R']),
+        # Restore the shape from the batch and the sliced static dims
+        helper.make_node('Slice', ['S', 'one', 'three'], ['S2']),
+        helper.make_node('Concat', ['B1', 'S2'], ['C2'], axis=0),
+        helper.make_node('Reshape', ['R', 'C2'], ['X2']),
+        # Broadcast a constant to the input shape
+        helper.make_node(
+            'Constant', [], ['K'],
+            value=helper.make_tensor('k', TensorProto.FLOAT, [1, 1, 4], [1.0, 2.0, 3.0, 4.0]),
+        ),
+        helper.make_node('Expand', ['K', 'S'], ['E']),
+        # Drop the last column with a bound computed from the shape
+        helper.make_node('Gather', ['S', 'last'], ['L']),
+        helper.make_node('Sub', ['L', 'one_scalar'], ['L1']),
+        helper.make_node('Slice', ['X', 'zero_1d', 'L1', 'last'], ['SL']),
+        # Merge the batch with the rows
+        helper.make_node('Mul', ['B1', 'rows'], ['BR']),
+        helper.make_node(
+            'Constant', [], ['four'],
+            value=helper.make_tensor('four_value', TensorProto.INT64, [1], [4]),
+        ),
+        helper.make_node('Concat', ['BR', 'four'], ['C3'], axis=0),
+        helper.make_node('Reshape', ['X', 'C3'], ['M']),
+    ]
+
+    graph_def = helper.make_graph(
+        nodes=nodes,
+        name='DynamicShapeGraph',
+        inputs=[helper.make_tensor_value_info('X', TensorProto.FLOAT, ['batch', 3, 4])],
+        outputs=[
+            helper.make_tensor_value_info('R', TensorProto.FLOAT, ['batch', 12]),
+            helper.make_tensor_value_info('X2', TensorProto.FLOAT, ['batch', 3, 4]),
+            helper.make_tensor_value_info('E', TensorProto.FLOAT, ['batch', 3, 4]),
+            helper.make_tensor_value_info('SL', TensorProto.FLOAT, ['batch', 3, 3]),
+            helper.make_tensor_value_info('M', TensorProto.FLOAT, ['batch_rows', 4]),
+        ],
+        initializer=[
+            helper.make_tensor('zero', TensorProto.INT64, [], [0]),
+            helper.make_tensor('axes', TensorProto.INT64, [1], [0]),
+            helper.make_tensor('neg_one', TensorProto.INT64, [1], [-1]),
+            helper.make_tensor('one', TensorProto.INT64, [1], [1]),
+            helper.make_tensor('three', TensorProto.INT64, [1], [3]),
+            helper.make_tensor('last', TensorProto.INT64, [1], [-1]),
+            helper.make_tensor('one_scalar', TensorProto.INT64, [], [1]),
+            helper.make_tensor('zero_1d', TensorProto.INT64, [1], [0]),
+            helper.make_tensor('rows', TensorProto.INT64, [1], [3]),
+        ],
+    )
+
+    model_def = helper.make_model(
+        graph_def, producer_name='dynamic_shape', opset_imports=[helper.make_opsetid('', 13)]
+    )
+
+    onnx.save(model_def, 'dynamic_shape.onnx')
+
+    # The batch size is only known at runtime
+    for batch in [1, 3]:
+        x = np.arange(batch * 12, dtype=np.float32).reshape(batch, 3, 4)
+        outputs = ReferenceEvaluator(model_def).run(None, {'X': x})
+        for name, output in zip(['R', 'X2', 'E', 'SL', 'M'], outputs):
+            print(f'batch {batch}, {name}: {output.shape}')
+
+
+if __name__ == '__main__':
+    main()
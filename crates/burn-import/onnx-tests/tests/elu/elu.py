@@ -0,0 +1,31 @@
+#!/usr/bin/env python3
+
+# used to generate model: onnx-tests/tests/elu/elu.onnx
+
+import numpy as np
+import onnx
+from onnx import helper, TensorProto
+from onnx.reference import ReferenceEvaluator
+
+
+def main():
+    graph_def = helper.make_graph(
+        nodes=[helper.make_node('Elu', inputs=['X'], outputs=['Y'], alpha=0.5)],
+        name='EluGraph',
+        inputs=[helper.make_tensor_value_info('X', TensorProto.FLOAT, [2, 3])],
+        outputs=[helper.make_tensor_value_info('Y', TensorProto.FLOAT, [2, 3])],
+    )
+
+    model_def = helper.make_model(
+        graph_def, producer_name='elu', opset_imports=[helper.make_opsetid('', 6)]
+    )
+
+    onnx.save(model_def, 'elu.onnx')
+
+    x = np.array([[-4.0, -1.0, 0.0], [0.5, 2.0, 3.5]], dtype=np.float32)
+    (y,) = ReferenceEvaluator(model_def).run(None, {'X': x})
+    print(f'Y: {y}')
+
+
+if __name__ == '__main__':
+    main()
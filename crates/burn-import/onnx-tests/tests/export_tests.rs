@@ -0,0 +1,200 @@
+// This test suite verifies that the ONNX models exported from Burn modules give the same results as
+// the modules. It traces the forward pass of each module, exports it to an ONNX file, evaluates the
+// file with the interpreter on the same inputs, and compares the outputs.
+
+use burn::{
+    module::Module,
+    nn::{
+        conv::{Conv2d, Conv2dConfig},
+        pool::{AdaptiveAvgPool2d, AdaptiveAvgPool2dConfig, MaxPool2d, MaxPool2dConfig},
+        Linear, LinearConfig, PaddingConfig2d,
+    },
+    tensor::{activation, backend::Backend as BackendTrait, Distribution, Int, Tensor, TensorData},
+};
+use burn_import::onnx::{DynTensor, OnnxExportError, OnnxModel, OnnxTracer, Value};
+use std::sync::Mutex;
+
+type TracedBackend = burn_fusion::Fusion<burn_ndarray::NdArray<f32>>;
+type Backend = burn_ndarray::NdArray<f32>;
+
+#[derive(Module, Debug)]
+struct Mlp<B: BackendTrait> {
+    linear1: Linear<B>,
+    linear2: Linear<B>,
+}
+
+impl<B: BackendTrait> Mlp<B> {
+    fn new(device: &B::Device) -> Self {
+        Self {
+            linear1: LinearConfig::new(4, 8).init(device),
+            linear2: LinearConfig::new(8, 3).init(device),
+        }
+    }
+
+    fn forward(&self, input: Tensor<B, 2>) -> Tensor<B, 2> {
+        let x = activation::relu(self.linear1.forward(input));
+        activation::softmax(self.linear2.forward(x), 1)
+    }
+}
+
+#[derive(Module, Debug)]
+struct ConvNet<B: BackendTrait> {
+    conv: Conv2d<B>,
+    pool: MaxPool2d,
+    global_pool: AdaptiveAvgPool2d,
+    linear: Linear<B>,
+}
+
+impl<B: BackendTrait> ConvNet<B> {
+    fn new(device: &B::Device) -> Self {
+        Self {
+            conv: Conv2dConfig::new([2, 4], [3, 3])
+                .with_padding(PaddingConfig2d::Explicit(1, 1))
+                .init(device),
+            pool: MaxPool2dConfig::new([2, 2]).with_strides([2, 2]).init(),
+            global_pool: AdaptiveAvgPool2dConfig::new([1, 1]).init(),
+            linear: LinearConfig::new(4, 2).init(device),
+        }
+    }
+
+    fn forward(&self, input: Tensor<B, 4>) -> Tensor<B, 2> {
+        let x = activation::gelu(self.conv.forward(input));
+        let x = self.global_pool.forward(self.pool.forward(x));
+        self.linear.forward(x.flatten(1, 3))
+    }
+}
+
+/// The operations are traced on the default device for all the tests, so the tests are run one
+/// at a time.
+static TRACE_LOCK: Mutex<()> = Mutex::new(());
+
+/// Traces the forward pass, exports it and evaluates the exported file with the interpreter.
+///
+/// Returns the traced and interpreted outputs.
+fn export_and_interpret<const D: usize, const D2: usize>(
+    tracer: OnnxTracer<burn_ndarray::NdArray<f32>>,
+    forward: impl FnOnce(Tensor<TracedBackend, D>) -> Tensor<TracedBackend, D2>,
+    input: Tensor<TracedBackend, D>,
+) -> (TensorData, TensorData) {
+    let mut tracer = tracer;
+    let output = forward(input.clone());
+    tracer.input("input", &input).output("output", &output);
+
+    let file = tempfile::NamedTempFile::new().unwrap();
+    tracer.save(file.path()).unwrap();
+
+    let device = Default::default();
+    let model = OnnxModel::<Backend>::from_file(file.path(), &device)
+        .expect("Every exported operator should be supported");
+    let input = Value::Float(DynTensor::from_data(input.into_data(), &device));
+    let interpreted = model.forward(vec![input]).remove(0);
+
+    (output.into_data(), interpreted.into_data())
+}
+
+#[test]
+fn export_mlp() {
+    let _lock = TRACE_LOCK.lock().unwrap_or_else(|err| err.into_inner());
+    let device = Default::default();
+    let model = Mlp::<TracedBackend>::new(&device);
+    let input = Tensor::random([2, 4], Distribution::Default, &device);
+
+    let tracer = OnnxTracer::new(&model, &device);
+    let (output, interpreted) = export_and_interpret(tracer, |x| model.forward(x), input);
+
+    interpreted.assert_approx_eq(&output, 4);
+}
+
+#[test]
+fn export_conv_net() {
+    let _lock = TRACE_LOCK.lock().unwrap_or_else(|err| err.into_inner());
+    let device = Default::default();
+    let model = ConvNet::<TracedBackend>::new(&device);
+    let input = Tensor::random([1, 2, 6, 6], Distribution::Default, &device);
+
+    let tracer = OnnxTracer::new(&model, &device);
+    let (output, interpreted) = export_and_interpret(tracer, |x| model.forward(x), input);
+
+    interpreted.assert_approx_eq(&output, 4);
+}
+
+#[test]
+fn export_tensor_ops() {
+    let _lock = TRACE_LOCK.lock().unwrap_or_else(|err| err.into_inner());
+    let device = Default::default();
+    let model = Mlp::<TracedBackend>::new(&device);
+    let input = Tensor::<TracedBackend, 2>::random([3, 4], Distribution::Default, &device);
+
+    let forward = |x: Tensor<TracedBackend, 2>| {
+        // The constant is created during the trace, so it is exported as an initializer
+        let offset = Tensor::<TracedBackend, 2>::from_floats([[0.5, -0.5, 1.0, 2.0]], &device);
+        let y = x.clone().swap_dims(0, 1).matmul(x.clone()).cumsum(1);
+        let y = y.slice([0..4, 3..4]);
+        let z = (x.clone() + offset)
+            .exp()
+            .log()
+            .powf_scalar(2.0)
+            .clamp(0.1, 3.0);
+        let z = z.mask_fill(x.clone().greater_elem(0.5), 0.25);
+        let z = z.slice([0..2, 1..4]).mean_dim(0).reshape([3, 1]);
+        Tensor::cat(vec![y.slice([0..3, 0..1]), z], 0)
+            .mul_scalar(2.0)
+            .sqrt()
+    };
+
+    let tracer = OnnxTracer::new(&model, &device);
+    let (output, interpreted) = export_and_interpret(tracer, forward, input);
+
+    interpreted.assert_approx_eq(&output, 4);
+}
+
+#[test]
+fn export_names_parameters_by_path() {
+    let _lock = TRACE_LOCK.lock().unwrap_or_else(|err| err.into_inner());
+    let device = Default::default();
+    let model = Mlp::<TracedBackend>::new(&device);
+    let input = Tensor::<TracedBackend, 2>::zeros([1, 4], &device);
+
+    let mut tracer = OnnxTracer::new(&model, &device);
+    let output = model.forward(input.clone());
+    tracer.input("input", &input).output("output", &output);
+    let onnx = tracer.finish().unwrap();
+
+    let mut names = onnx
+        .graph
+        .initializer
+        .iter()
+        .map(|tensor| tensor.name.as_str())
+        .filter(|name| name.starts_with("linear"))
+        .collect::<Vec<_>>();
+    names.sort();
+
+    assert_eq!(
+        names,
+        vec![
+            "linear1.bias",
+            "linear1.weight",
+            "linear2.bias",
+            "linear2.weight"
+        ]
+    );
+}
+
+#[test]
+fn export_unsupported_operation() {
+    let _lock = TRACE_LOCK.lock().unwrap_or_else(|err| err.into_inner());
+    let device = Default::default();
+    let model = Mlp::<TracedBackend>::new(&device);
+    let input = Tensor::<TracedBackend, 2, Int>::zeros([2, 2], &device);
+
+    let mut tracer = OnnxTracer::new(&model, &device);
+    let output = input
+        .clone()
+        .slice_assign([0..1, 0..1], input.clone().slice([0..1, 0..1]));
+    tracer.input("input", &input).output("output", &output);
+
+    assert!(matches!(
+        tracer.finish(),
+        Err(OnnxExportError::UnsupportedOperation(_))
+    ));
+}
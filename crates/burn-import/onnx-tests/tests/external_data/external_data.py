@@ -0,0 +1,55 @@
+#!/usr/bin/env python3
+
+# used to generate model: onnx-tests/tests/external_data/external_data.onnx
+
+import numpy as np
+import onnx
+from onnx import helper, numpy_helper, TensorProto
+from onnx.external_data_helper import convert_model_to_external_data
+from onnx.reference import ReferenceEvaluator
+
+
+def main():
+    weight = numpy_helper.from_array(
+        (np.arange(12, dtype=np.float32) * 0.1).reshape(4, 3), name='weight'
+    )
+    bias = numpy_helper.from_array(np.array([0.1, 0.2, 0.3, 0.4], dtype=np.float32), name='bias')
+    offset = numpy_helper.from_array(
+        np.array([[1.0, 2.0, 3.0, 4.0]], dtype=np.float32), name='offset_value'
+    )
+
+    graph_def = helper.make_graph(
+        nodes=[
+            helper.make_node(
+                'Gemm', inputs=['X', 'weight', 'bias'], outputs=['Y'], alpha=1.0, beta=1.0, transB=1
+            ),
+            helper.make_node('Constant', inputs=[], outputs=['offset'], value=offset),
+            helper.make_node('Add', inputs=['Y', 'offset'], outputs=['Z']),
+        ],
+        name='main_graph',
+        inputs=[helper.make_tensor_value_info('X', TensorProto.FLOAT, [2, 3])],
+        outputs=[helper.make_tensor_value_info('Z', TensorProto.FLOAT, [2, 4])],
+        initializer=[weight, bias],
+    )
+
+    model_def = helper.make_model(
+        graph_def, producer_name='onnx-tests', opset_imports=[helper.make_opsetid('', 13)]
+    )
+
+    x = np.array([[1.0, 2.0, 3.0], [4.0, 5.0, 6.0]], dtype=np.float32)
+    (z,) = ReferenceEvaluator(model_def).run(None, {'X': x})
+    print(f'Z: {z}')
+
+    # Store all the tensors, including the constant attributes, in a file next to the model
+    convert_model_to_external_data(
+        model_def,
+        all_tensors_to_one_file=True,
+        location='external_data.onnx.data',
+        size_threshold=0,
+        convert_attribute=True,
+    )
+    onnx.save(model_def, 'external_data.onnx')
+
+
+if __name__ == '__main__':
+    main()
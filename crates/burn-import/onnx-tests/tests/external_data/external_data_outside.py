@@ -0,0 +1,26 @@
+#!/usr/bin/env python3
+
+# used to generate model: onnx-tests/tests/external_data/external_data_outside.onnx
+
+import onnx
+
+
+def main():
+    # Same model, but with the external data referenced through the parent directory, which must
+    # be rejected even though the file exists
+    model_def = onnx.load('external_data.onnx', load_external_data=False)
+
+    tensors = list(model_def.graph.initializer)
+    for node in model_def.graph.node:
+        tensors.extend(attr.t for attr in node.attribute if attr.HasField('t'))
+
+    for tensor in tensors:
+        for entry in tensor.external_data:
+            if entry.key == 'location':
+                entry.value = '../external_data/external_data.onnx.data'
+
+    onnx.save(model_def, 'external_data_outside.onnx')
+
+
+if __name__ == '__main__':
+    main()
@@ -0,0 +1,42 @@
+#!/usr/bin/env python3
+
+# used to generate model: onnx-tests/tests/group_norm/group_norm.onnx
+
+import numpy as np
+import onnx
+from onnx import helper, numpy_helper, TensorProto
+from onnx.reference import ReferenceEvaluator
+
+
+def main():
+    initializers = [
+        # One value per group, as defined by opset 18
+        numpy_helper.from_array(np.array([1.0, 2.0], dtype=np.float32), 'scale'),
+        numpy_helper.from_array(np.array([0.0, 1.0], dtype=np.float32), 'bias'),
+    ]
+
+    graph_def = helper.make_graph(
+        nodes=[
+            helper.make_node(
+                'GroupNormalization', inputs=['X', 'scale', 'bias'], outputs=['Y'], num_groups=2
+            )
+        ],
+        name='GroupNormalizationGraph',
+        inputs=[helper.make_tensor_value_info('X', TensorProto.FLOAT, [1, 4, 2])],
+        outputs=[helper.make_tensor_value_info('Y', TensorProto.FLOAT, [1, 4, 2])],
+        initializer=initializers,
+    )
+
+    model_def = helper.make_model(
+        graph_def, producer_name='group_norm', opset_imports=[helper.make_opsetid('', 18)]
+    )
+
+    onnx.save(model_def, 'group_norm.onnx')
+
+    x = np.array([[[0.0, 1.0], [2.0, 3.0], [4.0, 5.0], [6.0, 8.0]]], dtype=np.float32)
+    (y,) = ReferenceEvaluator(model_def).run(None, {'X': x})
+    print(f'Y: {y}')
+
+
+if __name__ == '__main__':
+    main()
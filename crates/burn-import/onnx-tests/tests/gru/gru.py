@@ -0,0 +1,76 @@
+#!/usr/bin/env python3
+
+# used to generate model: onnx-tests/tests/gru/gru.onnx
+
+import numpy as np
+import onnx
+from onnx import helper, numpy_helper, TensorProto
+from onnx.reference import ReferenceEvaluator
+
+SEQ_LENGTH, BATCH_SIZE, INPUT_SIZE, HIDDEN_SIZE = 3, 2, 2, 3
+NUM_GATES, NUM_DIRECTIONS = 3, 1
+
+
+def main():
+    # Deterministic weights, with the gates packed in the update, reset, hidden order
+    w = 0.5 * np.sin(np.arange(NUM_DIRECTIONS * NUM_GATES * HIDDEN_SIZE * INPUT_SIZE))
+    r = 0.5 * np.cos(np.arange(NUM_DIRECTIONS * NUM_GATES * HIDDEN_SIZE * HIDDEN_SIZE))
+    b = 0.1 * np.sin(np.arange(NUM_DIRECTIONS * 2 * NUM_GATES * HIDDEN_SIZE) + 1)
+    initializers = [
+        numpy_helper.from_array(
+            w.reshape(NUM_DIRECTIONS, NUM_GATES * HIDDEN_SIZE, INPUT_SIZE).astype(np.float32), 'W'
+        ),
+        numpy_helper.from_array(
+            r.reshape(NUM_DIRECTIONS, NUM_GATES * HIDDEN_SIZE, HIDDEN_SIZE).astype(np.float32), 'R'
+        ),
+        numpy_helper.from_array(
+            b.reshape(NUM_DIRECTIONS, 2 * NUM_GATES * HIDDEN_SIZE).astype(np.float32), 'B'
+        ),
+    ]
+
+    state_shape = [NUM_DIRECTIONS, BATCH_SIZE, HIDDEN_SIZE]
+    graph_def = helper.make_graph(
+        nodes=[
+            # The sequence is processed from its end, and the sequence lengths are omitted
+            helper.make_node(
+                'GRU',
+                inputs=['X', 'W', 'R', 'B', '', 'initial_h'],
+                outputs=['Y', 'Y_h'],
+                hidden_size=HIDDEN_SIZE,
+                direction='reverse',
+            ),
+        ],
+        name='GruGraph',
+        inputs=[
+            helper.make_tensor_value_info(
+                'X', TensorProto.FLOAT, [SEQ_LENGTH, BATCH_SIZE, INPUT_SIZE]
+            ),
+            helper.make_tensor_value_info('initial_h', TensorProto.FLOAT, state_shape),
+        ],
+        outputs=[
+            helper.make_tensor_value_info(
+                'Y', TensorProto.FLOAT, [SEQ_LENGTH, NUM_DIRECTIONS, BATCH_SIZE, HIDDEN_SIZE]
+            ),
+            helper.make_tensor_value_info('Y_h', TensorProto.FLOAT, state_shape),
+        ],
+        initializer=initializers,
+    )
+
+    model_def = helper.make_model(
+        graph_def, producer_name='gru', opset_imports=[helper.make_opsetid('', 14)]
+    )
+
+    onnx.save(model_def, 'gru.onnx')
+
+    x = np.array(
+        [[[0.1, 0.2], [-0.1, 0.4]], [[0.2, 0.1], [-0.2, 0.3]], [[0.3, 0.0], [-0.3, 0.2]]],
+        dtype=np.float32,
+    )
+    initial_h = np.array([[[0.1, 0.2, 0.3], [0.2, 0.4, 0.6]]], dtype=np.float32)
+    outputs = ReferenceEvaluator(model_def).run(None, {'X': x, 'initial_h': initial_h})
+    for name, output in zip(['Y', 'Y_h'], outputs):
+        print(f'{name}: {output.round(4)}')
+
+
+if __name__ == '__main__':
+    main()
@@ -0,0 +1,64 @@
+#!/usr/bin/env python3
+
+# used to generate model: onnx-tests/tests/if/if_else.onnx
+
+import onnx
+from onnx import helper, TensorProto
+
+def main():
+    # The branches use the input x of the enclosing graph
+    then_branch = helper.make_graph(
+        nodes=[
+            helper.make_node('Relu', inputs=['x'], outputs=['then_y1']),
+            helper.make_node('Identity', inputs=['x'], outputs=['then_y2']),
+        ],
+        name='then_branch',
+        inputs=[],
+        outputs=[
+            helper.make_tensor_value_info('then_y1', TensorProto.FLOAT, [2, 3]),
+            helper.make_tensor_value_info('then_y2', TensorProto.FLOAT, [2, 3]),
+        ],
+    )
+    else_branch = helper.make_graph(
+        nodes=[
+            helper.make_node('Neg', inputs=['x'], outputs=['else_y1']),
+            helper.make_node('Mul', inputs=['x', 'x'], outputs=['else_y2']),
+        ],
+        name='else_branch',
+        inputs=[],
+        outputs=[
+            helper.make_tensor_value_info('else_y1', TensorProto.FLOAT, [2, 3]),
+            helper.make_tensor_value_info('else_y2', TensorProto.FLOAT, [2, 3]),
+        ],
+    )
+
+    graph_def = helper.make_graph(
+        nodes=[
+            helper.make_node(
+                'If',
+                inputs=['cond'],
+                outputs=['y1', 'y2'],
+                then_branch=then_branch,
+                else_branch=else_branch,
+            ),
+            helper.make_node('Add', inputs=['y1', 'y2'], outputs=['sum']),
+            helper.make_node('Add', inputs=['sum', 'x'], outputs=['z']),
+        ],
+        name='IfGraph',
+        inputs=[
+            helper.make_tensor_value_info('x', TensorProto.FLOAT, [2, 3]),
+            helper.make_tensor_value_info('cond', TensorProto.BOOL, []),
+        ],
+        outputs=[
+            helper.make_tensor_value_info('z', TensorProto.FLOAT, [2, 3]),
+        ],
+    )
+
+    model_def = helper.make_model(
+        graph_def, producer_name='if_else', opset_imports=[helper.make_opsetid('', 16)]
+    )
+
+    onnx.save(model_def, 'if_else.onnx')
+
+if __name__ == '__main__':
+    main()
@@ -0,0 +1,39 @@
+#!/usr/bin/env python3
+
+# used to generate model: onnx-tests/tests/instance_norm/instance_norm.onnx
+
+import numpy as np
+import onnx
+from onnx import helper, numpy_helper, TensorProto
+from onnx.reference import ReferenceEvaluator
+
+
+def main():
+    initializers = [
+        numpy_helper.from_array(np.array([1.0, 2.0], dtype=np.float32), 'scale'),
+        numpy_helper.from_array(np.array([0.5, -0.5], dtype=np.float32), 'B'),
+    ]
+
+    graph_def = helper.make_graph(
+        nodes=[
+            helper.make_node('InstanceNormalization', inputs=['X', 'scale', 'B'], outputs=['Y'])
+        ],
+        name='InstanceNormalizationGraph',
+        inputs=[helper.make_tensor_value_info('X', TensorProto.FLOAT, [1, 2, 2, 2])],
+        outputs=[helper.make_tensor_value_info('Y', TensorProto.FLOAT, [1, 2, 2, 2])],
+        initializer=initializers,
+    )
+
+    model_def = helper.make_model(
+        graph_def, producer_name='instance_norm', opset_imports=[helper.make_opsetid('', 16)]
+    )
+
+    onnx.save(model_def, 'instance_norm.onnx')
+
+    x = np.array([[[[1.0, 2.0], [3.0, 4.0]], [[-1.0, 0.0], [2.0, 5.0]]]], dtype=np.float32)
+    (y,) = ReferenceEvaluator(model_def).run(None, {'X': x})
+    print(f'Y: {y}')
+
+
+if __name__ == '__main__':
+    main()
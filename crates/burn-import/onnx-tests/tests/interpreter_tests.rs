@@ -0,0 +1,1329 @@
+// This test suite verifies that the runtime interpreter gives the same results as the generated
+// models. It evaluates the ONNX files of the generated models with the interpreter on the same
+// inputs, and compares the outputs.
+
+macro_rules! include_models {
+    ($($model:ident),*) => {
+        $(
+            // Allow type complexity for generated code
+            #[allow(clippy::type_complexity)]
+            pub mod $model {
+                include!(concat!(env!("OUT_DIR"), concat!("/model/", stringify!($model), ".rs")));
+            }
+        )*
+    };
+}
+
+include_models!(
+    add,
+    add_int,
+    argmax,
+    avg_pool1d,
+    avg_pool2d,
+    batch_norm,
+    cast,
+    clip_opset16,
+    clip_opset7,
+    concat,
+    constant_of_shape,
+    constant_of_shape_full_like,
+    conv1d,
+    conv2d,
+    conv3d,
+    conv_transpose2d,
+    conv_transpose3d,
+    cos,
+    cumsum,
+    div,
+    dropout_opset16,
+    dropout_opset7,
+    dynamic_shape,
+    elu,
+    equal,
+    erf,
+    exp,
+    expand,
+    external_data,
+    flatten,
+    gather,
+    gather_elements,
+    gelu,
+    global_avr_pool,
+    greater,
+    greater_or_equal,
+    group_norm,
+    gru,
+    gru_linear_before_reset,
+    hard_sigmoid,
+    hard_swish,
+    if_else,
+    instance_norm,
+    layer_norm,
+    leaky_relu,
+    less,
+    less_or_equal,
+    linear,
+    log,
+    log_softmax,
+    loop_cond,
+    lstm,
+    lstm_bidirectional,
+    mask_where,
+    matmul,
+    max,
+    maxpool1d,
+    maxpool2d,
+    min,
+    mul,
+    neg,
+    nonzero,
+    not,
+    one_hot,
+    opset_upgrade,
+    optimize,
+    pad,
+    pow,
+    pow_int,
+    prelu,
+    random_normal,
+    random_uniform,
+    range,
+    recip,
+    reduce_max,
+    reduce_mean,
+    reduce_min,
+    reduce_prod,
+    reduce_sum_opset11,
+    reduce_sum_opset13,
+    relu,
+    reshape,
+    resize,
+    rnn,
+    scan,
+    selu,
+    shape,
+    sigmoid,
+    sign,
+    sin,
+    slice,
+    softmax,
+    softplus,
+    split,
+    sqrt,
+    squeeze_opset13,
+    squeeze_opset16,
+    sub,
+    sub_int,
+    sum,
+    sum_int,
+    tanh,
+    tile,
+    top_k,
+    transpose,
+    trilu,
+    unsqueeze,
+    unsqueeze_opset11,
+    unsqueeze_opset16
+);
+
+use burn::tensor::{Bool, Int, Tensor};
+use burn_import::onnx::{OnnxModel, OnnxModelError, OnnxParseError, Pass, Value};
+
+type Backend = burn_ndarray::NdArray<f32>;
+
+/// Evaluates the ONNX file of the model with the interpreter.
+fn interpret(file: &str, inputs: Vec<Value<Backend>>) -> Vec<Value<Backend>> {
+    let path = format!("tests/{file}");
+    let model = OnnxModel::<Backend>::from_file(path.as_ref(), &Default::default())
+        .expect("Every operator of the model should be supported");
+
+    model.forward(inputs)
+}
+
+/// Asserts that the interpreted output is the same as the generated one.
+fn assert_same(generated: impl Into<Value<Backend>>, interpreted: Value<Backend>) {
+    match (generated.into(), interpreted) {
+        (Value::Scalar(generated), Value::Scalar(interpreted)) => {
+            assert_eq!(generated, interpreted)
+        }
+        (Value::Shape(generated), Value::Shape(interpreted)) => {
+            assert_eq!(generated, interpreted)
+        }
+        (generated, interpreted) => interpreted
+            .into_data()
+            .assert_eq(&generated.into_data(), true),
+    }
+}
+
+#[test]
+fn add() {
+    let device = Default::default();
+    let model: add::Model<Backend> = add::Model::default();
+
+    let input = Tensor::<Backend, 4>::from_floats([[[[1., 2., 3., 4.]]]], &device);
+    let output = model.forward(input.clone(), 2.0);
+    let outputs = interpret("add/add.onnx", vec![input.into(), 2.0f64.into()]);
+
+    assert_same(output, outputs[0].clone());
+}
+
+#[test]
+fn add_int() {
+    let device = Default::default();
+    let model: add_int::Model<Backend> = add_int::Model::default();
+
+    let input = Tensor::<Backend, 4, Int>::from_ints([[[[1, 2, 3, 4]]]], &device);
+    let output = model.forward(input.clone(), 2);
+    let outputs = interpret("add/add_int.onnx", vec![input.into(), 2i64.into()]);
+
+    assert_same(output, outputs[0].clone());
+}
+
+#[test]
+fn sub() {
+    let device = Default::default();
+    let model: sub::Model<Backend> = sub::Model::default();
+
+    let input = Tensor::<Backend, 4>::from_floats([[[[1., 2., 3., 4.]]]], &device);
+    let output = model.forward(input.clone(), 3.0);
+    let outputs = interpret("sub/sub.onnx", vec![input.into(), 3.0f64.into()]);
+
+    assert_same(output, outputs[0].clone());
+}
+
+#[test]
+fn sum() {
+    let device = Default::default();
+    let model: sum::Model<Backend> = sum::Model::default();
+
+    let input = Tensor::<Backend, 1>::from_floats([1., 2., 3., 4.], &device);
+    let output = model.forward(input.clone(), input.clone(), input.clone());
+    let outputs = interpret(
+        "sum/sum.onnx",
+        vec![input.clone().into(), input.clone().into(), input.into()],
+    );
+
+    assert_same(output, outputs[0].clone());
+}
+
+#[test]
+fn pow_int() {
+    let device = Default::default();
+    let model: pow_int::Model<Backend> = pow_int::Model::new(&device);
+
+    let input = Tensor::<Backend, 4, Int>::from_ints([[[[1, 2, 3, 4]]]], &device);
+    let output = model.forward(input.clone(), 2);
+    let outputs = interpret("pow/pow_int.onnx", vec![input.into(), 2i64.into()]);
+
+    assert_same(output, outputs[0].clone());
+}
+
+#[test]
+fn equal() {
+    let device = Default::default();
+    let model: equal::Model<Backend> = equal::Model::default();
+
+    let input = Tensor::<Backend, 4>::from_floats([[[[1., 1., 1., 1.]]]], &device);
+    let (tensor_out, scalar_out) = model.forward(input.clone(), 2.0);
+    let outputs = interpret("equal/equal.onnx", vec![input.into(), 2.0f64.into()]);
+
+    assert_same(tensor_out, outputs[0].clone());
+    assert_same(scalar_out, outputs[1].clone());
+}
+
+#[test]
+fn cast() {
+    let device = Default::default();
+    let model: cast::Model<Backend> = cast::Model::new(&device);
+
+    let input_bool = Tensor::<Backend, 2, Bool>::from_bool([[true], [true]].into(), &device);
+    let input_int = Tensor::<Backend, 2, Int>::from_ints([[1], [1]], &device);
+    let input_float = Tensor::<Backend, 2>::from_floats([[1.], [1.]], &device);
+
+    let output = model.forward(
+        input_bool.clone(),
+        input_int.clone(),
+        input_float.clone(),
+        1.0,
+    );
+    let outputs = interpret(
+        "cast/cast.onnx",
+        vec![
+            input_bool.into(),
+            input_int.into(),
+            input_float.into(),
+            1.0f32.into(),
+        ],
+    );
+
+    assert_same(output.0, outputs[0].clone());
+    assert_same(output.1, outputs[1].clone());
+    assert_same(output.2, outputs[2].clone());
+    assert_same(output.3, outputs[3].clone());
+    assert_same(output.4, outputs[4].clone());
+    assert_same(output.5, outputs[5].clone());
+    assert_same(output.6, outputs[6].clone());
+    assert_same(output.7, outputs[7].clone());
+    assert_same(output.8, outputs[8].clone());
+    assert_same(output.9, outputs[9].clone());
+}
+
+#[test]
+fn clip() {
+    let device = Default::default();
+    let model: clip_opset16::Model<Backend> = clip_opset16::Model::new(&device);
+
+    let input = Tensor::<Backend, 1>::from_floats([0.88, 0.91, 0.38, 0.95, 0.39, 0.60], &device);
+    let (output1, output2, output3) = model.forward(input.clone());
+    let outputs = interpret("clip/clip_opset16.onnx", vec![input.into()]);
+
+    assert_same(output1, outputs[0].clone());
+    assert_same(output2, outputs[1].clone());
+    assert_same(output3, outputs[2].clone());
+}
+
+#[test]
+fn concat() {
+    let device = Default::default();
+    let model: concat::Model<Backend> = concat::Model::new(&device);
+
+    let input = Tensor::<Backend, 1, Int>::arange(0..30, &device)
+        .reshape([1, 2, 3, 5])
+        .float();
+    let output = model.forward(input.clone());
+    let outputs = interpret("concat/concat.onnx", vec![input.into()]);
+
+    assert_same(output, outputs[0].clone());
+}
+
+#[test]
+fn constant_of_shape() {
+    let device = Default::default();
+    let model = constant_of_shape::Model::<Backend>::new(&device);
+
+    let output = model.forward([2, 3, 2]);
+    let outputs = interpret(
+        "constant_of_shape/constant_of_shape.onnx",
+        vec![Value::Shape(vec![2, 3, 2])],
+    );
+
+    assert_same(output, outputs[0].clone());
+}
+
+#[test]
+fn conv2d() {
+    let device = Default::default();
+    let model: conv2d::Model<Backend> = conv2d::Model::default();
+
+    let input = Tensor::<Backend, 4>::ones([2, 4, 10, 15], &device);
+    let output = model.forward(input.clone());
+    let outputs = interpret("conv2d/conv2d.onnx", vec![input.into()]);
+
+    assert_same(output, outputs[0].clone());
+}
+
+#[test]
+fn batch_norm() {
+    let device = Default::default();
+    let model: batch_norm::Model<Backend> = batch_norm::Model::default();
+
+    let input = Tensor::<Backend, 3>::ones([1, 20, 1], &device);
+    let output = model.forward(input.clone());
+    let outputs = interpret("batch_norm/batch_norm.onnx", vec![input.into()]);
+
+    assert_same(output, outputs[0].clone());
+}
+
+#[test]
+fn layer_norm() {
+    let device = Default::default();
+    let model: layer_norm::Model<Backend> = layer_norm::Model::default();
+
+    let input = Tensor::<Backend, 1, Int>::arange(0..24, &device)
+        .reshape([2, 3, 4])
+        .float();
+    let output = model.forward(input.clone());
+    let outputs = interpret("layer_norm/layer_norm.onnx", vec![input.into()]);
+
+    assert_same(output, outputs[0].clone());
+}
+
+#[test]
+fn linear() {
+    let device = Default::default();
+    let model: linear::Model<Backend> = linear::Model::default();
+
+    let input1 = Tensor::<Backend, 2>::full([4, 3], 2.5, &device);
+    let input2 = Tensor::<Backend, 2>::full([2, 5], 2.5, &device);
+    let input3 = Tensor::<Backend, 3>::full([3, 2, 7], 2.5, &device);
+    let (output1, output2, output3) = model.forward(input1.clone(), input2.clone(), input3.clone());
+    let outputs = interpret(
+        "linear/linear.onnx",
+        vec![input1.into(), input2.into(), input3.into()],
+    );
+
+    assert_same(output1, outputs[0].clone());
+    assert_same(output2, outputs[1].clone());
+    assert_same(output3, outputs[2].clone());
+}
+
+#[test]
+fn global_avg_pool() {
+    let device = Default::default();
+    let model: global_avr_pool::Model<Backend> = global_avr_pool::Model::default();
+
+    let input_1d = Tensor::<Backend, 3>::ones([2, 4, 10], &device);
+    let input_2d = Tensor::<Backend, 4>::ones([3, 10, 3, 15], &device);
+    let (output_1d, output_2d) = model.forward(input_1d.clone(), input_2d.clone());
+    let outputs = interpret(
+        "global_avr_pool/global_avr_pool.onnx",
+        vec![input_1d.into(), input_2d.into()],
+    );
+
+    assert_same(output_1d, outputs[0].clone());
+    assert_same(output_2d, outputs[1].clone());
+}
+
+#[test]
+fn matmul() {
+    let device = Default::default();
+    let model: matmul::Model<Backend> = matmul::Model::default();
+
+    let a = Tensor::<Backend, 1, Int>::arange(0..24, &device)
+        .reshape([1, 2, 3, 4])
+        .float();
+    let b = Tensor::<Backend, 1, Int>::arange(0..16, &device)
+        .reshape([1, 2, 4, 2])
+        .float();
+    let c = Tensor::<Backend, 1, Int>::arange(0..96, &device)
+        .reshape([2, 3, 4, 4])
+        .float();
+    let d = Tensor::<Backend, 1, Int>::arange(0..4, &device).float();
+    let (output_mm, output_mv, output_vm) =
+        model.forward(a.clone(), b.clone(), c.clone(), d.clone());
+    let outputs = interpret(
+        "matmul/matmul.onnx",
+        vec![a.into(), b.into(), c.into(), d.into()],
+    );
+
+    assert_same(output_mm, outputs[0].clone());
+    assert_same(output_mv, outputs[1].clone());
+    assert_same(output_vm, outputs[2].clone());
+}
+
+#[test]
+fn mask_where() {
+    let device = Default::default();
+    let model: mask_where::Model<Backend> = mask_where::Model::new(&device);
+
+    let x1 = Tensor::<Backend, 2>::ones([2, 2], &device);
+    let y1 = Tensor::<Backend, 2>::zeros([2, 2], &device);
+    let x2 = Tensor::<Backend, 1>::ones([2], &device);
+    let y2 = Tensor::<Backend, 1>::zeros([2], &device);
+    let mask =
+        Tensor::<Backend, 2, Bool>::from_bool([[true, false], [false, true]].into(), &device);
+    let (output, output_broadcasted) =
+        model.forward(mask.clone(), x1.clone(), y1.clone(), x2.clone(), y2.clone());
+    let outputs = interpret(
+        "mask_where/mask_where.onnx",
+        vec![mask.into(), x1.into(), y1.into(), x2.into(), y2.into()],
+    );
+
+    assert_same(output, outputs[0].clone());
+    assert_same(output_broadcasted, outputs[1].clone());
+}
+
+#[test]
+fn gather() {
+    let device = Default::default();
+    let model: gather::Model<Backend> = gather::Model::default();
+
+    let input = Tensor::<Backend, 2>::from_floats([[1., 2., 3.], [4., 5., 6.]], &device);
+    let index = Tensor::<Backend, 1, Int>::from_ints([0, 2], &device);
+    let output = model.forward(input.clone(), index.clone());
+    let outputs = interpret("gather/gather.onnx", vec![input.into(), index.into()]);
+
+    assert_same(output, outputs[0].clone());
+}
+
+#[test]
+fn range() {
+    let device = Default::default();
+    let model: range::Model<Backend> = range::Model::new(&device);
+
+    let output = model.forward(0, 10, 2);
+    let outputs = interpret(
+        "range/range.onnx",
+        vec![0i64.into(), 10i64.into(), 2i64.into()],
+    );
+
+    assert_same(output, outputs[0].clone());
+}
+
+#[test]
+fn reduce_mean() {
+    let device = Default::default();
+    let model: reduce_mean::Model<Backend> = reduce_mean::Model::new(&device);
+
+    let input = Tensor::<Backend, 4>::from_floats([[[[1.0, 4.0, 9.0, 25.0]]]], &device);
+    let (output_scalar, output_tensor, output_value) = model.forward(input.clone());
+    let outputs = interpret("reduce_mean/reduce_mean.onnx", vec![input.into()]);
+
+    assert_same(output_scalar, outputs[0].clone());
+    assert_same(output_tensor, outputs[1].clone());
+    assert_same(output_value, outputs[2].clone());
+}
+
+#[test]
+fn resize() {
+    let device = Default::default();
+    let model: resize::Model<Backend> = resize::Model::new(&device);
+
+    let input = Tensor::<Backend, 1, Int>::arange(0..16, &device)
+        .reshape([1, 1, 4, 4])
+        .float();
+    let size = Tensor::<Backend, 1, Int>::from_ints([1, 1, 2, 3], &device);
+    let output = model.forward(input.clone(), size.clone());
+    let outputs = interpret("resize/resize.onnx", vec![input.into(), size.into()]);
+
+    assert_same(output, outputs[0].clone());
+}
+
+#[test]
+fn shape_ops() {
+    let device = Default::default();
+    let input = Tensor::<Backend, 1, Int>::arange(0..24, &device)
+        .reshape([2, 3, 4])
+        .float();
+
+    let flatten = flatten::Model::<Backend>::new(&device);
+    let outputs = interpret(
+        "flatten/flatten.onnx",
+        vec![Tensor::<Backend, 3>::ones([1, 5, 15], &device).into()],
+    );
+    assert_same(
+        flatten.forward(Tensor::ones([1, 5, 15], &device)),
+        outputs[0].clone(),
+    );
+
+    let transpose = transpose::Model::<Backend>::new(&device);
+    let outputs = interpret("transpose/transpose.onnx", vec![input.clone().into()]);
+    assert_same(transpose.forward(input.clone()), outputs[0].clone());
+
+    let expand = expand::Model::<Backend>::new(&device);
+    let expand_input = Tensor::<Backend, 2>::from_floats([[-1.0], [1.0]], &device);
+    let outputs = interpret("expand/expand.onnx", vec![expand_input.clone().into()]);
+    assert_same(expand.forward(expand_input), outputs[0].clone());
+
+    let unsqueeze = unsqueeze_opset16::Model::<Backend>::new(&device);
+    let (output1, output2) = unsqueeze.forward(input.clone(), 1.0);
+    let outputs = interpret(
+        "unsqueeze/unsqueeze_opset16.onnx",
+        vec![input.into(), 1.0f64.into()],
+    );
+    assert_same(output1, outputs[0].clone());
+    assert_same(output2, outputs[1].clone());
+}
+
+#[test]
+fn slice() {
+    let device = Default::default();
+    let model: slice::Model<Backend> = slice::Model::default();
+
+    let input = Tensor::<Backend, 1, Int>::arange(1..51, &device)
+        .reshape([5, 10])
+        .float();
+    let output = model.forward(input.clone());
+    let outputs = interpret("slice/slice.onnx", vec![input.into()]);
+
+    assert_same(output, outputs[0].clone());
+}
+
+#[test]
+fn softmax() {
+    let device = Default::default();
+    let model: softmax::Model<Backend> = softmax::Model::new(&device);
+
+    let input =
+        Tensor::<Backend, 2>::from_floats([[0.33, 0.12, 0.23], [0.23, -1.12, -0.18]], &device);
+    let output = model.forward(input.clone());
+    let outputs = interpret("softmax/softmax.onnx", vec![input.into()]);
+
+    assert_same(output, outputs[0].clone());
+}
+
+#[test]
+fn pad() {
+    let device = Default::default();
+    let model: pad::Model<Backend> = pad::Model::new(&device);
+
+    let input = Tensor::<Backend, 2>::from_floats([[1.0, 2.0, 3.0], [4.0, 5.0, 6.0]], &device);
+    let output = model.forward(input.clone());
+    let outputs = interpret("pad/pad.onnx", vec![input.into()]);
+
+    assert_same(output, outputs[0].clone());
+}
+
+#[test]
+fn split() {
+    let device = Default::default();
+    let model: split::Model<Backend> = split::Model::new(&device);
+
+    let input = Tensor::<Backend, 1, Int>::arange(0..12, &device)
+        .float()
+        .reshape([2, 6]);
+    let (output1, output2, output3, output4) = model.forward(input.clone());
+    let outputs = interpret("split/split.onnx", vec![input.into()]);
+
+    assert_same(output1, outputs[0].clone());
+    assert_same(output2, outputs[1].clone());
+    assert_same(output3, outputs[2].clone());
+    assert_same(output4, outputs[3].clone());
+}
+
+#[test]
+fn tile() {
+    let device = Default::default();
+    let model: tile::Model<Backend> = tile::Model::new(&device);
+
+    let input = Tensor::<Backend, 3>::from_floats([[[1.0, 2.0], [3.0, 4.0]]], &device);
+    let output = model.forward(input.clone());
+    let outputs = interpret("tile/tile.onnx", vec![input.into()]);
+
+    assert_same(output, outputs[0].clone());
+}
+
+#[test]
+fn top_k() {
+    let device = Default::default();
+    let model: top_k::Model<Backend> = top_k::Model::new(&device);
+
+    let input = Tensor::<Backend, 2>::from_floats(
+        [[0.1, 0.5, -0.3, 0.9, 0.2], [1.0, -1.0, 0.3, 0.0, 0.7]],
+        &device,
+    );
+    let (values_largest, indices_largest, values_smallest, indices_smallest) =
+        model.forward(input.clone());
+    let outputs = interpret("top_k/top_k.onnx", vec![input.into()]);
+
+    assert_same(values_largest, outputs[0].clone());
+    assert_same(indices_largest, outputs[1].clone());
+    assert_same(values_smallest, outputs[2].clone());
+    assert_same(indices_smallest, outputs[3].clone());
+}
+
+#[test]
+fn trilu() {
+    let device = Default::default();
+    let model: trilu::Model<Backend> = trilu::Model::new(&device);
+
+    let input = Tensor::<Backend, 1, Int>::arange(1..13, &device)
+        .reshape([3, 4])
+        .float();
+    let (upper, lower) = model.forward(input.clone());
+    let outputs = interpret("trilu/trilu.onnx", vec![input.into()]);
+
+    assert_same(upper, outputs[0].clone());
+    assert_same(lower, outputs[1].clone());
+}
+
+#[test]
+fn one_hot() {
+    let device = Default::default();
+    let model: one_hot::Model<Backend> = one_hot::Model::new(&device);
+
+    let indices = Tensor::<Backend, 2, Int>::from_ints([[0, 2], [-1, 1]], &device);
+    let output = model.forward(indices.clone());
+    let outputs = interpret("one_hot/one_hot.onnx", vec![indices.into()]);
+
+    assert_same(output, outputs[0].clone());
+}
+
+#[test]
+fn cumsum() {
+    let device = Default::default();
+    let model: cumsum::Model<Backend> = cumsum::Model::new(&device);
+
+    let input = Tensor::<Backend, 2>::from_floats([[1.0, 2.0, 3.0], [4.0, 5.0, 6.0]], &device);
+    let (output, output_exclusive_reverse) = model.forward(input.clone());
+    let outputs = interpret("cumsum/cumsum.onnx", vec![input.into()]);
+
+    assert_same(output, outputs[0].clone());
+    assert_same(output_exclusive_reverse, outputs[1].clone());
+}
+
+#[test]
+fn nonzero() {
+    let device = Default::default();
+    let model: nonzero::Model<Backend> = nonzero::Model::new(&device);
+
+    let input = Tensor::<Backend, 2>::from_floats([[1.0, 0.0, 2.0], [0.0, 0.0, 3.0]], &device);
+    let output = model.forward(input.clone());
+    let outputs = interpret("nonzero/nonzero.onnx", vec![input.into()]);
+
+    assert_same(output, outputs[0].clone());
+}
+
+#[test]
+fn instance_norm() {
+    let device = Default::default();
+    let model: instance_norm::Model<Backend> = instance_norm::Model::default();
+
+    let input = Tensor::<Backend, 4>::from_floats(
+        [[[[1.0, 2.0], [3.0, 4.0]], [[-1.0, 0.0], [2.0, 5.0]]]],
+        &device,
+    );
+    let output = model.forward(input.clone());
+    let outputs = interpret("instance_norm/instance_norm.onnx", vec![input.into()]);
+
+    assert_same(output, outputs[0].clone());
+}
+
+#[test]
+fn group_norm() {
+    let device = Default::default();
+    let model: group_norm::Model<Backend> = group_norm::Model::default();
+
+    let input = Tensor::<Backend, 3>::from_floats(
+        [[[0.0, 1.0], [2.0, 3.0], [4.0, 5.0], [6.0, 8.0]]],
+        &device,
+    );
+    let output = model.forward(input.clone());
+    let outputs = interpret("group_norm/group_norm.onnx", vec![input.into()]);
+
+    assert_same(output, outputs[0].clone());
+}
+
+#[test]
+fn activations() {
+    let device = Default::default();
+    let input = Tensor::<Backend, 2>::from_floats([[-4.0, -1.0, 0.0], [0.5, 2.0, 3.5]], &device);
+
+    let hard_sigmoid = hard_sigmoid::Model::<Backend>::new(&device);
+    let outputs = interpret("hard_sigmoid/hard_sigmoid.onnx", vec![input.clone().into()]);
+    assert_same(hard_sigmoid.forward(input.clone()), outputs[0].clone());
+
+    let hard_swish = hard_swish::Model::<Backend>::new(&device);
+    let outputs = interpret("hard_swish/hard_swish.onnx", vec![input.clone().into()]);
+    assert_same(hard_swish.forward(input.clone()), outputs[0].clone());
+
+    let elu = elu::Model::<Backend>::new(&device);
+    let outputs = interpret("elu/elu.onnx", vec![input.clone().into()]);
+    assert_same(elu.forward(input.clone()), outputs[0].clone());
+
+    let selu = selu::Model::<Backend>::new(&device);
+    let outputs = interpret("selu/selu.onnx", vec![input.clone().into()]);
+    assert_same(selu.forward(input.clone()), outputs[0].clone());
+
+    let softplus = softplus::Model::<Backend>::new(&device);
+    let outputs = interpret("softplus/softplus.onnx", vec![input.clone().into()]);
+    assert_same(softplus.forward(input), outputs[0].clone());
+}
+
+#[test]
+fn if_else() {
+    let device = Default::default();
+    let model: if_else::Model<Backend> = if_else::Model::new(&device);
+
+    let input = Tensor::<Backend, 2>::from_floats([[-1., 0., 1.], [2., -3., 4.]], &device);
+    for condition in [true, false] {
+        let output = model.forward(input.clone(), condition);
+        let outputs = interpret(
+            "if/if_else.onnx",
+            vec![input.clone().into(), condition.into()],
+        );
+
+        assert_same(output, outputs[0].clone());
+    }
+}
+
+#[test]
+fn loop_cond() {
+    let device = Default::default();
+    let model: loop_cond::Model<Backend> = loop_cond::Model::new(&device);
+
+    // The loop stops on the condition, then on the maximum trip count
+    let input = Tensor::<Backend, 2>::from_floats([[1., 2., 3.], [4., 5., 6.]], &device);
+    for max_trip_count in [10i64, 1] {
+        let (output, scans) = model.forward(input.clone(), max_trip_count);
+        let outputs = interpret(
+            "loop/loop_cond.onnx",
+            vec![input.clone().into(), max_trip_count.into()],
+        );
+
+        assert_same(output, outputs[0].clone());
+        assert_same(scans, outputs[1].clone());
+    }
+}
+
+#[test]
+fn scan() {
+    let device = Default::default();
+    let model: scan::Model<Backend> = scan::Model::new(&device);
+
+    let init = Tensor::<Backend, 1>::from_floats([1., 0., -1.], &device);
+    let xs = Tensor::<Backend, 2>::from_floats(
+        [[1., 2., 3.], [0., 1., 0.], [2., 0., 1.], [-1., 1., 2.]],
+        &device,
+    );
+    let (final_state, ys) = model.forward(init.clone(), xs.clone());
+    let outputs = interpret("scan/scan.onnx", vec![init.into(), xs.into()]);
+
+    assert_same(final_state, outputs[0].clone());
+    assert_same(ys, outputs[1].clone());
+}
+
+/// The input sequence `[seq_length, batch_size, d_input]` of the recurrent models.
+fn recurrent_input(
+    device: &<Backend as burn::tensor::backend::Backend>::Device,
+) -> Tensor<Backend, 3> {
+    Tensor::<Backend, 3>::from_floats(
+        [
+            [[0.1, 0.2], [-0.1, 0.4]],
+            [[0.2, 0.1], [-0.2, 0.3]],
+            [[0.3, 0.0], [-0.3, 0.2]],
+        ],
+        device,
+    )
+}
+
+#[test]
+fn lstm() {
+    let device = Default::default();
+    let model: lstm::Model<Backend> = lstm::Model::default();
+
+    let input = recurrent_input(&device);
+    let initial_hidden =
+        Tensor::<Backend, 3>::from_floats([[[0.1, 0.2, 0.3], [0.2, 0.4, 0.6]]], &device);
+    let initial_cell =
+        Tensor::<Backend, 3>::from_floats([[[-0.1, -0.2, -0.3], [-0.05, -0.15, -0.25]]], &device);
+    let (output, output_hidden, output_cell) =
+        model.forward(input.clone(), initial_hidden.clone(), initial_cell.clone());
+    let outputs = interpret(
+        "lstm/lstm.onnx",
+        vec![input.into(), initial_hidden.into(), initial_cell.into()],
+    );
+
+    assert_same(output, outputs[0].clone());
+    assert_same(output_hidden, outputs[1].clone());
+    assert_same(output_cell, outputs[2].clone());
+}
+
+#[test]
+fn lstm_bidirectional() {
+    let device = Default::default();
+    let model: lstm_bidirectional::Model<Backend> = lstm_bidirectional::Model::default();
+
+    let input = recurrent_input(&device);
+    let (output, output_hidden) = model.forward(input.clone());
+    let outputs = interpret("lstm/lstm_bidirectional.onnx", vec![input.into()]);
+
+    assert_same(output, outputs[0].clone());
+    assert_same(output_hidden, outputs[1].clone());
+}
+
+#[test]
+fn gru() {
+    let device = Default::default();
+    let model: gru::Model<Backend> = gru::Model::default();
+
+    let input = recurrent_input(&device);
+    let initial_hidden =
+        Tensor::<Backend, 3>::from_floats([[[0.1, 0.2, 0.3], [0.2, 0.4, 0.6]]], &device);
+    let (output, output_hidden) = model.forward(input.clone(), initial_hidden.clone());
+    let outputs = interpret("gru/gru.onnx", vec![input.into(), initial_hidden.into()]);
+
+    assert_same(output, outputs[0].clone());
+    assert_same(output_hidden, outputs[1].clone());
+}
+
+#[test]
+fn gru_linear_before_reset() {
+    let device = Default::default();
+    let model: gru_linear_before_reset::Model<Backend> = gru_linear_before_reset::Model::default();
+
+    let input = recurrent_input(&device);
+    let initial_hidden =
+        Tensor::<Backend, 3>::from_floats([[[0.1, 0.2, 0.3], [0.2, 0.4, 0.6]]], &device);
+    let (output, output_hidden) = model.forward(input.clone(), initial_hidden.clone());
+    let outputs = interpret(
+        "gru/gru_linear_before_reset.onnx",
+        vec![input.into(), initial_hidden.into()],
+    );
+
+    assert_same(output, outputs[0].clone());
+    assert_same(output_hidden, outputs[1].clone());
+}
+
+#[test]
+fn rnn() {
+    let device = Default::default();
+    let model: rnn::Model<Backend> = rnn::Model::default();
+
+    // The sequences of the model are batch first
+    let input = recurrent_input(&device).swap_dims(0, 1);
+    let output_hidden = model.forward(input.clone());
+    let outputs = interpret("rnn/rnn.onnx", vec![input.into()]);
+
+    assert_same(output_hidden, outputs[0].clone());
+}
+
+#[test]
+fn arithmetic() {
+    let device = Default::default();
+    let input = Tensor::<Backend, 4>::from_floats([[[[3., 6., 6., 9.]]]], &device);
+
+    let mul = mul::Model::<Backend>::default();
+    let outputs = interpret("mul/mul.onnx", vec![input.clone().into(), 6.0f64.into()]);
+    assert_same(mul.forward(input.clone(), 6.0), outputs[0].clone());
+
+    let div = div::Model::<Backend>::new(&device);
+    let outputs = interpret(
+        "div/div.onnx",
+        vec![input.clone().into(), 9.0f64.into(), 3.0f64.into()],
+    );
+    assert_same(div.forward(input.clone(), 9.0, 3.0), outputs[0].clone());
+
+    let pow = pow::Model::<Backend>::new(&device);
+    let outputs = interpret("pow/pow.onnx", vec![input.clone().into(), 2.0f64.into()]);
+    assert_same(pow.forward(input.clone(), 2.0), outputs[0].clone());
+
+    let neg = neg::Model::<Backend>::new(&device);
+    let (output1, output2) = neg.forward(input.clone(), 99.0);
+    let outputs = interpret("neg/neg.onnx", vec![input.clone().into(), 99.0f64.into()]);
+    assert_same(output1, outputs[0].clone());
+    assert_same(output2, outputs[1].clone());
+
+    let sqrt = sqrt::Model::<Backend>::new(&device);
+    let (output1, output2) = sqrt.forward(input.clone(), 36.0);
+    let outputs = interpret("sqrt/sqrt.onnx", vec![input.into(), 36.0f64.into()]);
+    assert_same(output1, outputs[0].clone());
+    assert_same(output2, outputs[1].clone());
+
+    let input = Tensor::<Backend, 4, Int>::from_ints([[[[1, 2, 3, 4]]]], &device);
+    let sub_int = sub_int::Model::<Backend>::default();
+    let outputs = interpret("sub/sub_int.onnx", vec![input.clone().into(), 3i64.into()]);
+    assert_same(sub_int.forward(input, 3), outputs[0].clone());
+
+    let input = Tensor::<Backend, 1, Int>::from_ints([1, 2, 3, 4], &device);
+    let sum_int = sum_int::Model::<Backend>::default();
+    let outputs = interpret(
+        "sum/sum_int.onnx",
+        vec![
+            input.clone().into(),
+            input.clone().into(),
+            input.clone().into(),
+        ],
+    );
+    assert_same(
+        sum_int.forward(input.clone(), input.clone(), input),
+        outputs[0].clone(),
+    );
+}
+
+#[test]
+fn unary() {
+    let device = Default::default();
+    let input = Tensor::<Backend, 4>::from_floats([[[[-1.0, 4.0, 0.0, 25.0]]]], &device);
+    let positive = Tensor::<Backend, 4>::from_floats([[[[1.0, 4.0, 9.0, 25.0]]]], &device);
+    let matrix =
+        Tensor::<Backend, 2>::from_floats([[0.33, 0.0, 0.23], [0.23, -1.12, -0.18]], &device);
+
+    macro_rules! assert_unary {
+        ($model:ident, $file:literal, $input:expr) => {
+            let model = $model::Model::<Backend>::default();
+            let outputs = interpret($file, vec![$input.clone().into()]);
+            assert_same(model.forward($input.clone()), outputs[0].clone());
+        };
+    }
+
+    assert_unary!(cos, "cos/cos.onnx", input);
+    assert_unary!(sin, "sin/sin.onnx", input);
+    assert_unary!(tanh, "tanh/tanh.onnx", input);
+    assert_unary!(exp, "exp/exp.onnx", input);
+    assert_unary!(erf, "erf/erf.onnx", input);
+    assert_unary!(gelu, "gelu/gelu.onnx", input);
+    assert_unary!(sign, "sign/sign.onnx", input);
+    assert_unary!(sigmoid, "sigmoid/sigmoid.onnx", matrix);
+    assert_unary!(relu, "relu/relu.onnx", matrix);
+    assert_unary!(leaky_relu, "leaky_relu/leaky_relu.onnx", matrix);
+    assert_unary!(prelu, "prelu/prelu.onnx", matrix);
+    assert_unary!(log_softmax, "log_softmax/log_softmax.onnx", matrix);
+    assert_unary!(log, "log/log.onnx", positive);
+    assert_unary!(recip, "recip/recip.onnx", positive);
+
+    let input =
+        Tensor::<Backend, 4, Bool>::from_bool([[[[true, false, true, false]]]].into(), &device);
+    assert_unary!(not, "not/not.onnx", input);
+}
+
+#[test]
+fn comparison() {
+    let device = Default::default();
+    let input1 = Tensor::<Backend, 2>::from_floats([[1.0, 4.0, 9.0, 25.0]], &device);
+    let input2 = Tensor::<Backend, 2>::from_floats([[1.0, 5.0, 8.0, -25.0]], &device);
+
+    macro_rules! assert_binary {
+        ($model:ident, $file:literal) => {
+            let model = $model::Model::<Backend>::new(&device);
+            let outputs = interpret($file, vec![input1.clone().into(), input2.clone().into()]);
+            assert_same(
+                model.forward(input1.clone(), input2.clone()),
+                outputs[0].clone(),
+            );
+        };
+    }
+
+    assert_binary!(greater, "greater/greater.onnx");
+    assert_binary!(greater_or_equal, "greater_or_equal/greater_or_equal.onnx");
+    assert_binary!(less, "less/less.onnx");
+    assert_binary!(less_or_equal, "less_or_equal/less_or_equal.onnx");
+    assert_binary!(max, "max/max.onnx");
+    assert_binary!(min, "min/min.onnx");
+}
+
+#[test]
+fn argmax() {
+    let device = Default::default();
+    let model: argmax::Model<Backend> = argmax::Model::default();
+
+    let input = Tensor::<Backend, 2>::from_floats([[1., 2., 3.], [4., 5., 6.]], &device);
+    let output = model.forward(input.clone());
+    let outputs = interpret("argmax/argmax.onnx", vec![input.into()]);
+
+    assert_same(output, outputs[0].clone());
+}
+
+#[test]
+fn clip_opset7() {
+    let device = Default::default();
+    let model: clip_opset7::Model<Backend> = clip_opset7::Model::new(&device);
+
+    let input = Tensor::<Backend, 1>::from_floats([0.88, 0.91, 0.38, 0.95, 0.39, 0.6], &device);
+    let (output1, output2, output3) = model.forward(input.clone());
+    let outputs = interpret("clip/clip_opset7.onnx", vec![input.into()]);
+
+    assert_same(output1, outputs[0].clone());
+    assert_same(output2, outputs[1].clone());
+    assert_same(output3, outputs[2].clone());
+}
+
+#[test]
+fn constant_of_shape_full_like() {
+    let device = Default::default();
+    let model = constant_of_shape_full_like::Model::<Backend>::new(&device);
+
+    let input = Tensor::<Backend, 3>::ones([2, 3, 2], &device);
+    let (f_output, i_output, b_output) = model.forward(input.clone());
+    let outputs = interpret(
+        "constant_of_shape/constant_of_shape_full_like.onnx",
+        vec![input.into()],
+    );
+
+    assert_same(f_output, outputs[0].clone());
+    assert_same(i_output, outputs[1].clone());
+    assert_same(b_output, outputs[2].clone());
+}
+
+#[test]
+fn convolutions() {
+    let device = Default::default();
+
+    let conv1d = conv1d::Model::<Backend>::default();
+    let input = Tensor::<Backend, 3>::full([6, 4, 10], 2.5, &device);
+    let outputs = interpret("conv1d/conv1d.onnx", vec![input.clone().into()]);
+    assert_same(conv1d.forward(input), outputs[0].clone());
+
+    let conv3d = conv3d::Model::<Backend>::default();
+    let input = Tensor::<Backend, 5>::ones([2, 4, 4, 5, 7], &device);
+    let outputs = interpret("conv3d/conv3d.onnx", vec![input.clone().into()]);
+    assert_same(conv3d.forward(input.clone()), outputs[0].clone());
+
+    let conv_transpose3d = conv_transpose3d::Model::<Backend>::default();
+    let outputs = interpret(
+        "conv_transpose3d/conv_transpose3d.onnx",
+        vec![input.clone().into()],
+    );
+    assert_same(conv_transpose3d.forward(input), outputs[0].clone());
+
+    let conv_transpose2d = conv_transpose2d::Model::<Backend>::default();
+    let input = Tensor::<Backend, 4>::ones([2, 4, 10, 15], &device);
+    let outputs = interpret(
+        "conv_transpose2d/conv_transpose2d.onnx",
+        vec![input.clone().into()],
+    );
+    assert_same(conv_transpose2d.forward(input), outputs[0].clone());
+}
+
+#[test]
+fn pools() {
+    let device = Default::default();
+    let input = Tensor::<Backend, 1, Int>::arange(0..25, &device)
+        .float()
+        .sin()
+        .reshape([1, 5, 5]);
+
+    let avg_pool1d = avg_pool1d::Model::<Backend>::new(&device);
+    let (output1, output2, output3) =
+        avg_pool1d.forward(input.clone(), input.clone(), input.clone());
+    let outputs = interpret(
+        "avg_pool1d/avg_pool1d.onnx",
+        vec![
+            input.clone().into(),
+            input.clone().into(),
+            input.clone().into(),
+        ],
+    );
+    assert_same(output1, outputs[0].clone());
+    assert_same(output2, outputs[1].clone());
+    assert_same(output3, outputs[2].clone());
+
+    let maxpool1d = maxpool1d::Model::<Backend>::new(&device);
+    let outputs = interpret("maxpool1d/maxpool1d.onnx", vec![input.clone().into()]);
+    assert_same(maxpool1d.forward(input.clone()), outputs[0].clone());
+
+    let input = input.reshape([1, 1, 5, 5]);
+    let avg_pool2d = avg_pool2d::Model::<Backend>::new(&device);
+    let (output1, output2, output3) =
+        avg_pool2d.forward(input.clone(), input.clone(), input.clone());
+    let outputs = interpret(
+        "avg_pool2d/avg_pool2d.onnx",
+        vec![
+            input.clone().into(),
+            input.clone().into(),
+            input.clone().into(),
+        ],
+    );
+    assert_same(output1, outputs[0].clone());
+    assert_same(output2, outputs[1].clone());
+    assert_same(output3, outputs[2].clone());
+
+    let maxpool2d = maxpool2d::Model::<Backend>::new(&device);
+    let outputs = interpret("maxpool2d/maxpool2d.onnx", vec![input.clone().into()]);
+    assert_same(maxpool2d.forward(input), outputs[0].clone());
+}
+
+#[test]
+fn dropout() {
+    let device = Default::default();
+    let input = Tensor::<Backend, 4>::ones([2, 4, 10, 15], &device);
+
+    let dropout_opset16 = dropout_opset16::Model::<Backend>::default();
+    let outputs = interpret("dropout/dropout_opset16.onnx", vec![input.clone().into()]);
+    assert_same(dropout_opset16.forward(input.clone()), outputs[0].clone());
+
+    let dropout_opset7 = dropout_opset7::Model::<Backend>::default();
+    let outputs = interpret("dropout/dropout_opset7.onnx", vec![input.clone().into()]);
+    assert_same(dropout_opset7.forward(input), outputs[0].clone());
+}
+
+#[test]
+fn gather_elements() {
+    let device = Default::default();
+    let model: gather_elements::Model<Backend> = gather_elements::Model::default();
+
+    let input = Tensor::<Backend, 2>::from_floats([[1., 2.], [3., 4.]], &device);
+    let index = Tensor::<Backend, 2, Int>::from_ints([[0, 0], [1, 0]], &device);
+    let output = model.forward(input.clone(), index.clone());
+    let outputs = interpret(
+        "gather_elements/gather_elements.onnx",
+        vec![input.into(), index.into()],
+    );
+
+    assert_same(output, outputs[0].clone());
+}
+
+#[test]
+fn reductions() {
+    let device = Default::default();
+    let input = Tensor::<Backend, 4>::from_floats([[[[1.0, 4.0, 9.0, 25.0]]]], &device);
+
+    macro_rules! assert_reduce {
+        ($model:ident, $file:literal) => {
+            let model = $model::Model::<Backend>::new(&device);
+            let (output_scalar, output_tensor, output_value) = model.forward(input.clone());
+            let outputs = interpret($file, vec![input.clone().into()]);
+            assert_same(output_scalar, outputs[0].clone());
+            assert_same(output_tensor, outputs[1].clone());
+            assert_same(output_value, outputs[2].clone());
+        };
+    }
+
+    assert_reduce!(reduce_max, "reduce_max/reduce_max.onnx");
+    assert_reduce!(reduce_min, "reduce_min/reduce_min.onnx");
+    assert_reduce!(reduce_prod, "reduce_prod/reduce_prod.onnx");
+    assert_reduce!(reduce_sum_opset11, "reduce_sum/reduce_sum_opset11.onnx");
+    assert_reduce!(reduce_sum_opset13, "reduce_sum/reduce_sum_opset13.onnx");
+}
+
+#[test]
+fn reshapes() {
+    let device = Default::default();
+
+    let reshape = reshape::Model::<Backend>::new(&device);
+    let input = Tensor::<Backend, 1>::from_floats([0., 1., 2., 3.], &device);
+    let outputs = interpret("reshape/reshape.onnx", vec![input.clone().into()]);
+    assert_same(reshape.forward(input), outputs[0].clone());
+
+    let shape = shape::Model::<Backend>::new(&device);
+    let input = Tensor::<Backend, 2>::ones([4, 2], &device);
+    let outputs = interpret("shape/shape.onnx", vec![input.clone().into()]);
+    assert_same(
+        Value::Shape(shape.forward(input).map(|dim| dim as i64).to_vec()),
+        outputs[0].clone(),
+    );
+
+    let input = Tensor::<Backend, 4>::ones([3, 4, 1, 5], &device);
+    let squeeze_opset13 = squeeze_opset13::Model::<Backend>::new(&device);
+    let outputs = interpret("squeeze/squeeze_opset13.onnx", vec![input.clone().into()]);
+    assert_same(squeeze_opset13.forward(input.clone()), outputs[0].clone());
+
+    let squeeze_opset16 = squeeze_opset16::Model::<Backend>::new(&device);
+    let outputs = interpret("squeeze/squeeze_opset16.onnx", vec![input.clone().into()]);
+    assert_same(squeeze_opset16.forward(input), outputs[0].clone());
+
+    let input = Tensor::<Backend, 3>::ones([3, 4, 5], &device);
+    let unsqueeze = unsqueeze::Model::<Backend>::new(&device);
+    let outputs = interpret("unsqueeze/unsqueeze.onnx", vec![input.clone().into()]);
+    assert_same(unsqueeze.forward(input.clone()), outputs[0].clone());
+
+    let unsqueeze_opset11 = unsqueeze_opset11::Model::<Backend>::new(&device);
+    let (output1, output2) = unsqueeze_opset11.forward(input.clone(), 1.0);
+    let outputs = interpret(
+        "unsqueeze/unsqueeze_opset11.onnx",
+        vec![input.into(), 1.0f64.into()],
+    );
+    assert_same(output1, outputs[0].clone());
+    assert_same(output2, outputs[1].clone());
+}
+
+#[test]
+fn random() {
+    let device = Default::default();
+
+    // The values are random, only their shape can be compared
+    let random_normal = random_normal::Model::<Backend>::new(&device);
+    let outputs = interpret("random_normal/random_normal.onnx", vec![]);
+    assert_eq!(
+        outputs[0].clone().into_float::<2>().dims(),
+        random_normal.forward().dims()
+    );
+
+    let random_uniform = random_uniform::Model::<Backend>::new(&device);
+    let outputs = interpret("random_uniform/random_uniform.onnx", vec![]);
+    assert_eq!(
+        outputs[0].clone().into_float::<2>().dims(),
+        random_uniform.forward().dims()
+    );
+}
+
+#[test]
+fn dynamic_shape() {
+    let device = Default::default();
+    let model: dynamic_shape::Model<Backend> = dynamic_shape::Model::default();
+
+    for batch in [1, 3] {
+        let input = Tensor::<Backend, 1, Int>::arange(0..batch * 12, &device)
+            .float()
+            .reshape([batch as usize, 3, 4]);
+        let (flat, restored, expanded, sliced, merged) = model.forward(input.clone());
+        let outputs = interpret("dynamic_shape/dynamic_shape.onnx", vec![input.into()]);
+
+        assert_same(flat, outputs[0].clone());
+        assert_same(restored, outputs[1].clone());
+        assert_same(expanded, outputs[2].clone());
+        assert_same(sliced, outputs[3].clone());
+        assert_same(merged, outputs[4].clone());
+    }
+}
+
+#[test]
+fn external_data() {
+    let device = Default::default();
+    let model: external_data::Model<Backend> = external_data::Model::default();
+
+    let input = Tensor::<Backend, 2>::from_floats([[1.0, 2.0, 3.0], [4.0, 5.0, 6.0]], &device);
+    let output = model.forward(input.clone());
+    let outputs = interpret("external_data/external_data.onnx", vec![input.into()]);
+
+    assert_same(output, outputs[0].clone());
+}
+
+#[test]
+fn external_data_outside_model_directory() {
+    // The external data is referenced through the parent directory of the model
+    let result = OnnxModel::<Backend>::from_file(
+        "tests/external_data/external_data_outside.onnx".as_ref(),
+        &Default::default(),
+    );
+
+    match result {
+        Err(OnnxModelError::Parse(OnnxParseError::ExternalData { tensor, reason })) => {
+            assert_eq!(tensor, "weight");
+            assert!(reason.contains(".."), "Unexpected reason: {reason}");
+        }
+        other => panic!("Expected an external data error, got {other:?}"),
+    }
+}
+
+#[test]
+fn opset_upgrade() {
+    let device = Default::default();
+    let model: opset_upgrade::Model<Backend> = opset_upgrade::Model::new(&device);
+
+    let input = Tensor::<Backend, 1, Int>::arange(0..24, &device)
+        .float()
+        .reshape([2, 3, 4]);
+    let (padded, values, indices, mean, split, squeezed) = model.forward(input.clone());
+    let outputs = interpret("opset_upgrade/opset_upgrade.onnx", vec![input.into()]);
+
+    assert_same(padded, outputs[0].clone());
+    assert_same(values, outputs[1].clone());
+    assert_same(indices, outputs[2].clone());
+    assert_same(mean, outputs[3].clone());
+    assert_same(split, outputs[4].clone());
+    assert_same(squeezed, outputs[5].clone());
+}
+
+#[test]
+fn opset_unsupported() {
+    // The broadcasting axis of opset 6 can't be converted to the latest opset
+    let result = OnnxModel::<Backend>::from_file(
+        "tests/opset_upgrade/opset_broadcast_axis.onnx".as_ref(),
+        &Default::default(),
+    );
+
+    match result {
+        Err(OnnxModelError::Parse(OnnxParseError::UnsupportedOpset {
+            opset_version,
+            feature,
+            ..
+        })) => {
+            assert_eq!(opset_version, 6);
+            assert_eq!(feature, "the broadcasting axis");
+        }
+        other => panic!("Expected an unsupported opset error, got {other:?}"),
+    }
+}
+
+#[test]
+fn optimize() {
+    let device = Default::default();
+    let model: optimize::Model<Backend> = optimize::Model::default();
+
+    let input = Tensor::<Backend, 1, Int>::arange(0..32, &device)
+        .float()
+        .mul_scalar(0.1)
+        .reshape([1, 2, 4, 4]);
+    let output = model.forward(input.clone());
+    // The graph is optimized like the generated model
+    let outputs = OnnxModel::<Backend>::from_file_with_passes(
+        "tests/optimize/optimize.onnx".as_ref(),
+        &Pass::ALL,
+        &device,
+    )
+    .expect("Every operator of the model should be supported")
+    .forward(vec![input.into()]);
+
+    assert_same(output, outputs[0].clone());
+}
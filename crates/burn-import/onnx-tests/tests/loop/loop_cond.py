@@ -0,0 +1,62 @@
+#!/usr/bin/env python3
+
+# used to generate model: onnx-tests/tests/loop/loop_cond.onnx
+
+import onnx
+from onnx import helper, TensorProto
+
+def main():
+    # Adds x to the accumulator while the iteration number is lower than 2, or until M iterations
+    body = helper.make_graph(
+        nodes=[
+            helper.make_node(
+                'Constant',
+                inputs=[],
+                outputs=['two'],
+                value=helper.make_tensor('two', TensorProto.INT64, [], [2]),
+            ),
+            helper.make_node('Less', inputs=['i', 'two'], outputs=['cond_out']),
+            helper.make_node('Add', inputs=['acc', 'x'], outputs=['acc_out']),
+            helper.make_node('Identity', inputs=['acc_out'], outputs=['scan_out']),
+        ],
+        name='body',
+        inputs=[
+            helper.make_tensor_value_info('i', TensorProto.INT64, []),
+            helper.make_tensor_value_info('cond_in', TensorProto.BOOL, []),
+            helper.make_tensor_value_info('acc', TensorProto.FLOAT, [2, 3]),
+        ],
+        outputs=[
+            helper.make_tensor_value_info('cond_out', TensorProto.BOOL, []),
+            helper.make_tensor_value_info('acc_out', TensorProto.FLOAT, [2, 3]),
+            helper.make_tensor_value_info('scan_out', TensorProto.FLOAT, [2, 3]),
+        ],
+    )
+
+    graph_def = helper.make_graph(
+        nodes=[
+            helper.make_node(
+                'Loop',
+                inputs=['M', '', 'x'],
+                outputs=['acc_final', 'scans'],
+                body=body,
+            ),
+        ],
+        name='LoopGraph',
+        inputs=[
+            helper.make_tensor_value_info('x', TensorProto.FLOAT, [2, 3]),
+            helper.make_tensor_value_info('M', TensorProto.INT64, []),
+        ],
+        outputs=[
+            helper.make_tensor_value_info('acc_final', TensorProto.FLOAT, [2, 3]),
+            helper.make_tensor_value_info('scans', TensorProto.FLOAT, ['N', 2, 3]),
+        ],
+    )
+
+    model_def = helper.make_model(
+        graph_def, producer_name='loop_cond', opset_imports=[helper.make_opsetid('', 16)]
+    )
+
+    onnx.save(model_def, 'loop_cond.onnx')
+
+if __name__ == '__main__':
+    main()
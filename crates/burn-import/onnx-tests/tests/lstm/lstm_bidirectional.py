@@ -0,0 +1,73 @@
+#!/usr/bin/env python3
+
+# used to generate model: onnx-tests/tests/lstm/lstm_bidirectional.onnx
+
+import numpy as np
+import onnx
+from onnx import helper, numpy_helper, TensorProto
+from onnx.reference import ReferenceEvaluator
+
+SEQ_LENGTH, BATCH_SIZE, INPUT_SIZE, HIDDEN_SIZE = 3, 2, 2, 3
+NUM_GATES, NUM_DIRECTIONS = 4, 2
+
+
+def main():
+    # Deterministic weights of both directions, without biases
+    w = 0.5 * np.sin(np.arange(NUM_DIRECTIONS * NUM_GATES * HIDDEN_SIZE * INPUT_SIZE))
+    r = 0.5 * np.cos(np.arange(NUM_DIRECTIONS * NUM_GATES * HIDDEN_SIZE * HIDDEN_SIZE))
+    initializers = [
+        numpy_helper.from_array(
+            w.reshape(NUM_DIRECTIONS, NUM_GATES * HIDDEN_SIZE, INPUT_SIZE).astype(np.float32), 'W'
+        ),
+        numpy_helper.from_array(
+            r.reshape(NUM_DIRECTIONS, NUM_GATES * HIDDEN_SIZE, HIDDEN_SIZE).astype(np.float32), 'R'
+        ),
+    ]
+
+    graph_def = helper.make_graph(
+        nodes=[
+            # The last cell state is omitted
+            helper.make_node(
+                'LSTM',
+                inputs=['X', 'W', 'R'],
+                outputs=['Y', 'Y_h'],
+                hidden_size=HIDDEN_SIZE,
+                direction='bidirectional',
+            ),
+        ],
+        name='LstmBidirectionalGraph',
+        inputs=[
+            helper.make_tensor_value_info(
+                'X', TensorProto.FLOAT, [SEQ_LENGTH, BATCH_SIZE, INPUT_SIZE]
+            ),
+        ],
+        outputs=[
+            helper.make_tensor_value_info(
+                'Y', TensorProto.FLOAT, [SEQ_LENGTH, NUM_DIRECTIONS, BATCH_SIZE, HIDDEN_SIZE]
+            ),
+            helper.make_tensor_value_info(
+                'Y_h', TensorProto.FLOAT, [NUM_DIRECTIONS, BATCH_SIZE, HIDDEN_SIZE]
+            ),
+        ],
+        initializer=initializers,
+    )
+
+    model_def = helper.make_model(
+        graph_def,
+        producer_name='lstm_bidirectional',
+        opset_imports=[helper.make_opsetid('', 14)],
+    )
+
+    onnx.save(model_def, 'lstm_bidirectional.onnx')
+
+    x = np.array(
+        [[[0.1, 0.2], [-0.1, 0.4]], [[0.2, 0.1], [-0.2, 0.3]], [[0.3, 0.0], [-0.3, 0.2]]],
+        dtype=np.float32,
+    )
+    outputs = ReferenceEvaluator(model_def).run(None, {'X': x})
+    for name, output in zip(['Y', 'Y_h'], outputs):
+        print(f'{name}: {output.round(4)}')
+
+
+if __name__ == '__main__':
+    main()
@@ -0,0 +1,31 @@
+#!/usr/bin/env python3
+
+# used to generate model: onnx-tests/tests/nonzero/nonzero.onnx
+
+import numpy as np
+import onnx
+from onnx import helper, TensorProto
+from onnx.reference import ReferenceEvaluator
+
+
+def main():
+    graph_def = helper.make_graph(
+        nodes=[helper.make_node('NonZero', inputs=['X'], outputs=['Y'])],
+        name='NonZeroGraph',
+        inputs=[helper.make_tensor_value_info('X', TensorProto.FLOAT, [2, 3])],
+        outputs=[helper.make_tensor_value_info('Y', TensorProto.INT64, [2, 'num_nonzero'])],
+    )
+
+    model_def = helper.make_model(
+        graph_def, producer_name='nonzero', opset_imports=[helper.make_opsetid('', 13)]
+    )
+
+    onnx.save(model_def, 'nonzero.onnx')
+
+    x = np.array([[1.0, 0.0, 2.0], [0.0, 0.0, 3.0]], dtype=np.float32)
+    (y,) = ReferenceEvaluator(model_def).run(None, {'X': x})
+    print(f'Y: {y}')
+
+
+if __name__ == '__main__':
+    main()
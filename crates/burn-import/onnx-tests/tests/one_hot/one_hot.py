@@ -0,0 +1,43 @@
+#!/usr/bin/env python3
+
+# used to generate model: onnx-tests/tests/one_hot/one_hot.onnx
+
+import numpy as np
+import onnx
+from onnx import helper, numpy_helper, TensorProto
+from onnx.reference import ReferenceEvaluator
+
+
+def main():
+    # The values are [off_value, on_value]
+    initializers = [
+        numpy_helper.from_array(np.array(3, dtype=np.int64), 'depth'),
+        numpy_helper.from_array(np.array([-1.0, 5.0], dtype=np.float32), 'values'),
+    ]
+
+    graph_def = helper.make_graph(
+        nodes=[
+            helper.make_node(
+                'OneHot', inputs=['indices', 'depth', 'values'], outputs=['Y'], axis=1
+            ),
+        ],
+        name='OneHotGraph',
+        inputs=[helper.make_tensor_value_info('indices', TensorProto.INT64, [2, 2])],
+        outputs=[helper.make_tensor_value_info('Y', TensorProto.FLOAT, [2, 3, 2])],
+        initializer=initializers,
+    )
+
+    model_def = helper.make_model(
+        graph_def, producer_name='one_hot', opset_imports=[helper.make_opsetid('', 11)]
+    )
+
+    onnx.save(model_def, 'one_hot.onnx')
+
+    # Negative indices are counted from the last class
+    indices = np.array([[0, 2], [-1, 1]], dtype=np.int64)
+    (y,) = ReferenceEvaluator(model_def).run(None, {'indices': indices})
+    print(f'Y: {y}')
+
+
+if __name__ == '__main__':
+    main()
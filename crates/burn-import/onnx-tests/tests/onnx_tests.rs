@@ -29,23 +29,33 @@ include_models!(
     conv2d,
     conv3d,
     cos,
+    cumsum,
     div,
+    dynamic_shape,
+    elu,
     dropout_opset16,
     dropout_opset7,
     equal,
     erf,
     exp,
     expand,
+    external_data,
     flatten,
     gather,
     gather_elements,
     gelu,
     global_avr_pool,
+    group_norm,
+    hard_sigmoid,
+    hard_swish,
+    if_else,
+    instance_norm,
     layer_norm,
     leaky_relu,
     linear,
     log_softmax,
     log,
+    loop_cond,
     mask_where,
     matmul,
     min,
@@ -54,11 +64,20 @@ include_models!(
     maxpool2d,
     mul,
     neg,
+    nonzero,
     not,
+    one_hot,
+    opset_upgrade,
+    optimize,
+    pad,
     greater,
     greater_or_equal,
+    gru,
+    gru_linear_before_reset,
     less,
     less_or_equal,
+    lstm,
+    lstm_bidirectional,
     prelu,
     range,
     recip,
@@ -71,19 +90,27 @@ include_models!(
     relu,
     reshape,
     resize,
+    rnn,
+    scan,
+    selu,
     shape,
     sigmoid,
     sign,
     sin,
     slice,
+    split,
     softmax,
+    softplus,
     sqrt,
     sub_int,
     sub,
     sum,
     sum_int,
     tanh,
+    tile,
+    top_k,
     transpose,
+    trilu,
     conv_transpose2d,
     conv_transpose3d,
     pow,
@@ -1249,6 +1276,238 @@ mod tests {
         output.to_data().assert_eq(&expected, true);
     }
 
+    #[test]
+    fn if_else() {
+        let device = Default::default();
+        let model: if_else::Model<Backend> = if_else::Model::new(&device);
+
+        // Run the model
+        let input = Tensor::<Backend, 2>::from_floats([[-1., 0., 1.], [2., -3., 4.]], &device);
+        let output = model.forward(input.clone(), true);
+        let expected = TensorData::from([[-2f32, 0., 3.], [6., -6., 12.]]);
+        output.to_data().assert_eq(&expected, true);
+
+        let output = model.forward(input, false);
+        let expected = TensorData::from([[1f32, 0., 1.], [4., 9., 16.]]);
+        output.to_data().assert_eq(&expected, true);
+    }
+
+    #[test]
+    fn loop_cond() {
+        let device = Default::default();
+        let model: loop_cond::Model<Backend> = loop_cond::Model::new(&device);
+
+        // Run the model until the condition is false
+        let input = Tensor::<Backend, 2>::from_floats([[1., 2., 3.], [4., 5., 6.]], &device);
+        let (output, scans) = model.forward(input.clone(), 10);
+        output
+            .to_data()
+            .assert_eq(&input.clone().mul_scalar(4).to_data(), true);
+        let expected = Tensor::stack::<3>(
+            [
+                input.clone().mul_scalar(2),
+                input.clone().mul_scalar(3),
+                input.clone().mul_scalar(4),
+            ]
+            .to_vec(),
+            0,
+        );
+        scans.to_data().assert_eq(&expected.to_data(), true);
+
+        // Run the model until the maximum trip count is reached
+        let (output, scans) = model.forward(input.clone(), 1);
+        output
+            .to_data()
+            .assert_eq(&input.clone().mul_scalar(2).to_data(), true);
+        assert_eq!(scans.dims(), [1, 2, 3]);
+    }
+
+    #[test]
+    fn scan() {
+        let device = Default::default();
+        let model: scan::Model<Backend> = scan::Model::new(&device);
+
+        // Run the model
+        let init = Tensor::<Backend, 1>::from_floats([1., 0., -1.], &device);
+        let xs = Tensor::<Backend, 2>::from_floats(
+            [[1., 2., 3.], [0., 1., 0.], [2., 0., 1.], [-1., 1., 2.]],
+            &device,
+        );
+        let (final_state, ys) = model.forward(init, xs);
+
+        // The rows are scanned from the last one
+        let expected = TensorData::from([3f32, 4., 5.]);
+        final_state.to_data().assert_eq(&expected, true);
+        let expected =
+            TensorData::from([[0f32, 1., 1.], [4., 1., 4.], [4., 4., 4.], [9., 16., 25.]]);
+        ys.to_data().assert_eq(&expected, true);
+    }
+
+    #[test]
+    fn lstm() {
+        // Initialize the model with weights (loaded from the exported file)
+        let device = Default::default();
+        let model: lstm::Model<Backend> = lstm::Model::default();
+
+        // Run the model
+        let input = Tensor::<Backend, 3>::from_floats(
+            [
+                [[0.1, 0.2], [-0.1, 0.4]],
+                [[0.2, 0.1], [-0.2, 0.3]],
+                [[0.3, 0.0], [-0.3, 0.2]],
+            ],
+            &device,
+        );
+        let initial_hidden =
+            Tensor::<Backend, 3>::from_floats([[[0.1, 0.2, 0.3], [0.2, 0.4, 0.6]]], &device);
+        let initial_cell = Tensor::<Backend, 3>::from_floats(
+            [[[-0.1, -0.2, -0.3], [-0.05, -0.15, -0.25]]],
+            &device,
+        );
+        let (output, output_hidden, output_cell) =
+            model.forward(input, initial_hidden, initial_cell);
+
+        // data from the ONNX reference implementation
+        let expected = TensorData::from([
+            [[[-0.0991f32, -0.0071, -0.1463], [-0.1194, 0.0624, -0.1578]]],
+            [[[-0.0662, -0.0370, -0.1116], [-0.0503, -0.0168, -0.1545]]],
+            [[[-0.0558, -0.0548, -0.0750], [0.0138, -0.0736, -0.1191]]],
+        ]);
+        output.to_data().assert_approx_eq(&expected, 3);
+        let expected =
+            TensorData::from([[[-0.0558f32, -0.0548, -0.0750], [0.0138, -0.0736, -0.1191]]]);
+        output_hidden.to_data().assert_approx_eq(&expected, 3);
+        let expected =
+            TensorData::from([[[-0.1180f32, -0.1156, -0.1616], [0.0271, -0.1787, -0.2525]]]);
+        output_cell.to_data().assert_approx_eq(&expected, 3);
+    }
+
+    #[test]
+    fn lstm_bidirectional() {
+        // Initialize the model with weights (loaded from the exported file)
+        let device = Default::default();
+        let model: lstm_bidirectional::Model<Backend> = lstm_bidirectional::Model::default();
+
+        // Run the model
+        let input = Tensor::<Backend, 3>::from_floats(
+            [
+                [[0.1, 0.2], [-0.1, 0.4]],
+                [[0.2, 0.1], [-0.2, 0.3]],
+                [[0.3, 0.0], [-0.3, 0.2]],
+            ],
+            &device,
+        );
+        let (output, output_hidden) = model.forward(input);
+
+        // data from the ONNX reference implementation
+        let expected = TensorData::from([
+            [
+                [[-0.0060f32, 0.0346, -0.0185], [0.0196, 0.0305, -0.0352]],
+                [[-0.0600, 0.0421, 0.0267], [-0.0241, 0.0663, -0.0123]],
+            ],
+            [
+                [[-0.0224, 0.0573, -0.0200], [0.0365, 0.0230, -0.0454]],
+                [[-0.0487, 0.0190, 0.0372], [0.0036, 0.0400, -0.0247]],
+            ],
+            [
+                [[-0.0419, 0.0743, -0.0137], [0.0542, -0.0024, -0.0412]],
+                [[-0.0294, 0.0007, 0.0356], [0.0152, 0.0205, -0.0271]],
+            ],
+        ]);
+        output.to_data().assert_approx_eq(&expected, 3);
+        let expected = TensorData::from([
+            [[-0.0419f32, 0.0743, -0.0137], [0.0542, -0.0024, -0.0412]],
+            [[-0.0600, 0.0421, 0.0267], [-0.0241, 0.0663, -0.0123]],
+        ]);
+        output_hidden.to_data().assert_approx_eq(&expected, 3);
+    }
+
+    #[test]
+    fn gru() {
+        // Initialize the model with weights (loaded from the exported file)
+        let device = Default::default();
+        let model: gru::Model<Backend> = gru::Model::default();
+
+        // Run the model
+        let input = Tensor::<Backend, 3>::from_floats(
+            [
+                [[0.1, 0.2], [-0.1, 0.4]],
+                [[0.2, 0.1], [-0.2, 0.3]],
+                [[0.3, 0.0], [-0.3, 0.2]],
+            ],
+            &device,
+        );
+        let initial_hidden =
+            Tensor::<Backend, 3>::from_floats([[[0.1, 0.2, 0.3], [0.2, 0.4, 0.6]]], &device);
+        let (output, output_hidden) = model.forward(input, initial_hidden);
+
+        // The sequence is processed from its end, so the last state is the first output
+        let expected = TensorData::from([
+            [[[0.0827f32, 0.0860, -0.0280], [0.2232, 0.0036, -0.0139]]],
+            [[[0.0725, 0.0978, 0.0546], [0.2476, -0.0376, 0.1596]]],
+            [[[0.0785, 0.1251, 0.1629], [0.2613, 0.0161, 0.3826]]],
+        ]);
+        output.to_data().assert_approx_eq(&expected, 3);
+        let expected =
+            TensorData::from([[[0.0827f32, 0.0860, -0.0280], [0.2232, 0.0036, -0.0139]]]);
+        output_hidden.to_data().assert_approx_eq(&expected, 3);
+    }
+
+    #[test]
+    fn gru_linear_before_reset() {
+        // Initialize the model with weights (loaded from the exported file)
+        let device = Default::default();
+        let model: gru_linear_before_reset::Model<Backend> =
+            gru_linear_before_reset::Model::default();
+
+        // Run the model
+        let input = Tensor::<Backend, 3>::from_floats(
+            [
+                [[0.1, 0.2], [-0.1, 0.4]],
+                [[0.2, 0.1], [-0.2, 0.3]],
+                [[0.3, 0.0], [-0.3, 0.2]],
+            ],
+            &device,
+        );
+        let initial_hidden =
+            Tensor::<Backend, 3>::from_floats([[[0.1, 0.2, 0.3], [0.2, 0.4, 0.6]]], &device);
+        let (output, output_hidden) = model.forward(input, initial_hidden);
+
+        // The reset gate is applied to the hidden state transformed by the new gate
+        let expected = TensorData::from([
+            [[[0.1264f32, 0.1207, 0.1407], [0.2557, 0.1185, 0.3016]]],
+            [[[0.1048, 0.1115, 0.0629], [0.2580, 0.0166, 0.1463]]],
+            [[[0.0640, 0.1233, 0.0348], [0.2455, -0.0446, 0.0790]]],
+        ]);
+        output.to_data().assert_approx_eq(&expected, 3);
+        let expected = TensorData::from([[[0.0640f32, 0.1233, 0.0348], [0.2455, -0.0446, 0.0790]]]);
+        output_hidden.to_data().assert_approx_eq(&expected, 3);
+    }
+
+    #[test]
+    fn rnn() {
+        // Initialize the model with weights (loaded from the exported file)
+        let device = Default::default();
+        let model: rnn::Model<Backend> = rnn::Model::default();
+
+        // Run the model, with batch first sequences
+        let input = Tensor::<Backend, 3>::from_floats(
+            [
+                [[0.1, 0.2], [0.2, 0.1], [0.3, 0.0]],
+                [[-0.1, 0.4], [-0.2, 0.3], [-0.3, 0.2]],
+            ],
+            &device,
+        );
+        let output_hidden = model.forward(input);
+
+        // data from the ONNX reference implementation
+        let expected = TensorData::from([
+            [[0.0939f32, 0.0500, -0.0518], [0.0082, 0.1389, -0.1821]],
+            [[0.1652, -0.1883, 0.0550], [0.1140, 0.0615, -0.1992]],
+        ]);
+        output_hidden.to_data().assert_approx_eq(&expected, 3);
+    }
+
     #[test]
     fn recip() {
         // Initialize the model
@@ -1691,4 +1950,356 @@ mod tests {
         assert!(i_output.equal(i_expected).all().into_scalar());
         assert!(b_output.equal(b_expected).all().into_scalar());
     }
+
+    #[test]
+    fn pad() {
+        let device = Default::default();
+        let model: pad::Model<Backend> = pad::Model::new(&device);
+
+        let input = Tensor::<Backend, 2>::from_floats([[1.0, 2.0, 3.0], [4.0, 5.0, 6.0]], &device);
+        let output = model.forward(input);
+
+        let expected = TensorData::from([
+            [0.5f32, 1.0, 2.0, 3.0],
+            [0.5, 4.0, 5.0, 6.0],
+            [0.5, 0.5, 0.5, 0.5],
+            [0.5, 0.5, 0.5, 0.5],
+        ]);
+        output.to_data().assert_eq(&expected, true);
+    }
+
+    #[test]
+    fn split() {
+        let device = Default::default();
+        let model: split::Model<Backend> = split::Model::new(&device);
+
+        let input = Tensor::<Backend, 1, Int>::arange(0..12, &device)
+            .float()
+            .reshape([2, 6]);
+        let (output1, output2, output3, output4) = model.forward(input);
+
+        let expected1 = TensorData::from([[0f32, 1.], [6., 7.]]);
+        let expected2 = TensorData::from([[2f32, 3., 4., 5.], [8., 9., 10., 11.]]);
+        let expected3 = TensorData::from([[0f32, 1., 2., 3., 4., 5.]]);
+        let expected4 = TensorData::from([[6f32, 7., 8., 9., 10., 11.]]);
+        output1.to_data().assert_eq(&expected1, true);
+        output2.to_data().assert_eq(&expected2, true);
+        output3.to_data().assert_eq(&expected3, true);
+        output4.to_data().assert_eq(&expected4, true);
+    }
+
+    #[test]
+    fn tile() {
+        let device = Default::default();
+        let model: tile::Model<Backend> = tile::Model::new(&device);
+
+        let input = Tensor::<Backend, 3>::from_floats([[[1.0, 2.0], [3.0, 4.0]]], &device);
+        let output = model.forward(input);
+
+        let expected = TensorData::from([
+            [[1f32, 2., 1., 2., 1., 2.], [3., 4., 3., 4., 3., 4.]],
+            [[1., 2., 1., 2., 1., 2.], [3., 4., 3., 4., 3., 4.]],
+        ]);
+        output.to_data().assert_eq(&expected, true);
+    }
+
+    #[test]
+    fn top_k() {
+        let device = Default::default();
+        let model: top_k::Model<Backend> = top_k::Model::new(&device);
+
+        let input = Tensor::<Backend, 2>::from_floats(
+            [[0.1, 0.5, -0.3, 0.9, 0.2], [1.0, -1.0, 0.3, 0.0, 0.7]],
+            &device,
+        );
+        let (values_largest, indices_largest, values_smallest, indices_smallest) =
+            model.forward(input);
+
+        let expected = TensorData::from([[0.9f32, 0.5], [1.0, 0.7]]);
+        values_largest.to_data().assert_eq(&expected, true);
+        let expected = TensorData::from([[3i64, 1], [0, 4]]);
+        indices_largest.to_data().assert_eq(&expected, true);
+        let expected = TensorData::from([[0.1f32, -1.0, -0.3, 0.0, 0.2]]);
+        values_smallest.to_data().assert_eq(&expected, true);
+        let expected = TensorData::from([[0i64, 1, 0, 1, 0]]);
+        indices_smallest.to_data().assert_eq(&expected, true);
+    }
+
+    #[test]
+    fn trilu() {
+        let device = Default::default();
+        let model: trilu::Model<Backend> = trilu::Model::new(&device);
+
+        let input = Tensor::<Backend, 2>::from_floats(
+            [
+                [1.0, 2.0, 3.0, 4.0],
+                [5.0, 6.0, 7.0, 8.0],
+                [9.0, 10.0, 11.0, 12.0],
+            ],
+            &device,
+        );
+        let (upper, lower) = model.forward(input);
+
+        let expected = TensorData::from([[0f32, 2., 3., 4.], [0., 0., 7., 8.], [0., 0., 0., 12.]]);
+        upper.to_data().assert_eq(&expected, true);
+        let expected = TensorData::from([[1f32, 0., 0., 0.], [5., 6., 0., 0.], [9., 10., 11., 0.]]);
+        lower.to_data().assert_eq(&expected, true);
+    }
+
+    #[test]
+    fn one_hot() {
+        let device = Default::default();
+        let model: one_hot::Model<Backend> = one_hot::Model::new(&device);
+
+        let indices = Tensor::<Backend, 2, Int>::from_ints([[0, 2], [-1, 1]], &device);
+        let output = model.forward(indices);
+
+        // The classes are along the axis 1, and the negative index is the last class
+        let expected = TensorData::from([
+            [[5f32, -1.], [-1., -1.], [-1., 5.]],
+            [[-1., -1.], [-1., 5.], [5., -1.]],
+        ]);
+        output.to_data().assert_eq(&expected, true);
+    }
+
+    #[test]
+    fn cumsum() {
+        let device = Default::default();
+        let model: cumsum::Model<Backend> = cumsum::Model::new(&device);
+
+        let input = Tensor::<Backend, 2>::from_floats([[1.0, 2.0, 3.0], [4.0, 5.0, 6.0]], &device);
+        let (output, output_exclusive_reverse) = model.forward(input);
+
+        let expected = TensorData::from([[1f32, 3., 6.], [4., 9., 15.]]);
+        output.to_data().assert_eq(&expected, true);
+        let expected = TensorData::from([[4f32, 5., 6.], [0., 0., 0.]]);
+        output_exclusive_reverse
+            .to_data()
+            .assert_eq(&expected, true);
+    }
+
+    #[test]
+    fn nonzero() {
+        let device = Default::default();
+        let model: nonzero::Model<Backend> = nonzero::Model::new(&device);
+
+        let input = Tensor::<Backend, 2>::from_floats([[1.0, 0.0, 2.0], [0.0, 0.0, 3.0]], &device);
+        let output = model.forward(input);
+
+        let expected = TensorData::from([[0i64, 0, 1], [0, 2, 2]]);
+        output.to_data().assert_eq(&expected, true);
+    }
+
+    #[test]
+    fn instance_norm() {
+        // Initialize the model with weights (loaded from the exported file)
+        let model: instance_norm::Model<Backend> = instance_norm::Model::default();
+
+        let device = Default::default();
+        let input = Tensor::<Backend, 4>::from_floats(
+            [[[[1.0, 2.0], [3.0, 4.0]], [[-1.0, 0.0], [2.0, 5.0]]]],
+            &device,
+        );
+        let output = model.forward(input);
+
+        let expected = TensorData::from([[
+            [[-0.84164f32, 0.05279], [0.94721, 1.84164]],
+            [[-2.68218, -1.80931], [-0.06356, 2.55505]],
+        ]]);
+        output.to_data().assert_approx_eq(&expected, 3);
+    }
+
+    #[test]
+    fn group_norm() {
+        // Initialize the model with weights (loaded from the exported file)
+        let model: group_norm::Model<Backend> = group_norm::Model::default();
+
+        let device = Default::default();
+        let input = Tensor::<Backend, 3>::from_floats(
+            [[[0.0, 1.0], [2.0, 3.0], [4.0, 5.0], [6.0, 8.0]]],
+            &device,
+        );
+        let output = model.forward(input);
+
+        let expected = TensorData::from([[
+            [-1.34164f32, -0.44721],
+            [0.44721, 1.34164],
+            [-1.36643, -0.01418],
+            [1.33806, 4.04255],
+        ]]);
+        output.to_data().assert_approx_eq(&expected, 3);
+    }
+
+    #[test]
+    fn hard_sigmoid() {
+        let device = Default::default();
+        let model: hard_sigmoid::Model<Backend> = hard_sigmoid::Model::new(&device);
+
+        let input =
+            Tensor::<Backend, 2>::from_floats([[-4.0, -1.0, 0.0], [0.5, 2.0, 3.5]], &device);
+        let output = model.forward(input);
+
+        let expected = TensorData::from([[0.0f32, 0.15, 0.4], [0.525, 0.9, 1.0]]);
+        output.to_data().assert_approx_eq(&expected, 4);
+    }
+
+    #[test]
+    fn hard_swish() {
+        let device = Default::default();
+        let model: hard_swish::Model<Backend> = hard_swish::Model::new(&device);
+
+        let input =
+            Tensor::<Backend, 2>::from_floats([[-4.0, -1.0, 0.0], [0.5, 2.0, 3.5]], &device);
+        let output = model.forward(input);
+
+        let expected = TensorData::from([[0.0f32, -0.33333, 0.0], [0.29167, 1.66667, 3.5]]);
+        output.to_data().assert_approx_eq(&expected, 4);
+    }
+
+    #[test]
+    fn elu() {
+        let device = Default::default();
+        let model: elu::Model<Backend> = elu::Model::new(&device);
+
+        let input =
+            Tensor::<Backend, 2>::from_floats([[-4.0, -1.0, 0.0], [0.5, 2.0, 3.5]], &device);
+        let output = model.forward(input);
+
+        let expected = TensorData::from([[-0.49084f32, -0.31606, 0.0], [0.5, 2.0, 3.5]]);
+        output.to_data().assert_approx_eq(&expected, 4);
+    }
+
+    #[test]
+    fn selu() {
+        let device = Default::default();
+        let model: selu::Model<Backend> = selu::Model::new(&device);
+
+        let input =
+            Tensor::<Backend, 2>::from_floats([[-4.0, -1.0, 0.0], [0.5, 2.0, 3.5]], &device);
+        let output = model.forward(input);
+
+        let expected =
+            TensorData::from([[-1.72590f32, -1.11133, 0.0], [0.52535, 2.10140, 3.67745]]);
+        output.to_data().assert_approx_eq(&expected, 4);
+    }
+
+    #[test]
+    fn softplus() {
+        let device = Default::default();
+        let model: softplus::Model<Backend> = softplus::Model::new(&device);
+
+        let input =
+            Tensor::<Backend, 2>::from_floats([[-4.0, -1.0, 0.0], [0.5, 2.0, 3.5]], &device);
+        let output = model.forward(input);
+
+        let expected = TensorData::from([
+            [0.01815f32, 0.31326, consts::LN_2 as f32],
+            [0.97408, 2.12693, 3.52975],
+        ]);
+        output.to_data().assert_approx_eq(&expected, 4);
+    }
+
+    #[test]
+    fn dynamic_shape() {
+        // Initialize the model with weights (loaded from the exported file)
+        let model: dynamic_shape::Model<Backend> = dynamic_shape::Model::default();
+
+        let device = Default::default();
+        // The batch size is only known at runtime
+        for batch in [1, 3] {
+            let input = Tensor::<Backend, 1, Int>::arange(0..batch as i64 * 12, &device)
+                .float()
+                .reshape([batch, 3, 4]);
+            let (flat, restored, expanded, sliced, merged) = model.forward(input.clone());
+
+            assert_eq!(flat.dims(), [batch, 12]);
+            flat.to_data()
+                .assert_eq(&input.clone().reshape([batch, 12]).to_data(), true);
+            restored.to_data().assert_eq(&input.to_data(), true);
+            expanded.to_data().assert_eq(
+                &Tensor::<Backend, 1>::from_floats([1.0, 2.0, 3.0, 4.0], &device)
+                    .reshape([1, 1, 4])
+                    .expand([batch, 3, 4])
+                    .to_data(),
+                true,
+            );
+            sliced
+                .to_data()
+                .assert_eq(&input.clone().slice([0..batch, 0..3, 0..3]).to_data(), true);
+            merged
+                .to_data()
+                .assert_eq(&input.reshape([batch * 3, 4]).to_data(), true);
+        }
+    }
+
+    #[test]
+    fn external_data() {
+        // Initialize the model with weights (loaded from the exported file)
+        let model: external_data::Model<Backend> = external_data::Model::default();
+
+        let device = Default::default();
+        let input = Tensor::<Backend, 2>::from_floats([[1.0, 2.0, 3.0], [4.0, 5.0, 6.0]], &device);
+        let output = model.forward(input);
+
+        // The weights, bias and constant are read from the external data file
+        let expected = TensorData::from([[1.9f32, 4.8, 7.7, 10.6], [2.8, 8.4, 14.0, 19.6]]);
+        output.to_data().assert_approx_eq(&expected, 4);
+    }
+
+    #[test]
+    fn opset_upgrade() {
+        let device = Default::default();
+        let model: opset_upgrade::Model<Backend> = opset_upgrade::Model::new(&device);
+
+        let input = Tensor::<Backend, 1, Int>::arange(0..24, &device)
+            .float()
+            .reshape([2, 3, 4]);
+        let (padded, values, indices, mean, split, squeezed) = model.forward(input.clone());
+
+        let sliced = input.clone().slice([0..2, 0..3, 0..2]);
+        let expected_padded =
+            Tensor::full([2, 3, 3], 1.5, &device).slice_assign([0..2, 0..3, 0..2], sliced);
+        padded.to_data().assert_eq(&expected_padded.to_data(), true);
+        values.to_data().assert_eq(
+            &input.clone().slice([0..2, 0..3, 2..4]).flip([2]).to_data(),
+            true,
+        );
+        indices.to_data().assert_eq(
+            &Tensor::<Backend, 1, Int>::from_ints([3, 2], &device)
+                .reshape([1, 1, 2])
+                .expand([2, 3, 2])
+                .to_data(),
+            true,
+        );
+        mean.to_data()
+            .assert_eq(&input.clone().mean_dim(1).to_data(), true);
+        split
+            .to_data()
+            .assert_eq(&input.clone().slice([0..2, 1..3, 0..4]).to_data(), true);
+        squeezed.to_data().assert_eq(
+            &input.slice([0..2, 0..1, 0..4]).reshape([2, 4]).to_data(),
+            true,
+        );
+    }
+
+    #[test]
+    fn optimize() {
+        let device = Default::default();
+        let model: optimize::Model<Backend> = optimize::Model::default();
+
+        let input = Tensor::<Backend, 1, Int>::arange(0..32, &device)
+            .float()
+            .mul_scalar(0.1)
+            .reshape([1, 2, 4, 4]);
+        let output = model.forward(input);
+
+        let expected = TensorData::from([[
+            -0.2100f32, -1.4400, -1.4100, -2.5800, -1.3200, -1.9500, -2.0400, -2.8500, -1.4400,
+            -2.3100, -2.4000, -3.2100, -1.1100, 0.1800, 0.1800, 0.0600, 0.3000, 0.2475, 0.2250,
+            0.5925, 0.5700, 0.5400, 0.5475, 0.7650, 0.5700, 0.5700, 0.5775, 0.8550, 0.9525, 0.5250,
+            0.5325, 0.1800, 7.1399, 6.7799, 7.1399, 7.9798, 5.8199, 2.5799, 2.5799, 2.5799, 6.2999,
+            2.5799, 2.5799, 2.1000, -3.1799, -4.0199, -4.3799, 3.4199,
+        ]]);
+        output.to_data().assert_approx_eq(&expected, 3);
+    }
 }
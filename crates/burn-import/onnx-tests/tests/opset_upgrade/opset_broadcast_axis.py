@@ -0,0 +1,31 @@
+#!/usr/bin/env python3
+
+# used to generate model: onnx-tests/tests/opset_upgrade/opset_broadcast_axis.onnx
+
+import onnx
+from onnx import helper, TensorProto
+
+
+def main():
+    # Opset 6 broadcasting aligned on an axis, which has no equivalent in later opsets
+    graph_def = helper.make_graph(
+        nodes=[
+            helper.make_node('Add', inputs=['X', 'Y'], outputs=['Z'], broadcast=1, axis=1),
+        ],
+        name='main_graph',
+        inputs=[
+            helper.make_tensor_value_info('X', TensorProto.FLOAT, [2, 3, 4]),
+            helper.make_tensor_value_info('Y', TensorProto.FLOAT, [3]),
+        ],
+        outputs=[helper.make_tensor_value_info('Z', TensorProto.FLOAT, [2, 3, 4])],
+    )
+
+    model_def = helper.make_model(
+        graph_def, producer_name='onnx-tests', opset_imports=[helper.make_opsetid('', 6)]
+    )
+
+    onnx.save(model_def, 'opset_broadcast_axis.onnx')
+
+
+if __name__ == '__main__':
+    main()
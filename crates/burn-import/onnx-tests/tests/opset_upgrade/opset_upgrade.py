@@ -0,0 +1,48 @@
+#!/usr/bin/env python3
+
+# used to generate model: onnx-tests/tests/opset_upgrade/opset_upgrade.onnx
+
+import numpy as np
+import onnx
+from onnx import helper, TensorProto
+from onnx.reference import ReferenceEvaluator
+
+
+def main():
+    # Opset 9 nodes whose attributes became inputs in later opsets
+    graph_def = helper.make_graph(
+        nodes=[
+            helper.make_node('Slice', inputs=['X'], outputs=['S'], starts=[0], ends=[2], axes=[2]),
+            helper.make_node(
+                'Pad', inputs=['S'], outputs=['P'], mode='constant', pads=[0, 0, 0, 0, 0, 1], value=1.5
+            ),
+            helper.make_node('TopK', inputs=['X'], outputs=['V', 'I'], k=2, axis=-1),
+            helper.make_node('ReduceMean', inputs=['X'], outputs=['M'], axes=[1], keepdims=1),
+            helper.make_node('Split', inputs=['X'], outputs=['A', 'B'], axis=1, split=[1, 2]),
+            helper.make_node('Squeeze', inputs=['A'], outputs=['Q'], axes=[1]),
+        ],
+        name='main_graph',
+        inputs=[helper.make_tensor_value_info('X', TensorProto.FLOAT, [2, 3, 4])],
+        outputs=[
+            helper.make_tensor_value_info('P', TensorProto.FLOAT, [2, 3, 3]),
+            helper.make_tensor_value_info('V', TensorProto.FLOAT, [2, 3, 2]),
+            helper.make_tensor_value_info('I', TensorProto.INT64, [2, 3, 2]),
+            helper.make_tensor_value_info('M', TensorProto.FLOAT, [2, 1, 4]),
+            helper.make_tensor_value_info('B', TensorProto.FLOAT, [2, 2, 4]),
+            helper.make_tensor_value_info('Q', TensorProto.FLOAT, [2, 4]),
+        ],
+    )
+
+    model_def = helper.make_model(
+        graph_def, producer_name='onnx-tests', opset_imports=[helper.make_opsetid('', 9)]
+    )
+
+    onnx.save(model_def, 'opset_upgrade.onnx')
+
+    x = np.arange(24, dtype=np.float32).reshape(2, 3, 4)
+    for name, value in zip(['P', 'V', 'I', 'M', 'B', 'Q'], ReferenceEvaluator(model_def).run(None, {'X': x})):
+        print(f'{name}: {value}')
+
+
+if __name__ == '__main__':
+    main()
@@ -0,0 +1,71 @@
+#!/usr/bin/env python3
+
+# used to generate model: onnx-tests/tests/optimize/optimize.onnx
+
+import numpy as np
+import onnx
+from onnx import helper, TensorProto
+from onnx.reference import ReferenceEvaluator
+
+
+def main():
+    # A graph simplified by the optimization passes: the batch normalization is folded into the
+    # convolution, the dropout is removed, the target shape of the reshape and the factor are
+    # constants, and the negation is not used
+    weight = ((np.arange(54) % 5 - 2) * 0.1).astype(np.float32).reshape(3, 2, 3, 3)
+    initializers = [
+        helper.make_tensor('weight', TensorProto.FLOAT, weight.shape, weight.flatten()),
+        helper.make_tensor('bias', TensorProto.FLOAT, [3], [0.1, -0.2, 0.3]),
+        helper.make_tensor('scale', TensorProto.FLOAT, [3], [1.0, 0.5, 2.0]),
+        helper.make_tensor('shift', TensorProto.FLOAT, [3], [0.0, 0.1, -0.1]),
+        helper.make_tensor('mean', TensorProto.FLOAT, [3], [0.5, -0.5, 0.0]),
+        helper.make_tensor('var', TensorProto.FLOAT, [3], [1.0, 4.0, 0.25]),
+    ]
+
+    def constant(name, data_type, dims, values):
+        return helper.make_node(
+            'Constant', inputs=[], outputs=[name], value=helper.make_tensor(name, data_type, dims, values)
+        )
+
+    graph_def = helper.make_graph(
+        nodes=[
+            helper.make_node(
+                'Conv', inputs=['X', 'weight', 'bias'], outputs=['C'], kernel_shape=[3, 3], pads=[1, 1, 1, 1]
+            ),
+            helper.make_node(
+                'BatchNormalization', inputs=['C', 'scale', 'shift', 'mean', 'var'], outputs=['N'], epsilon=1e-5
+            ),
+            helper.make_node('Dropout', inputs=['N'], outputs=['D']),
+            helper.make_node('Shape', inputs=['X'], outputs=['S']),
+            constant('index', TensorProto.INT64, [], [0]),
+            helper.make_node('Gather', inputs=['S', 'index'], outputs=['batch'], axis=0),
+            constant('axes', TensorProto.INT64, [1], [0]),
+            helper.make_node('Unsqueeze', inputs=['batch', 'axes'], outputs=['batch_dim']),
+            constant('minus_one', TensorProto.INT64, [1], [-1]),
+            helper.make_node('Concat', inputs=['batch_dim', 'minus_one'], outputs=['new_shape'], axis=0),
+            helper.make_node('Reshape', inputs=['D', 'new_shape'], outputs=['F']),
+            constant('two', TensorProto.FLOAT, [1, 1], [2.0]),
+            constant('half', TensorProto.FLOAT, [1, 1], [0.5]),
+            helper.make_node('Mul', inputs=['two', 'half'], outputs=['one']),
+            helper.make_node('Add', inputs=['one', 'two'], outputs=['factor']),
+            helper.make_node('Mul', inputs=['F', 'factor'], outputs=['Y']),
+            helper.make_node('Neg', inputs=['X'], outputs=['unused']),
+        ],
+        name='main_graph',
+        inputs=[helper.make_tensor_value_info('X', TensorProto.FLOAT, [1, 2, 4, 4])],
+        outputs=[helper.make_tensor_value_info('Y', TensorProto.FLOAT, [1, 48])],
+        initializer=initializers,
+    )
+
+    model_def = helper.make_model(
+        graph_def, producer_name='onnx-tests', opset_imports=[helper.make_opsetid('', 13)]
+    )
+
+    onnx.save(model_def, 'optimize.onnx')
+
+    x = (np.arange(32, dtype=np.float32) * 0.1).reshape(1, 2, 4, 4)
+    print(f'Y: {ReferenceEvaluator(model_def).run(None, {"X": x})[0]}')
+
+
+if __name__ == '__main__':
+    main()
@@ -0,0 +1,40 @@
+#!/usr/bin/env python3
+
+# used to generate model: onnx-tests/tests/pad/pad.onnx
+
+import numpy as np
+import onnx
+from onnx import helper, numpy_helper, TensorProto
+from onnx.reference import ReferenceEvaluator
+
+
+def main():
+    # The pads of each axis are [begin_0, begin_1, end_0, end_1]
+    initializers = [
+        numpy_helper.from_array(np.array([0, 1, 2, 0], dtype=np.int64), 'pads'),
+        numpy_helper.from_array(np.array(0.5, dtype=np.float32), 'value'),
+    ]
+
+    graph_def = helper.make_graph(
+        nodes=[
+            helper.make_node('Pad', inputs=['X', 'pads', 'value'], outputs=['Y'], mode='constant'),
+        ],
+        name='PadGraph',
+        inputs=[helper.make_tensor_value_info('X', TensorProto.FLOAT, [2, 3])],
+        outputs=[helper.make_tensor_value_info('Y', TensorProto.FLOAT, [4, 4])],
+        initializer=initializers,
+    )
+
+    model_def = helper.make_model(
+        graph_def, producer_name='pad', opset_imports=[helper.make_opsetid('', 18)]
+    )
+
+    onnx.save(model_def, 'pad.onnx')
+
+    x = np.array([[1.0, 2.0, 3.0], [4.0, 5.0, 6.0]], dtype=np.float32)
+    (y,) = ReferenceEvaluator(model_def).run(None, {'X': x})
+    print(f'Y: {y}')
+
+
+if __name__ == '__main__':
+    main()
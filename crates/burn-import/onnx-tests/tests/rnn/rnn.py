@@ -0,0 +1,70 @@
+#!/usr/bin/env python3
+
+# used to generate model: onnx-tests/tests/rnn/rnn.onnx
+
+import numpy as np
+import onnx
+from onnx import helper, numpy_helper, TensorProto
+from onnx.reference import ReferenceEvaluator
+
+SEQ_LENGTH, BATCH_SIZE, INPUT_SIZE, HIDDEN_SIZE = 3, 2, 2, 3
+NUM_DIRECTIONS = 2
+
+
+def main():
+    # Deterministic weights of both directions
+    w = 0.5 * np.sin(np.arange(NUM_DIRECTIONS * HIDDEN_SIZE * INPUT_SIZE))
+    r = 0.5 * np.cos(np.arange(NUM_DIRECTIONS * HIDDEN_SIZE * HIDDEN_SIZE))
+    b = 0.1 * np.sin(np.arange(NUM_DIRECTIONS * 2 * HIDDEN_SIZE) + 1)
+    initializers = [
+        numpy_helper.from_array(
+            w.reshape(NUM_DIRECTIONS, HIDDEN_SIZE, INPUT_SIZE).astype(np.float32), 'W'
+        ),
+        numpy_helper.from_array(
+            r.reshape(NUM_DIRECTIONS, HIDDEN_SIZE, HIDDEN_SIZE).astype(np.float32), 'R'
+        ),
+        numpy_helper.from_array(b.reshape(NUM_DIRECTIONS, 2 * HIDDEN_SIZE).astype(np.float32), 'B'),
+    ]
+
+    graph_def = helper.make_graph(
+        nodes=[
+            # Batch first layout, where only the last hidden state is used
+            helper.make_node(
+                'RNN',
+                inputs=['X', 'W', 'R', 'B'],
+                outputs=['', 'Y_h'],
+                hidden_size=HIDDEN_SIZE,
+                direction='bidirectional',
+                layout=1,
+            ),
+        ],
+        name='RnnGraph',
+        inputs=[
+            helper.make_tensor_value_info(
+                'X', TensorProto.FLOAT, [BATCH_SIZE, SEQ_LENGTH, INPUT_SIZE]
+            ),
+        ],
+        outputs=[
+            helper.make_tensor_value_info(
+                'Y_h', TensorProto.FLOAT, [BATCH_SIZE, NUM_DIRECTIONS, HIDDEN_SIZE]
+            ),
+        ],
+        initializer=initializers,
+    )
+
+    model_def = helper.make_model(
+        graph_def, producer_name='rnn', opset_imports=[helper.make_opsetid('', 14)]
+    )
+
+    onnx.save(model_def, 'rnn.onnx')
+
+    x = np.array(
+        [[[0.1, 0.2], [0.2, 0.1], [0.3, 0.0]], [[-0.1, 0.4], [-0.2, 0.3], [-0.3, 0.2]]],
+        dtype=np.float32,
+    )
+    (y_h,) = ReferenceEvaluator(model_def).run(None, {'X': x})
+    print(f'Y_h: {y_h.round(4)}')
+
+
+if __name__ == '__main__':
+    main()
@@ -0,0 +1,55 @@
+#!/usr/bin/env python3
+
+# used to generate model: onnx-tests/tests/scan/scan.onnx
+
+import onnx
+from onnx import helper, TensorProto
+
+def main():
+    # Cumulative sum of the rows of xs, from the last one, with the squares of the partial sums
+    body = helper.make_graph(
+        nodes=[
+            helper.make_node('Add', inputs=['state', 'x_in'], outputs=['s']),
+            helper.make_node('Mul', inputs=['s', 's'], outputs=['y']),
+        ],
+        name='body',
+        inputs=[
+            helper.make_tensor_value_info('state', TensorProto.FLOAT, [3]),
+            helper.make_tensor_value_info('x_in', TensorProto.FLOAT, [3]),
+        ],
+        outputs=[
+            helper.make_tensor_value_info('s', TensorProto.FLOAT, [3]),
+            helper.make_tensor_value_info('y', TensorProto.FLOAT, [3]),
+        ],
+    )
+
+    graph_def = helper.make_graph(
+        nodes=[
+            helper.make_node(
+                'Scan',
+                inputs=['init', 'xs'],
+                outputs=['final_state', 'ys'],
+                body=body,
+                num_scan_inputs=1,
+                scan_input_directions=[1],
+            ),
+        ],
+        name='ScanGraph',
+        inputs=[
+            helper.make_tensor_value_info('init', TensorProto.FLOAT, [3]),
+            helper.make_tensor_value_info('xs', TensorProto.FLOAT, [4, 3]),
+        ],
+        outputs=[
+            helper.make_tensor_value_info('final_state', TensorProto.FLOAT, [3]),
+            helper.make_tensor_value_info('ys', TensorProto.FLOAT, [4, 3]),
+        ],
+    )
+
+    model_def = helper.make_model(
+        graph_def, producer_name='scan', opset_imports=[helper.make_opsetid('', 16)]
+    )
+
+    onnx.save(model_def, 'scan.onnx')
+
+if __name__ == '__main__':
+    main()
@@ -0,0 +1,44 @@
+#!/usr/bin/env python3
+
+# used to generate model: onnx-tests/tests/split/split.onnx
+
+import numpy as np
+import onnx
+from onnx import helper, numpy_helper, TensorProto
+from onnx.reference import ReferenceEvaluator
+
+
+def main():
+    initializers = [numpy_helper.from_array(np.array([2, 4], dtype=np.int64), 'split')]
+
+    graph_def = helper.make_graph(
+        nodes=[
+            # Split with explicit sizes
+            helper.make_node('Split', inputs=['X', 'split'], outputs=['A', 'B'], axis=1),
+            # Split in parts of equal size
+            helper.make_node('Split', inputs=['X'], outputs=['C', 'D'], axis=0),
+        ],
+        name='SplitGraph',
+        inputs=[helper.make_tensor_value_info('X', TensorProto.FLOAT, [2, 6])],
+        outputs=[
+            helper.make_tensor_value_info('A', TensorProto.FLOAT, [2, 2]),
+            helper.make_tensor_value_info('B', TensorProto.FLOAT, [2, 4]),
+            helper.make_tensor_value_info('C', TensorProto.FLOAT, [1, 6]),
+            helper.make_tensor_value_info('D', TensorProto.FLOAT, [1, 6]),
+        ],
+        initializer=initializers,
+    )
+
+    model_def = helper.make_model(
+        graph_def, producer_name='split', opset_imports=[helper.make_opsetid('', 13)]
+    )
+
+    onnx.save(model_def, 'split.onnx')
+
+    x = np.arange(12, dtype=np.float32).reshape(2, 6)
+    for name, value in zip('ABCD', ReferenceEvaluator(model_def).run(None, {'X': x})):
+        print(f'{name}: {value}')
+
+
+if __name__ == '__main__':
+    main()
@@ -0,0 +1,34 @@
+#!/usr/bin/env python3
+
+# used to generate model: onnx-tests/tests/tile/tile.onnx
+
+import numpy as np
+import onnx
+from onnx import helper, numpy_helper, TensorProto
+from onnx.reference import ReferenceEvaluator
+
+
+def main():
+    initializers = [numpy_helper.from_array(np.array([2, 1, 3], dtype=np.int64), 'repeats')]
+
+    graph_def = helper.make_graph(
+        nodes=[helper.make_node('Tile', inputs=['X', 'repeats'], outputs=['Y'])],
+        name='TileGraph',
+        inputs=[helper.make_tensor_value_info('X', TensorProto.FLOAT, [1, 2, 2])],
+        outputs=[helper.make_tensor_value_info('Y', TensorProto.FLOAT, [2, 2, 6])],
+        initializer=initializers,
+    )
+
+    model_def = helper.make_model(
+        graph_def, producer_name='tile', opset_imports=[helper.make_opsetid('', 13)]
+    )
+
+    onnx.save(model_def, 'tile.onnx')
+
+    x = np.array([[[1.0, 2.0], [3.0, 4.0]]], dtype=np.float32)
+    (y,) = ReferenceEvaluator(model_def).run(None, {'X': x})
+    print(f'Y: {y}')
+
+
+if __name__ == '__main__':
+    main()
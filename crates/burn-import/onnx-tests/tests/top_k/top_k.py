@@ -0,0 +1,50 @@
+#!/usr/bin/env python3
+
+# used to generate model: onnx-tests/tests/top_k/top_k.onnx
+
+import numpy as np
+import onnx
+from onnx import helper, numpy_helper, TensorProto
+from onnx.reference import ReferenceEvaluator
+
+
+def main():
+    initializers = [
+        numpy_helper.from_array(np.array([2], dtype=np.int64), 'k'),
+        numpy_helper.from_array(np.array([1], dtype=np.int64), 'k_smallest'),
+    ]
+
+    graph_def = helper.make_graph(
+        nodes=[
+            # Largest elements of the last axis
+            helper.make_node('TopK', inputs=['X', 'k'], outputs=['V1', 'I1']),
+            # Smallest elements of the first axis
+            helper.make_node(
+                'TopK', inputs=['X', 'k_smallest'], outputs=['V2', 'I2'], axis=0, largest=0
+            ),
+        ],
+        name='TopKGraph',
+        inputs=[helper.make_tensor_value_info('X', TensorProto.FLOAT, [2, 5])],
+        outputs=[
+            helper.make_tensor_value_info('V1', TensorProto.FLOAT, [2, 2]),
+            helper.make_tensor_value_info('I1', TensorProto.INT64, [2, 2]),
+            helper.make_tensor_value_info('V2', TensorProto.FLOAT, [1, 5]),
+            helper.make_tensor_value_info('I2', TensorProto.INT64, [1, 5]),
+        ],
+        initializer=initializers,
+    )
+
+    model_def = helper.make_model(
+        graph_def, producer_name='top_k', opset_imports=[helper.make_opsetid('', 11)]
+    )
+
+    onnx.save(model_def, 'top_k.onnx')
+
+    x = np.array([[0.1, 0.5, -0.3, 0.9, 0.2], [1.0, -1.0, 0.3, 0.0, 0.7]], dtype=np.float32)
+    outputs = ReferenceEvaluator(model_def).run(None, {'X': x})
+    for name, value in zip(['V1', 'I1', 'V2', 'I2'], outputs):
+        print(f'{name}: {value}')
+
+
+if __name__ == '__main__':
+    main()
@@ -0,0 +1,43 @@
+#!/usr/bin/env python3
+
+# used to generate model: onnx-tests/tests/trilu/trilu.onnx
+
+import numpy as np
+import onnx
+from onnx import helper, numpy_helper, TensorProto
+from onnx.reference import ReferenceEvaluator
+
+
+def main():
+    initializers = [numpy_helper.from_array(np.array(1, dtype=np.int64), 'k')]
+
+    graph_def = helper.make_graph(
+        nodes=[
+            # Upper triangular part above the main diagonal
+            helper.make_node('Trilu', inputs=['X', 'k'], outputs=['U']),
+            # Lower triangular part
+            helper.make_node('Trilu', inputs=['X'], outputs=['L'], upper=0),
+        ],
+        name='TriluGraph',
+        inputs=[helper.make_tensor_value_info('X', TensorProto.FLOAT, [3, 4])],
+        outputs=[
+            helper.make_tensor_value_info('U', TensorProto.FLOAT, [3, 4]),
+            helper.make_tensor_value_info('L', TensorProto.FLOAT, [3, 4]),
+        ],
+        initializer=initializers,
+    )
+
+    model_def = helper.make_model(
+        graph_def, producer_name='trilu', opset_imports=[helper.make_opsetid('', 14)]
+    )
+
+    onnx.save(model_def, 'trilu.onnx')
+
+    x = np.arange(1, 13, dtype=np.float32).reshape(3, 4)
+    upper, lower = ReferenceEvaluator(model_def).run(None, {'X': x})
+    print(f'U: {upper}')
+    print(f'L: {lower}')
+
+
+if __name__ == '__main__':
+    main()
@@ -1,4 +1,4 @@
-use super::{BurnImports, Scope, Type};
+use super::{BurnImports, Scope, ToTokens, Type};
 use crate::burn::{
     node::{Node, NodeCodegen},
     TensorKind, TensorType,
@@ -13,7 +13,13 @@ use serde::{
     ser::{SerializeMap, SerializeTuple},
     Serialize,
 };
-use std::{any::type_name, collections::HashMap, marker::PhantomData, path::PathBuf};
+use std::{
+    any::type_name,
+    collections::HashMap,
+    fs,
+    marker::PhantomData,
+    path::{Path, PathBuf},
+};
 
 /// Type of the record to be saved.
 #[derive(Debug, Clone, Default, Copy)]
@@ -49,6 +55,9 @@ pub struct BurnGraph<PS: PrecisionSettings> {
     _ps: PhantomData<PS>,
 }
 
+/// The maximum size of a record embedded in the generated code.
+const MAX_EMBEDDED_RECORD_SIZE: u64 = i32::MAX as u64;
+
 // The backend used for recording.
 type Backend = burn::backend::ndarray::NdArray;
 
@@ -64,6 +73,11 @@ impl<PS: PrecisionSettings> BurnGraph<PS> {
         self.nodes.push(node);
     }
 
+    /// Consume the graph and return its nodes, to be nested in a [sub-graph](SubGraph).
+    pub fn into_nodes(self) -> Vec<Node<PS>> {
+        self.nodes
+    }
+
     /// Save the state of each node in a record file.
     ///
     /// The `Default` trait will be implemented for the generated model, which will load the record
@@ -78,7 +92,8 @@ impl<PS: PrecisionSettings> BurnGraph<PS> {
     ///
     /// # Panics
     ///
-    /// Panics if the record type is not `RecordType::Bincode` and `embed_states` is `true`.
+    /// Panics if the record type is not `RecordType::Bincode` and `embed_states` is `true`, or if
+    /// the record is too large to be embedded.
     pub fn with_record(
         mut self,
         out_file: PathBuf,
@@ -96,7 +111,7 @@ impl<PS: PrecisionSettings> BurnGraph<PS> {
                 Recorder::<Backend>::save_item(
                     &recorder,
                     BurnRecord::<_, Backend>::new::<PrettyJsonFileRecorder<PS>>(StructMap(
-                        BurnGraphState::new(&self.nodes),
+                        BurnGraphState::new(all_nodes(&self.nodes)),
                     )),
                     out_file.clone(),
                 )
@@ -118,7 +133,7 @@ impl<PS: PrecisionSettings> BurnGraph<PS> {
                 Recorder::<Backend>::save_item(
                     &recorder,
                     BurnRecord::<_, Backend>::new::<NamedMpkGzFileRecorder<PS>>(StructMap(
-                        BurnGraphState::new(&self.nodes),
+                        BurnGraphState::new(all_nodes(&self.nodes)),
                     )),
                     out_file.clone(),
                 )
@@ -140,7 +155,7 @@ impl<PS: PrecisionSettings> BurnGraph<PS> {
                 Recorder::<Backend>::save_item(
                     &recorder,
                     BurnRecord::<_, Backend>::new::<NamedMpkGzFileRecorder<PS>>(StructMap(
-                        BurnGraphState::new(&self.nodes),
+                        BurnGraphState::new(all_nodes(&self.nodes)),
                     )),
                     out_file.clone(),
                 )
@@ -163,13 +178,14 @@ impl<PS: PrecisionSettings> BurnGraph<PS> {
                 Recorder::<Backend>::save_item(
                     &recorder,
                     BurnRecord::<_, Backend>::new::<BinFileRecorder<PS>>(StructTuple(
-                        BurnGraphState::new(&self.nodes),
+                        BurnGraphState::new(all_nodes(&self.nodes)),
                     )),
                     out_file.clone(),
                 )
                 .unwrap();
 
                 if embed_states {
+                    assert_embeddable::<PS>(&out_file);
                     self.register_record_embed(out_file);
                 } else {
                     self.register_record_file(
@@ -254,8 +270,8 @@ impl<PS: PrecisionSettings> BurnGraph<PS> {
 
     fn register_imports(&mut self) {
         // Register imports from nodes
-        self.nodes
-            .iter()
+        all_nodes(&self.nodes)
+            .into_iter()
             .for_each(|node| node.register_imports(&mut self.imports));
 
         // Combine input and output types into a single vector
@@ -287,49 +303,11 @@ impl<PS: PrecisionSettings> BurnGraph<PS> {
     fn build_scope(&mut self) {
         log::debug!("Building the scope nodes len => '{}'", self.nodes.len());
 
-        fn to_tensor(ty: Type) -> Option<TensorType> {
-            match ty {
-                Type::Tensor(tensor) => Some(tensor),
-                Type::Scalar(_) => None,
-                Type::Other(_) => None,
-                Type::Shape(_) => None,
-            }
-        }
-
-        // Register graph tensor input with 0 as node position
-        self.graph_input_types
-            .clone()
-            .into_iter()
-            .flat_map(to_tensor)
-            .for_each(|tensor| {
-                self.scope.tensor_register_variable(&tensor, 0);
-            });
-
-        self.nodes
-            .iter()
-            .enumerate()
-            .for_each(|(node_position, node)| {
-                node.output_types()
-                    .into_iter()
-                    .flat_map(to_tensor)
-                    .for_each(|tensor| {
-                        self.scope
-                            .tensor_register_variable(&tensor, node_position + 1)
-                    })
-            });
-
-        self.nodes
-            .iter()
-            .enumerate()
-            .for_each(|(node_position, node)| {
-                node.input_types()
-                    .into_iter()
-                    .flat_map(to_tensor)
-                    .for_each(|tensor| {
-                        self.scope
-                            .tensor_register_future_use(&tensor, node_position)
-                    })
-            });
+        self.scope = Scope::build(
+            &self.graph_input_types,
+            &self.nodes,
+            &self.graph_output_types,
+        );
     }
 
     fn register_record_file(&mut self, file: PathBuf, recorder_str: &str) {
@@ -394,8 +372,8 @@ impl<PS: PrecisionSettings> BurnGraph<PS> {
 
     fn codegen_struct(&self) -> TokenStream {
         let mut body = quote! {};
-        self.nodes
-            .iter()
+        all_nodes(&self.nodes)
+            .into_iter()
             .filter_map(|node| node.field_type())
             .map(|field| {
                 let name = field.name();
@@ -430,13 +408,13 @@ impl<PS: PrecisionSettings> BurnGraph<PS> {
     fn codegen_new(&self) -> TokenStream {
         let mut body = quote! {};
 
-        self.nodes
+        let nodes = all_nodes(&self.nodes);
+        nodes
             .iter()
             .map(|node| node.field_init())
             .for_each(|code| body.extend(code));
 
-        let fields = self
-            .nodes
+        let fields = nodes
             .iter()
             .flat_map(|node| node.field_type())
             .map(|field| field.name().clone())
@@ -474,7 +452,16 @@ impl<PS: PrecisionSettings> BurnGraph<PS> {
         let multiple_output = self.graph_output_types.len() > 1;
 
         self.graph_output_types.iter().for_each(|output| {
-            let name = output.name();
+            let name = match output {
+                Type::Shape(shape) => {
+                    let name = &shape.name;
+                    quote! { #name.map(|dim| dim as usize) }
+                }
+                _ => {
+                    let name = output.name();
+                    quote! { #name }
+                }
+            };
             let ty = output.ty();
 
             if multiple_output {
@@ -504,6 +491,16 @@ impl<PS: PrecisionSettings> BurnGraph<PS> {
         }
 
         let mut body = quote! {};
+        // The shapes are converted to int64 values inside the forward pass
+        self.graph_input_types.iter().for_each(|input| {
+            if let Type::Shape(shape) = input {
+                let name = &shape.name;
+                let dim = shape.dim.to_tokens();
+                body.extend(quote! {
+                    let #name: [i64; #dim] = #name.map(|dim| dim as i64);
+                });
+            }
+        });
         self.nodes
             .iter()
             .enumerate()
@@ -570,9 +567,42 @@ impl<PS: PrecisionSettings> BurnGraph<PS> {
     }
 }
 
+/// Check that the record saved at the given path is small enough to be embedded in the generated
+/// code.
+///
+/// The record of large models, such as the ONNX models storing their weights as external data, must
+/// be loaded from a file instead.
+fn assert_embeddable<PS: PrecisionSettings>(out_file: &Path) {
+    let file =
+        out_file.with_extension(<BinFileRecorder<PS> as FileRecorder<Backend>>::file_extension());
+    let size = fs::metadata(&file)
+        .unwrap_or_else(|err| panic!("Unable to read the record file {file:?}: {err}"))
+        .len();
+
+    assert!(
+        size <= MAX_EMBEDDED_RECORD_SIZE,
+        "The record is too large to be embedded ({size} bytes), set `embed_states` to false to load it from a file."
+    );
+}
+
+/// Get the nodes of the graph and of the sub-graphs nested in them, whose fields are all declared
+/// in the model.
+fn all_nodes<PS: PrecisionSettings>(nodes: &[Node<PS>]) -> Vec<&Node<PS>> {
+    nodes
+        .iter()
+        .flat_map(|node| {
+            let sub_nodes = node
+                .sub_graphs()
+                .into_iter()
+                .flat_map(|sub_graph| all_nodes(&sub_graph.nodes));
+            std::iter::once(node).chain(sub_nodes).collect::<Vec<_>>()
+        })
+        .collect()
+}
+
 #[derive(new, Debug)]
 struct BurnGraphState<'a, PS: PrecisionSettings> {
-    nodes: &'a Vec<Node<PS>>,
+    nodes: Vec<&'a Node<PS>>,
 }
 
 /// Represents a custom serialization strategy for the graph state in the module struct.
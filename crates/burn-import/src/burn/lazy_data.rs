@@ -0,0 +1,58 @@
+use std::{fmt, sync::Arc};
+
+use burn::tensor::TensorData;
+
+/// The data of a tensor, only read when the record of the model is generated.
+///
+/// The weights of a large model can be stored in external files, so each tensor is read when it's
+/// serialized instead of all the weights being loaded when the model is converted.
+#[derive(Clone)]
+pub struct LazyTensorData {
+    shape: Vec<usize>,
+    read: Arc<dyn Fn() -> TensorData>,
+}
+
+impl LazyTensorData {
+    /// Create the data of a tensor of the given shape, read by the given function.
+    pub fn new<F>(shape: Vec<usize>, read: F) -> Self
+    where
+        F: Fn() -> TensorData + 'static,
+    {
+        Self {
+            shape,
+            read: Arc::new(read),
+        }
+    }
+
+    /// The shape of the tensor.
+    pub fn shape(&self) -> &[usize] {
+        &self.shape
+    }
+
+    /// Read the data.
+    pub fn read(&self) -> TensorData {
+        (self.read)()
+    }
+
+    /// Transform the data once it's read into data of the given shape.
+    pub fn map<F>(self, shape: Vec<usize>, func: F) -> Self
+    where
+        F: Fn(TensorData) -> TensorData + 'static,
+    {
+        Self::new(shape, move || func(self.read()))
+    }
+}
+
+impl From<TensorData> for LazyTensorData {
+    fn from(data: TensorData) -> Self {
+        Self::new(data.shape.clone(), move || data.clone())
+    }
+}
+
+impl fmt::Debug for LazyTensorData {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct("LazyTensorData")
+            .field("shape", &self.shape)
+            .finish()
+    }
+}
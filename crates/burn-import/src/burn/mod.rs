@@ -5,10 +5,12 @@ pub(crate) mod node;
 
 mod codegen;
 mod imports;
+mod lazy_data;
 mod scope;
 mod ty;
 
 pub(crate) use codegen::*;
 pub(crate) use imports::*;
+pub(crate) use lazy_data::*;
 pub(crate) use scope::*;
 pub(crate) use ty::*;
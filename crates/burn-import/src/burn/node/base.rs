@@ -5,12 +5,16 @@ use super::{
     batch_norm::BatchNormNode, binary::BinaryNode, clip::ClipNode, concat::ConcatNode,
     constant::ConstantNode, constant_of_shape::ConstantOfShapeNode, conv1d::Conv1dNode,
     conv2d::Conv2dNode, conv3d::Conv3dNode, conv_transpose_2d::ConvTranspose2dNode,
-    conv_transpose_3d::ConvTranspose3dNode, dropout::DropoutNode, expand::ExpandNode,
-    gather::GatherNode, gather_elements::GatherElementsNode, global_avg_pool::GlobalAvgPoolNode,
-    layer_norm::LayerNormNode, linear::LinearNode, mask_where::WhereNode, matmul::MatmulNode,
-    max_pool1d::MaxPool1dNode, max_pool2d::MaxPool2dNode, prelu::PReluNode,
-    random_normal::RandomNormalNode, random_uniform::RandomUniformNode, range::RangeNode,
-    reshape::ReshapeNode, resize::ResizeNode, slice::SliceNode, squeeze::SqueezeNode, sum::SumNode,
+    conv_transpose_3d::ConvTranspose3dNode, cumsum::CumSumNode, dropout::DropoutNode,
+    expand::ExpandNode, gather::GatherNode, gather_elements::GatherElementsNode,
+    global_avg_pool::GlobalAvgPoolNode, group_norm::GroupNormNode, gru::GruNode, if_node::IfNode,
+    instance_norm::InstanceNormNode, layer_norm::LayerNormNode, linear::LinearNode,
+    loop_node::LoopNode, lstm::LstmNode, mask_where::WhereNode, matmul::MatmulNode,
+    max_pool1d::MaxPool1dNode, max_pool2d::MaxPool2dNode, nonzero::NonZeroNode,
+    one_hot::OneHotNode, pad::PadNode, prelu::PReluNode, random_normal::RandomNormalNode,
+    random_uniform::RandomUniformNode, range::RangeNode, reshape::ReshapeNode, resize::ResizeNode,
+    rnn::RnnNode, scan::ScanNode, slice::SliceNode, split::SplitNode, squeeze::SqueezeNode,
+    subgraph::SubGraph, sum::SumNode, tile::TileNode, top_k::TopKNode, trilu::TriluNode,
     unary::UnaryNode, unsqueeze::UnsqueezeNode,
 };
 use crate::burn::{BurnImports, Scope, Type};
@@ -94,23 +98,39 @@ pub enum Node<PS: PrecisionSettings> {
     Conv3d(Conv3dNode),
     ConvTranspose2d(ConvTranspose2dNode),
     ConvTranspose3d(ConvTranspose3dNode),
+    CumSum(CumSumNode),
     PRelu(PReluNode),
     Dropout(DropoutNode),
     Expand(ExpandNode),
     Gather(GatherNode),
     GatherElements(GatherElementsNode),
     GlobalAvgPool(GlobalAvgPoolNode),
+    GroupNorm(GroupNormNode),
+    Gru(GruNode),
+    If(IfNode<PS>),
+    InstanceNorm(InstanceNormNode),
     LayerNorm(LayerNormNode),
     Linear(LinearNode),
+    Loop(LoopNode<PS>),
+    Lstm(LstmNode),
     Matmul(MatmulNode),
     MaxPool1d(MaxPool1dNode),
     MaxPool2d(MaxPool2dNode),
+    NonZero(NonZeroNode),
+    OneHot(OneHotNode),
+    Pad(PadNode),
     Range(RangeNode),
     Reshape(ReshapeNode),
     Resize(ResizeNode),
+    Rnn(RnnNode),
+    Scan(ScanNode<PS>),
     Slice(SliceNode),
+    Split(SplitNode),
     Squeeze(SqueezeNode),
     Sum(SumNode),
+    Tile(TileNode),
+    TopK(TopKNode),
+    Trilu(TriluNode),
     Unary(UnaryNode),
     Unsqueeze(UnsqueezeNode),
     Where(WhereNode),
@@ -139,23 +159,39 @@ macro_rules! match_all {
             Node::Conv3d(node) => $func(node),
             Node::ConvTranspose2d(node) => $func(node),
             Node::ConvTranspose3d(node) => $func(node),
+            Node::CumSum(node) => $func(node),
             Node::PRelu(node) => $func(node),
             Node::Dropout(node) => $func(node),
             Node::Expand(node) => $func(node),
             Node::Gather(node) => $func(node),
             Node::GatherElements(node) => $func(node),
             Node::GlobalAvgPool(node) => $func(node),
+            Node::GroupNorm(node) => $func(node),
+            Node::Gru(node) => $func(node),
+            Node::If(node) => $func(node),
+            Node::InstanceNorm(node) => $func(node),
             Node::LayerNorm(node) => $func(node),
             Node::Linear(node) => $func(node),
+            Node::Loop(node) => $func(node),
+            Node::Lstm(node) => $func(node),
             Node::Matmul(node) => $func(node),
             Node::MaxPool1d(node) => $func(node),
             Node::MaxPool2d(node) => $func(node),
+            Node::NonZero(node) => $func(node),
+            Node::OneHot(node) => $func(node),
+            Node::Pad(node) => $func(node),
             Node::Range(node) => $func(node),
             Node::Reshape(node) => $func(node),
             Node::Resize(node) => $func(node),
+            Node::Rnn(node) => $func(node),
+            Node::Scan(node) => $func(node),
             Node::Slice(node) => $func(node),
+            Node::Split(node) => $func(node),
             Node::Squeeze(node) => $func(node),
             Node::Sum(node) => $func(node),
+            Node::Tile(node) => $func(node),
+            Node::TopK(node) => $func(node),
+            Node::Trilu(node) => $func(node),
             Node::Unary(node) => $func(node),
             Node::Unsqueeze(node) => $func(node),
             Node::Where(node) => $func(node),
@@ -192,23 +228,39 @@ impl<PS: PrecisionSettings> Node<PS> {
             Node::Conv3d(_) => "conv3d",
             Node::ConvTranspose2d(_) => "conv_transpose2d",
             Node::ConvTranspose3d(_) => "conv_transpose3d",
+            Node::CumSum(_) => "cumsum",
             Node::PRelu(_) => "prelu",
             Node::Dropout(_) => "dropout",
             Node::Expand(_) => "expand",
             Node::Gather(_) => "gather",
             Node::GatherElements(_) => "gather_elements",
             Node::GlobalAvgPool(_) => "global_avg_pool",
+            Node::GroupNorm(_) => "group_norm",
+            Node::Gru(_) => "gru",
+            Node::If(_) => "if",
+            Node::InstanceNorm(_) => "instance_norm",
             Node::LayerNorm(_) => "layer_norm",
             Node::Linear(_) => "linear",
+            Node::Loop(_) => "loop",
+            Node::Lstm(_) => "lstm",
             Node::Matmul(_) => "matmul",
             Node::MaxPool1d(_) => "max_pool1d",
             Node::MaxPool2d(_) => "max_pool2d",
+            Node::NonZero(_) => "nonzero",
+            Node::OneHot(_) => "one_hot",
+            Node::Pad(_) => "pad",
             Node::Range(_) => "range",
             Node::Reshape(_) => "reshape",
             Node::Resize(_) => "resize",
+            Node::Rnn(_) => "rnn",
+            Node::Scan(_) => "scan",
             Node::Slice(_) => "slice",
+            Node::Split(_) => "split",
             Node::Squeeze(_) => "squeeze",
             Node::Sum(_) => "add",
+            Node::Tile(_) => "tile",
+            Node::TopK(_) => "top_k",
+            Node::Trilu(_) => "trilu",
             Node::Unary(unary) => unary.kind.as_str(),
             Node::Unsqueeze(_) => "unsqueeze",
             Node::Where(_) => "where",
@@ -218,6 +270,16 @@ impl<PS: PrecisionSettings> Node<PS> {
             _ => unimplemented!(),
         }
     }
+
+    /// The sub-graphs nested in the node, like the branches of an `if`.
+    pub fn sub_graphs(&self) -> Vec<&SubGraph<PS>> {
+        match self {
+            Node::If(node) => vec![&node.then_branch, &node.else_branch],
+            Node::Loop(node) => vec![&node.body],
+            Node::Scan(node) => vec![&node.body],
+            _ => vec![],
+        }
+    }
 }
 
 impl<PS: PrecisionSettings> NodeCodegen<PS> for Node<PS> {
@@ -332,7 +394,7 @@ pub(crate) mod tests {
             "conv2d",
             TensorType::new_float("tensor3", 4),
             TensorType::new_float("tensor4", 4),
-            TensorData::from([2f32]),
+            TensorData::from([2f32]).into(),
             None,
             Conv2dConfig::new([3, 3], [3, 3]).with_padding(PaddingConfig2d::Valid),
         ));
@@ -405,7 +467,7 @@ pub(crate) mod tests {
             "conv2d",
             TensorType::new_float("tensor2", 4),
             TensorType::new_float("tensor4", 4),
-            TensorData::from([2f32]),
+            TensorData::from([2f32]).into(),
             None,
             Conv2dConfig::new([3, 3], [3, 3]).with_padding(PaddingConfig2d::Valid),
         ));
@@ -1,10 +1,10 @@
 use super::{Node, NodeCodegen, SerializationBackend};
-use crate::burn::{BurnImports, OtherType, Scope, TensorType, ToTokens, Type};
+use crate::burn::{BurnImports, LazyTensorData, OtherType, Scope, TensorType, ToTokens, Type};
 use burn::{
     module::{ConstantRecord, Param, ParamId},
     nn::{BatchNormConfig, BatchNormRecord},
     record::{PrecisionSettings, Record},
-    tensor::{Tensor, TensorData},
+    tensor::Tensor,
 };
 use proc_macro2::TokenStream;
 use quote::quote;
@@ -16,10 +16,10 @@ pub struct BatchNormNode {
     pub field: OtherType,
     pub input: TensorType,
     pub output: TensorType,
-    pub gamma: TensorData,
-    pub beta: TensorData,
-    pub running_mean: TensorData,
-    pub running_var: TensorData,
+    pub gamma: LazyTensorData,
+    pub beta: LazyTensorData,
+    pub running_mean: LazyTensorData,
+    pub running_var: LazyTensorData,
     pub config: BatchNormConfig,
 }
 
@@ -30,10 +30,10 @@ impl BatchNormNode {
         name: S,
         input: TensorType,
         output: TensorType,
-        gamma: TensorData,
-        beta: TensorData,
-        running_mean: TensorData,
-        running_var: TensorData,
+        gamma: LazyTensorData,
+        beta: LazyTensorData,
+        running_mean: LazyTensorData,
+        running_var: LazyTensorData,
         config: BatchNormConfig,
     ) -> Self {
         let dim_tokens = dim.to_tokens();
@@ -82,19 +82,19 @@ macro_rules! batch_norm_serialize {
         BatchNormRecord {
             gamma: Param::initialized(
                 ParamId::new(),
-                Tensor::from_data($self.gamma.clone().convert::<PS::FloatElem>(), &device),
+                Tensor::from_data($self.gamma.read().convert::<PS::FloatElem>(), &device),
             ),
             beta: Param::initialized(
                 ParamId::new(),
-                Tensor::from_data($self.beta.clone().convert::<PS::FloatElem>(), &device),
+                Tensor::from_data($self.beta.read().convert::<PS::FloatElem>(), &device),
             ),
             running_mean: Param::initialized(
                 ParamId::new(),
-                Tensor::from_data($self.running_mean.clone().convert::<PS::FloatElem>(), &device),
+                Tensor::from_data($self.running_mean.read().convert::<PS::FloatElem>(), &device),
             ),
             running_var: Param::initialized(
                 ParamId::new(),
-                Tensor::from_data($self.running_var.clone().convert::<PS::FloatElem>(), &device),
+                Tensor::from_data($self.running_var.read().convert::<PS::FloatElem>(), &device),
             ),
             epsilon: ConstantRecord::new(),
             momentum: ConstantRecord::new(),
@@ -156,7 +156,7 @@ impl<PS: PrecisionSettings> NodeCodegen<PS> for BatchNormNode {
 mod tests {
     use super::*;
     use crate::burn::{graph::BurnGraph, node::test::assert_tokens, TensorType};
-    use burn::record::FullPrecisionSettings;
+    use burn::{record::FullPrecisionSettings, tensor::TensorData};
 
     #[test]
     fn test_codegen() {
@@ -167,10 +167,10 @@ mod tests {
             "norm",
             TensorType::new_float("input", 4),
             TensorType::new_float("output", 4),
-            TensorData::from([2f32]),
-            TensorData::from([2f32]),
-            TensorData::from([2f32]),
-            TensorData::from([2f32]),
+            TensorData::from([2f32]).into(),
+            TensorData::from([2f32]).into(),
+            TensorData::from([2f32]).into(),
+            TensorData::from([2f32]).into(),
             BatchNormConfig::new(128),
         ));
 
@@ -1,5 +1,5 @@
 use super::{Node, NodeCodegen};
-use crate::burn::{Scope, Type};
+use crate::burn::{Scope, ToTokens, Type};
 use burn::record::PrecisionSettings;
 use proc_macro2::TokenStream;
 use quote::quote;
@@ -87,6 +87,10 @@ impl<PS: PrecisionSettings> NodeCodegen<PS> for BinaryNode {
                 let name = scalar.name.clone();
                 quote! { #name }
             }
+            Type::Shape(shape) => {
+                let name = shape.name.clone();
+                quote! { #name }
+            }
             _ => panic!("lhs must be a tensor or scalar"),
         };
 
@@ -97,14 +101,26 @@ impl<PS: PrecisionSettings> NodeCodegen<PS> for BinaryNode {
                 let name = scalar.name.clone();
                 quote! { #name }
             }
+            Type::Shape(shape) => {
+                let name = shape.name.clone();
+                quote! { #name }
+            }
             _ => panic!("rhs must be a tensor or scalar"),
         };
 
         let output = &self.output.name();
         let function = (self.function)(lhs, rhs);
 
-        quote! {
-            let #output = #function;
+        match &self.output {
+            Type::Shape(shape) => {
+                let dim = shape.dim.to_tokens();
+                quote! {
+                    let #output: [i64; #dim] = #function;
+                }
+            }
+            _ => quote! {
+                let #output = #function;
+            },
         }
     }
 
@@ -113,13 +129,55 @@ impl<PS: PrecisionSettings> NodeCodegen<PS> for BinaryNode {
     }
 }
 
+/// Check if the operands of an arithmetic node are runtime shapes and scalars.
+fn is_shape_arithmetic(lhs: &Type, rhs: &Type) -> bool {
+    matches!(
+        (lhs, rhs),
+        (Type::Shape(_), Type::Shape(_) | Type::Scalar(_)) | (Type::Scalar(_), Type::Shape(_))
+    )
+}
+
 impl BinaryNode {
+    /// Elementwise arithmetic on runtime shapes, where the shapes of one element and the scalars
+    /// are broadcast.
+    fn shape_arithmetic(lhs: Type, rhs: Type, output: Type, binary_type: BinaryType) -> Self {
+        let element = |operand: &Type| -> fn(TokenStream) -> TokenStream {
+            match operand {
+                Type::Shape(shape) if shape.dim == 1 => |name| quote! { #name[0] },
+                Type::Shape(_) => |name| quote! { #name[i] },
+                _ => |name| quote! { (#name as i64) },
+            }
+        };
+        let (lhs_element, rhs_element) = (element(&lhs), element(&rhs));
+        let op: fn(TokenStream, TokenStream) -> TokenStream = match binary_type {
+            BinaryType::Add => |lhs, rhs| quote! { #lhs + #rhs },
+            BinaryType::Sub => |lhs, rhs| quote! { #lhs - #rhs },
+            BinaryType::Mul => |lhs, rhs| quote! { #lhs * #rhs },
+            BinaryType::Div => |lhs, rhs| quote! { #lhs / #rhs },
+            _ => panic!("Only arithmetic is supported for shapes"),
+        };
+        // The shapes of one element are indexed directly, without iterating over the elements
+        let single_element = matches!(&output, Type::Shape(shape) if shape.dim == 1);
+        let function = move |lhs, rhs| {
+            let element = op(lhs_element(lhs), rhs_element(rhs));
+            match single_element {
+                true => quote! { [#element] },
+                false => quote! { core::array::from_fn(|i| #element) },
+            }
+        };
+
+        Self::new(lhs, rhs, output, binary_type, Arc::new(function))
+    }
+
     pub(crate) fn add(lhs: Type, rhs: Type, output: Type) -> Self {
         let function = match (&lhs, &rhs) {
             (Type::Tensor(_), Type::Tensor(_)) => move |lhs, rhs| quote! { #lhs.add(#rhs) },
             (Type::Tensor(_), Type::Scalar(_)) => move |lhs, rhs| quote! { #lhs.add_scalar(#rhs) },
             (Type::Scalar(_), Type::Tensor(_)) => move |lhs, rhs| quote! { #rhs.add_scalar(#lhs) },
             (Type::Scalar(_), Type::Scalar(_)) => move |lhs, rhs| quote! { #lhs + #rhs },
+            _ if is_shape_arithmetic(&lhs, &rhs) => {
+                return Self::shape_arithmetic(lhs, rhs, output, BinaryType::Add)
+            }
             _ => panic!("Addition is supported for tensor and scalar only"),
         };
 
@@ -132,6 +190,9 @@ impl BinaryNode {
             (Type::Tensor(_), Type::Scalar(_)) => move |lhs, rhs| quote! { #lhs.sub_scalar(#rhs) },
             (Type::Scalar(_), Type::Scalar(_)) => move |lhs, rhs| quote! { #lhs - #rhs },
             (Type::Scalar(_), Type::Tensor(_)) => move |lhs, rhs| quote! { -#rhs.sub_scalar(#lhs) },
+            _ if is_shape_arithmetic(&lhs, &rhs) => {
+                return Self::shape_arithmetic(lhs, rhs, output, BinaryType::Sub)
+            }
             _ => panic!("Subtraction is supported for tensor and scalar only"),
         };
 
@@ -144,6 +205,9 @@ impl BinaryNode {
             (Type::Tensor(_), Type::Scalar(_)) => move |lhs, rhs| quote! { #lhs.mul_scalar(#rhs) },
             (Type::Scalar(_), Type::Tensor(_)) => move |lhs, rhs| quote! { #rhs.mul_scalar(#lhs) },
             (Type::Scalar(_), Type::Scalar(_)) => move |lhs, rhs| quote! { #lhs * #rhs },
+            _ if is_shape_arithmetic(&lhs, &rhs) => {
+                return Self::shape_arithmetic(lhs, rhs, output, BinaryType::Mul)
+            }
             _ => panic!("Multiplication is supported for tensor and scalar only"),
         };
 
@@ -155,6 +219,9 @@ impl BinaryNode {
             (Type::Tensor(_), Type::Tensor(_)) => move |lhs, rhs| quote! { #lhs.div(#rhs) },
             (Type::Tensor(_), Type::Scalar(_)) => move |lhs, rhs| quote! { #lhs.div_scalar(#rhs) },
             (Type::Scalar(_), Type::Scalar(_)) => move |lhs, rhs| quote! { #lhs / #rhs },
+            _ if is_shape_arithmetic(&lhs, &rhs) => {
+                return Self::shape_arithmetic(lhs, rhs, output, BinaryType::Div)
+            }
             _ => panic!("Division is supported for tensor and scalar only"),
         };
 
@@ -206,7 +273,8 @@ impl BinaryNode {
     pub(crate) fn greater(lhs: Type, rhs: Type, output: Type) -> Self {
         let function = match (&lhs, &rhs) {
             (Type::Tensor(_), Type::Tensor(_)) => move |lhs, rhs| quote! { #lhs.greater(#rhs) },
-            _ => panic!("greater is supported for tensor only"),
+            (Type::Scalar(_), Type::Scalar(_)) => move |lhs, rhs| quote! { #lhs > #rhs },
+            _ => panic!("Comparison is supported for tensor to tensor and scalar to scalar only"),
         };
         Self::new(lhs, rhs, output, BinaryType::Greater, Arc::new(function))
     }
@@ -216,7 +284,8 @@ impl BinaryNode {
             (Type::Tensor(_), Type::Tensor(_)) => {
                 move |lhs, rhs| quote! { #lhs.greater_equal(#rhs) }
             }
-            _ => panic!("greater_equal is supported for tensor only"),
+            (Type::Scalar(_), Type::Scalar(_)) => move |lhs, rhs| quote! { #lhs >= #rhs },
+            _ => panic!("Comparison is supported for tensor to tensor and scalar to scalar only"),
         };
         Self::new(
             lhs,
@@ -230,7 +299,8 @@ impl BinaryNode {
     pub(crate) fn lower(lhs: Type, rhs: Type, output: Type) -> Self {
         let function = match (&lhs, &rhs) {
             (Type::Tensor(_), Type::Tensor(_)) => move |lhs, rhs| quote! { #lhs.lower(#rhs) },
-            _ => panic!("lower is supported for tensor only"),
+            (Type::Scalar(_), Type::Scalar(_)) => move |lhs, rhs| quote! { #lhs < #rhs },
+            _ => panic!("Comparison is supported for tensor to tensor and scalar to scalar only"),
         };
         Self::new(lhs, rhs, output, BinaryType::Less, Arc::new(function))
     }
@@ -238,7 +308,8 @@ impl BinaryNode {
     pub(crate) fn lower_equal(lhs: Type, rhs: Type, output: Type) -> Self {
         let function = match (&lhs, &rhs) {
             (Type::Tensor(_), Type::Tensor(_)) => move |lhs, rhs| quote! { #lhs.lower_equal(#rhs) },
-            _ => panic!("lower_equal is supported for tensor only"),
+            (Type::Scalar(_), Type::Scalar(_)) => move |lhs, rhs| quote! { #lhs <= #rhs },
+            _ => panic!("Comparison is supported for tensor to tensor and scalar to scalar only"),
         };
         Self::new(
             lhs,
@@ -259,7 +330,7 @@ mod tests {
     use crate::burn::graph::BurnGraph;
     use crate::burn::node::test::assert_tokens;
     use crate::burn::node::tests::one_node_graph;
-    use crate::burn::{ScalarKind, ScalarType, TensorType};
+    use crate::burn::{ScalarKind, ScalarType, ShapeType, TensorType};
 
     macro_rules! test_binary_operator_on_tensors {
     ($operator:ident) => {{
@@ -428,6 +499,11 @@ mod tests {
         test_binary_operator_on_tensors!(lower);
     }
 
+    #[test]
+    fn test_binary_codegen_less_scalars() {
+        test_binary_operator_on_scalar_and_scalar!(lower, <);
+    }
+
     #[test]
     fn test_binary_codegen_less_or_equal() {
         test_binary_operator_on_tensors!(lower_equal);
@@ -491,4 +567,47 @@ mod tests {
     fn test_binary_codegen_equal_scalars() {
         test_binary_operator_on_scalar_and_scalar!(equal, ==);
     }
+
+    #[test]
+    fn test_binary_codegen_mul_shapes() {
+        one_node_graph(
+            BinaryNode::mul(
+                Type::Shape(ShapeType::new("shape1", 3)),
+                Type::Shape(ShapeType::new("shape2", 1)),
+                Type::Shape(ShapeType::new("shape3", 3)),
+            ),
+            quote! {
+                pub fn forward(&self, shape1: [usize; 3], shape2: [usize; 1]) -> [usize; 3] {
+                    let shape1: [i64; 3] = shape1.map(|dim| dim as i64);
+                    let shape2: [i64; 1] = shape2.map(|dim| dim as i64);
+                    let shape3: [i64; 3] = core::array::from_fn(|i| shape1[i] * shape2[0]);
+
+                    shape3.map(|dim| dim as usize)
+                }
+            },
+            vec!["shape1".to_string(), "shape2".to_string()],
+            vec!["shape3".to_string()],
+        );
+    }
+
+    #[test]
+    fn test_binary_codegen_sub_shape_scalar() {
+        one_node_graph(
+            BinaryNode::sub(
+                Type::Shape(ShapeType::new("shape1", 1)),
+                Type::Scalar(ScalarType::new("scalar1", ScalarKind::Int64)),
+                Type::Shape(ShapeType::new("shape2", 1)),
+            ),
+            quote! {
+                pub fn forward(&self, shape1: [usize; 1], scalar1: i64) -> [usize; 1] {
+                    let shape1: [i64; 1] = shape1.map(|dim| dim as i64);
+                    let shape2: [i64; 1] = [shape1[0] - (scalar1 as i64)];
+
+                    shape2.map(|dim| dim as usize)
+                }
+            },
+            vec!["shape1".to_string(), "scalar1".to_string()],
+            vec!["shape2".to_string()],
+        );
+    }
 }
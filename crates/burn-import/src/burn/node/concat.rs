@@ -1,5 +1,5 @@
 use super::{Node, NodeCodegen};
-use crate::burn::{Scope, TensorType, ToTokens, Type};
+use crate::burn::{Scope, ToTokens, Type};
 
 use burn::record::PrecisionSettings;
 use proc_macro2::TokenStream;
@@ -7,34 +7,55 @@ use quote::quote;
 
 #[derive(Debug, Clone, new)]
 pub struct ConcatNode {
-    pub inputs: Vec<TensorType>,
-    pub output: TensorType,
+    pub inputs: Vec<Type>,
+    pub output: Type,
     pub dim: usize,
 }
 
 impl<PS: PrecisionSettings> NodeCodegen<PS> for ConcatNode {
     fn output_types(&self) -> Vec<Type> {
-        vec![Type::Tensor(self.output.clone())]
+        vec![self.output.clone()]
     }
 
     fn input_types(&self) -> Vec<Type> {
-        self.inputs
-            .iter()
-            .map(|t| Type::Tensor(t.clone()))
-            .collect()
+        self.inputs.clone()
     }
 
     fn forward(&self, scope: &mut Scope, node_position: usize) -> TokenStream {
-        let dim = self.dim.to_tokens();
-        let inputs = self
-            .inputs
-            .iter()
-            .map(|t| scope.tensor_use_owned(t, node_position));
-
-        let output = &self.output.name;
+        let output = self.output.name();
+
+        match &self.output {
+            // Runtime shapes are concatenated element by element
+            Type::Shape(shape) => {
+                let dim = shape.dim.to_tokens();
+                let elements = self.inputs.iter().flat_map(|input| match input {
+                    Type::Shape(shape) => {
+                        let name = &shape.name;
+                        (0..shape.dim)
+                            .map(|i| {
+                                let i = i.to_tokens();
+                                quote! { #name[#i] }
+                            })
+                            .collect::<Vec<_>>()
+                    }
+                    _ => panic!("Concat: shapes can only be concatenated with shapes"),
+                });
 
-        quote! {
-            let #output = burn::tensor::Tensor::cat([#(#inputs),*].into(), #dim);
+                quote! {
+                    let #output: [i64; #dim] = [#(#elements),*];
+                }
+            }
+            _ => {
+                let dim = self.dim.to_tokens();
+                let inputs = self.inputs.iter().map(|input| match input {
+                    Type::Tensor(tensor) => scope.tensor_use_owned(tensor, node_position),
+                    _ => panic!("Concat: tensors can only be concatenated with tensors"),
+                });
+
+                quote! {
+                    let #output = burn::tensor::Tensor::cat([#(#inputs),*].into(), #dim);
+                }
+            }
         }
     }
 
@@ -52,7 +73,7 @@ mod tests {
     use crate::burn::{
         graph::BurnGraph,
         node::{concat::ConcatNode, test::assert_tokens},
-        TensorType,
+        ShapeType, TensorType,
     };
 
     #[test]
@@ -61,10 +82,10 @@ mod tests {
 
         graph.register(ConcatNode::new(
             vec![
-                TensorType::new_float("tensor1", 4),
-                TensorType::new_float("tensor2", 4),
+                Type::Tensor(TensorType::new_float("tensor1", 4)),
+                Type::Tensor(TensorType::new_float("tensor2", 4)),
             ],
-            TensorType::new_float("tensor3", 4),
+            Type::Tensor(TensorType::new_float("tensor3", 4)),
             1,
         ));
 
@@ -109,4 +130,57 @@ mod tests {
 
         assert_tokens(graph.codegen(), expected);
     }
+
+    #[test]
+    fn test_codegen_concat_shape() {
+        let mut graph = BurnGraph::<FullPrecisionSettings>::default();
+
+        graph.register(ConcatNode::new(
+            vec![
+                Type::Shape(ShapeType::new("shape1", 1)),
+                Type::Shape(ShapeType::new("shape2", 2)),
+            ],
+            Type::Shape(ShapeType::new("shape3", 3)),
+            0,
+        ));
+
+        graph.register_input_output(
+            vec!["shape1".to_string(), "shape2".to_string()],
+            vec!["shape3".to_string()],
+        );
+
+        let expected = quote! {
+            use burn::{
+                module::Module,
+                tensor::{backend::Backend, Tensor},
+            };
+
+            #[derive(Module, Debug)]
+            pub struct Model<B: Backend> {
+                phantom: core::marker::PhantomData<B>,
+                device: burn::module::Ignored<B::Device>,
+            }
+
+            impl<B: Backend> Model <B> {
+                #[allow(unused_variables)]
+                pub fn new(device: &B::Device) -> Self {
+                    Self {
+                        phantom: core::marker::PhantomData,
+                        device: burn::module::Ignored(device.clone()),
+                    }
+                }
+
+                #[allow(clippy::let_and_return, clippy::approx_constant)]
+                pub fn forward(&self, shape1: [usize; 1], shape2: [usize; 2]) -> [usize; 3] {
+                    let shape1: [i64; 1] = shape1.map(|dim| dim as i64);
+                    let shape2: [i64; 2] = shape2.map(|dim| dim as i64);
+                    let shape3: [i64; 3] = [shape1[0], shape2[0], shape2[1]];
+
+                    shape3.map(|dim| dim as usize)
+                }
+            }
+        };
+
+        assert_tokens(graph.codegen(), expected);
+    }
 }
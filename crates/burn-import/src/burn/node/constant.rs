@@ -1,9 +1,10 @@
 use super::{Node, NodeCodegen};
-use crate::burn::{ScalarKind, ScalarType, Scope, TensorType, ToTokens, Type};
+use crate::burn::{
+    LazyTensorData, ScalarKind, ScalarType, Scope, ShapeType, TensorType, ToTokens, Type,
+};
 use burn::{
     module::ParamId,
     record::{ParamSerde, PrecisionSettings},
-    tensor::TensorData,
 };
 use proc_macro2::{Ident, Span, TokenStream};
 use quote::quote;
@@ -29,8 +30,11 @@ pub enum ConstantValue {
     // Boolean constant.
     Bool(bool),
 
+    /// Shape constant, e.g. an operand of a runtime shape computation.
+    Shape(Vec<i64>),
+
     /// Tensor constant.
-    Tensor(TensorType, TensorData),
+    Tensor(TensorType, LazyTensorData),
 }
 
 impl ConstantValue {
@@ -41,6 +45,10 @@ impl ConstantValue {
             ConstantValue::Int32(_) => quote! { i32 },
             ConstantValue::Int64(_) => quote! { i64 },
             ConstantValue::Bool(_) => quote! { bool },
+            ConstantValue::Shape(values) => {
+                let dim = values.len().to_tokens();
+                quote! { [i64; #dim] }
+            }
             ConstantValue::Tensor(tensor_type, _) => {
                 let ty = tensor_type.ty();
                 quote! { burn::module::Param<#ty>}
@@ -54,6 +62,7 @@ impl ConstantValue {
             ConstantValue::Int32(val) => quote! { #val },
             ConstantValue::Int64(val) => quote! { #val },
             ConstantValue::Bool(val) => quote! { #val },
+            ConstantValue::Shape(values) => quote! { [#(#values),*] },
             ConstantValue::Tensor(_, _) => {
                 panic!("Tensor constant is not assignable.")
             }
@@ -92,6 +101,10 @@ impl ConstantNode {
                 name,
                 kind: ScalarKind::Bool,
             }),
+            ConstantValue::Shape(values) => Type::Shape(ShapeType {
+                name,
+                dim: values.len(),
+            }),
 
             ConstantValue::Tensor(tensor_type, _) => Type::Tensor(tensor_type.clone()),
         }
@@ -156,7 +169,7 @@ impl<PS: PrecisionSettings> NodeCodegen<PS> for ConstantNode {
 
     fn field_serialize<S: serde::Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
         if let ConstantValue::Tensor(_, data) = &self.value {
-            let data = data.clone().convert::<PS::FloatElem>();
+            let data = data.read().convert::<PS::FloatElem>();
             let data = ParamSerde::new(ParamId::new().into_string(), data);
             return data.serialize(serializer);
         }
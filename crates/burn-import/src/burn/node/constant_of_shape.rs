@@ -110,6 +110,8 @@ impl<PS: PrecisionSettings> NodeCodegen<PS> for ConstantOfShapeNode {
         };
 
         let value = self.value.val_tokens();
+        // The dimensions of the shape are int64 values
+        let input = quote! { #input.map(|dim| dim as usize) };
         // Note: in the generated code, self.device is a &module::Ignored<Device>,
         // so to get a &Device, &* is needed
 
@@ -196,8 +198,9 @@ mod tests {
                     }
                 }
                 #[allow(clippy::let_and_return, clippy::approx_constant)]
-                pub fn forward(&self, shape1: [usize;4]) -> Tensor<B, 4> {
-                    let tensor2 = Tensor::full(shape1, 1.25f32, &*self.device);
+                pub fn forward(&self, shape1: [usize; 4]) -> Tensor<B, 4> {
+                    let shape1: [i64; 4] = shape1.map(|dim| dim as i64);
+                    let tensor2 = Tensor::full(shape1.map(|dim| dim as usize), 1.25f32, &*self.device);
                     tensor2
                 }
             }
@@ -1,10 +1,10 @@
 use super::{Node, NodeCodegen, SerializationBackend};
-use crate::burn::{BurnImports, OtherType, Scope, TensorType, ToTokens, Type};
+use crate::burn::{BurnImports, LazyTensorData, OtherType, Scope, TensorType, ToTokens, Type};
 use burn::{
     module::{ConstantRecord, Param, ParamId},
     nn::conv::{Conv1dConfig, Conv1dRecord},
     record::{PrecisionSettings, Record},
-    tensor::{Tensor, TensorData},
+    tensor::Tensor,
 };
 use proc_macro2::TokenStream;
 use quote::quote;
@@ -15,8 +15,8 @@ pub struct Conv1dNode {
     pub field: OtherType,
     pub input: TensorType,
     pub output: TensorType,
-    pub data_weights: TensorData,
-    pub data_bias: Option<TensorData>,
+    pub data_weights: LazyTensorData,
+    pub data_bias: Option<LazyTensorData>,
     pub config: Conv1dConfig,
 }
 
@@ -25,8 +25,8 @@ impl Conv1dNode {
         name: S,
         input: TensorType,
         output: TensorType,
-        data_weights: TensorData,
-        data_bias: Option<TensorData>,
+        data_weights: LazyTensorData,
+        data_bias: Option<LazyTensorData>,
         config: Conv1dConfig,
     ) -> Self {
         Self {
@@ -85,15 +85,12 @@ impl<PS: PrecisionSettings> NodeCodegen<PS> for Conv1dNode {
         let record = Conv1dRecord::<SerializationBackend> {
             weight: Param::initialized(
                 ParamId::new(),
-                Tensor::from_data(
-                    self.data_weights.clone().convert::<PS::FloatElem>(),
-                    &device,
-                ),
+                Tensor::from_data(self.data_weights.read().convert::<PS::FloatElem>(), &device),
             ),
             bias: self.data_bias.as_ref().map(|bias| {
                 Param::initialized(
                     ParamId::new(),
-                    Tensor::from_data(bias.clone().convert::<PS::FloatElem>(), &device),
+                    Tensor::from_data(bias.read().convert::<PS::FloatElem>(), &device),
                 )
             }),
             stride: ConstantRecord::new(),
@@ -138,6 +135,7 @@ mod tests {
     use burn::{
         nn::{conv::Conv1dConfig, PaddingConfig1d},
         record::FullPrecisionSettings,
+        tensor::TensorData,
     };
 
     #[test]
@@ -148,7 +146,7 @@ mod tests {
             "conv1d",
             TensorType::new_float("input", 4),
             TensorType::new_float("output", 4),
-            TensorData::from([2f32]),
+            TensorData::from([2f32]).into(),
             None,
             Conv1dConfig::new(3, 3, 3).with_padding(PaddingConfig1d::Valid),
         ));
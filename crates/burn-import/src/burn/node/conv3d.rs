@@ -1,10 +1,10 @@
 use super::{Node, NodeCodegen, SerializationBackend};
-use crate::burn::{BurnImports, OtherType, Scope, TensorType, ToTokens, Type};
+use crate::burn::{BurnImports, LazyTensorData, OtherType, Scope, TensorType, ToTokens, Type};
 use burn::{
     module::{ConstantRecord, Param, ParamId},
     nn::conv::{Conv3dConfig, Conv3dRecord},
     record::{PrecisionSettings, Record},
-    tensor::{Tensor, TensorData},
+    tensor::Tensor,
 };
 use proc_macro2::TokenStream;
 use quote::quote;
@@ -15,8 +15,8 @@ pub struct Conv3dNode {
     pub field: OtherType,
     pub input: TensorType,
     pub output: TensorType,
-    pub data_weights: TensorData,
-    pub data_bias: Option<TensorData>,
+    pub data_weights: LazyTensorData,
+    pub data_bias: Option<LazyTensorData>,
     pub config: Conv3dConfig,
 }
 
@@ -25,8 +25,8 @@ impl Conv3dNode {
         name: S,
         input: TensorType,
         output: TensorType,
-        data_weights: TensorData,
-        data_bias: Option<TensorData>,
+        data_weights: LazyTensorData,
+        data_bias: Option<LazyTensorData>,
         config: Conv3dConfig,
     ) -> Self {
         Self {
@@ -84,15 +84,12 @@ impl<PS: PrecisionSettings> NodeCodegen<PS> for Conv3dNode {
         let record = Conv3dRecord::<SerializationBackend> {
             weight: Param::initialized(
                 ParamId::new(),
-                Tensor::from_data(
-                    self.data_weights.clone().convert::<PS::FloatElem>(),
-                    &device,
-                ),
+                Tensor::from_data(self.data_weights.read().convert::<PS::FloatElem>(), &device),
             ),
             bias: self.data_bias.as_ref().map(|bias| {
                 Param::initialized(
                     ParamId::new(),
-                    Tensor::from_data(bias.clone().convert::<PS::FloatElem>(), &device),
+                    Tensor::from_data(bias.read().convert::<PS::FloatElem>(), &device),
                 )
             }),
             stride: [ConstantRecord::new(); 3],
@@ -134,7 +131,10 @@ mod tests {
         node::{conv3d::Conv3dNode, test::assert_tokens},
         TensorType,
     };
-    use burn::{nn::conv::Conv3dConfig, nn::PaddingConfig3d, record::FullPrecisionSettings};
+    use burn::{
+        nn::conv::Conv3dConfig, nn::PaddingConfig3d, record::FullPrecisionSettings,
+        tensor::TensorData,
+    };
 
     #[test]
     fn test_codegen() {
@@ -144,7 +144,7 @@ mod tests {
             "conv3d",
             TensorType::new_float("input", 5),
             TensorType::new_float("output", 5),
-            TensorData::from([2f32]),
+            TensorData::from([2f32]).into(),
             None,
             Conv3dConfig::new([3, 3], [3, 3, 3]).with_padding(PaddingConfig3d::Valid),
         ));
@@ -1,10 +1,10 @@
 use super::{Node, NodeCodegen, SerializationBackend};
-use crate::burn::{BurnImports, OtherType, Scope, TensorType, ToTokens, Type};
+use crate::burn::{BurnImports, LazyTensorData, OtherType, Scope, TensorType, ToTokens, Type};
 use burn::{
     module::{ConstantRecord, Param, ParamId},
     nn::conv::{ConvTranspose2dConfig, ConvTranspose2dRecord},
     record::{PrecisionSettings, Record},
-    tensor::{Tensor, TensorData},
+    tensor::Tensor,
 };
 use proc_macro2::TokenStream;
 use quote::quote;
@@ -15,8 +15,8 @@ pub struct ConvTranspose2dNode {
     pub field: OtherType,
     pub input: TensorType,
     pub output: TensorType,
-    pub data_weights: TensorData,
-    pub data_bias: Option<TensorData>,
+    pub data_weights: LazyTensorData,
+    pub data_bias: Option<LazyTensorData>,
     pub config: ConvTranspose2dConfig,
 }
 
@@ -25,8 +25,8 @@ impl ConvTranspose2dNode {
         name: S,
         input: TensorType,
         output: TensorType,
-        data_weights: TensorData,
-        data_bias: Option<TensorData>,
+        data_weights: LazyTensorData,
+        data_bias: Option<LazyTensorData>,
         config: ConvTranspose2dConfig,
     ) -> Self {
         Self {
@@ -84,15 +84,12 @@ impl<PS: PrecisionSettings> NodeCodegen<PS> for ConvTranspose2dNode {
         let record = ConvTranspose2dRecord::<SerializationBackend> {
             weight: Param::initialized(
                 ParamId::new(),
-                Tensor::from_data(
-                    self.data_weights.clone().convert::<PS::FloatElem>(),
-                    &device,
-                ),
+                Tensor::from_data(self.data_weights.read().convert::<PS::FloatElem>(), &device),
             ),
             bias: self.data_bias.as_ref().map(|bias| {
                 Param::initialized(
                     ParamId::new(),
-                    Tensor::from_data(bias.clone().convert::<PS::FloatElem>(), &device),
+                    Tensor::from_data(bias.read().convert::<PS::FloatElem>(), &device),
                 )
             }),
             stride: [ConstantRecord::new(); 2],
@@ -135,7 +132,9 @@ mod tests {
         node::{conv_transpose_2d::ConvTranspose2dNode, test::assert_tokens},
         TensorType,
     };
-    use burn::{nn::conv::ConvTranspose2dConfig, record::FullPrecisionSettings};
+    use burn::{
+        nn::conv::ConvTranspose2dConfig, record::FullPrecisionSettings, tensor::TensorData,
+    };
 
     #[test]
     fn test_codegen() {
@@ -145,7 +144,7 @@ mod tests {
             "conv_transpose_2d",
             TensorType::new_float("input", 4),
             TensorType::new_float("output", 4),
-            TensorData::from([2f32]),
+            TensorData::from([2f32]).into(),
             None,
             ConvTranspose2dConfig::new([3, 3], [3, 3]).with_padding([0, 0]),
         ));
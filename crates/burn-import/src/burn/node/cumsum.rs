@@ -0,0 +1,114 @@
+use super::{Node, NodeCodegen};
+use crate::burn::{Scope, TensorType, ToTokens, Type};
+use burn::record::PrecisionSettings;
+use proc_macro2::TokenStream;
+use quote::quote;
+
+#[derive(new, Debug, Clone)]
+pub struct CumSumConfig {
+    pub axis: usize,
+    /// True when each sum excludes the element at its own position.
+    pub exclusive: bool,
+    /// True when the elements are summed from the last one.
+    pub reverse: bool,
+}
+
+#[derive(Debug, Clone, new)]
+pub struct CumSumNode {
+    pub input: TensorType,
+    pub output: TensorType,
+    pub config: CumSumConfig,
+}
+
+impl<PS: PrecisionSettings> NodeCodegen<PS> for CumSumNode {
+    fn output_types(&self) -> Vec<Type> {
+        vec![Type::Tensor(self.output.clone())]
+    }
+
+    fn input_types(&self) -> Vec<Type> {
+        vec![Type::Tensor(self.input.clone())]
+    }
+
+    fn forward(&self, scope: &mut Scope, node_position: usize) -> TokenStream {
+        let input = scope.tensor_use_owned(&self.input, node_position);
+        let output = &self.output.name;
+        let axis = self.config.axis.to_tokens();
+
+        let (input, flip) = match self.config.reverse {
+            true => (quote! { #input.flip([#axis]) }, quote! { .flip([#axis]) }),
+            false => (input, quote! {}),
+        };
+
+        match self.config.exclusive {
+            true => quote! {
+                let #output = {
+                    let input = #input;
+                    input.clone().cumsum(#axis).sub(input) #flip
+                };
+            },
+            false => quote! {
+                let #output = #input.cumsum(#axis) #flip;
+            },
+        }
+    }
+
+    fn into_node(self) -> Node<PS> {
+        Node::CumSum(self)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use burn::record::FullPrecisionSettings;
+
+    use super::*;
+    use crate::burn::{graph::BurnGraph, node::test::assert_tokens, TensorType};
+
+    #[test]
+    fn test_codegen_exclusive_reverse() {
+        let mut graph = BurnGraph::<FullPrecisionSettings>::default();
+
+        graph.register(CumSumNode::new(
+            TensorType::new_float("tensor1", 2),
+            TensorType::new_float("tensor2", 2),
+            CumSumConfig::new(1, true, true),
+        ));
+
+        graph.register_input_output(vec!["tensor1".to_string()], vec!["tensor2".to_string()]);
+
+        let expected = quote! {
+            use burn::{
+                module::Module,
+                tensor::{backend::Backend, Tensor},
+            };
+
+            #[derive(Module, Debug)]
+            pub struct Model<B: Backend> {
+                phantom: core::marker::PhantomData<B>,
+                device: burn::module::Ignored<B::Device>,
+            }
+
+            impl<B: Backend> Model <B> {
+                #[allow(unused_variables)]
+                pub fn new(device: &B::Device) -> Self {
+                    Self {
+                        phantom: core::marker::PhantomData,
+                        device: burn::module::Ignored(device.clone()),
+                    }
+                }
+
+                #[allow(clippy::let_and_return, clippy::approx_constant)]
+                pub fn forward(&self, tensor1: Tensor<B, 2>) -> Tensor<B, 2> {
+                    let tensor2 = {
+                        let input = tensor1.flip([1]);
+                        input.clone().cumsum(1).sub(input).flip([1])
+                    };
+
+                    tensor2
+                }
+            }
+        };
+
+        assert_tokens(graph.codegen(), expected);
+    }
+}
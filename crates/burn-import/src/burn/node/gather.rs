@@ -1,27 +1,24 @@
 use super::{Node, NodeCodegen};
-use crate::burn::{TensorType, ToTokens, Type};
+use crate::burn::{ToTokens, Type};
 
 use burn::record::PrecisionSettings;
 use quote::quote;
 
 #[derive(Debug, Clone, new)]
 pub struct GatherNode {
-    pub input: TensorType,
-    pub index: TensorType,
-    pub output: TensorType,
+    pub input: Type,
+    pub index: Type,
+    pub output: Type,
     pub dim: usize,
 }
 
 impl<PS: PrecisionSettings> NodeCodegen<PS> for GatherNode {
     fn output_types(&self) -> Vec<Type> {
-        vec![Type::Tensor(self.output.clone())]
+        vec![self.output.clone()]
     }
 
     fn input_types(&self) -> Vec<crate::burn::Type> {
-        vec![
-            Type::Tensor(self.input.clone()),
-            Type::Tensor(self.index.clone()),
-        ]
+        vec![self.input.clone(), self.index.clone()]
     }
 
     fn forward(
@@ -29,13 +26,29 @@ impl<PS: PrecisionSettings> NodeCodegen<PS> for GatherNode {
         scope: &mut crate::burn::Scope,
         node_position: usize,
     ) -> proc_macro2::TokenStream {
-        let dim = self.dim.to_tokens();
-        let input = scope.tensor_use_owned(&self.input, node_position);
-        let index = scope.tensor_use_owned(&self.index, node_position);
-        let output = &self.output.name;
+        let output = self.output.name();
 
-        quote! {
-            let #output = #input.select(#dim, #index);
+        match (&self.input, &self.index) {
+            (Type::Tensor(input), Type::Tensor(index)) => {
+                let dim = self.dim.to_tokens();
+                let input = scope.tensor_use_owned(input, node_position);
+                let index = scope.tensor_use_owned(index, node_position);
+
+                quote! {
+                    let #output = #input.select(#dim, #index);
+                }
+            }
+            // The dimensions of a runtime shape, selected by indices known when tracing the shape
+            (Type::Shape(input), Type::Shape(index)) => {
+                let input = &input.name;
+                let dim = index.dim.to_tokens();
+                let index = &index.name;
+
+                quote! {
+                    let #output: [i64; #dim] = #index.map(|i| #input[i as usize]);
+                }
+            }
+            _ => panic!("Gather: unsupported input types {:?}", self.input),
         }
     }
 
@@ -53,7 +66,7 @@ mod tests {
     use crate::burn::{
         graph::BurnGraph,
         node::{gather::GatherNode, test::assert_tokens},
-        TensorType,
+        ShapeType, TensorType,
     };
 
     #[test]
@@ -61,9 +74,9 @@ mod tests {
         let mut graph = BurnGraph::<FullPrecisionSettings>::default();
 
         graph.register(GatherNode::new(
-            TensorType::new_float("tensor1", 2),
-            TensorType::new_int("tensor2", 1),
-            TensorType::new_float("tensor3", 2),
+            Type::Tensor(TensorType::new_float("tensor1", 2)),
+            Type::Tensor(TensorType::new_int("tensor2", 1)),
+            Type::Tensor(TensorType::new_float("tensor3", 2)),
             0,
         ));
 
@@ -109,4 +122,55 @@ mod tests {
 
         assert_tokens(graph.codegen(), expected);
     }
+
+    #[test]
+    fn test_codegen_gather_shape() {
+        let mut graph = BurnGraph::<FullPrecisionSettings>::default();
+
+        graph.register(GatherNode::new(
+            Type::Shape(ShapeType::new("shape1", 3)),
+            Type::Shape(ShapeType::new("shape2", 2)),
+            Type::Shape(ShapeType::new("shape3", 2)),
+            0,
+        ));
+
+        graph.register_input_output(
+            vec!["shape1".to_string(), "shape2".to_string()],
+            vec!["shape3".to_string()],
+        );
+
+        let expected = quote! {
+            use burn::{
+                module::Module,
+                tensor::{backend::Backend, Tensor},
+            };
+
+            #[derive(Module, Debug)]
+            pub struct Model<B: Backend> {
+                phantom: core::marker::PhantomData<B>,
+                device: burn::module::Ignored<B::Device>,
+            }
+
+            impl<B: Backend> Model <B> {
+                #[allow(unused_variables)]
+                pub fn new(device: &B::Device) -> Self {
+                    Self {
+                        phantom: core::marker::PhantomData,
+                        device: burn::module::Ignored(device.clone()),
+                    }
+                }
+
+                #[allow(clippy::let_and_return, clippy::approx_constant)]
+                pub fn forward(&self, shape1: [usize; 3], shape2: [usize; 2]) -> [usize; 2] {
+                    let shape1: [i64; 3] = shape1.map(|dim| dim as i64);
+                    let shape2: [i64; 2] = shape2.map(|dim| dim as i64);
+                    let shape3: [i64; 2] = shape2.map(|i| shape1[i as usize]);
+
+                    shape3.map(|dim| dim as usize)
+                }
+            }
+        };
+
+        assert_tokens(graph.codegen(), expected);
+    }
 }
@@ -0,0 +1,317 @@
+use super::{
+    rnn::{
+        bind, direction_output, direction_sequence, last_state, GateWeights, RecurrentConfig,
+        RnnDirection,
+    },
+    subgraph::tuple,
+    Node, NodeCodegen, SerializationBackend,
+};
+use crate::burn::{BurnImports, OtherType, Scope, TensorType, ToTokens, Type};
+use burn::{
+    module::ConstantRecord,
+    nn::gru::GruRecord,
+    record::{PrecisionSettings, Record},
+};
+use proc_macro2::TokenStream;
+use quote::quote;
+use serde::Serialize;
+
+/// Node of the ONNX GRU operator, mapped to a [Gru](burn::nn::gru::Gru) for each direction.
+#[derive(Debug, Clone)]
+pub struct GruNode {
+    pub field: OtherType,
+    pub input: TensorType,
+    pub initial_hidden: Option<TensorType>,
+    /// The hidden states of each step, `[seq_length, num_directions, batch_size, d_hidden]`.
+    pub output: Option<TensorType>,
+    /// The last hidden state, `[num_directions, batch_size, d_hidden]`.
+    pub output_hidden: Option<TensorType>,
+    /// The weights of the update, reset and new gates, for each direction.
+    pub gates: Vec<Vec<GateWeights>>,
+    pub config: RecurrentConfig,
+    /// If the reset gate is applied after the linear transformation of the hidden state.
+    pub linear_before_reset: bool,
+}
+
+impl GruNode {
+    #[allow(clippy::too_many_arguments)]
+    pub fn new<S: AsRef<str>>(
+        name: S,
+        input: TensorType,
+        initial_hidden: Option<TensorType>,
+        output: Option<TensorType>,
+        output_hidden: Option<TensorType>,
+        gates: Vec<Vec<GateWeights>>,
+        config: RecurrentConfig,
+        linear_before_reset: bool,
+    ) -> Self {
+        let ty = match config.direction {
+            RnnDirection::Bidirectional => quote! { (Gru<B>, Gru<B>) },
+            _ => quote! { Gru<B> },
+        };
+
+        Self {
+            field: OtherType::new(name, ty),
+            input,
+            initial_hidden,
+            output,
+            output_hidden,
+            gates,
+            config,
+            linear_before_reset,
+        }
+    }
+
+    /// The ONNX gates are packed in the update, reset and new (hidden) order, like the Burn ones.
+    fn record<PS: PrecisionSettings>(gates: &[GateWeights]) -> GruRecord<SerializationBackend> {
+        let gate = |index: usize| gates[index].clone().into_record::<PS>();
+
+        GruRecord {
+            update_gate: gate(0),
+            reset_gate: gate(1),
+            new_gate: gate(2),
+            d_hidden: ConstantRecord::new(),
+            reset_after: ConstantRecord::new(),
+        }
+    }
+}
+
+impl<PS: PrecisionSettings> NodeCodegen<PS> for GruNode {
+    fn input_types(&self) -> Vec<Type> {
+        [Some(&self.input), self.initial_hidden.as_ref()]
+            .into_iter()
+            .flatten()
+            .map(|tensor| Type::Tensor(tensor.clone()))
+            .collect()
+    }
+
+    fn output_types(&self) -> Vec<Type> {
+        [self.output.as_ref(), self.output_hidden.as_ref()]
+            .into_iter()
+            .flatten()
+            .map(|tensor| Type::Tensor(tensor.clone()))
+            .collect()
+    }
+
+    fn field_type(&self) -> Option<Type> {
+        Some(Type::Other(self.field.clone()))
+    }
+
+    fn field_init(&self) -> Option<TokenStream> {
+        let name = &self.field.name;
+        let d_input = self.config.d_input.to_tokens();
+        let d_hidden = self.config.d_hidden.to_tokens();
+        let bias = self.config.bias;
+        let gru = match self.linear_before_reset {
+            true => quote! {
+                GruConfig::new(#d_input, #d_hidden, #bias)
+                    .with_reset_after(true)
+                    .init(device)
+            },
+            false => quote! { GruConfig::new(#d_input, #d_hidden, #bias).init(device) },
+        };
+
+        let tokens = match self.config.direction {
+            RnnDirection::Bidirectional => quote! {
+                let #name = (#gru, #gru);
+            },
+            _ => quote! {
+                let #name = #gru;
+            },
+        };
+
+        Some(tokens)
+    }
+
+    fn field_serialize<S: serde::Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        match self.config.direction {
+            RnnDirection::Bidirectional => {
+                let records = (
+                    Self::record::<PS>(&self.gates[0]),
+                    Self::record::<PS>(&self.gates[1]),
+                );
+                Record::into_item::<PS>(records).serialize(serializer)
+            }
+            _ => {
+                let record = Self::record::<PS>(&self.gates[0]);
+                Record::into_item::<PS>(record).serialize(serializer)
+            }
+        }
+    }
+
+    fn forward(&self, scope: &mut Scope, node_position: usize) -> TokenStream {
+        let field = &self.field.name;
+        let input = scope.tensor_use_owned(&self.input, node_position);
+        let input = self.config.input(input);
+        let initial_hidden = self
+            .initial_hidden
+            .as_ref()
+            .map(|state| scope.tensor_use_owned(state, node_position));
+
+        let mut body = quote! {};
+        let input = bind(&mut body, quote! { input }, input);
+        let initial_hidden =
+            initial_hidden.map(|state| bind(&mut body, quote! { initial_hidden }, state));
+
+        let mut outputs = Vec::new();
+        let mut states = Vec::new();
+        for direction in 0..self.config.num_directions() {
+            let gru = match self.config.direction {
+                RnnDirection::Bidirectional => {
+                    let direction = syn::Index::from(direction);
+                    quote! { self.#field.#direction }
+                }
+                _ => quote! { self.#field },
+            };
+            let sequence = direction_sequence(&self.config, &input, direction);
+            let state = match &initial_hidden {
+                Some(initial_hidden) => {
+                    let state = self.config.direction_state(initial_hidden, direction);
+                    quote! { Some(#state) }
+                }
+                None => quote! { None },
+            };
+            let output = direction_output(
+                &self.config,
+                quote! { #gru.forward(#sequence, #state) },
+                direction,
+            );
+
+            let name = quote::format_ident!("output_{}", direction);
+            body.extend(quote! { let #name = #output; });
+
+            let name = quote! { #name };
+            states.push(last_state(&self.config, &name, direction));
+            outputs.push(name);
+        }
+
+        // The last states are taken before the output sequences are moved
+        let mut results = Vec::new();
+        if self.output_hidden.is_some() {
+            let state = self.config.stack_state(&states);
+            body.extend(quote! { let output_hidden = #state; });
+        }
+        if self.output.is_some() {
+            results.push(self.config.stack_output(&outputs));
+        }
+        if self.output_hidden.is_some() {
+            results.push(quote! { output_hidden });
+        }
+
+        let output_names = NodeCodegen::<PS>::output_types(self)
+            .into_iter()
+            .map(|output| {
+                let name = output.name();
+                quote! { #name }
+            })
+            .collect::<Vec<_>>();
+        let output_names = tuple(&output_names);
+        let results = tuple(&results);
+
+        quote! {
+            let #output_names = {
+                #body
+                #results
+            };
+        }
+    }
+
+    fn register_imports(&self, imports: &mut BurnImports) {
+        imports.register("burn::nn::gru::Gru");
+        imports.register("burn::nn::gru::GruConfig");
+    }
+
+    fn into_node(self) -> Node<PS> {
+        Node::Gru(self)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::burn::{graph::BurnGraph, node::test::assert_tokens, TensorType};
+    use burn::{record::FullPrecisionSettings, tensor::TensorData};
+
+    #[test]
+    fn test_codegen_reverse() {
+        let mut graph = BurnGraph::<FullPrecisionSettings>::default();
+
+        graph.register(GruNode::new(
+            "gru",
+            TensorType::new_float("input", 3),
+            Some(TensorType::new_float("initial_hidden", 3)),
+            Some(TensorType::new_float("output", 4)),
+            Some(TensorType::new_float("output_hidden", 3)),
+            GateWeights::from_onnx(
+                TensorData::zeros::<f32, _>([1, 3 * 8, 4]).into(),
+                TensorData::zeros::<f32, _>([1, 3 * 8, 8]).into(),
+                None,
+                3,
+            ),
+            RecurrentConfig::new(4, 8, false, RnnDirection::Reverse, false),
+            false,
+        ));
+
+        graph.register_input_output(
+            vec!["input".to_string(), "initial_hidden".to_string()],
+            vec!["output".to_string(), "output_hidden".to_string()],
+        );
+
+        let expected = quote! {
+            use burn::nn::gru::Gru;
+            use burn::nn::gru::GruConfig;
+            use burn::{
+                module::Module,
+                tensor::{backend::Backend, Tensor},
+            };
+
+            #[derive(Module, Debug)]
+            pub struct Model<B: Backend> {
+                gru: Gru<B>,
+                phantom: core::marker::PhantomData<B>,
+                device: burn::module::Ignored<B::Device>,
+            }
+
+            impl<B: Backend> Model <B> {
+                #[allow(unused_variables)]
+                pub fn new(device: &B::Device) -> Self {
+                    let gru = GruConfig::new(4, 8, false).init(device);
+
+                    Self {
+                        gru,
+                        phantom: core::marker::PhantomData,
+                        device: burn::module::Ignored(device.clone()),
+                    }
+                }
+
+                #[allow(clippy::let_and_return, clippy::approx_constant)]
+                pub fn forward(
+                    &self,
+                    input: Tensor<B, 3>,
+                    initial_hidden: Tensor<B, 3>
+                ) -> (Tensor<B, 4>, Tensor<B, 3>) {
+                    let (output, output_hidden) = {
+                        let input = input.swap_dims(0, 1);
+                        let output_0 = self
+                            .gru
+                            .forward(input.flip([1]), Some(initial_hidden.squeeze::<2>(0)))
+                            .flip([1]);
+                        let output_hidden = output_0
+                            .clone()
+                            .narrow(1, 0, 1)
+                            .squeeze::<2>(1)
+                            .unsqueeze_dim::<3>(0);
+                        (
+                            output_0.unsqueeze_dim::<4>(2).permute([1, 2, 0, 3]),
+                            output_hidden
+                        )
+                    };
+
+                    (output, output_hidden)
+                }
+            }
+        };
+
+        assert_tokens(graph.codegen(), expected);
+    }
+}
@@ -0,0 +1,193 @@
+use super::{
+    subgraph::{tuple, SubGraph},
+    Node, NodeCodegen,
+};
+use crate::burn::{Scope, Type};
+
+use burn::record::PrecisionSettings;
+use proc_macro2::TokenStream;
+use quote::quote;
+
+/// Node executing one of two sub-graphs depending on a condition.
+#[derive(Debug, Clone, new)]
+pub struct IfNode<PS: PrecisionSettings> {
+    /// Bool scalar, or bool tensor with a single element.
+    pub condition: Type,
+    pub then_branch: SubGraph<PS>,
+    pub else_branch: SubGraph<PS>,
+    /// The variables of the enclosing scope used by the branches.
+    pub captured: Vec<Type>,
+    pub outputs: Vec<Type>,
+}
+
+impl<PS: PrecisionSettings> NodeCodegen<PS> for IfNode<PS> {
+    fn output_types(&self) -> Vec<Type> {
+        self.outputs.clone()
+    }
+
+    fn input_types(&self) -> Vec<Type> {
+        let mut inputs = vec![self.condition.clone()];
+        inputs.extend(self.captured.iter().cloned());
+        inputs
+    }
+
+    fn forward(&self, scope: &mut Scope, node_position: usize) -> TokenStream {
+        let condition = match &self.condition {
+            Type::Scalar(scalar) => {
+                let name = &scalar.name;
+                quote! { #name }
+            }
+            Type::Tensor(tensor) => {
+                let tensor = scope.tensor_use_owned(tensor, node_position);
+                quote! { #tensor.into_scalar() }
+            }
+            _ => panic!("If condition must be a scalar or a tensor"),
+        };
+
+        let mut captured = quote! {};
+        for ty in self.captured.iter() {
+            if let Type::Tensor(tensor) = ty {
+                captured.extend(scope.tensor_use_captured(tensor, node_position));
+            }
+        }
+
+        let (then_body, mut then_scope) = self.then_branch.forward(&self.captured);
+        let (else_body, mut else_scope) = self.else_branch.forward(&self.captured);
+        let then_outputs = tuple(&self.then_branch.outputs(&mut then_scope));
+        let else_outputs = tuple(&self.else_branch.outputs(&mut else_scope));
+        let outputs = tuple(
+            &self
+                .outputs
+                .iter()
+                .map(|output| {
+                    let name = output.name();
+                    quote! { #name }
+                })
+                .collect::<Vec<_>>(),
+        );
+
+        let branches = quote! {
+            if #condition {
+                #then_body
+                #then_outputs
+            } else {
+                #else_body
+                #else_outputs
+            }
+        };
+
+        if captured.is_empty() {
+            quote! {
+                let #outputs = #branches;
+            }
+        } else {
+            quote! {
+                let #outputs = {
+                    #captured
+                    #branches
+                };
+            }
+        }
+    }
+
+    fn into_node(self) -> Node<PS> {
+        Node::If(self)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use burn::record::FullPrecisionSettings;
+
+    use super::*;
+    use crate::burn::{
+        graph::BurnGraph,
+        node::{test::assert_tokens, unary::UnaryNode},
+        ScalarKind, ScalarType, TensorType,
+    };
+
+    #[test]
+    fn test_codegen_if() {
+        let mut graph = BurnGraph::<FullPrecisionSettings>::default();
+        let input = Type::Tensor(TensorType::new_float("tensor1", 2));
+
+        let then_branch = SubGraph::new(
+            vec![UnaryNode::relu(
+                input.clone(),
+                Type::Tensor(TensorType::new_float("tensor2", 2)),
+            )
+            .into_node()],
+            vec![],
+            vec![Type::Tensor(TensorType::new_float("tensor2", 2))],
+        );
+        let else_branch = SubGraph::new(
+            vec![UnaryNode::neg(
+                input.clone(),
+                Type::Tensor(TensorType::new_float("tensor3", 2)),
+            )
+            .into_node()],
+            vec![],
+            vec![Type::Tensor(TensorType::new_float("tensor3", 2))],
+        );
+
+        graph.register(IfNode::new(
+            Type::Scalar(ScalarType::new("cond", ScalarKind::Bool)),
+            then_branch,
+            else_branch,
+            vec![input.clone()],
+            vec![Type::Tensor(TensorType::new_float("tensor4", 2))],
+        ));
+        graph.register(UnaryNode::relu(
+            input,
+            Type::Tensor(TensorType::new_float("tensor5", 2)),
+        ));
+
+        graph.register_input_output(
+            vec!["cond".to_string(), "tensor1".to_string()],
+            vec!["tensor4".to_string(), "tensor5".to_string()],
+        );
+
+        let expected = quote! {
+            use burn::{
+                module::Module,
+                tensor::{backend::Backend, Tensor},
+            };
+            use core::ops::Neg;
+
+            #[derive(Module, Debug)]
+            pub struct Model<B: Backend> {
+                phantom: core::marker::PhantomData<B>,
+                device: burn::module::Ignored<B::Device>,
+            }
+
+            impl<B: Backend> Model <B> {
+                #[allow(unused_variables)]
+                pub fn new(device: &B::Device) -> Self {
+                    Self {
+                        phantom: core::marker::PhantomData,
+                        device: burn::module::Ignored(device.clone()),
+                    }
+                }
+
+                #[allow(clippy::let_and_return, clippy::approx_constant)]
+                pub fn forward(&self, cond: bool, tensor1: Tensor<B, 2>) -> (Tensor<B, 2>, Tensor<B, 2>) {
+                    let tensor4 = {
+                        let tensor1 = tensor1.clone();
+                        if cond {
+                            let tensor2 = burn::tensor::activation::relu(tensor1);
+                            tensor2
+                        } else {
+                            let tensor3 = tensor1.neg();
+                            tensor3
+                        }
+                    };
+                    let tensor5 = burn::tensor::activation::relu(tensor1);
+
+                    (tensor4, tensor5)
+                }
+            }
+        };
+
+        assert_tokens(graph.codegen(), expected);
+    }
+}
@@ -1,10 +1,10 @@
 use super::{Node, NodeCodegen, SerializationBackend};
-use crate::burn::{BurnImports, OtherType, Scope, TensorType, ToTokens, Type};
+use crate::burn::{BurnImports, LazyTensorData, OtherType, Scope, TensorType, ToTokens, Type};
 use burn::{
     module::{ConstantRecord, Param, ParamId},
     nn::{LayerNormConfig, LayerNormRecord},
     record::{PrecisionSettings, Record},
-    tensor::{Tensor, TensorData},
+    tensor::Tensor,
 };
 use proc_macro2::TokenStream;
 use quote::quote;
@@ -15,8 +15,8 @@ pub struct LayerNormNode {
     pub field: OtherType,
     pub input: TensorType,
     pub output: TensorType,
-    pub gamma: TensorData,        // Scale
-    pub beta: Option<TensorData>, // Bias (B)
+    pub gamma: LazyTensorData,        // Scale
+    pub beta: Option<LazyTensorData>, // Bias (B)
     pub config: LayerNormConfig,
     pub full_precision: bool,
 }
@@ -26,8 +26,8 @@ impl LayerNormNode {
         name: S,
         input: TensorType,
         output: TensorType,
-        gamma: TensorData,
-        beta: Option<TensorData>,
+        gamma: LazyTensorData,
+        beta: Option<LazyTensorData>,
         config: LayerNormConfig,
         full_precision: bool,
     ) -> Self {
@@ -78,12 +78,12 @@ impl<PS: PrecisionSettings> NodeCodegen<PS> for LayerNormNode {
         let record = LayerNormRecord::<SerializationBackend> {
             gamma: Param::initialized(
                 ParamId::new(),
-                Tensor::from_data(self.gamma.clone().convert::<PS::FloatElem>(), &device),
+                Tensor::from_data(self.gamma.read().convert::<PS::FloatElem>(), &device),
             ),
             beta: Param::initialized(
                 ParamId::new(),
-                if let Some(beta) = self.beta.clone() {
-                    Tensor::from_data(beta.convert::<PS::FloatElem>(), &device)
+                if let Some(beta) = &self.beta {
+                    Tensor::from_data(beta.read().convert::<PS::FloatElem>(), &device)
                 } else {
                     Tensor::zeros([self.config.d_model], &device)
                 },
@@ -119,7 +119,7 @@ impl<PS: PrecisionSettings> NodeCodegen<PS> for LayerNormNode {
 mod tests {
     use super::*;
     use crate::burn::{graph::BurnGraph, node::test::assert_tokens, TensorType};
-    use burn::record::FullPrecisionSettings;
+    use burn::{record::FullPrecisionSettings, tensor::TensorData};
 
     #[test]
     fn test_codegen() {
@@ -129,8 +129,8 @@ mod tests {
             "norm",
             TensorType::new_float("input", 4),
             TensorType::new_float("output", 4),
-            TensorData::from([2f32]),
-            Some(TensorData::from([2f32])),
+            TensorData::from([2f32]).into(),
+            Some(TensorData::from([2f32]).into()),
             LayerNormConfig::new(128),
             true, // full_precision isn't taken into account
         ));
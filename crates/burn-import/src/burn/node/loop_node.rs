@@ -0,0 +1,299 @@
+use super::{
+    subgraph::{register_scan_output_imports, scan_output, tuple, SubGraph},
+    Node, NodeCodegen,
+};
+use crate::burn::{BurnImports, ScalarKind, Scope, Type};
+
+use burn::record::PrecisionSettings;
+use proc_macro2::TokenStream;
+use quote::quote;
+
+/// Node executing a sub-graph repeatedly, until the maximum trip count is reached or the
+/// condition is false.
+///
+/// The body inputs are the iteration number, the condition and the loop carried values. The body
+/// outputs are the condition, the loop carried values and the scan outputs, whose values of each
+/// iteration are stacked on a new first dimension.
+#[derive(Debug, Clone, new)]
+pub struct LoopNode<PS: PrecisionSettings> {
+    /// Int scalar, or int tensor with a single element. Unbounded when missing.
+    pub max_trip_count: Option<Type>,
+    /// Bool scalar, or bool tensor with a single element. True when missing.
+    pub condition: Option<Type>,
+    /// The initial values of the loop carried values.
+    pub initial_values: Vec<Type>,
+    pub body: SubGraph<PS>,
+    /// The variables of the enclosing scope used by the body.
+    pub captured: Vec<Type>,
+    pub outputs: Vec<Type>,
+}
+
+impl<PS: PrecisionSettings> NodeCodegen<PS> for LoopNode<PS> {
+    fn output_types(&self) -> Vec<Type> {
+        self.outputs.clone()
+    }
+
+    fn input_types(&self) -> Vec<Type> {
+        self.max_trip_count
+            .iter()
+            .chain(self.condition.iter())
+            .chain(self.initial_values.iter())
+            .chain(self.captured.iter())
+            .cloned()
+            .collect()
+    }
+
+    fn forward(&self, scope: &mut Scope, node_position: usize) -> TokenStream {
+        let max_trip_count = match &self.max_trip_count {
+            Some(Type::Scalar(scalar)) => {
+                let name = &scalar.name;
+                match scalar.kind {
+                    ScalarKind::Int64 => quote! { #name },
+                    ScalarKind::Int32 => quote! { #name as i64 },
+                    _ => panic!("Loop max trip count must be an int"),
+                }
+            }
+            Some(Type::Tensor(tensor)) => {
+                let tensor = scope.tensor_use_owned(tensor, node_position);
+                quote! { #tensor.into_scalar().elem::<i64>() }
+            }
+            Some(_) => panic!("Loop max trip count must be a scalar or a tensor"),
+            None => quote! { i64::MAX },
+        };
+        let condition = match &self.condition {
+            Some(Type::Scalar(scalar)) => {
+                let name = &scalar.name;
+                quote! { #name }
+            }
+            Some(Type::Tensor(tensor)) => {
+                let tensor = scope.tensor_use_owned(tensor, node_position);
+                quote! { #tensor.into_scalar() }
+            }
+            Some(_) => panic!("Loop condition must be a scalar or a tensor"),
+            None => quote! { true },
+        };
+        let initial_values = self.initial_values.iter().map(|ty| match ty {
+            Type::Tensor(tensor) => scope.tensor_use_owned(tensor, node_position),
+            _ => {
+                let name = ty.name();
+                quote! { #name }
+            }
+        });
+        let initial_values = std::iter::once(condition)
+            .chain(initial_values)
+            .collect::<Vec<_>>();
+
+        let mut captured = quote! {};
+        for ty in self.captured.iter() {
+            if let Type::Tensor(tensor) = ty {
+                let name = &tensor.name;
+                let tensor = scope.tensor_use_in_loop(tensor, node_position);
+                captured.extend(quote! { let #name = #tensor; });
+            }
+        }
+
+        let (body, mut body_scope) = self.body.forward(&self.captured);
+        let num_carried = self.body.inputs.len() - 2;
+        let mut declarations = quote! {};
+        let mut scan_declarations = quote! {};
+
+        // The scan outputs are pushed before the loop carried values are updated, since they can
+        // be the values of the current iteration.
+        let mut scan_pushes = quote! {};
+        let mut scan_results = Vec::new();
+        for (i, output) in self.outputs[num_carried..].iter().enumerate() {
+            let index = 1 + num_carried + i;
+            let name = output.name();
+            let value = self.body.output(&mut body_scope, index);
+            let (declaration, push, result) =
+                scan_output(name, &self.body.outputs[index], value, 0);
+
+            scan_declarations.extend(declaration);
+            scan_pushes.extend(push);
+            scan_results.push(result);
+        }
+
+        // The condition and the loop carried values are variables updated by each iteration
+        let variables = &self.body.inputs[1..];
+        let mut targets = Vec::new();
+        let mut updates = Vec::new();
+        for (index, (variable, initial)) in variables.iter().zip(initial_values).enumerate() {
+            let name = variable.name();
+            let mut value = self.body.output(&mut body_scope, index);
+
+            // A value passed through the iterations is never updated
+            if self.body.outputs[index].name() == name {
+                declarations.extend(quote! { let #name = #initial; });
+                continue;
+            }
+
+            if index == 0 && matches!(self.body.outputs[0], Type::Tensor(_)) {
+                value = quote! { #value.into_scalar() };
+            }
+            declarations.extend(quote! { let mut #name = #initial; });
+            targets.push(quote! { #name });
+            updates.push(value);
+        }
+        let updates = match updates.is_empty() {
+            true => quote! {},
+            false => {
+                let targets = tuple(&targets);
+                let updates = tuple(&updates);
+                quote! { #targets = #updates; }
+            }
+        };
+
+        let iteration = self.body.inputs[0].name();
+        let iteration = match self.body.uses(iteration) {
+            true => quote! { #iteration },
+            false => quote! { _ },
+        };
+        let condition = self.body.inputs[1].name();
+        let results = tuple(
+            &variables[1..]
+                .iter()
+                .map(|variable| {
+                    let name = variable.name();
+                    quote! { #name }
+                })
+                .chain(scan_results)
+                .collect::<Vec<_>>(),
+        );
+        let outputs = tuple(
+            &self
+                .outputs
+                .iter()
+                .map(|output| {
+                    let name = output.name();
+                    quote! { #name }
+                })
+                .collect::<Vec<_>>(),
+        );
+
+        quote! {
+            let #outputs = {
+                #declarations
+                #scan_declarations
+                for #iteration in 0..#max_trip_count {
+                    if !#condition {
+                        break;
+                    }
+                    #captured
+                    #body
+                    #scan_pushes
+                    #updates
+                }
+                #results
+            };
+        }
+    }
+
+    fn register_imports(&self, imports: &mut BurnImports) {
+        if matches!(self.max_trip_count, Some(Type::Tensor(_))) {
+            imports.register("burn::tensor::ElementConversion");
+        }
+        let num_carried = self.body.inputs.len() - 2;
+        register_scan_output_imports(&self.body.outputs[1 + num_carried..], imports);
+    }
+
+    fn into_node(self) -> Node<PS> {
+        Node::Loop(self)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use burn::record::FullPrecisionSettings;
+
+    use super::*;
+    use crate::burn::{
+        graph::BurnGraph,
+        node::{binary::BinaryNode, test::assert_tokens},
+        ScalarKind, ScalarType, TensorType,
+    };
+
+    #[test]
+    fn test_codegen_loop() {
+        let mut graph = BurnGraph::<FullPrecisionSettings>::default();
+        let input = Type::Tensor(TensorType::new_float("tensor1", 2));
+        let state = Type::Tensor(TensorType::new_float("state", 2));
+        let next = Type::Tensor(TensorType::new_float("tensor2", 2));
+
+        let body = SubGraph::new(
+            vec![BinaryNode::add(state.clone(), input.clone(), next.clone()).into_node()],
+            vec![
+                Type::Scalar(ScalarType::new("iteration", ScalarKind::Int64)),
+                Type::Scalar(ScalarType::new("cond", ScalarKind::Bool)),
+                state,
+            ],
+            vec![
+                Type::Scalar(ScalarType::new("cond", ScalarKind::Bool)),
+                next.clone(),
+                next,
+            ],
+        );
+
+        graph.register(LoopNode::new(
+            Some(Type::Scalar(ScalarType::new("count", ScalarKind::Int64))),
+            None,
+            vec![input.clone()],
+            body,
+            vec![input],
+            vec![
+                Type::Tensor(TensorType::new_float("tensor3", 2)),
+                Type::Tensor(TensorType::new_float("tensor4", 3)),
+            ],
+        ));
+
+        graph.register_input_output(
+            vec!["count".to_string(), "tensor1".to_string()],
+            vec!["tensor3".to_string(), "tensor4".to_string()],
+        );
+
+        let expected = quote! {
+            use burn::{
+                module::Module,
+                tensor::{backend::Backend, Tensor},
+            };
+
+            #[derive(Module, Debug)]
+            pub struct Model<B: Backend> {
+                phantom: core::marker::PhantomData<B>,
+                device: burn::module::Ignored<B::Device>,
+            }
+
+            impl<B: Backend> Model <B> {
+                #[allow(unused_variables)]
+                pub fn new(device: &B::Device) -> Self {
+                    Self {
+                        phantom: core::marker::PhantomData,
+                        device: burn::module::Ignored(device.clone()),
+                    }
+                }
+
+                #[allow(clippy::let_and_return, clippy::approx_constant)]
+                pub fn forward(&self, count: i64, tensor1: Tensor<B, 2>) -> (Tensor<B, 2>, Tensor<B, 3>) {
+                    let (tensor3, tensor4) = {
+                        let cond = true;
+                        let mut state = tensor1.clone();
+                        let mut tensor4 = [].to_vec();
+                        for _ in 0..count {
+                            if !cond {
+                                break;
+                            }
+                            let tensor1 = tensor1.clone();
+                            let tensor2 = state.add(tensor1);
+                            tensor4.push(tensor2.clone());
+                            state = tensor2;
+                        }
+                        (state, Tensor::stack::<3>(tensor4, 0usize))
+                    };
+
+                    (tensor3, tensor4)
+                }
+            }
+        };
+
+        assert_tokens(graph.codegen(), expected);
+    }
+}
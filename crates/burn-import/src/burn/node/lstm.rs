@@ -0,0 +1,464 @@
+use super::{
+    rnn::{direction_output, direction_sequence, GateWeights, RecurrentConfig, RnnDirection},
+    subgraph::tuple,
+    Node, NodeCodegen, SerializationBackend,
+};
+use crate::burn::{BurnImports, OtherType, Scope, TensorType, ToTokens, Type};
+use burn::{
+    module::ConstantRecord,
+    nn::{BiLstmRecord, LstmRecord},
+    record::{PrecisionSettings, Record},
+};
+use proc_macro2::TokenStream;
+use quote::quote;
+use serde::Serialize;
+
+/// Node of the ONNX LSTM operator, mapped to a [Lstm](burn::nn::Lstm), or to a
+/// [BiLstm](burn::nn::BiLstm) for the bidirectional ones.
+#[derive(Debug, Clone)]
+pub struct LstmNode {
+    pub field: OtherType,
+    pub input: TensorType,
+    pub initial_hidden: Option<TensorType>,
+    pub initial_cell: Option<TensorType>,
+    /// The hidden states of each step, `[seq_length, num_directions, batch_size, d_hidden]`.
+    pub output: Option<TensorType>,
+    /// The last hidden state, `[num_directions, batch_size, d_hidden]`.
+    pub output_hidden: Option<TensorType>,
+    /// The last cell state, `[num_directions, batch_size, d_hidden]`.
+    pub output_cell: Option<TensorType>,
+    /// The weights of the input, output, forget and cell gates, for each direction.
+    pub gates: Vec<Vec<GateWeights>>,
+    pub config: RecurrentConfig,
+}
+
+/// Index of the gates in the ONNX weights, packed in the input, output, forget, cell order.
+pub(crate) const INPUT_GATE: usize = 0;
+pub(crate) const OUTPUT_GATE: usize = 1;
+pub(crate) const FORGET_GATE: usize = 2;
+pub(crate) const CELL_GATE: usize = 3;
+
+impl LstmNode {
+    #[allow(clippy::too_many_arguments)]
+    pub fn new<S: AsRef<str>>(
+        name: S,
+        input: TensorType,
+        initial_hidden: Option<TensorType>,
+        initial_cell: Option<TensorType>,
+        output: Option<TensorType>,
+        output_hidden: Option<TensorType>,
+        output_cell: Option<TensorType>,
+        gates: Vec<Vec<GateWeights>>,
+        config: RecurrentConfig,
+    ) -> Self {
+        let ty = match config.direction {
+            RnnDirection::Bidirectional => quote! { BiLstm<B> },
+            _ => quote! { Lstm<B> },
+        };
+
+        Self {
+            field: OtherType::new(name, ty),
+            input,
+            initial_hidden,
+            initial_cell,
+            output,
+            output_hidden,
+            output_cell,
+            gates,
+            config,
+        }
+    }
+
+    fn record<PS: PrecisionSettings>(gates: &[GateWeights]) -> LstmRecord<SerializationBackend> {
+        let gate = |index: usize| gates[index].clone().into_record::<PS>();
+
+        LstmRecord {
+            input_gate: gate(INPUT_GATE),
+            forget_gate: gate(FORGET_GATE),
+            output_gate: gate(OUTPUT_GATE),
+            cell_gate: gate(CELL_GATE),
+            d_hidden: ConstantRecord::new(),
+        }
+    }
+
+    /// Generate the code of the initial states of the module, if any.
+    ///
+    /// The [BiLstm](burn::nn::BiLstm) states are stacked on the first axis, like the default ONNX
+    /// layout.
+    fn initial_state(&self, scope: &mut Scope, node_position: usize) -> TokenStream {
+        let hidden = self
+            .initial_hidden
+            .as_ref()
+            .map(|state| scope.tensor_use_owned(state, node_position));
+        let cell = self
+            .initial_cell
+            .as_ref()
+            .map(|state| scope.tensor_use_owned(state, node_position));
+
+        let state = |state: TokenStream| match self.config.direction {
+            RnnDirection::Bidirectional if self.config.batch_first => {
+                quote! { #state.swap_dims(0, 1) }
+            }
+            RnnDirection::Bidirectional => state,
+            _ => self.config.direction_state(&state, 0),
+        };
+
+        // A missing state is initialized to zeros, like the module does when both are missing
+        match (hidden, cell) {
+            (Some(hidden), Some(cell)) => {
+                let hidden = state(hidden);
+                let cell = state(cell);
+                quote! { Some(LstmState::new(#cell, #hidden)) }
+            }
+            (Some(hidden), None) => {
+                let hidden = state(hidden);
+                quote! {{
+                    let hidden = #hidden;
+                    Some(LstmState::new(hidden.zeros_like(), hidden))
+                }}
+            }
+            (None, Some(cell)) => {
+                let cell = state(cell);
+                quote! {{
+                    let cell = #cell;
+                    Some(LstmState::new(cell.clone(), cell.zeros_like()))
+                }}
+            }
+            (None, None) => quote! { None },
+        }
+    }
+}
+
+impl<PS: PrecisionSettings> NodeCodegen<PS> for LstmNode {
+    fn input_types(&self) -> Vec<Type> {
+        [
+            Some(&self.input),
+            self.initial_hidden.as_ref(),
+            self.initial_cell.as_ref(),
+        ]
+        .into_iter()
+        .flatten()
+        .map(|tensor| Type::Tensor(tensor.clone()))
+        .collect()
+    }
+
+    fn output_types(&self) -> Vec<Type> {
+        [
+            self.output.as_ref(),
+            self.output_hidden.as_ref(),
+            self.output_cell.as_ref(),
+        ]
+        .into_iter()
+        .flatten()
+        .map(|tensor| Type::Tensor(tensor.clone()))
+        .collect()
+    }
+
+    fn field_type(&self) -> Option<Type> {
+        Some(Type::Other(self.field.clone()))
+    }
+
+    fn field_init(&self) -> Option<TokenStream> {
+        let name = &self.field.name;
+        let d_input = self.config.d_input.to_tokens();
+        let d_hidden = self.config.d_hidden.to_tokens();
+        let bias = self.config.bias;
+
+        let tokens = match self.config.direction {
+            RnnDirection::Bidirectional => quote! {
+                let #name = BiLstmConfig::new(#d_input, #d_hidden, #bias).init(device);
+            },
+            _ => quote! {
+                let #name = LstmConfig::new(#d_input, #d_hidden, #bias).init(device);
+            },
+        };
+
+        Some(tokens)
+    }
+
+    fn field_serialize<S: serde::Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        match self.config.direction {
+            RnnDirection::Bidirectional => {
+                let record = BiLstmRecord::<SerializationBackend> {
+                    forward: Self::record::<PS>(&self.gates[0]),
+                    reverse: Self::record::<PS>(&self.gates[1]),
+                    d_hidden: ConstantRecord::new(),
+                };
+                Record::into_item::<PS>(record).serialize(serializer)
+            }
+            _ => {
+                let record = Self::record::<PS>(&self.gates[0]);
+                Record::into_item::<PS>(record).serialize(serializer)
+            }
+        }
+    }
+
+    fn forward(&self, scope: &mut Scope, node_position: usize) -> TokenStream {
+        let field = &self.field.name;
+        let input = scope.tensor_use_owned(&self.input, node_position);
+        let input = self.config.input(input);
+        let state = self.initial_state(scope, node_position);
+        let d_hidden = self.config.d_hidden.to_tokens();
+
+        let mut results = Vec::new();
+        let forward = match self.config.direction {
+            RnnDirection::Bidirectional => {
+                // The hidden states of both directions are concatenated on the last axis
+                if self.output.is_some() {
+                    let output = quote! {
+                        output.reshape([batch_size, seq_length, 2, #d_hidden])
+                    };
+                    let output = match self.config.batch_first {
+                        true => output,
+                        false => quote! { #output.permute([1, 2, 0, 3]) },
+                    };
+                    results.push(quote! {{
+                        let [batch_size, seq_length, _] = output.dims();
+                        #output
+                    }});
+                }
+                let output_state = |state| match self.config.batch_first {
+                    true => quote! { #state.swap_dims(0, 1) },
+                    false => state,
+                };
+                if self.output_hidden.is_some() {
+                    results.push(output_state(quote! { state.hidden }));
+                }
+                if self.output_cell.is_some() {
+                    results.push(output_state(quote! { state.cell }));
+                }
+
+                quote! { self.#field.forward(#input, #state) }
+            }
+            _ => {
+                let sequence = direction_sequence(&self.config, &input, 0);
+                let output = direction_output(&self.config, quote! { output }, 0);
+                if self.output.is_some() {
+                    results.push(self.config.stack_output(&[output]));
+                }
+                if self.output_hidden.is_some() {
+                    results.push(self.config.stack_state(&[quote! { state.hidden }]));
+                }
+                if self.output_cell.is_some() {
+                    results.push(self.config.stack_state(&[quote! { state.cell }]));
+                }
+
+                quote! { self.#field.forward(#sequence, #state) }
+            }
+        };
+
+        let output_names = NodeCodegen::<PS>::output_types(self)
+            .into_iter()
+            .map(|output| {
+                let name = output.name();
+                quote! { #name }
+            })
+            .collect::<Vec<_>>();
+        let output_names = tuple(&output_names);
+        let results = tuple(&results);
+
+        // The outputs of the module which aren't outputs of the node are ignored
+        let output = match self.output {
+            Some(_) => quote! { output },
+            None => quote! { _ },
+        };
+        let state = match self.output_hidden.is_some() || self.output_cell.is_some() {
+            true => quote! { state },
+            false => quote! { _ },
+        };
+
+        quote! {
+            let #output_names = {
+                let (#output, #state) = #forward;
+                #results
+            };
+        }
+    }
+
+    fn register_imports(&self, imports: &mut BurnImports) {
+        match self.config.direction {
+            RnnDirection::Bidirectional => {
+                imports.register("burn::nn::BiLstm");
+                imports.register("burn::nn::BiLstmConfig");
+            }
+            _ => {
+                imports.register("burn::nn::Lstm");
+                imports.register("burn::nn::LstmConfig");
+            }
+        }
+        if self.initial_hidden.is_some() || self.initial_cell.is_some() {
+            imports.register("burn::nn::LstmState");
+        }
+    }
+
+    fn into_node(self) -> Node<PS> {
+        Node::Lstm(self)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::burn::{graph::BurnGraph, node::test::assert_tokens, TensorType};
+    use burn::{record::FullPrecisionSettings, tensor::TensorData};
+
+    fn gates(num_directions: usize) -> Vec<Vec<GateWeights>> {
+        GateWeights::from_onnx(
+            TensorData::zeros::<f32, _>([num_directions, 4 * 8, 4]).into(),
+            TensorData::zeros::<f32, _>([num_directions, 4 * 8, 8]).into(),
+            None,
+            4,
+        )
+    }
+
+    #[test]
+    fn test_codegen_forward() {
+        let mut graph = BurnGraph::<FullPrecisionSettings>::default();
+
+        graph.register(LstmNode::new(
+            "lstm",
+            TensorType::new_float("input", 3),
+            Some(TensorType::new_float("initial_hidden", 3)),
+            Some(TensorType::new_float("initial_cell", 3)),
+            Some(TensorType::new_float("output", 4)),
+            Some(TensorType::new_float("output_hidden", 3)),
+            None,
+            gates(1),
+            RecurrentConfig::new(4, 8, false, RnnDirection::Forward, false),
+        ));
+
+        graph.register_input_output(
+            vec![
+                "input".to_string(),
+                "initial_hidden".to_string(),
+                "initial_cell".to_string(),
+            ],
+            vec!["output".to_string(), "output_hidden".to_string()],
+        );
+
+        let expected = quote! {
+            use burn::nn::Lstm;
+            use burn::nn::LstmConfig;
+            use burn::nn::LstmState;
+            use burn::{
+                module::Module,
+                tensor::{backend::Backend, Tensor},
+            };
+
+            #[derive(Module, Debug)]
+            pub struct Model<B: Backend> {
+                lstm: Lstm<B>,
+                phantom: core::marker::PhantomData<B>,
+                device: burn::module::Ignored<B::Device>,
+            }
+
+            impl<B: Backend> Model <B> {
+                #[allow(unused_variables)]
+                pub fn new(device: &B::Device) -> Self {
+                    let lstm = LstmConfig::new(4, 8, false).init(device);
+
+                    Self {
+                        lstm,
+                        phantom: core::marker::PhantomData,
+                        device: burn::module::Ignored(device.clone()),
+                    }
+                }
+
+                #[allow(clippy::let_and_return, clippy::approx_constant)]
+                pub fn forward(
+                    &self,
+                    input: Tensor<B, 3>,
+                    initial_hidden: Tensor<B, 3>,
+                    initial_cell: Tensor<B, 3>
+                ) -> (Tensor<B, 4>, Tensor<B, 3>) {
+                    let (output, output_hidden) = {
+                        let (output, state) = self.lstm.forward(
+                            input.swap_dims(0, 1),
+                            Some(LstmState::new(
+                                initial_cell.squeeze::<2>(0),
+                                initial_hidden.squeeze::<2>(0)
+                            ))
+                        );
+                        (
+                            output.unsqueeze_dim::<4>(2).permute([1, 2, 0, 3]),
+                            state.hidden.unsqueeze_dim::<3>(0)
+                        )
+                    };
+
+                    (output, output_hidden)
+                }
+            }
+        };
+
+        assert_tokens(graph.codegen(), expected);
+    }
+
+    #[test]
+    fn test_codegen_bidirectional() {
+        let mut graph = BurnGraph::<FullPrecisionSettings>::default();
+
+        graph.register(LstmNode::new(
+            "lstm",
+            TensorType::new_float("input", 3),
+            None,
+            None,
+            Some(TensorType::new_float("output", 4)),
+            None,
+            Some(TensorType::new_float("output_cell", 3)),
+            gates(2),
+            RecurrentConfig::new(4, 8, true, RnnDirection::Bidirectional, true),
+        ));
+
+        graph.register_input_output(
+            vec!["input".to_string()],
+            vec!["output".to_string(), "output_cell".to_string()],
+        );
+
+        let expected = quote! {
+            use burn::nn::BiLstm;
+            use burn::nn::BiLstmConfig;
+            use burn::{
+                module::Module,
+                tensor::{backend::Backend, Tensor},
+            };
+
+            #[derive(Module, Debug)]
+            pub struct Model<B: Backend> {
+                lstm: BiLstm<B>,
+                phantom: core::marker::PhantomData<B>,
+                device: burn::module::Ignored<B::Device>,
+            }
+
+            impl<B: Backend> Model <B> {
+                #[allow(unused_variables)]
+                pub fn new(device: &B::Device) -> Self {
+                    let lstm = BiLstmConfig::new(4, 8, true).init(device);
+
+                    Self {
+                        lstm,
+                        phantom: core::marker::PhantomData,
+                        device: burn::module::Ignored(device.clone()),
+                    }
+                }
+
+                #[allow(clippy::let_and_return, clippy::approx_constant)]
+                pub fn forward(&self, input: Tensor<B, 3>) -> (Tensor<B, 4>, Tensor<B, 3>) {
+                    let (output, output_cell) = {
+                        let (output, state) = self.lstm.forward(input, None);
+                        (
+                            {
+                                let [batch_size, seq_length, _] = output.dims();
+                                output.reshape([batch_size, seq_length, 2, 8])
+                            },
+                            state.cell.swap_dims(0, 1)
+                        )
+                    };
+
+                    (output, output_cell)
+                }
+            }
+        };
+
+        assert_tokens(graph.codegen(), expected);
+    }
+}
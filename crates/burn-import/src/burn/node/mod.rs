@@ -14,26 +14,43 @@ pub(crate) mod conv2d;
 pub(crate) mod conv3d;
 pub(crate) mod conv_transpose_2d;
 pub(crate) mod conv_transpose_3d;
+pub(crate) mod cumsum;
 pub(crate) mod dropout;
 pub(crate) mod expand;
 pub(crate) mod gather;
 pub(crate) mod gather_elements;
 pub(crate) mod global_avg_pool;
+pub(crate) mod group_norm;
+pub(crate) mod gru;
+pub(crate) mod if_node;
+pub(crate) mod instance_norm;
 pub(crate) mod layer_norm;
 pub(crate) mod linear;
+pub(crate) mod loop_node;
+pub(crate) mod lstm;
 pub(crate) mod mask_where;
 pub(crate) mod matmul;
 pub(crate) mod max_pool1d;
 pub(crate) mod max_pool2d;
+pub(crate) mod nonzero;
+pub(crate) mod one_hot;
+pub(crate) mod pad;
 pub(crate) mod prelu;
 pub(crate) mod random_normal;
 pub(crate) mod random_uniform;
 pub(crate) mod range;
 pub(crate) mod reshape;
 pub(crate) mod resize;
+pub(crate) mod rnn;
+pub(crate) mod scan;
 pub(crate) mod slice;
+pub(crate) mod split;
 pub(crate) mod squeeze;
+pub(crate) mod subgraph;
 pub(crate) mod sum;
+pub(crate) mod tile;
+pub(crate) mod top_k;
+pub(crate) mod trilu;
 pub(crate) mod unary;
 pub(crate) mod unsqueeze;
 pub(crate) use base::*;
@@ -0,0 +1,165 @@
+use super::{rnn::bind, Node, NodeCodegen};
+use crate::burn::{BurnImports, Scope, TensorKind, TensorType, ToTokens, Type};
+use burn::record::PrecisionSettings;
+use proc_macro2::TokenStream;
+use quote::quote;
+
+#[derive(new, Debug, Clone)]
+pub struct OneHotConfig {
+    /// The number of classes.
+    pub depth: usize,
+    /// The values of the other classes and of the class of the index.
+    pub values: (f64, f64),
+    /// The axis of the classes in the output.
+    pub axis: usize,
+}
+
+#[derive(Debug, Clone, new)]
+pub struct OneHotNode {
+    pub indices: TensorType,
+    pub output: TensorType,
+    pub config: OneHotConfig,
+}
+
+impl<PS: PrecisionSettings> NodeCodegen<PS> for OneHotNode {
+    fn output_types(&self) -> Vec<Type> {
+        vec![Type::Tensor(self.output.clone())]
+    }
+
+    fn input_types(&self) -> Vec<Type> {
+        vec![Type::Tensor(self.indices.clone())]
+    }
+
+    fn forward(&self, scope: &mut Scope, node_position: usize) -> TokenStream {
+        let indices = scope.tensor_use_owned(&self.indices, node_position);
+        let output = &self.output.name;
+        let rank = self.output.dim.to_tokens();
+        let axis = self.config.axis.to_tokens();
+        let depth = self.config.depth.to_tokens();
+        let num_classes = (self.config.depth as i64).to_tokens();
+
+        // The classes are broadcast along all the dimensions but the axis
+        let mut classes_shape = vec![1; self.output.dim];
+        classes_shape[self.config.axis] = self.config.depth;
+        let classes_shape = classes_shape.to_tokens();
+
+        let (off, on) = self.config.values;
+        let (output_type, off, on) = match self.output.kind {
+            TensorKind::Float => (
+                quote! { Tensor::<B, #rank> },
+                quote! { #off },
+                quote! { #on },
+            ),
+            TensorKind::Int => {
+                let (off, on) = (off as i64, on as i64);
+                (
+                    quote! { Tensor::<B, #rank, Int> },
+                    quote! { #off },
+                    quote! { #on },
+                )
+            }
+            TensorKind::Bool => panic!("OneHot is not supported for bool outputs"),
+        };
+
+        let mut body = quote! {};
+        let indices = match self.indices.kind {
+            TensorKind::Int => indices,
+            _ => quote! { #indices.int() },
+        };
+        let indices = bind(&mut body, quote! { indices }, indices);
+
+        quote! {
+            let #output = {
+                #body
+                let device = #indices.device();
+                // Negative indices are counted from the last class
+                let indices = #indices
+                    .clone()
+                    .mask_where(#indices.clone().lower_elem(0), #indices.add_scalar(#num_classes));
+                let indices = indices.unsqueeze_dim::<#rank>(#axis).repeat(#axis, #depth);
+                let dims = indices.dims();
+                let classes = Tensor::<B, 1, Int>::arange(0..#num_classes, &device)
+                    .reshape(#classes_shape)
+                    .expand(dims);
+
+                #output_type::full(dims, #off, &device).mask_fill(indices.equal(classes), #on)
+            };
+        }
+    }
+
+    fn register_imports(&self, imports: &mut BurnImports) {
+        imports.register("burn::tensor::Int");
+    }
+
+    fn into_node(self) -> Node<PS> {
+        Node::OneHot(self)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use burn::record::FullPrecisionSettings;
+
+    use super::*;
+    use crate::burn::{graph::BurnGraph, node::test::assert_tokens, TensorType};
+
+    #[test]
+    fn test_codegen_nodes() {
+        let mut graph = BurnGraph::<FullPrecisionSettings>::default();
+
+        graph.register(OneHotNode::new(
+            TensorType::new_int("tensor1", 1),
+            TensorType::new_float("tensor2", 2),
+            OneHotConfig::new(3, (0.0, 1.0), 1),
+        ));
+
+        graph.register_input_output(vec!["tensor1".to_string()], vec!["tensor2".to_string()]);
+
+        let expected = quote! {
+            use burn::tensor::Int;
+            use burn::{
+                module::Module,
+                tensor::{backend::Backend, Tensor},
+            };
+
+            #[derive(Module, Debug)]
+            pub struct Model<B: Backend> {
+                phantom: core::marker::PhantomData<B>,
+                device: burn::module::Ignored<B::Device>,
+            }
+
+            impl<B: Backend> Model <B> {
+                #[allow(unused_variables)]
+                pub fn new(device: &B::Device) -> Self {
+                    Self {
+                        phantom: core::marker::PhantomData,
+                        device: burn::module::Ignored(device.clone()),
+                    }
+                }
+
+                #[allow(clippy::let_and_return, clippy::approx_constant)]
+                pub fn forward(&self, tensor1: Tensor<B, 1, Int>) -> Tensor<B, 2> {
+                    let tensor2 = {
+                        let indices = tensor1;
+                        let device = indices.device();
+                        // Negative indices are counted from the last class
+                        let indices = indices
+                            .clone()
+                            .mask_where(indices.clone().lower_elem(0), indices.add_scalar(3));
+                        let indices = indices.unsqueeze_dim::<2>(1).repeat(1, 3);
+                        let dims = indices.dims();
+                        let classes = Tensor::<B, 1, Int>::arange(0..3, &device)
+                            .reshape([1, 3])
+                            .expand(dims);
+
+                        Tensor::<B, 2>::full(dims, 0f64, &device).mask_fill(indices.equal(classes), 1f64)
+                    };
+
+                    tensor2
+                }
+            }
+        };
+
+        assert_tokens(graph.codegen(), expected);
+    }
+}
@@ -0,0 +1,123 @@
+use super::{Node, NodeCodegen};
+use crate::burn::{BurnImports, Scope, TensorKind, TensorType, ToTokens, Type};
+use burn::record::PrecisionSettings;
+use proc_macro2::TokenStream;
+use quote::quote;
+
+/// Constant padding of the last two dimensions.
+#[derive(new, Debug, Clone)]
+pub struct PadConfig {
+    /// The padding on the left, right, top and bottom, like [Tensor::pad](burn::tensor::Tensor::pad).
+    pub pads: (usize, usize, usize, usize),
+    /// The value of the padded elements.
+    pub value: f64,
+}
+
+#[derive(Debug, Clone, new)]
+pub struct PadNode {
+    pub input: TensorType,
+    pub output: TensorType,
+    pub config: PadConfig,
+}
+
+impl<PS: PrecisionSettings> NodeCodegen<PS> for PadNode {
+    fn output_types(&self) -> Vec<Type> {
+        vec![Type::Tensor(self.output.clone())]
+    }
+
+    fn input_types(&self) -> Vec<Type> {
+        vec![Type::Tensor(self.input.clone())]
+    }
+
+    fn forward(&self, scope: &mut Scope, node_position: usize) -> TokenStream {
+        let input = scope.tensor_use_owned(&self.input, node_position);
+        let output = &self.output.name;
+        let (left, right, top, bottom) = self.config.pads;
+        let (left, right, top, bottom) = (
+            left.to_tokens(),
+            right.to_tokens(),
+            top.to_tokens(),
+            bottom.to_tokens(),
+        );
+        let value = match self.input.kind {
+            TensorKind::Float => {
+                let value = self.config.value;
+                quote! { #value }
+            }
+            TensorKind::Int => {
+                let value = self.config.value as i64;
+                quote! { #value }
+            }
+            TensorKind::Bool => panic!("Pad is not supported for bool tensors"),
+        };
+
+        quote! {
+            let #output = #input.pad(
+                (#left, #right, #top, #bottom),
+                ElementConversion::from_elem(#value),
+            );
+        }
+    }
+
+    fn register_imports(&self, imports: &mut BurnImports) {
+        imports.register("burn::tensor::ElementConversion");
+    }
+
+    fn into_node(self) -> Node<PS> {
+        Node::Pad(self)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use burn::record::FullPrecisionSettings;
+
+    use super::*;
+    use crate::burn::{graph::BurnGraph, node::test::assert_tokens, TensorType};
+
+    #[test]
+    fn test_codegen_nodes() {
+        let mut graph = BurnGraph::<FullPrecisionSettings>::default();
+
+        graph.register(PadNode::new(
+            TensorType::new_float("tensor1", 4),
+            TensorType::new_float("tensor2", 4),
+            PadConfig::new((1, 2, 0, 3), -1.5),
+        ));
+
+        graph.register_input_output(vec!["tensor1".to_string()], vec!["tensor2".to_string()]);
+
+        let expected = quote! {
+            use burn::tensor::ElementConversion;
+            use burn::{
+                module::Module,
+                tensor::{backend::Backend, Tensor},
+            };
+
+            #[derive(Module, Debug)]
+            pub struct Model<B: Backend> {
+                phantom: core::marker::PhantomData<B>,
+                device: burn::module::Ignored<B::Device>,
+            }
+
+            impl<B: Backend> Model <B> {
+                #[allow(unused_variables)]
+                pub fn new(device: &B::Device) -> Self {
+                    Self {
+                        phantom: core::marker::PhantomData,
+                        device: burn::module::Ignored(device.clone()),
+                    }
+                }
+
+                #[allow(clippy::let_and_return, clippy::approx_constant)]
+                pub fn forward(&self, tensor1: Tensor<B, 4>) -> Tensor<B, 4> {
+                    let tensor2 = tensor1.pad((1, 2, 0, 3), ElementConversion::from_elem(-1.5f64));
+
+                    tensor2
+                }
+            }
+        };
+
+        assert_tokens(graph.codegen(), expected);
+    }
+}
@@ -1,10 +1,10 @@
 use super::{Node, NodeCodegen, SerializationBackend};
-use crate::burn::{BurnImports, OtherType, Scope, TensorType, Type};
+use crate::burn::{BurnImports, LazyTensorData, OtherType, Scope, TensorType, Type};
 use burn::{
     module::{ConstantRecord, Param, ParamId},
     nn::{PReluConfig, PReluRecord},
     record::{PrecisionSettings, Record},
-    tensor::{Tensor, TensorData},
+    tensor::Tensor,
 };
 use proc_macro2::TokenStream;
 use quote::quote;
@@ -15,7 +15,7 @@ pub struct PReluNode {
     pub field: OtherType,
     pub input: TensorType,
     pub output: TensorType,
-    pub alpha: TensorData,
+    pub alpha: LazyTensorData,
     pub config: PReluConfig,
 }
 
@@ -24,7 +24,7 @@ impl PReluNode {
         name: S,
         input: TensorType,
         output: TensorType,
-        alpha: TensorData,
+        alpha: LazyTensorData,
         config: PReluConfig,
     ) -> Self {
         Self {
@@ -68,7 +68,7 @@ impl<PS: PrecisionSettings> NodeCodegen<PS> for PReluNode {
         let record = PReluRecord::<SerializationBackend> {
             alpha: Param::initialized(
                 ParamId::new(),
-                Tensor::from_data(self.alpha.clone().convert::<PS::FloatElem>(), &device),
+                Tensor::from_data(self.alpha.read().convert::<PS::FloatElem>(), &device),
             ),
             alpha_value: ConstantRecord,
         };
@@ -100,7 +100,7 @@ impl<PS: PrecisionSettings> NodeCodegen<PS> for PReluNode {
 mod tests {
     use super::*;
     use crate::burn::{graph::BurnGraph, node::test::assert_tokens, TensorType};
-    use burn::record::FullPrecisionSettings;
+    use burn::{record::FullPrecisionSettings, tensor::TensorData};
 
     #[test]
     fn test_codegen() {
@@ -110,7 +110,7 @@ mod tests {
             "prelu",
             TensorType::new_float("input", 4),
             TensorType::new_float("output", 4),
-            TensorData::from([2f32]),
+            TensorData::from([2f32]).into(),
             PReluConfig::new(),
         ));
 
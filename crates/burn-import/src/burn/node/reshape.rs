@@ -1,5 +1,5 @@
 use super::{Node, NodeCodegen};
-use crate::burn::{Scope, TensorType, ToTokens, Type};
+use crate::burn::{Scope, ShapeType, TensorType, ToTokens, Type};
 use burn::record::PrecisionSettings;
 use proc_macro2::TokenStream;
 use quote::quote;
@@ -8,7 +8,16 @@ use quote::quote;
 pub struct ReshapeNode {
     pub input: TensorType,
     pub output: TensorType,
-    pub shape: Vec<i64>,
+    pub shape: ReshapeShape,
+}
+
+/// The target shape of a reshape, where `0` copies a dimension and `-1` infers it.
+#[derive(Debug, Clone)]
+pub enum ReshapeShape {
+    /// A shape known when generating the code.
+    Static(Vec<i64>),
+    /// A shape computed at runtime, e.g. from the shape of another tensor.
+    Runtime(ShapeType),
 }
 
 impl<PS: PrecisionSettings> NodeCodegen<PS> for ReshapeNode {
@@ -17,16 +26,27 @@ impl<PS: PrecisionSettings> NodeCodegen<PS> for ReshapeNode {
     }
 
     fn input_types(&self) -> Vec<Type> {
-        vec![Type::Tensor(self.input.clone())]
+        match &self.shape {
+            ReshapeShape::Static(_) => vec![Type::Tensor(self.input.clone())],
+            ReshapeShape::Runtime(shape) => {
+                vec![Type::Tensor(self.input.clone()), Type::Shape(shape.clone())]
+            }
+        }
     }
 
     fn forward(&self, scope: &mut Scope, node_position: usize) -> TokenStream {
         let input = scope.tensor_use_owned(&self.input, node_position);
         let output = &self.output.name;
-        let shape_values = &self.shape.to_tokens();
+        let shape = match &self.shape {
+            ReshapeShape::Static(shape) => shape.to_tokens(),
+            ReshapeShape::Runtime(shape) => {
+                let name = &shape.name;
+                quote! { #name }
+            }
+        };
 
         quote! {
-            let #output = #input.reshape(#shape_values);
+            let #output = #input.reshape(#shape);
         }
     }
 
@@ -43,7 +63,7 @@ mod tests {
     use crate::burn::{
         graph::BurnGraph,
         node::{reshape::ReshapeNode, test::assert_tokens},
-        TensorType,
+        ShapeType, TensorType,
     };
 
     #[test]
@@ -53,7 +73,7 @@ mod tests {
         graph.register(ReshapeNode::new(
             TensorType::new_float("tensor1", 4),
             TensorType::new_float("tensor2", 4),
-            [4, 4, 4, 4].into(),
+            ReshapeShape::Static([4, 4, 4, 4].into()),
         ));
 
         graph.register_input_output(vec!["tensor1".to_string()], vec!["tensor2".to_string()]);
@@ -89,4 +109,52 @@ mod tests {
 
         assert_tokens(graph.codegen(), expected);
     }
+
+    #[test]
+    fn test_codegen_runtime_shape() {
+        let mut graph = BurnGraph::<FullPrecisionSettings>::default();
+
+        graph.register(ReshapeNode::new(
+            TensorType::new_float("tensor1", 4),
+            TensorType::new_float("tensor2", 4),
+            ReshapeShape::Runtime(ShapeType::new("shape1", 4)),
+        ));
+
+        graph.register_input_output(
+            vec!["tensor1".to_string(), "shape1".to_string()],
+            vec!["tensor2".to_string()],
+        );
+
+        let expected = quote! {
+            use burn::{
+                module::Module,
+                tensor::{backend::Backend, Tensor},
+            };
+
+            #[derive(Module, Debug)]
+            pub struct Model<B: Backend> {
+                phantom: core::marker::PhantomData<B>,
+                device: burn::module::Ignored<B::Device>,
+            }
+
+            impl<B: Backend> Model <B> {
+                #[allow(unused_variables)]
+                pub fn new(device: &B::Device) -> Self {
+                    Self {
+                        phantom: core::marker::PhantomData,
+                        device: burn::module::Ignored(device.clone()),
+                    }
+                }
+                #[allow(clippy::let_and_return, clippy::approx_constant)]
+                pub fn forward(&self, tensor1: Tensor<B, 4>, shape1: [usize; 4]) -> Tensor<B, 4> {
+                    let shape1: [i64; 4] = shape1.map(|dim| dim as i64);
+                    let tensor2 = tensor1.reshape(shape1);
+
+                    tensor2
+                }
+            }
+        };
+
+        assert_tokens(graph.codegen(), expected);
+    }
 }
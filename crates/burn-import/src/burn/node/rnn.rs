@@ -0,0 +1,605 @@
+use super::{subgraph::tuple, Node, NodeCodegen, SerializationBackend};
+use crate::burn::{BurnImports, LazyTensorData, OtherType, Scope, TensorType, ToTokens, Type};
+use burn::{
+    module::{Param, ParamId},
+    nn::{GateControllerRecord, LinearRecord},
+    record::{PrecisionSettings, Record},
+    tensor::Tensor,
+};
+use proc_macro2::TokenStream;
+use quote::quote;
+use serde::Serialize;
+
+/// Direction in which the sequence is processed by a recurrent node.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum RnnDirection {
+    Forward,
+    Reverse,
+    Bidirectional,
+}
+
+/// Configuration of the recurrent nodes: [LSTM](super::lstm::LstmNode),
+/// [GRU](super::gru::GruNode) and [RNN](RnnNode).
+#[derive(new, Debug, Clone)]
+pub struct RecurrentConfig {
+    pub d_input: usize,
+    pub d_hidden: usize,
+    pub bias: bool,
+    pub direction: RnnDirection,
+    /// The sequences are `[batch_size, seq_length, features]` instead of the default ONNX layout,
+    /// `[seq_length, batch_size, features]`.
+    pub batch_first: bool,
+}
+
+impl RecurrentConfig {
+    pub fn num_directions(&self) -> usize {
+        match self.direction {
+            RnnDirection::Bidirectional => 2,
+            _ => 1,
+        }
+    }
+
+    /// Whether the given direction, in `0..num_directions`, processes the sequence from its end.
+    pub fn is_reversed(&self, direction: usize) -> bool {
+        match self.direction {
+            RnnDirection::Forward => false,
+            RnnDirection::Reverse => true,
+            RnnDirection::Bidirectional => direction == 1,
+        }
+    }
+
+    /// Generate the code converting the input sequence to `[batch_size, seq_length, d_input]`.
+    pub(crate) fn input(&self, input: TokenStream) -> TokenStream {
+        match self.batch_first {
+            true => input,
+            false => quote! { #input.swap_dims(0, 1) },
+        }
+    }
+
+    /// Generate the code getting the initial state `[batch_size, d_hidden]` of a direction.
+    ///
+    /// The state of each direction is stacked on the axis 0, or the axis 1 for batch first
+    /// sequences.
+    pub(crate) fn direction_state(&self, state: &TokenStream, direction: usize) -> TokenStream {
+        let axis = self.state_axis();
+
+        if self.num_directions() == 1 {
+            return quote! { #state.squeeze::<2>(#axis) };
+        }
+
+        let state = match direction + 1 < self.num_directions() {
+            true => quote! { #state.clone() },
+            false => quote! { #state },
+        };
+        let direction = direction.to_tokens();
+        quote! { #state.narrow(#axis, #direction, 1).squeeze::<2>(#axis) }
+    }
+
+    /// Generate the code stacking the output sequences `[batch_size, seq_length, d_hidden]` of
+    /// each direction, in the ONNX layout.
+    pub(crate) fn stack_output(&self, outputs: &[TokenStream]) -> TokenStream {
+        let output = match outputs {
+            [output] => quote! { #output.unsqueeze_dim::<4>(2) },
+            outputs => quote! { Tensor::stack::<4>([#(#outputs),*].to_vec(), 2) },
+        };
+
+        match self.batch_first {
+            true => output,
+            false => quote! { #output.permute([1, 2, 0, 3]) },
+        }
+    }
+
+    /// Generate the code stacking the last states `[batch_size, d_hidden]` of each direction, in
+    /// the ONNX layout.
+    pub(crate) fn stack_state(&self, states: &[TokenStream]) -> TokenStream {
+        let axis = self.state_axis();
+
+        match states {
+            [state] => quote! { #state.unsqueeze_dim::<3>(#axis) },
+            states => quote! { Tensor::stack::<3>([#(#states),*].to_vec(), #axis) },
+        }
+    }
+
+    fn state_axis(&self) -> TokenStream {
+        match self.batch_first {
+            true => 1usize.to_tokens(),
+            false => 0usize.to_tokens(),
+        }
+    }
+}
+
+/// Generate the code of the sequence processed by a direction, `[batch_size, seq_length, d_input]`.
+///
+/// The input is flipped for the reversed directions, so that the modules always process the
+/// sequence from its start.
+pub(crate) fn direction_sequence(
+    config: &RecurrentConfig,
+    input: &TokenStream,
+    direction: usize,
+) -> TokenStream {
+    let input = match direction + 1 < config.num_directions() {
+        true => quote! { #input.clone() },
+        false => quote! { #input },
+    };
+
+    match config.is_reversed(direction) {
+        true => quote! { #input.flip([1]) },
+        false => input,
+    }
+}
+
+/// Generate the code putting the output sequence of a direction back in the order of the input
+/// sequence.
+pub(crate) fn direction_output(
+    config: &RecurrentConfig,
+    output: TokenStream,
+    direction: usize,
+) -> TokenStream {
+    match config.is_reversed(direction) {
+        true => quote! { #output.flip([1]) },
+        false => output,
+    }
+}
+
+/// Generate the code binding a value to a variable of the block, and return the variable.
+///
+/// Nothing is generated when the value is already a variable of the same name.
+pub(crate) fn bind(body: &mut TokenStream, name: TokenStream, value: TokenStream) -> TokenStream {
+    if name.to_string() != value.to_string() {
+        body.extend(quote! { let #name = #value; });
+    }
+
+    name
+}
+
+/// Generate the code getting the last state `[batch_size, d_hidden]` of a direction from its
+/// output sequence, in the order of the input sequence.
+pub(crate) fn last_state(
+    config: &RecurrentConfig,
+    output: &TokenStream,
+    direction: usize,
+) -> TokenStream {
+    match config.is_reversed(direction) {
+        true => quote! { #output.clone().narrow(1, 0, 1).squeeze::<2>(1) },
+        false => quote! {
+            #output.clone().narrow(1, #output.dims()[1] - 1, 1).squeeze::<2>(1)
+        },
+    }
+}
+
+/// Weights of a [gate controller](burn::nn::GateController), with the layout of the Burn linear
+/// transforms.
+#[derive(Debug, Clone)]
+pub struct GateWeights {
+    /// The input transform weights, `[d_input, d_hidden]`.
+    pub input: LazyTensorData,
+    pub input_bias: Option<LazyTensorData>,
+    /// The hidden transform weights, `[d_hidden, d_hidden]`.
+    pub hidden: LazyTensorData,
+    pub hidden_bias: Option<LazyTensorData>,
+}
+
+impl GateWeights {
+    /// Split the packed ONNX weights into the weights of each gate, for each direction.
+    ///
+    /// ONNX packs the gates along the second axis of the input weights `W`
+    /// `[num_directions, num_gates * d_hidden, d_input]` and of the hidden weights `R`
+    /// `[num_directions, num_gates * d_hidden, d_hidden]`. The biases `B`
+    /// `[num_directions, 2 * num_gates * d_hidden]` have the input biases of all gates followed
+    /// by their hidden biases. The gates are returned in the ONNX order.
+    ///
+    /// The weights of each gate are only split when they're read.
+    pub fn from_onnx(
+        input: LazyTensorData,
+        hidden: LazyTensorData,
+        bias: Option<LazyTensorData>,
+        num_gates: usize,
+    ) -> Vec<Vec<Self>> {
+        let [num_directions, d_gates, _] = input.shape()[..] else {
+            panic!("The input weights must be a rank 3 tensor");
+        };
+        let d_hidden = d_gates / num_gates;
+        if let Some(bias) = &bias {
+            if bias.shape().len() != 2 {
+                panic!("The bias must be a rank 2 tensor");
+            }
+        }
+
+        // The ONNX weights are used as `x W^T`, when Burn linear weights are used as `x W`
+        let gate_weights = |weights: &LazyTensorData, direction: usize, gate: usize| {
+            let d_input = weights.shape()[2];
+            weights.clone().map(vec![d_input, d_hidden], move |data| {
+                Tensor::<SerializationBackend, 3>::from_data(
+                    data.convert::<f32>(),
+                    &Default::default(),
+                )
+                .narrow(0, direction, 1)
+                .narrow(1, gate * d_hidden, d_hidden)
+                .squeeze::<2>(0)
+                .transpose()
+                .into_data()
+            })
+        };
+        let gate_bias = |bias: &LazyTensorData, direction: usize, index: usize| {
+            bias.clone().map(vec![d_hidden], move |data| {
+                Tensor::<SerializationBackend, 2>::from_data(
+                    data.convert::<f32>(),
+                    &Default::default(),
+                )
+                .reshape([num_directions, 2 * num_gates, d_hidden])
+                .narrow(0, direction, 1)
+                .narrow(1, index, 1)
+                .flatten::<1>(0, 2)
+                .into_data()
+            })
+        };
+
+        (0..num_directions)
+            .map(|direction| {
+                (0..num_gates)
+                    .map(|gate| GateWeights {
+                        input: gate_weights(&input, direction, gate),
+                        input_bias: bias.as_ref().map(|bias| gate_bias(bias, direction, gate)),
+                        hidden: gate_weights(&hidden, direction, gate),
+                        hidden_bias: bias
+                            .as_ref()
+                            .map(|bias| gate_bias(bias, direction, num_gates + gate)),
+                    })
+                    .collect()
+            })
+            .collect()
+    }
+
+    pub fn into_record<PS: PrecisionSettings>(self) -> GateControllerRecord<SerializationBackend> {
+        fn param<const D: usize, PS: PrecisionSettings>(
+            data: LazyTensorData,
+        ) -> Param<Tensor<SerializationBackend, D>> {
+            Param::initialized(
+                ParamId::new(),
+                Tensor::from_data(data.read().convert::<PS::FloatElem>(), &Default::default()),
+            )
+        }
+
+        GateControllerRecord {
+            input_transform: LinearRecord {
+                weight: param::<2, PS>(self.input),
+                bias: self.input_bias.map(param::<1, PS>),
+            },
+            hidden_transform: LinearRecord {
+                weight: param::<2, PS>(self.hidden),
+                bias: self.hidden_bias.map(param::<1, PS>),
+            },
+        }
+    }
+}
+
+/// Activation function of the [RNN](RnnNode) hidden state.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum RnnActivation {
+    Tanh,
+    Relu,
+    Sigmoid,
+}
+
+/// Node of the ONNX RNN operator, a simple recurrent network computing the hidden state at each
+/// step as `activation(x W + h R + b)`.
+///
+/// Burn doesn't have a RNN module, so a [gate controller](burn::nn::GateController) holds the
+/// weights of each direction, and the steps are generated in the forward pass.
+#[derive(Debug, Clone)]
+pub struct RnnNode {
+    pub field: OtherType,
+    pub input: TensorType,
+    pub initial_hidden: Option<TensorType>,
+    /// The hidden states of each step, `[seq_length, num_directions, batch_size, d_hidden]`.
+    pub output: Option<TensorType>,
+    /// The last hidden state, `[num_directions, batch_size, d_hidden]`.
+    pub output_hidden: Option<TensorType>,
+    /// The weights of each direction.
+    pub gates: Vec<GateWeights>,
+    pub config: RecurrentConfig,
+    pub activation: RnnActivation,
+}
+
+impl RnnNode {
+    #[allow(clippy::too_many_arguments)]
+    pub fn new<S: AsRef<str>>(
+        name: S,
+        input: TensorType,
+        initial_hidden: Option<TensorType>,
+        output: Option<TensorType>,
+        output_hidden: Option<TensorType>,
+        gates: Vec<GateWeights>,
+        config: RecurrentConfig,
+        activation: RnnActivation,
+    ) -> Self {
+        let ty = match config.direction {
+            RnnDirection::Bidirectional => quote! { (GateController<B>, GateController<B>) },
+            _ => quote! { GateController<B> },
+        };
+
+        Self {
+            field: OtherType::new(name, ty),
+            input,
+            initial_hidden,
+            output,
+            output_hidden,
+            gates,
+            config,
+            activation,
+        }
+    }
+}
+
+impl<PS: PrecisionSettings> NodeCodegen<PS> for RnnNode {
+    fn input_types(&self) -> Vec<Type> {
+        [Some(&self.input), self.initial_hidden.as_ref()]
+            .into_iter()
+            .flatten()
+            .map(|tensor| Type::Tensor(tensor.clone()))
+            .collect()
+    }
+
+    fn output_types(&self) -> Vec<Type> {
+        [self.output.as_ref(), self.output_hidden.as_ref()]
+            .into_iter()
+            .flatten()
+            .map(|tensor| Type::Tensor(tensor.clone()))
+            .collect()
+    }
+
+    fn field_type(&self) -> Option<Type> {
+        Some(Type::Other(self.field.clone()))
+    }
+
+    fn field_init(&self) -> Option<TokenStream> {
+        let name = &self.field.name;
+        let d_input = self.config.d_input.to_tokens();
+        let d_hidden = self.config.d_hidden.to_tokens();
+        let bias = self.config.bias;
+        let gate = quote! {
+            GateController::new(
+                #d_input,
+                #d_hidden,
+                #bias,
+                Initializer::XavierNormal { gain: 1.0 },
+                device,
+            )
+        };
+
+        let tokens = match self.config.direction {
+            RnnDirection::Bidirectional => quote! {
+                let #name = (#gate, #gate);
+            },
+            _ => quote! {
+                let #name = #gate;
+            },
+        };
+
+        Some(tokens)
+    }
+
+    fn field_serialize<S: serde::Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        let mut records = self
+            .gates
+            .iter()
+            .cloned()
+            .map(GateWeights::into_record::<PS>)
+            .collect::<Vec<_>>();
+
+        match self.config.direction {
+            RnnDirection::Bidirectional => {
+                let reverse = records.pop().unwrap();
+                let forward = records.pop().unwrap();
+                Record::into_item::<PS>((forward, reverse)).serialize(serializer)
+            }
+            _ => Record::into_item::<PS>(records.remove(0)).serialize(serializer),
+        }
+    }
+
+    fn forward(&self, scope: &mut Scope, node_position: usize) -> TokenStream {
+        let field = &self.field.name;
+        let input = scope.tensor_use_owned(&self.input, node_position);
+        let input = self.config.input(input);
+        let initial_hidden = self
+            .initial_hidden
+            .as_ref()
+            .map(|state| scope.tensor_use_owned(state, node_position));
+        let d_hidden = self.config.d_hidden.to_tokens();
+
+        let mut body = quote! {};
+        let input = bind(&mut body, quote! { input }, input);
+        let initial_hidden =
+            initial_hidden.map(|state| bind(&mut body, quote! { initial_hidden }, state));
+
+        let mut outputs = Vec::new();
+        let mut states = Vec::new();
+        for direction in 0..self.config.num_directions() {
+            let sequence = direction_sequence(&self.config, &input, direction);
+            let output = direction_output(
+                &self.config,
+                quote! { Tensor::stack::<3>(output, 1) },
+                direction,
+            );
+            let gate = match self.config.direction {
+                RnnDirection::Bidirectional => {
+                    let direction = syn::Index::from(direction);
+                    quote! { self.#field.#direction }
+                }
+                _ => quote! { self.#field },
+            };
+            let hidden = match &initial_hidden {
+                Some(initial_hidden) => self.config.direction_state(initial_hidden, direction),
+                None => quote! {
+                    Tensor::zeros([#input.dims()[0], #d_hidden], &#input.device())
+                },
+            };
+            let activation = match self.activation {
+                RnnActivation::Tanh => quote! { product.tanh() },
+                RnnActivation::Relu => quote! { burn::tensor::activation::relu(product) },
+                RnnActivation::Sigmoid => quote! { burn::tensor::activation::sigmoid(product) },
+            };
+
+            let name = quote::format_ident!("output_{}", direction);
+            body.extend(quote! {
+                let #name = {
+                    let mut hidden = #hidden;
+                    let mut output = [].to_vec();
+                    for input_t in #sequence.iter_dim(1) {
+                        let product = #gate.gate_product(input_t.squeeze(1), hidden);
+                        hidden = #activation;
+                        output.push(hidden.clone());
+                    }
+                    #output
+                };
+            });
+
+            let name = quote! { #name };
+            states.push(last_state(&self.config, &name, direction));
+            outputs.push(name);
+        }
+
+        let mut results = Vec::new();
+        if self.output_hidden.is_some() {
+            let state = self.config.stack_state(&states);
+            body.extend(quote! { let output_hidden = #state; });
+        }
+        if self.output.is_some() {
+            results.push(self.config.stack_output(&outputs));
+        }
+        if self.output_hidden.is_some() {
+            results.push(quote! { output_hidden });
+        }
+
+        let output_names = NodeCodegen::<PS>::output_types(self)
+            .into_iter()
+            .map(|output| {
+                let name = output.name();
+                quote! { #name }
+            })
+            .collect::<Vec<_>>();
+        let output_names = tuple(&output_names);
+        let results = tuple(&results);
+
+        quote! {
+            let #output_names = {
+                #body
+                #results
+            };
+        }
+    }
+
+    fn register_imports(&self, imports: &mut BurnImports) {
+        imports.register("burn::nn::GateController");
+        imports.register("burn::nn::Initializer");
+    }
+
+    fn into_node(self) -> Node<PS> {
+        Node::Rnn(self)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::burn::{graph::BurnGraph, node::test::assert_tokens, TensorType};
+    use burn::{record::FullPrecisionSettings, tensor::TensorData};
+
+    #[test]
+    fn test_codegen_bidirectional() {
+        let mut graph = BurnGraph::<FullPrecisionSettings>::default();
+        let gates = GateWeights::from_onnx(
+            TensorData::zeros::<f32, _>([2, 8, 4]).into(),
+            TensorData::zeros::<f32, _>([2, 8, 8]).into(),
+            Some(TensorData::zeros::<f32, _>([2, 16]).into()),
+            1,
+        );
+
+        graph.register(RnnNode::new(
+            "rnn",
+            TensorType::new_float("input", 3),
+            None,
+            None,
+            Some(TensorType::new_float("output_hidden", 3)),
+            gates.into_iter().map(|mut gates| gates.remove(0)).collect(),
+            RecurrentConfig::new(4, 8, true, RnnDirection::Bidirectional, true),
+            RnnActivation::Relu,
+        ));
+
+        graph.register_input_output(vec!["input".to_string()], vec!["output_hidden".to_string()]);
+
+        let expected = quote! {
+            use burn::nn::GateController;
+            use burn::nn::Initializer;
+            use burn::{
+                module::Module,
+                tensor::{backend::Backend, Tensor},
+            };
+
+            #[derive(Module, Debug)]
+            pub struct Model<B: Backend> {
+                rnn: (GateController<B>, GateController<B>),
+                phantom: core::marker::PhantomData<B>,
+                device: burn::module::Ignored<B::Device>,
+            }
+
+            impl<B: Backend> Model <B> {
+                #[allow(unused_variables)]
+                pub fn new(device: &B::Device) -> Self {
+                    let rnn = (
+                        GateController::new(4, 8, true, Initializer::XavierNormal { gain: 1.0 }, device),
+                        GateController::new(4, 8, true, Initializer::XavierNormal { gain: 1.0 }, device),
+                    );
+
+                    Self {
+                        rnn,
+                        phantom: core::marker::PhantomData,
+                        device: burn::module::Ignored(device.clone()),
+                    }
+                }
+
+                #[allow(clippy::let_and_return, clippy::approx_constant)]
+                pub fn forward(&self, input: Tensor<B, 3>) -> Tensor<B, 3> {
+                    let output_hidden = {
+                        let output_0 = {
+                            let mut hidden = Tensor::zeros([input.dims()[0], 8], &input.device());
+                            let mut output = [].to_vec();
+                            for input_t in input.clone().iter_dim(1) {
+                                let product = self.rnn.0.gate_product(input_t.squeeze(1), hidden);
+                                hidden = burn::tensor::activation::relu(product);
+                                output.push(hidden.clone());
+                            }
+                            Tensor::stack::<3>(output, 1)
+                        };
+                        let output_1 = {
+                            let mut hidden = Tensor::zeros([input.dims()[0], 8], &input.device());
+                            let mut output = [].to_vec();
+                            for input_t in input.flip([1]).iter_dim(1) {
+                                let product = self.rnn.1.gate_product(input_t.squeeze(1), hidden);
+                                hidden = burn::tensor::activation::relu(product);
+                                output.push(hidden.clone());
+                            }
+                            Tensor::stack::<3>(output, 1).flip([1])
+                        };
+                        let output_hidden = Tensor::stack::<3>(
+                            [
+                                output_0
+                                    .clone()
+                                    .narrow(1, output_0.dims()[1] - 1, 1)
+                                    .squeeze::<2>(1),
+                                output_1.clone().narrow(1, 0, 1).squeeze::<2>(1),
+                            ]
+                            .to_vec(),
+                            1,
+                        );
+                        output_hidden
+                    };
+
+                    output_hidden
+                }
+            }
+        };
+
+        assert_tokens(graph.codegen(), expected);
+    }
+}
@@ -0,0 +1,307 @@
+use super::{
+    subgraph::{register_scan_output_imports, scan_output, tuple, SubGraph},
+    Node, NodeCodegen,
+};
+use crate::burn::{BurnImports, Scope, TensorType, ToTokens, Type};
+
+use burn::record::PrecisionSettings;
+use proc_macro2::TokenStream;
+use quote::quote;
+
+/// Axes and directions of the scan inputs and outputs.
+#[derive(new, Debug, Clone)]
+pub struct ScanConfig {
+    pub input_axes: Vec<usize>,
+    /// True when the scan input is iterated from its last element.
+    pub input_reversed: Vec<bool>,
+    pub output_axes: Vec<usize>,
+    /// True when the values of the scan output are stacked from the last iteration.
+    pub output_reversed: Vec<bool>,
+}
+
+/// Node executing a sub-graph for each element of the scan inputs.
+///
+/// The body inputs are the state variables and one element of each scan input. The body outputs
+/// are the state variables and the scan outputs, whose values of each iteration are stacked along
+/// their axis.
+#[derive(Debug, Clone, new)]
+pub struct ScanNode<PS: PrecisionSettings> {
+    /// The initial values of the state variables.
+    pub initial_states: Vec<Type>,
+    pub scan_inputs: Vec<TensorType>,
+    pub body: SubGraph<PS>,
+    /// The variables of the enclosing scope used by the body.
+    pub captured: Vec<Type>,
+    pub outputs: Vec<Type>,
+    pub config: ScanConfig,
+}
+
+impl<PS: PrecisionSettings> NodeCodegen<PS> for ScanNode<PS> {
+    fn output_types(&self) -> Vec<Type> {
+        self.outputs.clone()
+    }
+
+    fn input_types(&self) -> Vec<Type> {
+        self.initial_states
+            .iter()
+            .cloned()
+            .chain(self.scan_inputs.iter().cloned().map(Type::Tensor))
+            .chain(self.captured.iter().cloned())
+            .collect()
+    }
+
+    fn forward(&self, scope: &mut Scope, node_position: usize) -> TokenStream {
+        let num_states = self.initial_states.len();
+        let mut declarations = quote! {};
+        let mut scan_declarations = quote! {};
+
+        let initial_states = self
+            .initial_states
+            .iter()
+            .map(|ty| match ty {
+                Type::Tensor(tensor) => scope.tensor_use_owned(tensor, node_position),
+                _ => {
+                    let name = ty.name();
+                    quote! { #name }
+                }
+            })
+            .collect::<Vec<_>>();
+
+        // The elements of the scan inputs are sliced at each iteration
+        let length = {
+            let input = &self.scan_inputs[0].name;
+            let axis = self.config.input_axes[0];
+            quote! { #input.dims()[#axis] }
+        };
+        let mut elements = quote! {};
+        for (i, input) in self.scan_inputs.iter().enumerate() {
+            let element = &self.body.inputs[num_states + i];
+            let tensor = scope.tensor_use_in_loop(input, node_position);
+            if !self.body.uses(element.name()) {
+                continue;
+            }
+
+            let name = element.name();
+            let axis = self.config.input_axes[i];
+            let index = match self.config.input_reversed[i] {
+                true => quote! { #length - 1 - iteration },
+                false => quote! { iteration },
+            };
+            let slice = quote! { #tensor.narrow(#axis, #index, 1) };
+            let element = match element {
+                Type::Tensor(element) => {
+                    let dim = element.dim.to_tokens();
+                    quote! { #slice.squeeze::<#dim>(#axis) }
+                }
+                Type::Scalar(element) => {
+                    let ty = element.ty();
+                    quote! { #slice.into_scalar().elem::<#ty>() }
+                }
+                _ => panic!("Scan input elements must be scalars or tensors"),
+            };
+            elements.extend(quote! { let #name = #element; });
+        }
+
+        let mut captured = quote! {};
+        for ty in self.captured.iter() {
+            if let Type::Tensor(tensor) = ty {
+                let name = &tensor.name;
+                let tensor = scope.tensor_use_in_loop(tensor, node_position);
+                captured.extend(quote! { let #name = #tensor; });
+            }
+        }
+
+        let (body, mut body_scope) = self.body.forward(&self.captured);
+
+        // The scan outputs are pushed before the states are updated, since they can be the states
+        // of the current iteration.
+        let mut scan_pushes = quote! {};
+        let mut scan_results = Vec::new();
+        for (i, output) in self.outputs[num_states..].iter().enumerate() {
+            let index = num_states + i;
+            let name = output.name();
+            let value = self.body.output(&mut body_scope, index);
+            let (declaration, push, result) = scan_output(
+                name,
+                &self.body.outputs[index],
+                value,
+                self.config.output_axes[i],
+            );
+
+            scan_declarations.extend(declaration);
+            scan_pushes.extend(push);
+            if self.config.output_reversed[i] {
+                scan_results.push(quote! {{
+                    #name.reverse();
+                    #result
+                }});
+            } else {
+                scan_results.push(result);
+            }
+        }
+
+        let states = &self.body.inputs[..num_states];
+        let mut targets = Vec::new();
+        let mut updates = Vec::new();
+        for (index, (state, initial)) in states.iter().zip(initial_states).enumerate() {
+            let name = state.name();
+            let value = self.body.output(&mut body_scope, index);
+
+            // A state passed through the iterations is never updated
+            if self.body.outputs[index].name() == name {
+                declarations.extend(quote! { let #name = #initial; });
+                continue;
+            }
+
+            declarations.extend(quote! { let mut #name = #initial; });
+            targets.push(quote! { #name });
+            updates.push(value);
+        }
+        let updates = match updates.is_empty() {
+            true => quote! {},
+            false => {
+                let targets = tuple(&targets);
+                let updates = tuple(&updates);
+                quote! { #targets = #updates; }
+            }
+        };
+
+        let results = tuple(
+            &states
+                .iter()
+                .map(|state| {
+                    let name = state.name();
+                    quote! { #name }
+                })
+                .chain(scan_results)
+                .collect::<Vec<_>>(),
+        );
+        let outputs = tuple(
+            &self
+                .outputs
+                .iter()
+                .map(|output| {
+                    let name = output.name();
+                    quote! { #name }
+                })
+                .collect::<Vec<_>>(),
+        );
+
+        quote! {
+            let #outputs = {
+                #declarations
+                #scan_declarations
+                for iteration in 0..#length {
+                    #elements
+                    #captured
+                    #body
+                    #scan_pushes
+                    #updates
+                }
+                #results
+            };
+        }
+    }
+
+    fn register_imports(&self, imports: &mut BurnImports) {
+        let num_states = self.initial_states.len();
+        if self.body.inputs[num_states..]
+            .iter()
+            .any(|input| matches!(input, Type::Scalar(_)))
+        {
+            imports.register("burn::tensor::ElementConversion");
+        }
+        register_scan_output_imports(&self.body.outputs[num_states..], imports);
+    }
+
+    fn into_node(self) -> Node<PS> {
+        Node::Scan(self)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use burn::record::FullPrecisionSettings;
+
+    use super::*;
+    use crate::burn::{
+        graph::BurnGraph,
+        node::{binary::BinaryNode, test::assert_tokens},
+    };
+
+    #[test]
+    fn test_codegen_scan() {
+        let mut graph = BurnGraph::<FullPrecisionSettings>::default();
+        let state = Type::Tensor(TensorType::new_float("state", 1));
+        let element = Type::Tensor(TensorType::new_float("element", 1));
+        let next = Type::Tensor(TensorType::new_float("tensor3", 1));
+
+        let body = SubGraph::new(
+            vec![BinaryNode::add(state.clone(), element.clone(), next.clone()).into_node()],
+            vec![state, element],
+            vec![next.clone(), next],
+        );
+
+        graph.register(ScanNode::new(
+            vec![Type::Tensor(TensorType::new_float("tensor1", 1))],
+            vec![TensorType::new_float("tensor2", 2)],
+            body,
+            vec![],
+            vec![
+                Type::Tensor(TensorType::new_float("tensor4", 1)),
+                Type::Tensor(TensorType::new_float("tensor5", 2)),
+            ],
+            ScanConfig::new(vec![0], vec![true], vec![0], vec![false]),
+        ));
+
+        graph.register_input_output(
+            vec!["tensor1".to_string(), "tensor2".to_string()],
+            vec!["tensor4".to_string(), "tensor5".to_string()],
+        );
+
+        let expected = quote! {
+            use burn::{
+                module::Module,
+                tensor::{backend::Backend, Tensor},
+            };
+
+            #[derive(Module, Debug)]
+            pub struct Model<B: Backend> {
+                phantom: core::marker::PhantomData<B>,
+                device: burn::module::Ignored<B::Device>,
+            }
+
+            impl<B: Backend> Model <B> {
+                #[allow(unused_variables)]
+                pub fn new(device: &B::Device) -> Self {
+                    Self {
+                        phantom: core::marker::PhantomData,
+                        device: burn::module::Ignored(device.clone()),
+                    }
+                }
+
+                #[allow(clippy::let_and_return, clippy::approx_constant)]
+                pub fn forward(&self, tensor1: Tensor<B, 1>, tensor2: Tensor<B, 2>) -> (Tensor<B, 1>, Tensor<B, 2>) {
+                    let (tensor4, tensor5) = {
+                        let mut state = tensor1;
+                        let mut tensor5 = [].to_vec();
+                        for iteration in 0..tensor2.dims()[0usize] {
+                            let element = tensor2
+                                .clone()
+                                .narrow(0usize, tensor2.dims()[0usize] - 1 - iteration, 1)
+                                .squeeze::<1>(0usize);
+                            let tensor3 = state.add(element);
+                            tensor5.push(tensor3.clone());
+                            state = tensor3;
+                        }
+                        (state, Tensor::stack::<2>(tensor5, 0usize))
+                    };
+
+                    (tensor4, tensor5)
+                }
+            }
+        };
+
+        assert_tokens(graph.codegen(), expected);
+    }
+}
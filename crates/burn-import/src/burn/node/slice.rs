@@ -1,39 +1,103 @@
 use super::{Node, NodeCodegen};
-use crate::burn::{Scope, TensorType, ToTokens, Type};
+use crate::burn::{Scope, ShapeType, ToTokens, Type};
 use burn::record::PrecisionSettings;
 use proc_macro2::TokenStream;
 use quote::quote;
 
 #[derive(Debug, Clone, new)]
 pub struct SliceNode {
-    pub input: TensorType,
-    pub output: TensorType,
-    pub ranges: Vec<Option<(i64, i64)>>,
+    pub input: Type,
+    pub output: Type,
+    pub ranges: Vec<Option<(SliceBound, SliceBound)>>,
+}
+
+/// The start or end of the range of a sliced dimension.
+#[derive(Debug, Clone)]
+pub enum SliceBound {
+    /// A bound known when generating the code.
+    Static(i64),
+    /// A bound read from an element of a runtime shape.
+    Runtime(ShapeType, usize),
+}
+
+impl ToTokens for SliceBound {
+    fn to_tokens(&self) -> TokenStream {
+        match self {
+            SliceBound::Static(bound) => bound.to_tokens(),
+            SliceBound::Runtime(shape, index) => {
+                let name = &shape.name;
+                let index = index.to_tokens();
+                quote! { #name[#index] }
+            }
+        }
+    }
 }
 
 impl<PS: PrecisionSettings> NodeCodegen<PS> for SliceNode {
     fn output_types(&self) -> Vec<Type> {
-        vec![Type::Tensor(self.output.clone())]
+        vec![self.output.clone()]
     }
     fn input_types(&self) -> Vec<Type> {
-        vec![Type::Tensor(self.input.clone())]
+        let bounds = self
+            .ranges
+            .iter()
+            .flatten()
+            .flat_map(|(start, end)| [start, end])
+            .filter_map(|bound| match bound {
+                SliceBound::Runtime(shape, _) => Some(Type::Shape(shape.clone())),
+                SliceBound::Static(_) => None,
+            });
+
+        let mut inputs = vec![self.input.clone()];
+        for bound in bounds {
+            if !inputs.iter().any(|input| input.name() == bound.name()) {
+                inputs.push(bound);
+            }
+        }
+        inputs
     }
     fn forward(&self, scope: &mut Scope, node_position: usize) -> TokenStream {
-        let input = scope.tensor_use_owned(&self.input, node_position);
-        let output = &self.output.name;
+        let output = self.output.name();
 
-        let ranges = self.ranges.iter().map(|range| match range {
-            Some((start, end)) => {
-                let start = start.to_tokens();
-                let end = end.to_tokens();
+        match &self.input {
+            Type::Tensor(tensor) => {
+                let input = scope.tensor_use_owned(tensor, node_position);
+                let ranges = self.ranges.iter().map(|range| match range {
+                    Some((start, end)) => {
+                        let start = start.to_tokens();
+                        let end = end.to_tokens();
 
-                quote! { Some((#start, #end))}
+                        quote! { Some((#start, #end))}
+                    }
+                    None => quote! { None },
+                });
+
+                quote! {
+                    let #output = #input.slice([#(#ranges),*]);
+                }
             }
-            None => quote! { None },
-        });
+            // A runtime shape is sliced with static bounds, so that its length is known
+            Type::Shape(shape) => {
+                let input = &shape.name;
+                let len = shape.dim as i64;
+                let clamp = |bound: &SliceBound| match bound {
+                    SliceBound::Static(bound) if *bound < 0 => (bound + len).clamp(0, len),
+                    SliceBound::Static(bound) => (*bound).clamp(0, len),
+                    SliceBound::Runtime(..) => panic!("Slice: a shape must be sliced statically"),
+                };
+                let (start, end) = match &self.ranges[0] {
+                    Some((start, end)) => (clamp(start), clamp(end).max(clamp(start))),
+                    None => (0, len),
+                };
+                let dim = (end - start).to_tokens();
+                let start = (start as usize).to_tokens();
+                let end = (end as usize).to_tokens();
 
-        quote! {
-            let #output = #input.slice([#(#ranges),*]);
+                quote! {
+                    let #output: [i64; #dim] = #input[#start..#end].try_into().unwrap();
+                }
+            }
+            _ => panic!("Slice: unsupported input type {:?}", self.input),
         }
     }
     fn into_node(self) -> Node<PS> {
@@ -49,16 +113,16 @@ mod tests {
     use crate::burn::{
         graph::BurnGraph,
         node::{slice::SliceNode, test::assert_tokens},
-        TensorType,
+        ShapeType, TensorType,
     };
 
     #[test]
     fn test_codegen_slice() {
         let mut graph = BurnGraph::<FullPrecisionSettings>::default();
         graph.register(SliceNode::new(
-            TensorType::new_float("tensor1", 4),
-            TensorType::new_float("tensor2", 4),
-            vec![Some((0, 1)), Some((0, 1)), Some((0, 1)), Some((0, 1))],
+            Type::Tensor(TensorType::new_float("tensor1", 4)),
+            Type::Tensor(TensorType::new_float("tensor2", 4)),
+            vec![Some((SliceBound::Static(0), SliceBound::Static(1))); 4],
         ));
         graph.register_input_output(vec!["tensor1".to_string()], vec!["tensor2".to_string()]);
 
@@ -92,4 +156,97 @@ mod tests {
 
         assert_tokens(graph.codegen(), expected);
     }
+
+    #[test]
+    fn test_codegen_slice_runtime_bound() {
+        let mut graph = BurnGraph::<FullPrecisionSettings>::default();
+        graph.register(SliceNode::new(
+            Type::Tensor(TensorType::new_float("tensor1", 2)),
+            Type::Tensor(TensorType::new_float("tensor2", 2)),
+            vec![
+                None,
+                Some((
+                    SliceBound::Static(0),
+                    SliceBound::Runtime(ShapeType::new("shape1", 2), 1),
+                )),
+            ],
+        ));
+        graph.register_input_output(
+            vec!["tensor1".to_string(), "shape1".to_string()],
+            vec!["tensor2".to_string()],
+        );
+
+        let expected = quote! {
+            use burn::{
+                module::Module,
+                tensor::{backend::Backend, Tensor},
+            };
+
+            #[derive(Module, Debug)]
+            pub struct Model<B: Backend> {
+                phantom: core::marker::PhantomData<B>,
+                device: burn::module::Ignored<B::Device>,
+            }
+
+            impl<B: Backend> Model <B> {
+                #[allow(unused_variables)]
+                pub fn new(device: &B::Device) -> Self {
+                    Self {
+                        phantom: core::marker::PhantomData,
+                        device: burn::module::Ignored(device.clone()),
+                    }
+                }
+                #[allow(clippy::let_and_return, clippy::approx_constant)]
+                pub fn forward(&self, tensor1: Tensor<B, 2>, shape1: [usize; 2]) -> Tensor<B, 2> {
+                    let shape1: [i64; 2] = shape1.map(|dim| dim as i64);
+                    let tensor2 = tensor1.slice([None, Some((0, shape1[1]))]);
+                    tensor2
+                }
+            }
+        };
+
+        assert_tokens(graph.codegen(), expected);
+    }
+
+    #[test]
+    fn test_codegen_slice_shape() {
+        let mut graph = BurnGraph::<FullPrecisionSettings>::default();
+        graph.register(SliceNode::new(
+            Type::Shape(ShapeType::new("shape1", 4)),
+            Type::Shape(ShapeType::new("shape2", 2)),
+            vec![Some((SliceBound::Static(-2), SliceBound::Static(i64::MAX)))],
+        ));
+        graph.register_input_output(vec!["shape1".to_string()], vec!["shape2".to_string()]);
+
+        let expected = quote! {
+            use burn::{
+                module::Module,
+                tensor::{backend::Backend, Tensor},
+            };
+
+            #[derive(Module, Debug)]
+            pub struct Model<B: Backend> {
+                phantom: core::marker::PhantomData<B>,
+                device: burn::module::Ignored<B::Device>,
+            }
+
+            impl<B: Backend> Model <B> {
+                #[allow(unused_variables)]
+                pub fn new(device: &B::Device) -> Self {
+                    Self {
+                        phantom: core::marker::PhantomData,
+                        device: burn::module::Ignored(device.clone()),
+                    }
+                }
+                #[allow(clippy::let_and_return, clippy::approx_constant)]
+                pub fn forward(&self, shape1: [usize; 4]) -> [usize; 2] {
+                    let shape1: [i64; 4] = shape1.map(|dim| dim as i64);
+                    let shape2: [i64; 2] = shape1[2..4].try_into().unwrap();
+                    shape2.map(|dim| dim as usize)
+                }
+            }
+        };
+
+        assert_tokens(graph.codegen(), expected);
+    }
 }
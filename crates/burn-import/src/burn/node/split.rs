@@ -0,0 +1,132 @@
+use super::{Node, NodeCodegen};
+use crate::burn::{Scope, TensorType, ToTokens, Type};
+use burn::record::PrecisionSettings;
+use proc_macro2::TokenStream;
+use quote::quote;
+
+#[derive(new, Debug, Clone)]
+pub struct SplitConfig {
+    pub axis: usize,
+    /// The size of each output along the axis, or `None` to split in chunks of equal size.
+    pub split_sizes: Option<Vec<usize>>,
+}
+
+#[derive(Debug, Clone, new)]
+pub struct SplitNode {
+    pub input: TensorType,
+    pub outputs: Vec<TensorType>,
+    pub config: SplitConfig,
+}
+
+impl<PS: PrecisionSettings> NodeCodegen<PS> for SplitNode {
+    fn output_types(&self) -> Vec<Type> {
+        self.outputs
+            .iter()
+            .map(|output| Type::Tensor(output.clone()))
+            .collect()
+    }
+
+    fn input_types(&self) -> Vec<Type> {
+        vec![Type::Tensor(self.input.clone())]
+    }
+
+    fn forward(&self, scope: &mut Scope, node_position: usize) -> TokenStream {
+        let input = scope.tensor_use_owned(&self.input, node_position);
+        let axis = self.config.axis.to_tokens();
+        let outputs = self
+            .outputs
+            .iter()
+            .map(|output| &output.name)
+            .collect::<Vec<_>>();
+
+        let Some(split_sizes) = &self.config.split_sizes else {
+            let num_outputs = outputs.len().to_tokens();
+
+            return quote! {
+                let [#(#outputs),*] = #input.chunk(#num_outputs, #axis).try_into().unwrap();
+            };
+        };
+
+        let name = &self.input.name;
+        let mut body = quote! {};
+        let mut start = 0;
+        for (index, (output, size)) in outputs.iter().zip(split_sizes).enumerate() {
+            let source = match index + 1 == outputs.len() {
+                true => input.clone(),
+                false => quote! { #name.clone() },
+            };
+            let (offset, length) = (start.to_tokens(), size.to_tokens());
+
+            body.extend(quote! {
+                let #output = #source.narrow(#axis, #offset, #length);
+            });
+            start += size;
+        }
+
+        body
+    }
+
+    fn into_node(self) -> Node<PS> {
+        Node::Split(self)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use burn::record::FullPrecisionSettings;
+
+    use super::*;
+    use crate::burn::{graph::BurnGraph, node::test::assert_tokens, TensorType};
+
+    #[test]
+    fn test_codegen_split_sizes() {
+        let mut graph = BurnGraph::<FullPrecisionSettings>::default();
+
+        graph.register(SplitNode::new(
+            TensorType::new_float("tensor1", 2),
+            vec![
+                TensorType::new_float("tensor2", 2),
+                TensorType::new_float("tensor3", 2),
+            ],
+            SplitConfig::new(1, Some(vec![2, 3])),
+        ));
+
+        graph.register_input_output(
+            vec!["tensor1".to_string()],
+            vec!["tensor2".to_string(), "tensor3".to_string()],
+        );
+
+        let expected = quote! {
+            use burn::{
+                module::Module,
+                tensor::{backend::Backend, Tensor},
+            };
+
+            #[derive(Module, Debug)]
+            pub struct Model<B: Backend> {
+                phantom: core::marker::PhantomData<B>,
+                device: burn::module::Ignored<B::Device>,
+            }
+
+            impl<B: Backend> Model <B> {
+                #[allow(unused_variables)]
+                pub fn new(device: &B::Device) -> Self {
+                    Self {
+                        phantom: core::marker::PhantomData,
+                        device: burn::module::Ignored(device.clone()),
+                    }
+                }
+
+                #[allow(clippy::let_and_return, clippy::approx_constant)]
+                pub fn forward(&self, tensor1: Tensor<B, 2>) -> (Tensor<B, 2>, Tensor<B, 2>) {
+                    let tensor2 = tensor1.clone().narrow(1, 0, 2);
+                    let tensor3 = tensor1.narrow(1, 2, 3);
+
+                    (tensor2, tensor3)
+                }
+            }
+        };
+
+        assert_tokens(graph.codegen(), expected);
+    }
+}
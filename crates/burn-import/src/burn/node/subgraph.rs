@@ -0,0 +1,131 @@
+use super::{Node, NodeCodegen};
+use crate::burn::{BurnImports, ScalarKind, Scope, ToTokens, Type};
+
+use burn::record::PrecisionSettings;
+use proc_macro2::{Ident, TokenStream};
+use quote::quote;
+
+/// Nodes of a sub-graph, like the branches of an [if](super::if_node::IfNode) or the body of a
+/// [loop](super::loop_node::LoopNode).
+///
+/// The code of the nodes is generated in a block of the forward pass, where the inputs are bound
+/// by the enclosing node.
+#[derive(Debug, Clone, new)]
+pub struct SubGraph<PS: PrecisionSettings> {
+    pub nodes: Vec<Node<PS>>,
+    pub inputs: Vec<Type>,
+    pub outputs: Vec<Type>,
+}
+
+impl<PS: PrecisionSettings> SubGraph<PS> {
+    /// Generate the code of the nodes, and return it with the scope at the end of the block.
+    ///
+    /// The variables captured from the enclosing scope must be bound before the nodes.
+    pub fn forward(&self, captured: &[Type]) -> (TokenStream, Scope) {
+        let inputs = self
+            .inputs
+            .iter()
+            .chain(captured)
+            .cloned()
+            .collect::<Vec<_>>();
+        let mut scope = Scope::build(&inputs, &self.nodes, &self.outputs);
+
+        let mut body = quote! {};
+        self.nodes
+            .iter()
+            .enumerate()
+            .map(|(position, node)| node.forward(&mut scope, position))
+            .for_each(|code| body.extend(code));
+
+        (body, scope)
+    }
+
+    /// Use an output at the end of the block.
+    ///
+    /// # Notes
+    ///
+    /// The outputs must be used in the order they are evaluated, so that only the last use of a
+    /// variable takes ownership of it.
+    pub fn output(&self, scope: &mut Scope, index: usize) -> TokenStream {
+        match &self.outputs[index] {
+            Type::Tensor(tensor) => scope.tensor_use_owned(tensor, self.nodes.len()),
+            output => {
+                let name = output.name();
+                quote! { #name }
+            }
+        }
+    }
+
+    /// Use all the outputs at the end of the block, in order.
+    pub fn outputs(&self, scope: &mut Scope) -> Vec<TokenStream> {
+        (0..self.outputs.len())
+            .map(|index| self.output(scope, index))
+            .collect()
+    }
+
+    /// Check if a variable is used by the nodes or returned by the sub-graph.
+    pub fn uses(&self, name: &Ident) -> bool {
+        self.nodes
+            .iter()
+            .flat_map(|node| node.input_types())
+            .chain(self.outputs.iter().cloned())
+            .any(|ty| ty.name() == name)
+    }
+}
+
+/// Return the values as a tuple, or as a single value.
+pub(crate) fn tuple(values: &[TokenStream]) -> TokenStream {
+    match values {
+        [value] => value.clone(),
+        values => quote! { (#(#values),*) },
+    }
+}
+
+/// Generate the code declaring a scan output, the code pushing the value of an iteration to it, and
+/// the code stacking the values along the given dimension once the iterations are done.
+pub(crate) fn scan_output(
+    name: &Ident,
+    body_output: &Type,
+    value: TokenStream,
+    dim: usize,
+) -> (TokenStream, TokenStream, TokenStream) {
+    // `Vec` isn't in the prelude of no_std crates
+    let declaration = quote! { let mut #name = [].to_vec(); };
+
+    match body_output {
+        Type::Tensor(tensor) => {
+            let output_dim = (tensor.dim + 1).to_tokens();
+            (
+                declaration,
+                quote! { #name.push(#value); },
+                quote! { Tensor::stack::<#output_dim>(#name, #dim) },
+            )
+        }
+        Type::Scalar(scalar) => {
+            let tensor = match scalar.kind {
+                ScalarKind::Int32 | ScalarKind::Int64 => quote! { Tensor::<B, 1, Int> },
+                ScalarKind::Float32 | ScalarKind::Float64 => quote! { Tensor::<B, 1> },
+                ScalarKind::Bool => quote! { Tensor::<B, 1, Bool> },
+            };
+            (
+                declaration,
+                quote! { #name.push(#tensor::from_data([#value], &*self.device)); },
+                quote! { Tensor::cat(#name, 0) },
+            )
+        }
+        _ => panic!("Scan outputs must be scalars or tensors"),
+    }
+}
+
+/// Register the imports used by [scan_output](scan_output) for the given body outputs.
+pub(crate) fn register_scan_output_imports(body_outputs: &[Type], imports: &mut BurnImports) {
+    for output in body_outputs {
+        if let Type::Scalar(scalar) = output {
+            match scalar.kind {
+                ScalarKind::Int32 | ScalarKind::Int64 => imports.register("burn::tensor::Int"),
+                ScalarKind::Bool => imports.register("burn::tensor::Bool"),
+                _ => {}
+            }
+        }
+    }
+}
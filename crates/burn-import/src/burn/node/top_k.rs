@@ -0,0 +1,120 @@
+use super::{Node, NodeCodegen};
+use crate::burn::{Scope, TensorType, ToTokens, Type};
+use burn::record::PrecisionSettings;
+use proc_macro2::TokenStream;
+use quote::quote;
+
+#[derive(new, Debug, Clone)]
+pub struct TopKConfig {
+    pub axis: usize,
+    pub k: usize,
+    /// True to retrieve the largest elements, false for the smallest ones.
+    pub largest: bool,
+}
+
+#[derive(Debug, Clone, new)]
+pub struct TopKNode {
+    pub input: TensorType,
+    pub values: TensorType,
+    pub indices: TensorType,
+    pub config: TopKConfig,
+}
+
+impl<PS: PrecisionSettings> NodeCodegen<PS> for TopKNode {
+    fn output_types(&self) -> Vec<Type> {
+        vec![
+            Type::Tensor(self.values.clone()),
+            Type::Tensor(self.indices.clone()),
+        ]
+    }
+
+    fn input_types(&self) -> Vec<Type> {
+        vec![Type::Tensor(self.input.clone())]
+    }
+
+    fn forward(&self, scope: &mut Scope, node_position: usize) -> TokenStream {
+        let input = scope.tensor_use_owned(&self.input, node_position);
+        let values = &self.values.name;
+        let indices = &self.indices.name;
+        let axis = self.config.axis.to_tokens();
+        let k = self.config.k.to_tokens();
+
+        if self.config.largest {
+            return quote! {
+                let (#values, #indices) = #input.topk_with_indices(#k, #axis);
+            };
+        }
+
+        quote! {
+            let (#values, #indices) = {
+                let (values, indices) = #input.sort_with_indices(#axis);
+                (values.narrow(#axis, 0, #k), indices.narrow(#axis, 0, #k))
+            };
+        }
+    }
+
+    fn into_node(self) -> Node<PS> {
+        Node::TopK(self)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use burn::record::FullPrecisionSettings;
+
+    use super::*;
+    use crate::burn::{graph::BurnGraph, node::test::assert_tokens, TensorType};
+
+    #[test]
+    fn test_codegen_smallest() {
+        let mut graph = BurnGraph::<FullPrecisionSettings>::default();
+
+        graph.register(TopKNode::new(
+            TensorType::new_float("tensor1", 2),
+            TensorType::new_float("tensor2", 2),
+            TensorType::new_int("tensor3", 2),
+            TopKConfig::new(1, 3, false),
+        ));
+
+        graph.register_input_output(
+            vec!["tensor1".to_string()],
+            vec!["tensor2".to_string(), "tensor3".to_string()],
+        );
+
+        let expected = quote! {
+            use burn::tensor::Int;
+            use burn::{
+                module::Module,
+                tensor::{backend::Backend, Tensor},
+            };
+
+            #[derive(Module, Debug)]
+            pub struct Model<B: Backend> {
+                phantom: core::marker::PhantomData<B>,
+                device: burn::module::Ignored<B::Device>,
+            }
+
+            impl<B: Backend> Model <B> {
+                #[allow(unused_variables)]
+                pub fn new(device: &B::Device) -> Self {
+                    Self {
+                        phantom: core::marker::PhantomData,
+                        device: burn::module::Ignored(device.clone()),
+                    }
+                }
+
+                #[allow(clippy::let_and_return, clippy::approx_constant)]
+                pub fn forward(&self, tensor1: Tensor<B, 2>) -> (Tensor<B, 2>, Tensor<B, 2, Int>) {
+                    let (tensor2, tensor3) = {
+                        let (values, indices) = tensor1.sort_with_indices(1);
+                        (values.narrow(1, 0, 3), indices.narrow(1, 0, 3))
+                    };
+
+                    (tensor2, tensor3)
+                }
+            }
+        };
+
+        assert_tokens(graph.codegen(), expected);
+    }
+}
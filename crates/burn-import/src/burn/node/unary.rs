@@ -8,6 +8,11 @@ use std::rc::Rc;
 // Simple fn pointer that receive input as a token stream and return function call.
 type FnPointer = Rc<dyn Fn(TokenStream) -> TokenStream>;
 
+/// The default alpha of the ONNX Selu operator, the same as [selu](burn::tensor::activation::selu).
+pub(crate) const SELU_ALPHA: f64 = 1.6732632423543772;
+/// The default gamma of the ONNX Selu operator, the same as [selu](burn::tensor::activation::selu).
+pub(crate) const SELU_GAMMA: f64 = 1.0507009873554805;
+
 /// Node for all unary operators.
 #[derive(Clone, new)]
 pub struct UnaryNode {
@@ -23,10 +28,13 @@ pub enum UnaryNodeKind {
     // Input and output tensor types (required for codegen imports)
     Cast(Option<TensorKind>, Option<TensorKind>),
     Cos,
+    Elu,
     Erf,
     Exp,
     Flatten,
     Gelu,
+    HardSigmoid,
+    HardSwish,
     LeakyRelu,
     Log,
     LogSoftmax,
@@ -39,10 +47,12 @@ pub enum UnaryNodeKind {
     ReduceSum,
     Reciprocal,
     Relu,
+    Selu,
     Shape,
     Sigmoid,
     Sin,
     Softmax,
+    Softplus,
     Sqrt,
     Tanh,
     Transpose,
@@ -54,10 +64,13 @@ impl UnaryNodeKind {
         match self {
             Self::Cast(..) => "cast",
             Self::Cos => "cos",
+            Self::Elu => "elu",
             Self::Erf => "erf",
             Self::Exp => "exp",
             Self::Flatten => "flatten",
             Self::Gelu => "gelu",
+            Self::HardSigmoid => "hard_sigmoid",
+            Self::HardSwish => "hard_swish",
             Self::LeakyRelu => "leaky_relu",
             Self::Log => "log",
             Self::LogSoftmax => "log_softmax",
@@ -70,10 +83,12 @@ impl UnaryNodeKind {
             Self::ReduceSum => "reduce_sum",
             Self::Reciprocal => "reciprocal",
             Self::Relu => "relu",
+            Self::Selu => "selu",
             Self::Shape => "shape",
             Self::Sigmoid => "sigmoid",
             Self::Sin => "sin",
             Self::Softmax => "softmax",
+            Self::Softplus => "softplus",
             Self::Sqrt => "sqrt",
             Self::Tanh => "tanh",
             Self::Transpose => "transpose",
@@ -113,6 +128,11 @@ impl<PS: PrecisionSettings> NodeCodegen<PS> for UnaryNode {
                 let name = scalar.name.clone();
                 quote! { #name }
             }
+            // Shapes are copied
+            Type::Shape(shape) => {
+                let name = shape.name.clone();
+                quote! { #name }
+            }
             _ => panic!("lhs must be a tensor or scalar"),
         };
 
@@ -123,7 +143,7 @@ impl<PS: PrecisionSettings> NodeCodegen<PS> for UnaryNode {
             Type::Shape(ref shape_type) => {
                 let dim = shape_type.dim.to_tokens();
                 quote! {
-                    let #output: [usize;#dim] = #function.try_into().unwrap();
+                    let #output: [i64;#dim] = #function;
                 }
             }
             _ => {
@@ -185,6 +205,42 @@ impl UnaryNode {
         Self::new(input, output, UnaryNodeKind::Relu, Rc::new(function))
     }
 
+    pub(crate) fn elu(input: Type, output: Type, alpha: f64) -> Self {
+        let function = move |input| quote! { burn::tensor::activation::elu(#input, #alpha) };
+        Self::new(input, output, UnaryNodeKind::Elu, Rc::new(function))
+    }
+
+    /// The SELU function with its default alpha and gamma, or an ELU scaled by gamma.
+    pub(crate) fn selu(input: Type, output: Type, alpha: Option<f64>, gamma: Option<f64>) -> Self {
+        let function: FnPointer = match (alpha, gamma) {
+            (None, None) => Rc::new(move |input| quote! { burn::tensor::activation::selu(#input) }),
+            (alpha, gamma) => {
+                let alpha = alpha.unwrap_or(SELU_ALPHA);
+                let gamma = gamma.unwrap_or(SELU_GAMMA);
+                Rc::new(move |input| {
+                    quote! { burn::tensor::activation::elu(#input, #alpha).mul_scalar(#gamma) }
+                })
+            }
+        };
+        Self::new(input, output, UnaryNodeKind::Selu, function)
+    }
+
+    pub(crate) fn hard_sigmoid(input: Type, output: Type, alpha: f64, beta: f64) -> Self {
+        let function =
+            move |input| quote! { burn::tensor::activation::hard_sigmoid(#input, #alpha, #beta) };
+        Self::new(input, output, UnaryNodeKind::HardSigmoid, Rc::new(function))
+    }
+
+    pub(crate) fn hard_swish(input: Type, output: Type) -> Self {
+        let function = move |input| quote! { burn::tensor::activation::hard_swish(#input) };
+        Self::new(input, output, UnaryNodeKind::HardSwish, Rc::new(function))
+    }
+
+    pub(crate) fn softplus(input: Type, output: Type) -> Self {
+        let function = move |input| quote! { burn::tensor::activation::softplus(#input, 1.0) };
+        Self::new(input, output, UnaryNodeKind::Softplus, Rc::new(function))
+    }
+
     pub(crate) fn sigmoid(input: Type, output: Type) -> Self {
         let function = move |input| quote! { burn::tensor::activation::sigmoid(#input) };
         Self::new(input, output, UnaryNodeKind::Sigmoid, Rc::new(function))
@@ -282,6 +338,13 @@ impl UnaryNode {
                     )
                 }
             }
+            // Shapes are always int64 values
+            (Type::Shape(_), Type::Shape(_)) => Self::new(
+                input,
+                output,
+                UnaryNodeKind::Cast(None, None),
+                Rc::new(|input| input),
+            ),
             (Type::Tensor(input_tensor), Type::Tensor(output_tensor)) => {
                 if input_tensor.kind == output_tensor.kind {
                     // If the input and output types are the same, we don't need to cast.
@@ -457,12 +520,22 @@ impl UnaryNode {
     }
 
     pub(crate) fn shape(input: Type, output: Type, start_dim: usize, end_dim: usize) -> Self {
-        let start_dim = start_dim.to_tokens();
-        let end_dim = end_dim.to_tokens();
+        // The number of dimensions is given by the output shape type
+        debug_assert!(matches!(&output, Type::Shape(shape) if shape.dim == end_dim - start_dim));
+        let index = match start_dim {
+            0 => quote! { i },
+            _ => {
+                let start_dim = start_dim.to_tokens();
+                quote! { i + #start_dim }
+            }
+        };
 
         let function = move |input| {
             quote! {
-                #input.dims()[#start_dim..#end_dim]
+                {
+                    let dims = #input.dims();
+                    core::array::from_fn(|i| dims[#index] as i64)
+                }
             }
         };
         Self::new(input, output, UnaryNodeKind::Shape, Rc::new(function))
@@ -539,6 +612,127 @@ mod tests {
         );
     }
 
+    #[test]
+    fn test_unary_codegen_elu() {
+        one_node_graph(
+            UnaryNode::elu(
+                Type::Tensor(TensorType::new_float("tensor1", 4)),
+                Type::Tensor(TensorType::new_float("tensor2", 4)),
+                1.0,
+            ),
+            quote! {
+                pub fn forward(&self, tensor1: Tensor<B, 4>) -> Tensor<B, 4> {
+                    let tensor2 = burn::tensor::activation::elu(tensor1, 1f64);
+
+                    tensor2
+                }
+            },
+            vec!["tensor1".to_string()],
+            vec!["tensor2".to_string()],
+        );
+    }
+
+    #[test]
+    fn test_unary_codegen_selu() {
+        one_node_graph(
+            UnaryNode::selu(
+                Type::Tensor(TensorType::new_float("tensor1", 4)),
+                Type::Tensor(TensorType::new_float("tensor2", 4)),
+                None,
+                None,
+            ),
+            quote! {
+                pub fn forward(&self, tensor1: Tensor<B, 4>) -> Tensor<B, 4> {
+                    let tensor2 = burn::tensor::activation::selu(tensor1);
+
+                    tensor2
+                }
+            },
+            vec!["tensor1".to_string()],
+            vec!["tensor2".to_string()],
+        );
+    }
+
+    #[test]
+    fn test_unary_codegen_selu_custom() {
+        one_node_graph(
+            UnaryNode::selu(
+                Type::Tensor(TensorType::new_float("tensor1", 4)),
+                Type::Tensor(TensorType::new_float("tensor2", 4)),
+                Some(2.0),
+                Some(0.5),
+            ),
+            quote! {
+                pub fn forward(&self, tensor1: Tensor<B, 4>) -> Tensor<B, 4> {
+                    let tensor2 = burn::tensor::activation::elu(tensor1, 2f64).mul_scalar(0.5f64);
+
+                    tensor2
+                }
+            },
+            vec!["tensor1".to_string()],
+            vec!["tensor2".to_string()],
+        );
+    }
+
+    #[test]
+    fn test_unary_codegen_hard_sigmoid() {
+        one_node_graph(
+            UnaryNode::hard_sigmoid(
+                Type::Tensor(TensorType::new_float("tensor1", 4)),
+                Type::Tensor(TensorType::new_float("tensor2", 4)),
+                0.2,
+                0.5,
+            ),
+            quote! {
+                pub fn forward(&self, tensor1: Tensor<B, 4>) -> Tensor<B, 4> {
+                    let tensor2 = burn::tensor::activation::hard_sigmoid(tensor1, 0.2f64, 0.5f64);
+
+                    tensor2
+                }
+            },
+            vec!["tensor1".to_string()],
+            vec!["tensor2".to_string()],
+        );
+    }
+
+    #[test]
+    fn test_unary_codegen_hard_swish() {
+        one_node_graph(
+            UnaryNode::hard_swish(
+                Type::Tensor(TensorType::new_float("tensor1", 4)),
+                Type::Tensor(TensorType::new_float("tensor2", 4)),
+            ),
+            quote! {
+                pub fn forward(&self, tensor1: Tensor<B, 4>) -> Tensor<B, 4> {
+                    let tensor2 = burn::tensor::activation::hard_swish(tensor1);
+
+                    tensor2
+                }
+            },
+            vec!["tensor1".to_string()],
+            vec!["tensor2".to_string()],
+        );
+    }
+
+    #[test]
+    fn test_unary_codegen_softplus() {
+        one_node_graph(
+            UnaryNode::softplus(
+                Type::Tensor(TensorType::new_float("tensor1", 4)),
+                Type::Tensor(TensorType::new_float("tensor2", 4)),
+            ),
+            quote! {
+                pub fn forward(&self, tensor1: Tensor<B, 4>) -> Tensor<B, 4> {
+                    let tensor2 = burn::tensor::activation::softplus(tensor1, 1.0);
+
+                    tensor2
+                }
+            },
+            vec!["tensor1".to_string()],
+            vec!["tensor2".to_string()],
+        );
+    }
+
     #[test]
     fn test_unary_codegen_leaky_relu() {
         one_node_graph(
@@ -1097,15 +1291,18 @@ mod tests {
         one_node_graph(
             UnaryNode::shape(
                 Type::Tensor(TensorType::new_float("tensor1", 4)),
-                Type::Shape(ShapeType::new("shape1", 4)),
+                Type::Shape(ShapeType::new("shape1", 2)),
                 1,
                 3,
             ),
             quote! {
-                pub fn forward(&self, tensor1: Tensor<B, 4>) -> [usize; 4] {
-                    let shape1: [usize; 4] = tensor1.dims()[1..3].try_into().unwrap();
+                pub fn forward(&self, tensor1: Tensor<B, 4>) -> [usize; 2] {
+                    let shape1: [i64; 2] = {
+                        let dims = tensor1.dims();
+                        core::array::from_fn(|i| dims[i + 1] as i64)
+                    };
 
-                    shape1
+                    shape1.map(|dim| dim as usize)
                 }
             },
             vec!["tensor1".to_string()],
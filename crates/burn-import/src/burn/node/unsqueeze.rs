@@ -1,5 +1,5 @@
 use super::{Node, NodeCodegen};
-use crate::burn::{BurnImports, Scope, TensorType, ToTokens, Type};
+use crate::burn::{BurnImports, Scope, ToTokens, Type};
 use burn::record::PrecisionSettings;
 use proc_macro2::TokenStream;
 use quote::quote;
@@ -7,13 +7,13 @@ use quote::quote;
 #[derive(Debug, Clone, new)]
 pub struct UnsqueezeNode {
     pub input: Type,
-    pub output: TensorType,
+    pub output: Type,
     pub axes: Vec<i64>,
 }
 
 impl<PS: PrecisionSettings> NodeCodegen<PS> for UnsqueezeNode {
     fn output_types(&self) -> Vec<Type> {
-        vec![Type::Tensor(self.output.clone())]
+        vec![self.output.clone()]
     }
 
     fn input_types(&self) -> Vec<Type> {
@@ -21,9 +21,12 @@ impl<PS: PrecisionSettings> NodeCodegen<PS> for UnsqueezeNode {
     }
 
     fn forward(&self, scope: &mut Scope, node_position: usize) -> TokenStream {
-        let output = &self.output.name;
+        let output = self.output.name();
         let shape_values = &self.axes.to_tokens();
-        let new_dims = self.output.dim.to_tokens();
+        let new_dims = match &self.output {
+            Type::Tensor(tensor) => tensor.dim.to_tokens(),
+            _ => quote! {},
+        };
 
         match &self.input {
             Type::Tensor(tensor) => {
@@ -38,6 +41,13 @@ impl<PS: PrecisionSettings> NodeCodegen<PS> for UnsqueezeNode {
                     let #output = Tensor::<B, #new_dims>::from_data([#input.elem::<B::FloatElem>()], &self.device).unsqueeze();
                 }
             }
+            // The values gathered from a runtime shape are already kept as one element shapes
+            Type::Shape(shape) => {
+                let input = &shape.name;
+                quote! {
+                    let #output = #input;
+                }
+            }
             _ => panic!("Unsupported input type"),
         }
     }
@@ -73,7 +83,7 @@ mod tests {
 
         graph.register(UnsqueezeNode::new(
             Type::Tensor(TensorType::new_float("tensor1", 3)),
-            TensorType::new_float("tensor2", 5),
+            Type::Tensor(TensorType::new_float("tensor2", 5)),
             [0, 4].into(),
         ));
 
@@ -1,4 +1,8 @@
-use super::TensorType;
+use super::{
+    node::{Node, NodeCodegen},
+    TensorType, Type,
+};
+use burn::record::PrecisionSettings;
 use derive_new::new;
 use proc_macro2::{Ident, TokenStream};
 use quote::quote;
@@ -17,6 +21,48 @@ struct TensorVariable {
 }
 
 impl Scope {
+    /// Build the scope of a graph, or of a sub-graph nested in a block like the body of a loop.
+    ///
+    /// The inputs are declared before the first node, and the outputs are used after the last
+    /// node, when they are returned.
+    ///
+    /// # Notes
+    ///
+    /// The variables captured by a sub-graph from the enclosing scope are declared as inputs, so
+    /// they are used as if they were owned by the block.
+    pub fn build<PS: PrecisionSettings>(
+        inputs: &[Type],
+        nodes: &[Node<PS>],
+        outputs: &[Type],
+    ) -> Self {
+        let mut scope = Self::default();
+
+        // Register graph tensor input with 0 as node position
+        inputs.iter().flat_map(to_tensor).for_each(|tensor| {
+            scope.tensor_register_variable(tensor, 0);
+        });
+
+        nodes.iter().enumerate().for_each(|(node_position, node)| {
+            node.output_types()
+                .iter()
+                .flat_map(to_tensor)
+                .for_each(|tensor| scope.tensor_register_variable(tensor, node_position + 1))
+        });
+
+        nodes.iter().enumerate().for_each(|(node_position, node)| {
+            node.input_types()
+                .iter()
+                .flat_map(to_tensor)
+                .for_each(|tensor| scope.tensor_register_future_use(tensor, node_position))
+        });
+
+        outputs.iter().flat_map(to_tensor).for_each(|tensor| {
+            scope.tensor_register_future_use(tensor, nodes.len());
+        });
+
+        scope
+    }
+
     /// Declare a new tensor variable.
     pub fn tensor_register_variable(&mut self, tensor: &TensorType, node_position: usize) {
         if let Some(variables) = self.variables.get_mut(&tensor.name) {
@@ -56,9 +102,53 @@ impl Scope {
 
     /// Use a tensor variable, cloning it if it was registered multiple times and the tensor will still be used afterward.
     pub fn tensor_use_owned(&mut self, tensor: &TensorType, node_position: usize) -> TokenStream {
+        let name = &tensor.name;
+
+        if self.tensor_use(tensor, node_position) > 0 {
+            quote! {
+                #name.clone()
+            }
+        } else {
+            quote! {
+                #name
+            }
+        }
+    }
+
+    /// Use a tensor variable captured by a block executed once, like the branch of an `if`.
+    ///
+    /// Returns the statement cloning the variable before the block when it will still be used
+    /// afterward, otherwise the block takes ownership of the variable.
+    pub fn tensor_use_captured(
+        &mut self,
+        tensor: &TensorType,
+        node_position: usize,
+    ) -> TokenStream {
+        let name = &tensor.name;
+
+        if self.tensor_use(tensor, node_position) > 0 {
+            quote! {
+                let #name = #name.clone();
+            }
+        } else {
+            quote! {}
+        }
+    }
+
+    /// Use a tensor variable in the body of a loop, where it is cloned at each iteration.
+    pub fn tensor_use_in_loop(&mut self, tensor: &TensorType, node_position: usize) -> TokenStream {
+        let name = &tensor.name;
+        self.tensor_use(tensor, node_position);
+
+        quote! {
+            #name.clone()
+        }
+    }
+
+    /// Use a tensor variable and return the number of its remaining uses.
+    fn tensor_use(&mut self, tensor: &TensorType, node_position: usize) -> usize {
         if let Some(variables) = self.variables.get_mut(&tensor.name) {
             let mut count = 0;
-            let name = &tensor.name;
 
             for variable in variables.iter_mut().rev() {
                 if node_position >= variable.node_position {
@@ -68,17 +158,18 @@ impl Scope {
                 }
             }
 
-            if count > 0 {
-                quote! {
-                    #name.clone()
-                }
-            } else {
-                quote! {
-                    #name
-                }
-            }
+            count
         } else {
             panic!("No variable with name {}", &tensor.name);
         }
     }
 }
+
+fn to_tensor(ty: &Type) -> Option<&TensorType> {
+    match ty {
+        Type::Tensor(tensor) => Some(tensor),
+        Type::Scalar(_) => None,
+        Type::Other(_) => None,
+        Type::Shape(_) => None,
+    }
+}
@@ -112,6 +112,11 @@ impl ShapeType {
             dim,
         }
     }
+
+    /// The type of the shape at the boundary of the model.
+    ///
+    /// Inside the forward pass, the shapes are int64 values like the ONNX shapes, so that the
+    /// computed target shapes can hold the `-1` inferred dimension of a reshape.
     pub fn ty(&self) -> TokenStream {
         let dim = self.dim.to_tokens();
         quote! { [usize; #dim] }
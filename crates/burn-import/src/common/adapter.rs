@@ -1,7 +1,8 @@
 use burn::{
     module::Param,
+    quantization::{Calibration, MinMaxCalibration, QuantizationScheme, QuantizationType},
     record::{PrecisionSettings, Record},
-    tensor::{backend::Backend, Tensor},
+    tensor::{backend::Backend, QuantizationStrategy, Tensor},
 };
 
 use burn::record::serde::{
@@ -12,6 +13,19 @@ use burn::record::serde::{
 
 use serde::Serialize;
 
+/// The adapter converting the tensors of a checkpoint file to the Burn modules.
+#[cfg(any(feature = "safetensors", feature = "gguf"))]
+#[derive(Debug, Default, Clone, Copy, PartialEq, Eq)]
+pub enum AdapterType {
+    /// The tensors follow the PyTorch conventions, e.g. the linear weights are transposed and the
+    /// normalization parameters are named `weight` and `bias`.
+    #[default]
+    PyTorch,
+
+    /// The tensors follow the Burn conventions, e.g. for files saved by a Burn recorder.
+    NoAdapter,
+}
+
 /// A PyTorch adapter for the Burn module used during deserialization.
 ///
 /// Not all Burn module correspond to a PyTorch module. Therefore,
@@ -37,9 +51,12 @@ impl<PS: PrecisionSettings, B: Backend> BurnModuleAdapter for PyTorchAdapter<PS,
             .expect("Failed to deserialize weight");
 
         // Do not capture transpose op when using autodiff backend
-        let weight = weight.set_require_grad(false);
+        let weight = weight.set_require_grad(false).val();
         // Transpose the weight tensor.
-        let weight_transposed = Param::from_tensor(weight.val().transpose());
+        let weight_transposed = match weight.is_quantized() {
+            true => Param::from_tensor(transpose_quantized(weight)),
+            false => Param::from_tensor(weight.transpose()),
+        };
 
         // Insert the transposed weight tensor back into the map.
         map.insert(
@@ -64,6 +81,27 @@ impl<PS: PrecisionSettings, B: Backend> BurnModuleAdapter for PyTorchAdapter<PS,
     }
 }
 
+/// Transposes a quantized weight, keeping it quantized.
+///
+/// The groups of per-group quantization (e.g. the `Q8_0` and `Q4_0` blocks of GGUF files) are
+/// contiguous values, which are no longer contiguous once transposed. The transposed values are
+/// therefore quantized again with groups of the same size.
+fn transpose_quantized<B: Backend>(weight: Tensor<B, 2>) -> Tensor<B, 2> {
+    let scheme = match weight.to_data().quantization_strategy() {
+        Some(QuantizationStrategy::PerGroupSymmetricInt8(q)) => {
+            QuantizationScheme::PerGroupSymmetric(QuantizationType::QInt8, q.group_size)
+        }
+        Some(QuantizationStrategy::PerGroupSymmetricInt4(q)) => {
+            QuantizationScheme::PerGroupSymmetric(QuantizationType::QInt4, q.group_size)
+        }
+        _ => return weight.dequantize().transpose(),
+    };
+
+    let weight = weight.dequantize().transpose();
+    let strategy = MinMaxCalibration { scheme }.configure(&weight);
+    weight.quantize(strategy)
+}
+
 /// Helper function to serialize a param tensor.
 fn serialize<PS, B, const D: usize>(val: Param<Tensor<B, D>>) -> NestedValue
 where
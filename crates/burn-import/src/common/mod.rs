@@ -0,0 +1,3 @@
+pub(crate) mod adapter;
+pub(crate) mod param;
+pub(crate) mod remap;
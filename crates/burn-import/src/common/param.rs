@@ -0,0 +1,68 @@
+use std::collections::HashMap;
+
+use burn::{
+    module::ParamId,
+    record::serde::{data::NestedValue, error, ser::Serializer},
+    tensor::TensorData,
+};
+use serde::Serialize;
+
+/// Serializes the tensor data as a `Param` item with a new id.
+///
+/// The tensor is manually serialized instead of using the `ParamSerde` struct, such as:
+/// `ParamSerde::new(param_id, data).serialize(serializer)`, because the serializer copies the
+/// individual bytes of `TensorData` into a new `Vec<u8>`, which is not necessary and inefficient.
+pub(crate) fn serialize_param(
+    data: TensorData,
+    serializer: Serializer,
+) -> Result<NestedValue, error::Error> {
+    let TensorData {
+        bytes,
+        shape,
+        dtype,
+    } = data;
+
+    let mut tensor_data: HashMap<String, NestedValue> = HashMap::new();
+    tensor_data.insert("bytes".into(), NestedValue::U8s(bytes));
+    tensor_data.insert("shape".into(), shape.serialize(serializer.clone())?);
+    tensor_data.insert("dtype".into(), dtype.serialize(serializer)?);
+
+    Ok(param_item(NestedValue::Map(tensor_data)))
+}
+
+/// Serializes the location of the tensor values in a memory mapped file as a `Param` item with a
+/// new id, so the values are only read when the tensor is used.
+#[cfg(feature = "safetensors")]
+pub(crate) fn serialize_mapped_param(
+    tensor: burn::record::MappedTensor,
+    serializer: Serializer,
+) -> Result<NestedValue, error::Error> {
+    Ok(param_item(tensor.serialize(serializer)?))
+}
+
+fn param_item(tensor: NestedValue) -> NestedValue {
+    let mut param: HashMap<String, NestedValue> = HashMap::new();
+    param.insert(
+        "id".into(),
+        NestedValue::String(ParamId::new().into_string()),
+    );
+    param.insert("param".into(), tensor);
+
+    NestedValue::Map(param)
+}
+
+/// Helper function to convert the little endian values of a tensor to the record element type.
+#[cfg(feature = "gguf")]
+pub(crate) fn convert<T, E>(bytes: &[u8], shape: Vec<usize>) -> TensorData
+where
+    T: bytemuck::Pod + burn::tensor::ElementConversion,
+    E: burn::tensor::Element,
+{
+    let data: Vec<E> = bytes
+        .chunks_exact(core::mem::size_of::<T>())
+        // The mapped data is not necessarily aligned with the element type
+        .map(|value| bytemuck::pod_read_unaligned::<T>(value).elem())
+        .collect();
+
+    TensorData::new(data, shape)
+}
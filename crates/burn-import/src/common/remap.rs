@@ -0,0 +1,44 @@
+use std::collections::HashMap;
+use std::fmt::Debug;
+
+use burn::record::serde::data::remap;
+use regex::Regex;
+
+/// Remaps the keys of the tensors (replace the keys in the map with the new keys).
+///
+/// If debug is enabled, the remapped keys are printed with the shape and data type of their
+/// tensor, as returned by `describe`.
+pub(crate) fn remap_keys<T, S, D>(
+    tensors: HashMap<String, T>,
+    key_remap: Vec<(Regex, String)>,
+    debug: bool,
+    describe: impl Fn(&T) -> (S, D),
+) -> HashMap<String, T>
+where
+    S: Debug,
+    D: Debug,
+{
+    let (tensors, remapped_keys) = remap(tensors, key_remap);
+
+    // Print the remapped keys if debug is enabled
+    if debug {
+        let mut remapped_keys = remapped_keys;
+        remapped_keys.sort();
+        println!("Debug information of keys and tensor shapes:\n---");
+        for (new_key, old_key) in remapped_keys {
+            if old_key != new_key {
+                println!("Original Key: {old_key}");
+                println!("Remapped Key: {new_key}");
+            } else {
+                println!("Key: {}", new_key);
+            }
+
+            let (shape, dtype) = describe(&tensors[&new_key]);
+            println!("Shape: {shape:?}");
+            println!("Dtype: {dtype:?}");
+            println!("---");
+        }
+    }
+
+    tensors
+}
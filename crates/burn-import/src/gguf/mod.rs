@@ -0,0 +1,5 @@
+mod error;
+mod reader;
+mod recorder;
+pub use crate::common::adapter::AdapterType;
+pub use recorder::GgufFileRecorder;
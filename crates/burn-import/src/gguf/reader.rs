@@ -0,0 +1,214 @@
+use std::collections::HashMap;
+use std::fs::File;
+use std::io::Cursor;
+use std::path::Path;
+
+use super::error::Error;
+use crate::common::{
+    adapter::{AdapterType, PyTorchAdapter},
+    param::{convert, serialize_param},
+    remap::remap_keys,
+};
+
+use burn::{
+    record::{
+        serde::{
+            adapter::DefaultAdapter,
+            data::{unflatten, NestedValue, Serializable},
+            de::Deserializer,
+            error,
+            ser::Serializer,
+        },
+        PrecisionSettings,
+    },
+    tensor::{
+        backend::Backend, Element, ElementConversion, PerGroupQuantization, QuantizationStrategy,
+        SymmetricQuantization, TensorData,
+    },
+};
+
+use candle_core::{
+    quantized::{ggml_file::qtensor_from_ggml, gguf_file::Content, GgmlDType},
+    Device,
+};
+use half::f16;
+use memmap2::Mmap;
+use regex::Regex;
+use serde::de::DeserializeOwned;
+
+/// The number of values in a `Q4_0` or `Q8_0` block.
+const BLOCK_SIZE: usize = 32;
+
+/// Deserializes a GGUF file.
+///
+/// The file is memory mapped, so the tensor values are read directly from the mapped pages
+/// without loading the whole file in memory first.
+///
+/// # Arguments
+///
+/// * `path` - A string slice that holds the path of the file to read.
+/// * `key_remap` - A vector of tuples containing a regular expression and a replacement string.
+/// * `top_level_key` - An optional key prefix of the tensors to load, which is removed from the keys.
+/// * `adapter_type` - The adapter used to convert the tensors to the Burn modules.
+/// * `dequantize` - Whether the quantized blocks are always dequantized to float tensors.
+pub fn from_file<PS, D, B>(
+    path: &Path,
+    key_remap: Vec<(Regex, String)>,
+    top_level_key: Option<&str>,
+    adapter_type: AdapterType,
+    dequantize: bool,
+    debug: bool,
+) -> Result<D, Error>
+where
+    D: DeserializeOwned,
+    PS: PrecisionSettings,
+    B: Backend,
+{
+    let file = File::open(path)?;
+    // SAFETY: the file should not be modified while it is mapped, which is the case of any
+    // memory mapped file loader.
+    let mmap = unsafe { Mmap::map(&file)? };
+    let content = Content::read(&mut Cursor::new(&mmap[..]))?;
+
+    // Only keep the tensors nested under the top-level key, without the prefix
+    let mut tensors: HashMap<String, GgufTensor> = HashMap::new();
+    for (key, info) in content.tensor_infos.iter() {
+        let key = match top_level_key {
+            Some(prefix) => match key.strip_prefix(prefix).and_then(|k| k.strip_prefix('.')) {
+                Some(key) => key.to_string(),
+                None => continue,
+            },
+            None => key.clone(),
+        };
+
+        let shape = info.shape.dims().to_vec();
+        let num_blocks = info.shape.elem_count() / info.ggml_dtype.block_size();
+        let start = (content.tensor_data_offset + info.offset) as usize;
+        let end = start + num_blocks * info.ggml_dtype.type_size();
+        let data = mmap.get(start..end).ok_or_else(|| {
+            Error::Other(format!(
+                "The data of tensor {key} is out of the file bounds"
+            ))
+        })?;
+
+        tensors.insert(
+            key,
+            GgufTensor {
+                dtype: info.ggml_dtype,
+                shape,
+                data,
+                dequantize,
+            },
+        );
+    }
+
+    // Remap the keys and print them if debug is enabled
+    let tensors = remap_keys(tensors, key_remap, debug, |tensor| {
+        (tensor.shape.clone(), tensor.dtype)
+    });
+
+    // Convert the tensors to a nested value data structure
+    let nested_value = unflatten::<PS, _>(tensors)?;
+
+    // Deserialize the nested value into a record type with the selected adapter
+    let value = match adapter_type {
+        AdapterType::PyTorch => D::deserialize(Deserializer::<PyTorchAdapter<PS, B>>::new(
+            nested_value,
+            true,
+        ))?,
+        AdapterType::NoAdapter => {
+            D::deserialize(Deserializer::<DefaultAdapter>::new(nested_value, true))?
+        }
+    };
+    Ok(value)
+}
+
+/// A GGUF tensor, with its row-major shape and the raw data of its blocks.
+struct GgufTensor<'data> {
+    dtype: GgmlDType,
+    shape: Vec<usize>,
+    data: &'data [u8],
+    dequantize: bool,
+}
+
+/// Serializes a GGUF tensor.
+///
+/// Tensors are wrapped in a `Param` struct (learnable parameters) and serialized as a `TensorData` struct.
+///
+/// Float values are serialized as `FloatElem` depending on the precision settings. The `Q8_0` and
+/// `Q4_0` blocks are mapped to the matching per-group symmetric quantization strategy, while the
+/// other quantized blocks are dequantized.
+impl Serializable for GgufTensor<'_> {
+    fn serialize<PS>(&self, serializer: Serializer) -> Result<NestedValue, error::Error>
+    where
+        PS: PrecisionSettings,
+    {
+        let shape = self.shape.clone();
+        let bytes = self.data;
+
+        let data = match self.dtype {
+            GgmlDType::F32 => convert::<f32, PS::FloatElem>(bytes, shape),
+            GgmlDType::F16 => convert::<f16, PS::FloatElem>(bytes, shape),
+            GgmlDType::Q8_0 if !self.dequantize => q8_0(bytes, shape),
+            GgmlDType::Q4_0 if !self.dequantize => q4_0(bytes, shape),
+            dtype => dequantize::<PS::FloatElem>(dtype, bytes, shape)?,
+        };
+
+        serialize_param(data, serializer)
+    }
+}
+
+/// Reads the little endian `f16` scale of a block.
+fn block_scale<const B: usize>(block: &[u8]) -> SymmetricQuantization<f32, i8, B> {
+    SymmetricQuantization::init(f16::from_le_bytes([block[0], block[1]]).to_f32())
+}
+
+/// Maps `Q8_0` blocks (an `f16` scale followed by 32 `i8` values) to a per-group symmetric `int8`
+/// quantized tensor.
+fn q8_0(bytes: &[u8], shape: Vec<usize>) -> TensorData {
+    let blocks = bytes.chunks_exact(2 + BLOCK_SIZE);
+    let groups = blocks.clone().map(block_scale).collect();
+    let values: Vec<i8> = blocks
+        .flat_map(|block| block[2..].iter().map(|x| *x as i8))
+        .collect();
+
+    let strategy =
+        QuantizationStrategy::PerGroupSymmetricInt8(PerGroupQuantization::new(BLOCK_SIZE, groups));
+    TensorData::quantized(values, shape, strategy)
+}
+
+/// Maps `Q4_0` blocks (an `f16` scale followed by 32 packed `uint4` values with an offset of 8) to
+/// a per-group symmetric `int4` quantized tensor.
+fn q4_0(bytes: &[u8], shape: Vec<usize>) -> TensorData {
+    let blocks = bytes.chunks_exact(2 + BLOCK_SIZE / 2);
+    let groups = blocks.clone().map(block_scale).collect();
+    // The low nibbles hold the first half of the block and the high nibbles the second half
+    let values: Vec<i32> = blocks
+        .flat_map(|block| {
+            let qs = &block[2..];
+            let low = qs.iter().map(|x| (x & 0x0F) as i32 - 8);
+            let high = qs.iter().map(|x| (x >> 4) as i32 - 8);
+            low.chain(high).collect::<Vec<_>>()
+        })
+        .collect();
+
+    let strategy =
+        QuantizationStrategy::PerGroupSymmetricInt4(PerGroupQuantization::new(BLOCK_SIZE, groups));
+    TensorData::quantized(strategy.pack(&values), shape, strategy)
+}
+
+/// Dequantizes the blocks of a tensor to the record element type.
+fn dequantize<E: Element>(
+    dtype: GgmlDType,
+    bytes: &[u8],
+    shape: Vec<usize>,
+) -> Result<TensorData, error::Error> {
+    let values = qtensor_from_ggml(dtype, bytes, shape.clone(), &Device::Cpu)
+        .and_then(|tensor| tensor.dequantize(&Device::Cpu))
+        .and_then(|tensor| tensor.flatten_all())
+        .and_then(|tensor| tensor.to_vec1::<f32>())
+        .map_err(|err| error::Error::Other(format!("Failed to dequantize {dtype:?}: {err}")))?;
+
+    let values: Vec<E> = values.into_iter().map(|x| x.elem()).collect();
+    Ok(TensorData::new(values, shape))
+}
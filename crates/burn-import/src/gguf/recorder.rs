@@ -0,0 +1,406 @@
+use core::marker::PhantomData;
+use std::path::PathBuf;
+
+use burn::{
+    record::{FileRecorder, PrecisionSettings, Record, Recorder, RecorderError},
+    tensor::backend::Backend,
+};
+
+use regex::Regex;
+use serde::{de::DeserializeOwned, Serialize};
+
+use super::{reader::from_file, AdapterType};
+
+/// A recorder that loads GGUF files (`.gguf`), such as the llama.cpp checkpoints, into Burn modules.
+///
+/// The `F32` and `F16` tensors are loaded as float tensors. The `Q8_0` and `Q4_0` quantized
+/// blocks are loaded as quantized tensors with the matching per-group symmetric quantization
+/// strategy (groups of 32 values with their own scale), and the other quantized blocks (e.g.
+/// `Q4_K`) are dequantized. See [with_dequantize](GgufFileRecorder::with_dequantize) to
+/// dequantize all the tensors.
+///
+/// The tensor names can be remapped to match the Burn module structure, see
+/// [with_key_remap](GgufFileRecorder::with_key_remap). Saving GGUF files is not supported.
+///
+/// # Examples
+///
+/// ```text
+/// use burn_import::gguf::GgufFileRecorder;
+/// use burn::record::{FullPrecisionSettings, Recorder};
+///
+/// let record = GgufFileRecorder::<FullPrecisionSettings>::default()
+///     .with_key_remap("blk\\.([0-9]+)\\.(.*)", "layers.$1.$2") // e.g. "blk.0.ffn_up" -> "layers.0.ffn_up"
+///     .load("model-q8_0.gguf".into(), &device)
+///     .expect("Should decode state successfully");
+/// ```
+#[derive(Debug, Default, Clone)]
+pub struct GgufFileRecorder<PS: PrecisionSettings> {
+    /// A list of key remappings.
+    key_remap: Vec<(Regex, String)>,
+
+    /// Key prefix of the tensors to load, removed from their keys.
+    top_level_key: Option<String>,
+
+    /// The adapter used to convert the tensors to the Burn modules.
+    adapter_type: AdapterType,
+
+    /// Whether to dequantize all the quantized blocks.
+    dequantize: bool,
+
+    /// Whether to print debug information.
+    debug: bool,
+
+    _settings: PhantomData<PS>,
+}
+
+impl<PS: PrecisionSettings> GgufFileRecorder<PS> {
+    /// Sets key remapping.
+    ///
+    /// # Arguments
+    ///
+    /// * `pattern` - The Regex pattern to be replaced.
+    /// * `replacement` - The pattern to replace with.
+    ///
+    /// See [Regex](https://docs.rs/regex/1.5.4/regex/#syntax) for the pattern syntax and
+    /// [Replacement](https://docs.rs/regex/latest/regex/struct.Regex.html#method.replace) for the
+    /// replacement syntax.
+    pub fn with_key_remap(mut self, pattern: &str, replacement: &str) -> Self {
+        let regex = Regex::new(pattern).expect("Valid regex");
+
+        self.key_remap.push((regex, replacement.into()));
+        self
+    }
+
+    /// Sets the top-level key of the tensors to load.
+    ///
+    /// GGUF files store a flat list of tensors, so only the tensors with a name starting with
+    /// `{key}.` are loaded, with the prefix removed from their name.
+    ///
+    /// # Arguments
+    ///
+    /// * `key` - The top-level key of the tensors to load.
+    pub fn with_top_level_key(mut self, key: &str) -> Self {
+        self.top_level_key = Some(key.into());
+        self
+    }
+
+    /// Sets the adapter used to convert the tensors to the Burn modules.
+    ///
+    /// Note that the [PyTorch](AdapterType::PyTorch) adapter transposes the linear weights. The
+    /// quantized blocks of a linear weight are then quantized again along the transposed layout.
+    pub fn with_adapter_type(mut self, adapter_type: AdapterType) -> Self {
+        self.adapter_type = adapter_type;
+        self
+    }
+
+    /// Sets dequantizing all the quantized blocks to float tensors on.
+    pub fn with_dequantize(mut self) -> Self {
+        self.dequantize = true;
+        self
+    }
+
+    /// Sets printing debug information on.
+    pub fn with_debug_print(mut self) -> Self {
+        self.debug = true;
+        self
+    }
+
+    fn load_file<I: DeserializeOwned, B: Backend>(
+        &self,
+        file: PathBuf,
+    ) -> Result<I, RecorderError> {
+        let item = from_file::<PS, I, B>(
+            &file,
+            self.key_remap.clone(),
+            self.top_level_key.as_deref(), // Convert Option<String> to Option<&str>
+            self.adapter_type,
+            self.dequantize,
+            self.debug,
+        )?;
+        Ok(item)
+    }
+}
+
+impl<PS: PrecisionSettings, B: Backend> Recorder<B> for GgufFileRecorder<PS> {
+    type Settings = PS;
+    type RecordArgs = PathBuf;
+    type RecordOutput = ();
+    type LoadArgs = PathBuf;
+
+    fn save_item<I: Serialize>(
+        &self,
+        _item: I,
+        _file: Self::RecordArgs,
+    ) -> Result<(), RecorderError> {
+        Err(RecorderError::Unknown(
+            "Saving GGUF files is not supported".into(),
+        ))
+    }
+
+    fn load_item<I: DeserializeOwned>(&self, mut file: Self::LoadArgs) -> Result<I, RecorderError> {
+        file.set_extension(<Self as FileRecorder<B>>::file_extension());
+        self.load_file::<I, B>(file)
+    }
+
+    fn load<R: Record<B>>(
+        &self,
+        mut file: Self::LoadArgs,
+        device: &B::Device,
+    ) -> Result<R, RecorderError> {
+        // The tensors are not wrapped with the record metadata
+        file.set_extension(<Self as FileRecorder<B>>::file_extension());
+        let item = self.load_file::<R::Item<Self::Settings>, B>(file)?;
+        Ok(R::from_item(item, device))
+    }
+}
+
+impl<PS: PrecisionSettings, B: Backend> FileRecorder<B> for GgufFileRecorder<PS> {
+    fn file_extension() -> &'static str {
+        "gguf"
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    use burn::{
+        backend::NdArray,
+        module::{Module, Param},
+        nn::{Embedding, EmbeddingConfig, Linear, LinearConfig},
+        record::{FullPrecisionSettings, Record},
+        tensor::{Tensor, TensorData},
+    };
+    use candle_core::{
+        quantized::{gguf_file, GgmlDType, QTensor},
+        Device,
+    };
+
+    type TestBackend = NdArray<f32>;
+
+    #[derive(Module, Debug)]
+    struct Net<B: Backend> {
+        embedding: Embedding<B>,
+        fc: Linear<B>,
+    }
+
+    impl<B: Backend> Net<B> {
+        fn new(device: &B::Device) -> Self {
+            Self {
+                embedding: EmbeddingConfig::new(4, 64).init(device),
+                fc: LinearConfig::new(64, 2).init(device),
+            }
+        }
+    }
+
+    #[derive(Record)]
+    struct WeightsRecord<B: Backend> {
+        weight: Param<Tensor<B, 2>>,
+    }
+
+    fn values(num_elements: usize) -> Vec<f32> {
+        (0..num_elements)
+            .map(|i| ((i * 7) % 23) as f32 / 11.0 - 1.0)
+            .collect()
+    }
+
+    /// Quantizes the tensors with the GGUF block formats and writes them to a file.
+    fn write_gguf_file(
+        path: &std::path::Path,
+        tensors: &[(&str, Vec<f32>, Vec<usize>, GgmlDType)],
+    ) {
+        let tensors: Vec<_> = tensors
+            .iter()
+            .map(|(name, values, shape, dtype)| {
+                let tensor =
+                    candle_core::Tensor::from_vec(values.clone(), shape.as_slice(), &Device::Cpu)
+                        .unwrap();
+                (*name, QTensor::quantize(&tensor, *dtype).unwrap())
+            })
+            .collect();
+        let tensors: Vec<_> = tensors.iter().map(|(name, t)| (*name, t)).collect();
+
+        let mut file = std::fs::File::create(path).unwrap();
+        gguf_file::write(&mut file, &[], &tensors).unwrap();
+    }
+
+    /// Loads a single `weight` tensor from a GGUF file.
+    fn load_weight(
+        dtype: GgmlDType,
+        shape: [usize; 2],
+        dequantize: bool,
+    ) -> Tensor<TestBackend, 2> {
+        let dir = tempfile::tempdir().unwrap();
+        let file = dir.path().join("weight.gguf");
+        let num_elements = shape.iter().product();
+        write_gguf_file(
+            &file,
+            &[("weight", values(num_elements), shape.to_vec(), dtype)],
+        );
+
+        let mut recorder = GgufFileRecorder::<FullPrecisionSettings>::default();
+        if dequantize {
+            recorder = recorder.with_dequantize();
+        }
+        let record: WeightsRecord<TestBackend> = recorder
+            .load(file, &Default::default())
+            .expect("Should load the record");
+        record.weight.val()
+    }
+
+    /// Dequantizes the tensor with candle, which is the reference implementation of the blocks.
+    fn reference(dtype: GgmlDType, shape: [usize; 2]) -> TensorData {
+        let num_elements = shape.iter().product();
+        let tensor =
+            candle_core::Tensor::from_vec(values(num_elements), shape.as_slice(), &Device::Cpu)
+                .unwrap();
+        let values = QTensor::quantize(&tensor, dtype)
+            .unwrap()
+            .dequantize(&Device::Cpu)
+            .unwrap()
+            .flatten_all()
+            .unwrap()
+            .to_vec1::<f32>()
+            .unwrap();
+        TensorData::new(values, shape)
+    }
+
+    #[test]
+    fn should_map_q8_0_blocks_to_quantized_tensor() {
+        let weight = load_weight(GgmlDType::Q8_0, [2, 64], false);
+
+        assert!(weight.is_quantized());
+        weight
+            .dequantize()
+            .into_data()
+            .assert_approx_eq(&reference(GgmlDType::Q8_0, [2, 64]), 5);
+    }
+
+    #[test]
+    fn should_map_q4_0_blocks_to_quantized_tensor() {
+        let weight = load_weight(GgmlDType::Q4_0, [2, 64], false);
+
+        assert!(weight.is_quantized());
+        weight
+            .dequantize()
+            .into_data()
+            .assert_approx_eq(&reference(GgmlDType::Q4_0, [2, 64]), 5);
+    }
+
+    #[test]
+    fn should_dequantize_q4_k_blocks() {
+        let weight = load_weight(GgmlDType::Q4K, [2, 256], false);
+
+        assert!(!weight.is_quantized());
+        weight
+            .into_data()
+            .assert_approx_eq(&reference(GgmlDType::Q4K, [2, 256]), 5);
+    }
+
+    #[test]
+    fn should_dequantize_blocks_when_requested() {
+        let weight = load_weight(GgmlDType::Q8_0, [2, 64], true);
+
+        assert!(!weight.is_quantized());
+        weight
+            .into_data()
+            .assert_approx_eq(&reference(GgmlDType::Q8_0, [2, 64]), 5);
+    }
+
+    #[test]
+    fn should_load_module_with_key_remap() {
+        let dir = tempfile::tempdir().unwrap();
+        let file = dir.path().join("net.gguf");
+        write_gguf_file(
+            &file,
+            &[
+                (
+                    "model.token_embd.weight",
+                    values(256),
+                    vec![4, 64],
+                    GgmlDType::Q8_0,
+                ),
+                (
+                    "model.output.weight",
+                    values(128),
+                    vec![2, 64],
+                    GgmlDType::F32,
+                ),
+                (
+                    "model.output.bias",
+                    vec![0.5, -0.5],
+                    vec![2],
+                    GgmlDType::F32,
+                ),
+            ],
+        );
+
+        let device = Default::default();
+        let record: NetRecord<TestBackend> = GgufFileRecorder::<FullPrecisionSettings>::default()
+            .with_top_level_key("model")
+            .with_key_remap("token_embd\\.(.*)", "embedding.$1")
+            .with_key_remap("output\\.(.*)", "fc.$1")
+            .load(file, &device)
+            .expect("Should load the record");
+        let net = Net::<TestBackend>::new(&device).load_record(record);
+
+        // The embedding keeps its layout and stays quantized
+        assert!(net.embedding.weight.val().is_quantized());
+        // The linear weight is transposed to the Burn layout
+        let expected =
+            Tensor::<TestBackend, 2>::from_data(TensorData::new(values(128), [2, 64]), &device)
+                .transpose();
+        net.fc
+            .weight
+            .val()
+            .into_data()
+            .assert_eq(&expected.into_data(), true);
+        net.fc
+            .bias
+            .unwrap()
+            .val()
+            .into_data()
+            .assert_eq(&TensorData::from([0.5f32, -0.5]), true);
+    }
+
+    #[test]
+    fn should_keep_quantized_linear_weight_quantized() {
+        let dir = tempfile::tempdir().unwrap();
+        let file = dir.path().join("linear.gguf");
+        write_gguf_file(
+            &file,
+            &[
+                ("weight", values(256), vec![4, 64], GgmlDType::Q8_0),
+                ("bias", vec![0.0; 4], vec![4], GgmlDType::F32),
+            ],
+        );
+
+        let device = Default::default();
+        let record = GgufFileRecorder::<FullPrecisionSettings>::default()
+            .load(file, &device)
+            .expect("Should load the record");
+        let linear = LinearConfig::new(64, 4)
+            .init::<TestBackend>(&device)
+            .load_record(record);
+
+        let weight = linear.weight.val();
+        assert!(weight.is_quantized());
+        assert_eq!(weight.dims(), [64, 4]);
+        let expected =
+            Tensor::<TestBackend, 2>::from_data(reference(GgmlDType::Q8_0, [4, 64]), &device)
+                .transpose();
+        weight
+            .dequantize()
+            .into_data()
+            .assert_approx_eq_diff(&expected.into_data(), 0.01);
+    }
+
+    #[test]
+    fn should_return_file_not_found() {
+        let result: Result<NetRecord<TestBackend>, _> =
+            GgufFileRecorder::<FullPrecisionSettings>::default()
+                .load("/does/not/exist".into(), &Default::default());
+
+        assert!(matches!(result, Err(RecorderError::FileNotFound(_))));
+    }
+}
@@ -25,9 +25,21 @@ pub mod onnx;
 #[cfg(feature = "onnx")]
 pub mod burn;
 
+// Shared by the PyTorch, Safetensors and GGUF recorders.
+#[cfg(any(feature = "pytorch", feature = "safetensors", feature = "gguf"))]
+mod common;
+
 /// The PyTorch module for recorder.
 #[cfg(feature = "pytorch")]
 pub mod pytorch;
 
+/// The Safetensors module for recorder.
+#[cfg(feature = "safetensors")]
+pub mod safetensors;
+
+/// The GGUF module for recorder.
+#[cfg(feature = "gguf")]
+pub mod gguf;
+
 mod formatter;
 pub use formatter::*;
@@ -0,0 +1,1024 @@
+use std::collections::{HashMap, HashSet};
+
+use burn::tensor::{
+    repr::{
+        BaseOperationDescription, BinaryOperationDescription, BoolOperationDescription,
+        FloatOperationDescription, IntOperationDescription, InterpolateModeDescription,
+        ModuleOperationDescription, NumericOperationDescription, OperationDescription,
+        ScalarOperationDescription, TensorDescription, TensorId, UnaryOperationDescription,
+    },
+    DType, Distribution, Element, TensorData,
+};
+use onnx_ir::protos::{
+    attribute_proto::AttributeType, tensor_proto::DataType, AttributeProto, NodeProto, TensorProto,
+};
+
+use super::OnnxExportError;
+
+/// Converts the traced [operations](OperationDescription) into ONNX nodes.
+///
+/// Each tensor is named after the graph input, output or parameter it corresponds to. The
+/// intermediate tensors and the constants created by the conversion get generated names.
+#[derive(Default)]
+pub(crate) struct GraphBuilder {
+    pub(crate) nodes: Vec<NodeProto>,
+    pub(crate) initializers: Vec<TensorProto>,
+    names: HashMap<TensorId, String>,
+    used: HashSet<TensorId>,
+    num_values: usize,
+    num_constants: usize,
+}
+
+impl GraphBuilder {
+    /// Gives a name to a tensor existing before the traced operations.
+    pub(crate) fn register(&mut self, tensor: &TensorDescription, name: String) {
+        self.names.insert(tensor.id, name);
+    }
+
+    /// Whether the tensor is used by one of the converted operations.
+    pub(crate) fn is_used(&self, tensor: &TensorDescription) -> bool {
+        self.used.contains(&tensor.id)
+    }
+
+    /// Adds an initializer with the name of a tensor existing before the traced operations.
+    pub(crate) fn initializer(
+        &mut self,
+        name: String,
+        data: &TensorData,
+    ) -> Result<(), OnnxExportError> {
+        let tensor = tensor_proto(name, data)?;
+        self.initializers.push(tensor);
+        Ok(())
+    }
+
+    /// Converts the operation into one or more nodes.
+    pub(crate) fn operation(&mut self, op: &OperationDescription) -> Result<(), OnnxExportError> {
+        match op {
+            OperationDescription::BaseFloat(op)
+            | OperationDescription::BaseInt(op)
+            | OperationDescription::BaseBool(op) => self.base(op),
+            OperationDescription::NumericFloat(op) => self.numeric(op),
+            OperationDescription::NumericInt(op) => self.numeric(op),
+            OperationDescription::Bool(op) => match op {
+                BoolOperationDescription::IntoFloat(desc)
+                | BoolOperationDescription::IntoInt(desc) => self.cast(desc),
+                BoolOperationDescription::Not(desc) => self.unary("Not", desc),
+            },
+            OperationDescription::Int(op) => match op {
+                IntOperationDescription::IntoFloat(desc) => self.cast(desc),
+                IntOperationDescription::FloorDiv(desc) => {
+                    let lhs = self.input(&desc.lhs)?;
+                    let rhs = self.input(&desc.rhs)?;
+                    self.floor_div(lhs, rhs, &desc.out)
+                }
+                IntOperationDescription::FloorDivScalar(desc) => {
+                    let lhs = self.input(&desc.lhs)?;
+                    let rhs = self.scalar(desc.rhs as f64, &desc.lhs.dtype, vec![])?;
+                    self.floor_div(lhs, rhs, &desc.out)
+                }
+                IntOperationDescription::Remainder(desc) => {
+                    // The integer modulo has the sign of the divisor, like Burn
+                    let inputs = vec![self.input(&desc.lhs)?, self.input(&desc.rhs)?];
+                    self.node("Mod", inputs, &desc.out, vec![int("fmod", 0)]);
+                    Ok(())
+                }
+            },
+            OperationDescription::Float(op) => self.float(op),
+            OperationDescription::Module(op) => self.module(op),
+        }
+    }
+
+    fn base(&mut self, op: &BaseOperationDescription) -> Result<(), OnnxExportError> {
+        match op {
+            // The tensor keeps its id on the other device
+            BaseOperationDescription::ToDevice(_) => Ok(()),
+            BaseOperationDescription::Reshape(desc) => {
+                let shape = self.shape(&desc.out.shape);
+                let input = self.input(&desc.input)?;
+                self.node("Reshape", vec![input, shape], &desc.out, vec![]);
+                Ok(())
+            }
+            BaseOperationDescription::SwapDims(desc) => {
+                let mut perm = (0..desc.input.shape.len() as i64).collect::<Vec<_>>();
+                perm.swap(desc.dim1, desc.dim2);
+                let input = self.input(&desc.input)?;
+                self.node(
+                    "Transpose",
+                    vec![input],
+                    &desc.out,
+                    vec![ints("perm", perm)],
+                );
+                Ok(())
+            }
+            BaseOperationDescription::Permute(desc) => {
+                let perm = desc.axes.iter().map(|axis| *axis as i64).collect();
+                let input = self.input(&desc.input)?;
+                self.node(
+                    "Transpose",
+                    vec![input],
+                    &desc.out,
+                    vec![ints("perm", perm)],
+                );
+                Ok(())
+            }
+            BaseOperationDescription::Flip(desc) => {
+                let num_axes = desc.axes.len();
+                let input = self.input(&desc.input)?;
+                let starts = self.int64s(vec![-1; num_axes]);
+                let ends = self.int64s(vec![i64::MIN; num_axes]);
+                let axes = self.int64s(desc.axes.iter().map(|axis| *axis as i64).collect());
+                let steps = self.int64s(vec![-1; num_axes]);
+                self.node(
+                    "Slice",
+                    vec![input, starts, ends, axes, steps],
+                    &desc.out,
+                    vec![],
+                );
+                Ok(())
+            }
+            BaseOperationDescription::Expand(desc) => {
+                let shape = self.shape(&desc.shape);
+                let input = self.input(&desc.input)?;
+                self.node("Expand", vec![input, shape], &desc.out, vec![]);
+                Ok(())
+            }
+            BaseOperationDescription::Slice(desc) => {
+                let input = self.input(&desc.tensor)?;
+                let starts = self.int64s(desc.ranges.iter().map(|r| r.start as i64).collect());
+                let ends = self.int64s(desc.ranges.iter().map(|r| r.end as i64).collect());
+                let axes = self.int64s((0..desc.ranges.len() as i64).collect());
+                self.node("Slice", vec![input, starts, ends, axes], &desc.out, vec![]);
+                Ok(())
+            }
+            BaseOperationDescription::Equal(desc) => self.binary("Equal", desc),
+            BaseOperationDescription::Repeat(desc) => {
+                let mut repeats = vec![1; desc.tensor.shape.len()];
+                repeats[desc.dim] = desc.times as i64;
+                let input = self.input(&desc.tensor)?;
+                let repeats = self.int64s(repeats);
+                self.node("Tile", vec![input, repeats], &desc.out, vec![]);
+                Ok(())
+            }
+            BaseOperationDescription::Cat(desc) => {
+                let inputs = desc
+                    .tensors
+                    .iter()
+                    .map(|tensor| self.input(tensor))
+                    .collect::<Result<_, _>>()?;
+                self.node(
+                    "Concat",
+                    inputs,
+                    &desc.out,
+                    vec![int("axis", desc.dim as i64)],
+                );
+                Ok(())
+            }
+            BaseOperationDescription::Cast(desc) => self.cast(desc),
+            BaseOperationDescription::SliceAssign(_) => unsupported("SliceAssign"),
+        }
+    }
+
+    fn numeric<E: Element>(
+        &mut self,
+        op: &NumericOperationDescription<E>,
+    ) -> Result<(), OnnxExportError> {
+        match op {
+            NumericOperationDescription::Add(desc) => self.binary("Add", desc),
+            NumericOperationDescription::AddScalar(desc) => self.binary_scalar("Add", desc),
+            NumericOperationDescription::Sub(desc) => self.binary("Sub", desc),
+            NumericOperationDescription::SubScalar(desc) => self.binary_scalar("Sub", desc),
+            NumericOperationDescription::Mul(desc) => self.binary("Mul", desc),
+            NumericOperationDescription::MulScalar(desc) => self.binary_scalar("Mul", desc),
+            NumericOperationDescription::Div(desc) => self.binary("Div", desc),
+            NumericOperationDescription::DivScalar(desc) => self.binary_scalar("Div", desc),
+            NumericOperationDescription::RemScalar(desc) => self.remainder_scalar(desc),
+            NumericOperationDescription::Abs(desc) => self.unary("Abs", desc),
+            NumericOperationDescription::Ones(out) => self.constant_of_shape(out, 1.0),
+            NumericOperationDescription::Zeros(out) => self.constant_of_shape(out, 0.0),
+            NumericOperationDescription::Full((out, value)) => {
+                self.constant_of_shape(out, value.to_f64())
+            }
+            NumericOperationDescription::Gather(desc) => {
+                let inputs = vec![self.input(&desc.tensor)?, self.input(&desc.indices)?];
+                let attrs = vec![int("axis", desc.dim as i64)];
+                self.node("GatherElements", inputs, &desc.out, attrs);
+                Ok(())
+            }
+            NumericOperationDescription::Scatter(desc) => {
+                let inputs = vec![
+                    self.input(&desc.tensor)?,
+                    self.input(&desc.indices)?,
+                    self.input(&desc.value)?,
+                ];
+                let attrs = vec![int("axis", desc.dim as i64), string("reduction", "add")];
+                self.node("ScatterElements", inputs, &desc.out, attrs);
+                Ok(())
+            }
+            NumericOperationDescription::Select(desc) => {
+                let inputs = vec![self.input(&desc.tensor)?, self.input(&desc.indices)?];
+                self.node(
+                    "Gather",
+                    inputs,
+                    &desc.out,
+                    vec![int("axis", desc.dim as i64)],
+                );
+                Ok(())
+            }
+            NumericOperationDescription::MaskWhere(desc) => {
+                let inputs = vec![
+                    self.input(&desc.mask)?,
+                    self.input(&desc.value)?,
+                    self.input(&desc.tensor)?,
+                ];
+                self.node("Where", inputs, &desc.out, vec![]);
+                Ok(())
+            }
+            NumericOperationDescription::MaskFill(desc) => {
+                let value = self.scalar(
+                    desc.value.to_f64(),
+                    &desc.tensor.dtype,
+                    vec![1; desc.tensor.shape.len()],
+                )?;
+                let inputs = vec![self.input(&desc.mask)?, value, self.input(&desc.tensor)?];
+                self.node("Where", inputs, &desc.out, vec![]);
+                Ok(())
+            }
+            NumericOperationDescription::Mean(desc) => self.reduce("ReduceMean", desc),
+            NumericOperationDescription::Sum(desc) => self.reduce("ReduceSum", desc),
+            NumericOperationDescription::Prod(desc) => self.reduce("ReduceProd", desc),
+            NumericOperationDescription::Max(desc) => self.reduce("ReduceMax", desc),
+            NumericOperationDescription::Min(desc) => self.reduce("ReduceMin", desc),
+            NumericOperationDescription::MeanDim(desc) => self.reduce_dim("ReduceMean", desc),
+            NumericOperationDescription::SumDim(desc) => self.reduce_dim("ReduceSum", desc),
+            NumericOperationDescription::ProdDim(desc) => self.reduce_dim("ReduceProd", desc),
+            NumericOperationDescription::MaxDim(desc) => self.reduce_dim("ReduceMax", desc),
+            NumericOperationDescription::MinDim(desc) => self.reduce_dim("ReduceMin", desc),
+            NumericOperationDescription::ArgMax(desc) => self.arg_reduce("ArgMax", desc),
+            NumericOperationDescription::ArgMin(desc) => self.arg_reduce("ArgMin", desc),
+            NumericOperationDescription::MaxDimWithIndices(desc) => {
+                let dim = ScalarOperationDescription {
+                    lhs: desc.tensor.clone(),
+                    rhs: desc.dim,
+                    out: desc.out.clone(),
+                };
+                self.reduce_dim("ReduceMax", &dim)?;
+                self.arg_reduce(
+                    "ArgMax",
+                    &ScalarOperationDescription {
+                        out: desc.out_indices.clone(),
+                        ..dim
+                    },
+                )
+            }
+            NumericOperationDescription::MinDimWithIndices(desc) => {
+                let dim = ScalarOperationDescription {
+                    lhs: desc.tensor.clone(),
+                    rhs: desc.dim,
+                    out: desc.out.clone(),
+                };
+                self.reduce_dim("ReduceMin", &dim)?;
+                self.arg_reduce(
+                    "ArgMin",
+                    &ScalarOperationDescription {
+                        out: desc.out_indices.clone(),
+                        ..dim
+                    },
+                )
+            }
+            NumericOperationDescription::EqualElem(desc) => self.compare_scalar("Equal", desc),
+            NumericOperationDescription::Greater(desc) => self.binary("Greater", desc),
+            NumericOperationDescription::GreaterElem(desc) => self.compare_scalar("Greater", desc),
+            NumericOperationDescription::GreaterEqual(desc) => self.binary("GreaterOrEqual", desc),
+            NumericOperationDescription::GreaterEqualElem(desc) => {
+                self.compare_scalar("GreaterOrEqual", desc)
+            }
+            NumericOperationDescription::Lower(desc) => self.binary("Less", desc),
+            NumericOperationDescription::LowerElem(desc) => self.compare_scalar("Less", desc),
+            NumericOperationDescription::LowerEqual(desc) => self.binary("LessOrEqual", desc),
+            NumericOperationDescription::LowerEqualElem(desc) => {
+                self.compare_scalar("LessOrEqual", desc)
+            }
+            NumericOperationDescription::Clamp(desc) => {
+                let input = self.input(&desc.tensor)?;
+                let min = self.scalar(desc.min.to_f64(), &desc.tensor.dtype, vec![])?;
+                let max = self.scalar(desc.max.to_f64(), &desc.tensor.dtype, vec![])?;
+                self.node("Clip", vec![input, min, max], &desc.out, vec![]);
+                Ok(())
+            }
+            NumericOperationDescription::Powf(desc) => self.binary("Pow", desc),
+            NumericOperationDescription::SelectAssign(_) => unsupported("SelectAssign"),
+            NumericOperationDescription::IntRandom(_) => unsupported("IntRandom"),
+            NumericOperationDescription::CumSum(desc) => {
+                let input = self.input(&desc.lhs)?;
+                let axis = self.scalar(desc.rhs as f64, &DType::I64, vec![])?;
+                self.node("CumSum", vec![input, axis], &desc.out, vec![]);
+                Ok(())
+            }
+            NumericOperationDescription::CumProd(_) => unsupported("CumProd"),
+            NumericOperationDescription::CumMax(_) => unsupported("CumMax"),
+            NumericOperationDescription::CumMin(_) => unsupported("CumMin"),
+        }
+    }
+
+    fn float(&mut self, op: &FloatOperationDescription) -> Result<(), OnnxExportError> {
+        match op {
+            FloatOperationDescription::Exp(desc) => self.unary("Exp", desc),
+            FloatOperationDescription::Log(desc) => self.unary("Log", desc),
+            FloatOperationDescription::Log1p(desc) => {
+                let one = self.scalar(1.0, &desc.input.dtype, vec![])?;
+                let input = self.input(&desc.input)?;
+                let sum = self.value();
+                self.push_node("Add", vec![input, one], vec![sum.clone()], vec![]);
+                self.node("Log", vec![sum], &desc.out, vec![]);
+                Ok(())
+            }
+            FloatOperationDescription::Erf(desc) => self.unary("Erf", desc),
+            FloatOperationDescription::PowfScalar(desc) => self.binary_scalar("Pow", desc),
+            FloatOperationDescription::Sqrt(desc) => self.unary("Sqrt", desc),
+            FloatOperationDescription::Cos(desc) => self.unary("Cos", desc),
+            FloatOperationDescription::Sin(desc) => self.unary("Sin", desc),
+            FloatOperationDescription::Tanh(desc) => self.unary("Tanh", desc),
+            FloatOperationDescription::IntoInt(desc) => self.cast(desc),
+            FloatOperationDescription::Matmul(desc) => self.binary("MatMul", desc),
+            FloatOperationDescription::Recip(desc) => self.unary("Reciprocal", desc),
+            FloatOperationDescription::Floor(desc) => self.unary("Floor", desc),
+            FloatOperationDescription::Ceil(desc) => self.unary("Ceil", desc),
+            FloatOperationDescription::Round(desc) => self.unary("Round", desc),
+            FloatOperationDescription::Trunc(desc) => {
+                // There is no truncation operator, the fractional part is removed instead
+                let one = self.scalar(1.0, &desc.input.dtype, vec![])?;
+                let input = self.input(&desc.input)?;
+                let fraction = self.value();
+                self.push_node(
+                    "Mod",
+                    vec![input.clone(), one],
+                    vec![fraction.clone()],
+                    vec![int("fmod", 1)],
+                );
+                self.node("Sub", vec![input, fraction], &desc.out, vec![]);
+                Ok(())
+            }
+            FloatOperationDescription::Fmod(desc) => {
+                let inputs = vec![self.input(&desc.lhs)?, self.input(&desc.rhs)?];
+                self.node("Mod", inputs, &desc.out, vec![int("fmod", 1)]);
+                Ok(())
+            }
+            FloatOperationDescription::FmodScalar(desc) => {
+                let lhs = self.input(&desc.lhs)?;
+                let rhs = self.scalar(desc.rhs as f64, &desc.lhs.dtype, vec![])?;
+                self.node("Mod", vec![lhs, rhs], &desc.out, vec![int("fmod", 1)]);
+                Ok(())
+            }
+            FloatOperationDescription::Random(desc) => {
+                let mut attrs = vec![
+                    ints("shape", desc.out.shape.iter().map(|d| *d as i64).collect()),
+                    int("dtype", elem_type(&desc.out.dtype)? as i64),
+                ];
+                let op_type = match desc.distribution {
+                    Distribution::Default => "RandomUniform",
+                    Distribution::Uniform(low, high) => {
+                        attrs.push(float("low", low as f32));
+                        attrs.push(float("high", high as f32));
+                        "RandomUniform"
+                    }
+                    Distribution::Normal(mean, std) => {
+                        attrs.push(float("mean", mean as f32));
+                        attrs.push(float("scale", std as f32));
+                        "RandomNormal"
+                    }
+                    Distribution::Bernoulli(_) => return unsupported("Random (Bernoulli)"),
+                };
+                self.node(op_type, vec![], &desc.out, attrs);
+                Ok(())
+            }
+            // The DFT operator requires the opset 17
+            FloatOperationDescription::Fft(_) => unsupported("Fft"),
+            FloatOperationDescription::LinalgDet(desc) => {
+                // The determinants are returned without the two matrix dimensions
+                let input = self.input(&desc.input)?;
+                let det = self.value();
+                self.push_node("Det", vec![input], vec![det.clone()], vec![]);
+                let shape = self.shape(&desc.out.shape);
+                self.node("Reshape", vec![det, shape], &desc.out, vec![]);
+                Ok(())
+            }
+            FloatOperationDescription::LinalgInv(_) => unsupported("LinalgInv"),
+            FloatOperationDescription::LinalgSlogdet(_) => unsupported("LinalgSlogdet"),
+            FloatOperationDescription::LinalgSolve(_) => unsupported("LinalgSolve"),
+            FloatOperationDescription::LinalgCholesky(_) => unsupported("LinalgCholesky"),
+            FloatOperationDescription::LinalgQr(_) => unsupported("LinalgQr"),
+            FloatOperationDescription::LinalgSvd(_) => unsupported("LinalgSvd"),
+            FloatOperationDescription::LinalgEigh(_) => unsupported("LinalgEigh"),
+        }
+    }
+
+    fn module(&mut self, op: &ModuleOperationDescription) -> Result<(), OnnxExportError> {
+        match op {
+            ModuleOperationDescription::Embedding(desc) => {
+                let inputs = vec![self.input(&desc.weights)?, self.input(&desc.indices)?];
+                self.node("Gather", inputs, &desc.out, vec![int("axis", 0)]);
+                Ok(())
+            }
+            ModuleOperationDescription::Conv1d(desc) => {
+                let options = &desc.options;
+                let attrs = conv_attrs(
+                    &desc.weight,
+                    &options.stride,
+                    &options.padding,
+                    &options.dilation,
+                    options.groups,
+                );
+                self.conv("Conv", &desc.x, &desc.weight, &desc.bias, &desc.out, attrs)
+            }
+            ModuleOperationDescription::Conv2d(desc) => {
+                let options = &desc.options;
+                let attrs = conv_attrs(
+                    &desc.weight,
+                    &options.stride,
+                    &options.padding,
+                    &options.dilation,
+                    options.groups,
+                );
+                self.conv("Conv", &desc.x, &desc.weight, &desc.bias, &desc.out, attrs)
+            }
+            ModuleOperationDescription::Conv3d(desc) => {
+                let options = &desc.options;
+                let attrs = conv_attrs(
+                    &desc.weight,
+                    &options.stride,
+                    &options.padding,
+                    &options.dilation,
+                    options.groups,
+                );
+                self.conv("Conv", &desc.x, &desc.weight, &desc.bias, &desc.out, attrs)
+            }
+            ModuleOperationDescription::ConvTranspose1d(desc) => {
+                let options = &desc.options;
+                let mut attrs = conv_attrs(
+                    &desc.weight,
+                    &options.stride,
+                    &options.padding,
+                    &options.dilation,
+                    options.groups,
+                );
+                attrs.push(ints("output_padding", to_i64s(&options.padding_out)));
+                self.conv(
+                    "ConvTranspose",
+                    &desc.x,
+                    &desc.weight,
+                    &desc.bias,
+                    &desc.out,
+                    attrs,
+                )
+            }
+            ModuleOperationDescription::ConvTranspose2d(desc) => {
+                let options = &desc.options;
+                let mut attrs = conv_attrs(
+                    &desc.weight,
+                    &options.stride,
+                    &options.padding,
+                    &options.dilation,
+                    options.groups,
+                );
+                attrs.push(ints("output_padding", to_i64s(&options.padding_out)));
+                self.conv(
+                    "ConvTranspose",
+                    &desc.x,
+                    &desc.weight,
+                    &desc.bias,
+                    &desc.out,
+                    attrs,
+                )
+            }
+            ModuleOperationDescription::ConvTranspose3d(desc) => {
+                let options = &desc.options;
+                let mut attrs = conv_attrs(
+                    &desc.weight,
+                    &options.stride,
+                    &options.padding,
+                    &options.dilation,
+                    options.groups,
+                );
+                attrs.push(ints("output_padding", to_i64s(&options.padding_out)));
+                self.conv(
+                    "ConvTranspose",
+                    &desc.x,
+                    &desc.weight,
+                    &desc.bias,
+                    &desc.out,
+                    attrs,
+                )
+            }
+            ModuleOperationDescription::AvgPool1d(desc) => {
+                let attrs = vec![
+                    ints("kernel_shape", vec![desc.kernel_size as i64]),
+                    ints("strides", vec![desc.stride as i64]),
+                    ints("pads", pads(&[desc.padding])),
+                    int("count_include_pad", desc.count_include_pad as i64),
+                ];
+                self.unary_with_attrs("AveragePool", &desc.x, &desc.out, attrs)
+            }
+            ModuleOperationDescription::AvgPool2d(desc) => {
+                let attrs = vec![
+                    ints("kernel_shape", to_i64s(&desc.kernel_size)),
+                    ints("strides", to_i64s(&desc.stride)),
+                    ints("pads", pads(&desc.padding)),
+                    int("count_include_pad", desc.count_include_pad as i64),
+                ];
+                self.unary_with_attrs("AveragePool", &desc.x, &desc.out, attrs)
+            }
+            ModuleOperationDescription::AdaptiveAvgPool1d(desc) if desc.output_size == 1 => {
+                self.unary_with_attrs("GlobalAveragePool", &desc.x, &desc.out, vec![])
+            }
+            ModuleOperationDescription::AdaptiveAvgPool2d(desc) if desc.output_size == [1, 1] => {
+                self.unary_with_attrs("GlobalAveragePool", &desc.x, &desc.out, vec![])
+            }
+            ModuleOperationDescription::MaxPool1d(desc) => {
+                let attrs = vec![
+                    ints("kernel_shape", vec![desc.kernel_size as i64]),
+                    ints("strides", vec![desc.stride as i64]),
+                    ints("pads", pads(&[desc.padding])),
+                    ints("dilations", vec![desc.dilation as i64]),
+                ];
+                self.unary_with_attrs("MaxPool", &desc.x, &desc.out, attrs)
+            }
+            ModuleOperationDescription::MaxPool2d(desc) => {
+                let attrs = vec![
+                    ints("kernel_shape", to_i64s(&desc.kernel_size)),
+                    ints("strides", to_i64s(&desc.stride)),
+                    ints("pads", pads(&desc.padding)),
+                    ints("dilations", to_i64s(&desc.dilation)),
+                ];
+                self.unary_with_attrs("MaxPool", &desc.x, &desc.out, attrs)
+            }
+            ModuleOperationDescription::Interpolate(desc) => {
+                // Burn aligns the corners of the input and output for the (bi)linear and cubic
+                // modes, and rounds down the source index for the nearest mode.
+                let attrs = match desc.options.mode {
+                    InterpolateModeDescription::Nearest => vec![
+                        string("mode", "nearest"),
+                        string("coordinate_transformation_mode", "asymmetric"),
+                        string("nearest_mode", "floor"),
+                    ],
+                    InterpolateModeDescription::Bilinear => vec![
+                        string("mode", "linear"),
+                        string("coordinate_transformation_mode", "align_corners"),
+                    ],
+                    InterpolateModeDescription::Bicubic => vec![
+                        string("mode", "cubic"),
+                        string("coordinate_transformation_mode", "align_corners"),
+                    ],
+                };
+                let input = self.input(&desc.x)?;
+                let sizes = self.shape(&desc.out.shape);
+                let inputs = vec![input, String::new(), String::new(), sizes];
+                self.node("Resize", inputs, &desc.out, attrs);
+                Ok(())
+            }
+            ModuleOperationDescription::AdaptiveAvgPool1d(_)
+            | ModuleOperationDescription::AdaptiveAvgPool2d(_) => {
+                unsupported("AdaptiveAvgPool with an output size other than 1")
+            }
+            ModuleOperationDescription::MaxPool1dWithIndices(_)
+            | ModuleOperationDescription::MaxPool2dWithIndices(_) => {
+                unsupported("MaxPool with indices")
+            }
+            ModuleOperationDescription::EmbeddingBackward(_)
+            | ModuleOperationDescription::AvgPool1dBackward(_)
+            | ModuleOperationDescription::AvgPool2dBackward(_)
+            | ModuleOperationDescription::AdaptiveAvgPool1dBackward(_)
+            | ModuleOperationDescription::AdaptiveAvgPool2dBackward(_)
+            | ModuleOperationDescription::MaxPool1dWithIndicesBackward(_)
+            | ModuleOperationDescription::MaxPool2dWithIndicesBackward(_)
+            | ModuleOperationDescription::InterpolateBackward(_) => {
+                unsupported("Backward pass operations")
+            }
+        }
+    }
+
+    fn unary(
+        &mut self,
+        op_type: &str,
+        desc: &UnaryOperationDescription,
+    ) -> Result<(), OnnxExportError> {
+        self.unary_with_attrs(op_type, &desc.input, &desc.out, vec![])
+    }
+
+    fn unary_with_attrs(
+        &mut self,
+        op_type: &str,
+        input: &TensorDescription,
+        out: &TensorDescription,
+        attrs: Vec<AttributeProto>,
+    ) -> Result<(), OnnxExportError> {
+        let input = self.input(input)?;
+        self.node(op_type, vec![input], out, attrs);
+        Ok(())
+    }
+
+    fn binary(
+        &mut self,
+        op_type: &str,
+        desc: &BinaryOperationDescription,
+    ) -> Result<(), OnnxExportError> {
+        let inputs = vec![self.input(&desc.lhs)?, self.input(&desc.rhs)?];
+        self.node(op_type, inputs, &desc.out, vec![]);
+        Ok(())
+    }
+
+    fn binary_scalar<E: Element>(
+        &mut self,
+        op_type: &str,
+        desc: &ScalarOperationDescription<E>,
+    ) -> Result<(), OnnxExportError> {
+        let lhs = self.input(&desc.lhs)?;
+        let rhs = self.scalar(desc.rhs.to_f64(), &desc.lhs.dtype, vec![])?;
+        self.node(op_type, vec![lhs, rhs], &desc.out, vec![]);
+        Ok(())
+    }
+
+    /// Compares with a scalar broadcast as a tensor of the same rank, since the comparisons with
+    /// a rank 0 tensor aren't supported when importing the model.
+    fn compare_scalar<E: Element>(
+        &mut self,
+        op_type: &str,
+        desc: &ScalarOperationDescription<E>,
+    ) -> Result<(), OnnxExportError> {
+        let lhs = self.input(&desc.lhs)?;
+        let shape = vec![1; desc.lhs.shape.len()];
+        let rhs = self.scalar(desc.rhs.to_f64(), &desc.lhs.dtype, shape)?;
+        self.node(op_type, vec![lhs, rhs], &desc.out, vec![]);
+        Ok(())
+    }
+
+    fn remainder_scalar<E: Element>(
+        &mut self,
+        desc: &ScalarOperationDescription<E>,
+    ) -> Result<(), OnnxExportError> {
+        let lhs = self.input(&desc.lhs)?;
+        let rhs = self.scalar(desc.rhs.to_f64(), &desc.lhs.dtype, vec![])?;
+
+        if !is_float(&desc.lhs.dtype) {
+            // The integer modulo has the sign of the divisor, like Burn
+            self.node("Mod", vec![lhs, rhs], &desc.out, vec![int("fmod", 0)]);
+            return Ok(());
+        }
+
+        // The float modulo has the sign of the dividend, so the remainder is computed as
+        // `((lhs % rhs) + rhs) % rhs` to have the sign of the divisor.
+        let fmod = self.value();
+        self.push_node(
+            "Mod",
+            vec![lhs, rhs.clone()],
+            vec![fmod.clone()],
+            vec![int("fmod", 1)],
+        );
+        let sum = self.value();
+        self.push_node("Add", vec![fmod, rhs.clone()], vec![sum.clone()], vec![]);
+        self.node("Mod", vec![sum, rhs], &desc.out, vec![int("fmod", 1)]);
+        Ok(())
+    }
+
+    /// The integer modulo has the sign of the divisor, so subtracting it from the dividend gives
+    /// a multiple of the divisor and the division is exact.
+    fn floor_div(
+        &mut self,
+        lhs: String,
+        rhs: String,
+        out: &TensorDescription,
+    ) -> Result<(), OnnxExportError> {
+        let modulo = self.value();
+        self.push_node(
+            "Mod",
+            vec![lhs.clone(), rhs.clone()],
+            vec![modulo.clone()],
+            vec![int("fmod", 0)],
+        );
+        let multiple = self.value();
+        self.push_node("Sub", vec![lhs, modulo], vec![multiple.clone()], vec![]);
+        self.node("Div", vec![multiple, rhs], out, vec![]);
+        Ok(())
+    }
+
+    fn cast(&mut self, desc: &UnaryOperationDescription) -> Result<(), OnnxExportError> {
+        let to = elem_type(&desc.out.dtype)? as i64;
+        self.unary_with_attrs("Cast", &desc.input, &desc.out, vec![int("to", to)])
+    }
+
+    fn constant_of_shape(
+        &mut self,
+        out: &TensorDescription,
+        value: f64,
+    ) -> Result<(), OnnxExportError> {
+        let shape = self.shape(&out.shape);
+        let value = scalar_proto(String::new(), value, &out.dtype, vec![1])?;
+        self.node(
+            "ConstantOfShape",
+            vec![shape],
+            out,
+            vec![tensor("value", value)],
+        );
+        Ok(())
+    }
+
+    /// Reduces all the elements into a tensor of shape `[1]`, like Burn.
+    fn reduce(
+        &mut self,
+        op_type: &str,
+        desc: &UnaryOperationDescription,
+    ) -> Result<(), OnnxExportError> {
+        let input = self.input(&desc.input)?;
+        let reduced = self.value();
+        self.push_node(
+            op_type,
+            vec![input],
+            vec![reduced.clone()],
+            vec![int("keepdims", 0)],
+        );
+        let shape = self.shape(&desc.out.shape);
+        self.node("Reshape", vec![reduced, shape], &desc.out, vec![]);
+        Ok(())
+    }
+
+    /// Reduces a dimension, keeping it with a size of 1 like Burn.
+    fn reduce_dim(
+        &mut self,
+        op_type: &str,
+        desc: &ScalarOperationDescription<usize>,
+    ) -> Result<(), OnnxExportError> {
+        let mut inputs = vec![self.input(&desc.lhs)?];
+        let mut attrs = vec![int("keepdims", 1)];
+
+        // Only ReduceSum takes the axes as an input before opset 18
+        match op_type {
+            "ReduceSum" => inputs.push(self.int64s(vec![desc.rhs as i64])),
+            _ => attrs.push(ints("axes", vec![desc.rhs as i64])),
+        }
+
+        self.node(op_type, inputs, &desc.out, attrs);
+        Ok(())
+    }
+
+    fn arg_reduce(
+        &mut self,
+        op_type: &str,
+        desc: &ScalarOperationDescription<usize>,
+    ) -> Result<(), OnnxExportError> {
+        let input = self.input(&desc.lhs)?;
+        let attrs = vec![int("axis", desc.rhs as i64), int("keepdims", 1)];
+
+        // The indices are always 64-bit integers in ONNX
+        if desc.out.dtype == DType::I64 {
+            self.node(op_type, vec![input], &desc.out, attrs);
+            return Ok(());
+        }
+
+        let indices = self.value();
+        self.push_node(op_type, vec![input], vec![indices.clone()], attrs);
+        let to = elem_type(&desc.out.dtype)? as i64;
+        self.node("Cast", vec![indices], &desc.out, vec![int("to", to)]);
+        Ok(())
+    }
+
+    fn conv(
+        &mut self,
+        op_type: &str,
+        x: &TensorDescription,
+        weight: &TensorDescription,
+        bias: &Option<TensorDescription>,
+        out: &TensorDescription,
+        attrs: Vec<AttributeProto>,
+    ) -> Result<(), OnnxExportError> {
+        let mut inputs = vec![self.input(x)?, self.input(weight)?];
+        if let Some(bias) = bias {
+            inputs.push(self.input(bias)?);
+        }
+        self.node(op_type, inputs, out, attrs);
+        Ok(())
+    }
+
+    /// The name of a tensor used by an operation, which must have been created before.
+    fn input(&mut self, tensor: &TensorDescription) -> Result<String, OnnxExportError> {
+        self.used.insert(tensor.id);
+        self.names
+            .get(&tensor.id)
+            .cloned()
+            .ok_or_else(|| OnnxExportError::UnknownTensor(format!("{:?}", tensor.id)))
+    }
+
+    /// The name of a tensor created by an operation.
+    fn output(&mut self, tensor: &TensorDescription) -> String {
+        if let Some(name) = self.names.get(&tensor.id) {
+            return name.clone();
+        }
+
+        let name = self.value();
+        self.names.insert(tensor.id, name.clone());
+        name
+    }
+
+    /// A new name for an intermediate value.
+    fn value(&mut self) -> String {
+        self.num_values += 1;
+        format!("value{}", self.num_values)
+    }
+
+    fn node(
+        &mut self,
+        op_type: &str,
+        inputs: Vec<String>,
+        out: &TensorDescription,
+        attrs: Vec<AttributeProto>,
+    ) {
+        let output = self.output(out);
+        self.push_node(op_type, inputs, vec![output], attrs);
+    }
+
+    fn push_node(
+        &mut self,
+        op_type: &str,
+        inputs: Vec<String>,
+        outputs: Vec<String>,
+        attrs: Vec<AttributeProto>,
+    ) {
+        let node = NodeProto {
+            name: format!("/{}_{}", op_type, self.nodes.len()),
+            op_type: op_type.to_string(),
+            input: inputs,
+            output: outputs,
+            attribute: attrs,
+            ..Default::default()
+        };
+        self.nodes.push(node);
+    }
+
+    /// Adds a constant initializer, returning its name.
+    fn constant(&mut self, mut tensor: TensorProto) -> String {
+        self.num_constants += 1;
+        tensor.name = format!("constant{}", self.num_constants);
+        let name = tensor.name.clone();
+        self.initializers.push(tensor);
+        name
+    }
+
+    fn int64s(&mut self, values: Vec<i64>) -> String {
+        let data = TensorData::new(values.clone(), [values.len()]);
+        self.constant(tensor_proto(String::new(), &data).unwrap())
+    }
+
+    fn shape(&mut self, shape: &[usize]) -> String {
+        self.int64s(to_i64s(shape))
+    }
+
+    fn scalar(
+        &mut self,
+        value: f64,
+        dtype: &DType,
+        shape: Vec<usize>,
+    ) -> Result<String, OnnxExportError> {
+        let tensor = scalar_proto(String::new(), value, dtype, shape)?;
+        Ok(self.constant(tensor))
+    }
+}
+
+fn unsupported(op: &str) -> Result<(), OnnxExportError> {
+    Err(OnnxExportError::UnsupportedOperation(op.to_string()))
+}
+
+fn is_float(dtype: &DType) -> bool {
+    matches!(dtype, DType::F64 | DType::F32 | DType::F16 | DType::BF16)
+}
+
+/// The ONNX [data type](DataType) of the tensor elements.
+pub(crate) fn elem_type(dtype: &DType) -> Result<i32, OnnxExportError> {
+    let data_type = match dtype {
+        DType::F64 => DataType::DOUBLE,
+        DType::F32 => DataType::FLOAT,
+        DType::F16 => DataType::FLOAT16,
+        DType::BF16 => DataType::BFLOAT16,
+        DType::I64 => DataType::INT64,
+        DType::I32 => DataType::INT32,
+        DType::I16 => DataType::INT16,
+        DType::I8 => DataType::INT8,
+        DType::U64 => DataType::UINT64,
+        DType::U32 => DataType::UINT32,
+        DType::U8 => DataType::UINT8,
+        DType::Bool => DataType::BOOL,
+        DType::QFloat(_) => return Err(OnnxExportError::UnsupportedDType(*dtype)),
+    };
+
+    Ok(data_type as i32)
+}
+
+/// Converts the tensor data to an ONNX tensor.
+///
+/// The bytes of the data are stored as is, which matches the little-endian layout of ONNX.
+pub(crate) fn tensor_proto(
+    name: String,
+    data: &TensorData,
+) -> Result<TensorProto, OnnxExportError> {
+    Ok(TensorProto {
+        name,
+        dims: to_i64s(&data.shape),
+        data_type: elem_type(&data.dtype)?,
+        raw_data: data.as_bytes().to_vec(),
+        ..Default::default()
+    })
+}
+
+/// A tensor filled with the value, with the given element type.
+fn scalar_proto(
+    name: String,
+    value: f64,
+    dtype: &DType,
+    shape: Vec<usize>,
+) -> Result<TensorProto, OnnxExportError> {
+    let num_elements = shape.iter().product();
+    let data = match dtype {
+        DType::F64 => TensorData::new(vec![value; num_elements], shape),
+        DType::F32 => TensorData::new(vec![value as f32; num_elements], shape),
+        DType::F16 => TensorData::new(vec![half::f16::from_f64(value); num_elements], shape),
+        DType::BF16 => TensorData::new(vec![half::bf16::from_f64(value); num_elements], shape),
+        DType::I64 => TensorData::new(vec![value as i64; num_elements], shape),
+        DType::I32 => TensorData::new(vec![value as i32; num_elements], shape),
+        DType::I16 => TensorData::new(vec![value as i16; num_elements], shape),
+        DType::I8 => TensorData::new(vec![value as i8; num_elements], shape),
+        DType::U64 => TensorData::new(vec![value as u64; num_elements], shape),
+        DType::U32 => TensorData::new(vec![value as u32; num_elements], shape),
+        DType::U8 => TensorData::new(vec![value as u8; num_elements], shape),
+        DType::Bool => TensorData::new(vec![value != 0.0; num_elements], shape),
+        DType::QFloat(_) => return Err(OnnxExportError::UnsupportedDType(*dtype)),
+    };
+
+    tensor_proto(name, &data)
+}
+
+fn to_i64s(values: &[usize]) -> Vec<i64> {
+    values.iter().map(|value| *value as i64).collect()
+}
+
+/// The padding at the beginning and the end of each spatial dimension.
+fn pads(padding: &[usize]) -> Vec<i64> {
+    let mut pads = to_i64s(padding);
+    pads.extend_from_within(..);
+    pads
+}
+
+fn conv_attrs(
+    weight: &TensorDescription,
+    stride: &[usize],
+    padding: &[usize],
+    dilation: &[usize],
+    groups: usize,
+) -> Vec<AttributeProto> {
+    vec![
+        ints("kernel_shape", to_i64s(&weight.shape[2..])),
+        ints("strides", to_i64s(stride)),
+        ints("pads", pads(padding)),
+        ints("dilations", to_i64s(dilation)),
+        int("group", groups as i64),
+    ]
+}
+
+fn attribute(name: &str, ty: AttributeType) -> AttributeProto {
+    AttributeProto {
+        name: name.to_string(),
+        type_: ty.into(),
+        ..Default::default()
+    }
+}
+
+fn int(name: &str, value: i64) -> AttributeProto {
+    AttributeProto {
+        i: value,
+        ..attribute(name, AttributeType::INT)
+    }
+}
+
+fn ints(name: &str, values: Vec<i64>) -> AttributeProto {
+    AttributeProto {
+        ints: values,
+        ..attribute(name, AttributeType::INTS)
+    }
+}
+
+fn float(name: &str, value: f32) -> AttributeProto {
+    AttributeProto {
+        f: value,
+        ..attribute(name, AttributeType::FLOAT)
+    }
+}
+
+fn string(name: &str, value: &str) -> AttributeProto {
+    AttributeProto {
+        s: value.as_bytes().to_vec(),
+        ..attribute(name, AttributeType::STRING)
+    }
+}
+
+fn tensor(name: &str, value: TensorProto) -> AttributeProto {
+    AttributeProto {
+        t: Some(value).into(),
+        ..attribute(name, AttributeType::TENSOR)
+    }
+}
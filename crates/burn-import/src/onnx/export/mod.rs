@@ -0,0 +1,43 @@
+//! Export of Burn modules to ONNX.
+//!
+//! The forward pass of a module is traced on a [fusion backend](burn_fusion::Fusion), which
+//! describes each operation with an [OperationDescription](burn::tensor::repr::OperationDescription).
+//! The [OnnxTracer] converts the traced operations into the nodes of an ONNX graph, with the
+//! parameters of the module as initializers.
+//!
+//! Since the graph is recorded for the inputs of the traced forward pass, the shapes are static
+//! and the control flow depending on the tensor values is fixed.
+
+mod graph;
+mod tracer;
+
+pub use tracer::*;
+
+/// Error that can occur when exporting a module to ONNX.
+#[derive(thiserror::Error, Debug)]
+pub enum OnnxExportError {
+    /// The traced operation has no ONNX equivalent.
+    #[error("Unsupported operation: {0}")]
+    UnsupportedOperation(String),
+
+    /// The element type has no ONNX equivalent.
+    #[error("Unsupported data type: {0:?}")]
+    UnsupportedDType(burn::tensor::DType),
+
+    /// A traced operation uses a tensor created before the trace that is neither an input nor a
+    /// parameter of the module.
+    #[error("Unknown tensor {0}, it must be an input or a parameter of the module")]
+    UnknownTensor(String),
+
+    /// Two inputs, outputs or parameters have the same name.
+    #[error("Duplicate name: {0}")]
+    DuplicateName(String),
+
+    /// Error when writing the model.
+    #[error("IO error: {0}")]
+    Io(#[from] std::io::Error),
+
+    /// Error when encoding the model.
+    #[error("Protobuf error: {0}")]
+    Protobuf(#[from] protobuf::Error),
+}
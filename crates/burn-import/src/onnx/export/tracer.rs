@@ -0,0 +1,409 @@
+use std::{
+    collections::{HashMap, HashSet},
+    path::Path,
+};
+
+use burn::{
+    module::{Module, ModuleMapper, ModuleVisitor, ParamId},
+    record::{FullPrecisionSettings, Record},
+    tensor::{
+        backend::Backend,
+        repr::{TensorDescription, TensorStatus},
+        Bool, DType, Int, Shape, Tensor, TensorData, TensorPrimitive,
+    },
+};
+use burn_fusion::{
+    client::FusionClient, get_client, Client, Fusion, FusionBackend, FusionHandle, FusionRuntime,
+    FusionTensor,
+};
+use onnx_ir::protos::{
+    tensor_shape_proto::{dimension::Value as DimensionValue, Dimension},
+    type_proto::{Tensor as TensorTypeProto, Value as TypeValue},
+    GraphProto, ModelProto, OperatorSetIdProto, TensorShapeProto, TypeProto, ValueInfoProto,
+};
+use protobuf::Message;
+
+use super::{
+    graph::{elem_type, GraphBuilder},
+    OnnxExportError,
+};
+
+/// The ONNX IR version of the exported models.
+const IR_VERSION: i64 = 8;
+/// The version of the default ONNX operator set used by the exported models.
+const OPSET_VERSION: i64 = 16;
+
+/// Records the forward pass of a module to export it as an ONNX model.
+///
+/// The operations executed on the device of the module between [new](OnnxTracer::new) and
+/// [finish](OnnxTracer::finish) are recorded, so the tracer must not be shared with other
+/// computations on the same device.
+///
+/// # Examples
+///
+/// ```text
+/// use burn_import::onnx::OnnxTracer;
+///
+/// type Backend = burn_fusion::Fusion<burn_ndarray::NdArray>;
+///
+/// let model = Model::<Backend>::new(&device);
+/// let input = Tensor::<Backend, 4>::zeros([1, 3, 224, 224], &device);
+///
+/// let mut tracer = OnnxTracer::new(&model, &device);
+/// let output = model.forward(input.clone());
+/// tracer.input("input", &input).output("output", &output);
+/// tracer.save("model.onnx".as_ref())?;
+/// ```
+pub struct OnnxTracer<B: FusionBackend> {
+    client: Option<Client<B::FusionRuntime>>,
+    params: Vec<(String, TensorDescription, TensorData)>,
+    inputs: Vec<(String, TensorDescription)>,
+    outputs: Vec<(String, TensorDescription)>,
+}
+
+impl<B: FusionBackend> OnnxTracer<B> {
+    /// Collects the parameters of the module and starts recording the operations on the device.
+    ///
+    /// The parameters are named after their path in the [record](Module::into_record) of the
+    /// module, e.g. `layers.0.weight`.
+    pub fn new<M: Module<Fusion<B>>>(module: &M, device: &B::Device) -> Self {
+        let names = param_names(module);
+        let mut collector = ParamCollector {
+            names,
+            params: Vec::new(),
+        };
+        module.visit(&mut collector);
+
+        let client = get_client::<B>(device);
+        client.start_trace();
+
+        Self {
+            client: Some(client),
+            params: collector.params,
+            inputs: Vec::new(),
+            outputs: Vec::new(),
+        }
+    }
+
+    /// Declares an input of the graph.
+    ///
+    /// The inputs are in the order of declaration. The tensor can be created before or during the
+    /// trace.
+    pub fn input<T: TracedTensor<B>>(&mut self, name: &str, tensor: &T) -> &mut Self {
+        self.inputs.push((name.to_string(), tensor.description()));
+        self
+    }
+
+    /// Declares an output of the graph.
+    ///
+    /// The outputs are in the order of declaration.
+    pub fn output<T: TracedTensor<B>>(&mut self, name: &str, tensor: &T) -> &mut Self {
+        self.outputs.push((name.to_string(), tensor.description()));
+        self
+    }
+
+    /// Stops recording the operations and converts them into an ONNX model.
+    ///
+    /// The tensors created from data during the trace that aren't inputs are constants of the
+    /// graph.
+    pub fn finish(mut self) -> Result<ModelProto, OnnxExportError> {
+        let trace = match self.client.take() {
+            Some(client) => client.end_trace(),
+            None => Default::default(),
+        };
+
+        let mut builder = GraphBuilder::default();
+        let mut names = HashSet::new();
+        let mut unique = |name: &str| match names.insert(name.to_string()) {
+            true => Ok(name.to_string()),
+            false => Err(OnnxExportError::DuplicateName(name.to_string())),
+        };
+
+        for (name, tensor) in self.inputs.iter() {
+            builder.register(tensor, unique(name)?);
+        }
+        for (name, tensor, _) in self.params.iter() {
+            builder.register(tensor, unique(name)?);
+        }
+        for (index, (tensor, _)) in trace.tensors.iter().enumerate() {
+            builder.register(tensor, format!("tensor{}", index + 1));
+        }
+        for (name, tensor) in self.outputs.iter() {
+            builder.register(tensor, unique(name)?);
+        }
+
+        for operation in trace.operations.iter() {
+            builder.operation(operation)?;
+        }
+
+        let input_ids = self
+            .inputs
+            .iter()
+            .map(|(_, tensor)| tensor.id)
+            .collect::<HashSet<_>>();
+
+        // Only the parameters and constants used by the traced operations are kept
+        for (name, tensor, data) in self.params.iter() {
+            if builder.is_used(tensor) && !input_ids.contains(&tensor.id) {
+                builder.initializer(name.clone(), data)?;
+            }
+        }
+        for (index, (tensor, handle)) in trace.tensors.into_iter().enumerate() {
+            if builder.is_used(&tensor) && !input_ids.contains(&tensor.id) {
+                let data = handle_into_data::<B>(&tensor, handle);
+                builder.initializer(format!("tensor{}", index + 1), &data)?;
+            }
+        }
+
+        let graph = GraphProto {
+            name: "burn".to_string(),
+            node: builder.nodes,
+            initializer: builder.initializers,
+            input: value_infos(&self.inputs)?,
+            output: value_infos(&self.outputs)?,
+            ..Default::default()
+        };
+
+        Ok(ModelProto {
+            ir_version: IR_VERSION,
+            opset_import: vec![OperatorSetIdProto {
+                domain: String::new(),
+                version: OPSET_VERSION,
+                ..Default::default()
+            }],
+            producer_name: "burn".to_string(),
+            producer_version: env!("CARGO_PKG_VERSION").to_string(),
+            graph: Some(graph).into(),
+            ..Default::default()
+        })
+    }
+
+    /// Stops recording the operations and saves them as an ONNX model.
+    pub fn save(self, path: &Path) -> Result<(), OnnxExportError> {
+        let model = self.finish()?;
+        std::fs::write(path, model.write_to_bytes()?)?;
+        Ok(())
+    }
+}
+
+impl<B: FusionBackend> Drop for OnnxTracer<B> {
+    fn drop(&mut self) {
+        // Stop recording if the tracer is dropped before the end of the trace
+        if let Some(client) = self.client.take() {
+            client.end_trace();
+        }
+    }
+}
+
+/// A tensor that can be an input or an output of the traced graph.
+pub trait TracedTensor<B: FusionBackend> {
+    /// The description of the tensor in the traced operations.
+    fn description(&self) -> TensorDescription;
+}
+
+impl<B: FusionBackend, const D: usize> TracedTensor<B> for Tensor<Fusion<B>, D> {
+    fn description(&self) -> TensorDescription {
+        match self.clone().into_primitive() {
+            TensorPrimitive::Float(tensor) => describe(&tensor),
+            TensorPrimitive::QFloat { .. } => panic!("Quantized tensors can't be traced"),
+        }
+    }
+}
+
+impl<B: FusionBackend, const D: usize> TracedTensor<B> for Tensor<Fusion<B>, D, Int> {
+    fn description(&self) -> TensorDescription {
+        describe(&self.clone().into_primitive())
+    }
+}
+
+impl<B: FusionBackend, const D: usize> TracedTensor<B> for Tensor<Fusion<B>, D, Bool> {
+    fn description(&self) -> TensorDescription {
+        describe(&self.clone().into_primitive())
+    }
+}
+
+fn describe<R: FusionRuntime>(tensor: &FusionTensor<R>) -> TensorDescription {
+    TensorDescription {
+        id: *tensor.id,
+        shape: tensor.shape.clone(),
+        status: TensorStatus::ReadOnly,
+        dtype: tensor.dtype,
+    }
+}
+
+/// Collects the parameters of the module with their data.
+struct ParamCollector {
+    names: HashMap<String, String>,
+    params: Vec<(String, TensorDescription, TensorData)>,
+}
+
+impl ParamCollector {
+    fn push<B: FusionBackend, T: TracedTensor<B>>(
+        &mut self,
+        id: &ParamId,
+        tensor: &T,
+        data: TensorData,
+    ) {
+        let id = id.to_string();
+        let name = self.names.get(&id).cloned().unwrap_or(id);
+        self.params.push((name, tensor.description(), data));
+    }
+}
+
+impl<B: FusionBackend> ModuleVisitor<Fusion<B>> for ParamCollector {
+    fn visit_float<const D: usize>(&mut self, id: &ParamId, tensor: &Tensor<Fusion<B>, D>) {
+        self.push(id, tensor, tensor.to_data());
+    }
+
+    fn visit_int<const D: usize>(&mut self, id: &ParamId, tensor: &Tensor<Fusion<B>, D, Int>) {
+        self.push(id, tensor, tensor.to_data());
+    }
+
+    fn visit_bool<const D: usize>(&mut self, id: &ParamId, tensor: &Tensor<Fusion<B>, D, Bool>) {
+        self.push(id, tensor, tensor.to_data());
+    }
+}
+
+/// Replaces the parameters with tensors of a single element, keeping their ids.
+struct ParamShrinker;
+
+impl<B: Backend> ModuleMapper<B> for ParamShrinker {
+    fn map_float<const D: usize>(&mut self, _id: &ParamId, tensor: Tensor<B, D>) -> Tensor<B, D> {
+        Tensor::empty([1; D], &tensor.device())
+    }
+
+    fn map_int<const D: usize>(
+        &mut self,
+        _id: &ParamId,
+        tensor: Tensor<B, D, Int>,
+    ) -> Tensor<B, D, Int> {
+        Tensor::empty([1; D], &tensor.device())
+    }
+
+    fn map_bool<const D: usize>(
+        &mut self,
+        _id: &ParamId,
+        tensor: Tensor<B, D, Bool>,
+    ) -> Tensor<B, D, Bool> {
+        Tensor::empty([1; D], &tensor.device())
+    }
+}
+
+/// Maps the id of each parameter to its path in the record of the module.
+///
+/// The parameters are serialized with their id (see [ParamSerde](burn::record::ParamSerde)), so
+/// the path is found by walking the serialized record, with the parameters shrunk beforehand to
+/// avoid copying their data.
+fn param_names<B: Backend, M: Module<B>>(module: &M) -> HashMap<String, String> {
+    fn walk(
+        value: &serde_json::Value,
+        path: &mut Vec<String>,
+        names: &mut HashMap<String, String>,
+    ) {
+        match value {
+            serde_json::Value::Object(map) => {
+                if let (2, Some(serde_json::Value::String(id)), Some(_)) =
+                    (map.len(), map.get("id"), map.get("param"))
+                {
+                    if !path.is_empty() {
+                        names.insert(id.clone(), path.join("."));
+                    }
+                    return;
+                }
+
+                for (key, value) in map {
+                    path.push(key.clone());
+                    walk(value, path, names);
+                    path.pop();
+                }
+            }
+            serde_json::Value::Array(values) => {
+                for (index, value) in values.iter().enumerate() {
+                    path.push(index.to_string());
+                    walk(value, path, names);
+                    path.pop();
+                }
+            }
+            _ => {}
+        }
+    }
+
+    let record = module.clone().map(&mut ParamShrinker).into_record();
+    let mut names = HashMap::new();
+
+    if let Ok(value) = serde_json::to_value(record.into_item::<FullPrecisionSettings>()) {
+        walk(&value, &mut Vec::new(), &mut names);
+    }
+
+    names
+}
+
+/// Reads the data of a tensor created from a handle during the trace.
+fn handle_into_data<B: FusionBackend>(
+    tensor: &TensorDescription,
+    handle: FusionHandle<B::FusionRuntime>,
+) -> TensorData {
+    macro_rules! into_data {
+        ($($rank:literal),*) => {
+            match tensor.shape.len() {
+                $($rank => {
+                    let shape = Shape::<$rank>::from(tensor.shape.clone());
+                    match tensor.dtype {
+                        DType::F64 | DType::F32 | DType::F16 | DType::BF16 => {
+                            let tensor = B::float_tensor(handle, shape);
+                            Tensor::<B, $rank>::from_primitive(TensorPrimitive::Float(tensor))
+                                .into_data()
+                        }
+                        DType::Bool => {
+                            Tensor::<B, $rank, Bool>::from_primitive(B::bool_tensor(handle, shape))
+                                .into_data()
+                        }
+                        _ => Tensor::<B, $rank, Int>::from_primitive(B::int_tensor(handle, shape))
+                            .into_data(),
+                    }
+                })*
+                rank => panic!("Tensors of rank {rank} can't be exported"),
+            }
+        };
+    }
+
+    into_data!(1, 2, 3, 4, 5, 6, 7, 8)
+}
+
+/// The description of the graph inputs or outputs, with their element type and static shape.
+fn value_infos(
+    values: &[(String, TensorDescription)],
+) -> Result<Vec<ValueInfoProto>, OnnxExportError> {
+    values
+        .iter()
+        .map(|(name, tensor)| {
+            let dims = tensor
+                .shape
+                .iter()
+                .map(|dim| Dimension {
+                    value: Some(DimensionValue::DimValue(*dim as i64)),
+                    ..Default::default()
+                })
+                .collect();
+            let tensor_type = TensorTypeProto {
+                elem_type: elem_type(&tensor.dtype)?,
+                shape: Some(TensorShapeProto {
+                    dim: dims,
+                    ..Default::default()
+                })
+                .into(),
+                ..Default::default()
+            };
+
+            Ok(ValueInfoProto {
+                name: name.clone(),
+                type_: Some(TypeProto {
+                    value: Some(TypeValue::TensorType(tensor_type)),
+                    ..Default::default()
+                })
+                .into(),
+                ..Default::default()
+            })
+        })
+        .collect()
+}
@@ -0,0 +1,254 @@
+//! Runtime evaluation of ONNX models.
+//!
+//! The [OnnxModel] evaluates the graph parsed by `onnx-ir` on any backend with the tensor API,
+//! without generating and compiling Rust code. The nodes are evaluated with the same operations
+//! and configurations as the code generated by [ModelGen](crate::onnx::ModelGen), so both give
+//! identical results. The initializers are loaded with full precision.
+//!
+//! Since the ranks of the tensors are only known at runtime, the values flowing through the graph
+//! are [dynamic tensors](DynTensor) of rank 1 to [MAX_RANK].
+
+mod ops;
+mod recurrent;
+mod value;
+
+pub use value::{DynTensor, Scalar, Value, MAX_RANK};
+
+use std::{collections::HashMap, path::Path};
+
+use burn::tensor::backend::Backend;
+use onnx_ir::{
+    ir::{Argument, Node, NodeType},
+    optimize, parse_onnx, OnnxGraph, Pass,
+};
+
+pub use onnx_ir::OnnxParseError;
+
+use crate::onnx::to_burn::shape_operands;
+use ops::{is_static_input, Op};
+
+/// Error returned when an ONNX model can't be evaluated by the interpreter.
+#[derive(thiserror::Error, Debug)]
+pub enum OnnxModelError {
+    /// The graph contains operators without an implementation in the interpreter.
+    #[error("Unsupported ops: {0:?}")]
+    UnsupportedOps(Vec<NodeType>),
+    /// The ONNX file can't be parsed.
+    #[error("Unable to parse the ONNX file: {0}")]
+    Parse(#[from] OnnxParseError),
+}
+
+/// An ONNX model evaluated at runtime.
+///
+/// # Examples
+///
+/// ```text
+/// use burn_import::onnx::OnnxModel;
+///
+/// let model = OnnxModel::<Backend>::from_file("model.onnx".as_ref(), &device)?;
+/// let input = Tensor::<Backend, 4>::zeros([1, 3, 224, 224], &device);
+/// let output = model.forward(vec![input.into()]).remove(0).into_float::<2>();
+/// ```
+#[derive(Debug)]
+pub struct OnnxModel<B: Backend> {
+    graph: Graph<B>,
+}
+
+impl<B: Backend> OnnxModel<B> {
+    /// Prepares the evaluation of the graph, loading its initializers on the device.
+    ///
+    /// Returns an error if the graph contains unsupported operators.
+    pub fn new(graph: OnnxGraph, device: &B::Device) -> Result<Self, OnnxModelError> {
+        let mut unsupported_ops = vec![];
+        let graph = Graph::new(graph, device, &mut unsupported_ops);
+
+        if !unsupported_ops.is_empty() {
+            return Err(OnnxModelError::UnsupportedOps(unsupported_ops));
+        }
+
+        Ok(Self { graph })
+    }
+
+    /// Parses the ONNX file and prepares the evaluation of its graph.
+    ///
+    /// Returns an error if the file can't be parsed or if the graph contains unsupported operators.
+    pub fn from_file(path: &Path, device: &B::Device) -> Result<Self, OnnxModelError> {
+        Self::from_file_with_passes(path, &[], device)
+    }
+
+    /// Parses the ONNX file, applies the optimization passes to its graph and prepares its
+    /// evaluation.
+    ///
+    /// The passes are the ones of [ModelGen::passes](crate::onnx::ModelGen::passes), so the graph
+    /// is evaluated like the code generated with the same passes.
+    ///
+    /// Returns an error if the file can't be parsed or if the graph contains unsupported operators.
+    pub fn from_file_with_passes(
+        path: &Path,
+        passes: &[Pass],
+        device: &B::Device,
+    ) -> Result<Self, OnnxModelError> {
+        Self::new(optimize(parse_onnx(path)?, passes), device)
+    }
+
+    /// The inputs of the graph, in the order expected by [forward](Self::forward).
+    pub fn inputs(&self) -> &[Argument] {
+        &self.graph.inputs
+    }
+
+    /// The outputs of the graph, in the order returned by [forward](Self::forward).
+    pub fn outputs(&self) -> &[Argument] {
+        &self.graph.outputs
+    }
+
+    /// Evaluates the graph on the inputs.
+    ///
+    /// # Panics
+    ///
+    /// If the number of inputs doesn't match the graph or an input doesn't have the expected kind
+    /// or rank.
+    pub fn forward(&self, inputs: Vec<Value<B>>) -> Vec<Value<B>> {
+        assert_eq!(
+            inputs.len(),
+            self.graph.inputs.len(),
+            "Expected {} inputs, got {}",
+            self.graph.inputs.len(),
+            inputs.len()
+        );
+
+        self.graph.forward(self.graph.inputs.iter().zip(inputs))
+    }
+}
+
+/// The nodes of a graph or a sub-graph, with their operations prepared for the evaluation.
+#[derive(Debug)]
+pub(crate) struct Graph<B: Backend> {
+    nodes: Vec<(Node, Op<B>)>,
+    pub(crate) inputs: Vec<Argument>,
+    pub(crate) outputs: Vec<Argument>,
+    initializers: HashMap<String, Value<B>>,
+    uses: HashMap<String, usize>,
+    device: B::Device,
+}
+
+impl<B: Backend> Graph<B> {
+    /// Prepares the evaluation of the graph, loading its initializers on the device.
+    ///
+    /// The types of the nodes without an operation, including the ones of the sub-graphs, are
+    /// added to the unsupported operators.
+    pub(crate) fn new(
+        graph: OnnxGraph,
+        device: &B::Device,
+        unsupported_ops: &mut Vec<NodeType>,
+    ) -> Self {
+        let mut nodes = Vec::with_capacity(graph.nodes.len());
+        let mut initializers = HashMap::new();
+        let mut uses = HashMap::<String, usize>::new();
+
+        for mut node in graph.nodes {
+            // The constant operands of the runtime shapes are shapes, like in the generated models
+            shape_operands(&mut node);
+
+            for (index, input) in node.inputs.iter().enumerate() {
+                // The missing optional inputs have no name
+                if input.name.is_empty() || is_static_input(&node, index) {
+                    continue;
+                }
+
+                match Value::from_argument(input, device) {
+                    Some(value) => {
+                        initializers.insert(input.name.clone(), value);
+                    }
+                    None => *uses.entry(input.name.clone()).or_default() += 1,
+                }
+            }
+
+            match Op::new(&node, device, unsupported_ops) {
+                Some(op) => nodes.push((node, op)),
+                None => unsupported_ops.push(node.node_type),
+            }
+        }
+
+        for output in graph.outputs.iter() {
+            *uses.entry(output.name.clone()).or_default() += 1;
+        }
+
+        Self {
+            nodes,
+            inputs: graph.inputs,
+            outputs: graph.outputs,
+            initializers,
+            uses,
+            device: device.clone(),
+        }
+    }
+
+    /// Evaluates the graph with the given values of its inputs, and of the values of the
+    /// enclosing graph captured by a sub-graph.
+    pub(crate) fn forward<'a>(
+        &self,
+        inputs: impl IntoIterator<Item = (&'a Argument, Value<B>)>,
+    ) -> Vec<Value<B>> {
+        let mut values = Values {
+            values: HashMap::new(),
+            uses: self.uses.clone(),
+            initializers: &self.initializers,
+            device: &self.device,
+        };
+
+        for (arg, value) in inputs {
+            values.insert(arg, value);
+        }
+
+        for (node, op) in self.nodes.iter() {
+            let outputs = op.forward(node, &mut values);
+
+            // The missing optional outputs have no name
+            for (arg, value) in node.outputs.iter().zip(outputs) {
+                if !arg.name.is_empty() {
+                    values.insert(arg, value);
+                }
+            }
+        }
+
+        self.outputs.iter().map(|arg| values.take(arg)).collect()
+    }
+}
+
+/// The values of the graph being evaluated.
+///
+/// Each value is dropped after its last use, so its tensor can be reused by the backend.
+pub(crate) struct Values<'a, B: Backend> {
+    values: HashMap<String, Value<B>>,
+    uses: HashMap<String, usize>,
+    initializers: &'a HashMap<String, Value<B>>,
+    device: &'a B::Device,
+}
+
+impl<B: Backend> Values<'_, B> {
+    fn insert(&mut self, arg: &Argument, value: Value<B>) {
+        self.values.insert(arg.name.clone(), value);
+    }
+
+    /// Takes the value of the argument, removing it if this is its last use.
+    pub(crate) fn take(&mut self, arg: &Argument) -> Value<B> {
+        if let Some(value) = self.initializers.get(&arg.name) {
+            return value.clone();
+        }
+
+        let uses = self.uses.entry(arg.name.clone()).or_default();
+        *uses = uses.saturating_sub(1);
+
+        let value = match *uses {
+            0 => self.values.remove(&arg.name),
+            _ => self.values.get(&arg.name).cloned(),
+        };
+
+        value.unwrap_or_else(|| panic!("Value {} should be evaluated before its use", arg.name))
+    }
+
+    /// The device of the values.
+    pub(crate) fn device(&self) -> &B::Device {
+        self.device
+    }
+}
@@ -0,0 +1,2115 @@
+use std::{
+    cmp::{max, Ordering},
+    collections::HashMap,
+};
+
+use burn::{
+    module::{Param, RunningState},
+    nn::{
+        conv::{Conv1d, Conv2d, Conv3d, ConvTranspose2d, ConvTranspose3d},
+        gru::Gru,
+        pool::{
+            AdaptiveAvgPool1d, AdaptiveAvgPool1dConfig, AdaptiveAvgPool2d, AdaptiveAvgPool2dConfig,
+            AvgPool1d, AvgPool2d, MaxPool1d, MaxPool2d,
+        },
+        BatchNorm, BiLstm, Dropout, GateController, GroupNorm, InstanceNorm, LayerNorm, Linear,
+        Lstm, LstmState, PRelu, PReluConfig,
+    },
+    record::{FullPrecisionSettings, PrecisionSettings},
+    tensor::{
+        activation,
+        backend::Backend,
+        module::interpolate,
+        ops::{InterpolateMode, InterpolateOptions},
+        BasicOps, Bool, Distribution, Element, ElementConversion, Float, Int, Numeric, Tensor,
+    },
+};
+use log::warn;
+use onnx_ir::{
+    convert_constant_value,
+    ir::{ArgType, Data, ElementType, Node, NodeType, TensorType},
+};
+
+use super::{
+    recurrent,
+    value::{visit_rank, visit_rank_pair, with_rank, DynTensor, Scalar, Value},
+    Graph, Values,
+};
+use crate::{
+    burn::node::{
+        cumsum::CumSumConfig,
+        lstm::{CELL_GATE, FORGET_GATE, INPUT_GATE, OUTPUT_GATE},
+        one_hot::OneHotConfig,
+        pad::PadConfig,
+        resize::ResizeMode,
+        rnn::{RecurrentConfig, RnnActivation, RnnDirection},
+        scan::ScanConfig,
+        slice::SliceBound,
+        split::SplitConfig,
+        top_k::TopKConfig,
+        trilu::TriluConfig,
+        unary::{SELU_ALPHA, SELU_GAMMA},
+    },
+    onnx::{
+        op_configuration::{
+            argmax_config, avg_pool1d_config, avg_pool2d_config, batch_norm_config, clip_config,
+            concat_config, conv1d_config, conv2d_config, conv3d_config, conv_transpose2d_config,
+            conv_transpose3d_config, cumsum_config, dropout_config, elu_config, expand_config,
+            flatten_config, gather_config, group_norm_config, gru_config, hard_sigmoid_config,
+            instance_norm_config, layer_norm_config, leaky_relu_config, linear_config,
+            log_softmax_config, lstm_config, max_pool1d_config, max_pool2d_config, one_hot_config,
+            pad_config, reduce_max_config, reduce_mean_config, reduce_min_config,
+            reduce_prod_config, reduce_sum_config, reshape_config, resize_config, rnn_config,
+            scan_config, selu_config, shape_config, softmax_config, split_config, squeeze_config,
+            tile_config, top_k_config, transpose_config, trilu_config, unsqueeze_config,
+        },
+        to_burn::{extract_data_serialize, gate_weights, group_norm_params, slice_ranges},
+    },
+};
+
+/// The parameters are loaded with the same precision as the generated models.
+pub(crate) type FloatElem = <FullPrecisionSettings as PrecisionSettings>::FloatElem;
+
+/// Matches a value with its tensor bound to `$tensor`, keeping the kind of the tensor.
+macro_rules! map_tensor {
+    ($value:expr, $tensor:ident => $body:expr) => {
+        match $value {
+            Value::Float($tensor) => Value::Float($body),
+            Value::Int($tensor) => Value::Int($body),
+            Value::Bool($tensor) => Value::Bool($body),
+            value => panic!("Expected a tensor, got a {}", value.kind()),
+        }
+    };
+}
+
+/// Matches a value with its numeric tensor bound to `$tensor`, keeping the kind of the tensor.
+macro_rules! map_numeric {
+    ($value:expr, $tensor:ident => $body:expr) => {
+        match $value {
+            Value::Float($tensor) => Value::Float($body),
+            Value::Int($tensor) => Value::Int($body),
+            value => panic!("Expected a numeric tensor, got a {}", value.kind()),
+        }
+    };
+}
+
+/// Matches a scalar with its primitive value bound to `$value`.
+macro_rules! visit_scalar {
+    ($scalar:expr, $value:ident => $body:expr) => {
+        match $scalar {
+            Scalar::Float32($value) => $body,
+            Scalar::Float64($value) => $body,
+            Scalar::Int32($value) => $body,
+            Scalar::Int64($value) => $body,
+            Scalar::Bool($value) => $body,
+        }
+    };
+}
+
+/// The evaluation of a node, with its configuration and parameters prepared ahead of time.
+#[derive(Debug)]
+pub(crate) enum Op<B: Backend> {
+    Constant(Value<B>),
+    ConstantOfShape(Scalar),
+    Unary(UnaryOp),
+    Binary(BinaryOp),
+    Cast(ElementType),
+    Flatten(usize, usize),
+    Transpose(Vec<isize>),
+    Reduce(ReduceOp, Option<usize>),
+    Shape(usize, usize),
+    ArgMax(usize),
+    Clip(Option<f64>, Option<f64>),
+    Concat(usize),
+    /// The static shape, or `None` for a runtime shape.
+    Expand(Option<Vec<i32>>),
+    Gather(usize),
+    GatherElements(usize),
+    MatMul,
+    Range,
+    /// The static shape, or `None` for a runtime shape.
+    Reshape(Option<Vec<i32>>),
+    Resize(InterpolateOptions),
+    Slice(Vec<Option<(SliceBound, SliceBound)>>),
+    Squeeze(Vec<isize>),
+    Unsqueeze(Vec<isize>),
+    Sum,
+    Where,
+    Random(Vec<usize>, Distribution),
+    Conv1d(Conv1d<B>),
+    Conv2d(Conv2d<B>),
+    Conv3d(Conv3d<B>),
+    ConvTranspose2d(ConvTranspose2d<B>),
+    ConvTranspose3d(ConvTranspose3d<B>),
+    Linear(Linear<B>),
+    BatchNorm(BatchNormModule<B>),
+    LayerNorm(LayerNorm<B>),
+    PRelu(PRelu<B>),
+    Dropout(Dropout),
+    MaxPool1d(MaxPool1d),
+    MaxPool2d(MaxPool2d),
+    AvgPool1d(AvgPool1d),
+    AvgPool2d(AvgPool2d),
+    GlobalAvgPool1d(AdaptiveAvgPool1d),
+    GlobalAvgPool2d(AdaptiveAvgPool2d),
+    InstanceNorm(InstanceNorm<B>),
+    GroupNorm(GroupNorm<B>),
+    Pad(PadConfig),
+    Split(SplitConfig),
+    Tile(Vec<usize>),
+    TopK(TopKConfig),
+    Trilu(TriluConfig),
+    OneHot(OneHotConfig),
+    CumSum(CumSumConfig),
+    NonZero,
+    Lstm(Box<Lstm<B>>, RecurrentConfig),
+    BiLstm(Box<BiLstm<B>>, RecurrentConfig),
+    /// The GRU of each direction.
+    Gru(Vec<Gru<B>>, RecurrentConfig),
+    /// The gate controller of each direction.
+    Rnn(Vec<GateController<B>>, RecurrentConfig, RnnActivation),
+    /// The then and else branches.
+    If(Graph<B>, Graph<B>),
+    /// The body of the loop.
+    Loop(Graph<B>),
+    /// The body of the scan.
+    Scan(Graph<B>, ScanConfig),
+}
+
+/// Element-wise operations.
+#[derive(Debug, Clone)]
+pub(crate) enum UnaryOp {
+    Erf,
+    Relu,
+    LeakyRelu(f64),
+    Sigmoid,
+    Tanh,
+    Gelu,
+    LogSoftmax(usize),
+    Softmax(usize),
+    Elu(f64),
+    /// The SELU function with its default alpha and gamma, or an ELU scaled by gamma.
+    Selu(Option<f64>, Option<f64>),
+    HardSigmoid(f64, f64),
+    HardSwish,
+    Softplus,
+    Sqrt,
+    Reciprocal,
+    Cos,
+    Sin,
+    Exp,
+    Log,
+    Neg,
+    Not,
+    Sign,
+}
+
+/// Operations between two tensors or scalars.
+#[derive(Debug, Clone)]
+pub(crate) enum BinaryOp {
+    Add,
+    Sub,
+    Mul,
+    Div,
+    Powf,
+    Powi,
+    Min,
+    Max,
+    Equal,
+    Greater,
+    GreaterOrEqual,
+    Less,
+    LessOrEqual,
+}
+
+/// Reductions, either along a dimension (keeping it) or over the whole tensor.
+#[derive(Debug, Clone)]
+pub(crate) enum ReduceOp {
+    Max,
+    Min,
+    Mean,
+    Prod,
+    Sum,
+}
+
+/// A batch norm module, whose type depends on the rank of its input.
+#[derive(Debug)]
+pub(crate) enum BatchNormModule<B: Backend> {
+    Dim0(BatchNorm<B, 0>),
+    Dim1(BatchNorm<B, 1>),
+    Dim2(BatchNorm<B, 2>),
+    Dim3(BatchNorm<B, 3>),
+    Dim4(BatchNorm<B, 4>),
+}
+
+/// Whether the input of a node is read from the configuration or the parameters of its operation
+/// instead of being evaluated.
+pub(crate) fn is_static_input(node: &Node, index: usize) -> bool {
+    match node.node_type {
+        NodeType::BatchNormalization
+        | NodeType::Clip
+        | NodeType::Conv1d
+        | NodeType::Conv2d
+        | NodeType::Conv3d
+        | NodeType::ConvTranspose2d
+        | NodeType::ConvTranspose3d
+        | NodeType::CumSum
+        | NodeType::Dropout
+        | NodeType::GroupNormalization
+        | NodeType::InstanceNormalization
+        | NodeType::LayerNormalization
+        | NodeType::Linear
+        | NodeType::OneHot
+        | NodeType::Pad
+        | NodeType::PRelu
+        | NodeType::ReduceSum
+        | NodeType::Split
+        | NodeType::Squeeze
+        | NodeType::Tile
+        | NodeType::TopK
+        | NodeType::Trilu
+        | NodeType::Unsqueeze => index > 0,
+        // The runtime shapes are evaluated, like the generated models
+        NodeType::Expand | NodeType::Reshape => {
+            index > 0 && !matches!(node.inputs[index].ty, ArgType::Shape(_))
+        }
+        NodeType::Slice => index > 0 && node.inputs[index].value.is_some(),
+        // The output size is evaluated, but not the region of interest and the scales
+        NodeType::Resize => index == 1 || index == 2,
+        // The weights, biases and sequence lengths, but not the initial states
+        NodeType::LSTM | NodeType::GRU | NodeType::RNN => (1..=4).contains(&index),
+        _ => false,
+    }
+}
+
+impl<B: Backend> Op<B> {
+    /// Prepares the evaluation of a node, loading its parameters on the device.
+    ///
+    /// Returns `None` if the node type is not supported. The unsupported node types of the
+    /// sub-graphs are added to the unsupported operators.
+    pub(crate) fn new(
+        node: &Node,
+        device: &B::Device,
+        unsupported_ops: &mut Vec<NodeType>,
+    ) -> Option<Self> {
+        let op = match node.node_type {
+            NodeType::Add => Op::Binary(BinaryOp::Add),
+            NodeType::Sub => Op::Binary(BinaryOp::Sub),
+            NodeType::Mul => Op::Binary(BinaryOp::Mul),
+            NodeType::Div => Op::Binary(BinaryOp::Div),
+            NodeType::Pow => Op::Binary(pow_op(node)),
+            NodeType::Min => Op::Binary(BinaryOp::Min),
+            NodeType::Max => Op::Binary(BinaryOp::Max),
+            NodeType::Equal => Op::Binary(BinaryOp::Equal),
+            NodeType::Greater => Op::Binary(BinaryOp::Greater),
+            NodeType::GreaterOrEqual => Op::Binary(BinaryOp::GreaterOrEqual),
+            NodeType::Less => Op::Binary(BinaryOp::Less),
+            NodeType::LessOrEqual => Op::Binary(BinaryOp::LessOrEqual),
+            NodeType::Erf => Op::Unary(UnaryOp::Erf),
+            NodeType::Relu => Op::Unary(UnaryOp::Relu),
+            NodeType::LeakyRelu => Op::Unary(UnaryOp::LeakyRelu(leaky_relu_config(node))),
+            NodeType::Sigmoid => Op::Unary(UnaryOp::Sigmoid),
+            NodeType::Tanh => Op::Unary(UnaryOp::Tanh),
+            NodeType::Gelu => Op::Unary(UnaryOp::Gelu),
+            NodeType::LogSoftmax => Op::Unary(UnaryOp::LogSoftmax(log_softmax_config(node))),
+            NodeType::Softmax => Op::Unary(UnaryOp::Softmax(softmax_config(node))),
+            NodeType::Elu => Op::Unary(UnaryOp::Elu(elu_config(node))),
+            NodeType::Selu => {
+                let (alpha, gamma) = selu_config(node);
+                Op::Unary(UnaryOp::Selu(alpha, gamma))
+            }
+            NodeType::HardSigmoid => {
+                let (alpha, beta) = hard_sigmoid_config(node);
+                Op::Unary(UnaryOp::HardSigmoid(alpha, beta))
+            }
+            NodeType::HardSwish => Op::Unary(UnaryOp::HardSwish),
+            NodeType::Softplus => Op::Unary(UnaryOp::Softplus),
+            NodeType::Sqrt => Op::Unary(UnaryOp::Sqrt),
+            NodeType::Reciprocal => Op::Unary(UnaryOp::Reciprocal),
+            NodeType::Cos => Op::Unary(UnaryOp::Cos),
+            NodeType::Sin => Op::Unary(UnaryOp::Sin),
+            NodeType::Exp => Op::Unary(UnaryOp::Exp),
+            NodeType::Log => Op::Unary(UnaryOp::Log),
+            NodeType::Neg => Op::Unary(UnaryOp::Neg),
+            NodeType::Not => Op::Unary(UnaryOp::Not),
+            NodeType::Sign => Op::Unary(UnaryOp::Sign),
+            NodeType::Cast => Op::Cast(output_elem_type(node)),
+            NodeType::Flatten => {
+                let (start_dim, end_dim) = flatten_config(node);
+                Op::Flatten(start_dim, end_dim)
+            }
+            NodeType::Transpose => Op::Transpose(to_isize(transpose_config(node))),
+            NodeType::ReduceMax => Op::Reduce(ReduceOp::Max, reduce_max_config(node)),
+            NodeType::ReduceMin => Op::Reduce(ReduceOp::Min, reduce_min_config(node)),
+            NodeType::ReduceMean => Op::Reduce(ReduceOp::Mean, reduce_mean_config(node)),
+            NodeType::ReduceProd => Op::Reduce(ReduceOp::Prod, reduce_prod_config(node)),
+            NodeType::ReduceSum => Op::Reduce(ReduceOp::Sum, reduce_sum_config(node)),
+            NodeType::Shape => {
+                let (start_dim, end_dim) = shape_config(node);
+                Op::Shape(start_dim, end_dim)
+            }
+            NodeType::ArgMax => Op::ArgMax(argmax_config(node)),
+            NodeType::Clip => {
+                let (min, max) = clip_config(node);
+                Op::Clip(min, max)
+            }
+            NodeType::Concat => Op::Concat(concat_config(node)),
+            NodeType::Expand => Op::Expand(match node.inputs[1].ty {
+                ArgType::Shape(_) => None,
+                _ => Some(to_i32(expand_config(node))),
+            }),
+            NodeType::Gather => Op::Gather(gather_config(node)),
+            NodeType::GatherElements => Op::GatherElements(gather_config(node)),
+            NodeType::MatMul => Op::MatMul,
+            NodeType::Range => Op::Range,
+            NodeType::Reshape => Op::Reshape(match node.inputs.get(1).map(|input| &input.ty) {
+                Some(ArgType::Shape(_)) => None,
+                _ => Some(to_i32(reshape_config(node))),
+            }),
+            NodeType::Resize => {
+                let mode = match resize_config(node) {
+                    ResizeMode::Nearest => InterpolateMode::Nearest,
+                    ResizeMode::Linear => InterpolateMode::Bilinear,
+                    ResizeMode::Cubic => InterpolateMode::Bicubic,
+                };
+                Op::Resize(InterpolateOptions::new(mode))
+            }
+            NodeType::Slice => Op::Slice(slice_ranges(node)),
+            NodeType::Squeeze => Op::Squeeze(to_isize(squeeze_config(node))),
+            NodeType::Unsqueeze => Op::Unsqueeze(to_isize(unsqueeze_config(node))),
+            NodeType::Sum => Op::Sum,
+            NodeType::Where => Op::Where,
+            NodeType::Constant => {
+                let constant = convert_constant_value(node);
+                Op::Constant(
+                    Value::from_argument(&constant, device).expect("Constant should have a value"),
+                )
+            }
+            NodeType::ConstantOfShape => Op::ConstantOfShape(constant_of_shape_value(node)),
+            NodeType::RandomNormal | NodeType::RandomUniform => {
+                let shape = match &node.outputs[0].ty {
+                    ArgType::Tensor(tensor) => tensor.shape.clone(),
+                    _ => None,
+                };
+                let shape = shape.unwrap_or_else(|| {
+                    panic!(
+                        "{:?} output should be a tensor with a shape",
+                        node.node_type
+                    )
+                });
+
+                Op::Random(shape, random_distribution(node))
+            }
+            NodeType::Conv1d => {
+                let mut conv = conv1d_config(node).init(device);
+                conv.weight = param(node, 1, device).expect("Weight is required");
+                conv.bias = param(node, 2, device);
+                Op::Conv1d(conv)
+            }
+            NodeType::Conv2d => {
+                let mut conv = conv2d_config(node).init(device);
+                conv.weight = param(node, 1, device).expect("Weight is required");
+                conv.bias = param(node, 2, device);
+                Op::Conv2d(conv)
+            }
+            NodeType::Conv3d => {
+                let mut conv = conv3d_config(node).init(device);
+                conv.weight = param(node, 1, device).expect("Weight is required");
+                conv.bias = param(node, 2, device);
+                Op::Conv3d(conv)
+            }
+            NodeType::ConvTranspose2d => {
+                let mut conv = conv_transpose2d_config(node).init(device);
+                conv.weight = param(node, 1, device).expect("Weight is required");
+                conv.bias = param(node, 2, device);
+                Op::ConvTranspose2d(conv)
+            }
+            NodeType::ConvTranspose3d => {
+                let mut conv = conv_transpose3d_config(node).init(device);
+                conv.weight = param(node, 1, device).expect("Weight is required");
+                conv.bias = param(node, 2, device);
+                Op::ConvTranspose3d(conv)
+            }
+            NodeType::Linear => {
+                let mut linear = linear_config(node).init(device);
+                linear.weight = param(node, 1, device).expect("Weight is required");
+                linear.bias = param(node, 2, device);
+                Op::Linear(linear)
+            }
+            NodeType::BatchNormalization => Op::BatchNorm(BatchNormModule::new(node, device)),
+            NodeType::LayerNormalization => {
+                // The generated models don't handle full_precision either
+                let (config, _full_precision) = layer_norm_config(node);
+                let mut norm = config.init(device);
+                norm.gamma = param(node, 1, device).expect("Gamma is required");
+                // The bias is initialized with zeros when it is missing
+                if let Some(beta) = param(node, 2, device) {
+                    norm.beta = beta;
+                }
+                Op::LayerNorm(norm)
+            }
+            NodeType::PRelu => {
+                let mut prelu = PReluConfig::new().init(device);
+                prelu.alpha = param(node, 1, device).expect("Alpha is required");
+                Op::PRelu(prelu)
+            }
+            NodeType::Dropout => Op::Dropout(dropout_config(node).init()),
+            NodeType::MaxPool1d => Op::MaxPool1d(max_pool1d_config(node).init()),
+            NodeType::MaxPool2d => Op::MaxPool2d(max_pool2d_config(node).init()),
+            NodeType::AveragePool1d => Op::AvgPool1d(avg_pool1d_config(node).init()),
+            NodeType::AveragePool2d => Op::AvgPool2d(avg_pool2d_config(node).init()),
+            NodeType::GlobalAveragePool => match input_rank(node, 0) {
+                3 => Op::GlobalAvgPool1d(AdaptiveAvgPool1dConfig::new(1).init()),
+                4 => Op::GlobalAvgPool2d(AdaptiveAvgPool2dConfig::new([1, 1]).init()),
+                dim => panic!("Unsupported input dim ({dim}) for GlobalAvgPoolNode"),
+            },
+            NodeType::InstanceNormalization => {
+                let mut norm = instance_norm_config(node).init(device);
+                norm.gamma = Some(param(node, 1, device).expect("Scale is required"));
+                norm.beta = Some(param(node, 2, device).expect("Bias is required"));
+                Op::InstanceNorm(norm)
+            }
+            NodeType::GroupNormalization => {
+                let config = group_norm_config(node);
+                let (gamma, beta) = group_norm_params::<FullPrecisionSettings>(node, &config);
+                let mut norm = config.init(device);
+                norm.gamma = Some(Param::from_tensor(Tensor::from_data(gamma.read(), device)));
+                norm.beta = Some(Param::from_tensor(Tensor::from_data(beta.read(), device)));
+                Op::GroupNorm(norm)
+            }
+            NodeType::Pad => Op::Pad(pad_config(node)),
+            NodeType::Split => Op::Split(split_config(node)),
+            NodeType::Tile => Op::Tile(tile_config(node)),
+            NodeType::TopK => Op::TopK(top_k_config(node)),
+            NodeType::Trilu => Op::Trilu(trilu_config(node)),
+            NodeType::OneHot => Op::OneHot(one_hot_config(node)),
+            NodeType::CumSum => Op::CumSum(cumsum_config(node)),
+            NodeType::NonZero => Op::NonZero,
+            NodeType::LSTM => {
+                let config = lstm_config(node);
+                let mut lstms = gate_weights::<FullPrecisionSettings>(node, 4)
+                    .into_iter()
+                    .map(|gates| {
+                        let gate =
+                            |index: usize| recurrent::gate_controller(gates[index].clone(), device);
+                        Lstm {
+                            input_gate: gate(INPUT_GATE),
+                            forget_gate: gate(FORGET_GATE),
+                            output_gate: gate(OUTPUT_GATE),
+                            cell_gate: gate(CELL_GATE),
+                            d_hidden: config.d_hidden,
+                        }
+                    })
+                    .collect::<Vec<_>>();
+
+                match config.direction {
+                    RnnDirection::Bidirectional => {
+                        let reverse = lstms.pop().unwrap();
+                        let forward = lstms.pop().unwrap();
+                        let d_hidden = config.d_hidden;
+                        Op::BiLstm(
+                            Box::new(BiLstm {
+                                forward,
+                                reverse,
+                                d_hidden,
+                            }),
+                            config,
+                        )
+                    }
+                    _ => Op::Lstm(Box::new(lstms.remove(0)), config),
+                }
+            }
+            NodeType::GRU => {
+                let (config, linear_before_reset) = gru_config(node);
+                // The ONNX gates are packed in the update, reset and new (hidden) order
+                let grus = gate_weights::<FullPrecisionSettings>(node, 3)
+                    .into_iter()
+                    .map(|gates| {
+                        let mut gates = gates
+                            .into_iter()
+                            .map(|gate| recurrent::gate_controller(gate, device));
+                        Gru {
+                            update_gate: gates.next().unwrap(),
+                            reset_gate: gates.next().unwrap(),
+                            new_gate: gates.next().unwrap(),
+                            d_hidden: config.d_hidden,
+                            reset_after: linear_before_reset,
+                        }
+                    })
+                    .collect();
+                Op::Gru(grus, config)
+            }
+            NodeType::RNN => {
+                let (config, activation) = rnn_config(node);
+                let gates = gate_weights::<FullPrecisionSettings>(node, 1)
+                    .into_iter()
+                    .map(|mut gates| recurrent::gate_controller(gates.remove(0), device))
+                    .collect();
+                Op::Rnn(gates, config, activation)
+            }
+            NodeType::If => {
+                let mut branch = |name: &str| {
+                    let graph = node.attrs.get(name).unwrap().clone().into_graph();
+                    Graph::new(graph, device, unsupported_ops)
+                };
+                let then_branch = branch("then_branch");
+                let else_branch = branch("else_branch");
+                Op::If(then_branch, else_branch)
+            }
+            NodeType::Loop => {
+                let body = node.attrs.get("body").unwrap().clone().into_graph();
+                Op::Loop(Graph::new(body, device, unsupported_ops))
+            }
+            NodeType::Scan => {
+                let body = node.attrs.get("body").unwrap().clone().into_graph();
+                Op::Scan(Graph::new(body, device, unsupported_ops), scan_config(node))
+            }
+            _ => return None,
+        };
+
+        Some(op)
+    }
+
+    /// Evaluates the node, taking its inputs from the values of the graph, and returns the values
+    /// of its outputs.
+    pub(crate) fn forward(&self, node: &Node, values: &mut Values<B>) -> Vec<Value<B>> {
+        match self {
+            Op::Split(config) => split(values.take(&node.inputs[0]), config, node.outputs.len()),
+            Op::TopK(config) => top_k(values.take(&node.inputs[0]), config),
+            Op::Lstm(..) | Op::BiLstm(..) | Op::Gru(..) | Op::Rnn(..) => {
+                self.recurrent_forward(node, values)
+            }
+            Op::If(..) | Op::Loop(..) | Op::Scan(..) => self.control_flow_forward(node, values),
+            op => vec![op.forward_output(node, values)],
+        }
+    }
+
+    /// Evaluates a node with a single output.
+    fn forward_output(&self, node: &Node, values: &mut Values<B>) -> Value<B> {
+        let mut input = |index: usize| values.take(&node.inputs[index]);
+
+        match self {
+            Op::Constant(value) => value.clone(),
+            Op::ConstantOfShape(value) => {
+                let shape = match input(0) {
+                    Value::Shape(shape) => shape.into_iter().map(|x| x as usize).collect(),
+                    Value::Int(shape) => shape
+                        .into_data()
+                        .iter::<i64>()
+                        .map(|x| x as usize)
+                        .collect(),
+                    value => panic!("ConstantOfShape expects a shape, got a {}", value.kind()),
+                };
+                constant_of_shape(shape, *value, &output_elem_type(node), values.device())
+            }
+            Op::Unary(op) => unary(op, input(0)),
+            Op::Binary(op) => {
+                let lhs = input(0);
+                let rhs = input(1);
+                match (&lhs, &rhs) {
+                    (Value::Shape(_), _) | (_, Value::Shape(_)) => shape_arithmetic(op, lhs, rhs),
+                    _ => binary(op, lhs, rhs),
+                }
+            }
+            Op::Cast(elem_type) => cast(input(0), elem_type),
+            Op::Flatten(start_dim, end_dim) => {
+                let rank = output_rank(node);
+                map_tensor!(input(0), tensor => flatten(tensor, *start_dim, *end_dim, rank))
+            }
+            Op::Transpose(axes) => map_tensor!(input(0), tensor => permute(tensor, axes)),
+            Op::Reduce(op, dim) => map_numeric!(input(0), tensor => reduce(op, *dim, tensor)),
+            Op::Shape(start_dim, end_dim) => {
+                let dims = match input(0) {
+                    Value::Float(tensor) => tensor.dims(),
+                    Value::Int(tensor) => tensor.dims(),
+                    Value::Bool(tensor) => tensor.dims(),
+                    value => panic!("Shape expects a tensor, got a {}", value.kind()),
+                };
+                Value::Shape(
+                    dims[*start_dim..*end_dim]
+                        .iter()
+                        .map(|x| *x as i64)
+                        .collect(),
+                )
+            }
+            Op::ArgMax(axis) => match input(0) {
+                Value::Float(tensor) => Value::Int(argmax(tensor, *axis)),
+                Value::Int(tensor) => Value::Int(argmax(tensor, *axis)),
+                value => panic!("ArgMax expects a numeric tensor, got a {}", value.kind()),
+            },
+            Op::Clip(min, max) => map_numeric!(input(0), tensor => clip(tensor, *min, *max)),
+            Op::Concat(dim) => {
+                let inputs = (0..node.inputs.len()).map(input).collect::<Vec<_>>();
+                match inputs[0] {
+                    Value::Float(_) => Value::Float(cat(inputs.into_iter().map(float), *dim)),
+                    Value::Int(_) => Value::Int(cat(inputs.into_iter().map(int), *dim)),
+                    Value::Bool(_) => Value::Bool(cat(inputs.into_iter().map(bool), *dim)),
+                    Value::Shape(_) => Value::Shape(inputs.into_iter().flat_map(shape).collect()),
+                    ref value => panic!("Concat expects tensors, got a {}", value.kind()),
+                }
+            }
+            Op::Expand(shape) => {
+                let tensor = input(0);
+                let shape = shape.clone().unwrap_or_else(|| runtime_shape(input(1)));
+                map_tensor!(tensor, tensor => expand(tensor, &shape))
+            }
+            Op::Gather(dim) => match (input(0), input(1)) {
+                // The dimensions of a runtime shape
+                (Value::Shape(shape), Value::Shape(index)) => {
+                    Value::Shape(index.into_iter().map(|i| shape[i as usize]).collect())
+                }
+                (tensor, index) => {
+                    let index = int(index).into_tensor::<1>();
+                    map_numeric!(tensor, tensor => visit_rank!(tensor, tensor => tensor.select(*dim, index).into()))
+                }
+            },
+            Op::GatherElements(dim) => {
+                let tensor = input(0);
+                let index = int(input(1));
+                map_numeric!(tensor, tensor => visit_rank_pair!(tensor, index, tensor, index => tensor.gather(*dim, index).into()))
+            }
+            Op::MatMul => {
+                let lhs = float(input(0));
+                let rhs = float(input(1));
+                Value::Float(matmul(lhs, rhs))
+            }
+            Op::Range => {
+                let start = int_scalar(input(0));
+                let end = int_scalar(input(1));
+                let step = int_scalar(input(2));
+                let tensor =
+                    Tensor::<B, 1, Int>::arange_step(start..end, step as usize, values.device());
+                Value::Int(tensor.into())
+            }
+            Op::Reshape(shape) => {
+                let tensor = input(0);
+                let shape = shape.clone().unwrap_or_else(|| runtime_shape(input(1)));
+                map_tensor!(tensor, tensor => reshape(tensor, &shape))
+            }
+            Op::Resize(options) => {
+                let tensor = float(input(0)).into_tensor::<4>();
+                let output_size = int(input(3)).into_data();
+                let output_size = output_size.iter::<i64>().collect::<Vec<_>>();
+                let output_size = match output_size[..] {
+                    [.., height, width] => [height as usize, width as usize],
+                    _ => panic!("Resize expects an output size with at least 2 dimensions"),
+                };
+
+                Value::Float(interpolate(tensor, output_size, options.clone()).into())
+            }
+            Op::Slice(ranges) => {
+                let tensor = input(0);
+                // The runtime bounds are read from the shapes evaluated before the node
+                let shapes = (1..node.inputs.len())
+                    .filter(|index| !is_static_input(node, *index))
+                    .map(|index| (node.inputs[index].name.clone(), shape(input(index))))
+                    .collect::<HashMap<_, _>>();
+                let bound = |bound: &SliceBound| match bound {
+                    SliceBound::Static(bound) => *bound,
+                    SliceBound::Runtime(shape, index) => shapes[&shape.name.to_string()][*index],
+                };
+                let ranges = ranges
+                    .iter()
+                    .map(|range| {
+                        range
+                            .as_ref()
+                            .map(|(start, end)| (bound(start), bound(end)))
+                    })
+                    .collect::<Vec<_>>();
+
+                match tensor {
+                    Value::Shape(shape) => Value::Shape(slice_shape(shape, ranges[0])),
+                    tensor => map_tensor!(tensor, tensor => slice(tensor, &ranges)),
+                }
+            }
+            Op::Squeeze(axes) => {
+                let rank = output_rank(node);
+                map_tensor!(input(0), tensor => squeeze_dims(tensor, axes, rank))
+            }
+            Op::Unsqueeze(axes) => {
+                let rank = output_rank(node);
+                match input(0) {
+                    // A runtime shape already has one dimension
+                    Value::Shape(shape) => Value::Shape(shape),
+                    Value::Scalar(scalar) => {
+                        let value = visit_scalar!(scalar, value => value.elem::<B::FloatElem>());
+                        let tensor = Tensor::<B, 1>::from_data([value], values.device());
+                        Value::Float(unsqueeze(tensor.into(), rank))
+                    }
+                    value => map_tensor!(value, tensor => unsqueeze_dims(tensor, axes, rank)),
+                }
+            }
+            Op::Sum => {
+                let inputs = (0..node.inputs.len()).map(input);
+                inputs
+                    .reduce(|lhs, rhs| binary(&BinaryOp::Add, lhs, rhs))
+                    .expect("Sum should have inputs")
+            }
+            Op::Where => {
+                let condition = bool(input(0));
+                let x = input(1);
+                let y = input(2);
+                match (x, y) {
+                    (Value::Float(x), Value::Float(y)) => Value::Float(mask_where(condition, x, y)),
+                    (Value::Int(x), Value::Int(y)) => Value::Int(mask_where(condition, x, y)),
+                    (x, y) => panic!(
+                        "Where expects numeric tensors of the same kind, got a {} and a {}",
+                        x.kind(),
+                        y.kind()
+                    ),
+                }
+            }
+            Op::Random(shape, distribution) => {
+                let tensor = with_rank!(shape.len(), D => {
+                    let shape: [usize; D] = shape[..].try_into().unwrap();
+                    Tensor::<B, D>::random(shape, *distribution, values.device()).into()
+                });
+                Value::Float(tensor)
+            }
+            Op::Conv1d(conv) => module_forward(input(0), |x: Tensor<B, 3>| conv.forward(x)),
+            Op::Conv2d(conv) => module_forward(input(0), |x: Tensor<B, 4>| conv.forward(x)),
+            Op::Conv3d(conv) => module_forward(input(0), |x: Tensor<B, 5>| conv.forward(x)),
+            Op::ConvTranspose2d(conv) => {
+                module_forward(input(0), |x: Tensor<B, 4>| conv.forward(x))
+            }
+            Op::ConvTranspose3d(conv) => {
+                module_forward(input(0), |x: Tensor<B, 5>| conv.forward(x))
+            }
+            Op::MaxPool1d(pool) => module_forward(input(0), |x: Tensor<B, 3>| pool.forward(x)),
+            Op::MaxPool2d(pool) => module_forward(input(0), |x: Tensor<B, 4>| pool.forward(x)),
+            Op::AvgPool1d(pool) => module_forward(input(0), |x: Tensor<B, 3>| pool.forward(x)),
+            Op::AvgPool2d(pool) => module_forward(input(0), |x: Tensor<B, 4>| pool.forward(x)),
+            Op::GlobalAvgPool1d(pool) => {
+                module_forward(input(0), |x: Tensor<B, 3>| pool.forward(x))
+            }
+            Op::GlobalAvgPool2d(pool) => {
+                module_forward(input(0), |x: Tensor<B, 4>| pool.forward(x))
+            }
+            Op::Linear(linear) => {
+                let tensor = float(input(0));
+                Value::Float(visit_rank!(tensor, tensor => linear.forward(tensor).into()))
+            }
+            Op::BatchNorm(norm) => Value::Float(norm.forward(float(input(0)))),
+            Op::LayerNorm(norm) => {
+                let tensor = float(input(0));
+                Value::Float(visit_rank!(tensor, tensor => norm.forward(tensor).into()))
+            }
+            Op::PRelu(prelu) => {
+                let tensor = float(input(0));
+                Value::Float(visit_rank!(tensor, tensor => prelu.forward(tensor).into()))
+            }
+            Op::Dropout(dropout) => {
+                let tensor = float(input(0));
+                Value::Float(visit_rank!(tensor, tensor => dropout.forward(tensor).into()))
+            }
+            Op::InstanceNorm(norm) => {
+                let tensor = float(input(0));
+                Value::Float(visit_rank!(tensor, tensor => norm.forward(tensor).into()))
+            }
+            Op::GroupNorm(norm) => {
+                let tensor = float(input(0));
+                Value::Float(visit_rank!(tensor, tensor => norm.forward(tensor).into()))
+            }
+            Op::Pad(config) => {
+                let pads = config.pads;
+                match input(0) {
+                    Value::Float(tensor) => Value::Float(
+                        visit_rank!(tensor, tensor => tensor.pad(pads, config.value.elem()).into()),
+                    ),
+                    Value::Int(tensor) => {
+                        let value = config.value as i64;
+                        Value::Int(
+                            visit_rank!(tensor, tensor => tensor.pad(pads, value.elem()).into()),
+                        )
+                    }
+                    value => panic!("Pad expects a numeric tensor, got a {}", value.kind()),
+                }
+            }
+            Op::Tile(repeats) => map_tensor!(input(0), tensor => tile(tensor, repeats)),
+            Op::Trilu(config) => map_numeric!(input(0), tensor => visit_rank!(tensor, tensor => {
+                let output = match config.upper {
+                    true => tensor.triu(config.diagonal),
+                    false => tensor.tril(config.diagonal),
+                };
+                output.into()
+            })),
+            Op::OneHot(config) => {
+                let indices = match input(0) {
+                    Value::Int(indices) => indices,
+                    Value::Float(indices) => visit_rank!(indices, t => t.int().into()),
+                    value => panic!("OneHot expects numeric indices, got a {}", value.kind()),
+                };
+                let rank = output_rank(node);
+                let classes = one_hot_classes(indices, config, rank);
+                let (off, on) = config.values;
+
+                with_rank!(rank, D => {
+                    let classes = classes.into_tensor::<D>();
+                    let device = classes.device();
+                    match output_elem_type(node) {
+                        ElementType::Int32 | ElementType::Int64 => Value::Int(
+                            Tensor::<B, D, Int>::full(classes.dims(), off as i64, &device)
+                                .mask_fill(classes, on as i64)
+                                .into(),
+                        ),
+                        ElementType::Bool => panic!("OneHot is not supported for bool outputs"),
+                        _ => Value::Float(
+                            Tensor::<B, D>::full(classes.dims(), off, &device)
+                                .mask_fill(classes, on)
+                                .into(),
+                        ),
+                    }
+                })
+            }
+            Op::CumSum(config) => map_numeric!(input(0), tensor => cumsum(tensor, config)),
+            Op::NonZero => {
+                let mask = match input(0) {
+                    Value::Bool(tensor) => tensor,
+                    Value::Float(tensor) => {
+                        visit_rank!(tensor, tensor => tensor.not_equal_elem(0).into())
+                    }
+                    Value::Int(tensor) => {
+                        visit_rank!(tensor, tensor => tensor.not_equal_elem(0).into())
+                    }
+                    value => panic!("NonZero expects a tensor, got a {}", value.kind()),
+                };
+                // Argwhere groups the indices by element
+                Value::Int(visit_rank!(mask, mask => mask.argwhere().transpose().into()))
+            }
+            Op::Split(..)
+            | Op::TopK(..)
+            | Op::Lstm(..)
+            | Op::BiLstm(..)
+            | Op::Gru(..)
+            | Op::Rnn(..)
+            | Op::If(..)
+            | Op::Loop(..)
+            | Op::Scan(..) => unreachable!("{:?} has multiple outputs", node.node_type),
+        }
+    }
+
+    /// Evaluates a LSTM, GRU or RNN node, like the generated models.
+    ///
+    /// The outputs are the hidden states of each step, the last hidden state and the last cell
+    /// state of the LSTM.
+    fn recurrent_forward(&self, node: &Node, values: &mut Values<B>) -> Vec<Value<B>> {
+        let mut optional_input = |index: usize| {
+            node.inputs
+                .get(index)
+                .filter(|arg| !arg.name.is_empty())
+                .map(|arg| float(values.take(arg)).into_tensor::<3>())
+        };
+        let input = optional_input(0).expect("X is required");
+        let initial_hidden = optional_input(5);
+
+        match self {
+            Op::Lstm(lstm, config) => {
+                let initial_cell = optional_input(6);
+                let state = |state| recurrent::direction_state(config, state, 0);
+                let state = lstm_state(initial_hidden.map(state), initial_cell.map(state));
+
+                let input = recurrent::input(config, input);
+                let sequence = recurrent::direction_order(config, input, 0);
+                let (output, state) = lstm.forward(sequence, state);
+                let output = recurrent::direction_order(config, output, 0);
+
+                vec![
+                    recurrent::stack_output(config, vec![output]).into(),
+                    recurrent::stack_state(config, vec![state.hidden]).into(),
+                    recurrent::stack_state(config, vec![state.cell]).into(),
+                ]
+            }
+            Op::BiLstm(lstm, config) => {
+                let initial_cell = optional_input(6);
+                // The states of both directions are stacked on the first axis
+                let swap = |state: Tensor<B, 3>| match config.batch_first {
+                    true => state.swap_dims(0, 1),
+                    false => state,
+                };
+                let state = lstm_state(initial_hidden.map(swap), initial_cell.map(swap));
+
+                let (output, state) = lstm.forward(recurrent::input(config, input), state);
+
+                // The hidden states of both directions are concatenated on the last axis
+                let [batch_size, seq_length, _] = output.dims();
+                let output = output.reshape([batch_size, seq_length, 2, config.d_hidden]);
+                let output = match config.batch_first {
+                    true => output,
+                    false => output.permute([1, 2, 0, 3]),
+                };
+
+                vec![
+                    output.into(),
+                    swap(state.hidden).into(),
+                    swap(state.cell).into(),
+                ]
+            }
+            Op::Gru(grus, config) => recurrent_directions(
+                config,
+                input,
+                initial_hidden,
+                |direction, sequence, state| grus[direction].forward(sequence, state),
+            ),
+            Op::Rnn(gates, config, activation) => recurrent_directions(
+                config,
+                input,
+                initial_hidden,
+                |direction, sequence, state| {
+                    let [batch_size, ..] = sequence.dims();
+                    let mut hidden = state.unwrap_or_else(|| {
+                        Tensor::zeros([batch_size, config.d_hidden], &sequence.device())
+                    });
+                    let mut output = Vec::new();
+                    for input_t in sequence.iter_dim(1) {
+                        let product = gates[direction].gate_product(input_t.squeeze(1), hidden);
+                        hidden = match activation {
+                            RnnActivation::Tanh => product.tanh(),
+                            RnnActivation::Relu => activation::relu(product),
+                            RnnActivation::Sigmoid => activation::sigmoid(product),
+                        };
+                        output.push(hidden.clone());
+                    }
+                    Tensor::stack(output, 1)
+                },
+            ),
+            _ => unreachable!("{:?} is not a recurrent node", node.node_type),
+        }
+    }
+
+    /// Evaluates an If, Loop or Scan node, evaluating its sub-graphs like the generated models.
+    fn control_flow_forward(&self, node: &Node, values: &mut Values<B>) -> Vec<Value<B>> {
+        let mut input = |index: usize| values.take(&node.inputs[index]);
+
+        match self {
+            Op::If(then_branch, else_branch) => {
+                let condition = bool_scalar(input(0));
+                // The variables of the enclosing graph used by the branches follow the condition
+                let captured = (1..node.inputs.len())
+                    .map(|index| (&node.inputs[index], input(index)))
+                    .collect::<Vec<_>>();
+
+                match condition {
+                    true => then_branch.forward(captured),
+                    false => else_branch.forward(captured),
+                }
+            }
+            Op::Loop(body) => {
+                let num_carried = body.inputs.len() - 2;
+                // The trip count and the condition are optional inputs, named "" when missing
+                let mut optional_input = |index: usize| match node.inputs[index].name.is_empty() {
+                    true => None,
+                    false => Some(input(index)),
+                };
+                let max_trip_count = optional_input(0).map(int_scalar).unwrap_or(i64::MAX);
+                let mut condition = optional_input(1).map(bool_scalar).unwrap_or(true);
+                let mut carried = (2..2 + num_carried).map(&mut input).collect::<Vec<_>>();
+                let captured = (2 + num_carried..node.inputs.len())
+                    .map(|index| (&node.inputs[index], input(index)))
+                    .collect::<Vec<_>>();
+
+                let mut scans = vec![Vec::new(); body.outputs.len() - 1 - num_carried];
+                let mut iteration = 0;
+                while iteration < max_trip_count && condition {
+                    let variables = [iteration.into(), condition.into()];
+                    let inputs = variables.into_iter().chain(carried);
+                    let mut outputs = body.forward(
+                        body.inputs
+                            .iter()
+                            .zip(inputs)
+                            .chain(captured.iter().map(|(arg, value)| (*arg, value.clone()))),
+                    );
+
+                    // The scan outputs are the values of the current iteration
+                    for (scan, value) in scans.iter_mut().zip(outputs.split_off(1 + num_carried)) {
+                        scan.push(value);
+                    }
+                    condition = bool_scalar(outputs.remove(0));
+                    carried = outputs;
+                    iteration += 1;
+                }
+
+                let device = values.device();
+                carried
+                    .into_iter()
+                    .chain(scans.into_iter().map(|scan| stack_scan(scan, 0, device)))
+                    .collect()
+            }
+            Op::Scan(body, config) => {
+                let num_scan_inputs = config.input_axes.len();
+                let num_states = body.inputs.len() - num_scan_inputs;
+                let mut states = (0..num_states).map(&mut input).collect::<Vec<_>>();
+                let scan_inputs = (num_states..num_states + num_scan_inputs)
+                    .map(&mut input)
+                    .collect::<Vec<_>>();
+                let captured = (num_states + num_scan_inputs..node.inputs.len())
+                    .map(|index| (&node.inputs[index], input(index)))
+                    .collect::<Vec<_>>();
+
+                // The elements of the scan inputs are sliced at each iteration
+                let length = tensor_dims(&scan_inputs[0])[config.input_axes[0]];
+                let mut scans = vec![Vec::new(); body.outputs.len() - num_states];
+                for iteration in 0..length {
+                    let elements = scan_inputs.iter().enumerate().map(|(i, tensor)| {
+                        let index = match config.input_reversed[i] {
+                            true => length - 1 - iteration,
+                            false => iteration,
+                        };
+                        let element = &body.inputs[num_states + i];
+                        scan_element(tensor.clone(), config.input_axes[i], index, &element.ty)
+                    });
+                    let inputs = states.into_iter().chain(elements);
+                    let mut outputs = body.forward(
+                        body.inputs
+                            .iter()
+                            .zip(inputs)
+                            .chain(captured.iter().map(|(arg, value)| (*arg, value.clone()))),
+                    );
+
+                    for (scan, value) in scans.iter_mut().zip(outputs.split_off(num_states)) {
+                        scan.push(value);
+                    }
+                    states = outputs;
+                }
+
+                let device = values.device();
+                let scans = scans.into_iter().enumerate().map(|(i, mut scan)| {
+                    if config.output_reversed[i] {
+                        scan.reverse();
+                    }
+                    stack_scan(scan, config.output_axes[i], device)
+                });
+                states.into_iter().chain(scans).collect()
+            }
+            _ => unreachable!("{:?} is not a control flow node", node.node_type),
+        }
+    }
+}
+
+impl<B: Backend> BatchNormModule<B> {
+    fn new(node: &Node, device: &B::Device) -> Self {
+        macro_rules! batch_norm {
+            ($dim:literal, $variant:ident) => {{
+                let mut norm = batch_norm_config(node).init::<B, $dim>(device);
+                norm.gamma = param(node, 1, device).expect("Gamma is required");
+                norm.beta = param(node, 2, device).expect("Beta is required");
+                norm.running_mean =
+                    RunningState::new(tensor(node, 3, device).expect("Running mean is required"));
+                norm.running_var =
+                    RunningState::new(tensor(node, 4, device).expect("Running var is required"));
+                BatchNormModule::$variant(norm)
+            }};
+        }
+
+        match input_rank(node, 0) - 2 {
+            0 => batch_norm!(0, Dim0),
+            1 => batch_norm!(1, Dim1),
+            2 => batch_norm!(2, Dim2),
+            3 => batch_norm!(3, Dim3),
+            4 => batch_norm!(4, Dim4),
+            dim => panic!("Unsupported dim {}", dim),
+        }
+    }
+
+    fn forward(&self, tensor: DynTensor<B>) -> DynTensor<B> {
+        match self {
+            BatchNormModule::Dim0(norm) => {
+                visit_rank!(tensor, tensor => norm.forward(tensor).into())
+            }
+            BatchNormModule::Dim1(norm) => {
+                visit_rank!(tensor, tensor => norm.forward(tensor).into())
+            }
+            BatchNormModule::Dim2(norm) => {
+                visit_rank!(tensor, tensor => norm.forward(tensor).into())
+            }
+            BatchNormModule::Dim3(norm) => {
+                visit_rank!(tensor, tensor => norm.forward(tensor).into())
+            }
+            BatchNormModule::Dim4(norm) => {
+                visit_rank!(tensor, tensor => norm.forward(tensor).into())
+            }
+        }
+    }
+}
+
+/// Loads the tensor of an initializer of the node.
+fn tensor<B: Backend, const D: usize>(
+    node: &Node,
+    index: usize,
+    device: &B::Device,
+) -> Option<Tensor<B, D>> {
+    extract_data_serialize::<FloatElem>(index, node)
+        .map(|data| Tensor::from_data(data.read(), device))
+}
+
+/// Loads the parameter of a module from an initializer of the node.
+fn param<B: Backend, const D: usize>(
+    node: &Node,
+    index: usize,
+    device: &B::Device,
+) -> Option<Param<Tensor<B, D>>> {
+    tensor(node, index, device).map(Param::from_tensor)
+}
+
+fn input_rank(node: &Node, index: usize) -> usize {
+    match &node.inputs[index].ty {
+        ArgType::Tensor(tensor) => tensor.dim,
+        ty => panic!("Expected a tensor input for {}, got {:?}", node.name, ty),
+    }
+}
+
+fn output_rank(node: &Node) -> usize {
+    match &node.outputs[0].ty {
+        ArgType::Tensor(tensor) => tensor.dim,
+        ArgType::Shape(dim) => *dim,
+        ArgType::Scalar(_) => 0,
+    }
+}
+
+fn output_elem_type(node: &Node) -> ElementType {
+    match &node.outputs[0].ty {
+        ArgType::Tensor(tensor) => tensor.elem_type.clone(),
+        ArgType::Scalar(elem_type) => elem_type.clone(),
+        ArgType::Shape(_) => ElementType::Int64,
+    }
+}
+
+fn to_i32(values: Vec<i64>) -> Vec<i32> {
+    values.into_iter().map(|x| x as i32).collect()
+}
+
+fn to_isize(values: Vec<i64>) -> Vec<isize> {
+    values.into_iter().map(|x| x as isize).collect()
+}
+
+/// Selects the power operation from the type of the exponent, like the generated models.
+fn pow_op(node: &Node) -> BinaryOp {
+    let elem_type = match &node.inputs[1].ty {
+        ArgType::Tensor(tensor) => &tensor.elem_type,
+        ArgType::Scalar(elem_type) => elem_type,
+        _ => panic!("pow function only supports RHS scalar or tensor types"),
+    };
+
+    match elem_type {
+        ElementType::Int32 | ElementType::Int64 => BinaryOp::Powi,
+        ElementType::Float32 | ElementType::Float64 => BinaryOp::Powf,
+        _ => panic!("pow function requires RHS to be int or float type"),
+    }
+}
+
+/// The value of the output elements of ConstantOfShape, which defaults to a float 0.
+///
+/// See <https://github.com/onnx/onnx/blob/main/docs/Operators.md#ConstantOfShape>.
+fn constant_of_shape_value(node: &Node) -> Scalar {
+    node.attrs
+        .get("value")
+        .and_then(|val| val.clone().into_tensor().data)
+        .map(|data| match data.load() {
+            Data::Float32s(vals) => Scalar::Float32(vals[0]),
+            Data::Float64s(vals) => Scalar::Float64(vals[0]),
+            Data::Int32s(vals) => Scalar::Int32(vals[0]),
+            Data::Int64s(vals) => Scalar::Int64(vals[0]),
+            Data::Bools(vals) => Scalar::Bool(vals[0]),
+            ty => panic!("Unsupported value type {:?} for ConstantOfShape!", ty),
+        })
+        .unwrap_or(Scalar::Float32(0.0))
+}
+
+fn random_distribution(node: &Node) -> Distribution {
+    let attr = |name: &str, default: f64| {
+        node.attrs
+            .get(name)
+            .map(|val| val.clone().into_f32() as f64)
+            .unwrap_or(default)
+    };
+
+    if node.attrs.contains_key("seed") {
+        warn!("seed attribute is not supported!");
+    }
+
+    match node.node_type {
+        NodeType::RandomNormal => Distribution::Normal(attr("mean", 0.0), attr("scale", 1.0)),
+        _ => Distribution::Uniform(attr("low", 0.0), attr("high", 1.0)),
+    }
+}
+
+fn float<B: Backend>(value: Value<B>) -> DynTensor<B, Float> {
+    match value {
+        Value::Float(tensor) => tensor,
+        value => panic!("Expected a float tensor, got a {}", value.kind()),
+    }
+}
+
+fn int<B: Backend>(value: Value<B>) -> DynTensor<B, Int> {
+    match value {
+        Value::Int(tensor) => tensor,
+        value => panic!("Expected an int tensor, got a {}", value.kind()),
+    }
+}
+
+fn bool<B: Backend>(value: Value<B>) -> DynTensor<B, Bool> {
+    match value {
+        Value::Bool(tensor) => tensor,
+        value => panic!("Expected a bool tensor, got a {}", value.kind()),
+    }
+}
+
+fn shape<B: Backend>(value: Value<B>) -> Vec<i64> {
+    match value {
+        Value::Shape(shape) => shape,
+        value => panic!("Expected a shape, got a {}", value.kind()),
+    }
+}
+
+/// The dimensions of a runtime shape, as the target shape of a tensor.
+fn runtime_shape<B: Backend>(value: Value<B>) -> Vec<i32> {
+    shape(value).into_iter().map(|dim| dim as i32).collect()
+}
+
+/// The value of an int scalar, or of the single element of an int tensor.
+fn int_scalar<B: Backend>(value: Value<B>) -> i64 {
+    match value {
+        Value::Scalar(Scalar::Int32(value)) => value as i64,
+        Value::Scalar(Scalar::Int64(value)) => value,
+        Value::Int(tensor) if tensor.dims().iter().product::<usize>() == 1 => {
+            tensor.into_data().iter::<i64>().next().unwrap()
+        }
+        value => panic!("Expected an int scalar, got a {}", value.kind()),
+    }
+}
+
+/// The value of a bool scalar, or of the single element of a bool tensor.
+fn bool_scalar<B: Backend>(value: Value<B>) -> bool {
+    match value {
+        Value::Scalar(Scalar::Bool(value)) => value,
+        Value::Bool(tensor) if tensor.dims().iter().product::<usize>() == 1 => {
+            tensor.into_data().iter::<bool>().next().unwrap()
+        }
+        value => panic!("Expected a bool scalar, got a {}", value.kind()),
+    }
+}
+
+fn tensor_dims<B: Backend>(value: &Value<B>) -> Vec<usize> {
+    match value {
+        Value::Float(tensor) => tensor.dims(),
+        Value::Int(tensor) => tensor.dims(),
+        Value::Bool(tensor) => tensor.dims(),
+        value => panic!("Expected a tensor, got a {}", value.kind()),
+    }
+}
+
+/// Evaluates a module on a float tensor of rank `D`.
+fn module_forward<B: Backend, const D: usize>(
+    value: Value<B>,
+    forward: impl FnOnce(Tensor<B, D>) -> Tensor<B, D>,
+) -> Value<B>
+where
+    DynTensor<B>: From<Tensor<B, D>>,
+{
+    Value::Float(forward(float(value).into_tensor()).into())
+}
+
+fn unary<B: Backend>(op: &UnaryOp, value: Value<B>) -> Value<B> {
+    match (op, value) {
+        (_, Value::Scalar(scalar)) => Value::Scalar(unary_scalar(op, scalar)),
+        (UnaryOp::Not, Value::Bool(tensor)) => {
+            Value::Bool(visit_rank!(tensor, tensor => tensor.bool_not().into()))
+        }
+        (UnaryOp::Neg, Value::Int(tensor)) => {
+            Value::Int(visit_rank!(tensor, tensor => tensor.neg().into()))
+        }
+        (UnaryOp::Sign, Value::Int(tensor)) => {
+            Value::Int(visit_rank!(tensor, tensor => tensor.sign().into()))
+        }
+        (op, Value::Float(tensor)) if !matches!(op, UnaryOp::Not) => {
+            Value::Float(visit_rank!(tensor, tensor => unary_float(op, tensor).into()))
+        }
+        (op, value) => panic!("{op:?} is not supported for a {}", value.kind()),
+    }
+}
+
+fn unary_float<B: Backend, const D: usize>(op: &UnaryOp, tensor: Tensor<B, D>) -> Tensor<B, D> {
+    match op {
+        UnaryOp::Erf => tensor.erf(),
+        UnaryOp::Relu => activation::relu(tensor),
+        UnaryOp::LeakyRelu(alpha) => activation::leaky_relu(tensor, *alpha),
+        UnaryOp::Sigmoid => activation::sigmoid(tensor),
+        UnaryOp::Tanh => activation::tanh(tensor),
+        UnaryOp::Gelu => activation::gelu(tensor),
+        UnaryOp::Elu(alpha) => activation::elu(tensor, *alpha),
+        UnaryOp::Selu(None, None) => activation::selu(tensor),
+        UnaryOp::Selu(alpha, gamma) => activation::elu(tensor, alpha.unwrap_or(SELU_ALPHA))
+            .mul_scalar(gamma.unwrap_or(SELU_GAMMA)),
+        UnaryOp::HardSigmoid(alpha, beta) => activation::hard_sigmoid(tensor, *alpha, *beta),
+        UnaryOp::HardSwish => activation::hard_swish(tensor),
+        UnaryOp::Softplus => activation::softplus(tensor, 1.0),
+        UnaryOp::LogSoftmax(dim) => activation::log_softmax(tensor, *dim),
+        UnaryOp::Softmax(dim) => activation::softmax(tensor, *dim),
+        UnaryOp::Sqrt => tensor.sqrt(),
+        UnaryOp::Reciprocal => tensor.recip(),
+        UnaryOp::Cos => tensor.cos(),
+        UnaryOp::Sin => tensor.sin(),
+        UnaryOp::Exp => tensor.exp(),
+        UnaryOp::Log => tensor.log(),
+        UnaryOp::Neg => tensor.neg(),
+        UnaryOp::Sign => tensor.sign(),
+        UnaryOp::Not => unreachable!("Not is only supported for bool tensors"),
+    }
+}
+
+/// Applies an element-wise operation to a scalar with the native operations, like the generated
+/// models.
+fn unary_scalar(op: &UnaryOp, scalar: Scalar) -> Scalar {
+    macro_rules! float_op {
+        ($x:expr) => {
+            match op {
+                UnaryOp::Sqrt => $x.sqrt(),
+                UnaryOp::Reciprocal => $x.recip(),
+                UnaryOp::Cos => $x.cos(),
+                UnaryOp::Sin => $x.sin(),
+                UnaryOp::Exp => $x.exp(),
+                UnaryOp::Log => $x.ln(),
+                UnaryOp::Neg => -$x,
+                op => panic!("{op:?} is not supported for scalars"),
+            }
+        };
+    }
+
+    match (op, scalar) {
+        (_, Scalar::Float32(x)) => Scalar::Float32(float_op!(x)),
+        (_, Scalar::Float64(x)) => Scalar::Float64(float_op!(x)),
+        (UnaryOp::Neg, Scalar::Int32(x)) => Scalar::Int32(-x),
+        (UnaryOp::Neg, Scalar::Int64(x)) => Scalar::Int64(-x),
+        (UnaryOp::Not, Scalar::Bool(x)) => Scalar::Bool(!x),
+        (op, scalar) => panic!("{op:?} is not supported for {scalar:?}"),
+    }
+}
+
+fn binary<B: Backend>(op: &BinaryOp, lhs: Value<B>, rhs: Value<B>) -> Value<B> {
+    match (lhs, rhs) {
+        (Value::Float(lhs), Value::Float(rhs)) => match op.is_comparison() {
+            true => Value::Bool(compare(op, lhs, rhs)),
+            false => Value::Float(arithmetic(op, lhs, rhs)),
+        },
+        (Value::Int(lhs), Value::Int(rhs)) => match op.is_comparison() {
+            true => Value::Bool(compare(op, lhs, rhs)),
+            false => Value::Int(arithmetic(op, lhs, rhs)),
+        },
+        (Value::Bool(lhs), Value::Bool(rhs)) if matches!(op, BinaryOp::Equal) => {
+            Value::Bool(visit_rank_pair!(lhs, rhs, lhs, rhs => lhs.equal(rhs).into()))
+        }
+        (Value::Float(tensor), Value::Scalar(scalar)) => {
+            Value::Float(arithmetic_scalar(op, tensor, scalar, false))
+        }
+        (Value::Int(tensor), Value::Scalar(scalar)) => {
+            Value::Int(arithmetic_scalar(op, tensor, scalar, false))
+        }
+        (Value::Scalar(scalar), Value::Float(tensor)) => {
+            Value::Float(arithmetic_scalar(op, tensor, scalar, true))
+        }
+        (Value::Scalar(scalar), Value::Int(tensor)) => {
+            Value::Int(arithmetic_scalar(op, tensor, scalar, true))
+        }
+        (Value::Scalar(lhs), Value::Scalar(rhs)) => Value::Scalar(binary_scalar(op, lhs, rhs)),
+        (lhs, rhs) => panic!(
+            "{op:?} is not supported between a {} and a {}",
+            lhs.kind(),
+            rhs.kind()
+        ),
+    }
+}
+
+impl BinaryOp {
+    fn is_comparison(&self) -> bool {
+        matches!(
+            self,
+            BinaryOp::Equal
+                | BinaryOp::Greater
+                | BinaryOp::GreaterOrEqual
+                | BinaryOp::Less
+                | BinaryOp::LessOrEqual
+        )
+    }
+}
+
+/// Elementwise arithmetic on runtime shapes, where the shapes of one element and the scalars are
+/// broadcast.
+fn shape_arithmetic<B: Backend>(op: &BinaryOp, lhs: Value<B>, rhs: Value<B>) -> Value<B> {
+    let operand = |value: Value<B>| match value {
+        Value::Shape(shape) => shape,
+        Value::Scalar(scalar) => vec![visit_scalar!(scalar, value => value.elem::<i64>())],
+        value => panic!(
+            "{op:?} is not supported between a shape and a {}",
+            value.kind()
+        ),
+    };
+    let (lhs, rhs) = (operand(lhs), operand(rhs));
+    let element = |shape: &[i64], i: usize| match shape.len() {
+        1 => shape[0],
+        _ => shape[i],
+    };
+
+    let shape = (0..max(lhs.len(), rhs.len()))
+        .map(|i| {
+            let (lhs, rhs) = (element(&lhs, i), element(&rhs, i));
+            match op {
+                BinaryOp::Add => lhs + rhs,
+                BinaryOp::Sub => lhs - rhs,
+                BinaryOp::Mul => lhs * rhs,
+                BinaryOp::Div => lhs / rhs,
+                _ => panic!("Only arithmetic is supported for shapes"),
+            }
+        })
+        .collect();
+
+    Value::Shape(shape)
+}
+
+fn arithmetic<B: Backend, K>(
+    op: &BinaryOp,
+    lhs: DynTensor<B, K>,
+    rhs: DynTensor<B, K>,
+) -> DynTensor<B, K>
+where
+    K: Numeric<B>,
+    K::Elem: Element,
+{
+    visit_rank_pair!(lhs, rhs, lhs, rhs => {
+        let output = match op {
+            BinaryOp::Add => lhs.add(rhs),
+            BinaryOp::Sub => lhs.sub(rhs),
+            BinaryOp::Mul => lhs.mul(rhs),
+            BinaryOp::Div => lhs.div(rhs),
+            BinaryOp::Powf => lhs.powf(rhs),
+            BinaryOp::Powi => lhs.powi(rhs),
+            BinaryOp::Min => lhs.min_pair(rhs),
+            BinaryOp::Max => lhs.max_pair(rhs),
+            op => unreachable!("{op:?} is a comparison"),
+        };
+        output.into()
+    })
+}
+
+fn compare<B: Backend, K>(
+    op: &BinaryOp,
+    lhs: DynTensor<B, K>,
+    rhs: DynTensor<B, K>,
+) -> DynTensor<B, Bool>
+where
+    K: Numeric<B>,
+    K::Elem: Element,
+{
+    visit_rank_pair!(lhs, rhs, lhs, rhs => {
+        let output = match op {
+            BinaryOp::Equal => lhs.equal(rhs),
+            BinaryOp::Greater => lhs.greater(rhs),
+            BinaryOp::GreaterOrEqual => lhs.greater_equal(rhs),
+            BinaryOp::Less => lhs.lower(rhs),
+            BinaryOp::LessOrEqual => lhs.lower_equal(rhs),
+            op => unreachable!("{op:?} is not a comparison"),
+        };
+        output.into()
+    })
+}
+
+/// Applies an operation between a tensor and a scalar, which is the left-hand side operand if
+/// `scalar_lhs` is true.
+fn arithmetic_scalar<B: Backend, K>(
+    op: &BinaryOp,
+    tensor: DynTensor<B, K>,
+    scalar: Scalar,
+    scalar_lhs: bool,
+) -> DynTensor<B, K>
+where
+    K: Numeric<B>,
+    K::Elem: Element,
+{
+    visit_rank!(tensor, tensor => visit_scalar!(scalar, value => {
+        let output = match (op, scalar_lhs) {
+            (BinaryOp::Add, _) => tensor.add_scalar(value),
+            (BinaryOp::Sub, false) => tensor.sub_scalar(value),
+            (BinaryOp::Sub, true) => tensor.sub_scalar(value).neg(),
+            (BinaryOp::Mul, _) => tensor.mul_scalar(value),
+            (BinaryOp::Div, false) => tensor.div_scalar(value),
+            (BinaryOp::Powf, false) => tensor.powf_scalar(value),
+            (BinaryOp::Powi, false) => tensor.powi_scalar(value),
+            (op, _) => panic!("{op:?} is not supported between a tensor and a scalar"),
+        };
+        output.into()
+    }))
+}
+
+/// Applies an operation between two scalars with the native operations, like the generated
+/// models.
+fn binary_scalar(op: &BinaryOp, lhs: Scalar, rhs: Scalar) -> Scalar {
+    macro_rules! native_op {
+        ($($variant:ident),*) => {
+            match (lhs, rhs) {
+                $(
+                    (Scalar::$variant(lhs), Scalar::$variant(rhs)) => match op {
+                        BinaryOp::Add => Scalar::$variant(lhs + rhs),
+                        BinaryOp::Sub => Scalar::$variant(lhs - rhs),
+                        BinaryOp::Mul => Scalar::$variant(lhs * rhs),
+                        BinaryOp::Div => Scalar::$variant(lhs / rhs),
+                        BinaryOp::Equal => Scalar::Bool(lhs == rhs),
+                        BinaryOp::Greater => Scalar::Bool(lhs > rhs),
+                        BinaryOp::GreaterOrEqual => Scalar::Bool(lhs >= rhs),
+                        BinaryOp::Less => Scalar::Bool(lhs < rhs),
+                        BinaryOp::LessOrEqual => Scalar::Bool(lhs <= rhs),
+                        op => panic!("{op:?} is not supported between scalars"),
+                    },
+                )*
+                (Scalar::Bool(lhs), Scalar::Bool(rhs)) if matches!(op, BinaryOp::Equal) => {
+                    Scalar::Bool(lhs == rhs)
+                }
+                (lhs, rhs) => panic!("{op:?} is not supported between {lhs:?} and {rhs:?}"),
+            }
+        };
+    }
+
+    native_op!(Float32, Float64, Int32, Int64)
+}
+
+/// Casts the value to the element type, with the same conversions as the generated models.
+fn cast<B: Backend>(value: Value<B>, elem_type: &ElementType) -> Value<B> {
+    let kind = match elem_type {
+        ElementType::Float16 | ElementType::Float32 | ElementType::Float64 => "float",
+        ElementType::Int32 | ElementType::Int64 => "int",
+        ElementType::Bool => "bool",
+        ElementType::String => panic!("String tensor unsupported"),
+    };
+
+    match (value, kind) {
+        (Value::Scalar(scalar), _) => Value::Scalar(cast_scalar(scalar, elem_type)),
+        // The runtime shapes keep their int64 dimensions
+        (Value::Shape(shape), _) => Value::Shape(shape),
+        (Value::Float(tensor), "float") => Value::Float(tensor),
+        (Value::Float(tensor), "int") => Value::Int(visit_rank!(tensor, t => t.int().into())),
+        (Value::Float(tensor), _) => Value::Bool(visit_rank!(tensor, t => t.bool().into())),
+        (Value::Int(tensor), "int") => Value::Int(tensor),
+        (Value::Int(tensor), "float") => Value::Float(visit_rank!(tensor, t => t.float().into())),
+        (Value::Int(tensor), _) => Value::Bool(visit_rank!(tensor, t => t.bool().into())),
+        (Value::Bool(tensor), "bool") => Value::Bool(tensor),
+        (Value::Bool(tensor), "float") => Value::Float(visit_rank!(tensor, t => t.float().into())),
+        (Value::Bool(tensor), _) => Value::Int(visit_rank!(tensor, t => t.int().into())),
+    }
+}
+
+fn cast_scalar(scalar: Scalar, elem_type: &ElementType) -> Scalar {
+    macro_rules! cast_to {
+        ($x:expr) => {
+            match elem_type {
+                ElementType::Float32 => Scalar::Float32($x as f32),
+                ElementType::Float64 => Scalar::Float64($x as f64),
+                ElementType::Int32 => Scalar::Int32($x as i32),
+                ElementType::Int64 => Scalar::Int64($x as i64),
+                _ => panic!("Cast from {scalar:?} to {elem_type:?} is not supported"),
+            }
+        };
+    }
+
+    match scalar {
+        Scalar::Float32(x) => cast_to!(x),
+        Scalar::Float64(x) => cast_to!(x),
+        Scalar::Int32(x) => cast_to!(x),
+        Scalar::Int64(x) => cast_to!(x),
+        Scalar::Bool(x) if matches!(elem_type, ElementType::Bool) => Scalar::Bool(x),
+        _ => panic!("Cast from {scalar:?} to {elem_type:?} is not supported"),
+    }
+}
+
+fn reduce<B: Backend, K>(
+    op: &ReduceOp,
+    dim: Option<usize>,
+    tensor: DynTensor<B, K>,
+) -> DynTensor<B, K>
+where
+    K: Numeric<B>,
+    K::Elem: Element,
+{
+    visit_rank!(tensor, tensor => match dim {
+        // keepdims=1, axes=[dim]
+        Some(dim) => {
+            let output = match op {
+                ReduceOp::Max => tensor.max_dim(dim),
+                ReduceOp::Min => tensor.min_dim(dim),
+                ReduceOp::Mean => tensor.mean_dim(dim),
+                ReduceOp::Prod => tensor.prod_dim(dim),
+                ReduceOp::Sum => tensor.sum_dim(dim),
+            };
+            output.into()
+        }
+        // keepdims=0, axes=None
+        None => {
+            let output = match op {
+                ReduceOp::Max => tensor.max(),
+                ReduceOp::Min => tensor.min(),
+                ReduceOp::Mean => tensor.mean(),
+                ReduceOp::Prod => tensor.prod(),
+                ReduceOp::Sum => tensor.sum(),
+            };
+            output.into()
+        }
+    })
+}
+
+fn argmax<B: Backend, K>(tensor: DynTensor<B, K>, axis: usize) -> DynTensor<B, Int>
+where
+    K: Numeric<B>,
+    K::Elem: Element,
+{
+    visit_rank!(tensor, tensor => tensor.argmax(axis).into())
+}
+
+fn clip<B: Backend, K>(
+    tensor: DynTensor<B, K>,
+    min: Option<f64>,
+    max: Option<f64>,
+) -> DynTensor<B, K>
+where
+    K: Numeric<B>,
+    K::Elem: Element,
+{
+    visit_rank!(tensor, tensor => {
+        let output = match (min, max) {
+            (Some(min), Some(max)) => tensor.clamp(min, max),
+            (Some(min), None) => tensor.clamp_min(min),
+            (None, Some(max)) => tensor.clamp_max(max),
+            (None, None) => panic!("Clip node must have at least one min or max value"),
+        };
+        output.into()
+    })
+}
+
+fn cat<B: Backend, K: BasicOps<B>>(
+    tensors: impl Iterator<Item = DynTensor<B, K>>,
+    dim: usize,
+) -> DynTensor<B, K> {
+    let tensors = tensors.collect::<Vec<_>>();
+    with_rank!(tensors[0].rank(), D => {
+        let tensors = tensors.into_iter().map(|tensor| tensor.into_tensor::<D>()).collect();
+        Tensor::cat(tensors, dim).into()
+    })
+}
+
+fn mask_where<B: Backend, K>(
+    condition: DynTensor<B, Bool>,
+    x: DynTensor<B, K>,
+    y: DynTensor<B, K>,
+) -> DynTensor<B, K>
+where
+    K: Numeric<B>,
+    K::Elem: Element,
+{
+    // x, y and condition need to be broadcastable
+    let rank = max(max(x.rank(), y.rank()), condition.rank());
+
+    with_rank!(rank, D => {
+        let condition = unsqueeze_to::<B, Bool, D>(condition);
+        let x = unsqueeze_to::<B, K, D>(x);
+        let y = unsqueeze_to::<B, K, D>(y);
+        y.mask_where(condition, x).into()
+    })
+}
+
+fn matmul<B: Backend>(lhs: DynTensor<B>, rhs: DynTensor<B>) -> DynTensor<B> {
+    let lhs_dim = lhs.rank();
+    let rhs_dim = rhs.rank();
+
+    // Support broadcasting for missing dimensions
+    match lhs_dim.cmp(&rhs_dim) {
+        Ordering::Greater => {
+            // Alternate unsqueeze(0) -> unsqueeze(-1) -> unsqueeze(0) -> ...
+            let axes = (0..lhs_dim - rhs_dim)
+                .map(|i| if i % 2 == 0 { 0 } else { -1 })
+                .collect::<Vec<isize>>();
+            let rhs = unsqueeze_dims(rhs, &axes, lhs_dim);
+            let output = visit_rank_pair!(lhs, rhs, lhs, rhs => lhs.matmul(rhs).into());
+
+            if rhs_dim == 1 {
+                // Matrix-vector product: squeeze(-1)
+                squeeze(output, lhs_dim - 1, lhs_dim - 1)
+            } else {
+                output
+            }
+        }
+        Ordering::Less => {
+            // Always unsqueeze(0)
+            let axes = [0].repeat(rhs_dim - lhs_dim);
+            let lhs = unsqueeze_dims(lhs, &axes, rhs_dim);
+            let output = visit_rank_pair!(lhs, rhs, lhs, rhs => lhs.matmul(rhs).into());
+
+            if lhs_dim == 1 {
+                // Vector-matrix product: squeeze(-2)
+                squeeze(output, rhs_dim - 2, rhs_dim - 1)
+            } else {
+                output
+            }
+        }
+        Ordering::Equal => visit_rank_pair!(lhs, rhs, lhs, rhs => lhs.matmul(rhs).into()),
+    }
+}
+
+fn constant_of_shape<B: Backend>(
+    shape: Vec<usize>,
+    value: Scalar,
+    elem_type: &ElementType,
+    device: &B::Device,
+) -> Value<B> {
+    with_rank!(shape.len(), D => {
+        let shape: [usize; D] = shape[..].try_into().unwrap();
+
+        match (value, elem_type) {
+            // Currently there is no full bool tensor support in the backend
+            // So we use 0 or 1 with bool type casting
+            (Scalar::Bool(true), _) => {
+                Value::Bool(Tensor::<B, D, Int>::ones(shape, device).bool().into())
+            }
+            (Scalar::Bool(false), _) => {
+                Value::Bool(Tensor::<B, D, Int>::zeros(shape, device).bool().into())
+            }
+            (value, ElementType::Int32 | ElementType::Int64) => Value::Int(
+                visit_scalar!(value, value => Tensor::<B, D, Int>::full(shape, value, device).into()),
+            ),
+            (value, _) => Value::Float(
+                visit_scalar!(value, value => Tensor::<B, D>::full(shape, value, device).into()),
+            ),
+        }
+    })
+}
+
+fn reshape<B: Backend, K: BasicOps<B>>(tensor: DynTensor<B, K>, shape: &[i32]) -> DynTensor<B, K> {
+    visit_rank!(tensor, tensor => with_rank!(shape.len(), D => {
+        let shape: [i32; D] = shape.try_into().unwrap();
+        tensor.reshape(shape).into()
+    }))
+}
+
+fn flatten<B: Backend, K: BasicOps<B>>(
+    tensor: DynTensor<B, K>,
+    start_dim: usize,
+    end_dim: usize,
+    rank: usize,
+) -> DynTensor<B, K> {
+    visit_rank!(tensor, tensor => with_rank!(rank, D => {
+        tensor.flatten::<D>(start_dim, end_dim).into()
+    }))
+}
+
+fn permute<B: Backend, K: BasicOps<B>>(tensor: DynTensor<B, K>, axes: &[isize]) -> DynTensor<B, K> {
+    visit_rank!(tensor, tensor => tensor.permute(axes.try_into().unwrap()).into())
+}
+
+fn expand<B: Backend, K: BasicOps<B>>(tensor: DynTensor<B, K>, shape: &[i32]) -> DynTensor<B, K> {
+    visit_rank!(tensor, tensor => with_rank!(shape.len(), D => {
+        let shape: [i32; D] = shape.try_into().unwrap();
+        tensor.expand(shape).into()
+    }))
+}
+
+fn slice<B: Backend, K: BasicOps<B>>(
+    tensor: DynTensor<B, K>,
+    ranges: &[Option<(i64, i64)>],
+) -> DynTensor<B, K> {
+    visit_rank!(tensor, tensor => with_rank!(ranges.len(), D => {
+        let ranges: [Option<(i64, i64)>; D] = ranges.try_into().unwrap();
+        tensor.slice(ranges).into()
+    }))
+}
+
+/// Slices a runtime shape with static bounds, like the generated models.
+fn slice_shape(shape: Vec<i64>, range: Option<(i64, i64)>) -> Vec<i64> {
+    let len = shape.len() as i64;
+    let clamp = |bound: i64| match bound < 0 {
+        true => (bound + len).clamp(0, len),
+        false => bound.clamp(0, len),
+    };
+    let (start, end) = match range {
+        Some((start, end)) => (clamp(start), clamp(end).max(clamp(start))),
+        None => (0, len),
+    };
+
+    shape[start as usize..end as usize].to_vec()
+}
+
+fn squeeze<B: Backend, K: BasicOps<B>>(
+    tensor: DynTensor<B, K>,
+    dim: usize,
+    rank: usize,
+) -> DynTensor<B, K> {
+    visit_rank!(tensor, tensor => with_rank!(rank, D => tensor.squeeze::<D>(dim).into()))
+}
+
+fn squeeze_dims<B: Backend, K: BasicOps<B>>(
+    tensor: DynTensor<B, K>,
+    axes: &[isize],
+    rank: usize,
+) -> DynTensor<B, K> {
+    visit_rank!(tensor, tensor => with_rank!(rank, D => tensor.squeeze_dims::<D>(axes).into()))
+}
+
+fn unsqueeze<B: Backend, K: BasicOps<B>>(tensor: DynTensor<B, K>, rank: usize) -> DynTensor<B, K> {
+    visit_rank!(tensor, tensor => with_rank!(rank, D => tensor.unsqueeze::<D>().into()))
+}
+
+fn unsqueeze_dims<B: Backend, K: BasicOps<B>>(
+    tensor: DynTensor<B, K>,
+    axes: &[isize],
+    rank: usize,
+) -> DynTensor<B, K> {
+    visit_rank!(tensor, tensor => with_rank!(rank, D => tensor.unsqueeze_dims::<D>(axes).into()))
+}
+
+/// Returns the tensor with rank `D`, inserting leading dimensions of size 1 if needed.
+fn unsqueeze_to<B: Backend, K: BasicOps<B>, const D: usize>(
+    tensor: DynTensor<B, K>,
+) -> Tensor<B, D, K> {
+    if tensor.rank() == D {
+        tensor.into_tensor()
+    } else {
+        visit_rank!(tensor, tensor => tensor.unsqueeze::<D>())
+    }
+}
+
+fn tile<B: Backend, K: BasicOps<B>>(tensor: DynTensor<B, K>, repeats: &[usize]) -> DynTensor<B, K> {
+    visit_rank!(tensor, tensor => {
+        let output = repeats
+            .iter()
+            .enumerate()
+            .filter(|(_, times)| **times != 1)
+            .fold(tensor, |tensor, (dim, times)| tensor.repeat(dim, *times));
+        output.into()
+    })
+}
+
+fn split<B: Backend>(value: Value<B>, config: &SplitConfig, num_outputs: usize) -> Vec<Value<B>> {
+    match value {
+        Value::Float(tensor) => split_tensor(tensor, config, num_outputs)
+            .into_iter()
+            .map(Value::Float)
+            .collect(),
+        Value::Int(tensor) => split_tensor(tensor, config, num_outputs)
+            .into_iter()
+            .map(Value::Int)
+            .collect(),
+        Value::Bool(tensor) => split_tensor(tensor, config, num_outputs)
+            .into_iter()
+            .map(Value::Bool)
+            .collect(),
+        value => panic!("Split expects a tensor, got a {}", value.kind()),
+    }
+}
+
+fn split_tensor<B: Backend, K: BasicOps<B>>(
+    tensor: DynTensor<B, K>,
+    config: &SplitConfig,
+    num_outputs: usize,
+) -> Vec<DynTensor<B, K>> {
+    visit_rank!(tensor, tensor => {
+        let Some(split_sizes) = &config.split_sizes else {
+            return tensor
+                .chunk(num_outputs, config.axis)
+                .into_iter()
+                .map(Into::into)
+                .collect();
+        };
+
+        let mut start = 0;
+        split_sizes
+            .iter()
+            .map(|size| {
+                let output = tensor.clone().narrow(config.axis, start, *size);
+                start += size;
+                output.into()
+            })
+            .collect()
+    })
+}
+
+fn top_k<B: Backend>(value: Value<B>, config: &TopKConfig) -> Vec<Value<B>> {
+    let (values, indices) = match value {
+        Value::Float(tensor) => {
+            let (values, indices) = top_k_tensor(tensor, config);
+            (Value::Float(values), indices)
+        }
+        Value::Int(tensor) => {
+            let (values, indices) = top_k_tensor(tensor, config);
+            (Value::Int(values), indices)
+        }
+        value => panic!("TopK expects a numeric tensor, got a {}", value.kind()),
+    };
+
+    vec![values, Value::Int(indices)]
+}
+
+fn top_k_tensor<B: Backend, K>(
+    tensor: DynTensor<B, K>,
+    config: &TopKConfig,
+) -> (DynTensor<B, K>, DynTensor<B, Int>)
+where
+    K: Numeric<B>,
+    K::Elem: Element,
+{
+    let (axis, k) = (config.axis, config.k);
+
+    visit_rank!(tensor, tensor => {
+        let (values, indices) = match config.largest {
+            true => tensor.topk_with_indices(k, axis),
+            false => {
+                let (values, indices) = tensor.sort_with_indices(axis);
+                (values.narrow(axis, 0, k), indices.narrow(axis, 0, k))
+            }
+        };
+        (values.into(), indices.into())
+    })
+}
+
+/// The mask of the one-hot encoding, true where the class along the axis is the index.
+fn one_hot_classes<B: Backend>(
+    indices: DynTensor<B, Int>,
+    config: &OneHotConfig,
+    rank: usize,
+) -> DynTensor<B, Bool> {
+    let num_classes = config.depth as i64;
+
+    with_rank!(rank, D => {
+        let indices: Tensor<B, D, Int> = visit_rank!(indices, indices => {
+            // Negative indices are counted from the last class
+            let indices = indices
+                .clone()
+                .mask_where(indices.clone().lower_elem(0), indices.add_scalar(num_classes));
+            indices.unsqueeze_dim(config.axis)
+        });
+        let indices = indices.repeat(config.axis, config.depth);
+        let dims = indices.dims();
+
+        // The classes are broadcast along all the dimensions but the axis
+        let mut classes_shape = [1; D];
+        classes_shape[config.axis] = config.depth;
+        let classes = Tensor::<B, 1, Int>::arange(0..num_classes, &indices.device())
+            .reshape(classes_shape)
+            .expand(dims);
+
+        indices.equal(classes).into()
+    })
+}
+
+fn cumsum<B: Backend, K>(tensor: DynTensor<B, K>, config: &CumSumConfig) -> DynTensor<B, K>
+where
+    K: Numeric<B>,
+    K::Elem: Element,
+{
+    let axis = config.axis;
+
+    visit_rank!(tensor, tensor => {
+        let tensor = match config.reverse {
+            true => tensor.flip([axis as isize]),
+            false => tensor,
+        };
+        let output = match config.exclusive {
+            true => tensor.clone().cumsum(axis).sub(tensor),
+            false => tensor.cumsum(axis),
+        };
+        let output = match config.reverse {
+            true => output.flip([axis as isize]),
+            false => output,
+        };
+        output.into()
+    })
+}
+
+/// The initial state of a LSTM, with zeros for the missing hidden or cell state.
+fn lstm_state<B: Backend, const D: usize>(
+    hidden: Option<Tensor<B, D>>,
+    cell: Option<Tensor<B, D>>,
+) -> Option<LstmState<B, D>> {
+    match (hidden, cell) {
+        (Some(hidden), Some(cell)) => Some(LstmState::new(cell, hidden)),
+        (Some(hidden), None) => Some(LstmState::new(hidden.zeros_like(), hidden)),
+        (None, Some(cell)) => Some(LstmState::new(cell.clone(), cell.zeros_like())),
+        (None, None) => None,
+    }
+}
+
+/// Processes the input sequence in each direction of a GRU or RNN node, and returns the stacked
+/// output sequences and last hidden states.
+fn recurrent_directions<B: Backend>(
+    config: &RecurrentConfig,
+    input: Tensor<B, 3>,
+    initial_hidden: Option<Tensor<B, 3>>,
+    forward: impl Fn(usize, Tensor<B, 3>, Option<Tensor<B, 2>>) -> Tensor<B, 3>,
+) -> Vec<Value<B>> {
+    let input = recurrent::input(config, input);
+
+    let (outputs, states): (Vec<_>, Vec<_>) = (0..config.num_directions())
+        .map(|direction| {
+            let sequence = recurrent::direction_order(config, input.clone(), direction);
+            let state = initial_hidden
+                .clone()
+                .map(|state| recurrent::direction_state(config, state, direction));
+            let output = forward(direction, sequence, state);
+            let output = recurrent::direction_order(config, output, direction);
+            let state = recurrent::last_state(config, &output, direction);
+            (output, state)
+        })
+        .unzip();
+
+    vec![
+        recurrent::stack_output(config, outputs).into(),
+        recurrent::stack_state(config, states).into(),
+    ]
+}
+
+/// The element of a scan input at the given index along the axis, with the type of the
+/// corresponding body input.
+fn scan_element<B: Backend>(
+    tensor: Value<B>,
+    axis: usize,
+    index: usize,
+    element: &ArgType,
+) -> Value<B> {
+    let slice = map_tensor!(tensor, tensor => {
+        visit_rank!(tensor, tensor => tensor.narrow(axis, index, 1).into())
+    });
+
+    match element {
+        ArgType::Tensor(element) if element.dim > 0 => {
+            map_tensor!(slice, tensor => squeeze(tensor, axis, element.dim))
+        }
+        ArgType::Tensor(TensorType { elem_type, .. }) | ArgType::Scalar(elem_type) => {
+            let scalar = match slice {
+                Value::Float(tensor) => Scalar::Float64(tensor.into_data().iter().next().unwrap()),
+                Value::Int(tensor) => Scalar::Int64(tensor.into_data().iter().next().unwrap()),
+                Value::Bool(tensor) => Scalar::Bool(tensor.into_data().iter().next().unwrap()),
+                _ => unreachable!(),
+            };
+            Value::Scalar(cast_scalar(scalar, elem_type))
+        }
+        ArgType::Shape(_) => panic!("Scan input elements must be scalars or tensors"),
+    }
+}
+
+/// Stacks the values of a scan output along the given dimension, like the generated models.
+fn stack_scan<B: Backend>(scan: Vec<Value<B>>, dim: usize, device: &B::Device) -> Value<B> {
+    let tensors = scan.into_iter().map(|value| match value {
+        // The scalars are concatenated as tensors of a single element, along the first dimension
+        Value::Scalar(scalar) => match scalar {
+            Scalar::Float32(value) => Tensor::<B, 1>::from_data([value], device).into(),
+            Scalar::Float64(value) => Tensor::<B, 1>::from_data([value], device).into(),
+            Scalar::Int32(value) => Tensor::<B, 1, Int>::from_data([value], device).into(),
+            Scalar::Int64(value) => Tensor::<B, 1, Int>::from_data([value], device).into(),
+            Scalar::Bool(value) => Tensor::<B, 1, Bool>::from_data([value], device).into(),
+        },
+        value => map_tensor!(value, tensor => {
+            let rank = tensor.rank();
+            unsqueeze_dims(tensor, &[dim as isize], rank + 1)
+        }),
+    });
+    let tensors = tensors.collect::<Vec<_>>();
+
+    match tensors.first() {
+        Some(Value::Float(_)) => Value::Float(cat(tensors.into_iter().map(float), dim)),
+        Some(Value::Int(_)) => Value::Int(cat(tensors.into_iter().map(int), dim)),
+        Some(Value::Bool(_)) => Value::Bool(cat(tensors.into_iter().map(bool), dim)),
+        _ => panic!("Scan outputs must have at least one iteration"),
+    }
+}
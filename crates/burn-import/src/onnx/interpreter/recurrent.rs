@@ -0,0 +1,132 @@
+//! The layouts of the sequences and states of the recurrent nodes, like the code generated for the
+//! [LSTM](crate::burn::node::lstm::LstmNode), [GRU](crate::burn::node::gru::GruNode) and
+//! [RNN](crate::burn::node::rnn::RnnNode) nodes.
+
+use burn::{
+    module::Param,
+    nn::{GateController, Linear, LinearConfig},
+    tensor::{backend::Backend, Tensor},
+};
+
+use super::ops::FloatElem;
+use crate::burn::{
+    node::rnn::{GateWeights, RecurrentConfig},
+    LazyTensorData,
+};
+
+/// Converts the input sequence to `[batch_size, seq_length, d_input]`.
+pub(crate) fn input<B: Backend>(config: &RecurrentConfig, input: Tensor<B, 3>) -> Tensor<B, 3> {
+    match config.batch_first {
+        true => input,
+        false => input.swap_dims(0, 1),
+    }
+}
+
+/// Flips the sequence `[batch_size, seq_length, features]` of a reversed direction, to process it
+/// from its start or to put it back in the order of the input sequence.
+pub(crate) fn direction_order<B: Backend>(
+    config: &RecurrentConfig,
+    sequence: Tensor<B, 3>,
+    direction: usize,
+) -> Tensor<B, 3> {
+    match config.is_reversed(direction) {
+        true => sequence.flip([1]),
+        false => sequence,
+    }
+}
+
+/// Gets the initial state `[batch_size, d_hidden]` of a direction.
+///
+/// The state of each direction is stacked on the axis 0, or the axis 1 for batch first sequences.
+pub(crate) fn direction_state<B: Backend>(
+    config: &RecurrentConfig,
+    state: Tensor<B, 3>,
+    direction: usize,
+) -> Tensor<B, 2> {
+    let axis = state_axis(config);
+
+    match config.num_directions() {
+        1 => state.squeeze(axis),
+        _ => state.narrow(axis, direction, 1).squeeze(axis),
+    }
+}
+
+/// Gets the last state `[batch_size, d_hidden]` of a direction from its output sequence, in the
+/// order of the input sequence.
+pub(crate) fn last_state<B: Backend>(
+    config: &RecurrentConfig,
+    output: &Tensor<B, 3>,
+    direction: usize,
+) -> Tensor<B, 2> {
+    let index = match config.is_reversed(direction) {
+        true => 0,
+        false => output.dims()[1] - 1,
+    };
+
+    output.clone().narrow(1, index, 1).squeeze(1)
+}
+
+/// Stacks the output sequences `[batch_size, seq_length, d_hidden]` of each direction, in the ONNX
+/// layout.
+pub(crate) fn stack_output<B: Backend>(
+    config: &RecurrentConfig,
+    mut outputs: Vec<Tensor<B, 3>>,
+) -> Tensor<B, 4> {
+    let output = match outputs.len() {
+        1 => outputs.remove(0).unsqueeze_dim(2),
+        _ => Tensor::stack(outputs, 2),
+    };
+
+    match config.batch_first {
+        true => output,
+        false => output.permute([1, 2, 0, 3]),
+    }
+}
+
+/// Stacks the last states `[batch_size, d_hidden]` of each direction, in the ONNX layout.
+pub(crate) fn stack_state<B: Backend>(
+    config: &RecurrentConfig,
+    mut states: Vec<Tensor<B, 2>>,
+) -> Tensor<B, 3> {
+    let axis = state_axis(config);
+
+    match states.len() {
+        1 => states.remove(0).unsqueeze_dim(axis),
+        _ => Tensor::stack(states, axis),
+    }
+}
+
+fn state_axis(config: &RecurrentConfig) -> usize {
+    match config.batch_first {
+        true => 1,
+        false => 0,
+    }
+}
+
+/// Creates a gate controller with the weights of a gate.
+pub(crate) fn gate_controller<B: Backend>(
+    gate: GateWeights,
+    device: &B::Device,
+) -> GateController<B> {
+    let linear = |weight: LazyTensorData, bias: Option<LazyTensorData>| -> Linear<B> {
+        let weight = Tensor::<B, 2>::from_data(weight.read().convert::<FloatElem>(), device);
+        let [d_input, d_output] = weight.dims();
+
+        let mut linear = LinearConfig::new(d_input, d_output)
+            .with_bias(bias.is_some())
+            .init(device);
+        linear.weight = Param::from_tensor(weight);
+        linear.bias = bias.map(|bias| {
+            Param::from_tensor(Tensor::from_data(
+                bias.read().convert::<FloatElem>(),
+                device,
+            ))
+        });
+        linear
+    };
+
+    GateController {
+        input_transform: linear(gate.input, gate.input_bias),
+        hidden_transform: linear(gate.hidden, gate.hidden_bias),
+    }
+}
@@ -0,0 +1,411 @@
+use burn::{
+    record::{FullPrecisionSettings, PrecisionSettings},
+    tensor::{backend::Backend, BasicOps, Bool, Float, Int, Tensor, TensorData, TensorKind},
+};
+use onnx_ir::ir::{ArgType, Argument, Data, ElementType};
+
+use crate::onnx::to_burn::serialize_data;
+
+/// The maximum rank of the tensors evaluated by the interpreter.
+pub const MAX_RANK: usize = 6;
+
+/// Matches a dynamic tensor with the tensor of its rank bound to `$tensor`.
+macro_rules! visit_rank {
+    ($value:expr, $tensor:ident => $body:expr) => {
+        match $value {
+            DynTensor::Rank1($tensor) => $body,
+            DynTensor::Rank2($tensor) => $body,
+            DynTensor::Rank3($tensor) => $body,
+            DynTensor::Rank4($tensor) => $body,
+            DynTensor::Rank5($tensor) => $body,
+            DynTensor::Rank6($tensor) => $body,
+        }
+    };
+}
+
+/// Matches two dynamic tensors of the same rank with their tensors bound to `$lhs` and `$rhs`.
+macro_rules! visit_rank_pair {
+    ($lhs_value:expr, $rhs_value:expr, $lhs:ident, $rhs:ident => $body:expr) => {
+        match ($lhs_value, $rhs_value) {
+            (DynTensor::Rank1($lhs), DynTensor::Rank1($rhs)) => $body,
+            (DynTensor::Rank2($lhs), DynTensor::Rank2($rhs)) => $body,
+            (DynTensor::Rank3($lhs), DynTensor::Rank3($rhs)) => $body,
+            (DynTensor::Rank4($lhs), DynTensor::Rank4($rhs)) => $body,
+            (DynTensor::Rank5($lhs), DynTensor::Rank5($rhs)) => $body,
+            (DynTensor::Rank6($lhs), DynTensor::Rank6($rhs)) => $body,
+            (lhs, rhs) => panic!(
+                "Expected tensors of the same rank, got ranks {} and {}",
+                lhs.rank(),
+                rhs.rank()
+            ),
+        }
+    };
+}
+
+/// Binds the rank `$rank`, only known at runtime, to the constant `$dim` so it can be used as
+/// the rank of the tensors created by `$body`.
+macro_rules! with_rank {
+    ($rank:expr, $dim:ident => $body:expr) => {
+        match $rank {
+            1 => {
+                const $dim: usize = 1;
+                $body
+            }
+            2 => {
+                const $dim: usize = 2;
+                $body
+            }
+            3 => {
+                const $dim: usize = 3;
+                $body
+            }
+            4 => {
+                const $dim: usize = 4;
+                $body
+            }
+            5 => {
+                const $dim: usize = 5;
+                $body
+            }
+            6 => {
+                const $dim: usize = 6;
+                $body
+            }
+            rank => panic!(
+                "Unsupported tensor rank {rank}, the maximum rank is {}",
+                $crate::onnx::interpreter::MAX_RANK
+            ),
+        }
+    };
+}
+
+pub(crate) use visit_rank;
+pub(crate) use visit_rank_pair;
+pub(crate) use with_rank;
+
+/// A tensor with a rank only known at runtime.
+#[derive(Debug, Clone)]
+pub enum DynTensor<B: Backend, K: TensorKind<B> = Float> {
+    /// A tensor of rank 1.
+    Rank1(Tensor<B, 1, K>),
+    /// A tensor of rank 2.
+    Rank2(Tensor<B, 2, K>),
+    /// A tensor of rank 3.
+    Rank3(Tensor<B, 3, K>),
+    /// A tensor of rank 4.
+    Rank4(Tensor<B, 4, K>),
+    /// A tensor of rank 5.
+    Rank5(Tensor<B, 5, K>),
+    /// A tensor of rank 6.
+    Rank6(Tensor<B, 6, K>),
+}
+
+macro_rules! dyn_tensor_from {
+    ($($dim:literal => $variant:ident),*) => {
+        $(
+            impl<B: Backend, K: TensorKind<B>> From<Tensor<B, $dim, K>> for DynTensor<B, K> {
+                fn from(tensor: Tensor<B, $dim, K>) -> Self {
+                    Self::$variant(tensor)
+                }
+            }
+        )*
+    };
+}
+
+dyn_tensor_from!(1 => Rank1, 2 => Rank2, 3 => Rank3, 4 => Rank4, 5 => Rank5, 6 => Rank6);
+
+impl<B: Backend, K: BasicOps<B>> DynTensor<B, K> {
+    /// Creates a tensor from the data, with the rank of its shape.
+    pub fn from_data(data: TensorData, device: &B::Device) -> Self {
+        with_rank!(data.shape.len(), D => Tensor::<B, D, K>::from_data(data, device).into())
+    }
+
+    /// The rank of the tensor.
+    pub fn rank(&self) -> usize {
+        visit_rank!(self, tensor => tensor.dims().len())
+    }
+
+    /// The dimensions of the tensor.
+    pub fn dims(&self) -> Vec<usize> {
+        visit_rank!(self, tensor => tensor.dims().to_vec())
+    }
+
+    /// Returns the data of the tensor.
+    pub fn into_data(self) -> TensorData {
+        visit_rank!(self, tensor => tensor.into_data())
+    }
+
+    /// Returns the tensor with its static rank.
+    ///
+    /// # Panics
+    ///
+    /// If the tensor is not of rank `D`.
+    pub fn into_tensor<const D: usize>(self) -> Tensor<B, D, K> {
+        assert_eq!(
+            self.rank(),
+            D,
+            "Expected a tensor of rank {D}, got rank {}",
+            self.rank()
+        );
+
+        visit_rank!(self, tensor => {
+            // Reshaping to the same dimensions only changes the static rank
+            let mut dims = [0; D];
+            dims.copy_from_slice(&tensor.dims());
+            tensor.reshape(dims)
+        })
+    }
+}
+
+/// A scalar value, which is a tensor of rank 0 in the ONNX graph.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub enum Scalar {
+    /// A 32-bit float.
+    Float32(f32),
+    /// A 64-bit float.
+    Float64(f64),
+    /// A 32-bit integer.
+    Int32(i32),
+    /// A 64-bit integer.
+    Int64(i64),
+    /// A boolean.
+    Bool(bool),
+}
+
+impl Scalar {
+    /// Creates a scalar of the given element type from the data of a constant.
+    pub(crate) fn from_data(data: Data, elem_type: &ElementType) -> Self {
+        let data = data.into_scalar();
+
+        match elem_type {
+            ElementType::Float32 => Scalar::Float32(data.into_f32()),
+            ElementType::Float64 => Scalar::Float64(data.into_f64()),
+            ElementType::Int32 => Scalar::Int32(data.into_i32()),
+            ElementType::Int64 => Scalar::Int64(data.into_i64()),
+            ElementType::Bool => Scalar::Bool(data.into_bool()),
+            _ => panic!("Unsupported scalar type: {:?}", elem_type),
+        }
+    }
+}
+
+impl From<f32> for Scalar {
+    fn from(value: f32) -> Self {
+        Self::Float32(value)
+    }
+}
+
+impl From<f64> for Scalar {
+    fn from(value: f64) -> Self {
+        Self::Float64(value)
+    }
+}
+
+impl From<i32> for Scalar {
+    fn from(value: i32) -> Self {
+        Self::Int32(value)
+    }
+}
+
+impl From<i64> for Scalar {
+    fn from(value: i64) -> Self {
+        Self::Int64(value)
+    }
+}
+
+impl From<bool> for Scalar {
+    fn from(value: bool) -> Self {
+        Self::Bool(value)
+    }
+}
+
+/// A value flowing through the graph, which is an input, an output or an intermediate result.
+///
+/// Values are created from the static tensors and scalars of the caller with [From], and
+/// converted back with the `into_*` methods.
+#[derive(Debug, Clone)]
+pub enum Value<B: Backend> {
+    /// A float tensor.
+    Float(DynTensor<B, Float>),
+    /// An int tensor.
+    Int(DynTensor<B, Int>),
+    /// A bool tensor.
+    Bool(DynTensor<B, Bool>),
+    /// A scalar.
+    Scalar(Scalar),
+    /// The dimensions of a tensor, produced by the `Shape` operator.
+    ///
+    /// Like the runtime shapes of the generated models, the dimensions are int64 values, so the
+    /// computed target shapes can hold the `-1` inferred dimension of a reshape.
+    Shape(Vec<i64>),
+}
+
+macro_rules! value_from_tensor {
+    ($($dim:literal),*) => {
+        $(
+            impl<B: Backend> From<Tensor<B, $dim>> for Value<B> {
+                fn from(tensor: Tensor<B, $dim>) -> Self {
+                    Self::Float(tensor.into())
+                }
+            }
+
+            impl<B: Backend> From<Tensor<B, $dim, Int>> for Value<B> {
+                fn from(tensor: Tensor<B, $dim, Int>) -> Self {
+                    Self::Int(tensor.into())
+                }
+            }
+
+            impl<B: Backend> From<Tensor<B, $dim, Bool>> for Value<B> {
+                fn from(tensor: Tensor<B, $dim, Bool>) -> Self {
+                    Self::Bool(tensor.into())
+                }
+            }
+        )*
+    };
+}
+
+value_from_tensor!(1, 2, 3, 4, 5, 6);
+
+macro_rules! value_from_scalar {
+    ($($ty:ty),*) => {
+        $(
+            impl<B: Backend> From<$ty> for Value<B> {
+                fn from(value: $ty) -> Self {
+                    Self::Scalar(value.into())
+                }
+            }
+        )*
+    };
+}
+
+value_from_scalar!(f32, f64, i32, i64, bool);
+
+impl<B: Backend> Value<B> {
+    /// Creates the value of a constant or an initializer of the graph, with the same element
+    /// types as the generated models (full precision).
+    ///
+    /// Returns `None` if the argument has no value.
+    pub(crate) fn from_argument(arg: &Argument, device: &B::Device) -> Option<Self> {
+        type FloatElem = <FullPrecisionSettings as PrecisionSettings>::FloatElem;
+        type IntElem = <FullPrecisionSettings as PrecisionSettings>::IntElem;
+
+        let data = arg.value.clone()?;
+
+        let value = match &arg.ty {
+            ArgType::Scalar(elem_type) => Value::Scalar(Scalar::from_data(data, elem_type)),
+            // Treat tensor with dim 0 as scalar
+            ArgType::Tensor(tensor) if tensor.dim == 0 => {
+                Value::Scalar(Scalar::from_data(data, &tensor.elem_type))
+            }
+            ArgType::Tensor(tensor) => {
+                let shape = tensor
+                    .shape
+                    .clone()
+                    .unwrap_or_else(|| panic!("The shape of {} should be known", arg.name));
+
+                match tensor.elem_type {
+                    ElementType::Float16 | ElementType::Float32 | ElementType::Float64 => {
+                        let data = serialize_data::<FloatElem>(data, shape);
+                        Value::Float(DynTensor::from_data(data, device))
+                    }
+                    ElementType::Int32 | ElementType::Int64 => {
+                        let data = serialize_data::<IntElem>(data, shape);
+                        Value::Int(DynTensor::from_data(data, device))
+                    }
+                    ElementType::Bool => {
+                        let data = TensorData::new(data.into_bools(), shape);
+                        Value::Bool(DynTensor::from_data(data, device))
+                    }
+                    ElementType::String => panic!("String tensor unsupported"),
+                }
+            }
+            ArgType::Shape(_) => Value::Shape(data.into_i64s()),
+        };
+
+        Some(value)
+    }
+
+    /// The kind of the value, used in error messages.
+    pub(crate) fn kind(&self) -> String {
+        match self {
+            Value::Float(tensor) => format!("float tensor of rank {}", tensor.rank()),
+            Value::Int(tensor) => format!("int tensor of rank {}", tensor.rank()),
+            Value::Bool(tensor) => format!("bool tensor of rank {}", tensor.rank()),
+            Value::Scalar(scalar) => format!("scalar {scalar:?}"),
+            Value::Shape(shape) => format!("shape {shape:?}"),
+        }
+    }
+
+    /// Returns the float tensor of rank `D`.
+    ///
+    /// # Panics
+    ///
+    /// If the value is not a float tensor of rank `D`.
+    pub fn into_float<const D: usize>(self) -> Tensor<B, D> {
+        match self {
+            Value::Float(tensor) => tensor.into_tensor(),
+            value => panic!("Expected a float tensor, got a {}", value.kind()),
+        }
+    }
+
+    /// Returns the int tensor of rank `D`.
+    ///
+    /// # Panics
+    ///
+    /// If the value is not an int tensor of rank `D`.
+    pub fn into_int<const D: usize>(self) -> Tensor<B, D, Int> {
+        match self {
+            Value::Int(tensor) => tensor.into_tensor(),
+            value => panic!("Expected an int tensor, got a {}", value.kind()),
+        }
+    }
+
+    /// Returns the bool tensor of rank `D`.
+    ///
+    /// # Panics
+    ///
+    /// If the value is not a bool tensor of rank `D`.
+    pub fn into_bool<const D: usize>(self) -> Tensor<B, D, Bool> {
+        match self {
+            Value::Bool(tensor) => tensor.into_tensor(),
+            value => panic!("Expected a bool tensor, got a {}", value.kind()),
+        }
+    }
+
+    /// Returns the scalar.
+    ///
+    /// # Panics
+    ///
+    /// If the value is not a scalar.
+    pub fn into_scalar(self) -> Scalar {
+        match self {
+            Value::Scalar(scalar) => scalar,
+            value => panic!("Expected a scalar, got a {}", value.kind()),
+        }
+    }
+
+    /// Returns the dimensions of the shape.
+    ///
+    /// # Panics
+    ///
+    /// If the value is not a shape.
+    pub fn into_shape(self) -> Vec<usize> {
+        match self {
+            Value::Shape(shape) => shape.into_iter().map(|dim| dim as usize).collect(),
+            value => panic!("Expected a shape, got a {}", value.kind()),
+        }
+    }
+
+    /// Returns the data of the tensor.
+    ///
+    /// # Panics
+    ///
+    /// If the value is not a tensor.
+    pub fn into_data(self) -> TensorData {
+        match self {
+            Value::Float(tensor) => tensor.into_data(),
+            Value::Int(tensor) => tensor.into_data(),
+            Value::Bool(tensor) => tensor.into_data(),
+            value => panic!("Expected a tensor, got a {}", value.kind()),
+        }
+    }
+}
@@ -1,3 +1,9 @@
+#[cfg(feature = "onnx-export")]
+mod export;
+mod interpreter;
 mod op_configuration;
 mod to_burn;
+#[cfg(feature = "onnx-export")]
+pub use export::*;
+pub use interpreter::*;
 pub use to_burn::*;
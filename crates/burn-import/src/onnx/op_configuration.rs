@@ -3,12 +3,24 @@ use burn::nn::{
         Conv1dConfig, Conv2dConfig, Conv3dConfig, ConvTranspose2dConfig, ConvTranspose3dConfig,
     },
     pool::{AvgPool1dConfig, AvgPool2dConfig, MaxPool1dConfig, MaxPool2dConfig},
-    BatchNormConfig, DropoutConfig, LayerNormConfig, LinearConfig, PaddingConfig1d,
-    PaddingConfig2d, PaddingConfig3d,
+    BatchNormConfig, DropoutConfig, GroupNormConfig, InstanceNormConfig, LayerNormConfig,
+    LinearConfig, PaddingConfig1d, PaddingConfig2d, PaddingConfig3d,
 };
 
-use crate::burn::node::resize::ResizeMode;
-use onnx_ir::ir::{ArgType, AttributeValue, Data, Node};
+use crate::burn::node::{
+    cumsum::CumSumConfig,
+    one_hot::OneHotConfig,
+    pad::PadConfig,
+    resize::ResizeMode,
+    rnn::{RecurrentConfig, RnnActivation, RnnDirection},
+    scan::ScanConfig,
+    slice::SliceBound,
+    split::SplitConfig,
+    top_k::TopKConfig,
+    trilu::TriluConfig,
+};
+use crate::burn::ShapeType;
+use onnx_ir::ir::{ArgType, Argument, AttributeValue, Data, Node};
 
 /// Create a Conv1dConfig from the attributes of the node
 pub fn conv1d_config(curr: &Node) -> Conv1dConfig {
@@ -383,12 +395,12 @@ pub fn avg_pool2d_config(curr: &Node) -> AvgPool2dConfig {
 }
 
 pub fn expand_config(node: &Node) -> Vec<i64> {
-    let input_value = &node.inputs[1].value;
+    let input_value = node.inputs[1].value.clone().map(Data::load);
     match &node.inputs[1].ty {
         ArgType::Tensor(tensor) => {
             assert_eq!(tensor.dim, 1, "Expand: shape tensor must be 1D");
-            if let Some(Data::Int64s(shape)) = input_value.as_ref() {
-                shape.clone()
+            if let Some(Data::Int64s(shape)) = input_value {
+                shape
             } else {
                 panic!("Tensor data type must be int64")
             }
@@ -453,9 +465,10 @@ pub fn gather_config(curr: &Node) -> usize {
         panic!("Gather: index tensor must be present");
     }
 
-    // extract the shape of the input tensor
-    let tensor = match curr.inputs.first().unwrap().clone().ty {
-        ArgType::Tensor(tensor) => tensor,
+    // extract the rank of the input tensor, a runtime shape being a rank 1 tensor
+    let rank = match curr.inputs.first().unwrap().clone().ty {
+        ArgType::Tensor(tensor) => tensor.dim,
+        ArgType::Shape(_) => 1,
         _ => panic!("Only tensor input is valid"),
     };
 
@@ -469,7 +482,7 @@ pub fn gather_config(curr: &Node) -> usize {
 
     // if dim is negative, it is counted from the end
     if dim < 0 {
-        dim += tensor.dim as i64;
+        dim += rank as i64;
     }
 
     dim as usize
@@ -507,12 +520,6 @@ pub fn linear_config(node: &Node) -> LinearConfig {
 
 /// Create a DropoutConfig from an attribute and state of the node
 pub fn dropout_config(node: &Node) -> DropoutConfig {
-    // Opset 7 and older store probability as an attribute
-    if node.attrs.contains_key("ratio") {
-        let prob = node.attrs.get("ratio").unwrap().clone().into_f32();
-        return DropoutConfig::new(prob as f64);
-    }
-
     if node.inputs.len() < 2 {
         panic!("Dropout configuration must have at least 2 inputs");
     }
@@ -660,9 +667,10 @@ pub fn concat_config(node: &Node) -> usize {
     // the axis is the last dimension (Default: 1 per ONNX spec)
     let mut axis: i64 = 1;
 
-    // extract the shape of the input tensor
-    let tensor = match node.inputs.first().unwrap().clone().ty {
-        ArgType::Tensor(tensor) => tensor,
+    // extract the rank of the input tensor, a runtime shape being a rank 1 tensor
+    let rank = match node.inputs.first().unwrap().clone().ty {
+        ArgType::Tensor(tensor) => tensor.dim,
+        ArgType::Shape(_) => 1,
         _ => panic!("Only tensor input is valid"),
     };
 
@@ -676,7 +684,7 @@ pub fn concat_config(node: &Node) -> usize {
 
     // if axis is negative, it is counted from the end
     if axis < 0 {
-        axis += tensor.dim as i64;
+        axis += rank as i64;
     }
 
     axis as usize
@@ -745,6 +753,61 @@ pub fn layer_norm_config(node: &Node) -> (LayerNormConfig, bool) {
     )
 }
 
+/// Create an InstanceNormConfig from the attributes of the node
+pub fn instance_norm_config(node: &Node) -> InstanceNormConfig {
+    // Extract the shape of the scale tensor
+    let tensor_type = if let ArgType::Tensor(ref tensor_type) = node.inputs[1].ty {
+        tensor_type
+    } else {
+        panic!("InstanceNorm: scale tensor must be present");
+    };
+
+    let num_channels: usize = tensor_type.shape.clone().unwrap()[0];
+
+    let mut epsilon = 1e-5;
+
+    for (key, value) in node.attrs.iter() {
+        match key.as_str() {
+            "epsilon" => epsilon = value.clone().into_f32(),
+            _ => {}
+        }
+    }
+
+    InstanceNormConfig::new(num_channels).with_epsilon(epsilon as f64)
+}
+
+/// Create a GroupNormConfig from the attributes of the node
+///
+/// The number of channels is read from the input shape when it is known, since the scale and
+/// bias of opset 18 hold one value per group. Otherwise, the scale is expected to hold one value
+/// per channel, as in opset 21.
+pub fn group_norm_config(node: &Node) -> GroupNormConfig {
+    let mut num_groups = None;
+    let mut epsilon = 1e-5;
+
+    for (key, value) in node.attrs.iter() {
+        match key.as_str() {
+            "num_groups" => num_groups = Some(value.clone().into_i64() as usize),
+            "epsilon" => epsilon = value.clone().into_f32(),
+            _ => {}
+        }
+    }
+
+    let num_groups =
+        num_groups.unwrap_or_else(|| panic!("{}: num_groups must be given", node.name));
+
+    let num_channels = match &node.inputs[0].ty {
+        ArgType::Tensor(tensor) => tensor.static_dim(1),
+        _ => panic!("{}: only tensor input is valid", node.name),
+    };
+    let num_channels = num_channels.unwrap_or_else(|| match &node.inputs[1].ty {
+        ArgType::Tensor(tensor) => tensor.shape.clone().unwrap()[0],
+        _ => panic!("GroupNorm: scale tensor must be present"),
+    });
+
+    GroupNormConfig::new(num_groups, num_channels).with_epsilon(epsilon as f64)
+}
+
 /// Calculate the padding configuration for a 1D operations such as Convolution and Pooling.
 ///
 /// # Arguments
@@ -874,6 +937,52 @@ pub fn leaky_relu_config(node: &Node) -> f64 {
     alpha
 }
 
+/// Get the alpha of the Elu activation from the attributes of the node
+pub fn elu_config(node: &Node) -> f64 {
+    let mut alpha = 1.0;
+
+    for (key, value) in node.attrs.iter() {
+        match key.as_str() {
+            "alpha" => alpha = value.clone().into_f32() as f64,
+            _ => {}
+        }
+    }
+
+    alpha
+}
+
+/// Get the alpha and gamma of the Selu activation, when they differ from the defaults
+pub fn selu_config(node: &Node) -> (Option<f64>, Option<f64>) {
+    let mut alpha = None;
+    let mut gamma = None;
+
+    for (key, value) in node.attrs.iter() {
+        match key.as_str() {
+            "alpha" => alpha = Some(value.clone().into_f32() as f64),
+            "gamma" => gamma = Some(value.clone().into_f32() as f64),
+            _ => {}
+        }
+    }
+
+    (alpha, gamma)
+}
+
+/// Get the alpha and beta of the HardSigmoid activation from the attributes of the node
+pub fn hard_sigmoid_config(node: &Node) -> (f64, f64) {
+    let mut alpha = 0.2;
+    let mut beta = 0.5;
+
+    for (key, value) in node.attrs.iter() {
+        match key.as_str() {
+            "alpha" => alpha = value.clone().into_f32() as f64,
+            "beta" => beta = value.clone().into_f32() as f64,
+            _ => {}
+        }
+    }
+
+    (alpha, beta)
+}
+
 pub fn reshape_config(node: &Node) -> Vec<i64> {
     let mut allowzero = 0;
 
@@ -895,13 +1004,13 @@ pub fn reshape_config(node: &Node) -> Vec<i64> {
         panic!("Reshape: shape tensor must be present for {:?}", node);
     }
 
-    let input_value = &node.inputs[1].value;
+    let input_value = node.inputs[1].value.clone().map(Data::load);
     match &node.inputs[1].ty {
         ArgType::Tensor(tensor) => {
             assert_eq!(tensor.dim, 1, "Reshape: shape tensor must be 1D");
 
-            if let Some(Data::Int64s(shape)) = input_value.as_ref() {
-                shape.clone()
+            if let Some(Data::Int64s(shape)) = input_value {
+                shape
             } else {
                 panic!("Tensor data type must be int64")
             }
@@ -935,14 +1044,6 @@ pub fn resize_config(node: &Node) -> ResizeMode {
 //Note this function should only execute if the second input is a constant
 //if it wasn't and the output shape was known, unsqueeze has been remapped to reshape
 pub fn unsqueeze_config(node: &Node) -> Vec<i64> {
-    // Check if axes attribute exists
-    for (key, value) in node.attrs.iter() {
-        match key.as_str() {
-            "axes" => return value.clone().into_i64s(),
-            _ => {}
-        }
-    }
-
     assert!(
         !node.inputs.is_empty(),
         "Unsqueeze: axes tensor must be present"
@@ -953,8 +1054,8 @@ pub fn unsqueeze_config(node: &Node) -> Vec<i64> {
     match &node.inputs[1].ty {
         ArgType::Tensor(tensor) => {
             assert_eq!(tensor.dim, 1, "Unsqueeze: axes tensor must be 1D");
-            if let Some(Data::Int64s(shape)) = input_value.value.as_ref() {
-                shape.clone()
+            if let Some(Data::Int64s(shape)) = input_value.value.clone().map(Data::load) {
+                shape
             } else {
                 panic!("Tensor data type must be int64")
             }
@@ -967,50 +1068,30 @@ pub fn clip_config(node: &Node) -> (Option<f64>, Option<f64>) {
     let mut min_result: Option<f64> = None;
     let mut max_result: Option<f64> = None;
 
-    // For Clip Opset 6+ , the min and max values are attributes
-    for (key, value) in node.attrs.iter() {
-        match key.as_str() {
-            "min" => {
-                let min = value.clone().into_f32() as f64;
-                min_result = Some(min);
-            }
-            "max" => {
-                let max = value.clone().into_f32();
-                max_result = Some(max as f64);
-            }
-            _ => {}
-        }
-    }
+    // The min and max values are optional inputs
+    let min = node.inputs.get(1).and_then(|input| input.value.clone());
+    let max = node.inputs.get(2).and_then(|input| input.value.clone());
 
-    // For Clip Opset 11+ , the min and max values are inputs
-    // Get the min and max values from the input values
-    if min_result.is_none() && max_result.is_none() {
-        let min = &node.inputs[1].value;
-        let max = &node.inputs[2].value;
-
-        if min_result.is_none() && min.is_some() {
-            let min = min.clone().unwrap().into_scalar();
-            min_result = match min {
-                Data::Float16(min) => Some(f32::from(min) as f64),
-                Data::Float32(min) => Some(min as f64),
-                Data::Float64(min) => Some(min),
-                _ => panic!("Clip: only float min is supported"),
-            };
-        }
+    if let Some(min) = min {
+        min_result = match min.into_scalar() {
+            Data::Float16(min) => Some(f32::from(min) as f64),
+            Data::Float32(min) => Some(min as f64),
+            Data::Float64(min) => Some(min),
+            _ => panic!("Clip: only float min is supported"),
+        };
+    }
 
-        if max_result.is_none() && max.is_some() {
-            let max = max.clone().unwrap().into_scalar();
-            max_result = match max {
-                Data::Float16(max) => Some(f32::from(max) as f64),
-                Data::Float32(max) => Some(max as f64),
-                Data::Float64(max) => Some(max),
-                _ => panic!("Clip: only float max is supported"),
-            };
-        }
+    if let Some(max) = max {
+        max_result = match max.into_scalar() {
+            Data::Float16(max) => Some(f32::from(max) as f64),
+            Data::Float32(max) => Some(max as f64),
+            Data::Float64(max) => Some(max),
+            _ => panic!("Clip: only float max is supported"),
+        };
     }
 
     if min_result.is_none() && max_result.is_none() {
-        panic!("Clip: min and max values must be either attributes or inputs");
+        panic!("Clip: min or max value must be given");
     }
 
     (min_result, max_result)
@@ -1028,12 +1109,15 @@ pub fn reduce_max_config(node: &Node) -> Option<usize> {
     // Extract the attributes
     for (key, value) in node.attrs.iter() {
         match key.as_str() {
-            "axes" => axes = value.clone().into_i64s(),
             "keepdims" => keepdims = value.clone().into_i64(),
             _ => {}
         }
     }
 
+    if has_input(node, 1) {
+        axes = constant_ints(node, 1);
+    }
+
     if axes.len() > 1 {
         panic!("ReduceMax: reducing on multiple dimensions is not supported")
     }
@@ -1072,12 +1156,15 @@ pub fn reduce_min_config(node: &Node) -> Option<usize> {
     // Extract the attributes
     for (key, value) in node.attrs.iter() {
         match key.as_str() {
-            "axes" => axes = value.clone().into_i64s(),
             "keepdims" => keepdims = value.clone().into_i64(),
             _ => {}
         }
     }
 
+    if has_input(node, 1) {
+        axes = constant_ints(node, 1);
+    }
+
     if axes.len() > 1 {
         panic!("ReduceMin: reducing on multiple dimensions is not supported")
     }
@@ -1114,12 +1201,15 @@ pub fn reduce_mean_config(node: &Node) -> Option<usize> {
     // Extract the attributes
     for (key, value) in node.attrs.iter() {
         match key.as_str() {
-            "axes" => axes = value.clone().into_i64s(),
             "keepdims" => keepdims = value.clone().into_i64(),
             _ => {}
         }
     }
 
+    if has_input(node, 1) {
+        axes = constant_ints(node, 1);
+    }
+
     if axes.len() > 1 {
         panic!("ReduceMean: reducing on multiple dimensions is not supported")
     }
@@ -1158,13 +1248,16 @@ pub fn reduce_prod_config(node: &Node) -> Option<usize> {
     // Extract the attributes
     for (key, value) in node.attrs.iter() {
         match key.as_str() {
-            "axes" => axes = value.clone().into_i64s(),
             "keepdims" => keepdims = value.clone().into_i64(),
             // TODO: handle noop_with_empty_axes (opset 18)
             _ => {}
         }
     }
 
+    if has_input(node, 1) {
+        axes = constant_ints(node, 1);
+    }
+
     if axes.len() > 1 {
         panic!("ReduceProd: reducing on multiple dimensions is not supported")
     }
@@ -1204,7 +1297,6 @@ pub fn reduce_sum_config(node: &Node) -> Option<usize> {
     for (key, value) in node.attrs.iter() {
         match key.as_str() {
             "keepdims" => keepdims = value.clone().into_i64(),
-            "axes" => axes = value.clone().into_i64s(),
             // TODO: handle noop_with_empty_axes
             _ => {}
         }
@@ -1290,37 +1382,29 @@ pub fn slice_config(node: &Node) -> Vec<Option<(i64, i64)>> {
             return Vec::new();
         }
 
-        match &node.inputs[index].value {
-            Some(Data::Int64s(shape)) => shape.clone(),
+        match node.inputs[index].value.clone().map(Data::load) {
+            Some(Data::Int64s(shape)) => shape,
 
             _ => panic!("Tensor data type must be int64"),
         }
     }
 
-    let mut starts = get_input_values(node, 1);
-    let mut ends = get_input_values(node, 2);
+    let starts = get_input_values(node, 1);
+    let ends = get_input_values(node, 2);
     let mut axes = get_input_values(node, 3);
-    let mut steps = get_input_values(node, 4);
+    let steps = get_input_values(node, 4);
 
     // https://burn.dev/docs/burn/prelude/struct.Tensor.html#method.slice
     // TODO default missing axes ranges to the full range of the corresponding axis
-    for (key, value) in node.attrs.iter() {
-        match key.as_str() {
-            "starts" => starts = value.clone().into_i64s(),
-            "ends" => ends = value.clone().into_i64s(),
-            "axes" => axes = value.clone().into_i64s(),
-            "steps" => steps = value.clone().into_i64s(),
-            _ => {}
-        }
-    }
 
     if !steps.is_empty() && steps.iter().any(|&x| x != 1) {
         panic!("Slice: steps other than 1 are not supported");
     }
 
-    // Extract the shape of the input tensor
+    // Extract the shape of the input tensor, a runtime shape being a rank 1 tensor
     let input_dim = match node.inputs.first().unwrap().clone().ty {
         ArgType::Tensor(tensor) => tensor.dim,
+        ArgType::Shape(_) => 1,
         _ => panic!("Only tensor input is valid"),
     };
 
@@ -1352,6 +1436,72 @@ pub fn slice_config(node: &Node) -> Vec<Option<(i64, i64)>> {
     ranges
 }
 
+/// Create the ranges of a Slice node whose starts or ends are computed at runtime, e.g. from the
+/// shape of another tensor.
+pub fn slice_runtime_config(node: &Node) -> Vec<Option<(SliceBound, SliceBound)>> {
+    fn get_input_bounds(node: &Node, index: usize) -> Vec<SliceBound> {
+        let input = &node.inputs[index];
+
+        match (&input.ty, input.value.clone().map(Data::load)) {
+            (_, Some(Data::Int64s(values))) => values.into_iter().map(SliceBound::Static).collect(),
+            (ArgType::Shape(dim), None) => {
+                let shape = ShapeType::new(input.name.clone(), *dim);
+                (0..*dim)
+                    .map(|i| SliceBound::Runtime(shape.clone(), i))
+                    .collect()
+            }
+            _ => panic!(
+                "Slice: the runtime bounds must be shapes, got {:?}",
+                input.ty
+            ),
+        }
+    }
+
+    let starts = get_input_bounds(node, 1);
+    let ends = get_input_bounds(node, 2);
+    let axes = match node
+        .inputs
+        .get(3)
+        .map(|input| input.value.clone().map(Data::load))
+    {
+        Some(Some(Data::Int64s(axes))) => axes,
+        Some(_) => panic!("Slice: the axes must be constant"),
+        None => (0..starts.len() as i64).collect(),
+    };
+
+    if let Some(Some(Data::Int64s(steps))) = node
+        .inputs
+        .get(4)
+        .map(|input| input.value.clone().map(Data::load))
+    {
+        if steps.iter().any(|&x| x != 1) {
+            panic!("Slice: steps other than 1 are not supported");
+        }
+    }
+
+    let input_dim = match &node.inputs[0].ty {
+        ArgType::Tensor(tensor) => tensor.dim,
+        _ => panic!("Slice: only tensors can be sliced with runtime bounds"),
+    };
+
+    if starts.len() != ends.len() || starts.len() != axes.len() {
+        panic!("Slice: starts, ends, and axes must have the same length");
+    }
+
+    let mut ranges = vec![None; input_dim];
+    for ((axis, start), end) in axes.into_iter().zip(starts).zip(ends) {
+        // Negative axes are counted from the end
+        let axis = if axis < 0 {
+            axis + input_dim as i64
+        } else {
+            axis
+        };
+        ranges[axis as usize] = Some((start, end));
+    }
+
+    ranges
+}
+
 pub fn transpose_config(curr: &Node) -> Vec<i64> {
     if curr.inputs.len() != 1 {
         panic!(
@@ -1377,18 +1527,10 @@ pub fn transpose_config(curr: &Node) -> Vec<i64> {
 }
 
 pub fn squeeze_config(curr: &Node) -> Vec<i64> {
-    let axes = curr
-        .attrs
-        .iter()
-        .filter_map(|(key, value)| {
-            if key == "axes" {
-                Some(value.clone().into_i64s())
-            } else {
-                None
-            }
-        })
-        .next()
-        .unwrap_or_else(Vec::new);
+    let axes = match has_input(curr, 1) {
+        true => constant_ints(curr, 1),
+        false => Vec::new(),
+    };
 
     match curr.inputs.first().unwrap().clone().ty {
         ArgType::Tensor(tensor) => tensor,
@@ -1397,3 +1539,397 @@ pub fn squeeze_config(curr: &Node) -> Vec<i64> {
 
     axes
 }
+
+/// Create a ScanConfig from the attributes of the node
+pub fn scan_config(node: &Node) -> ScanConfig {
+    let mut num_scan_inputs = 0;
+    let mut input_axes = Vec::new();
+    let mut input_directions = Vec::new();
+    let mut output_axes = Vec::new();
+    let mut output_directions = Vec::new();
+    let mut num_body_inputs = 0;
+
+    for (key, value) in node.attrs.iter() {
+        match key.as_str() {
+            "num_scan_inputs" => num_scan_inputs = value.clone().into_i64() as usize,
+            "scan_input_axes" => input_axes = value.clone().into_i64s(),
+            "scan_input_directions" => input_directions = value.clone().into_i64s(),
+            "scan_output_axes" => output_axes = value.clone().into_i64s(),
+            "scan_output_directions" => output_directions = value.clone().into_i64s(),
+            "body" => {
+                if let AttributeValue::Graph(body) = value {
+                    num_body_inputs = body.inputs.len();
+                }
+            }
+            _ => {}
+        }
+    }
+
+    // The node inputs are the states and the scan inputs, followed by the captured variables
+    let num_states = num_body_inputs - num_scan_inputs;
+    let num_scan_outputs = node.outputs.len() - num_states;
+
+    let rank = |arg: &Argument| match &arg.ty {
+        ArgType::Tensor(tensor) => tensor.dim as i64,
+        _ => panic!("Scan inputs and outputs must be tensors"),
+    };
+    // If axis is negative, it is counted from the end
+    let axis = |axes: &[i64], i: usize, rank: i64| match axes.get(i).copied().unwrap_or(0) {
+        axis if axis < 0 => (axis + rank) as usize,
+        axis => axis as usize,
+    };
+    let reversed = |directions: &[i64], i: usize| directions.get(i).copied().unwrap_or(0) == 1;
+
+    let input_reversed = (0..num_scan_inputs)
+        .map(|i| reversed(&input_directions, i))
+        .collect();
+    let output_reversed = (0..num_scan_outputs)
+        .map(|i| reversed(&output_directions, i))
+        .collect();
+    let input_axes = (0..num_scan_inputs)
+        .map(|i| axis(&input_axes, i, rank(&node.inputs[num_states + i])))
+        .collect();
+    let output_axes = (0..num_scan_outputs)
+        .map(|i| axis(&output_axes, i, rank(&node.outputs[num_states + i])))
+        .collect();
+
+    ScanConfig::new(input_axes, input_reversed, output_axes, output_reversed)
+}
+
+/// Create a RecurrentConfig from the attributes of a LSTM, GRU or RNN node, and return it with the
+/// activation functions of each direction.
+///
+/// The features of the operators without an equivalent in the Burn modules are rejected.
+fn recurrent_config(node: &Node, default_activations: &[&str]) -> (RecurrentConfig, Vec<String>) {
+    let mut hidden_size = None;
+    let mut direction = RnnDirection::Forward;
+    let mut batch_first = false;
+    let mut activations = None;
+
+    for (key, value) in node.attrs.iter() {
+        match key.as_str() {
+            "hidden_size" => hidden_size = Some(value.clone().into_i64() as usize),
+            "direction" => {
+                direction = match value.clone().into_string().as_str() {
+                    "forward" => RnnDirection::Forward,
+                    "reverse" => RnnDirection::Reverse,
+                    "bidirectional" => RnnDirection::Bidirectional,
+                    direction => panic!("{}: unsupported direction {direction}", node.name),
+                }
+            }
+            "layout" => batch_first = value.clone().into_i64() == 1,
+            "activations" => activations = Some(value.clone().into_strings()),
+            "activation_alpha" | "activation_beta" => {}
+            "clip" => panic!("{}: cell clipping is not supported", node.name),
+            "input_forget" if value.clone().into_i64() != 0 => panic!(
+                "{}: coupled input and forget gates are not supported",
+                node.name
+            ),
+            _ => {}
+        }
+    }
+
+    let is_present = |index: usize| {
+        node.inputs
+            .get(index)
+            .is_some_and(|input| !input.name.is_empty())
+    };
+    if is_present(4) {
+        panic!("{}: sequence lengths are not supported", node.name);
+    }
+    if is_present(7) {
+        panic!("{}: peephole weights are not supported", node.name);
+    }
+
+    // The input weights W are `[num_directions, num_gates * hidden_size, input_size]` and the
+    // hidden weights R are `[num_directions, num_gates * hidden_size, hidden_size]`
+    let shape = |index: usize| match &node.inputs[index].ty {
+        ArgType::Tensor(tensor) => tensor
+            .shape
+            .clone()
+            .expect("Recurrent: weight tensors must have a known shape"),
+        _ => panic!("Recurrent: weights must be tensors"),
+    };
+    let d_input = shape(1)[2];
+    let d_hidden = hidden_size.unwrap_or_else(|| shape(2)[2]);
+
+    let config = RecurrentConfig::new(d_input, d_hidden, is_present(3), direction, batch_first);
+    let activations = activations.unwrap_or_else(|| {
+        (0..config.num_directions())
+            .flat_map(|_| default_activations.iter().map(|name| name.to_string()))
+            .collect()
+    });
+
+    (config, activations)
+}
+
+/// Create a RecurrentConfig from the attributes of the LSTM node
+pub fn lstm_config(node: &Node) -> RecurrentConfig {
+    let (config, activations) = recurrent_config(node, &["Sigmoid", "Tanh", "Tanh"]);
+
+    if activations
+        .chunks(3)
+        .any(|activations| activations != ["Sigmoid", "Tanh", "Tanh"])
+    {
+        panic!("{}: only the default activations are supported", node.name);
+    }
+
+    config
+}
+
+/// Create a RecurrentConfig from the attributes of the GRU node, and return it with whether the
+/// reset gate is applied after the linear transformation of the hidden state (`linear_before_reset`)
+pub fn gru_config(node: &Node) -> (RecurrentConfig, bool) {
+    let (config, activations) = recurrent_config(node, &["Sigmoid", "Tanh"]);
+
+    if activations
+        .chunks(2)
+        .any(|activations| activations != ["Sigmoid", "Tanh"])
+    {
+        panic!("{}: only the default activations are supported", node.name);
+    }
+
+    let linear_before_reset = node
+        .attrs
+        .get("linear_before_reset")
+        .is_some_and(|value| value.clone().into_i64() != 0);
+
+    (config, linear_before_reset)
+}
+
+/// Create a RecurrentConfig and the activation function from the attributes of the RNN node
+pub fn rnn_config(node: &Node) -> (RecurrentConfig, RnnActivation) {
+    let (config, activations) = recurrent_config(node, &["Tanh"]);
+
+    let activation = |name: &str| match name {
+        "Tanh" => RnnActivation::Tanh,
+        "Relu" => RnnActivation::Relu,
+        "Sigmoid" => RnnActivation::Sigmoid,
+        name => panic!("{}: unsupported activation {name}", node.name),
+    };
+    let activation = activation(&activations[0]);
+    if activations.iter().any(|name| name != &activations[0]) {
+        panic!(
+            "{}: the directions must have the same activation",
+            node.name
+        );
+    }
+
+    (config, activation)
+}
+
+/// Read the values of a constant integer input, which must be known when the model is imported.
+fn constant_ints(node: &Node, index: usize) -> Vec<i64> {
+    match node.inputs[index].value.clone().map(Data::load) {
+        Some(Data::Int64s(values)) => values,
+        Some(Data::Int64(value)) => vec![value],
+        Some(Data::Int32s(values)) => values.into_iter().map(|value| value as i64).collect(),
+        Some(Data::Int32(value)) => vec![value as i64],
+        Some(data) => panic!(
+            "{}: input {index} must be integers, got {data:?}",
+            node.name
+        ),
+        None => panic!("{}: input {index} must be a constant", node.name),
+    }
+}
+
+/// Read the values of a constant numeric input, which must be known when the model is imported.
+fn constant_floats(node: &Node, index: usize) -> Vec<f64> {
+    match node.inputs[index].value.clone().map(Data::load) {
+        Some(Data::Float16s(values)) => values.into_iter().map(|value| value.to_f64()).collect(),
+        Some(Data::Float16(value)) => vec![value.to_f64()],
+        Some(Data::Float32s(values)) => values.into_iter().map(|value| value as f64).collect(),
+        Some(Data::Float32(value)) => vec![value as f64],
+        Some(Data::Float64s(values)) => values,
+        Some(Data::Float64(value)) => vec![value],
+        _ => constant_ints(node, index)
+            .into_iter()
+            .map(|value| value as f64)
+            .collect(),
+    }
+}
+
+/// Return true when the optional input at the given index is given.
+fn has_input(node: &Node, index: usize) -> bool {
+    node.inputs
+        .get(index)
+        .is_some_and(|input| !input.name.is_empty())
+}
+
+fn input_rank(node: &Node) -> usize {
+    match &node.inputs[0].ty {
+        ArgType::Tensor(tensor) => tensor.dim,
+        _ => panic!("{}: only tensor input is valid", node.name),
+    }
+}
+
+/// Normalize an axis counted from the end when it is negative.
+fn normalize_axis(axis: i64, rank: usize) -> usize {
+    match axis {
+        axis if axis < 0 => (axis + rank as i64) as usize,
+        axis => axis as usize,
+    }
+}
+
+/// Create a PadConfig from the attributes and the constant inputs of the node
+pub fn pad_config(node: &Node) -> PadConfig {
+    let rank = input_rank(node);
+    if rank < 2 {
+        panic!("{}: the input must have at least 2 dimensions", node.name);
+    }
+
+    let mut pads = Vec::new();
+    let mut value = 0.0;
+
+    for (key, value_attr) in node.attrs.iter() {
+        if key.as_str() == "mode" {
+            let mode = value_attr.clone().into_string();
+            if mode != "constant" {
+                panic!(
+                    "{}: only the constant mode is supported, got {mode}",
+                    node.name
+                );
+            }
+        }
+    }
+
+    if has_input(node, 1) {
+        pads = constant_ints(node, 1);
+    }
+    if has_input(node, 2) {
+        value = constant_floats(node, 2)[0];
+    }
+    let axes = match has_input(node, 3) {
+        true => constant_ints(node, 3)
+            .into_iter()
+            .map(|axis| normalize_axis(axis, rank))
+            .collect(),
+        false => (0..rank).collect::<Vec<_>>(),
+    };
+
+    // The pads are the beginning of each axis, followed by the end of each axis
+    let (mut left, mut right, mut top, mut bottom) = (0, 0, 0, 0);
+    for (i, axis) in axes.iter().enumerate() {
+        let (begin, end) = (pads[i], pads[i + axes.len()]);
+        if begin < 0 || end < 0 {
+            panic!("{}: negative pads are not supported", node.name);
+        }
+        let (begin, end) = (begin as usize, end as usize);
+
+        match rank - axis {
+            1 => (left, right) = (begin, end),
+            2 => (top, bottom) = (begin, end),
+            _ if begin == 0 && end == 0 => {}
+            _ => panic!("{}: only the last two dimensions can be padded", node.name),
+        }
+    }
+
+    PadConfig::new((left, right, top, bottom), value)
+}
+
+/// Create a SplitConfig from the attributes and the constant inputs of the node
+pub fn split_config(node: &Node) -> SplitConfig {
+    let rank = input_rank(node);
+    let mut axis = 0;
+    let mut split_sizes = None;
+
+    for (key, value) in node.attrs.iter() {
+        if key.as_str() == "axis" {
+            axis = value.clone().into_i64();
+        }
+    }
+
+    if has_input(node, 1) {
+        split_sizes = Some(constant_ints(node, 1));
+    }
+    let split_sizes =
+        split_sizes.map(|sizes| sizes.into_iter().map(|size| size as usize).collect());
+
+    SplitConfig::new(normalize_axis(axis, rank), split_sizes)
+}
+
+/// Create the repeats of each dimension from the constant input of the Tile node
+pub fn tile_config(node: &Node) -> Vec<usize> {
+    constant_ints(node, 1)
+        .into_iter()
+        .map(|times| times as usize)
+        .collect()
+}
+
+/// Create a TopKConfig from the attributes and the constant inputs of the node
+pub fn top_k_config(node: &Node) -> TopKConfig {
+    let rank = input_rank(node);
+    let mut axis = -1;
+    let mut k = None;
+    let mut largest = true;
+
+    for (key, value) in node.attrs.iter() {
+        match key.as_str() {
+            "axis" => axis = value.clone().into_i64(),
+            "largest" => largest = value.clone().into_i64() == 1,
+            _ => {}
+        }
+    }
+
+    if has_input(node, 1) {
+        k = Some(constant_ints(node, 1)[0]);
+    }
+    let k = k.unwrap_or_else(|| panic!("{}: k must be given", node.name));
+
+    TopKConfig::new(normalize_axis(axis, rank), k as usize, largest)
+}
+
+/// Create a TriluConfig from the attributes and the constant inputs of the node
+pub fn trilu_config(node: &Node) -> TriluConfig {
+    let mut upper = true;
+
+    for (key, value) in node.attrs.iter() {
+        if key.as_str() == "upper" {
+            upper = value.clone().into_i64() == 1;
+        }
+    }
+
+    let diagonal = match has_input(node, 1) {
+        true => constant_ints(node, 1)[0],
+        false => 0,
+    };
+
+    TriluConfig::new(upper, diagonal)
+}
+
+/// Create a OneHotConfig from the attributes and the constant inputs of the node
+pub fn one_hot_config(node: &Node) -> OneHotConfig {
+    let mut axis = -1;
+
+    for (key, value) in node.attrs.iter() {
+        if key.as_str() == "axis" {
+            axis = value.clone().into_i64();
+        }
+    }
+
+    let depth = constant_floats(node, 1)[0] as usize;
+    let values = constant_floats(node, 2);
+
+    // The axis is counted in the output, which has one more dimension than the indices
+    let axis = normalize_axis(axis, input_rank(node) + 1);
+
+    OneHotConfig::new(depth, (values[0], values[1]), axis)
+}
+
+/// Create a CumSumConfig from the attributes and the constant inputs of the node
+pub fn cumsum_config(node: &Node) -> CumSumConfig {
+    let mut exclusive = false;
+    let mut reverse = false;
+
+    for (key, value) in node.attrs.iter() {
+        match key.as_str() {
+            "exclusive" => exclusive = value.clone().into_i64() == 1,
+            "reverse" => reverse = value.clone().into_i64() == 1,
+            _ => {}
+        }
+    }
+
+    let axis = normalize_axis(constant_ints(node, 1)[0], input_rank(node));
+
+    CumSumConfig::new(axis, exclusive, reverse)
+}
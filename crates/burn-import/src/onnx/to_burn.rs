@@ -5,7 +5,7 @@ use std::{
 };
 
 use burn::{
-    nn::PReluConfig,
+    nn::{GroupNormConfig, PReluConfig},
     record::{FullPrecisionSettings, HalfPrecisionSettings, PrecisionSettings},
     tensor::{Element, TensorData},
 };
@@ -29,30 +29,47 @@ use crate::{
             conv3d::Conv3dNode,
             conv_transpose_2d::ConvTranspose2dNode,
             conv_transpose_3d::ConvTranspose3dNode,
+            cumsum::CumSumNode,
             dropout::DropoutNode,
-            expand::ExpandNode,
+            expand::{ExpandNode, ExpandShape},
             gather::GatherNode,
             gather_elements::GatherElementsNode,
             global_avg_pool::GlobalAvgPoolNode,
+            group_norm::GroupNormNode,
+            gru::GruNode,
+            if_node::IfNode,
+            instance_norm::InstanceNormNode,
             layer_norm::LayerNormNode,
             linear::LinearNode,
+            loop_node::LoopNode,
+            lstm::LstmNode,
             mask_where::WhereNode,
             matmul::MatmulNode,
             max_pool1d::MaxPool1dNode,
             max_pool2d::MaxPool2dNode,
+            nonzero::NonZeroNode,
+            one_hot::OneHotNode,
+            pad::PadNode,
             prelu::PReluNode,
             random_normal::RandomNormalNode,
             random_uniform::RandomUniformNode,
             range::RangeNode,
-            reshape::ReshapeNode,
+            reshape::{ReshapeNode, ReshapeShape},
             resize::{ResizeNode, ResizeOptions},
-            slice::SliceNode,
+            rnn::{GateWeights, RnnNode},
+            scan::ScanNode,
+            slice::{SliceBound, SliceNode},
+            split::SplitNode,
             squeeze::SqueezeNode,
+            subgraph::SubGraph,
             sum::SumNode,
+            tile::TileNode,
+            top_k::TopKNode,
+            trilu::TriluNode,
             unary::UnaryNode,
             unsqueeze::UnsqueezeNode,
         },
-        ScalarKind, ScalarType, ShapeType, TensorKind, TensorType, Type,
+        LazyTensorData, ScalarKind, ScalarType, ShapeType, TensorKind, TensorType, Type,
     },
     format_tokens,
     logger::init_log,
@@ -61,11 +78,14 @@ use crate::{
 use super::op_configuration::{
     argmax_config, avg_pool1d_config, avg_pool2d_config, batch_norm_config, clip_config,
     concat_config, conv1d_config, conv2d_config, conv3d_config, conv_transpose2d_config,
-    conv_transpose3d_config, dropout_config, expand_config, flatten_config, gather_config,
-    layer_norm_config, leaky_relu_config, linear_config, log_softmax_config, max_pool1d_config,
-    max_pool2d_config, reduce_max_config, reduce_mean_config, reduce_min_config,
-    reduce_prod_config, reduce_sum_config, reshape_config, resize_config, shape_config,
-    slice_config, softmax_config, squeeze_config, transpose_config, unsqueeze_config,
+    conv_transpose3d_config, cumsum_config, dropout_config, elu_config, expand_config,
+    flatten_config, gather_config, group_norm_config, gru_config, hard_sigmoid_config,
+    instance_norm_config, layer_norm_config, leaky_relu_config, linear_config, log_softmax_config,
+    lstm_config, max_pool1d_config, max_pool2d_config, one_hot_config, pad_config,
+    reduce_max_config, reduce_mean_config, reduce_min_config, reduce_prod_config,
+    reduce_sum_config, reshape_config, resize_config, rnn_config, scan_config, selu_config,
+    shape_config, slice_config, slice_runtime_config, softmax_config, split_config, squeeze_config,
+    tile_config, top_k_config, transpose_config, trilu_config, unsqueeze_config,
 };
 use onnx_ir::{
     convert_constant_value,
@@ -73,10 +93,11 @@ use onnx_ir::{
         ArgType, Argument as OnnxArgument, Data, ElementType, Node, NodeType, OnnxGraph,
         TensorType as OnnxTensorType,
     },
-    parse_onnx,
+    optimize, parse_onnx,
 };
 
 pub use crate::burn::graph::RecordType;
+pub use onnx_ir::Pass;
 
 /// Generate code and states from `.onnx` files and save them to the `out_dir`.
 #[derive(Debug, Default)]
@@ -88,6 +109,7 @@ pub struct ModelGen {
     half_precision: bool,
     record_type: RecordType,
     embed_states: bool,
+    passes: Vec<Pass>,
 }
 
 impl ModelGen {
@@ -158,11 +180,31 @@ impl ModelGen {
     ///
     /// * `embed_states` - If true, states are embedded in the generated code. Otherwise, states are
     /// saved as a separate file.
+    ///
+    /// # Notes
+    ///
+    /// Large models, such as the models storing their weights as ONNX external data, can't be
+    /// embedded and must have their states saved as a separate file.
     pub fn embed_states(&mut self, embed_states: bool) -> &mut Self {
         self.embed_states = embed_states;
         self
     }
 
+    /// Specify the optimization passes applied to the ONNX graph before the code generation.
+    ///
+    /// No pass is applied by default. The passes reduce the number of nodes to generate, e.g. by
+    /// folding the constants or the batch normalizations following convolutions, which also
+    /// allows importing the models computing shapes that are only known statically.
+    ///
+    /// # Arguments
+    ///
+    /// * `passes` - The passes to apply, e.g. [Pass::ALL]. They are always applied in the order
+    ///   of [Pass::ALL].
+    pub fn passes(&mut self, passes: &[Pass]) -> &mut Self {
+        self.passes = passes.to_vec();
+        self
+    }
+
     /// Run code generation.
     fn run(&self, is_build_script: bool) {
         log::info!("Starting to convert ONNX to Burn");
@@ -203,7 +245,9 @@ impl ModelGen {
         log::debug!("Development mode: {:?}", self.development);
         log::debug!("Output file: {:?}", out_file);
 
-        let graph = parse_onnx(input.as_ref());
+        let graph = parse_onnx(input.as_ref())
+            .unwrap_or_else(|err| panic!("Unable to convert {input:?}: {err}"));
+        let graph = optimize(graph, &self.passes);
         let graph = ParsedOnnxGraph(graph);
 
         if self.development {
@@ -247,8 +291,41 @@ impl ParsedOnnxGraph {
         let mut graph = BurnGraph::<PS>::default();
 
         let mut unsupported_ops = vec![];
+        Self::register_nodes(&mut graph, self.0.nodes, &mut unsupported_ops);
+
+        if !unsupported_ops.is_empty() {
+            panic!("Unsupported ops: {:?}", unsupported_ops);
+        }
+
+        // Get input and output names
+        let input_names = self
+            .0
+            .inputs
+            .iter()
+            .map(|input| input.name.clone())
+            .collect::<Vec<_>>();
+        let output_names = self
+            .0
+            .outputs
+            .iter()
+            .map(|output| output.name.clone())
+            .collect::<Vec<_>>();
+
+        // Register inputs and outputs with the graph
+        graph.register_input_output(input_names, output_names);
+
+        graph
+    }
+
+    /// Registers the nodes into the Burn graph, collecting the types of the unsupported nodes.
+    fn register_nodes<PS: PrecisionSettings + 'static>(
+        graph: &mut BurnGraph<PS>,
+        nodes: Vec<Node>,
+        unsupported_ops: &mut Vec<NodeType>,
+    ) {
+        for mut node in nodes {
+            Self::register_shape_operands(graph, &mut node);
 
-        for node in self.0.nodes {
             match node.node_type {
                 NodeType::Add => graph.register(Self::add_conversion(node)),
                 NodeType::ArgMax => graph.register(Self::argmax_conversion(node)),
@@ -334,32 +411,58 @@ impl ParsedOnnxGraph {
                 NodeType::ConstantOfShape => {
                     graph.register(Self::constant_of_shape_conversion(node))
                 }
+                NodeType::If => graph.register(Self::if_conversion::<PS>(node, unsupported_ops)),
+                NodeType::Loop => {
+                    graph.register(Self::loop_conversion::<PS>(node, unsupported_ops))
+                }
+                NodeType::Scan => {
+                    graph.register(Self::scan_conversion::<PS>(node, unsupported_ops))
+                }
+                NodeType::LSTM => graph.register(Self::lstm_conversion::<PS>(node)),
+                NodeType::GRU => graph.register(Self::gru_conversion::<PS>(node)),
+                NodeType::RNN => graph.register(Self::rnn_conversion::<PS>(node)),
+                NodeType::Pad => graph.register(Self::pad_conversion(node)),
+                NodeType::Split => graph.register(Self::split_conversion(node)),
+                NodeType::Tile => graph.register(Self::tile_conversion(node)),
+                NodeType::TopK => graph.register(Self::top_k_conversion(node)),
+                NodeType::Trilu => graph.register(Self::trilu_conversion(node)),
+                NodeType::OneHot => graph.register(Self::one_hot_conversion(node)),
+                NodeType::CumSum => graph.register(Self::cumsum_conversion(node)),
+                NodeType::NonZero => graph.register(Self::nonzero_conversion(node)),
+                NodeType::InstanceNormalization => {
+                    graph.register(Self::instance_norm_conversion::<PS>(node))
+                }
+                NodeType::GroupNormalization => {
+                    graph.register(Self::group_norm_conversion::<PS>(node))
+                }
+                NodeType::HardSigmoid => graph.register(Self::hard_sigmoid_conversion(node)),
+                NodeType::HardSwish => graph.register(Self::hard_swish_conversion(node)),
+                NodeType::Elu => graph.register(Self::elu_conversion(node)),
+                NodeType::Selu => graph.register(Self::selu_conversion(node)),
+                NodeType::Softplus => graph.register(Self::softplus_conversion(node)),
                 node_type => unsupported_ops.push(node_type),
             }
         }
+    }
 
-        if !unsupported_ops.is_empty() {
-            panic!("Unsupported ops: {:?}", unsupported_ops);
+    /// Registers the constant operands of a runtime shape computation, e.g. the indices of a
+    /// `Gather` or the values concatenated to a shape, as shape constants.
+    fn register_shape_operands<PS: PrecisionSettings + 'static>(
+        graph: &mut BurnGraph<PS>,
+        node: &mut Node,
+    ) {
+        for i in shape_operands(node) {
+            let input = &mut node.inputs[i];
+            let Some(Data::Int64s(values)) = input.value.take() else {
+                unreachable!("the shape operands are converted to int64 values")
+            };
+
+            graph.register(ConstantNode::new(
+                input.name.clone(),
+                ConstantValue::Shape(values),
+                Type::from(&*input),
+            ));
         }
-
-        // Get input and output names
-        let input_names = self
-            .0
-            .inputs
-            .iter()
-            .map(|input| input.name.clone())
-            .collect::<Vec<_>>();
-        let output_names = self
-            .0
-            .outputs
-            .iter()
-            .map(|output| output.name.clone())
-            .collect::<Vec<_>>();
-
-        // Register inputs and outputs with the graph
-        graph.register_input_output(input_names, output_names);
-
-        graph
     }
 
     fn constant_conversion<PS: PrecisionSettings>(node: Node) -> ConstantNode {
@@ -384,15 +487,17 @@ impl ParsedOnnxGraph {
                     let tensor_data = match tensor.elem_type {
                         // TODO Review how double precision should be supported
                         ElementType::Float32 | ElementType::Float64 => {
-                            serialize_data::<PS::FloatElem>(
+                            lazy_serialize_data::<PS::FloatElem>(
+                                attr.value.unwrap(),
+                                tensor.shape.unwrap(),
+                            )
+                        }
+                        ElementType::Int32 | ElementType::Int64 => {
+                            lazy_serialize_data::<PS::IntElem>(
                                 attr.value.unwrap(),
                                 tensor.shape.unwrap(),
                             )
                         }
-                        ElementType::Int32 | ElementType::Int64 => serialize_data::<PS::IntElem>(
-                            attr.value.unwrap(),
-                            tensor.shape.unwrap(),
-                        ),
                         // TODO support Bool tensor when it is supported by Burn
                         _ => panic!("Unsupported constant tensor type: {:?} ", tensor.elem_type),
                     };
@@ -485,7 +590,7 @@ impl ParsedOnnxGraph {
             .attrs
             .get("value")
             .and_then(|val| val.clone().into_tensor().data)
-            .map(|val_data| match val_data {
+            .map(|val_data| match val_data.load() {
                 // TODO: Handle Float16
                 Data::Float32s(vals) => ConstantValue::from_vec(vals),
                 Data::Float64s(vals) => ConstantValue::from_vec(vals),
@@ -600,9 +705,9 @@ impl ParsedOnnxGraph {
     }
 
     fn gather_conversion(node: Node) -> GatherNode {
-        let input = TensorType::from(node.inputs.first().unwrap());
-        let index = TensorType::from(node.inputs.get(1).unwrap());
-        let output = TensorType::from(node.outputs.first().unwrap());
+        let input = Type::from(node.inputs.first().unwrap());
+        let index = Type::from(node.inputs.get(1).unwrap());
+        let output = Type::from(node.outputs.first().unwrap());
         let dim = gather_config(&node);
 
         GatherNode::new(input, index, output, dim)
@@ -635,7 +740,10 @@ impl ParsedOnnxGraph {
     fn reshape_conversion(node: Node) -> ReshapeNode {
         let input = TensorType::from(node.inputs.first().unwrap());
         let output = TensorType::from(node.outputs.first().unwrap());
-        let shape = reshape_config(&node);
+        let shape = match node.inputs.get(1).map(Type::from) {
+            Some(Type::Shape(shape)) => ReshapeShape::Runtime(shape),
+            _ => ReshapeShape::Static(reshape_config(&node)),
+        };
 
         ReshapeNode::new(input, output, shape)
     }
@@ -734,7 +842,7 @@ impl ParsedOnnxGraph {
 
     fn unsqueeze_conversion(node: Node) -> UnsqueezeNode {
         let input = Type::from(node.inputs.first().unwrap());
-        let output = TensorType::from(node.outputs.first().unwrap());
+        let output = Type::from(node.outputs.first().unwrap());
         let dims = unsqueeze_config(&node);
 
         UnsqueezeNode::new(input, output, dims)
@@ -772,9 +880,9 @@ impl ParsedOnnxGraph {
     }
 
     fn slice_conversion(node: Node) -> SliceNode {
-        let input = TensorType::from(node.inputs.first().unwrap());
-        let output = TensorType::from(node.outputs.first().unwrap());
-        let ranges = slice_config(&node);
+        let input = Type::from(node.inputs.first().unwrap());
+        let output = Type::from(node.outputs.first().unwrap());
+        let ranges = slice_ranges(&node);
 
         SliceNode::new(input, output, ranges)
     }
@@ -832,9 +940,9 @@ impl ParsedOnnxGraph {
     }
 
     fn concat_conversion(node: Node) -> ConcatNode {
-        let inputs = node.inputs.iter().map(TensorType::from).collect();
+        let inputs = node.inputs.iter().map(Type::from).collect();
 
-        let output = TensorType::from(node.outputs.first().unwrap());
+        let output = Type::from(node.outputs.first().unwrap());
         let dim = concat_config(&node);
 
         ConcatNode::new(inputs, output, dim)
@@ -1053,7 +1161,10 @@ impl ParsedOnnxGraph {
     fn expand_conversion(node: Node) -> ExpandNode {
         let input = TensorType::from(node.inputs.first().unwrap());
         let output = TensorType::from(node.outputs.first().unwrap());
-        let shape = expand_config(&node);
+        let shape = match Type::from(&node.inputs[1]) {
+            Type::Shape(shape) => ExpandShape::Runtime(shape),
+            _ => ExpandShape::Static(expand_config(&node)),
+        };
 
         ExpandNode::new(input, output, shape)
     }
@@ -1130,16 +1241,426 @@ impl ParsedOnnxGraph {
 
         SqueezeNode::new(input, output, axes)
     }
+
+    fn if_conversion<PS: PrecisionSettings + 'static>(
+        node: Node,
+        unsupported_ops: &mut Vec<NodeType>,
+    ) -> IfNode<PS> {
+        let condition = Type::from(node.inputs.first().unwrap());
+        // The variables of the enclosing graph used by the branches follow the ONNX inputs
+        let captured = node.inputs[1..].iter().map(Type::from).collect();
+        let outputs = node.outputs.iter().map(Type::from).collect();
+
+        let then_branch = node.attrs.get("then_branch").unwrap().clone().into_graph();
+        let else_branch = node.attrs.get("else_branch").unwrap().clone().into_graph();
+
+        IfNode::new(
+            condition,
+            Self::sub_graph_conversion(then_branch, unsupported_ops),
+            Self::sub_graph_conversion(else_branch, unsupported_ops),
+            captured,
+            outputs,
+        )
+    }
+
+    fn loop_conversion<PS: PrecisionSettings + 'static>(
+        node: Node,
+        unsupported_ops: &mut Vec<NodeType>,
+    ) -> LoopNode<PS> {
+        let body = node.attrs.get("body").unwrap().clone().into_graph();
+        let num_carried = body.inputs.len() - 2;
+
+        // The trip count and the condition are optional inputs, named "" when missing
+        let optional = |arg: &OnnxArgument| match arg.name.is_empty() {
+            true => None,
+            false => Some(Type::from(arg)),
+        };
+        let max_trip_count = optional(&node.inputs[0]);
+        let condition = optional(&node.inputs[1]);
+        let initial_values = node.inputs[2..2 + num_carried]
+            .iter()
+            .map(Type::from)
+            .collect();
+        let captured = node.inputs[2 + num_carried..]
+            .iter()
+            .map(Type::from)
+            .collect();
+        let outputs = node.outputs.iter().map(Type::from).collect();
+
+        LoopNode::new(
+            max_trip_count,
+            condition,
+            initial_values,
+            Self::sub_graph_conversion(body, unsupported_ops),
+            captured,
+            outputs,
+        )
+    }
+
+    fn scan_conversion<PS: PrecisionSettings + 'static>(
+        node: Node,
+        unsupported_ops: &mut Vec<NodeType>,
+    ) -> ScanNode<PS> {
+        let config = scan_config(&node);
+        let body = node.attrs.get("body").unwrap().clone().into_graph();
+        let num_scan_inputs = config.input_axes.len();
+        let num_states = body.inputs.len() - num_scan_inputs;
+
+        let initial_states = node.inputs[..num_states].iter().map(Type::from).collect();
+        let scan_inputs = node.inputs[num_states..num_states + num_scan_inputs]
+            .iter()
+            .map(TensorType::from)
+            .collect();
+        let captured = node.inputs[num_states + num_scan_inputs..]
+            .iter()
+            .map(Type::from)
+            .collect();
+        let outputs = node.outputs.iter().map(Type::from).collect();
+
+        ScanNode::new(
+            initial_states,
+            scan_inputs,
+            Self::sub_graph_conversion(body, unsupported_ops),
+            captured,
+            outputs,
+            config,
+        )
+    }
+
+    fn lstm_conversion<PS: PrecisionSettings>(node: Node) -> LstmNode {
+        let name = &node.name;
+        let input = TensorType::from(node.inputs.first().unwrap());
+        let config = lstm_config(&node);
+        let gates = gate_weights::<PS>(&node, 4);
+
+        LstmNode::new(
+            name,
+            input,
+            optional_tensor(&node.inputs, 5),
+            optional_tensor(&node.inputs, 6),
+            optional_tensor(&node.outputs, 0),
+            optional_tensor(&node.outputs, 1),
+            optional_tensor(&node.outputs, 2),
+            gates,
+            config,
+        )
+    }
+
+    fn gru_conversion<PS: PrecisionSettings>(node: Node) -> GruNode {
+        let name = &node.name;
+        let input = TensorType::from(node.inputs.first().unwrap());
+        let (config, linear_before_reset) = gru_config(&node);
+        let gates = gate_weights::<PS>(&node, 3);
+
+        GruNode::new(
+            name,
+            input,
+            optional_tensor(&node.inputs, 5),
+            optional_tensor(&node.outputs, 0),
+            optional_tensor(&node.outputs, 1),
+            gates,
+            config,
+            linear_before_reset,
+        )
+    }
+
+    fn rnn_conversion<PS: PrecisionSettings>(node: Node) -> RnnNode {
+        let name = &node.name;
+        let input = TensorType::from(node.inputs.first().unwrap());
+        let (config, activation) = rnn_config(&node);
+        let gates = gate_weights::<PS>(&node, 1)
+            .into_iter()
+            .map(|mut gates| gates.remove(0))
+            .collect();
+
+        RnnNode::new(
+            name,
+            input,
+            optional_tensor(&node.inputs, 5),
+            optional_tensor(&node.outputs, 0),
+            optional_tensor(&node.outputs, 1),
+            gates,
+            config,
+            activation,
+        )
+    }
+
+    fn pad_conversion(node: Node) -> PadNode {
+        let input = TensorType::from(node.inputs.first().unwrap());
+        let output = TensorType::from(node.outputs.first().unwrap());
+        let config = pad_config(&node);
+
+        PadNode::new(input, output, config)
+    }
+
+    fn split_conversion(node: Node) -> SplitNode {
+        let input = TensorType::from(node.inputs.first().unwrap());
+        let outputs = node.outputs.iter().map(TensorType::from).collect();
+        let config = split_config(&node);
+
+        SplitNode::new(input, outputs, config)
+    }
+
+    fn tile_conversion(node: Node) -> TileNode {
+        let input = TensorType::from(node.inputs.first().unwrap());
+        let output = TensorType::from(node.outputs.first().unwrap());
+        let repeats = tile_config(&node);
+
+        TileNode::new(input, output, repeats)
+    }
+
+    fn top_k_conversion(node: Node) -> TopKNode {
+        let input = TensorType::from(node.inputs.first().unwrap());
+        let values = TensorType::from(&node.outputs[0]);
+        let indices = TensorType::from(&node.outputs[1]);
+        let config = top_k_config(&node);
+
+        TopKNode::new(input, values, indices, config)
+    }
+
+    fn trilu_conversion(node: Node) -> TriluNode {
+        let input = TensorType::from(node.inputs.first().unwrap());
+        let output = TensorType::from(node.outputs.first().unwrap());
+        let config = trilu_config(&node);
+
+        TriluNode::new(input, output, config)
+    }
+
+    fn one_hot_conversion(node: Node) -> OneHotNode {
+        let indices = TensorType::from(node.inputs.first().unwrap());
+        let output = TensorType::from(node.outputs.first().unwrap());
+        let config = one_hot_config(&node);
+
+        OneHotNode::new(indices, output, config)
+    }
+
+    fn cumsum_conversion(node: Node) -> CumSumNode {
+        let input = TensorType::from(node.inputs.first().unwrap());
+        let output = TensorType::from(node.outputs.first().unwrap());
+        let config = cumsum_config(&node);
+
+        CumSumNode::new(input, output, config)
+    }
+
+    fn nonzero_conversion(node: Node) -> NonZeroNode {
+        let input = TensorType::from(node.inputs.first().unwrap());
+        let output = TensorType::from(node.outputs.first().unwrap());
+
+        NonZeroNode::new(input, output)
+    }
+
+    fn instance_norm_conversion<PS: PrecisionSettings>(node: Node) -> InstanceNormNode {
+        let config = instance_norm_config(&node);
+        let input = TensorType::from(node.inputs.first().unwrap());
+        let output = TensorType::from(node.outputs.first().unwrap());
+
+        let gamma = extract_data_serialize::<PS::FloatElem>(1, &node).expect("Scale is required");
+        let beta = extract_data_serialize::<PS::FloatElem>(2, &node).expect("Bias is required");
+
+        let name = &node.name;
+
+        InstanceNormNode::new(name, input, output, gamma, beta, config)
+    }
+
+    fn group_norm_conversion<PS: PrecisionSettings>(node: Node) -> GroupNormNode {
+        let config = group_norm_config(&node);
+        let input = TensorType::from(node.inputs.first().unwrap());
+        let output = TensorType::from(node.outputs.first().unwrap());
+
+        let (gamma, beta) = group_norm_params::<PS>(&node, &config);
+
+        let name = &node.name;
+
+        GroupNormNode::new(name, input, output, gamma, beta, config)
+    }
+
+    fn hard_sigmoid_conversion(node: Node) -> UnaryNode {
+        let input = Type::from(node.inputs.first().unwrap());
+        let output = Type::from(node.outputs.first().unwrap());
+        let (alpha, beta) = hard_sigmoid_config(&node);
+
+        UnaryNode::hard_sigmoid(input, output, alpha, beta)
+    }
+
+    fn hard_swish_conversion(node: Node) -> UnaryNode {
+        let input = Type::from(node.inputs.first().unwrap());
+        let output = Type::from(node.outputs.first().unwrap());
+
+        UnaryNode::hard_swish(input, output)
+    }
+
+    fn elu_conversion(node: Node) -> UnaryNode {
+        let input = Type::from(node.inputs.first().unwrap());
+        let output = Type::from(node.outputs.first().unwrap());
+        let alpha = elu_config(&node);
+
+        UnaryNode::elu(input, output, alpha)
+    }
+
+    fn selu_conversion(node: Node) -> UnaryNode {
+        let input = Type::from(node.inputs.first().unwrap());
+        let output = Type::from(node.outputs.first().unwrap());
+        let (alpha, gamma) = selu_config(&node);
+
+        UnaryNode::selu(input, output, alpha, gamma)
+    }
+
+    fn softplus_conversion(node: Node) -> UnaryNode {
+        let input = Type::from(node.inputs.first().unwrap());
+        let output = Type::from(node.outputs.first().unwrap());
+
+        UnaryNode::softplus(input, output)
+    }
+
+    fn sub_graph_conversion<PS: PrecisionSettings + 'static>(
+        graph: OnnxGraph,
+        unsupported_ops: &mut Vec<NodeType>,
+    ) -> SubGraph<PS> {
+        let inputs = graph.inputs.iter().map(Type::from).collect();
+        let outputs = graph.outputs.iter().map(Type::from).collect();
+
+        let mut sub_graph = BurnGraph::<PS>::default();
+        Self::register_nodes(&mut sub_graph, graph.nodes, unsupported_ops);
+
+        SubGraph::new(sub_graph.into_nodes(), inputs, outputs)
+    }
+}
+
+/// Extract the packed gate weights of a recurrent node, for each direction.
+pub(crate) fn gate_weights<PS: PrecisionSettings>(
+    node: &Node,
+    num_gates: usize,
+) -> Vec<Vec<GateWeights>> {
+    let input = extract_data_serialize::<PS::FloatElem>(1, node).expect("W is required");
+    let hidden = extract_data_serialize::<PS::FloatElem>(2, node).expect("R is required");
+    let bias = extract_data_serialize::<PS::FloatElem>(3, node);
+
+    GateWeights::from_onnx(input, hidden, bias, num_gates)
 }
 
-/// Extract data from node states and convert it to `TensorData`.
+/// Extract the scale and bias of a group norm node, with one value per channel.
+pub(crate) fn group_norm_params<PS: PrecisionSettings>(
+    node: &Node,
+    config: &GroupNormConfig,
+) -> (LazyTensorData, LazyTensorData) {
+    // Since opset 21, the scale and bias hold one value per channel instead of per group
+    let per_channel = |data: LazyTensorData| {
+        let num_values = data.shape().iter().product::<usize>();
+        let num_channels = config.num_channels;
+        if num_values == num_channels {
+            return data;
+        }
+        if num_channels % num_values != 0 {
+            panic!(
+                "{}: {} values cannot be spread over {} channels",
+                node.name, num_values, num_channels
+            );
+        }
+
+        let repeats = num_channels / num_values;
+        data.map(vec![num_channels], move |data| {
+            let values = data
+                .to_vec::<PS::FloatElem>()
+                .unwrap()
+                .into_iter()
+                .flat_map(|value| core::iter::repeat(value).take(repeats))
+                .collect::<Vec<_>>();
+            TensorData::new(values, [num_channels])
+        })
+    };
+
+    let gamma = extract_data_serialize::<PS::FloatElem>(1, node).expect("Scale is required");
+    let beta = extract_data_serialize::<PS::FloatElem>(2, node).expect("Bias is required");
+
+    (per_channel(gamma), per_channel(beta))
+}
+
+/// Converts the constant operands of a node computing a runtime shape to shapes, with int64
+/// values, and returns their indices.
+///
+/// Each converted constant is renamed, since it is only used by this node.
+pub(crate) fn shape_operands(node: &mut Node) -> Vec<usize> {
+    if !matches!(node.outputs[0].ty, ArgType::Shape(_)) {
+        return vec![];
+    }
+    let operands = match node.node_type {
+        NodeType::Add | NodeType::Concat | NodeType::Div | NodeType::Mul | NodeType::Sub => {
+            0..node.inputs.len()
+        }
+        NodeType::Gather => 1..2,
+        _ => return vec![],
+    };
+    let len = match node.inputs[0].ty {
+        ArgType::Shape(dim) => dim as i64,
+        _ => 0,
+    };
+
+    let mut converted = vec![];
+    for i in operands {
+        let input = &mut node.inputs[i];
+        let mut values = match input.value.take().map(Data::load) {
+            Some(Data::Int64s(values)) => values,
+            Some(Data::Int32s(values)) => values.into_iter().map(|x| x as i64).collect(),
+            Some(Data::Int64(value)) => vec![value],
+            Some(Data::Int32(value)) => vec![value as i64],
+            Some(data) => panic!("{}: unsupported shape operand {:?}", node.name, data),
+            None => continue,
+        };
+        // Negative indices are counted from the end of the shape
+        if node.node_type == NodeType::Gather {
+            values
+                .iter_mut()
+                .filter(|index| **index < 0)
+                .for_each(|index| *index += len);
+        }
+
+        input.name = format!("{}_in{}", node.name, i + 1);
+        input.ty = ArgType::Shape(values.len());
+        input.value = Some(Data::Int64s(values));
+        converted.push(i);
+    }
+
+    converted
+}
+
+/// The ranges of a slice node, whose bounds are read from runtime shapes when some of them are
+/// not constant.
+pub(crate) fn slice_ranges(node: &Node) -> Vec<Option<(SliceBound, SliceBound)>> {
+    match node
+        .inputs
+        .iter()
+        .skip(1)
+        .any(|input| input.value.is_none())
+    {
+        true => slice_runtime_config(node),
+        false => slice_config(node)
+            .into_iter()
+            .map(|range| {
+                range.map(|(start, end)| (SliceBound::Static(start), SliceBound::Static(end)))
+            })
+            .collect(),
+    }
+}
+
+/// The tensor of an optional input or output, if present.
+fn optional_tensor(arguments: &[OnnxArgument], index: usize) -> Option<TensorType> {
+    arguments
+        .get(index)
+        .filter(|argument| !argument.name.is_empty())
+        .map(TensorType::from)
+}
+
+/// Extract data from node states and convert it to `TensorData` when it's read.
 ///
 /// # Arguments
 ///
 /// * `input_index` - The index of the input originally from input.
 /// * `node` - The node where value are stored.
 #[track_caller]
-fn extract_data_serialize<E: Element>(input_index: usize, node: &Node) -> Option<TensorData> {
+pub(crate) fn extract_data_serialize<E: Element>(
+    input_index: usize,
+    node: &Node,
+) -> Option<LazyTensorData> {
     if node.inputs.is_empty() {
         return None;
     }
@@ -1154,8 +1675,8 @@ fn extract_data_serialize<E: Element>(input_index: usize, node: &Node) -> Option
         ArgType::Tensor(tensor_type) => {
             let value = input.value.as_ref().expect("Value to be provided.").clone();
 
-            Some(serialize_data::<E>(
-                value.clone(),
+            Some(lazy_serialize_data::<E>(
+                value,
                 tensor_type.shape.unwrap().clone(),
             ))
         }
@@ -1163,9 +1684,9 @@ fn extract_data_serialize<E: Element>(input_index: usize, node: &Node) -> Option
     }
 }
 
-/// Convert data to `TensorData`.
-fn serialize_data<E: Element>(data: Data, shape: Vec<usize>) -> TensorData {
-    match data {
+/// Convert data to `TensorData`, reading it if it's stored in an external file.
+pub(crate) fn serialize_data<E: Element>(data: Data, shape: Vec<usize>) -> TensorData {
+    match data.load() {
         Data::Float16s(val) => TensorData::new(val, shape).convert::<E>(),
         Data::Float32s(val) => TensorData::new(val, shape).convert::<E>(),
         Data::Float64s(val) => TensorData::new(val, shape).convert::<E>(),
@@ -1176,6 +1697,17 @@ fn serialize_data<E: Element>(data: Data, shape: Vec<usize>) -> TensorData {
     }
 }
 
+/// Convert data to `TensorData` when it's read, so the data stored in an external file is only
+/// read when the record is generated.
+pub(crate) fn lazy_serialize_data<E: Element>(data: Data, shape: Vec<usize>) -> LazyTensorData {
+    match data {
+        Data::External(_) => LazyTensorData::new(shape.clone(), move || {
+            serialize_data::<E>(data.clone(), shape.clone())
+        }),
+        data => serialize_data::<E>(data, shape).into(),
+    }
+}
+
 impl From<&OnnxArgument> for TensorType {
     fn from(arg: &OnnxArgument) -> Self {
         match &arg.ty {
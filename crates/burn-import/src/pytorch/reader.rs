@@ -2,22 +2,22 @@ use core::ops::Deref;
 use std::collections::HashMap;
 use std::path::Path;
 
-use super::{adapter::PyTorchAdapter, error::Error};
+use super::error::Error;
+use crate::common::{adapter::PyTorchAdapter, param::serialize_param, remap::remap_keys};
 
-use burn::{
-    module::ParamId,
-    record::PrecisionSettings,
-    tensor::{Element, ElementConversion, TensorData},
-};
 use burn::{
     record::serde::{
-        data::{remap, unflatten, NestedValue, Serializable},
+        data::{unflatten, NestedValue, Serializable},
         de::Deserializer,
         error,
         ser::Serializer,
     },
     tensor::backend::Backend,
 };
+use burn::{
+    record::PrecisionSettings,
+    tensor::{Element, ElementConversion, TensorData},
+};
 
 use candle_core::{pickle, WithDType};
 use half::{bf16, f16};
@@ -48,29 +48,10 @@ where
         .map(|(key, tensor)| (key, CandleTensor(tensor)))
         .collect();
 
-    // Remap the keys (replace the keys in the map with the new keys)
-    let (tensors, remapped_keys) = remap(tensors, key_remap);
-
-    // Print the remapped keys if debug is enabled
-    if debug {
-        let mut remapped_keys = remapped_keys;
-        remapped_keys.sort();
-        println!("Debug information of keys and tensor shapes:\n---");
-        for (new_key, old_key) in remapped_keys {
-            if old_key != new_key {
-                println!("Original Key: {old_key}");
-                println!("Remapped Key: {new_key}");
-            } else {
-                println!("Key: {}", new_key);
-            }
-
-            let shape = tensors[&new_key].shape();
-            let dtype = tensors[&new_key].dtype();
-            println!("Shape: {shape:?}");
-            println!("Dtype: {dtype:?}");
-            println!("---");
-        }
-    }
+    // Remap the keys and print them if debug is enabled
+    let tensors = remap_keys(tensors, key_remap, debug, |tensor| {
+        (tensor.shape().clone(), tensor.dtype())
+    });
 
     // Convert the vector of Candle tensors to a nested value data structure
     let nested_value = unflatten::<PS, _>(tensors)?;
@@ -95,29 +76,26 @@ impl Serializable for CandleTensor {
     {
         let shape = self.shape().clone().into_dims();
         let flatten = CandleTensor(self.flatten_all().expect("Failed to flatten the tensor"));
-        let param_id = ParamId::new().into_string();
 
         match self.dtype() {
-            candle_core::DType::U8 => {
-                serialize_data::<u8, PS::IntElem>(flatten, shape, param_id, serializer)
-            }
+            candle_core::DType::U8 => serialize_data::<u8, PS::IntElem>(flatten, shape, serializer),
             candle_core::DType::U32 => {
-                serialize_data::<u32, PS::IntElem>(flatten, shape, param_id, serializer)
+                serialize_data::<u32, PS::IntElem>(flatten, shape, serializer)
             }
             candle_core::DType::I64 => {
-                serialize_data::<i64, PS::IntElem>(flatten, shape, param_id, serializer)
+                serialize_data::<i64, PS::IntElem>(flatten, shape, serializer)
             }
             candle_core::DType::BF16 => {
-                serialize_data::<bf16, PS::FloatElem>(flatten, shape, param_id, serializer)
+                serialize_data::<bf16, PS::FloatElem>(flatten, shape, serializer)
             }
             candle_core::DType::F16 => {
-                serialize_data::<f16, PS::FloatElem>(flatten, shape, param_id, serializer)
+                serialize_data::<f16, PS::FloatElem>(flatten, shape, serializer)
             }
             candle_core::DType::F32 => {
-                serialize_data::<f32, PS::FloatElem>(flatten, shape, param_id, serializer)
+                serialize_data::<f32, PS::FloatElem>(flatten, shape, serializer)
             }
             candle_core::DType::F64 => {
-                serialize_data::<f64, PS::FloatElem>(flatten, shape, param_id, serializer)
+                serialize_data::<f64, PS::FloatElem>(flatten, shape, serializer)
             }
         }
     }
@@ -127,7 +105,6 @@ impl Serializable for CandleTensor {
 fn serialize_data<T, E>(
     tensor: CandleTensor,
     shape: Vec<usize>,
-    param_id: String,
     serializer: Serializer,
 ) -> Result<NestedValue, error::Error>
 where
@@ -141,26 +118,7 @@ where
         .map(ElementConversion::elem)
         .collect();
 
-    let TensorData {
-        bytes,
-        shape,
-        dtype,
-    } = TensorData::new(data, shape);
-
-    // Manually serialize the tensor instead of using the `ParamSerde` struct, such as:
-    // ParamSerde::new(param_id, TensorData::new(data, shape)).serialize(serializer)
-    // Because serializer copies individual elements of TensorData `value` into a new Vec<u8>,
-    // which is not necessary and inefficient.
-    let mut tensor_data: HashMap<String, NestedValue> = HashMap::new();
-    tensor_data.insert("bytes".into(), NestedValue::U8s(bytes));
-    tensor_data.insert("shape".into(), shape.serialize(serializer.clone())?);
-    tensor_data.insert("dtype".into(), dtype.serialize(serializer)?);
-
-    let mut param: HashMap<String, NestedValue> = HashMap::new();
-    param.insert("id".into(), NestedValue::String(param_id));
-    param.insert("param".into(), NestedValue::Map(tensor_data));
-
-    Ok(NestedValue::Map(param))
+    serialize_param(TensorData::new(data, shape), serializer)
 }
 
 /// New type struct for Candle tensors because we need to implement the `Serializable` trait for it.
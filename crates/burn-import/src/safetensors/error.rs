@@ -0,0 +1,30 @@
+use burn::record::{serde::error, RecorderError};
+use safetensors::SafeTensorError;
+
+#[derive(thiserror::Error, Debug)]
+pub enum Error {
+    #[error("Serde error: {0}")]
+    Serde(#[from] error::Error),
+
+    #[error("Safetensors error: {0}")]
+    Safetensors(#[from] SafeTensorError),
+
+    #[error("IO error: {0}")]
+    Io(#[from] std::io::Error),
+
+    // Add other kinds of errors as needed
+    #[error("other error: {0}")]
+    Other(String),
+}
+
+// Implement From trait for Error to RecorderError
+impl From<Error> for RecorderError {
+    fn from(error: Error) -> Self {
+        match error {
+            Error::Io(err) if err.kind() == std::io::ErrorKind::NotFound => {
+                RecorderError::FileNotFound(err.to_string())
+            }
+            error => RecorderError::DeserializeError(error.to_string()),
+        }
+    }
+}
@@ -0,0 +1,154 @@
+use std::collections::HashMap;
+use std::fs::File;
+use std::path::Path;
+use std::sync::Arc;
+
+use super::error::Error;
+use crate::common::{
+    adapter::{AdapterType, PyTorchAdapter},
+    param::{serialize_mapped_param, serialize_param},
+    remap::remap_keys,
+};
+
+use burn::{
+    record::{
+        serde::{
+            adapter::DefaultAdapter,
+            data::{unflatten, NestedValue, Serializable},
+            de::Deserializer,
+            error,
+            ser::Serializer,
+        },
+        with_mapped_source, MappedTensor, PrecisionSettings,
+    },
+    tensor::{backend::Backend, DType, TensorData},
+};
+
+use memmap2::Mmap;
+use regex::Regex;
+use safetensors::{tensor::TensorView, Dtype, SafeTensors};
+use serde::de::DeserializeOwned;
+
+/// Deserializes a Safetensors file.
+///
+/// The file is memory mapped instead of being read into a buffer, and the tensors of the record
+/// reference their values in the mapped file. The values are only read, and converted to the
+/// element type of the precision settings, when the tensors are used.
+///
+/// # Arguments
+///
+/// * `path` - A string slice that holds the path of the file to read.
+/// * `key_remap` - A vector of tuples containing a regular expression and a replacement string.
+/// * `top_level_key` - An optional key prefix of the tensors to load, which is removed from the keys.
+/// * `adapter_type` - The adapter used to convert the tensors to the Burn modules.
+pub fn from_file<PS, D, B>(
+    path: &Path,
+    key_remap: Vec<(Regex, String)>,
+    top_level_key: Option<&str>,
+    adapter_type: AdapterType,
+    debug: bool,
+) -> Result<D, Error>
+where
+    D: DeserializeOwned,
+    PS: PrecisionSettings,
+    B: Backend,
+{
+    let file = File::open(path)?;
+    // SAFETY: the file should not be modified while it is mapped, which is the case of any
+    // memory mapped file loader.
+    let mmap = Arc::new(unsafe { Mmap::map(&file)? });
+    let safetensors = SafeTensors::deserialize(&mmap)?;
+
+    // Only keep the tensors nested under the top-level key, without the prefix
+    let tensors: HashMap<String, SafetensorsTensor> = safetensors
+        .tensors()
+        .into_iter()
+        .filter_map(|(key, tensor)| {
+            let key = match top_level_key {
+                Some(prefix) => key.strip_prefix(prefix)?.strip_prefix('.')?.to_string(),
+                None => key,
+            };
+            Some((
+                key,
+                SafetensorsTensor {
+                    tensor,
+                    file: &mmap,
+                },
+            ))
+        })
+        .collect();
+
+    // Remap the keys and print them if debug is enabled
+    let tensors = remap_keys(tensors, key_remap, debug, |tensor| {
+        (tensor.tensor.shape().to_vec(), tensor.tensor.dtype())
+    });
+
+    // Convert the tensors to a nested value data structure
+    let nested_value = unflatten::<PS, _>(tensors)?;
+
+    // Deserialize the nested value into a record type with the selected adapter, the tensors
+    // reading their values from the mapped file
+    let value = with_mapped_source(mmap.clone(), || match adapter_type {
+        AdapterType::PyTorch => D::deserialize(Deserializer::<PyTorchAdapter<PS, B>>::new(
+            nested_value,
+            true,
+        )),
+        AdapterType::NoAdapter => {
+            D::deserialize(Deserializer::<DefaultAdapter>::new(nested_value, true))
+        }
+    })?;
+    Ok(value)
+}
+
+/// A Safetensors tensor view with the memory mapped file it borrows its values from, because we
+/// need to implement the `Serializable` trait for it.
+struct SafetensorsTensor<'data> {
+    tensor: TensorView<'data>,
+    file: &'data [u8],
+}
+
+/// Serializes a Safetensors tensor view.
+///
+/// Tensors are wrapped in a `Param` struct (learnable parameters) and serialized as the location
+/// of their values in the mapped file, which are converted to `FloatElem` or `IntElem` depending
+/// on the precision settings when the tensors are used.
+impl Serializable for SafetensorsTensor<'_> {
+    fn serialize<PS>(&self, serializer: Serializer) -> Result<NestedValue, error::Error>
+    where
+        PS: PrecisionSettings,
+    {
+        let tensor = &self.tensor;
+        let shape = tensor.shape().to_vec();
+        let bytes = tensor.data();
+
+        let dtype = match tensor.dtype() {
+            // Booleans are not necessarily stored as 0 or 1, so they are read right away
+            Dtype::BOOL => {
+                let data = TensorData::new(bytes.iter().map(|b| *b != 0).collect(), shape);
+                return serialize_param(data, serializer);
+            }
+            Dtype::U8 => DType::U8,
+            Dtype::I8 => DType::I8,
+            Dtype::I16 => DType::I16,
+            Dtype::I32 => DType::I32,
+            Dtype::U32 => DType::U32,
+            Dtype::I64 => DType::I64,
+            Dtype::U64 => DType::U64,
+            Dtype::F16 => DType::F16,
+            Dtype::BF16 => DType::BF16,
+            Dtype::F32 => DType::F32,
+            Dtype::F64 => DType::F64,
+            dtype => {
+                return Err(error::Error::Other(format!(
+                    "Unsupported Safetensors dtype: {dtype:?}"
+                )))
+            }
+        };
+
+        // The tensor view borrows its values from the mapped file
+        let offset = bytes.as_ptr() as usize - self.file.as_ptr() as usize;
+        let tensor = MappedTensor::new(offset, bytes.len(), shape, dtype);
+
+        serialize_mapped_param(tensor, serializer)
+    }
+}
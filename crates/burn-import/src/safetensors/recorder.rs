@@ -0,0 +1,347 @@
+use core::marker::PhantomData;
+use std::path::PathBuf;
+
+use burn::{
+    record::{FileRecorder, PrecisionSettings, Record, Recorder, RecorderError},
+    tensor::backend::Backend,
+};
+
+use regex::Regex;
+use serde::{de::DeserializeOwned, Serialize};
+
+use super::{reader::from_file, writer::to_file, AdapterType};
+
+/// A recorder that loads and saves Safetensors files (`.safetensors`) for Burn modules.
+///
+/// The files are memory mapped when loading instead of being read into a buffer, and the values of
+/// each tensor are only read from the mapped file when the tensor is used. The keys can be remapped
+/// to match the Burn module structure, see [with_key_remap](SafetensorsFileRecorder::with_key_remap).
+///
+/// Saved files contain the tensors of the record, keyed by their path in the module (e.g.
+/// `layers.0.weight`), in the layout of the Burn modules. They should be loaded back with
+/// [AdapterType::NoAdapter].
+///
+/// # Examples
+///
+/// ```text
+/// use burn_import::safetensors::SafetensorsFileRecorder;
+/// use burn::record::{FullPrecisionSettings, Recorder};
+///
+/// let record = SafetensorsFileRecorder::<FullPrecisionSettings>::default()
+///     .with_key_remap("conv\\.(.*)", "$1") // Remove "conv" prefix, e.g. "conv.conv1" -> "conv1"
+///     .load("tests/key_remap/key_remap.safetensors".into(), &device)
+///     .expect("Should decode state successfully");
+/// ```
+#[derive(Debug, Default, Clone)]
+pub struct SafetensorsFileRecorder<PS: PrecisionSettings> {
+    /// A list of key remappings.
+    key_remap: Vec<(Regex, String)>,
+
+    /// Key prefix of the tensors to load, removed from their keys.
+    top_level_key: Option<String>,
+
+    /// The adapter used to convert the tensors to the Burn modules.
+    adapter_type: AdapterType,
+
+    /// Whether to print debug information.
+    debug: bool,
+
+    _settings: PhantomData<PS>,
+}
+
+impl<PS: PrecisionSettings> SafetensorsFileRecorder<PS> {
+    /// Sets key remapping.
+    ///
+    /// # Arguments
+    ///
+    /// * `pattern` - The Regex pattern to be replaced.
+    /// * `replacement` - The pattern to replace with.
+    ///
+    /// See [Regex](https://docs.rs/regex/1.5.4/regex/#syntax) for the pattern syntax and
+    /// [Replacement](https://docs.rs/regex/latest/regex/struct.Regex.html#method.replace) for the
+    /// replacement syntax.
+    pub fn with_key_remap(mut self, pattern: &str, replacement: &str) -> Self {
+        let regex = Regex::new(pattern).expect("Valid regex");
+
+        self.key_remap.push((regex, replacement.into()));
+        self
+    }
+
+    /// Sets the top-level key of the tensors to load.
+    ///
+    /// Safetensors files store a flat list of tensors, so only the tensors with a key starting
+    /// with `{key}.` are loaded, with the prefix removed from their key.
+    ///
+    /// # Arguments
+    ///
+    /// * `key` - The top-level key of the tensors to load.
+    pub fn with_top_level_key(mut self, key: &str) -> Self {
+        self.top_level_key = Some(key.into());
+        self
+    }
+
+    /// Sets the adapter used to convert the tensors to the Burn modules.
+    pub fn with_adapter_type(mut self, adapter_type: AdapterType) -> Self {
+        self.adapter_type = adapter_type;
+        self
+    }
+
+    /// Sets printing debug information on.
+    pub fn with_debug_print(mut self) -> Self {
+        self.debug = true;
+        self
+    }
+
+    fn load_file<I: DeserializeOwned, B: Backend>(
+        &self,
+        file: PathBuf,
+    ) -> Result<I, RecorderError> {
+        let item = from_file::<PS, I, B>(
+            &file,
+            self.key_remap.clone(),
+            self.top_level_key.as_deref(), // Convert Option<String> to Option<&str>
+            self.adapter_type,
+            self.debug,
+        )?;
+        Ok(item)
+    }
+}
+
+impl<PS: PrecisionSettings, B: Backend> Recorder<B> for SafetensorsFileRecorder<PS> {
+    type Settings = PS;
+    type RecordArgs = PathBuf;
+    type RecordOutput = ();
+    type LoadArgs = PathBuf;
+
+    fn save_item<I: Serialize>(
+        &self,
+        item: I,
+        mut file: Self::RecordArgs,
+    ) -> Result<(), RecorderError> {
+        file.set_extension(<Self as FileRecorder<B>>::file_extension());
+        to_file(item, &file).map_err(|err| RecorderError::Unknown(err.to_string()))
+    }
+
+    fn load_item<I: DeserializeOwned>(&self, mut file: Self::LoadArgs) -> Result<I, RecorderError> {
+        file.set_extension(<Self as FileRecorder<B>>::file_extension());
+        self.load_file::<I, B>(file)
+    }
+
+    fn load<R: Record<B>>(
+        &self,
+        mut file: Self::LoadArgs,
+        device: &B::Device,
+    ) -> Result<R, RecorderError> {
+        // The tensors are not wrapped with the record metadata
+        file.set_extension(<Self as FileRecorder<B>>::file_extension());
+        let item = self.load_file::<R::Item<Self::Settings>, B>(file)?;
+        Ok(R::from_item(item, device))
+    }
+}
+
+impl<PS: PrecisionSettings, B: Backend> FileRecorder<B> for SafetensorsFileRecorder<PS> {
+    fn file_extension() -> &'static str {
+        "safetensors"
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::collections::HashMap;
+
+    use burn::{
+        backend::NdArray,
+        module::Module,
+        nn::{LayerNorm, LayerNormConfig, Linear, LinearConfig},
+        record::{FullPrecisionSettings, HalfPrecisionSettings},
+        tensor::{Tensor, TensorData},
+    };
+    use half::f16;
+    use safetensors::{tensor::TensorView, Dtype};
+
+    type TestBackend = NdArray<f32>;
+
+    #[derive(Module, Debug)]
+    struct Net<B: Backend> {
+        fc: Linear<B>,
+        norm: LayerNorm<B>,
+    }
+
+    impl<B: Backend> Net<B> {
+        fn new(device: &B::Device) -> Self {
+            Self {
+                fc: LinearConfig::new(3, 2).init(device),
+                norm: LayerNormConfig::new(2).init(device),
+            }
+        }
+    }
+
+    fn bytes<T: bytemuck::Pod>(values: &[T]) -> Vec<u8> {
+        bytemuck::cast_slice(values).to_vec()
+    }
+
+    /// Writes the tensors of a PyTorch `Net` module nested under the `model` key.
+    fn write_pytorch_file(path: &std::path::Path) {
+        let weight = bytes(&[1.0f32, 2.0, 3.0, 4.0, 5.0, 6.0]);
+        let bias = bytes(&[0.5f32, -0.5]);
+        let gamma = bytes(&[2.0f32, 3.0]);
+        let beta = bytes(&[0.1f32, 0.2]);
+        let tensors = HashMap::from([
+            (
+                "model.fc.weight",
+                TensorView::new(Dtype::F32, vec![2, 3], &weight).unwrap(),
+            ),
+            (
+                "model.fc.bias",
+                TensorView::new(Dtype::F32, vec![2], &bias).unwrap(),
+            ),
+            (
+                "model.layer_norm.weight",
+                TensorView::new(Dtype::F32, vec![2], &gamma).unwrap(),
+            ),
+            (
+                "model.layer_norm.bias",
+                TensorView::new(Dtype::F32, vec![2], &beta).unwrap(),
+            ),
+        ]);
+
+        safetensors::serialize_to_file(tensors, &None, path).unwrap();
+    }
+
+    #[test]
+    fn should_load_pytorch_tensors_with_key_remap_and_top_level_key() {
+        let dir = tempfile::tempdir().unwrap();
+        let file = dir.path().join("net.safetensors");
+        write_pytorch_file(&file);
+
+        let device = Default::default();
+        let record: NetRecord<TestBackend> =
+            SafetensorsFileRecorder::<FullPrecisionSettings>::default()
+                .with_top_level_key("model")
+                .with_key_remap("layer_norm\\.(.*)", "norm.$1")
+                .load(file, &device)
+                .expect("Should load the record");
+        let net = Net::<TestBackend>::new(&device).load_record(record);
+
+        // The linear weight is transposed and the normalization parameters are renamed
+        net.fc.weight.to_data().assert_eq(
+            &TensorData::from([[1.0f32, 4.0], [2.0, 5.0], [3.0, 6.0]]),
+            true,
+        );
+        net.fc
+            .bias
+            .unwrap()
+            .to_data()
+            .assert_eq(&TensorData::from([0.5f32, -0.5]), true);
+        net.norm
+            .gamma
+            .to_data()
+            .assert_eq(&TensorData::from([2.0f32, 3.0]), true);
+        net.norm
+            .beta
+            .to_data()
+            .assert_eq(&TensorData::from([0.1f32, 0.2]), true);
+    }
+
+    #[test]
+    fn should_convert_half_precision_tensors() {
+        let dir = tempfile::tempdir().unwrap();
+        let file = dir.path().join("linear.safetensors");
+        let weight = bytes(&[f16::from_f32(1.5), f16::from_f32(-2.0)]);
+        let tensors = HashMap::from([(
+            "weight",
+            TensorView::new(Dtype::F16, vec![1, 2], &weight).unwrap(),
+        )]);
+        safetensors::serialize_to_file(tensors, &None, &file).unwrap();
+
+        let device = Default::default();
+        let record: burn::nn::LinearRecord<TestBackend> =
+            SafetensorsFileRecorder::<FullPrecisionSettings>::default()
+                .load(file, &device)
+                .expect("Should load the record");
+
+        record
+            .weight
+            .to_data()
+            .assert_eq(&TensorData::from([[1.5f32], [-2.0]]), true);
+        assert!(record.bias.is_none());
+    }
+
+    #[cfg(unix)]
+    #[test]
+    fn should_read_values_from_mapped_file_when_used() {
+        use std::io::{Seek, SeekFrom, Write};
+
+        let dir = tempfile::tempdir().unwrap();
+        let file = dir.path().join("linear.safetensors");
+        let weight = bytes(&[1.0f32, 2.0]);
+        let tensors = HashMap::from([(
+            "weight",
+            TensorView::new(Dtype::F32, vec![2, 1], &weight).unwrap(),
+        )]);
+        safetensors::serialize_to_file(tensors, &None, &file).unwrap();
+
+        let device = Default::default();
+        let record: burn::nn::LinearRecord<TestBackend> =
+            SafetensorsFileRecorder::<FullPrecisionSettings>::default()
+                .with_adapter_type(AdapterType::NoAdapter)
+                .load(file.clone(), &device)
+                .expect("Should load the record");
+
+        // The values at the end of the file are overwritten in place after loading the record
+        let mut writer = std::fs::OpenOptions::new().write(true).open(&file).unwrap();
+        writer.seek(SeekFrom::End(-8)).unwrap();
+        writer.write_all(&bytes(&[3.0f32, 4.0])).unwrap();
+        writer.sync_all().unwrap();
+
+        record
+            .weight
+            .to_data()
+            .assert_eq(&TensorData::from([[3.0f32], [4.0]]), true);
+    }
+
+    #[test]
+    fn should_save_and_load_module() {
+        let dir = tempfile::tempdir().unwrap();
+        let file = dir.path().join("net");
+        let device = Default::default();
+        let net = Net::<TestBackend>::new(&device);
+        let recorder = SafetensorsFileRecorder::<HalfPrecisionSettings>::default()
+            .with_adapter_type(AdapterType::NoAdapter);
+
+        net.clone()
+            .save_file(file.clone(), &recorder)
+            .expect("Should save the module");
+        let loaded = Net::<TestBackend>::new(&device)
+            .load_file(file.clone(), &recorder, &device)
+            .expect("Should load the module");
+
+        let weight: Tensor<TestBackend, 2> = loaded.fc.weight.val();
+        weight
+            .into_data()
+            .assert_approx_eq(&net.fc.weight.val().into_data(), 2);
+
+        // The tensors are saved with their path in the module and the record metadata
+        let bytes = std::fs::read(file.with_extension("safetensors")).unwrap();
+        let (_, metadata) = safetensors::SafeTensors::read_metadata(&bytes).unwrap();
+        let mut keys: Vec<_> = metadata.tensors().into_keys().collect();
+        keys.sort();
+        assert_eq!(keys, ["fc.bias", "fc.weight", "norm.beta", "norm.gamma"]);
+        assert_eq!(metadata.tensors()["fc.weight"].dtype, Dtype::F16);
+        assert!(metadata
+            .metadata()
+            .as_ref()
+            .unwrap()
+            .contains_key("version"));
+    }
+
+    #[test]
+    fn should_return_file_not_found() {
+        let result: Result<NetRecord<TestBackend>, _> =
+            SafetensorsFileRecorder::<FullPrecisionSettings>::default()
+                .load("/does/not/exist".into(), &Default::default());
+
+        assert!(matches!(result, Err(RecorderError::FileNotFound(_))));
+    }
+}
@@ -0,0 +1,140 @@
+use std::borrow::Cow;
+use std::collections::HashMap;
+use std::path::Path;
+
+use super::error::Error;
+
+use burn::{
+    record::serde::{
+        adapter::DefaultAdapter, data::NestedValue, de::Deserializer, ser::Serializer,
+    },
+    tensor::{DType, TensorData},
+};
+
+use safetensors::{Dtype, View};
+use serde::{Deserialize, Serialize};
+
+/// Serializes a record item to a Safetensors file.
+///
+/// The tensors are saved with the keys of their path in the record, e.g. `layers.0.weight`,
+/// without any adaptation of their layout. Values that are not tensors are not saved.
+///
+/// # Arguments
+///
+/// * `item` - The record item to serialize.
+/// * `path` - The path of the file to write.
+pub fn to_file<I: Serialize>(item: I, path: &Path) -> Result<(), Error> {
+    let value = item.serialize(Serializer::new())?;
+
+    // Records are wrapped with their metadata, which is saved in the file header
+    let (value, metadata) = match value {
+        NestedValue::Map(mut map) if map.contains_key("metadata") && map.contains_key("item") => {
+            let metadata = map.remove("metadata").and_then(NestedValue::as_map);
+            let metadata = metadata.map(|metadata| {
+                metadata
+                    .into_iter()
+                    .filter_map(|(key, value)| Some((key, value.as_string()?)))
+                    .collect::<HashMap<_, _>>()
+            });
+            (map.remove("item").unwrap(), metadata)
+        }
+        value => (value, None),
+    };
+
+    let mut tensors = Vec::new();
+    flatten(value, None, &mut tensors)?;
+
+    // Add parent directories if they don't exist
+    if let Some(parent) = path.parent() {
+        std::fs::create_dir_all(parent)?;
+    }
+
+    safetensors::serialize_to_file(tensors, &metadata, path)?;
+    Ok(())
+}
+
+/// Collects the tensors of a nested value with their flattened keys.
+fn flatten(
+    value: NestedValue,
+    key: Option<String>,
+    tensors: &mut Vec<(String, SafetensorsData)>,
+) -> Result<(), Error> {
+    let child_key = |name: &str| match &key {
+        Some(key) => format!("{key}.{name}"),
+        None => name.to_string(),
+    };
+
+    match value {
+        // Parameters are serialized as a map with an id and the tensor data
+        NestedValue::Map(mut map) if map.contains_key("id") && map.contains_key("param") => {
+            let param = map.remove("param").unwrap();
+            let data = TensorData::deserialize(Deserializer::<DefaultAdapter>::new(param, false))?;
+            let key = key.ok_or_else(|| Error::Other("Expected a key for the tensor".into()))?;
+
+            tensors.push((key.clone(), SafetensorsData::try_new(key, data)?));
+        }
+        NestedValue::Map(map) => {
+            for (name, value) in map {
+                flatten(value, Some(child_key(&name)), tensors)?;
+            }
+        }
+        NestedValue::Vec(values) => {
+            for (index, value) in values.into_iter().enumerate() {
+                flatten(value, Some(child_key(&index.to_string())), tensors)?;
+            }
+        }
+        _ => {}
+    }
+
+    Ok(())
+}
+
+/// Tensor data with its Safetensors data type.
+struct SafetensorsData {
+    data: TensorData,
+    dtype: Dtype,
+}
+
+impl SafetensorsData {
+    fn try_new(key: String, data: TensorData) -> Result<Self, Error> {
+        let dtype = match data.dtype {
+            DType::F64 => Dtype::F64,
+            DType::F32 => Dtype::F32,
+            DType::F16 => Dtype::F16,
+            DType::BF16 => Dtype::BF16,
+            DType::I64 => Dtype::I64,
+            DType::I32 => Dtype::I32,
+            DType::I16 => Dtype::I16,
+            DType::I8 => Dtype::I8,
+            DType::U64 => Dtype::U64,
+            DType::U32 => Dtype::U32,
+            DType::U8 => Dtype::U8,
+            DType::Bool => Dtype::BOOL,
+            DType::QFloat(_) => {
+                return Err(Error::Other(format!(
+                    "Quantized tensors are not supported by Safetensors (key: {key})"
+                )))
+            }
+        };
+
+        Ok(Self { data, dtype })
+    }
+}
+
+impl View for SafetensorsData {
+    fn dtype(&self) -> Dtype {
+        self.dtype
+    }
+
+    fn shape(&self) -> &[usize] {
+        &self.data.shape
+    }
+
+    fn data(&self) -> Cow<'_, [u8]> {
+        Cow::Borrowed(&self.data.bytes)
+    }
+
+    fn data_len(&self) -> usize {
+        self.data.bytes.len()
+    }
+}
@@ -33,7 +33,7 @@ where
     type FloatTensorPrimitive<const D: usize> = JitTensor<R, Self::FloatElem, D>;
     type IntTensorPrimitive<const D: usize> = JitTensor<R, Self::IntElem, D>;
     type BoolTensorPrimitive<const D: usize> = JitTensor<R, u32, D>;
-    // TODO: implement `JitElement` / `CubeElement` for quantized type
+    // Quantized values are stored as `u32`, see the quantization kernels for the layout.
     type QuantizedTensorPrimitive<const D: usize> = JitTensor<R, u32, D>;
 
     fn name() -> String {
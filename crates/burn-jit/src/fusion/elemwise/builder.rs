@@ -165,6 +165,21 @@ impl<R: JitRuntime> ElementWiseBuilder<R> {
                 .register_unary_ops(desc, |input, out| {
                     Operator::Recip(UnaryOperator { input, out })
                 }),
+            FloatOperationDescription::Floor(desc) => self
+                .register_unary_ops(desc, |input, out| {
+                    Operator::Floor(UnaryOperator { input, out })
+                }),
+            FloatOperationDescription::Ceil(desc) => self.register_unary_ops(desc, |input, out| {
+                Operator::Ceil(UnaryOperator { input, out })
+            }),
+            FloatOperationDescription::Round(desc) => self
+                .register_unary_ops(desc, |input, out| {
+                    Operator::Round(UnaryOperator { input, out })
+                }),
+            FloatOperationDescription::Trunc(desc) => self
+                .register_unary_ops(desc, |input, out| {
+                    Operator::Trunc(UnaryOperator { input, out })
+                }),
             _ => false,
         }
     }
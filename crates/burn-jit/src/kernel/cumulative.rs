@@ -0,0 +1,161 @@
+use burn_cube::{calculate_cube_count_elemwise, prelude::*, unexpanded, SUBCUBE_DIM_APPROX};
+
+use crate::{
+    element::JitElement, kernel::into_contiguous, ops::numeric::empty_device, tensor::JitTensor,
+    JitRuntime,
+};
+
+/// An associative operation accumulated along a dimension.
+pub(crate) trait CumulativeOp<C: Numeric>: 'static + Send + Sync {
+    /// Combine the accumulated value of the previous elements with the current one.
+    fn execute(_previous: C, _current: C) -> C {
+        unexpanded!();
+    }
+    fn __expand_execute(
+        context: &mut CubeContext,
+        previous: C::ExpandType,
+        current: C::ExpandType,
+    ) -> C::ExpandType;
+}
+
+macro_rules! cumulative_op {
+    ($name:ident, $execute:item) => {
+        pub(crate) struct $name;
+
+        impl<C: Numeric> CumulativeOp<C> for $name {
+            fn __expand_execute(
+                context: &mut CubeContext,
+                previous: C::ExpandType,
+                current: C::ExpandType,
+            ) -> C::ExpandType {
+                #[cube]
+                $execute
+
+                execute::__expand::<C>(context, previous, current)
+            }
+        }
+    };
+}
+
+cumulative_op!(
+    SumOp,
+    fn execute<C: Numeric>(previous: C, current: C) -> C {
+        previous + current
+    }
+);
+cumulative_op!(
+    ProdOp,
+    fn execute<C: Numeric>(previous: C, current: C) -> C {
+        previous * current
+    }
+);
+cumulative_op!(
+    MaxOp,
+    fn execute<C: Numeric>(previous: C, current: C) -> C {
+        C::max(previous, current)
+    }
+);
+cumulative_op!(
+    MinOp,
+    fn execute<C: Numeric>(previous: C, current: C) -> C {
+        C::min(previous, current)
+    }
+);
+
+/// One step of a Hillis-Steele scan: each element is combined with the element `offset` positions
+/// before it along the dimension.
+#[cube(launch)]
+fn cumulative_step_kernel<C: Numeric, O: CumulativeOp<C>>(
+    input: &Tensor<C>,
+    output: &mut Tensor<C>,
+    dim: UInt,
+    offset: UInt,
+) {
+    if ABSOLUTE_POS >= output.len() {
+        return;
+    }
+
+    let stride = input.stride(dim);
+    let index = ABSOLUTE_POS / stride % input.shape(dim);
+
+    if index >= offset {
+        output[ABSOLUTE_POS] =
+            O::execute(input[ABSOLUTE_POS - offset * stride], input[ABSOLUTE_POS]);
+    } else {
+        output[ABSOLUTE_POS] = input[ABSOLUTE_POS];
+    }
+}
+
+/// Accumulate the elements of the tensor along a dimension with the given operation.
+///
+/// The scan takes `log2(n)` steps, alternating between two new buffers, where `n` is the size of
+/// the dimension.
+pub(crate) fn cumulative<
+    R: JitRuntime,
+    E: JitElement,
+    O: CumulativeOp<E::Primitive>,
+    const D: usize,
+>(
+    tensor: JitTensor<R, E, D>,
+    dim: usize,
+) -> JitTensor<R, E, D> {
+    let size = tensor.shape.dims[dim];
+    let mut input = into_contiguous(tensor);
+    let (client, device, shape) = (
+        input.client.clone(),
+        input.device.clone(),
+        input.shape.clone(),
+    );
+    let empty = || empty_device(client.clone(), device.clone(), shape.clone());
+    let mut output = empty();
+    let cube_count = calculate_cube_count_elemwise(shape.num_elements(), SUBCUBE_DIM_APPROX);
+    let mut offset = 1;
+
+    while offset < size {
+        cumulative_step_kernel::launch::<E::Primitive, O, R>(
+            input.client.clone(),
+            cube_count.clone(),
+            CubeDim::default(),
+            TensorArg::new(&input.handle, &input.strides, &input.shape.dims),
+            TensorArg::new(&output.handle, &output.strides, &output.shape.dims),
+            ScalarArg::new(dim as u32),
+            ScalarArg::new(offset as u32),
+        );
+
+        // The first step reads the input, which may share its buffer with other tensors, so the
+        // next steps write into a second new buffer instead
+        let previous = core::mem::replace(&mut input, output);
+        output = if offset == 1 { empty() } else { previous };
+        offset *= 2;
+    }
+
+    input
+}
+
+pub(crate) fn cumsum<R: JitRuntime, E: JitElement, const D: usize>(
+    tensor: JitTensor<R, E, D>,
+    dim: usize,
+) -> JitTensor<R, E, D> {
+    cumulative::<R, E, SumOp, D>(tensor, dim)
+}
+
+pub(crate) fn cumprod<R: JitRuntime, E: JitElement, const D: usize>(
+    tensor: JitTensor<R, E, D>,
+    dim: usize,
+) -> JitTensor<R, E, D> {
+    cumulative::<R, E, ProdOp, D>(tensor, dim)
+}
+
+pub(crate) fn cummax<R: JitRuntime, E: JitElement, const D: usize>(
+    tensor: JitTensor<R, E, D>,
+    dim: usize,
+) -> JitTensor<R, E, D> {
+    cumulative::<R, E, MaxOp, D>(tensor, dim)
+}
+
+pub(crate) fn cummin<R: JitRuntime, E: JitElement, const D: usize>(
+    tensor: JitTensor<R, E, D>,
+    dim: usize,
+) -> JitTensor<R, E, D> {
+    cumulative::<R, E, MinOp, D>(tensor, dim)
+}
@@ -0,0 +1,285 @@
+use core::f64::consts::PI;
+
+use burn_cube::{calculate_cube_count_elemwise, prelude::*, SUBCUBE_DIM_APPROX};
+use burn_tensor::{Shape, TensorData};
+
+use crate::{
+    kernel::into_contiguous,
+    ops::{from_data, numeric::empty_device},
+    tensor::JitTensor,
+    FloatElement, JitRuntime,
+};
+
+/// Move each element along the dimension to the position with the reversed bits of its index, the
+/// input order of the radix-2 transform.
+#[cube(launch)]
+fn bit_reverse_kernel<F: Float>(
+    input_re: &Tensor<F>,
+    input_im: &Tensor<F>,
+    output_re: &mut Tensor<F>,
+    output_im: &mut Tensor<F>,
+    dim: UInt,
+    bits: UInt,
+) {
+    if ABSOLUTE_POS >= output_re.len() {
+        return;
+    }
+
+    let stride = input_re.stride(dim);
+    let index = ABSOLUTE_POS / stride % input_re.shape(dim);
+
+    let mut reversed = UInt::new(0);
+    let mut rest = index;
+    for _ in range(0u32, bits, Comptime::new(false)) {
+        reversed = reversed * UInt::new(2) + rest % UInt::new(2);
+        rest /= UInt::new(2);
+    }
+
+    let position = ABSOLUTE_POS - index * stride + reversed * stride;
+    output_re[ABSOLUTE_POS] = input_re[position];
+    output_im[ABSOLUTE_POS] = input_im[position];
+}
+
+/// One stage of the radix-2 transform, combining the pairs of transforms of size `half` into
+/// transforms of size `2 half`.
+///
+/// Each element is the sum or the difference of its pair, with the second element of the pair
+/// multiplied by `exp(i angle k / half)`.
+#[cube(launch)]
+fn butterfly_kernel<F: Float>(
+    input_re: &Tensor<F>,
+    input_im: &Tensor<F>,
+    output_re: &mut Tensor<F>,
+    output_im: &mut Tensor<F>,
+    dim: UInt,
+    half: UInt,
+    angle: F,
+) {
+    if ABSOLUTE_POS >= output_re.len() {
+        return;
+    }
+
+    let stride = input_re.stride(dim);
+    let index = ABSOLUTE_POS / stride % input_re.shape(dim);
+    let position_in_pair = index % (half * UInt::new(2));
+    let k = position_in_pair % half;
+
+    // The first and the second element of the pair
+    let first = ABSOLUTE_POS - (position_in_pair - k) * stride;
+    let second = first + half * stride;
+
+    let theta = angle * F::cast_from(k) / F::cast_from(half);
+    let cos = F::cos(theta);
+    let sin = F::sin(theta);
+    let re = input_re[second];
+    let im = input_im[second];
+    let twiddled_re = re * cos - im * sin;
+    let twiddled_im = re * sin + im * cos;
+
+    if position_in_pair < half {
+        output_re[ABSOLUTE_POS] = input_re[first] + twiddled_re;
+        output_im[ABSOLUTE_POS] = input_im[first] + twiddled_im;
+    } else {
+        output_re[ABSOLUTE_POS] = input_re[first] - twiddled_re;
+        output_im[ABSOLUTE_POS] = input_im[first] - twiddled_im;
+    }
+}
+
+/// Multiply the elements along the dimension by a complex sequence, scaled by `scale`.
+///
+/// The output is truncated or padded with zeros when its dimension is smaller or larger than the
+/// one of the input.
+#[cube(launch)]
+fn modulate_kernel<F: Float>(
+    input_re: &Tensor<F>,
+    input_im: &Tensor<F>,
+    sequence_re: &Tensor<F>,
+    sequence_im: &Tensor<F>,
+    output_re: &mut Tensor<F>,
+    output_im: &mut Tensor<F>,
+    dim: UInt,
+    scale: F,
+) {
+    if ABSOLUTE_POS >= output_re.len() {
+        return;
+    }
+
+    let stride = output_re.stride(dim);
+    let size_output = output_re.shape(dim);
+    let size_input = input_re.shape(dim);
+    let index = ABSOLUTE_POS / stride % size_output;
+
+    if index < size_input {
+        // The dimensions after the transformed one have the same strides in both tensors
+        let outer = ABSOLUTE_POS / (stride * size_output);
+        let inner = ABSOLUTE_POS % stride;
+        let position = (outer * size_input + index) * stride + inner;
+
+        let re = input_re[position];
+        let im = input_im[position];
+        let factor_re = sequence_re[index] * scale;
+        let factor_im = sequence_im[index] * scale;
+
+        output_re[ABSOLUTE_POS] = re * factor_re - im * factor_im;
+        output_im[ABSOLUTE_POS] = re * factor_im + im * factor_re;
+    } else {
+        output_re[ABSOLUTE_POS] = F::new(0.0);
+        output_im[ABSOLUTE_POS] = F::new(0.0);
+    }
+}
+
+/// Compute the unnormalized discrete Fourier transform of complex tensors along a dimension.
+///
+/// Sizes that are powers of two use the radix-2 Cooley-Tukey transform, with a launch per stage.
+/// The other sizes use Bluestein's algorithm, which computes the transform as a convolution with a
+/// chirp, itself computed with power of two transforms. Both run in `O(n log(n))`.
+pub(crate) fn fft<R: JitRuntime, E: FloatElement, const D: usize>(
+    re: JitTensor<R, E, D>,
+    im: JitTensor<R, E, D>,
+    dim: usize,
+    inverse: bool,
+) -> (JitTensor<R, E, D>, JitTensor<R, E, D>) {
+    let n = re.shape.dims[dim];
+    let sign = if inverse { 1.0 } else { -1.0 };
+    let re = into_contiguous(re);
+    let im = into_contiguous(im);
+
+    if n.is_power_of_two() || n == 0 {
+        return radix2(re, im, dim, sign);
+    }
+
+    // jk = (j^2 + k^2 - (k - j)^2) / 2, so the transform is the convolution of the signal
+    // multiplied by the chirp with the conjugate of the chirp
+    let m = (2 * n - 1).next_power_of_two();
+    let chirp = (0..n)
+        .map(|k| sign * PI * ((k * k) % (2 * n)) as f64 / n as f64)
+        .collect::<Vec<_>>();
+    let (mut filter_re, mut filter_im) = (vec![0.0; m], vec![0.0; m]);
+    for (k, angle) in chirp.iter().enumerate() {
+        filter_re[k] = angle.cos();
+        filter_im[k] = -angle.sin();
+        filter_re[(m - k) % m] = angle.cos();
+        filter_im[(m - k) % m] = -angle.sin();
+    }
+
+    let sequence = |values: Vec<f64>| {
+        let size = values.len();
+        from_data::<R, E, 1>(TensorData::new(values, [size]), &re.device)
+    };
+    let chirp_re = sequence(chirp.iter().map(|angle| angle.cos()).collect());
+    let chirp_im = sequence(chirp.iter().map(|angle| angle.sin()).collect());
+    let (filter_re, filter_im) = radix2(sequence(filter_re), sequence(filter_im), 0, -1.0);
+
+    let mut shape_padded = re.shape.clone();
+    shape_padded.dims[dim] = m;
+    let shape = re.shape.clone();
+
+    let (re, im) = modulate(
+        re,
+        im,
+        (&chirp_re, &chirp_im),
+        dim,
+        shape_padded.clone(),
+        1.0,
+    );
+    let (re, im) = radix2(re, im, dim, -1.0);
+    let (re, im) = modulate(re, im, (&filter_re, &filter_im), dim, shape_padded, 1.0);
+    let (re, im) = radix2(re, im, dim, 1.0);
+
+    modulate(re, im, (&chirp_re, &chirp_im), dim, shape, 1.0 / m as f64)
+}
+
+/// Iterative radix-2 transform of contiguous tensors, whose dimension is a power of two.
+///
+/// The angle of the factors is `sign 2 pi jk / n`.
+fn radix2<R: JitRuntime, E: FloatElement, const D: usize>(
+    re: JitTensor<R, E, D>,
+    im: JitTensor<R, E, D>,
+    dim: usize,
+    sign: f64,
+) -> (JitTensor<R, E, D>, JitTensor<R, E, D>) {
+    let n = re.shape.dims[dim];
+    if n <= 1 {
+        return (re, im);
+    }
+
+    let empty = || empty_device(re.client.clone(), re.device.clone(), re.shape.clone());
+    let (mut input_re, mut input_im) = (empty(), empty());
+    let cube_count = calculate_cube_count_elemwise(re.shape.num_elements(), SUBCUBE_DIM_APPROX);
+
+    bit_reverse_kernel::launch::<E::FloatPrimitive, R>(
+        re.client.clone(),
+        cube_count.clone(),
+        CubeDim::default(),
+        TensorArg::new(&re.handle, &re.strides, &re.shape.dims),
+        TensorArg::new(&im.handle, &im.strides, &im.shape.dims),
+        TensorArg::new(&input_re.handle, &input_re.strides, &input_re.shape.dims),
+        TensorArg::new(&input_im.handle, &input_im.strides, &input_im.shape.dims),
+        ScalarArg::new(dim as u32),
+        ScalarArg::new(n.trailing_zeros()),
+    );
+
+    // The stages alternate between two buffers
+    let (mut output_re, mut output_im) = (empty(), empty());
+    let mut half = 1;
+
+    while half < n {
+        butterfly_kernel::launch::<E::FloatPrimitive, R>(
+            input_re.client.clone(),
+            cube_count.clone(),
+            CubeDim::default(),
+            TensorArg::new(&input_re.handle, &input_re.strides, &input_re.shape.dims),
+            TensorArg::new(&input_im.handle, &input_im.strides, &input_im.shape.dims),
+            TensorArg::new(&output_re.handle, &output_re.strides, &output_re.shape.dims),
+            TensorArg::new(&output_im.handle, &output_im.strides, &output_im.shape.dims),
+            ScalarArg::new(dim as u32),
+            ScalarArg::new(half as u32),
+            ScalarArg::new(E::from_elem(sign * PI)),
+        );
+
+        core::mem::swap(&mut input_re, &mut output_re);
+        core::mem::swap(&mut input_im, &mut output_im);
+        half *= 2;
+    }
+
+    (input_re, input_im)
+}
+
+/// Multiply contiguous tensors along the dimension by a complex sequence, into tensors of the
+/// given shape.
+fn modulate<R: JitRuntime, E: FloatElement, const D: usize>(
+    re: JitTensor<R, E, D>,
+    im: JitTensor<R, E, D>,
+    (sequence_re, sequence_im): (&JitTensor<R, E, 1>, &JitTensor<R, E, 1>),
+    dim: usize,
+    shape: Shape<D>,
+    scale: f64,
+) -> (JitTensor<R, E, D>, JitTensor<R, E, D>) {
+    let empty = || empty_device(re.client.clone(), re.device.clone(), shape.clone());
+    let (output_re, output_im) = (empty(), empty());
+    let cube_count = calculate_cube_count_elemwise(shape.num_elements(), SUBCUBE_DIM_APPROX);
+
+    modulate_kernel::launch::<E::FloatPrimitive, R>(
+        re.client.clone(),
+        cube_count,
+        CubeDim::default(),
+        TensorArg::new(&re.handle, &re.strides, &re.shape.dims),
+        TensorArg::new(&im.handle, &im.strides, &im.shape.dims),
+        TensorArg::new(
+            &sequence_re.handle,
+            &sequence_re.strides,
+            &sequence_re.shape.dims,
+        ),
+        TensorArg::new(
+            &sequence_im.handle,
+            &sequence_im.strides,
+            &sequence_im.shape.dims,
+        ),
+        TensorArg::new(&output_re.handle, &output_re.strides, &output_re.shape.dims),
+        TensorArg::new(&output_im.handle, &output_im.strides, &output_im.shape.dims),
+        ScalarArg::new(dim as u32),
+        ScalarArg::new(E::from_elem(scale)),
+    );
+
+    (output_re, output_im)
+}
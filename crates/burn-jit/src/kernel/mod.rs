@@ -3,6 +3,8 @@ mod cast;
 mod clamp;
 mod comparison;
 mod contiguous;
+mod cumulative;
+mod fft;
 mod index;
 mod mask;
 mod unary;
@@ -25,9 +27,13 @@ pub mod matmul;
 pub mod pool;
 /// Pseudo-random number generator kernels
 pub mod prng;
+/// Quantization kernels
+pub mod quantization;
 /// Reduction algorithms
 pub mod reduce;
 
 pub(crate) use clamp::*;
 pub(crate) use comparison::*;
+pub(crate) use cumulative::*;
+pub(crate) use fft::*;
 pub(crate) use index::*;
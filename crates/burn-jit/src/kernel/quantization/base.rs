@@ -0,0 +1,254 @@
+use burn_cube::{calculate_cube_count_elemwise, prelude::*, SUBCUBE_DIM_APPROX};
+use burn_tensor::{QuantizationKind, QuantizationStrategy, TensorData};
+
+use crate::{
+    kernel::into_contiguous,
+    ops::{from_data, numeric::empty_device},
+    tensor::JitTensor,
+    FloatElement, JitRuntime,
+};
+
+/// The quantized values are stored as `u32` elements offset by the minimum value of the quantized
+/// type, so that the stored values are always positive.
+///
+/// Packed 4-bit values are only packed in the tensor data, each value still uses one element on the
+/// device.
+pub(crate) fn storage_offset(kind: QuantizationKind) -> i32 {
+    kind.value_range().0
+}
+
+#[derive(CubeLaunch)]
+struct QuantizationArgs<F: Float> {
+    /// Number of contiguous values that share the same quantization parameters.
+    channel_size: UInt,
+    /// Minimum value of the quantized range.
+    min_value: F,
+    /// Maximum value of the quantized range.
+    max_value: F,
+    /// Offset applied to the quantized values when they are stored.
+    storage_offset: F,
+}
+
+/// The quantization parameters of a strategy, with one scale and offset per channel (or group).
+struct QuantizationParams<R: JitRuntime, F: FloatElement> {
+    scale: JitTensor<R, F, 1>,
+    offset: JitTensor<R, F, 1>,
+    channel_size: usize,
+    range: (f32, f32),
+    storage_offset: i32,
+}
+
+impl<R: JitRuntime, F: FloatElement> QuantizationParams<R, F> {
+    /// Create the quantization parameters of a tensor with the given shape on the device.
+    fn new(strategy: &QuantizationStrategy, shape: &[usize], device: &R::Device) -> Self {
+        let num_elements = shape.iter().product::<usize>();
+        let (min, max) = strategy.value_range();
+        let affine = (min as f32, max as f32);
+        // The symmetric range is restricted to `[-b, b]`
+        let symmetric = (-max as f32, max as f32);
+
+        let (scales, offsets, channel_size, range): (Vec<f32>, Vec<f32>, usize, (f32, f32)) =
+            match strategy {
+                QuantizationStrategy::PerTensorAffineInt8(q) => {
+                    (vec![q.scale], vec![q.offset as f32], num_elements, affine)
+                }
+                QuantizationStrategy::PerTensorSymmetricInt8(q) => {
+                    (vec![q.scale], vec![0.], num_elements, symmetric)
+                }
+                QuantizationStrategy::PerTensorAffineUInt8(q) => {
+                    (vec![q.scale], vec![q.offset as f32], num_elements, affine)
+                }
+                QuantizationStrategy::PerChannelAffineInt8(q) => (
+                    q.channels.iter().map(|c| c.scale).collect(),
+                    q.channels.iter().map(|c| c.offset as f32).collect(),
+                    shape[q.axis + 1..].iter().product(),
+                    affine,
+                ),
+                QuantizationStrategy::PerChannelSymmetricInt8(q) => (
+                    q.channels.iter().map(|c| c.scale).collect(),
+                    vec![0.; q.channels.len()],
+                    shape[q.axis + 1..].iter().product(),
+                    symmetric,
+                ),
+                QuantizationStrategy::PerChannelAffineUInt8(q) => (
+                    q.channels.iter().map(|c| c.scale).collect(),
+                    q.channels.iter().map(|c| c.offset as f32).collect(),
+                    shape[q.axis + 1..].iter().product(),
+                    affine,
+                ),
+                QuantizationStrategy::PerGroupAffineInt8(q) => (
+                    q.groups.iter().map(|g| g.scale).collect(),
+                    q.groups.iter().map(|g| g.offset as f32).collect(),
+                    q.group_size,
+                    affine,
+                ),
+                QuantizationStrategy::PerGroupSymmetricInt8(q) => (
+                    q.groups.iter().map(|g| g.scale).collect(),
+                    vec![0.; q.groups.len()],
+                    q.group_size,
+                    symmetric,
+                ),
+                QuantizationStrategy::PerGroupAffineUInt8(q) => (
+                    q.groups.iter().map(|g| g.scale).collect(),
+                    q.groups.iter().map(|g| g.offset as f32).collect(),
+                    q.group_size,
+                    affine,
+                ),
+                QuantizationStrategy::PerGroupAffineInt4(q) => (
+                    q.groups.iter().map(|g| g.scale).collect(),
+                    q.groups.iter().map(|g| g.offset as f32).collect(),
+                    q.group_size,
+                    affine,
+                ),
+                QuantizationStrategy::PerGroupSymmetricInt4(q) => (
+                    q.groups.iter().map(|g| g.scale).collect(),
+                    vec![0.; q.groups.len()],
+                    q.group_size,
+                    symmetric,
+                ),
+                QuantizationStrategy::PerGroupAffineUInt4(q) => (
+                    q.groups.iter().map(|g| g.scale).collect(),
+                    q.groups.iter().map(|g| g.offset as f32).collect(),
+                    q.group_size,
+                    affine,
+                ),
+            };
+
+        let num_channels = scales.len();
+        Self {
+            scale: from_data(TensorData::new(scales, [num_channels]), device),
+            offset: from_data(TensorData::new(offsets, [num_channels]), device),
+            channel_size: channel_size.max(1),
+            range,
+            storage_offset: storage_offset(strategy.kind()),
+        }
+    }
+
+    /// The launch arguments of the quantization kernels.
+    fn args(&self) -> QuantizationArgsLaunch<'_, R, F::FloatPrimitive> {
+        QuantizationArgsLaunch::new(
+            ScalarArg::new(self.channel_size as u32),
+            ScalarArg::new(F::from_elem(self.range.0)),
+            ScalarArg::new(F::from_elem(self.range.1)),
+            ScalarArg::new(F::from_elem(self.storage_offset)),
+        )
+    }
+}
+
+#[cube(launch)]
+fn quantize_kernel<F: Float>(
+    input: &Tensor<F>,
+    scale: &Tensor<F>,
+    offset: &Tensor<F>,
+    output: &mut Tensor<UInt>,
+    args: &QuantizationArgs<F>,
+) {
+    if ABSOLUTE_POS >= output.len() {
+        return;
+    }
+
+    let channel = ABSOLUTE_POS / args.channel_size % scale.len();
+
+    // x_q = clamp(round(x / scale + offset), a, b)
+    let value = F::round(input[ABSOLUTE_POS] / scale[channel] + offset[channel]);
+    let value = F::clamp(value, args.min_value, args.max_value);
+
+    output[ABSOLUTE_POS] = UInt::cast_from(value - args.storage_offset);
+}
+
+/// Convert the tensor to a lower precision data type based on the quantization strategy.
+pub fn quantize<R: JitRuntime, F: FloatElement, const D: usize>(
+    tensor: JitTensor<R, F, D>,
+    strategy: &QuantizationStrategy,
+) -> JitTensor<R, u32, D> {
+    let tensor = into_contiguous(tensor);
+    let params = QuantizationParams::<R, F>::new(strategy, &tensor.shape.dims, &tensor.device);
+
+    let output = empty_device(
+        tensor.client.clone(),
+        tensor.device.clone(),
+        tensor.shape.clone(),
+    );
+
+    let num_elems = tensor.shape.num_elements();
+    let cube_count = calculate_cube_count_elemwise(num_elems, SUBCUBE_DIM_APPROX);
+
+    quantize_kernel::launch::<F::FloatPrimitive, R>(
+        tensor.client,
+        cube_count,
+        CubeDim::default(),
+        TensorArg::new(&tensor.handle, &tensor.strides, &tensor.shape.dims),
+        TensorArg::new(
+            &params.scale.handle,
+            &params.scale.strides,
+            &params.scale.shape.dims,
+        ),
+        TensorArg::new(
+            &params.offset.handle,
+            &params.offset.strides,
+            &params.offset.shape.dims,
+        ),
+        TensorArg::new(&output.handle, &output.strides, &output.shape.dims),
+        params.args(),
+    );
+
+    output
+}
+
+#[cube(launch)]
+fn dequantize_kernel<F: Float>(
+    input: &Tensor<UInt>,
+    scale: &Tensor<F>,
+    offset: &Tensor<F>,
+    output: &mut Tensor<F>,
+    args: &QuantizationArgs<F>,
+) {
+    if ABSOLUTE_POS >= output.len() {
+        return;
+    }
+
+    let channel = ABSOLUTE_POS / args.channel_size % scale.len();
+    let value = F::cast_from(input[ABSOLUTE_POS]) + args.storage_offset;
+
+    // x = scale * (x_q - offset)
+    output[ABSOLUTE_POS] = scale[channel] * (value - offset[channel]);
+}
+
+/// Convert the tensor back to a higher precision data type based on the quantization strategy.
+pub fn dequantize<R: JitRuntime, F: FloatElement, const D: usize>(
+    tensor: JitTensor<R, u32, D>,
+    strategy: &QuantizationStrategy,
+) -> JitTensor<R, F, D> {
+    let tensor = into_contiguous(tensor);
+    let params = QuantizationParams::<R, F>::new(strategy, &tensor.shape.dims, &tensor.device);
+
+    let output = empty_device(
+        tensor.client.clone(),
+        tensor.device.clone(),
+        tensor.shape.clone(),
+    );
+
+    let num_elems = tensor.shape.num_elements();
+    let cube_count = calculate_cube_count_elemwise(num_elems, SUBCUBE_DIM_APPROX);
+
+    dequantize_kernel::launch::<F::FloatPrimitive, R>(
+        tensor.client,
+        cube_count,
+        CubeDim::default(),
+        TensorArg::new(&tensor.handle, &tensor.strides, &tensor.shape.dims),
+        TensorArg::new(
+            &params.scale.handle,
+            &params.scale.strides,
+            &params.scale.shape.dims,
+        ),
+        TensorArg::new(
+            &params.offset.handle,
+            &params.offset.strides,
+            &params.offset.shape.dims,
+        ),
+        TensorArg::new(&output.handle, &output.strides, &output.shape.dims),
+        params.args(),
+    );
+
+    output
+}
@@ -0,0 +1,232 @@
+use burn_cube::{calculate_cube_count_elemwise, prelude::*, SUBCUBE_DIM_APPROX};
+use burn_tensor::{
+    ops::{conv::calculate_conv_output_size, ConvOptions},
+    QuantizationStrategy, Shape,
+};
+
+use crate::{
+    kernel::{conv::conv2d, into_contiguous},
+    ops::{
+        numeric::{empty_device, zeros_device},
+        reshape,
+    },
+    tensor::JitTensor,
+    FloatElement, JitRuntime,
+};
+
+use super::{channel_params, dequantize, quantize, storage_offset};
+
+#[derive(CubeLaunch)]
+struct QuantizedConv2dArgs {
+    conv_stride_0: UInt,
+    conv_stride_1: UInt,
+    dilation_0: UInt,
+    dilation_1: UInt,
+    padding_0: UInt,
+    padding_1: UInt,
+    groups: UInt,
+    /// Offset applied to the input quantized values when they are stored.
+    input_storage_offset: I32,
+    /// Offset applied to the weight quantized values when they are stored.
+    weight_storage_offset: I32,
+}
+
+#[cube(launch)]
+fn quantized_conv2d_kernel<F: Float>(
+    input: &Tensor<UInt>,
+    weight: &Tensor<UInt>,
+    bias: &Tensor<F>,
+    input_scale: &Tensor<F>,
+    input_offset: &Tensor<I32>,
+    weight_scale: &Tensor<F>,
+    weight_offset: &Tensor<I32>,
+    output: &mut Tensor<F>,
+    args: &QuantizedConv2dArgs,
+) {
+    if ABSOLUTE_POS >= output.len() {
+        return;
+    }
+
+    let in_channels = weight.shape(1);
+    let kernel_size_0 = weight.shape(2);
+    let kernel_size_1 = weight.shape(3);
+
+    let b = ABSOLUTE_POS / output.stride(0) % output.shape(0);
+    let oc = ABSOLUTE_POS / output.stride(1) % output.shape(1);
+    let oh = ABSOLUTE_POS / output.stride(2) % output.shape(2);
+    let ow = ABSOLUTE_POS / output.stride(3) % output.shape(3);
+
+    let g = (weight.shape(0) + oc) % args.groups;
+    let ic_start = in_channels * g;
+    let ic_end = ic_start + in_channels;
+
+    let input_zero = input_offset[UInt::new(0)] - args.input_storage_offset;
+    let weight_zero = weight_offset[oc % weight_offset.len()] - args.weight_storage_offset;
+
+    let ih_base = oh * args.conv_stride_0;
+    let iw_base = ow * args.conv_stride_1;
+
+    let border_top = args.padding_0;
+    let border_left = args.padding_1;
+    let border_bottom = input.shape(2) + args.padding_0;
+    let border_right = input.shape(3) + args.padding_1;
+
+    let index_input_0 = b * input.stride(0);
+    let index_weight_0 = oc * weight.stride(0);
+
+    // acc = sum((x_q - offset) * (w_q - offset)), where the padding is zero
+    let mut acc = I32::new(0);
+
+    for ic in range(ic_start, ic_end, Comptime::new(false)) {
+        let index_input_1 = ic * input.stride(1);
+        let index_weight_1 = (ic - ic_start) * weight.stride(1);
+
+        for kh in range(0u32, kernel_size_0, Comptime::new(false)) {
+            for kw in range(0u32, kernel_size_1, Comptime::new(false)) {
+                let ih = kh * args.dilation_0 + ih_base;
+                let iw = kw * args.dilation_1 + iw_base;
+
+                let within_padding = ih >= border_top
+                    && ih < border_bottom
+                    && iw >= border_left
+                    && iw < border_right;
+
+                if within_padding {
+                    let ih_pad = ih - args.padding_0;
+                    let iw_pad = iw - args.padding_1;
+
+                    let index_input = index_input_0
+                        + index_input_1
+                        + ih_pad * input.stride(2)
+                        + iw_pad * input.stride(3);
+
+                    let index_weight = index_weight_0
+                        + index_weight_1
+                        + kh * weight.stride(2)
+                        + kw * weight.stride(3);
+
+                    let input_value = I32::cast_from(input[index_input]) - input_zero;
+                    let weight_value = I32::cast_from(weight[index_weight]) - weight_zero;
+
+                    acc += input_value * weight_value;
+                }
+            }
+        }
+    }
+
+    let scale = input_scale[UInt::new(0)] * weight_scale[oc % weight_scale.len()];
+    output[ABSOLUTE_POS] = F::cast_from(acc) * scale + bias[oc];
+}
+
+/// 2D convolution of quantized tensors.
+///
+/// The products of the quantized values are accumulated in `int32` and rescaled before being
+/// requantized with the output strategy. Only per-tensor input and per-tensor (or per output
+/// channel) weight strategies can be accumulated this way, the other strategies are dequantized
+/// to perform the convolution in floating point precision.
+pub fn quantized_conv2d<R: JitRuntime, F: FloatElement>(
+    input: JitTensor<R, u32, 4>,
+    input_strategy: &QuantizationStrategy,
+    weight: JitTensor<R, u32, 4>,
+    weight_strategy: &QuantizationStrategy,
+    bias: Option<JitTensor<R, F, 1>>,
+    options: ConvOptions<2>,
+    out_strategy: &QuantizationStrategy,
+) -> JitTensor<R, u32, 4> {
+    let (Some((input_scales, input_offsets)), Some((weight_scales, weight_offsets))) = (
+        input_strategy
+            .channel_params(0)
+            .filter(|(scales, _)| scales.len() == 1),
+        weight_strategy.channel_params(0),
+    ) else {
+        let input = dequantize::<R, F, 4>(input, input_strategy);
+        let weight = dequantize::<R, F, 4>(weight, weight_strategy);
+        return quantize(conv2d(input, weight, bias, options), out_strategy);
+    };
+
+    let input = into_contiguous(input);
+    let weight = into_contiguous(weight);
+    let [batch_size, _, in_height, in_width] = input.shape.dims;
+    let [out_channels, _, kernel_0, kernel_1] = weight.shape.dims;
+
+    let out_0 = calculate_conv_output_size(
+        kernel_0,
+        options.stride[0],
+        options.padding[0],
+        options.dilation[0],
+        in_height,
+    );
+    let out_1 = calculate_conv_output_size(
+        kernel_1,
+        options.stride[1],
+        options.padding[1],
+        options.dilation[1],
+        in_width,
+    );
+
+    let shape_out = Shape::new([batch_size, out_channels, out_0, out_1]);
+
+    let output = empty_device::<R, F, 4>(input.client.clone(), input.device.clone(), shape_out);
+
+    let bias = match bias {
+        Some(bias) => {
+            let shape = Shape::from([bias.shape.dims[0], 1, 1, 1]);
+            reshape(bias, shape)
+        }
+        None => {
+            let shape = Shape::from([output.shape.dims[0], 1, 1, 1]);
+            zeros_device(input.client.clone(), input.device.clone(), shape)
+        }
+    };
+
+    let (input_scale, input_offset) =
+        channel_params::<R, F>(input_scales, input_offsets, &input.device);
+    let (weight_scale, weight_offset) =
+        channel_params::<R, F>(weight_scales, weight_offsets, &weight.device);
+
+    let num_elems_output = output.shape.num_elements();
+    let cube_count = calculate_cube_count_elemwise(num_elems_output, SUBCUBE_DIM_APPROX);
+
+    quantized_conv2d_kernel::launch::<F::FloatPrimitive, R>(
+        input.client,
+        cube_count,
+        CubeDim::default(),
+        TensorArg::new(&input.handle, &input.strides, &input.shape.dims),
+        TensorArg::new(&weight.handle, &weight.strides, &weight.shape.dims),
+        TensorArg::new(&bias.handle, &bias.strides, &bias.shape.dims),
+        TensorArg::new(
+            &input_scale.handle,
+            &input_scale.strides,
+            &input_scale.shape.dims,
+        ),
+        TensorArg::new(
+            &input_offset.handle,
+            &input_offset.strides,
+            &input_offset.shape.dims,
+        ),
+        TensorArg::new(
+            &weight_scale.handle,
+            &weight_scale.strides,
+            &weight_scale.shape.dims,
+        ),
+        TensorArg::new(
+            &weight_offset.handle,
+            &weight_offset.strides,
+            &weight_offset.shape.dims,
+        ),
+        TensorArg::new(&output.handle, &output.strides, &output.shape.dims),
+        QuantizedConv2dArgsLaunch::new(
+            ScalarArg::new(options.stride[0] as u32),
+            ScalarArg::new(options.stride[1] as u32),
+            ScalarArg::new(options.dilation[0] as u32),
+            ScalarArg::new(options.dilation[1] as u32),
+            ScalarArg::new(options.padding[0] as u32),
+            ScalarArg::new(options.padding[1] as u32),
+            ScalarArg::new(options.groups as u32),
+            ScalarArg::new(storage_offset(input_strategy.kind())),
+            ScalarArg::new(storage_offset(weight_strategy.kind())),
+        ),
+    );
+
+    quantize(output, out_strategy)
+}
@@ -0,0 +1,152 @@
+use burn_cube::{calculate_cube_count_elemwise, prelude::*, SUBCUBE_DIM_APPROX};
+use burn_tensor::{QuantizationStrategy, TensorData};
+
+use crate::{
+    kernel::{
+        into_contiguous,
+        matmul::{matmul, shape_out, MatmulStrategy},
+    },
+    ops::{from_data, numeric::empty_device},
+    tensor::JitTensor,
+    FloatElement, JitRuntime,
+};
+
+use super::{dequantize, quantize, storage_offset};
+
+#[derive(CubeLaunch)]
+struct QuantizedMatmulArgs {
+    /// Offset applied to the lhs quantized values when they are stored.
+    lhs_storage_offset: I32,
+    /// Offset applied to the rhs quantized values when they are stored.
+    rhs_storage_offset: I32,
+}
+
+#[cube(launch)]
+fn quantized_matmul_kernel<F: Float>(
+    lhs: &Tensor<UInt>,
+    rhs: &Tensor<UInt>,
+    lhs_scale: &Tensor<F>,
+    lhs_offset: &Tensor<I32>,
+    rhs_scale: &Tensor<F>,
+    rhs_offset: &Tensor<I32>,
+    out: &mut Tensor<F>,
+    args: &QuantizedMatmulArgs,
+) {
+    if ABSOLUTE_POS >= out.len() {
+        return;
+    }
+
+    let rank = out.rank();
+    let n_rows = out.shape(rank - UInt::new(2));
+    let n_cols = out.shape(rank - UInt::new(1));
+    let k = rhs.shape(rank - UInt::new(2));
+
+    let row = ABSOLUTE_POS / n_cols % n_rows;
+    let col = ABSOLUTE_POS % n_cols;
+    let offset_out = ABSOLUTE_POS - row * n_cols - col;
+
+    // Broadcast the batch dimensions
+    let mut offset_lhs = UInt::new(0);
+    let mut offset_rhs = UInt::new(0);
+    for i in range(0u32, rank - UInt::new(2), Comptime::new(false)) {
+        let ogwl = offset_out / out.stride(i);
+
+        offset_lhs += ogwl % lhs.shape(i) * lhs.stride(i);
+        offset_rhs += ogwl % rhs.shape(i) * rhs.stride(i);
+    }
+
+    let lhs_zero = lhs_offset[row % lhs_offset.len()] - args.lhs_storage_offset;
+    let rhs_zero = rhs_offset[col % rhs_offset.len()] - args.rhs_storage_offset;
+
+    // acc = sum((x_q - offset) * (w_q - offset))
+    let mut acc = I32::new(0);
+    for i in range(0u32, k, Comptime::new(false)) {
+        let lhs_value = I32::cast_from(lhs[offset_lhs + row * k + i]) - lhs_zero;
+        let rhs_value = I32::cast_from(rhs[offset_rhs + i * n_cols + col]) - rhs_zero;
+
+        acc += lhs_value * rhs_value;
+    }
+
+    let scale = lhs_scale[row % lhs_scale.len()] * rhs_scale[col % rhs_scale.len()];
+    out[ABSOLUTE_POS] = F::cast_from(acc) * scale;
+}
+
+/// Create the scale and offset of each channel on the device.
+pub(super) fn channel_params<R: JitRuntime, F: FloatElement>(
+    scales: Vec<f32>,
+    offsets: Vec<i32>,
+    device: &R::Device,
+) -> (JitTensor<R, F, 1>, JitTensor<R, i32, 1>) {
+    let num_channels = scales.len();
+    (
+        from_data(TensorData::new(scales, [num_channels]), device),
+        from_data(TensorData::new(offsets, [num_channels]), device),
+    )
+}
+
+/// Matrix multiplication of two quantized tensors.
+///
+/// The products of the quantized values are accumulated in `int32` and rescaled before being
+/// requantized with the output strategy. Only per-tensor (or per-row) lhs and per-tensor (or
+/// per-column) rhs strategies can be accumulated this way, the other strategies are dequantized
+/// to perform the multiplication in floating point precision.
+pub fn quantized_matmul<R: JitRuntime, F: FloatElement, const D: usize>(
+    lhs: JitTensor<R, u32, D>,
+    lhs_strategy: &QuantizationStrategy,
+    rhs: JitTensor<R, u32, D>,
+    rhs_strategy: &QuantizationStrategy,
+    out_strategy: &QuantizationStrategy,
+) -> JitTensor<R, u32, D> {
+    let (Some((lhs_scales, lhs_offsets)), Some((rhs_scales, rhs_offsets))) = (
+        lhs_strategy.channel_params(D - 2),
+        rhs_strategy.channel_params(D - 1),
+    ) else {
+        let lhs = dequantize::<R, F, D>(lhs, lhs_strategy);
+        let rhs = dequantize::<R, F, D>(rhs, rhs_strategy);
+        let out = matmul(lhs, rhs, MatmulStrategy::default());
+        return quantize(out, out_strategy);
+    };
+
+    lhs.assert_is_on_same_device(&rhs);
+    let lhs = into_contiguous(lhs);
+    let rhs = into_contiguous(rhs);
+
+    let output = empty_device::<R, F, D>(
+        lhs.client.clone(),
+        lhs.device.clone(),
+        shape_out(&lhs, &rhs),
+    );
+
+    let (lhs_scale, lhs_offset) = channel_params::<R, F>(lhs_scales, lhs_offsets, &lhs.device);
+    let (rhs_scale, rhs_offset) = channel_params::<R, F>(rhs_scales, rhs_offsets, &rhs.device);
+
+    let num_elems = output.shape.num_elements();
+    let cube_count = calculate_cube_count_elemwise(num_elems, SUBCUBE_DIM_APPROX);
+
+    quantized_matmul_kernel::launch::<F::FloatPrimitive, R>(
+        lhs.client,
+        cube_count,
+        CubeDim::default(),
+        TensorArg::new(&lhs.handle, &lhs.strides, &lhs.shape.dims),
+        TensorArg::new(&rhs.handle, &rhs.strides, &rhs.shape.dims),
+        TensorArg::new(&lhs_scale.handle, &lhs_scale.strides, &lhs_scale.shape.dims),
+        TensorArg::new(
+            &lhs_offset.handle,
+            &lhs_offset.strides,
+            &lhs_offset.shape.dims,
+        ),
+        TensorArg::new(&rhs_scale.handle, &rhs_scale.strides, &rhs_scale.shape.dims),
+        TensorArg::new(
+            &rhs_offset.handle,
+            &rhs_offset.strides,
+            &rhs_offset.shape.dims,
+        ),
+        TensorArg::new(&output.handle, &output.strides, &output.shape.dims),
+        QuantizedMatmulArgsLaunch::new(
+            ScalarArg::new(storage_offset(lhs_strategy.kind())),
+            ScalarArg::new(storage_offset(rhs_strategy.kind())),
+        ),
+    );
+
+    quantize(output, out_strategy)
+}
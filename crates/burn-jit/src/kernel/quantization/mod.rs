@@ -0,0 +1,7 @@
+mod base;
+mod conv2d;
+mod matmul;
+
+pub use base::*;
+pub use conv2d::*;
+pub use matmul::*;
@@ -0,0 +1,18 @@
+use crate::{kernel, FloatElement, IntElement, JitBackend, JitRuntime};
+use burn_tensor::ops::{FftOps, FloatTensor};
+
+impl<R, F, I> FftOps<Self> for JitBackend<R, F, I>
+where
+    R: JitRuntime,
+    F: FloatElement,
+    I: IntElement,
+{
+    fn fft<const D: usize>(
+        re: FloatTensor<Self, D>,
+        im: FloatTensor<Self, D>,
+        dim: usize,
+        inverse: bool,
+    ) -> (FloatTensor<Self, D>, FloatTensor<Self, D>) {
+        kernel::fft(re, im, dim, inverse)
+    }
+}
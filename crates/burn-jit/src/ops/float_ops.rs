@@ -142,6 +142,20 @@ where
         numeric::remainder_scalar(lhs, rhs)
     }
 
+    fn float_fmod<const D: usize>(
+        lhs: FloatTensor<Self, D>,
+        rhs: FloatTensor<Self, D>,
+    ) -> FloatTensor<Self, D> {
+        numeric::fmod(lhs, rhs)
+    }
+
+    fn float_fmod_scalar<const D: usize>(
+        lhs: FloatTensor<Self, D>,
+        rhs: FloatElem<Self>,
+    ) -> FloatTensor<Self, D> {
+        numeric::fmod_scalar(lhs, rhs)
+    }
+
     fn float_matmul<const D: usize>(
         lhs: FloatTensor<Self, D>,
         rhs: FloatTensor<Self, D>,
@@ -431,6 +445,74 @@ where
         })
     }
 
+    fn float_floor<const D: usize>(tensor: FloatTensor<Self, D>) -> FloatTensor<Self, D> {
+        unary_op!(float(tensor) => |context, tensor| {
+            #[cube]
+            fn execute<C: Float>(input: C) -> C {
+                C::floor(input)
+            }
+            execute::__expand::<C>(context, tensor)
+        })
+    }
+
+    fn float_ceil<const D: usize>(tensor: FloatTensor<Self, D>) -> FloatTensor<Self, D> {
+        unary_op!(float(tensor) => |context, tensor| {
+            #[cube]
+            fn execute<C: Float>(input: C) -> C {
+                C::ceil(input)
+            }
+            execute::__expand::<C>(context, tensor)
+        })
+    }
+
+    fn float_round<const D: usize>(tensor: FloatTensor<Self, D>) -> FloatTensor<Self, D> {
+        unary_op!(float(tensor) => |context, tensor| {
+            #[cube]
+            fn execute<C: Float>(input: C) -> C {
+                C::round(input)
+            }
+            execute::__expand::<C>(context, tensor)
+        })
+    }
+
+    fn float_trunc<const D: usize>(tensor: FloatTensor<Self, D>) -> FloatTensor<Self, D> {
+        unary_op!(float(tensor) => |context, tensor| {
+            #[cube]
+            fn execute<C: Float>(input: C) -> C {
+                C::trunc(input)
+            }
+            execute::__expand::<C>(context, tensor)
+        })
+    }
+
+    fn float_cumsum<const D: usize>(
+        tensor: FloatTensor<Self, D>,
+        dim: usize,
+    ) -> FloatTensor<Self, D> {
+        kernel::cumsum(tensor, dim)
+    }
+
+    fn float_cumprod<const D: usize>(
+        tensor: FloatTensor<Self, D>,
+        dim: usize,
+    ) -> FloatTensor<Self, D> {
+        kernel::cumprod(tensor, dim)
+    }
+
+    fn float_cummax<const D: usize>(
+        tensor: FloatTensor<Self, D>,
+        dim: usize,
+    ) -> FloatTensor<Self, D> {
+        kernel::cummax(tensor, dim)
+    }
+
+    fn float_cummin<const D: usize>(
+        tensor: FloatTensor<Self, D>,
+        dim: usize,
+    ) -> FloatTensor<Self, D> {
+        kernel::cummin(tensor, dim)
+    }
+
     fn float_argmax<const D: usize>(
         tensor: FloatTensor<Self, D>,
         dim: usize,
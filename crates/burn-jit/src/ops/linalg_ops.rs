@@ -0,0 +1,10 @@
+use crate::{FloatElement, IntElement, JitBackend, JitRuntime};
+use burn_tensor::ops::LinalgOps;
+
+impl<R, F, I> LinalgOps<Self> for JitBackend<R, F, I>
+where
+    R: JitRuntime,
+    F: FloatElement,
+    I: IntElement,
+{
+}
@@ -2,7 +2,8 @@ use crate::kernel::{launch_unary, unary_op, UnaryOp};
 use crate::{binary, JitRuntime};
 use crate::{element::JitElement, tensor::JitTensor};
 use burn_compute::client::ComputeClient;
-use burn_cube::ir::{BinaryOperator, Elem, Operator, Scope, Variable};
+use burn_cube::cpa;
+use burn_cube::ir::{BinaryOperator, Elem, Operator, Scope, UnaryOperator, Variable};
 use burn_cube::{calculate_cube_count_elemwise, prelude::*, SUBCUBE_DIM_APPROX};
 use burn_cube::{tensor_vectorization_factor, Runtime};
 use burn_tensor::{ElementConversion, Shape};
@@ -239,6 +240,141 @@ pub fn remainder_scalar<R: JitRuntime, E: JitElement, const D: usize>(
     )
 }
 
+pub fn fmod<R: JitRuntime, E: JitElement, const D: usize>(
+    lhs: JitTensor<R, E, D>,
+    rhs: JitTensor<R, E, D>,
+) -> JitTensor<R, E, D> {
+    binary!(
+        operation: |scope: &mut Scope, elem: Elem, position: Variable| {
+            let lhs = scope.read_array(0, elem, position);
+            let rhs = scope.read_array(1, elem, position);
+            let product = scope.create_local(elem);
+
+            cpa!(scope, product = lhs / rhs);
+            cpa!(scope, product = trunc(product));
+            cpa!(scope, product = product * rhs);
+
+            Operator::Sub(BinaryOperator {
+                lhs,
+                rhs: product,
+                out: scope.create_local(elem),
+            })
+        },
+        runtime: R,
+        input: lhs; rhs,
+        elem: E
+    )
+}
+
+pub fn fmod_scalar<R: JitRuntime, E: JitElement, const D: usize>(
+    lhs: JitTensor<R, E, D>,
+    rhs: E,
+) -> JitTensor<R, E, D> {
+    let shape = lhs.shape.clone();
+    let device = lhs.device.clone();
+
+    let rhs_tensor = full::<R, E, D>(shape, &device, rhs);
+
+    fmod(lhs, rhs_tensor)
+}
+
+pub fn floor_div<R: JitRuntime, E: JitElement, const D: usize>(
+    lhs: JitTensor<R, E, D>,
+    rhs: JitTensor<R, E, D>,
+) -> JitTensor<R, E, D> {
+    binary!(
+        operation: |scope: &mut Scope, elem: Elem, position: Variable| {
+            let lhs = scope.read_array(0, elem, position);
+            let rhs = scope.read_array(1, elem, position);
+            let zero = scope.create_with_value(0, elem);
+            let one = scope.create_with_value(1, elem);
+            let quotient = scope.create_local(elem);
+            let remainder = scope.create_local(elem);
+            let remainder_negative = scope.create_local(Elem::Bool);
+            let rhs_negative = scope.create_local(Elem::Bool);
+            let sign_differs = scope.create_local(Elem::Bool);
+            let remainder_nonzero = scope.create_local(Elem::Bool);
+            let round_down = scope.create_local(Elem::Bool);
+
+            // The division rounds towards zero, so the quotient is off by one when the remainder
+            // is not zero and its sign differs from the divisor.
+            cpa!(scope, quotient = lhs / rhs);
+            cpa!(scope, remainder = quotient * rhs);
+            cpa!(scope, remainder = lhs - remainder);
+            cpa!(scope, remainder_negative = remainder < zero);
+            cpa!(scope, rhs_negative = rhs < zero);
+            cpa!(scope, sign_differs = remainder_negative != rhs_negative);
+            cpa!(scope, remainder_nonzero = remainder != zero);
+            cpa!(scope, round_down = remainder_nonzero && sign_differs);
+            cpa!(scope, if(round_down).then(|scope| {
+                cpa!(scope, quotient = quotient - one);
+            }));
+
+            Operator::Assign(UnaryOperator {
+                input: quotient,
+                out: scope.create_local(elem),
+            })
+        },
+        runtime: R,
+        input: lhs; rhs,
+        elem: E
+    )
+}
+
+pub fn floor_div_scalar<R: JitRuntime, E: JitElement, const D: usize>(
+    lhs: JitTensor<R, E, D>,
+    rhs: E,
+) -> JitTensor<R, E, D> {
+    let shape = lhs.shape.clone();
+    let device = lhs.device.clone();
+
+    let rhs_tensor = full::<R, E, D>(shape, &device, rhs);
+
+    floor_div(lhs, rhs_tensor)
+}
+
+pub fn remainder<R: JitRuntime, E: JitElement, const D: usize>(
+    lhs: JitTensor<R, E, D>,
+    rhs: JitTensor<R, E, D>,
+) -> JitTensor<R, E, D> {
+    binary!(
+        operation: |scope: &mut Scope, elem: Elem, position: Variable| {
+            let lhs = scope.read_array(0, elem, position);
+            let rhs = scope.read_array(1, elem, position);
+            let zero = scope.create_with_value(0, elem);
+            let remainder = scope.create_local(elem);
+            let remainder_negative = scope.create_local(Elem::Bool);
+            let rhs_negative = scope.create_local(Elem::Bool);
+            let sign_differs = scope.create_local(Elem::Bool);
+            let remainder_nonzero = scope.create_local(Elem::Bool);
+            let add_divisor = scope.create_local(Elem::Bool);
+
+            // The remainder of the division rounding towards zero has the sign of the dividend,
+            // so the divisor is added when the remainder is not zero and its sign differs from the
+            // divisor.
+            cpa!(scope, remainder = lhs / rhs);
+            cpa!(scope, remainder = remainder * rhs);
+            cpa!(scope, remainder = lhs - remainder);
+            cpa!(scope, remainder_negative = remainder < zero);
+            cpa!(scope, rhs_negative = rhs < zero);
+            cpa!(scope, sign_differs = remainder_negative != rhs_negative);
+            cpa!(scope, remainder_nonzero = remainder != zero);
+            cpa!(scope, add_divisor = remainder_nonzero && sign_differs);
+            cpa!(scope, if(add_divisor).then(|scope| {
+                cpa!(scope, remainder = remainder + rhs);
+            }));
+
+            Operator::Assign(UnaryOperator {
+                input: remainder,
+                out: scope.create_local(elem),
+            })
+        },
+        runtime: R,
+        input: lhs; rhs,
+        elem: E
+    )
+}
+
 pub fn pow<R: JitRuntime, E: JitElement, const D: usize>(
     lhs: JitTensor<R, E, D>,
     rhs: JitTensor<R, E, D>,
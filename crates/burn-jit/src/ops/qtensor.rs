@@ -1,9 +1,12 @@
 use burn_tensor::{
-    ops::{FloatTensor, QTensorOps, QuantizedTensor},
-    Device, QuantizationStrategy, Shape, TensorData,
+    ops::{ConvOptions, FloatTensor, QTensorOps, QuantizedTensor},
+    DType, Device, QuantizationStrategy, Shape, TensorData,
 };
 
-use crate::{FloatElement, IntElement, JitBackend, JitRuntime};
+use crate::{
+    kernel::{self, quantization::storage_offset},
+    FloatElement, IntElement, JitBackend, JitRuntime,
+};
 
 impl<R, F, I> QTensorOps<Self> for JitBackend<R, F, I>
 where
@@ -12,24 +15,38 @@ where
     I: IntElement,
 {
     fn q_from_data<const D: usize>(
-        _data: TensorData,
-        _device: &Device<Self>,
+        data: TensorData,
+        device: &Device<Self>,
     ) -> QuantizedTensor<Self, D> {
-        todo!()
+        match &data.dtype {
+            DType::QFloat(kind) => {
+                let offset = storage_offset(*kind);
+                let values = kind
+                    .unpack(data.as_bytes(), data.num_elements())
+                    .into_iter()
+                    .map(|x| (x - offset) as u32)
+                    .collect();
+                super::from_data(TensorData::new(values, data.shape), device)
+            }
+            _ => panic!(
+                "Invalid dtype (expected DType::QFloat, got {:?})",
+                data.dtype
+            ),
+        }
     }
 
     fn quantize<const D: usize>(
-        _tensor: FloatTensor<Self, D>,
-        _strategy: &QuantizationStrategy,
+        tensor: FloatTensor<Self, D>,
+        strategy: &QuantizationStrategy,
     ) -> QuantizedTensor<Self, D> {
-        unimplemented!()
+        kernel::quantization::quantize(tensor, strategy)
     }
 
     fn dequantize<const D: usize>(
-        _tensor: QuantizedTensor<Self, D>,
-        _strategy: &QuantizationStrategy,
+        tensor: QuantizedTensor<Self, D>,
+        strategy: &QuantizationStrategy,
     ) -> FloatTensor<Self, D> {
-        unimplemented!()
+        kernel::quantization::dequantize(tensor, strategy)
     }
 
     fn q_shape<const D: usize>(tensor: &QuantizedTensor<Self, D>) -> Shape<D> {
@@ -48,9 +65,51 @@ where
     }
 
     async fn q_into_data<const D: usize>(
-        _tensor: QuantizedTensor<Self, D>,
-        _strategy: QuantizationStrategy,
+        tensor: QuantizedTensor<Self, D>,
+        strategy: QuantizationStrategy,
     ) -> TensorData {
-        unimplemented!()
+        let data = super::into_data(tensor).await;
+        let offset = storage_offset(strategy.kind());
+        let values = data
+            .iter::<u32>()
+            .map(|x| x as i32 + offset)
+            .collect::<Vec<_>>();
+        TensorData::quantized(strategy.pack(&values), data.shape, strategy)
+    }
+
+    fn q_matmul<const D: usize>(
+        lhs: QuantizedTensor<Self, D>,
+        lhs_strategy: &QuantizationStrategy,
+        rhs: QuantizedTensor<Self, D>,
+        rhs_strategy: &QuantizationStrategy,
+        out_strategy: &QuantizationStrategy,
+    ) -> QuantizedTensor<Self, D> {
+        kernel::quantization::quantized_matmul::<R, F, D>(
+            lhs,
+            lhs_strategy,
+            rhs,
+            rhs_strategy,
+            out_strategy,
+        )
+    }
+
+    fn q_conv2d(
+        x: QuantizedTensor<Self, 4>,
+        x_strategy: &QuantizationStrategy,
+        weight: QuantizedTensor<Self, 4>,
+        weight_strategy: &QuantizationStrategy,
+        bias: Option<FloatTensor<Self, 1>>,
+        options: ConvOptions<2>,
+        out_strategy: &QuantizationStrategy,
+    ) -> QuantizedTensor<Self, 4> {
+        kernel::quantization::quantized_conv2d(
+            x,
+            x_strategy,
+            weight,
+            weight_strategy,
+            bias,
+            options,
+            out_strategy,
+        )
     }
 }
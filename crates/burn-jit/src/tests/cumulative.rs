@@ -0,0 +1,71 @@
+#[burn_tensor_testgen::testgen(cumulative)]
+mod tests {
+    use super::*;
+    use burn_tensor::{Distribution, Tensor};
+
+    #[test]
+    fn cumsum_should_match_reference() {
+        let input = Tensor::<TestBackend, 3>::random(
+            [3, 37, 5],
+            Distribution::Default,
+            &Default::default(),
+        );
+        let input_ref =
+            Tensor::<ReferenceBackend, 3>::from_data(input.to_data(), &Default::default());
+
+        for dim in 0..3 {
+            let output = input.clone().cumsum(dim);
+
+            output
+                .into_data()
+                .assert_approx_eq(&input_ref.clone().cumsum(dim).into_data(), 2);
+        }
+    }
+
+    #[test]
+    fn cumprod_should_match_reference() {
+        let input = Tensor::<TestBackend, 2>::random(
+            [6, 19],
+            Distribution::Uniform(0.5, 1.5),
+            &Default::default(),
+        );
+        let input_ref =
+            Tensor::<ReferenceBackend, 2>::from_data(input.to_data(), &Default::default());
+
+        let output = input.cumprod(1);
+
+        output
+            .into_data()
+            .assert_approx_eq(&input_ref.cumprod(1).into_data(), 3);
+    }
+
+    #[test]
+    fn cummax_cummin_should_match_reference_when_not_contiguous() {
+        let input =
+            Tensor::<TestBackend, 2>::random([33, 7], Distribution::Default, &Default::default())
+                .swap_dims(0, 1);
+        let input_ref =
+            Tensor::<ReferenceBackend, 2>::from_data(input.to_data(), &Default::default());
+
+        let output_max = input.clone().cummax(1);
+        let output_min = input.cummin(1);
+
+        output_max
+            .into_data()
+            .assert_approx_eq(&input_ref.clone().cummax(1).into_data(), 3);
+        output_min
+            .into_data()
+            .assert_approx_eq(&input_ref.cummin(1).into_data(), 3);
+    }
+
+    #[test]
+    fn cumsum_should_not_modify_input() {
+        let input =
+            Tensor::<TestBackend, 2>::random([4, 9], Distribution::Default, &Default::default());
+        let data = input.to_data();
+
+        let _output = input.clone().cumsum(1);
+
+        input.into_data().assert_eq(&data, true);
+    }
+}
@@ -0,0 +1,72 @@
+#[burn_tensor_testgen::testgen(fft)]
+mod tests {
+    use super::*;
+    use burn_tensor::{signal, Distribution, Tensor};
+
+    #[test]
+    fn fft_should_match_reference() {
+        let re = Tensor::<TestBackend, 3>::random(
+            [3, 37, 8],
+            Distribution::Default,
+            &Default::default(),
+        );
+        let im = Tensor::<TestBackend, 3>::random(
+            [3, 37, 8],
+            Distribution::Default,
+            &Default::default(),
+        );
+        let re_ref = Tensor::<ReferenceBackend, 3>::from_data(re.to_data(), &Default::default());
+        let im_ref = Tensor::<ReferenceBackend, 3>::from_data(im.to_data(), &Default::default());
+
+        for dim in 0..3 {
+            let (output_re, output_im) = signal::fft(re.clone(), im.clone(), dim);
+            let (expected_re, expected_im) = signal::fft(re_ref.clone(), im_ref.clone(), dim);
+
+            output_re
+                .into_data()
+                .assert_approx_eq(&expected_re.into_data(), 2);
+            output_im
+                .into_data()
+                .assert_approx_eq(&expected_im.into_data(), 2);
+        }
+    }
+
+    #[test]
+    fn fft_should_match_reference_when_large() {
+        // The size is prime, and the products of two of its indices overflow 32 bits
+        let n = 65537;
+        let re = Tensor::<TestBackend, 1>::random([n], Distribution::Default, &Default::default())
+            .div_scalar(n as f32);
+        let im = Tensor::<TestBackend, 1>::random([n], Distribution::Default, &Default::default())
+            .div_scalar(n as f32);
+        let re_ref = Tensor::<ReferenceBackend, 1>::from_data(re.to_data(), &Default::default());
+        let im_ref = Tensor::<ReferenceBackend, 1>::from_data(im.to_data(), &Default::default());
+
+        let (output_re, output_im) = signal::fft(re, im, 0);
+        let (expected_re, expected_im) = signal::fft(re_ref, im_ref, 0);
+
+        output_re
+            .into_data()
+            .assert_approx_eq(&expected_re.into_data(), 4);
+        output_im
+            .into_data()
+            .assert_approx_eq(&expected_im.into_data(), 4);
+    }
+
+    #[test]
+    fn irfft_should_match_reference_when_not_contiguous() {
+        let signal =
+            Tensor::<TestBackend, 2>::random([33, 7], Distribution::Default, &Default::default())
+                .swap_dims(0, 1);
+        let signal_ref =
+            Tensor::<ReferenceBackend, 2>::from_data(signal.to_data(), &Default::default());
+
+        let (re, im) = signal::rfft(signal, 1);
+        let (re_ref, im_ref) = signal::rfft(signal_ref, 1);
+        let output = signal::irfft(re, im, 1, 33);
+
+        output
+            .into_data()
+            .assert_approx_eq(&signal::irfft(re_ref, im_ref, 1, 33).into_data(), 3);
+    }
+}
@@ -9,6 +9,8 @@ mod conv2d;
 mod conv3d;
 mod conv_transpose2d;
 mod conv_transpose3d;
+mod cumulative;
+mod fft;
 mod gather;
 mod mask_fill;
 mod mask_where;
@@ -17,6 +19,7 @@ pub mod matmul_cube;
 mod max_pool2d;
 mod max_pool2d_backward;
 mod normal;
+mod quantization;
 mod reduce;
 mod repeat;
 mod scatter;
@@ -68,11 +71,14 @@ macro_rules! testgen_all {
 
                 burn_jit::testgen_bernoulli!();
                 burn_jit::testgen_normal!();
+                burn_jit::testgen_quantization!();
                 burn_jit::testgen_uniform!();
 
                 burn_jit::testgen_cast!();
                 burn_jit::testgen_cat!();
                 burn_jit::testgen_clamp!();
+                burn_jit::testgen_cumulative!();
+                burn_jit::testgen_fft!();
                 burn_jit::testgen_unary!();
                 burn_jit::testgen_matmul!();
                 burn_jit::testgen_matmul_cube!();
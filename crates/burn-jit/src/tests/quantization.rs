@@ -0,0 +1,192 @@
+#[burn_tensor_testgen::testgen(quantization)]
+mod tests {
+    use super::*;
+    use burn_tensor::{
+        module::quantized_conv2d, ops::ConvOptions, AffineQuantization, Distribution,
+        PerChannelQuantization, PerGroupQuantization, Quantization, QuantizationStrategy,
+        SymmetricQuantization, Tensor,
+    };
+
+    #[test]
+    fn per_tensor_affine_quantization_should_match_reference() {
+        let strategy =
+            QuantizationStrategy::PerTensorAffineInt8(AffineQuantization::new(-1.0, 1.0));
+
+        quantization_should_match_reference([8, 16, 32], strategy);
+    }
+
+    #[test]
+    fn per_tensor_symmetric_quantization_should_match_reference() {
+        let strategy =
+            QuantizationStrategy::PerTensorSymmetricInt8(SymmetricQuantization::new(-1.0, 1.0));
+
+        quantization_should_match_reference([8, 16, 32], strategy);
+    }
+
+    #[test]
+    fn per_channel_affine_quantization_should_match_reference() {
+        let channels = (0..16)
+            .map(|i| AffineQuantization::new(-1.0, 0.1 * i as f32 + 0.5))
+            .collect();
+        let strategy =
+            QuantizationStrategy::PerChannelAffineInt8(PerChannelQuantization::new(1, channels));
+
+        quantization_should_match_reference([8, 16, 32], strategy);
+    }
+
+    #[test]
+    fn per_channel_symmetric_quantization_should_match_reference() {
+        let channels = (0..32)
+            .map(|i| SymmetricQuantization::new(-1.0, 0.1 * i as f32))
+            .collect();
+        let strategy =
+            QuantizationStrategy::PerChannelSymmetricInt8(PerChannelQuantization::new(2, channels));
+
+        quantization_should_match_reference([8, 16, 32], strategy);
+    }
+
+    #[test]
+    fn per_tensor_affine_uint8_quantization_should_match_reference() {
+        let strategy =
+            QuantizationStrategy::PerTensorAffineUInt8(AffineQuantization::new(-1.0, 1.0));
+
+        quantization_should_match_reference([8, 16, 32], strategy);
+    }
+
+    #[test]
+    fn per_group_affine_int4_quantization_should_match_reference() {
+        let groups = (0..128)
+            .map(|i| AffineQuantization::new(-1.0, 0.01 * i as f32 + 0.5))
+            .collect();
+        let strategy =
+            QuantizationStrategy::PerGroupAffineInt4(PerGroupQuantization::new(32, groups));
+
+        quantization_should_match_reference([8, 16, 32], strategy);
+    }
+
+    #[test]
+    fn per_group_symmetric_int4_quantization_should_match_reference() {
+        let groups = (0..128)
+            .map(|i| SymmetricQuantization::new(-1.0, 0.01 * i as f32))
+            .collect();
+        let strategy =
+            QuantizationStrategy::PerGroupSymmetricInt4(PerGroupQuantization::new(32, groups));
+
+        quantization_should_match_reference([8, 16, 32], strategy);
+    }
+
+    #[test]
+    fn per_group_affine_uint4_quantization_should_match_reference() {
+        let groups = (0..128)
+            .map(|i| AffineQuantization::new(-1.0, 0.01 * i as f32 + 0.5))
+            .collect();
+        let strategy =
+            QuantizationStrategy::PerGroupAffineUInt4(PerGroupQuantization::new(32, groups));
+
+        quantization_should_match_reference([8, 16, 32], strategy);
+    }
+
+    fn quantization_should_match_reference(shape: [usize; 3], strategy: QuantizationStrategy) {
+        let tensor = Tensor::<TestBackend, 3>::random(
+            shape,
+            Distribution::Uniform(-1.0, 1.0),
+            &Default::default(),
+        );
+        let tensor_ref =
+            Tensor::<ReferenceBackend, 3>::from_data(tensor.to_data(), &Default::default());
+
+        let output = tensor.quantize(strategy.clone());
+        let output_ref = tensor_ref.quantize(strategy);
+
+        output
+            .clone()
+            .dequantize()
+            .into_data()
+            .assert_approx_eq(&output_ref.clone().dequantize().into_data(), 2);
+
+        // Quantized data should round-trip through the backend.
+        let data = output.into_data();
+        let output = Tensor::<TestBackend, 3>::from_data(data.clone(), &Default::default());
+        output.into_data().assert_eq(&data, true);
+    }
+
+    #[test]
+    fn quantized_matmul_should_match_reference() {
+        let lhs_strategy =
+            QuantizationStrategy::PerTensorAffineInt8(AffineQuantization::new(-1.0, 1.0));
+        let channels = (0..24)
+            .map(|i| SymmetricQuantization::new(-1.0, 0.05 * i as f32 + 0.2))
+            .collect();
+        let rhs_strategy =
+            QuantizationStrategy::PerChannelSymmetricInt8(PerChannelQuantization::new(2, channels));
+        let out_strategy =
+            QuantizationStrategy::PerTensorAffineInt8(AffineQuantization::new(-8.0, 8.0));
+
+        let lhs = random::<3>([4, 16, 32]).quantize(lhs_strategy);
+        let rhs = random::<3>([1, 32, 24]).quantize(rhs_strategy);
+        let lhs_ref = reference(&lhs);
+        let rhs_ref = reference(&rhs);
+
+        let output = lhs.quantized_matmul(rhs, out_strategy.clone());
+        let output_ref = lhs_ref.quantized_matmul(rhs_ref, out_strategy.clone());
+
+        assert_quantized_approx_eq(output, output_ref, &out_strategy);
+    }
+
+    #[test]
+    fn quantized_conv2d_should_match_reference() {
+        let x_strategy =
+            QuantizationStrategy::PerTensorAffineUInt8(AffineQuantization::new(-1.0, 1.0));
+        let channels = (0..6)
+            .map(|i| AffineQuantization::new(-0.1 * i as f32 - 0.3, 0.1 * i as f32 + 0.5))
+            .collect();
+        let weight_strategy =
+            QuantizationStrategy::PerChannelAffineInt8(PerChannelQuantization::new(0, channels));
+        let out_strategy =
+            QuantizationStrategy::PerTensorAffineInt8(AffineQuantization::new(-4.0, 4.0));
+        let options = ConvOptions::new([1, 2], [1, 1], [1, 1], 2);
+
+        let x = random::<4>([2, 4, 9, 8]).quantize(x_strategy);
+        let weight = random::<4>([6, 2, 3, 3]).quantize(weight_strategy);
+        let bias = random::<1>([6]);
+        let x_ref = reference(&x);
+        let weight_ref = reference(&weight);
+        let bias_ref = reference(&bias);
+
+        let output = quantized_conv2d(x, weight, Some(bias), options.clone(), out_strategy.clone());
+        let output_ref = quantized_conv2d(
+            x_ref,
+            weight_ref,
+            Some(bias_ref),
+            options,
+            out_strategy.clone(),
+        );
+
+        assert_quantized_approx_eq(output, output_ref, &out_strategy);
+    }
+
+    fn random<const D: usize>(shape: [usize; D]) -> Tensor<TestBackend, D> {
+        Tensor::random(shape, Distribution::Uniform(-1.0, 1.0), &Default::default())
+    }
+
+    fn reference<const D: usize>(tensor: &Tensor<TestBackend, D>) -> Tensor<ReferenceBackend, D> {
+        Tensor::from_data(tensor.to_data(), &Default::default())
+    }
+
+    fn assert_quantized_approx_eq<const D: usize>(
+        output: Tensor<TestBackend, D>,
+        output_ref: Tensor<ReferenceBackend, D>,
+        strategy: &QuantizationStrategy,
+    ) {
+        let scale = match strategy {
+            QuantizationStrategy::PerTensorAffineInt8(q) => q.scale,
+            _ => unreachable!(),
+        };
+
+        // The accumulation order may differ, so values can land on a neighbouring step.
+        output
+            .dequantize()
+            .into_data()
+            .assert_approx_eq_diff(&output_ref.dequantize().into_data(), scale as f64 * 1.01);
+    }
+}
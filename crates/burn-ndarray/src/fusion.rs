@@ -0,0 +1,145 @@
+use core::any::Any;
+
+use alloc::{boxed::Box, vec, vec::Vec};
+use burn_fusion::{client::MutexFusionClient, FusionBackend, FusionRuntime};
+use burn_tensor::{
+    ops::{BoolTensor, FloatTensor, IntTensor},
+    repr::ReprBackend,
+    DType, Element, Shape,
+};
+use ndarray::{ArcArray, IxDyn};
+
+use crate::{element::FloatNdArrayElement, NdArray, NdArrayDevice, NdArrayTensor};
+
+/// Handle of a tensor of any kind and element type, used by [fusion](burn_fusion::Fusion) to
+/// store the tensors of the [ndarray backend](NdArray).
+#[derive(Debug, Clone)]
+pub enum NdArrayFusionHandle {
+    /// A tensor of 32-bit floats.
+    Float32(ArcArray<f32, IxDyn>),
+    /// A tensor of 64-bit floats.
+    Float64(ArcArray<f64, IxDyn>),
+    /// A tensor of integers.
+    Int(ArcArray<i64, IxDyn>),
+    /// A tensor of booleans.
+    Bool(ArcArray<bool, IxDyn>),
+}
+
+/// The [fusion runtime](FusionRuntime) of the [ndarray backend](NdArray).
+///
+/// No operations are fused, they are executed one by one. This is mostly useful to record the
+/// [operations](burn_tensor::repr::OperationDescription) of a model on the CPU, e.g. with a
+/// [trace](burn_fusion::Trace).
+#[derive(Debug)]
+pub struct NdArrayFusionRuntime;
+
+/// The optimizations of the [ndarray fusion runtime](NdArrayFusionRuntime), which has none.
+pub enum NdArrayOptimization {}
+
+impl burn_fusion::Optimization<NdArrayFusionRuntime> for NdArrayOptimization {
+    fn execute(&mut self, _context: &mut burn_fusion::stream::Context<'_, NdArrayFusionHandle>) {
+        match *self {}
+    }
+
+    fn len(&self) -> usize {
+        match *self {}
+    }
+
+    fn to_state(&self) {
+        match *self {}
+    }
+
+    fn from_state(_device: &NdArrayDevice, _state: ()) -> Self {
+        unreachable!("The ndarray fusion runtime has no optimizations")
+    }
+}
+
+impl FusionRuntime for NdArrayFusionRuntime {
+    type OptimizationState = ();
+    type Optimization = NdArrayOptimization;
+    type FusionHandle = NdArrayFusionHandle;
+    type FusionDevice = NdArrayDevice;
+    type FusionClient = MutexFusionClient<Self>;
+
+    fn optimizations(
+        _device: NdArrayDevice,
+    ) -> Vec<Box<dyn burn_fusion::OptimizationBuilder<Self::Optimization>>> {
+        vec![]
+    }
+}
+
+/// Converts the array to another element type, without copying it if the type is the same.
+fn convert<T, E>(array: ArcArray<T, IxDyn>) -> ArcArray<E, IxDyn>
+where
+    T: Element + 'static,
+    E: Element + 'static,
+{
+    match (&array as &dyn Any).downcast_ref::<ArcArray<E, IxDyn>>() {
+        Some(array) => array.clone(),
+        None => array.mapv(|x| x.elem()).into_shared(),
+    }
+}
+
+fn float_handle<E: FloatNdArrayElement>(array: ArcArray<E, IxDyn>) -> NdArrayFusionHandle {
+    match E::dtype() {
+        DType::F64 => NdArrayFusionHandle::Float64(convert(array)),
+        _ => NdArrayFusionHandle::Float32(convert(array)),
+    }
+}
+
+impl<E: FloatNdArrayElement> ReprBackend for NdArray<E> {
+    type Handle = NdArrayFusionHandle;
+
+    fn float_tensor<const D: usize>(
+        handle: Self::Handle,
+        _shape: Shape<D>,
+    ) -> FloatTensor<Self, D> {
+        let array = match handle {
+            NdArrayFusionHandle::Float32(array) => convert(array),
+            NdArrayFusionHandle::Float64(array) => convert(array),
+            handle => panic!("Expected a float tensor handle, got {handle:?}"),
+        };
+
+        NdArrayTensor::new(array)
+    }
+
+    fn int_tensor<const D: usize>(handle: Self::Handle, _shape: Shape<D>) -> IntTensor<Self, D> {
+        match handle {
+            NdArrayFusionHandle::Int(array) => NdArrayTensor::new(array),
+            handle => panic!("Expected an int tensor handle, got {handle:?}"),
+        }
+    }
+
+    fn bool_tensor<const D: usize>(handle: Self::Handle, _shape: Shape<D>) -> BoolTensor<Self, D> {
+        match handle {
+            NdArrayFusionHandle::Bool(array) => NdArrayTensor::new(array),
+            handle => panic!("Expected a bool tensor handle, got {handle:?}"),
+        }
+    }
+
+    fn float_tensor_handle<const D: usize>(tensor: FloatTensor<Self, D>) -> Self::Handle {
+        float_handle(tensor.array)
+    }
+
+    fn int_tensor_handle<const D: usize>(tensor: IntTensor<Self, D>) -> Self::Handle {
+        NdArrayFusionHandle::Int(tensor.array)
+    }
+
+    fn bool_tensor_handle<const D: usize>(tensor: BoolTensor<Self, D>) -> Self::Handle {
+        NdArrayFusionHandle::Bool(tensor.array)
+    }
+}
+
+impl<E: FloatNdArrayElement> FusionBackend for NdArray<E> {
+    type FusionRuntime = NdArrayFusionRuntime;
+
+    type FullPrecisionBackend = NdArray<f32>;
+
+    fn cast_float<const D: usize>(tensor: FloatTensor<Self, D>, dtype: DType) -> Self::Handle {
+        match dtype {
+            DType::F32 => NdArrayFusionHandle::Float32(convert(tensor.array)),
+            DType::F64 => NdArrayFusionHandle::Float64(convert(tensor.array)),
+            _ => panic!("Casting error: {dtype:?} unsupported."),
+        }
+    }
+}
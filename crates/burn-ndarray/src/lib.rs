@@ -16,6 +16,8 @@ extern crate blas_src;
 mod backend;
 mod bridge;
 mod element;
+#[cfg(feature = "fusion")]
+mod fusion;
 mod ops;
 mod sharing;
 mod tensor;
@@ -23,6 +25,8 @@ mod tensor;
 pub use backend::*;
 pub use bridge::*;
 pub use element::FloatNdArrayElement;
+#[cfg(feature = "fusion")]
+pub use fusion::*;
 pub(crate) use sharing::*;
 pub use tensor::*;
 
@@ -43,3 +47,16 @@ mod tests {
     #[cfg(feature = "std")]
     burn_autodiff::testgen_all!();
 }
+
+#[cfg(all(test, feature = "fusion"))]
+mod fusion_tests {
+    type TestBackend = burn_fusion::Fusion<crate::NdArray<f32>>;
+    type TestTensor<const D: usize> = burn_tensor::Tensor<TestBackend, D>;
+    type TestTensorInt<const D: usize> = burn_tensor::Tensor<TestBackend, D, burn_tensor::Int>;
+    type TestTensorBool<const D: usize> = burn_tensor::Tensor<TestBackend, D, burn_tensor::Bool>;
+
+    use alloc::format;
+    use alloc::vec;
+
+    burn_tensor::testgen_all!();
+}
@@ -499,6 +499,32 @@ where
         arg(tensor, dim, CmpType::Min)
     }
 
+    pub fn cumsum<const D: usize>(tensor: NdArrayTensor<E, D>, dim: usize) -> NdArrayTensor<E, D> {
+        accumulate(tensor, dim, |previous, current| *current += *previous)
+    }
+
+    pub fn cumprod<const D: usize>(tensor: NdArrayTensor<E, D>, dim: usize) -> NdArrayTensor<E, D> {
+        accumulate(tensor, dim, |previous, current| {
+            *current = *current * *previous
+        })
+    }
+
+    pub fn cummax<const D: usize>(tensor: NdArrayTensor<E, D>, dim: usize) -> NdArrayTensor<E, D> {
+        accumulate(tensor, dim, |previous, current| {
+            if previous > current {
+                *current = *previous;
+            }
+        })
+    }
+
+    pub fn cummin<const D: usize>(tensor: NdArrayTensor<E, D>, dim: usize) -> NdArrayTensor<E, D> {
+        accumulate(tensor, dim, |previous, current| {
+            if previous < current {
+                *current = *previous;
+            }
+        })
+    }
+
     pub fn clamp_min<const D: usize>(
         mut tensor: NdArrayTensor<E, D>,
         min: E,
@@ -544,12 +570,17 @@ where
         rhs: NdArrayTensor<OtherE, D>,
         var_name: impl FnMut(&E, &OtherE) -> E,
     ) -> NdArrayTensor<E, D> {
-        NdArrayTensor::new(
-            Zip::from(lhs.array.view())
-                .and(rhs.array.view())
-                .map_collect(var_name)
-                .into_shared(),
-        )
+        let shape = lhs
+            .array
+            .shape()
+            .iter()
+            .zip(rhs.array.shape())
+            .map(|(&lhs, &rhs)| if lhs == 1 { rhs } else { lhs })
+            .collect::<Vec<_>>();
+        let lhs = lhs.array.broadcast(shape.clone()).unwrap();
+        let rhs = rhs.array.broadcast(shape).unwrap();
+
+        NdArrayTensor::new(Zip::from(lhs).and(rhs).map_collect(var_name).into_shared())
     }
 
     pub(crate) fn elementwise_op_scalar<const D: usize>(
@@ -582,6 +613,18 @@ where
     }
 }
 
+/// Accumulate the elements along a dimension, each element being updated from the previous one.
+fn accumulate<E: NdArrayElement, const D: usize>(
+    tensor: NdArrayTensor<E, D>,
+    dim: usize,
+    update: impl FnMut(&E, &mut E),
+) -> NdArrayTensor<E, D> {
+    let mut array = tensor.array.into_owned();
+    array.accumulate_axis_inplace(Axis(dim), update);
+
+    NdArrayTensor::new(array.into_shared())
+}
+
 enum CmpType {
     Min,
     Max,
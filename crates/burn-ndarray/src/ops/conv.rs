@@ -11,14 +11,14 @@ use ndarray::{
 };
 
 use crate::{
-    element::FloatNdArrayElement,
+    element::{FloatNdArrayElement, NdArrayElement},
     ops::padding::{apply_padding_4d, apply_padding_5d},
     sharing::UnsafeSharedRef,
     tensor::NdArrayTensor,
 };
 
 #[inline(always)]
-fn conv2d_mad_inner<E: FloatNdArrayElement>(
+fn conv2d_mad_inner<E: NdArrayElement>(
     mut output: ArrayViewMut2<E>,
     x: ArrayView2<E>,
     k: E,
@@ -98,7 +98,7 @@ fn conv3d_mad_inner<E: FloatNdArrayElement>(
     }
 }
 
-pub(crate) fn conv2d<E: FloatNdArrayElement>(
+pub(crate) fn conv2d<E: NdArrayElement>(
     x: NdArrayTensor<E, 4>,
     weight: NdArrayTensor<E, 4>,
     bias: Option<NdArrayTensor<E, 1>>,
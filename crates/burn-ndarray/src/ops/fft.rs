@@ -0,0 +1,225 @@
+use alloc::vec::Vec;
+use burn_tensor::{ops::FftOps, ElementConversion};
+use core::f64::consts::PI;
+use core::ops::{Add, Mul, Sub};
+use libm::{cos, sin};
+use ndarray::{Axis, Zip};
+
+use crate::{element::FloatNdArrayElement, tensor::NdArrayTensor, NdArray};
+
+impl<E: FloatNdArrayElement> FftOps<Self> for NdArray<E> {
+    fn fft<const D: usize>(
+        re: NdArrayTensor<E, D>,
+        im: NdArrayTensor<E, D>,
+        dim: usize,
+        inverse: bool,
+    ) -> (NdArrayTensor<E, D>, NdArrayTensor<E, D>) {
+        let mut re = re.array.mapv(|value| value.elem::<f64>());
+        let mut im = im.array.mapv(|value| value.elem::<f64>());
+        let plan = Plan::new(re.shape()[dim], inverse);
+        let mut buffer = Vec::new();
+
+        Zip::from(re.lanes_mut(Axis(dim)))
+            .and(im.lanes_mut(Axis(dim)))
+            .for_each(|mut re, mut im| {
+                buffer.clear();
+                buffer.extend(
+                    re.iter()
+                        .zip(im.iter())
+                        .map(|(&re, &im)| Complex { re, im }),
+                );
+
+                plan.transform(&mut buffer);
+
+                for (value, (re, im)) in buffer.iter().zip(re.iter_mut().zip(im.iter_mut())) {
+                    *re = value.re;
+                    *im = value.im;
+                }
+            });
+
+        (
+            NdArrayTensor::new(re.mapv(|value| value.elem::<E>()).into_shared()),
+            NdArrayTensor::new(im.mapv(|value| value.elem::<E>()).into_shared()),
+        )
+    }
+}
+
+#[derive(Clone, Copy, Default)]
+struct Complex {
+    re: f64,
+    im: f64,
+}
+
+impl Complex {
+    fn from_angle(angle: f64) -> Self {
+        Self {
+            re: cos(angle),
+            im: sin(angle),
+        }
+    }
+
+    fn conj(self) -> Self {
+        Self {
+            re: self.re,
+            im: -self.im,
+        }
+    }
+}
+
+impl Add for Complex {
+    type Output = Self;
+
+    fn add(self, rhs: Self) -> Self {
+        Self {
+            re: self.re + rhs.re,
+            im: self.im + rhs.im,
+        }
+    }
+}
+
+impl Sub for Complex {
+    type Output = Self;
+
+    fn sub(self, rhs: Self) -> Self {
+        Self {
+            re: self.re - rhs.re,
+            im: self.im - rhs.im,
+        }
+    }
+}
+
+impl Mul for Complex {
+    type Output = Self;
+
+    fn mul(self, rhs: Self) -> Self {
+        Self {
+            re: self.re * rhs.re - self.im * rhs.im,
+            im: self.re * rhs.im + self.im * rhs.re,
+        }
+    }
+}
+
+/// The factors of the transform of a given size, computed once for all the lanes of the tensor.
+enum Plan {
+    /// Cooley-Tukey transform, for sizes that are powers of two.
+    Radix2 { twiddles: Vec<Complex> },
+    /// Bluestein's algorithm, which computes the transform of any size as a convolution with a
+    /// chirp, itself computed with power of two transforms.
+    Bluestein {
+        chirp: Vec<Complex>,
+        filter: Vec<Complex>,
+        twiddles: Vec<Complex>,
+    },
+}
+
+impl Plan {
+    fn new(n: usize, inverse: bool) -> Self {
+        let sign = if inverse { 1.0 } else { -1.0 };
+
+        if n.is_power_of_two() || n == 0 {
+            return Self::Radix2 {
+                twiddles: twiddles(n, sign),
+            };
+        }
+
+        // jk = (j^2 + k^2 - (k - j)^2) / 2, so the transform is the convolution of the signal
+        // multiplied by the chirp with the conjugate of the chirp
+        let m = (2 * n - 1).next_power_of_two();
+        let twiddles = twiddles(m, -1.0);
+        let chirp = (0..n)
+            .map(|k| Complex::from_angle(sign * PI * ((k * k) % (2 * n)) as f64 / n as f64))
+            .collect::<Vec<_>>();
+
+        let mut filter = alloc::vec![Complex::default(); m];
+        for k in 0..n {
+            filter[k] = chirp[k].conj();
+            if k > 0 {
+                filter[m - k] = chirp[k].conj();
+            }
+        }
+        radix2(&mut filter, &twiddles);
+
+        Self::Bluestein {
+            chirp,
+            filter,
+            twiddles,
+        }
+    }
+
+    fn transform(&self, buffer: &mut Vec<Complex>) {
+        match self {
+            Self::Radix2 { twiddles } => radix2(buffer, twiddles),
+            Self::Bluestein {
+                chirp,
+                filter,
+                twiddles,
+            } => {
+                let n = buffer.len();
+                let m = filter.len();
+
+                for (value, chirp) in buffer.iter_mut().zip(chirp) {
+                    *value = *value * *chirp;
+                }
+                buffer.resize(m, Complex::default());
+
+                // The inverse transform of the product is the conjugate of the transform of the
+                // conjugate
+                radix2(buffer, twiddles);
+                for (value, filter) in buffer.iter_mut().zip(filter) {
+                    *value = (*value * *filter).conj();
+                }
+                radix2(buffer, twiddles);
+
+                buffer.truncate(n);
+                for (value, chirp) in buffer.iter_mut().zip(chirp) {
+                    let value_conj = value.conj();
+                    *value = Complex {
+                        re: value_conj.re / m as f64,
+                        im: value_conj.im / m as f64,
+                    } * *chirp;
+                }
+            }
+        }
+    }
+}
+
+/// The factors `exp(sign 2 pi i k / n)` of the first half of a power of two transform.
+fn twiddles(n: usize, sign: f64) -> Vec<Complex> {
+    (0..n / 2)
+        .map(|k| Complex::from_angle(sign * 2.0 * PI * k as f64 / n as f64))
+        .collect()
+}
+
+/// Iterative radix-2 Cooley-Tukey transform, in place.
+fn radix2(buffer: &mut [Complex], twiddles: &[Complex]) {
+    let n = buffer.len();
+    if n <= 1 {
+        return;
+    }
+
+    let bits = n.trailing_zeros();
+    for i in 0..n {
+        let j = i.reverse_bits() >> (usize::BITS - bits);
+        if i < j {
+            buffer.swap(i, j);
+        }
+    }
+
+    let mut size = 2;
+    while size <= n {
+        let half = size / 2;
+        let step = n / size;
+
+        for start in (0..n).step_by(size) {
+            for k in 0..half {
+                let t = twiddles[k * step] * buffer[start + k + half];
+                let u = buffer[start + k];
+
+                buffer[start + k] = u + t;
+                buffer[start + k + half] = u - t;
+            }
+        }
+
+        size *= 2;
+    }
+}
@@ -241,6 +241,27 @@ impl<E: FloatNdArrayElement> IntTensorOps<Self> for NdArray<E> {
         NdArrayMathOps::div_scalar(lhs, rhs)
     }
 
+    fn int_floor_div<const D: usize>(
+        lhs: NdArrayTensor<i64, D>,
+        rhs: NdArrayTensor<i64, D>,
+    ) -> NdArrayTensor<i64, D> {
+        NdArrayMathOps::elementwise_op(lhs, rhs, |&a, &b| floor_div(a, b))
+    }
+
+    fn int_floor_div_scalar<const D: usize>(
+        lhs: NdArrayTensor<i64, D>,
+        rhs: i64,
+    ) -> NdArrayTensor<i64, D> {
+        NdArrayMathOps::elementwise_op_scalar(lhs, |a| floor_div(a, rhs))
+    }
+
+    fn int_remainder<const D: usize>(
+        lhs: NdArrayTensor<i64, D>,
+        rhs: NdArrayTensor<i64, D>,
+    ) -> NdArrayTensor<i64, D> {
+        NdArrayMathOps::elementwise_op(lhs, rhs, |&a, &b| ((a % b) + b) % b)
+    }
+
     fn int_remainder_scalar<const D: usize>(
         lhs: NdArrayTensor<i64, D>,
         rhs: i64,
@@ -340,6 +361,34 @@ impl<E: FloatNdArrayElement> IntTensorOps<Self> for NdArray<E> {
     ) -> NdArrayTensor<i64, D> {
         NdArrayMathOps::select_assign(tensor, dim, indices, value)
     }
+    fn int_cumsum<const D: usize>(
+        tensor: NdArrayTensor<i64, D>,
+        dim: usize,
+    ) -> NdArrayTensor<i64, D> {
+        NdArrayMathOps::cumsum(tensor, dim)
+    }
+
+    fn int_cumprod<const D: usize>(
+        tensor: NdArrayTensor<i64, D>,
+        dim: usize,
+    ) -> NdArrayTensor<i64, D> {
+        NdArrayMathOps::cumprod(tensor, dim)
+    }
+
+    fn int_cummax<const D: usize>(
+        tensor: NdArrayTensor<i64, D>,
+        dim: usize,
+    ) -> NdArrayTensor<i64, D> {
+        NdArrayMathOps::cummax(tensor, dim)
+    }
+
+    fn int_cummin<const D: usize>(
+        tensor: NdArrayTensor<i64, D>,
+        dim: usize,
+    ) -> NdArrayTensor<i64, D> {
+        NdArrayMathOps::cummin(tensor, dim)
+    }
+
     fn int_argmax<const D: usize>(
         tensor: NdArrayTensor<i64, D>,
         dim: usize,
@@ -470,3 +519,14 @@ impl<E: FloatNdArrayElement> IntTensorOps<Self> for NdArray<E> {
         NdArrayOps::expand(tensor, shape)
     }
 }
+
+/// Integer division rounding towards negative infinity instead of zero.
+fn floor_div(lhs: i64, rhs: i64) -> i64 {
+    let quotient = lhs / rhs;
+
+    if lhs % rhs != 0 && (lhs < 0) != (rhs < 0) {
+        quotient - 1
+    } else {
+        quotient
+    }
+}
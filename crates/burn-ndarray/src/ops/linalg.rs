@@ -0,0 +1,413 @@
+use alloc::vec::Vec;
+use burn_tensor::{ops::LinalgOps, ElementConversion};
+use libm::{fabs, log, sqrt};
+use ndarray::{Array2, ArrayView2, Axis, IxDyn};
+
+use crate::{element::FloatNdArrayElement, tensor::NdArrayTensor, NdArray};
+
+/// Maximum number of sweeps of the Jacobi algorithms, which usually converge in less than ten.
+const MAX_SWEEPS: usize = 64;
+
+impl<E: FloatNdArrayElement> LinalgOps<Self> for NdArray<E> {
+    fn linalg_inv<const D: usize>(tensor: NdArrayTensor<E, D>) -> NdArrayTensor<E, D> {
+        let n = tensor.shape().dims[D - 1];
+        let [inverse] = map_matrices(tensor, [(n, n)], |matrix| {
+            let (lu, permutation, _) = lu(matrix);
+            [lu_solve(&lu, &permutation, Array2::eye(n).view())]
+        });
+
+        inverse
+    }
+
+    fn linalg_det<const D: usize>(tensor: NdArrayTensor<E, D>) -> NdArrayTensor<E, D> {
+        let [det] = map_matrices(tensor, [(1, 1)], |matrix| {
+            let (lu, _, sign) = lu(matrix);
+            let det = lu.diag().iter().product::<f64>() * sign;
+
+            [Array2::from_elem((1, 1), det)]
+        });
+
+        det
+    }
+
+    fn linalg_slogdet<const D: usize>(
+        tensor: NdArrayTensor<E, D>,
+    ) -> (NdArrayTensor<E, D>, NdArrayTensor<E, D>) {
+        let [sign, logabsdet] = map_matrices(tensor, [(1, 1), (1, 1)], |matrix| {
+            let (lu, _, mut sign) = lu(matrix);
+            let mut logabsdet = 0.0;
+
+            for &value in lu.diag() {
+                sign *= signum(value);
+                logabsdet += log(fabs(value));
+            }
+
+            [
+                Array2::from_elem((1, 1), sign),
+                Array2::from_elem((1, 1), logabsdet),
+            ]
+        });
+
+        (sign, logabsdet)
+    }
+
+    fn linalg_solve<const D: usize>(
+        lhs: NdArrayTensor<E, D>,
+        rhs: NdArrayTensor<E, D>,
+    ) -> NdArrayTensor<E, D> {
+        let shape = rhs.shape();
+        let (n, k) = (shape.dims[D - 2], shape.dims[D - 1]);
+        let rhs = matrices(rhs);
+        let mut batch = 0;
+
+        let [solution] = map_matrices(lhs, [(n, k)], |matrix| {
+            let (lu, permutation, _) = lu(matrix);
+            let solution = lu_solve(&lu, &permutation, rhs.index_axis(Axis(0), batch));
+            batch += 1;
+
+            [solution]
+        });
+
+        solution
+    }
+
+    fn linalg_cholesky<const D: usize>(tensor: NdArrayTensor<E, D>) -> NdArrayTensor<E, D> {
+        let n = tensor.shape().dims[D - 1];
+        let [lower] = map_matrices(tensor, [(n, n)], |matrix| [cholesky(matrix)]);
+
+        lower
+    }
+
+    fn linalg_qr<const D: usize>(
+        tensor: NdArrayTensor<E, D>,
+    ) -> (NdArrayTensor<E, D>, NdArrayTensor<E, D>) {
+        let shape = tensor.shape();
+        let (m, n) = (shape.dims[D - 2], shape.dims[D - 1]);
+        let k = m.min(n);
+        let [q, r] = map_matrices(tensor, [(m, k), (k, n)], qr);
+
+        (q, r)
+    }
+
+    fn linalg_svd<const D: usize>(
+        tensor: NdArrayTensor<E, D>,
+    ) -> (
+        NdArrayTensor<E, D>,
+        NdArrayTensor<E, D>,
+        NdArrayTensor<E, D>,
+    ) {
+        let shape = tensor.shape();
+        let (m, n) = (shape.dims[D - 2], shape.dims[D - 1]);
+        let k = m.min(n);
+        let [u, s, vh] = map_matrices(tensor, [(m, k), (1, k), (k, n)], svd);
+
+        (u, s, vh)
+    }
+
+    fn linalg_eigh<const D: usize>(
+        tensor: NdArrayTensor<E, D>,
+    ) -> (NdArrayTensor<E, D>, NdArrayTensor<E, D>) {
+        let n = tensor.shape().dims[D - 1];
+        let [values, vectors] = map_matrices(tensor, [(1, n), (n, n)], eigh);
+
+        (values, vectors)
+    }
+}
+
+/// Apply the function to each matrix of the tensor in double precision, the outputs having the
+/// batch dimensions of the tensor and the given matrix shapes.
+fn map_matrices<E: FloatNdArrayElement, const D: usize, const N: usize>(
+    tensor: NdArrayTensor<E, D>,
+    shapes: [(usize, usize); N],
+    mut func: impl FnMut(Array2<f64>) -> [Array2<f64>; N],
+) -> [NdArrayTensor<E, D>; N] {
+    let dims = tensor.shape().dims;
+    let matrices = matrices(tensor);
+    let mut outputs: [Vec<E>; N] = core::array::from_fn(|_| Vec::new());
+
+    for matrix in matrices.outer_iter() {
+        for (output, values) in outputs.iter_mut().zip(func(matrix.to_owned())) {
+            output.extend(values.iter().map(|value| value.elem::<E>()));
+        }
+    }
+
+    let mut outputs = outputs.into_iter();
+
+    shapes.map(|(rows, cols)| {
+        let mut dims = dims;
+        dims[D - 2] = rows;
+        dims[D - 1] = cols;
+
+        let array = ndarray::Array::from_shape_vec(IxDyn(&dims), outputs.next().unwrap()).unwrap();
+        NdArrayTensor::new(array.into_shared())
+    })
+}
+
+/// The matrices of the tensor in double precision, with a shape of `[batch, rows, cols]`.
+fn matrices<E: FloatNdArrayElement, const D: usize>(
+    tensor: NdArrayTensor<E, D>,
+) -> ndarray::Array3<f64> {
+    let dims = tensor.shape().dims;
+    let (rows, cols) = (dims[D - 2], dims[D - 1]);
+    let batch = dims[..D - 2].iter().product();
+    let values = tensor
+        .array
+        .iter()
+        .map(|value| value.elem::<f64>())
+        .collect();
+
+    ndarray::Array3::from_shape_vec((batch, rows, cols), values).unwrap()
+}
+
+/// LU decomposition with partial pivoting `P A = L U`, stored in a single matrix with the unit
+/// diagonal of `L` left implicit.
+///
+/// Returns the decomposition, the row of `A` at each row of `P A` and the sign of the
+/// permutation.
+fn lu(mut a: Array2<f64>) -> (Array2<f64>, Vec<usize>, f64) {
+    let n = a.nrows();
+    let mut permutation: Vec<usize> = (0..n).collect();
+    let mut sign = 1.0;
+
+    for k in 0..n {
+        let pivot = (k..n)
+            .max_by(|&i, &j| fabs(a[[i, k]]).total_cmp(&fabs(a[[j, k]])))
+            .unwrap();
+
+        if pivot != k {
+            for j in 0..n {
+                a.swap([k, j], [pivot, j]);
+            }
+            permutation.swap(k, pivot);
+            sign = -sign;
+        }
+
+        let diagonal = a[[k, k]];
+        if diagonal == 0.0 {
+            continue;
+        }
+
+        for i in k + 1..n {
+            let factor = a[[i, k]] / diagonal;
+            a[[i, k]] = factor;
+
+            for j in k + 1..n {
+                a[[i, j]] -= factor * a[[k, j]];
+            }
+        }
+    }
+
+    (a, permutation, sign)
+}
+
+/// Solve `A X = B` from the LU decomposition of `A`.
+fn lu_solve(lu: &Array2<f64>, permutation: &[usize], b: ArrayView2<f64>) -> Array2<f64> {
+    let n = lu.nrows();
+    let mut x = b.select(Axis(0), permutation);
+
+    for j in 0..x.ncols() {
+        for i in 0..n {
+            let value = (0..i).fold(x[[i, j]], |value, k| value - lu[[i, k]] * x[[k, j]]);
+            x[[i, j]] = value;
+        }
+
+        for i in (0..n).rev() {
+            let value = (i + 1..n).fold(x[[i, j]], |value, k| value - lu[[i, k]] * x[[k, j]]);
+            x[[i, j]] = value / lu[[i, i]];
+        }
+    }
+
+    x
+}
+
+fn cholesky(a: Array2<f64>) -> Array2<f64> {
+    let n = a.nrows();
+    let mut l = Array2::zeros((n, n));
+
+    for j in 0..n {
+        let diagonal = (0..j).fold(a[[j, j]], |value, k| value - l[[j, k]] * l[[j, k]]);
+        let diagonal = sqrt(diagonal);
+        l[[j, j]] = diagonal;
+
+        for i in j + 1..n {
+            let value = (0..j).fold(a[[i, j]], |value, k| value - l[[i, k]] * l[[j, k]]);
+            l[[i, j]] = value / diagonal;
+        }
+    }
+
+    l
+}
+
+/// Reduced QR decomposition with Householder reflections.
+fn qr(mut r: Array2<f64>) -> [Array2<f64>; 2] {
+    let (m, n) = r.dim();
+    let k = m.min(n);
+    let mut q = Array2::eye(m);
+
+    for j in 0..k.min(m.saturating_sub(1)) {
+        let mut v: Vec<f64> = (j..m).map(|i| r[[i, j]]).collect();
+        let norm = sqrt(v.iter().map(|x| x * x).sum());
+        // The sign avoids the cancellation
+        v[0] += if v[0] < 0.0 { -norm } else { norm };
+
+        let norm_v = sqrt(v.iter().map(|x| x * x).sum());
+        if norm_v == 0.0 {
+            continue;
+        }
+        v.iter_mut().for_each(|x| *x /= norm_v);
+
+        // R = H R and Q = Q H, with H = I - 2 v v^T
+        for col in 0..n {
+            let dot: f64 = (j..m).map(|i| v[i - j] * r[[i, col]]).sum();
+            (j..m).for_each(|i| r[[i, col]] -= 2.0 * v[i - j] * dot);
+        }
+        for row in 0..m {
+            let dot: f64 = (j..m).map(|i| q[[row, i]] * v[i - j]).sum();
+            (j..m).for_each(|i| q[[row, i]] -= 2.0 * dot * v[i - j]);
+        }
+    }
+
+    let q = q.slice_move(ndarray::s![.., ..k]);
+    let mut r = r.slice_move(ndarray::s![..k, ..]);
+    for i in 0..k {
+        for j in 0..i.min(n) {
+            r[[i, j]] = 0.0;
+        }
+    }
+
+    [q, r]
+}
+
+/// Reduced singular value decomposition with the one-sided Jacobi algorithm, which rotates the
+/// columns in pairs until they are orthogonal.
+fn svd(a: Array2<f64>) -> [Array2<f64>; 3] {
+    let (m, n) = a.dim();
+
+    if m < n {
+        // A^T = U S V^T, so A = V S U^T
+        let [u, s, vh] = svd(a.reversed_axes().as_standard_layout().to_owned());
+        return [vh.reversed_axes(), s, u.reversed_axes()];
+    }
+
+    let mut u = a;
+    let mut v = Array2::eye(n);
+
+    for _ in 0..MAX_SWEEPS {
+        let mut converged = true;
+
+        for p in 0..n {
+            for q in p + 1..n {
+                let column_p = u.column(p);
+                let column_q = u.column(q);
+                let alpha = column_p.dot(&column_p);
+                let beta = column_q.dot(&column_q);
+                let gamma = column_p.dot(&column_q);
+
+                if fabs(gamma) <= f64::EPSILON * sqrt(alpha * beta) {
+                    continue;
+                }
+                converged = false;
+
+                let (cos, sin) = jacobi_rotation(alpha, beta, gamma);
+                rotate_columns(&mut u, p, q, cos, sin);
+                rotate_columns(&mut v, p, q, cos, sin);
+            }
+        }
+
+        if converged {
+            break;
+        }
+    }
+
+    let singular_values: Vec<f64> = u.columns().into_iter().map(|c| sqrt(c.dot(&c))).collect();
+    let mut order: Vec<usize> = (0..n).collect();
+    order.sort_by(|&i, &j| singular_values[j].total_cmp(&singular_values[i]));
+
+    let mut u = u.select(Axis(1), &order);
+    let v = v.select(Axis(1), &order);
+    let s = Array2::from_shape_fn((1, n), |(_, j)| singular_values[order[j]]);
+
+    for (mut column, &value) in u.columns_mut().into_iter().zip(s.iter()) {
+        if value != 0.0 {
+            column /= value;
+        }
+    }
+
+    [u, s, v.reversed_axes()]
+}
+
+/// Eigendecomposition of a symmetric matrix with the cyclic Jacobi algorithm, each rotation
+/// zeroing an off-diagonal element.
+fn eigh(mut a: Array2<f64>) -> [Array2<f64>; 2] {
+    let n = a.nrows();
+    let mut vectors = Array2::eye(n);
+
+    for _ in 0..MAX_SWEEPS {
+        let off_diagonal: f64 = (0..n)
+            .flat_map(|p| (p + 1..n).map(move |q| (p, q)))
+            .map(|(p, q)| a[[p, q]] * a[[p, q]])
+            .sum();
+        let diagonal: f64 = a.diag().iter().map(|x| x * x).sum();
+
+        if off_diagonal <= f64::EPSILON * f64::EPSILON * diagonal {
+            break;
+        }
+
+        for p in 0..n {
+            for q in p + 1..n {
+                if a[[p, q]] == 0.0 {
+                    continue;
+                }
+
+                let (cos, sin) = jacobi_rotation(a[[p, p]], a[[q, q]], a[[p, q]]);
+                rotate_columns(&mut a, p, q, cos, sin);
+                let mut rows = a.view_mut().reversed_axes();
+                rotate_columns(&mut rows, p, q, cos, sin);
+                rotate_columns(&mut vectors, p, q, cos, sin);
+            }
+        }
+    }
+
+    let mut order: Vec<usize> = (0..n).collect();
+    order.sort_by(|&i, &j| a[[i, i]].total_cmp(&a[[j, j]]));
+
+    let values = Array2::from_shape_fn((1, n), |(_, j)| a[[order[j], order[j]]]);
+    let vectors = vectors.select(Axis(1), &order);
+
+    [values, vectors]
+}
+
+/// The rotation zeroing the off-diagonal element of the symmetric matrix `[[app, apq], [apq, aqq]]`.
+fn jacobi_rotation(app: f64, aqq: f64, apq: f64) -> (f64, f64) {
+    let theta = (aqq - app) / (2.0 * apq);
+    // The smallest root of t^2 + 2 t theta - 1 = 0
+    let sign = if theta < 0.0 { -1.0 } else { 1.0 };
+    let tan = sign / (fabs(theta) + sqrt(theta * theta + 1.0));
+    let cos = 1.0 / sqrt(tan * tan + 1.0);
+
+    (cos, tan * cos)
+}
+
+fn rotate_columns<S: ndarray::DataMut<Elem = f64>>(
+    matrix: &mut ndarray::ArrayBase<S, ndarray::Ix2>,
+    p: usize,
+    q: usize,
+    cos: f64,
+    sin: f64,
+) {
+    for i in 0..matrix.nrows() {
+        let (x, y) = (matrix[[i, p]], matrix[[i, q]]);
+        matrix[[i, p]] = cos * x - sin * y;
+        matrix[[i, q]] = sin * x + cos * y;
+    }
+}
+
+fn signum(value: f64) -> f64 {
+    if value > 0.0 {
+        1.0
+    } else if value < 0.0 {
+        -1.0
+    } else {
+        0.0
+    }
+}
@@ -1,7 +1,9 @@
 mod activations;
 mod base;
 mod bool_tensor;
+mod fft;
 mod int_tensor;
+mod linalg;
 mod module;
 mod qtensor;
 mod tensor;
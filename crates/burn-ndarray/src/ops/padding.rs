@@ -1,8 +1,14 @@
-use crate::{element::FloatNdArrayElement, tensor::NdArrayTensor, NdArray};
+use crate::{
+    element::{FloatNdArrayElement, NdArrayElement},
+    tensor::NdArrayTensor,
+    NdArray,
+};
 use burn_tensor::ops::FloatTensorOps;
+
+use super::NdArrayOps;
 use ndarray::{Array4, Array5};
 
-pub(crate) fn apply_padding_4d<E: FloatNdArrayElement>(
+pub(crate) fn apply_padding_4d<E: NdArrayElement>(
     x: NdArrayTensor<E, 4>,
     padding: [usize; 2],
     elem: E,
@@ -18,7 +24,7 @@ pub(crate) fn apply_padding_4d<E: FloatNdArrayElement>(
     );
     let mut x_new = NdArrayTensor::new(x_new.into_shared().into_dyn());
 
-    x_new = NdArray::float_slice_assign(
+    x_new = NdArrayOps::slice_assign(
         x_new,
         [
             0..batch_size,
@@ -1,11 +1,12 @@
+use alloc::vec::Vec;
 use burn_tensor::{
-    ops::{FloatTensor, QTensorOps, QuantizedTensor},
-    DType, Quantization, QuantizationStrategy, Shape, TensorData,
+    ops::{ConvOptions, FloatTensor, FloatTensorOps, ModuleOps, QTensorOps, QuantizedTensor},
+    DType, QuantizationStrategy, Shape, TensorData,
 };
 
 use crate::{element::NdArrayElement, FloatNdArrayElement, NdArray, NdArrayDevice, NdArrayTensor};
 
-use super::NdArrayOps;
+use super::{conv::conv2d, matmul::matmul, NdArrayOps};
 
 fn into_data<E: NdArrayElement, const D: usize>(tensor: NdArrayTensor<E, D>) -> TensorData {
     let shape = tensor.shape();
@@ -13,22 +14,79 @@ fn into_data<E: NdArrayElement, const D: usize>(tensor: NdArrayTensor<E, D>) ->
     TensorData::new(values, shape)
 }
 
+/// Returns the quantized values from their `i8` storage.
+fn from_storage<const D: usize>(
+    tensor: NdArrayTensor<i8, D>,
+    strategy: &QuantizationStrategy,
+) -> Vec<i32> {
+    let unsigned = strategy.value_range().0 >= 0;
+    tensor
+        .array
+        .into_iter()
+        .map(|x| match unsigned {
+            true => x as u8 as i32,
+            false => x as i32,
+        })
+        .collect()
+}
+
+/// Returns the quantized values minus the zero-point offset of their channel, where each channel
+/// has `channel_size` contiguous values.
+fn centered<const D: usize>(
+    tensor: NdArrayTensor<i8, D>,
+    strategy: &QuantizationStrategy,
+    offsets: &[i32],
+    channel_size: usize,
+) -> NdArrayTensor<i32, D> {
+    let shape = tensor.shape();
+    let values = from_storage(tensor, strategy)
+        .into_iter()
+        .enumerate()
+        .map(|(i, x)| x - offsets[i / channel_size % offsets.len()])
+        .collect();
+    NdArrayTensor::from_data(TensorData::new(values, shape))
+}
+
+/// Quantize the `int32` accumulated values scaled by the channel scales of the input and the
+/// weight (or lhs and rhs), each with `channel_size` contiguous values.
+fn requantize<const D: usize>(
+    acc: NdArrayTensor<i32, D>,
+    scale: impl Fn(usize) -> f32,
+    bias: Option<Vec<f32>>,
+    out_strategy: &QuantizationStrategy,
+) -> NdArrayTensor<i8, D> {
+    let shape = acc.shape();
+    let values = acc
+        .array
+        .iter()
+        .enumerate()
+        .map(|(i, x)| *x as f32 * scale(i))
+        .collect::<Vec<_>>();
+    let values = match bias {
+        Some(bias) => values
+            .into_iter()
+            .enumerate()
+            .map(|(i, x)| x + bias[i])
+            .collect(),
+        None => values,
+    };
+    let values = out_strategy.quantize(&values, &shape.dims);
+    let values = values.into_iter().map(|x| x as i8).collect();
+    NdArrayTensor::from_data(TensorData::new(values, shape))
+}
+
 impl<E: FloatNdArrayElement> QTensorOps<Self> for NdArray<E> {
     fn q_from_data<const D: usize>(
         data: TensorData,
         _device: &NdArrayDevice,
     ) -> QuantizedTensor<Self, D> {
-        match data.dtype {
-            DType::QFloat(strategy) => match strategy {
-                QuantizationStrategy::PerTensorAffineInt8(_) => {
-                    let data = data.convert::<i8>();
-                    NdArrayTensor::<i8, D>::from_data(data)
-                }
-                QuantizationStrategy::PerTensorSymmetricInt8(_) => {
-                    let data = data.convert::<i8>();
-                    NdArrayTensor::<i8, D>::from_data(data)
-                }
-            },
+        match &data.dtype {
+            DType::QFloat(strategy) => {
+                let values = strategy.unpack(data.as_bytes(), data.num_elements());
+                // Unsigned values are stored with the same bit pattern
+                let values = values.into_iter().map(|x| x as i8).collect();
+                NdArrayTensor::<i8, D>::from_data(TensorData::new(values, data.shape))
+            }
             _ => panic!(
                 "Invalid dtype (expected DType::QFloat, got {:?})",
                 data.dtype
@@ -40,22 +98,20 @@ impl<E: FloatNdArrayElement> QTensorOps<Self> for NdArray<E> {
         tensor: FloatTensor<Self, D>,
         strategy: &QuantizationStrategy,
     ) -> QuantizedTensor<Self, D> {
-        let data = into_data(tensor).with_quantization(*strategy);
-        NdArrayTensor::<i8, D>::from_data(data)
+        let data = into_data(tensor);
+        let values = strategy.quantize(data.as_slice().unwrap(), &data.shape);
+        let values = values.into_iter().map(|x| x as i8).collect();
+        NdArrayTensor::<i8, D>::from_data(TensorData::new(values, data.shape))
     }
 
     fn dequantize<const D: usize>(
         tensor: QuantizedTensor<Self, D>,
         strategy: &QuantizationStrategy,
     ) -> FloatTensor<Self, D> {
-        let data = into_data(tensor);
-        let values = match strategy {
-            QuantizationStrategy::PerTensorAffineInt8(s) => s.dequantize(data.as_slice().unwrap()),
-            QuantizationStrategy::PerTensorSymmetricInt8(s) => {
-                s.dequantize(data.as_slice().unwrap())
-            }
-        };
-        NdArrayTensor::<E, D>::from_data(TensorData::new(values, data.shape))
+        let shape = tensor.shape().dims.to_vec();
+        let values = from_storage(tensor, strategy);
+        let values = strategy.dequantize(&values, &shape);
+        NdArrayTensor::<E, D>::from_data(TensorData::new(values, shape))
     }
 
     fn q_shape<const D: usize>(tensor: &QuantizedTensor<Self, D>) -> Shape<D> {
@@ -78,7 +134,255 @@ impl<E: FloatNdArrayElement> QTensorOps<Self> for NdArray<E> {
         strategy: QuantizationStrategy,
     ) -> TensorData {
         let shape = tensor.shape();
-        let values = tensor.array.into_iter().collect();
-        TensorData::quantized(values, shape, strategy)
+        let values = from_storage(tensor, &strategy);
+        TensorData::quantized(strategy.pack(&values), shape, strategy)
+    }
+
+    fn q_matmul<const D: usize>(
+        lhs: QuantizedTensor<Self, D>,
+        lhs_strategy: &QuantizationStrategy,
+        rhs: QuantizedTensor<Self, D>,
+        rhs_strategy: &QuantizationStrategy,
+        out_strategy: &QuantizationStrategy,
+    ) -> QuantizedTensor<Self, D> {
+        // The lhs parameters must be constant along the rows and the rhs parameters along the
+        // columns to be factored out of the accumulation.
+        let (Some((lhs_scales, lhs_offsets)), Some((rhs_scales, rhs_offsets))) = (
+            lhs_strategy.channel_params(D - 2),
+            rhs_strategy.channel_params(D - 1),
+        ) else {
+            let lhs = Self::dequantize(lhs, lhs_strategy);
+            let rhs = Self::dequantize(rhs, rhs_strategy);
+            return Self::quantize(Self::float_matmul(lhs, rhs), out_strategy);
+        };
+
+        let [m, k] = [lhs.shape().dims[D - 2], lhs.shape().dims[D - 1]];
+        let n = rhs.shape().dims[D - 1];
+        let lhs = centered(lhs, lhs_strategy, &lhs_offsets, k);
+        let rhs = centered(rhs, rhs_strategy, &rhs_offsets, 1);
+
+        let acc = matmul(lhs, rhs);
+        let scale = |i: usize| {
+            lhs_scales[i / n % m % lhs_scales.len()] * rhs_scales[i % n % rhs_scales.len()]
+        };
+
+        requantize(acc, scale, None, out_strategy)
+    }
+
+    fn q_conv2d(
+        x: QuantizedTensor<Self, 4>,
+        x_strategy: &QuantizationStrategy,
+        weight: QuantizedTensor<Self, 4>,
+        weight_strategy: &QuantizationStrategy,
+        bias: Option<FloatTensor<Self, 1>>,
+        options: ConvOptions<2>,
+        out_strategy: &QuantizationStrategy,
+    ) -> QuantizedTensor<Self, 4> {
+        // The input parameters must be constant and the weight parameters must be constant for
+        // each output channel to be factored out of the accumulation.
+        let (Some((x_scales, x_offsets)), Some((weight_scales, weight_offsets))) = (
+            x_strategy
+                .channel_params(0)
+                .filter(|(scales, _)| scales.len() == 1),
+            weight_strategy.channel_params(0),
+        ) else {
+            let x = Self::dequantize(x, x_strategy);
+            let weight = Self::dequantize(weight, weight_strategy);
+            return Self::quantize(Self::conv2d(x, weight, bias, options), out_strategy);
+        };
+
+        let [out_channels, in_channels, kernel_height, kernel_width] = weight.shape().dims;
+        let x = centered(x, x_strategy, &x_offsets, 1);
+        let weight = centered(
+            weight,
+            weight_strategy,
+            &weight_offsets,
+            in_channels * kernel_height * kernel_width,
+        );
+
+        let acc = conv2d(x, weight, None, options);
+        let [_, _, out_height, out_width] = acc.shape().dims;
+        let channel = |i: usize| i / (out_height * out_width) % out_channels;
+        let scale = |i: usize| x_scales[0] * weight_scales[channel(i) % weight_scales.len()];
+        let bias = bias.map(|bias| {
+            let bias = into_data(bias).convert::<f32>();
+            let bias = bias.as_slice::<f32>().unwrap();
+            (0..acc.array.len()).map(|i| bias[channel(i)]).collect()
+        });
+
+        requantize(acc, scale, bias, out_strategy)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use alloc::vec;
+    use burn_common::rand::get_seeded_rng;
+    use burn_tensor::{
+        activation::relu,
+        module::{conv2d, quantized_conv2d, quantized_linear},
+        AffineQuantization, Distribution, PerChannelQuantization, Quantization,
+        SymmetricQuantization, Tensor,
+    };
+
+    type TestBackend = NdArray<f32>;
+
+    fn random<const D: usize>(shape: [usize; D]) -> Tensor<TestBackend, D> {
+        let data = TensorData::random::<f32, _, _>(
+            shape,
+            Distribution::Uniform(-1.0, 1.0),
+            &mut get_seeded_rng(),
+        );
+        Tensor::from_data(data, &Default::default())
+    }
+
+    /// Per-tensor affine strategy that covers the range of the tensor values.
+    fn per_tensor_affine<const D: usize>(tensor: &Tensor<TestBackend, D>) -> QuantizationStrategy {
+        let min = tensor.clone().min().into_scalar();
+        let max = tensor.clone().max().into_scalar();
+        QuantizationStrategy::PerTensorAffineInt8(AffineQuantization::new(min, max))
+    }
+
+    /// Asserts the quantized outputs differ by at most one quantization step.
+    fn assert_within_one_step<const D: usize>(
+        output: Tensor<TestBackend, D>,
+        reference: Tensor<TestBackend, D>,
+        strategy: &QuantizationStrategy,
+    ) {
+        let QuantizationStrategy::PerTensorAffineInt8(q) = strategy else {
+            unreachable!()
+        };
+        output
+            .dequantize()
+            .into_data()
+            .assert_approx_eq_diff(&reference.dequantize().into_data(), q.scale as f64 * 1.01);
+    }
+
+    #[test]
+    fn q_matmul_should_match_dequantized_reference() {
+        let lhs = random([2, 3, 8]);
+        let rhs = random([1, 8, 5]);
+        let reference = lhs.clone().matmul(rhs.clone());
+        let out_strategy = per_tensor_affine(&reference);
+
+        let lhs_strategy = per_tensor_affine(&lhs);
+        let rhs_strategy = QuantizationStrategy::PerChannelSymmetricInt8(
+            PerChannelQuantization::new(2, vec![SymmetricQuantization::new(-1.0, 1.0); 5]),
+        );
+        let lhs = lhs.quantize(lhs_strategy);
+        let rhs = rhs.quantize(rhs_strategy);
+
+        let output = lhs
+            .clone()
+            .quantized_matmul(rhs.clone(), out_strategy.clone());
+        let reference = lhs
+            .dequantize()
+            .matmul(rhs.dequantize())
+            .quantize(out_strategy.clone());
+
+        assert_within_one_step(output, reference, &out_strategy);
+    }
+
+    #[test]
+    fn q_matmul_uint8_should_match_dequantized_reference() {
+        let lhs = random([4, 6]);
+        let rhs = random([6, 3]);
+        let reference = lhs.clone().matmul(rhs.clone());
+        let out_strategy = per_tensor_affine(&reference);
+
+        let lhs_strategy =
+            QuantizationStrategy::PerTensorAffineUInt8(AffineQuantization::new(-1.0, 1.0));
+        let rhs_strategy =
+            QuantizationStrategy::PerTensorAffineUInt8(AffineQuantization::new(-1.0, 1.0));
+        let lhs = lhs.quantize(lhs_strategy);
+        let rhs = rhs.quantize(rhs_strategy);
+
+        let output = lhs
+            .clone()
+            .quantized_matmul(rhs.clone(), out_strategy.clone());
+        let reference = lhs
+            .dequantize()
+            .matmul(rhs.dequantize())
+            .quantize(out_strategy.clone());
+
+        assert_within_one_step(output, reference, &out_strategy);
+    }
+
+    #[test]
+    fn q_conv2d_should_match_dequantized_reference() {
+        let x = random([2, 4, 6, 6]);
+        let weight = random([6, 2, 3, 3]);
+        let bias = random([6]);
+        let options = ConvOptions::new([1, 2], [1, 1], [1, 1], 2);
+        let reference = conv2d(
+            x.clone(),
+            weight.clone(),
+            Some(bias.clone()),
+            options.clone(),
+        );
+        let out_strategy = per_tensor_affine(&reference);
+
+        let x_strategy = per_tensor_affine(&x);
+        let weight_strategy =
+            QuantizationStrategy::PerChannelAffineInt8(PerChannelQuantization::new(
+                0,
+                (0..6)
+                    .map(|i| AffineQuantization::new(-1.0, 0.5 + 0.1 * i as f32))
+                    .collect(),
+            ));
+        let x = x.quantize(x_strategy);
+        let weight = weight.quantize(weight_strategy);
+
+        let output = quantized_conv2d(
+            x.clone(),
+            weight.clone(),
+            Some(bias.clone()),
+            options.clone(),
+            out_strategy.clone(),
+        );
+        let reference = conv2d(x.dequantize(), weight.dequantize(), Some(bias), options)
+            .quantize(out_strategy.clone());
+
+        assert_within_one_step(output, reference, &out_strategy);
+    }
+
+    #[test]
+    fn q_linear_should_match_dequantized_reference() {
+        let x = random([2, 3, 8]);
+        let weight = random([1, 8, 5]);
+        let bias = random([5]);
+        let reference = x.clone().matmul(weight.clone()) + bias.clone().unsqueeze();
+        let out_strategy = per_tensor_affine(&reference);
+
+        let x_strategy = per_tensor_affine(&x);
+        let weight_strategy = per_tensor_affine(&weight);
+        let x = x.quantize(x_strategy);
+        let weight = weight.quantize(weight_strategy);
+
+        let output = quantized_linear(
+            x.clone(),
+            weight.clone(),
+            Some(bias.clone()),
+            out_strategy.clone(),
+        );
+        let reference = (x.dequantize().matmul(weight.dequantize()) + bias.unsqueeze())
+            .quantize(out_strategy.clone());
+
+        assert!(output.is_quantized());
+        assert_within_one_step(output, reference, &out_strategy);
+    }
+
+    #[test]
+    fn q_relu_should_keep_input_strategy() {
+        let tensor = random([4, 6]);
+        let strategy = per_tensor_affine(&tensor);
+        let tensor = tensor.quantize(strategy.clone());
+
+        let output = relu(tensor.clone());
+        let reference = relu(tensor.dequantize()).quantize(strategy.clone());
+
+        assert!(output.is_quantized());
+        assert_within_one_step(output, reference, &strategy);
     }
 }
@@ -301,6 +301,42 @@ impl<E: tch::kind::Element + Copy + Default> TchOps<E> {
         )
     }
 
+    pub fn floor_div<const D: usize>(
+        lhs: TchTensor<E, D>,
+        rhs: TchTensor<E, D>,
+    ) -> TchTensor<E, D> {
+        TchTensor::binary_ops_tensor(
+            lhs,
+            rhs,
+            |lhs, rhs| lhs.f_floor_divide_(rhs).unwrap(),
+            |lhs, rhs| lhs.f_floor_divide(rhs).unwrap(),
+            |lhs, rhs| lhs.f_floor_divide(rhs).unwrap(),
+        )
+    }
+
+    pub fn remainder<const D: usize>(
+        lhs: TchTensor<E, D>,
+        rhs: TchTensor<E, D>,
+    ) -> TchTensor<E, D> {
+        TchTensor::binary_ops_tensor(
+            lhs,
+            rhs,
+            |lhs, rhs| lhs.f_remainder_tensor_(rhs).unwrap(),
+            |lhs, rhs| lhs.f_remainder_tensor(rhs).unwrap(),
+            |lhs, rhs| lhs.f_remainder_tensor(rhs).unwrap(),
+        )
+    }
+
+    pub fn fmod<const D: usize>(lhs: TchTensor<E, D>, rhs: TchTensor<E, D>) -> TchTensor<E, D> {
+        TchTensor::binary_ops_tensor(
+            lhs,
+            rhs,
+            |lhs, rhs| lhs.f_fmod_tensor_(rhs).unwrap(),
+            |lhs, rhs| lhs.f_fmod_tensor(rhs).unwrap(),
+            |lhs, rhs| lhs.f_fmod_tensor(rhs).unwrap(),
+        )
+    }
+
     pub fn mean<const D: usize>(tensor: TchTensor<E, D>) -> TchTensor<E, 1> {
         let tensor = tensor.tensor.mean(E::KIND);
         TchTensor::new(tensor)
@@ -341,6 +377,28 @@ impl<E: tch::kind::Element + Copy + Default> TchOps<E> {
         )
     }
 
+    pub fn cumsum<const D: usize>(tensor: TchTensor<E, D>, dim: usize) -> TchTensor<E, D> {
+        TchTensor::from_existing(tensor.tensor.cumsum(dim as i64, E::KIND), tensor.storage)
+    }
+
+    pub fn cumprod<const D: usize>(tensor: TchTensor<E, D>, dim: usize) -> TchTensor<E, D> {
+        TchTensor::from_existing(tensor.tensor.cumprod(dim as i64, E::KIND), tensor.storage)
+    }
+
+    pub fn cummax<const D: usize>(tensor: TchTensor<E, D>, dim: usize) -> TchTensor<E, D> {
+        let storage = tensor.storage.clone();
+        let (values, _indices) = tensor.tensor.cummax(dim as i64);
+
+        TchTensor::from_existing(values, storage)
+    }
+
+    pub fn cummin<const D: usize>(tensor: TchTensor<E, D>, dim: usize) -> TchTensor<E, D> {
+        let storage = tensor.storage.clone();
+        let (values, _indices) = tensor.tensor.cummin(dim as i64);
+
+        TchTensor::from_existing(values, storage)
+    }
+
     pub fn argmax<const D: usize>(tensor: TchTensor<E, D>, dim: usize) -> TchTensor<i64, D> {
         let storage = tensor.storage.clone();
         let tensor = tensor.tensor.argmax(dim as i64, true);
@@ -536,6 +594,53 @@ impl<E: tch::kind::Element + Copy + Default> TchOps<E> {
                     .tensor
                     .quantize_per_tensor(q.scale.into(), 0, tch::Kind::QInt8),
             ),
+            QuantizationStrategy::PerTensorAffineUInt8(ref q) => {
+                TchTensor::new(tensor.tensor.quantize_per_tensor(
+                    q.scale.into(),
+                    q.offset.into(),
+                    tch::Kind::QUInt8,
+                ))
+            }
+            QuantizationStrategy::PerChannelAffineInt8(ref q) => {
+                let scales = q.channels.iter().map(|c| c.scale).collect::<Vec<_>>();
+                let offsets = q.channels.iter().map(|c| c.offset as i64).collect();
+                Self::quantize_per_channel(tensor, scales, offsets, q.axis, tch::Kind::QInt8)
+            }
+            QuantizationStrategy::PerChannelSymmetricInt8(ref q) => {
+                let scales = q.channels.iter().map(|c| c.scale).collect::<Vec<_>>();
+                let offsets = vec![0i64; scales.len()];
+                Self::quantize_per_channel(tensor, scales, offsets, q.axis, tch::Kind::QInt8)
+            }
+            QuantizationStrategy::PerChannelAffineUInt8(ref q) => {
+                let scales = q.channels.iter().map(|c| c.scale).collect::<Vec<_>>();
+                let offsets = q.channels.iter().map(|c| c.offset as i64).collect();
+                Self::quantize_per_channel(tensor, scales, offsets, q.axis, tch::Kind::QUInt8)
+            }
+            QuantizationStrategy::PerGroupAffineInt8(_)
+            | QuantizationStrategy::PerGroupSymmetricInt8(_)
+            | QuantizationStrategy::PerGroupAffineUInt8(_)
+            | QuantizationStrategy::PerGroupAffineInt4(_)
+            | QuantizationStrategy::PerGroupSymmetricInt4(_)
+            | QuantizationStrategy::PerGroupAffineUInt4(_) => {
+                unimplemented!("Per-group quantization is not supported by LibTorch")
+            }
         }
     }
+
+    fn quantize_per_channel<const D: usize, I: tch::kind::Element>(
+        tensor: TchTensor<E, D>,
+        scales: Vec<f32>,
+        offsets: Vec<i64>,
+        axis: usize,
+        kind: tch::Kind,
+    ) -> TchTensor<I, D> {
+        let scales = scales.into_iter().map(|s| s as f64).collect::<Vec<_>>();
+        let device = tensor.tensor.device();
+        TchTensor::new(tensor.tensor.quantize_per_channel(
+            &tch::Tensor::from_slice(&scales).to(device),
+            &tch::Tensor::from_slice(&offsets).to(device),
+            axis as i64,
+            kind,
+        ))
+    }
 }
@@ -0,0 +1,30 @@
+use crate::{element::TchElement, LibTorch, TchTensor};
+use burn_tensor::ops::FftOps;
+
+impl<E: TchElement> FftOps<Self> for LibTorch<E> {
+    fn fft<const D: usize>(
+        re: TchTensor<E, D>,
+        im: TchTensor<E, D>,
+        dim: usize,
+        inverse: bool,
+    ) -> (TchTensor<E, D>, TchTensor<E, D>) {
+        // Complex tensors can't be built from half precision parts on every device
+        let kind = re.tensor.kind();
+        let upcast = |tensor: &tch::Tensor| match kind {
+            tch::Kind::Half | tch::Kind::BFloat16 => tensor.to_kind(tch::Kind::Float),
+            _ => tensor.shallow_clone(),
+        };
+        let input = tch::Tensor::complex(&upcast(&re.tensor), &upcast(&im.tensor));
+
+        // The norms are chosen so that neither direction is normalized
+        let output = match inverse {
+            true => input.fft_ifft(None::<i64>, dim as i64, "forward"),
+            false => input.fft_fft(None::<i64>, dim as i64, "backward"),
+        };
+
+        (
+            TchTensor::new(output.real().to_kind(kind).contiguous()),
+            TchTensor::new(output.imag().to_kind(kind).contiguous()),
+        )
+    }
+}
@@ -215,6 +215,27 @@ impl<E: TchElement> IntTensorOps<Self> for LibTorch<E> {
         TchTensor::<i64, D>::new(out.tensor.to_dtype(tch::Kind::Int64, non_blocking, copy))
     }
 
+    fn int_floor_div<const D: usize>(
+        lhs: TchTensor<i64, D>,
+        rhs: TchTensor<i64, D>,
+    ) -> TchTensor<i64, D> {
+        TchOps::floor_div(lhs, rhs)
+    }
+
+    fn int_floor_div_scalar<const D: usize>(lhs: TchTensor<i64, D>, rhs: i64) -> TchTensor<i64, D> {
+        lhs.unary_ops(
+            |mut tensor| tensor.f_floor_divide_scalar_(rhs).unwrap(),
+            |tensor| tensor.f_floor_divide_scalar(rhs).unwrap(),
+        )
+    }
+
+    fn int_remainder<const D: usize>(
+        lhs: TchTensor<i64, D>,
+        rhs: TchTensor<i64, D>,
+    ) -> TchTensor<i64, D> {
+        TchOps::remainder(lhs, rhs)
+    }
+
     fn int_remainder_scalar<const D: usize>(lhs: TchTensor<i64, D>, rhs: i64) -> TchTensor<i64, D> {
         lhs.unary_ops(
             |tensor| tensor.f_remainder(rhs).unwrap(),
@@ -353,6 +374,22 @@ impl<E: TchElement> IntTensorOps<Self> for LibTorch<E> {
         )
     }
 
+    fn int_cumsum<const D: usize>(tensor: TchTensor<i64, D>, dim: usize) -> TchTensor<i64, D> {
+        TchOps::cumsum(tensor, dim)
+    }
+
+    fn int_cumprod<const D: usize>(tensor: TchTensor<i64, D>, dim: usize) -> TchTensor<i64, D> {
+        TchOps::cumprod(tensor, dim)
+    }
+
+    fn int_cummax<const D: usize>(tensor: TchTensor<i64, D>, dim: usize) -> TchTensor<i64, D> {
+        TchOps::cummax(tensor, dim)
+    }
+
+    fn int_cummin<const D: usize>(tensor: TchTensor<i64, D>, dim: usize) -> TchTensor<i64, D> {
+        TchOps::cummin(tensor, dim)
+    }
+
     fn int_argmax<const D: usize>(tensor: TchTensor<i64, D>, dim: usize) -> TchTensor<i64, D> {
         TchOps::argmax(tensor, dim)
     }
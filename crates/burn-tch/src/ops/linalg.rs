@@ -0,0 +1,66 @@
+use crate::{element::TchElement, LibTorch, TchTensor};
+use burn_tensor::ops::LinalgOps;
+
+impl<E: TchElement> LinalgOps<Self> for LibTorch<E> {
+    fn linalg_inv<const D: usize>(tensor: TchTensor<E, D>) -> TchTensor<E, D> {
+        TchTensor::new(tch::Tensor::linalg_inv(&tensor.tensor))
+    }
+
+    fn linalg_det<const D: usize>(tensor: TchTensor<E, D>) -> TchTensor<E, D> {
+        let det = tch::Tensor::linalg_det(&tensor.tensor);
+
+        TchTensor::new(matrix(det))
+    }
+
+    fn linalg_slogdet<const D: usize>(
+        tensor: TchTensor<E, D>,
+    ) -> (TchTensor<E, D>, TchTensor<E, D>) {
+        let (sign, logabsdet) = tch::Tensor::linalg_slogdet(&tensor.tensor);
+
+        (
+            TchTensor::new(matrix(sign)),
+            TchTensor::new(matrix(logabsdet)),
+        )
+    }
+
+    fn linalg_solve<const D: usize>(lhs: TchTensor<E, D>, rhs: TchTensor<E, D>) -> TchTensor<E, D> {
+        TchTensor::new(tch::Tensor::linalg_solve(&lhs.tensor, &rhs.tensor, true))
+    }
+
+    fn linalg_cholesky<const D: usize>(tensor: TchTensor<E, D>) -> TchTensor<E, D> {
+        TchTensor::new(tensor.tensor.linalg_cholesky(false))
+    }
+
+    fn linalg_qr<const D: usize>(tensor: TchTensor<E, D>) -> (TchTensor<E, D>, TchTensor<E, D>) {
+        let (q, r) = tch::Tensor::linalg_qr(&tensor.tensor, "reduced");
+
+        (TchTensor::new(q), TchTensor::new(r))
+    }
+
+    fn linalg_svd<const D: usize>(
+        tensor: TchTensor<E, D>,
+    ) -> (TchTensor<E, D>, TchTensor<E, D>, TchTensor<E, D>) {
+        // `linalg_svd` requires a driver on some devices, `svd` returns V instead of Vh
+        let (u, s, v) = tensor.tensor.svd(true, true);
+
+        (
+            TchTensor::new(u),
+            TchTensor::new(s.unsqueeze(-2)),
+            TchTensor::new(v.transpose(-2, -1).contiguous()),
+        )
+    }
+
+    fn linalg_eigh<const D: usize>(tensor: TchTensor<E, D>) -> (TchTensor<E, D>, TchTensor<E, D>) {
+        let (values, vectors) = tensor.tensor.linalg_eigh("L");
+
+        (
+            TchTensor::new(values.unsqueeze(-2)),
+            TchTensor::new(vectors),
+        )
+    }
+}
+
+/// Reshape the scalars computed for each matrix into `[..., 1, 1]` tensors.
+fn matrix(tensor: tch::Tensor) -> tch::Tensor {
+    tensor.unsqueeze(-1).unsqueeze(-1)
+}
@@ -1,7 +1,9 @@
 mod activation;
 mod base;
 mod bool_tensor;
+mod fft;
 mod int_tensor;
+mod linalg;
 mod module;
 mod qtensor;
 mod tensor;
@@ -1,6 +1,6 @@
 use burn_tensor::{
     ops::{FloatTensor, QTensorOps, QuantizedTensor},
-    DType, Quantization, QuantizationStrategy, Shape, TensorData,
+    QuantizationStrategy, Shape, TensorData,
 };
 
 use crate::{LibTorch, LibTorchDevice, TchElement, TchShape, TchTensor};
@@ -19,28 +19,18 @@ impl<E: TchElement> QTensorOps<Self> for LibTorch<E> {
         // https://github.com/pytorch/pytorch/blob/main/aten/src/ATen/quantized/Quantizer.cpp#L322
         // So for now we have to load the dequantized values to quantize them back since the dequantization
         // methods take the values provided when quantizing.
-        let tensor = match data.dtype {
-            DType::QFloat(strategy) => match strategy {
-                QuantizationStrategy::PerTensorAffineInt8(q) => {
-                    let values = q.dequantize(&data.iter::<i8>().collect::<Vec<_>>());
-                    let tensor = tch::Tensor::from_slice(&values).to(device);
-                    TchOps::<E>::quantize::<D, i8>(
-                        TchTensor::new(tensor.reshape(shape_tch.dims)),
-                        &strategy,
-                    )
-                    .tensor
-                }
-                QuantizationStrategy::PerTensorSymmetricInt8(q) => {
-                    let values = q.dequantize(&data.iter::<i8>().collect::<Vec<_>>());
-                    let tensor = tch::Tensor::from_slice(&values).to(device);
-                    TchOps::<E>::quantize::<D, i8>(
-                        TchTensor::new(tensor.reshape(shape_tch.dims)),
-                        &strategy,
-                    )
-                    .tensor
-                }
-            },
-            _ => panic!(
+        let tensor = match data.quantization_strategy() {
+            Some(strategy) => {
+                let values =
+                    strategy.dequantize(&data.iter::<i32>().collect::<Vec<_>>(), &data.shape);
+                let tensor = tch::Tensor::from_slice(&values).to(device);
+                TchOps::<E>::quantize::<D, i8>(
+                    TchTensor::new(tensor.reshape(shape_tch.dims)),
+                    &strategy,
+                )
+                .tensor
+            }
+            None => panic!(
                 "Invalid dtype (expected DType::QFloat, got {:?})",
                 data.dtype
             ),
@@ -52,26 +42,7 @@ impl<E: TchElement> QTensorOps<Self> for LibTorch<E> {
         tensor: FloatTensor<Self, D>,
         strategy: &QuantizationStrategy,
     ) -> QuantizedTensor<Self, D> {
-        let mut tensor = tensor;
-        // Quantize only works on Float Tensor
-        if E::dtype() == DType::F16 {
-            tensor.tensor = tensor.tensor.to_kind(tch::Kind::Float);
-        }
-
-        match strategy {
-            QuantizationStrategy::PerTensorAffineInt8(ref q) => {
-                TchTensor::new(tensor.tensor.quantize_per_tensor(
-                    q.scale.into(),
-                    q.offset.into(),
-                    tch::Kind::QInt8,
-                ))
-            }
-            QuantizationStrategy::PerTensorSymmetricInt8(ref q) => TchTensor::new(
-                tensor
-                    .tensor
-                    .quantize_per_tensor(q.scale.into(), 0, tch::Kind::QInt8),
-            ),
-        }
+        TchOps::<E>::quantize::<D, i8>(tensor, strategy)
     }
 
     fn dequantize<const D: usize>(
@@ -103,8 +74,9 @@ impl<E: TchElement> QTensorOps<Self> for LibTorch<E> {
         let shape = Self::q_shape(&tensor);
         let tensor = Self::q_reshape(tensor.clone(), Shape::new([shape.num_elements()]));
         // To get the integer values we have to call `int_repr()`
-        let values: Result<Vec<i8>, tch::TchError> = tensor.tensor.int_repr().try_into();
+        let values: Result<Vec<i32>, tch::TchError> =
+            tensor.tensor.int_repr().to_kind(tch::Kind::Int).try_into();
 
-        TensorData::quantized(values.unwrap(), shape, strategy)
+        TensorData::quantized(strategy.pack(&values.unwrap()), shape, strategy)
     }
 }
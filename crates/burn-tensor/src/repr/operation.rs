@@ -59,6 +59,36 @@ pub enum FloatOperationDescription {
     Random(RandomOperationDescription),
     /// Operation corresponding to [recip](crate::ops::FloatTensorOps::float_recip).
     Recip(UnaryOperationDescription),
+    /// Operation corresponding to [floor](crate::ops::FloatTensorOps::float_floor).
+    Floor(UnaryOperationDescription),
+    /// Operation corresponding to [ceil](crate::ops::FloatTensorOps::float_ceil).
+    Ceil(UnaryOperationDescription),
+    /// Operation corresponding to [round](crate::ops::FloatTensorOps::float_round).
+    Round(UnaryOperationDescription),
+    /// Operation corresponding to [trunc](crate::ops::FloatTensorOps::float_trunc).
+    Trunc(UnaryOperationDescription),
+    /// Operation corresponding to [fmod](crate::ops::FloatTensorOps::float_fmod).
+    Fmod(BinaryOperationDescription),
+    /// Operation corresponding to [fmod_scalar](crate::ops::FloatTensorOps::float_fmod_scalar).
+    FmodScalar(ScalarOperationDescription<f32>),
+    /// Operation corresponding to [fft](crate::ops::FftOps::fft).
+    Fft(FftDescription),
+    /// Operation corresponding to [inv](crate::ops::LinalgOps::linalg_inv).
+    LinalgInv(UnaryOperationDescription),
+    /// Operation corresponding to [det](crate::ops::LinalgOps::linalg_det).
+    LinalgDet(UnaryOperationDescription),
+    /// Operation corresponding to [slogdet](crate::ops::LinalgOps::linalg_slogdet).
+    LinalgSlogdet(LinalgSlogdetDescription),
+    /// Operation corresponding to [solve](crate::ops::LinalgOps::linalg_solve).
+    LinalgSolve(BinaryOperationDescription),
+    /// Operation corresponding to [cholesky](crate::ops::LinalgOps::linalg_cholesky).
+    LinalgCholesky(UnaryOperationDescription),
+    /// Operation corresponding to [qr](crate::ops::LinalgOps::linalg_qr).
+    LinalgQr(LinalgQrDescription),
+    /// Operation corresponding to [svd](crate::ops::LinalgOps::linalg_svd).
+    LinalgSvd(LinalgSvdDescription),
+    /// Operation corresponding to [eigh](crate::ops::LinalgOps::linalg_eigh).
+    LinalgEigh(LinalgEighDescription),
 }
 
 /// Operation description specific to module.
@@ -433,6 +463,26 @@ pub enum NumericOperationDescription<E> {
     /// Float => [powf](crate::ops::FloatTensorOps::float_powf).
     /// Int => [powf](crate::ops::IntTensorOps::int_powf).
     Powf(BinaryOperationDescription),
+    /// Operation corresponding to:
+    ///
+    /// Float => [cumulative sum](crate::ops::FloatTensorOps::float_cumsum).
+    /// Int => [cumulative sum](crate::ops::IntTensorOps::int_cumsum).
+    CumSum(ScalarOperationDescription<usize>),
+    /// Operation corresponding to:
+    ///
+    /// Float => [cumulative product](crate::ops::FloatTensorOps::float_cumprod).
+    /// Int => [cumulative product](crate::ops::IntTensorOps::int_cumprod).
+    CumProd(ScalarOperationDescription<usize>),
+    /// Operation corresponding to:
+    ///
+    /// Float => [cumulative maximum](crate::ops::FloatTensorOps::float_cummax).
+    /// Int => [cumulative maximum](crate::ops::IntTensorOps::int_cummax).
+    CumMax(ScalarOperationDescription<usize>),
+    /// Operation corresponding to:
+    ///
+    /// Float => [cumulative minimum](crate::ops::FloatTensorOps::float_cummin).
+    /// Int => [cumulative minimum](crate::ops::IntTensorOps::int_cummin).
+    CumMin(ScalarOperationDescription<usize>),
 }
 
 /// Operation description specific to an int tensor.
@@ -440,6 +490,12 @@ pub enum NumericOperationDescription<E> {
 pub enum IntOperationDescription {
     /// Operation corresponding to [into float](crate::ops::IntTensorOps::int_into_float).
     IntoFloat(UnaryOperationDescription),
+    /// Operation corresponding to [floor div](crate::ops::IntTensorOps::int_floor_div).
+    FloorDiv(BinaryOperationDescription),
+    /// Operation corresponding to [floor div scalar](crate::ops::IntTensorOps::int_floor_div_scalar).
+    FloorDivScalar(ScalarOperationDescription<i32>),
+    /// Operation corresponding to [remainder](crate::ops::IntTensorOps::int_remainder).
+    Remainder(BinaryOperationDescription),
 }
 
 /// Operation description specific to a bool tensor.
@@ -651,6 +707,50 @@ pub struct ReduceDimWithIndicesDescription {
     pub out_indices: TensorDescription,
 }
 
+#[derive(Clone, Debug, Hash, PartialEq, Serialize, Deserialize)]
+#[allow(missing_docs)]
+pub struct FftDescription {
+    pub re: TensorDescription,
+    pub im: TensorDescription,
+    pub dim: usize,
+    pub inverse: bool,
+    pub out_re: TensorDescription,
+    pub out_im: TensorDescription,
+}
+
+#[derive(Clone, Debug, Hash, PartialEq, Serialize, Deserialize)]
+#[allow(missing_docs)]
+pub struct LinalgSlogdetDescription {
+    pub tensor: TensorDescription,
+    pub sign: TensorDescription,
+    pub logabsdet: TensorDescription,
+}
+
+#[derive(Clone, Debug, Hash, PartialEq, Serialize, Deserialize)]
+#[allow(missing_docs)]
+pub struct LinalgQrDescription {
+    pub tensor: TensorDescription,
+    pub q: TensorDescription,
+    pub r: TensorDescription,
+}
+
+#[derive(Clone, Debug, Hash, PartialEq, Serialize, Deserialize)]
+#[allow(missing_docs)]
+pub struct LinalgSvdDescription {
+    pub tensor: TensorDescription,
+    pub u: TensorDescription,
+    pub s: TensorDescription,
+    pub vh: TensorDescription,
+}
+
+#[derive(Clone, Debug, Hash, PartialEq, Serialize, Deserialize)]
+#[allow(missing_docs)]
+pub struct LinalgEighDescription {
+    pub tensor: TensorDescription,
+    pub values: TensorDescription,
+    pub vectors: TensorDescription,
+}
+
 #[derive(Clone, Debug, Hash, PartialEq, Serialize, Deserialize)]
 #[allow(missing_docs)]
 pub struct EmbeddingDescription {
@@ -1330,6 +1430,18 @@ impl<E: Element> NumericOperationDescription<E> {
             NumericOperationDescription::Powf(desc) => {
                 vec![&desc.lhs, &desc.rhs, &desc.out]
             }
+            NumericOperationDescription::CumSum(desc) => {
+                vec![&desc.lhs, &desc.out]
+            }
+            NumericOperationDescription::CumProd(desc) => {
+                vec![&desc.lhs, &desc.out]
+            }
+            NumericOperationDescription::CumMax(desc) => {
+                vec![&desc.lhs, &desc.out]
+            }
+            NumericOperationDescription::CumMin(desc) => {
+                vec![&desc.lhs, &desc.out]
+            }
         }
     }
 }
@@ -1352,6 +1464,31 @@ impl FloatOperationDescription {
             FloatOperationDescription::Sin(desc) => vec![&desc.input, &desc.out],
             FloatOperationDescription::Tanh(desc) => vec![&desc.input, &desc.out],
             FloatOperationDescription::IntoInt(desc) => vec![&desc.input, &desc.out],
+            FloatOperationDescription::Floor(desc) => vec![&desc.input, &desc.out],
+            FloatOperationDescription::Ceil(desc) => vec![&desc.input, &desc.out],
+            FloatOperationDescription::Round(desc) => vec![&desc.input, &desc.out],
+            FloatOperationDescription::Trunc(desc) => vec![&desc.input, &desc.out],
+            FloatOperationDescription::Fmod(desc) => vec![&desc.lhs, &desc.rhs, &desc.out],
+            FloatOperationDescription::FmodScalar(desc) => vec![&desc.lhs, &desc.out],
+            FloatOperationDescription::Fft(desc) => {
+                vec![&desc.re, &desc.im, &desc.out_re, &desc.out_im]
+            }
+            FloatOperationDescription::LinalgInv(desc) => vec![&desc.input, &desc.out],
+            FloatOperationDescription::LinalgDet(desc) => vec![&desc.input, &desc.out],
+            FloatOperationDescription::LinalgSlogdet(desc) => {
+                vec![&desc.tensor, &desc.sign, &desc.logabsdet]
+            }
+            FloatOperationDescription::LinalgSolve(desc) => {
+                vec![&desc.lhs, &desc.rhs, &desc.out]
+            }
+            FloatOperationDescription::LinalgCholesky(desc) => vec![&desc.input, &desc.out],
+            FloatOperationDescription::LinalgQr(desc) => vec![&desc.tensor, &desc.q, &desc.r],
+            FloatOperationDescription::LinalgSvd(desc) => {
+                vec![&desc.tensor, &desc.u, &desc.s, &desc.vh]
+            }
+            FloatOperationDescription::LinalgEigh(desc) => {
+                vec![&desc.tensor, &desc.values, &desc.vectors]
+            }
         }
     }
 }
@@ -1360,6 +1497,9 @@ impl IntOperationDescription {
     fn nodes(&self) -> Vec<&TensorDescription> {
         match self {
             IntOperationDescription::IntoFloat(desc) => vec![&desc.input, &desc.out],
+            IntOperationDescription::FloorDiv(desc) => vec![&desc.lhs, &desc.rhs, &desc.out],
+            IntOperationDescription::FloorDivScalar(desc) => vec![&desc.lhs, &desc.out],
+            IntOperationDescription::Remainder(desc) => vec![&desc.lhs, &desc.rhs, &desc.out],
         }
     }
 }
@@ -1560,6 +1700,10 @@ impl<E> core::hash::Hash for NumericOperationDescription<E> {
             NumericOperationDescription::Clamp(desc) => desc.hash(state),
             NumericOperationDescription::IntRandom(desc) => desc.hash(state),
             NumericOperationDescription::Powf(desc) => desc.hash(state),
+            NumericOperationDescription::CumSum(desc) => desc.hash(state),
+            NumericOperationDescription::CumProd(desc) => desc.hash(state),
+            NumericOperationDescription::CumMax(desc) => desc.hash(state),
+            NumericOperationDescription::CumMin(desc) => desc.hash(state),
         }
     }
 }
@@ -23,6 +23,28 @@ pub fn leaky_relu<const D: usize, B: Backend>(
     )))
 }
 
+/// Applies the exponential linear unit function as described in the paper [Fast and Accurate Deep
+/// Network Learning by Exponential Linear Units (ELUs)](https://arxiv.org/abs/1511.07289).
+///
+/// f(x) = alpha * (exp(x) - 1) for x <= 0, f(x) = x for x > 0
+pub fn elu<const D: usize, B: Backend>(tensor: Tensor<B, D>, alpha: f64) -> Tensor<B, D> {
+    let mask = tensor.clone().lower_equal_elem(0);
+    let negative = tensor.clone().exp().sub_scalar(1).mul_scalar(alpha);
+
+    tensor.mask_where(mask, negative)
+}
+
+/// Applies the scaled exponential linear unit function as described in the paper
+/// [Self-Normalizing Neural Networks](https://arxiv.org/abs/1706.02515).
+///
+/// `selu(x) = scale * elu(x, alpha)`, with `alpha ≈ 1.6733` and `scale ≈ 1.0507`
+pub fn selu<const D: usize, B: Backend>(tensor: Tensor<B, D>) -> Tensor<B, D> {
+    const ALPHA: f64 = 1.6732632423543772;
+    const SCALE: f64 = 1.0507009873554805;
+
+    elu(tensor, ALPHA).mul_scalar(SCALE)
+}
+
 /// Applies the Gaussian Error Linear Units function as described in the paper [Gaussian Error Linear Units (GELUs)](https://arxiv.org/pdf/1606.08415v3.pdf).
 pub fn gelu<const D: usize, B: Backend>(tensor: Tensor<B, D>) -> Tensor<B, D> {
     Tensor::from_primitive(TensorPrimitive::Float(B::gelu(tensor.primitive.tensor())))
@@ -137,6 +159,24 @@ pub fn log_sigmoid<const D: usize, B: Backend>(tensor: Tensor<B, D>) -> Tensor<B
     )))
 }
 
+/// Applies the hard sigmoid function, a piecewise linear approximation of the sigmoid.
+///
+/// `hard_sigmoid(x) = max(0, min(1, alpha * x + beta))`
+pub fn hard_sigmoid<const D: usize, B: Backend>(
+    tensor: Tensor<B, D>,
+    alpha: f64,
+    beta: f64,
+) -> Tensor<B, D> {
+    tensor.mul_scalar(alpha).add_scalar(beta).clamp(0.0, 1.0)
+}
+
+/// Applies the hard swish function as described in the paper [Searching for MobileNetV3](https://arxiv.org/abs/1905.02244).
+///
+/// `hard_swish(x) = x * hard_sigmoid(x, 1/6, 1/2)`
+pub fn hard_swish<const D: usize, B: Backend>(tensor: Tensor<B, D>) -> Tensor<B, D> {
+    tensor.clone().mul(hard_sigmoid(tensor, 1.0 / 6.0, 0.5))
+}
+
 /// Applies the silu function
 pub fn silu<const D: usize, B: Backend>(tensor: Tensor<B, D>) -> Tensor<B, D> {
     tensor.clone().mul(sigmoid(tensor))
@@ -18,7 +18,7 @@ use crate::check::TensorCheck;
 use crate::tensor::api::chunk::chunk;
 use crate::tensor::api::narrow::narrow;
 use crate::{backend::Backend, check, Bool, Float, Int, Shape, TensorData, TensorKind};
-use crate::{DType, Element, TensorPrimitive};
+use crate::{Element, TensorPrimitive};
 
 /// A tensor with a given backend, shape and data type.
 #[derive(new, Clone, Debug)]
@@ -1702,10 +1702,20 @@ impl<B: Backend> BasicOps<B> for Float {
             TensorPrimitive::Float(tensor) => {
                 TensorPrimitive::Float(B::float_reshape(tensor, shape))
             }
-            TensorPrimitive::QFloat { tensor, strategy } => TensorPrimitive::QFloat {
-                tensor: B::q_reshape(tensor, shape),
-                strategy,
-            },
+            TensorPrimitive::QFloat { tensor, strategy } => {
+                let dims = B::q_shape(&tensor).dims;
+                match strategy.reshape(&dims, &shape.dims) {
+                    Some(strategy) => TensorPrimitive::QFloat {
+                        tensor: B::q_reshape(tensor, shape),
+                        strategy,
+                    },
+                    // The channels are not preserved, so the tensor is dequantized
+                    None => TensorPrimitive::Float(B::float_reshape(
+                        B::dequantize(tensor, &strategy),
+                        shape,
+                    )),
+                }
+            }
         }
     }
 
@@ -1766,12 +1776,12 @@ impl<B: Backend> BasicOps<B> for Float {
     }
 
     fn from_data<const D: usize>(data: TensorData, device: &B::Device) -> Self::Primitive<D> {
-        match data.dtype {
-            DType::QFloat(strategy) => TensorPrimitive::QFloat {
-                tensor: B::q_from_data(data, device),
+        match data.quantization_strategy() {
+            Some(strategy) => TensorPrimitive::QFloat {
                 strategy,
+                tensor: B::q_from_data(data, device),
             },
-            _ => TensorPrimitive::Float(B::float_from_data(data, device)),
+            None => TensorPrimitive::Float(B::float_from_data(data, device)),
         }
     }
 
@@ -2305,6 +2315,15 @@ impl<const D2: usize> ReshapeArgs<D2> for [i32; D2] {
     }
 }
 
+impl<const D2: usize> ReshapeArgs<D2> for [i64; D2] {
+    fn into_shape<B: Backend, const D: usize, K: BasicOps<B>>(
+        self,
+        tensor: &Tensor<B, D, K>,
+    ) -> Shape<D2> {
+        ReshapeArgs::into_shape(self.map(|dim| dim as i32), tensor)
+    }
+}
+
 /// Trait used for broadcast arguments.
 pub trait BroadcastArgs<const D1: usize, const D2: usize> {
     /// Converts to a shape.
@@ -2354,6 +2373,13 @@ impl<const D1: usize, const D2: usize> BroadcastArgs<D1, D2> for [i32; D2] {
     }
 }
 
+impl<const D1: usize, const D2: usize> BroadcastArgs<D1, D2> for [i64; D2] {
+    // Passing -1 as the size for a dimension means not changing the size of that dimension.
+    fn into_shape(self, shape: &Shape<D1>) -> Shape<D2> {
+        BroadcastArgs::into_shape(self.map(|dim| dim as i32), shape)
+    }
+}
+
 impl<B, const D: usize, K> Serialize for Tensor<B, D, K>
 where
     B: Backend,
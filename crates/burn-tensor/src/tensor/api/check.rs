@@ -954,6 +954,174 @@ impl TensorCheck {
         check
     }
 
+    /// Checks that the tensor holds matrices in its last two dimensions, which must be square when
+    /// required.
+    pub(crate) fn linalg_matrix<const D: usize>(ops: &str, shape: &Shape<D>, square: bool) -> Self {
+        let mut check = Self::Ok;
+
+        if D < 2 {
+            return check.register(
+                ops,
+                TensorError::new(format!(
+                    "The input tensor must have at least 2 dimensions, got {D}"
+                )),
+            );
+        }
+
+        let (rows, cols) = (shape.dims[D - 2], shape.dims[D - 1]);
+
+        if square && rows != cols {
+            check = check.register(
+                ops,
+                TensorError::new(format!(
+                    "The matrices must be square, got {rows} rows and {cols} columns"
+                ))
+                .details(format!("Shape {:?}.", shape.dims)),
+            );
+        }
+
+        check
+    }
+
+    /// Checks that the right-hand sides of linear systems match their matrices.
+    pub(crate) fn linalg_solve<const D: usize>(lhs: &Shape<D>, rhs: &Shape<D>) -> Self {
+        let mut check = Self::linalg_matrix("Solve", lhs, true);
+
+        if D < 2 {
+            return check;
+        }
+
+        if lhs.dims[..D - 2] != rhs.dims[..D - 2] || lhs.dims[D - 1] != rhs.dims[D - 2] {
+            check = check.register(
+                "Solve",
+                TensorError::new(
+                    "The right-hand sides must have the same batch dimensions as the matrices, \
+                     and as many rows as the matrices",
+                )
+                .details(format!(
+                    "Lhs shape {:?}, rhs shape {:?}.",
+                    lhs.dims, rhs.dims
+                )),
+            );
+        }
+
+        check
+    }
+
+    pub(crate) fn einsum<T>(equation: &str, parsed: &Result<T, String>) -> Self {
+        let mut check = Self::Ok;
+
+        if let Err(reason) = parsed {
+            check = check.register(
+                "Einsum",
+                TensorError::new("Invalid equation.")
+                    .details(format!("{reason} Equation: '{equation}'.")),
+            );
+        }
+
+        check
+    }
+
+    pub(crate) fn einsum_operand<const D: usize>(max_rank: usize) -> Self {
+        let mut check = Self::Ok;
+
+        if D > max_rank {
+            check = check.register(
+                "Einsum",
+                TensorError::new("Invalid operand.").details(format!(
+                    "The tensor has a rank of {D}, but at most {max_rank} are supported."
+                )),
+            );
+        }
+
+        check
+    }
+
+    pub(crate) fn fft<const D: usize>(ops: &str, re: &Shape<D>, im: &Shape<D>, dim: usize) -> Self {
+        let mut check = Self::dim_ops::<D>(ops, dim);
+
+        if re != im {
+            check = check.register(
+                ops,
+                TensorError::new("The real and the imaginary parts must have the same shape.")
+                    .details(format!(
+                        "Real part shape {:?}, imaginary part shape {:?}.",
+                        re.dims, im.dims
+                    )),
+            );
+        }
+
+        check
+    }
+
+    pub(crate) fn irfft<const D: usize>(shape: &Shape<D>, dim: usize, n: usize) -> Self {
+        let mut check = Self::Ok;
+
+        if dim < D && (n == 0 || shape.dims[dim] != n / 2 + 1) {
+            check = check.register(
+                "IRFFT",
+                TensorError::new(
+                    "The transform must hold the n / 2 + 1 non-negative frequencies of a signal \
+                     of size n",
+                )
+                .details(format!(
+                    "Transform shape {:?}, dimension {dim}, signal size {n}.",
+                    shape.dims
+                )),
+            );
+        }
+
+        check
+    }
+
+    pub(crate) fn stft(ops: &str, n_fft: usize, hop_length: usize, window: Option<usize>) -> Self {
+        let mut check = Self::Ok;
+
+        if n_fft == 0 || hop_length == 0 {
+            check = check.register(
+                ops,
+                TensorError::new("The frame size and the hop length must be greater than zero.")
+                    .details(format!("Frame size {n_fft}, hop length {hop_length}.")),
+            );
+        }
+
+        if let Some(window) = window {
+            if window != n_fft {
+                check = check.register(
+                    ops,
+                    TensorError::new("The window must have the same size as the frames.")
+                        .details(format!("Window size {window}, frame size {n_fft}.")),
+                );
+            }
+        }
+
+        check
+    }
+
+    pub(crate) fn stft_signal(length: usize, n_fft: usize, center: bool) -> Self {
+        let mut check = Self::Ok;
+
+        let too_short = match center {
+            true => length <= n_fft / 2,
+            false => length < n_fft,
+        };
+
+        if too_short {
+            check = check.register(
+                "STFT",
+                TensorError::new(
+                    "The signal must be at least as long as a frame, and longer than half a \
+                     frame when it is centered",
+                )
+                .details(format!(
+                    "Signal length {length}, frame size {n_fft}, centered {center}."
+                )),
+            );
+        }
+
+        check
+    }
+
     /// The goal is to minimize the cost of checks when there are no error, but it's way less
     /// important when an error occurred, crafting a comprehensive error message is more important
     /// than optimizing string manipulation.
@@ -0,0 +1,135 @@
+use crate::{backend::Backend, narrow, Numeric};
+use alloc::vec;
+
+/// Computes the cumulative sum of the elements of the input `tensor` along a given dimension.
+///
+/// # Arguments
+///
+/// * `tensor` - The input tensor.
+/// * `dim` - The axis along which to accumulate.
+///
+/// # Returns
+///
+/// A tensor with the same shape as the input tensor, where each element is the sum of the
+/// elements up to its position along the dimension.
+///
+/// # Remarks
+///
+/// This is a fallback solution that used only when the backend doesn't have the corresponding implementation.
+/// Ideally, it is supposed to be implemented by the backend and the backend implementation will be resolved
+/// by static dispatch. It is not designed for direct usage by users, and not recommended to import
+/// or use this function directly.
+pub fn cumsum<B: Backend, const D: usize, K: Numeric<B>>(
+    tensor: K::Primitive<D>,
+    dim: usize,
+) -> K::Primitive<D> {
+    scan::<B, D, K>(tensor, dim, K::add)
+}
+
+/// Computes the cumulative product of the elements of the input `tensor` along a given
+/// dimension.
+///
+/// # Arguments
+///
+/// * `tensor` - The input tensor.
+/// * `dim` - The axis along which to accumulate.
+///
+/// # Returns
+///
+/// A tensor with the same shape as the input tensor, where each element is the product of the
+/// elements up to its position along the dimension.
+///
+/// # Remarks
+///
+/// This is a fallback solution that used only when the backend doesn't have the corresponding implementation.
+/// Ideally, it is supposed to be implemented by the backend and the backend implementation will be resolved
+/// by static dispatch. It is not designed for direct usage by users, and not recommended to import
+/// or use this function directly.
+pub fn cumprod<B: Backend, const D: usize, K: Numeric<B>>(
+    tensor: K::Primitive<D>,
+    dim: usize,
+) -> K::Primitive<D> {
+    scan::<B, D, K>(tensor, dim, K::mul)
+}
+
+/// Computes the cumulative maximum of the elements of the input `tensor` along a given
+/// dimension.
+///
+/// # Arguments
+///
+/// * `tensor` - The input tensor.
+/// * `dim` - The axis along which to accumulate.
+///
+/// # Returns
+///
+/// A tensor with the same shape as the input tensor, where each element is the maximum of the
+/// elements up to its position along the dimension.
+///
+/// # Remarks
+///
+/// This is a fallback solution that used only when the backend doesn't have the corresponding implementation.
+/// Ideally, it is supposed to be implemented by the backend and the backend implementation will be resolved
+/// by static dispatch. It is not designed for direct usage by users, and not recommended to import
+/// or use this function directly.
+pub fn cummax<B: Backend, const D: usize, K: Numeric<B>>(
+    tensor: K::Primitive<D>,
+    dim: usize,
+) -> K::Primitive<D> {
+    scan::<B, D, K>(tensor, dim, |previous, current| {
+        let mask = K::greater(previous.clone(), current.clone());
+        K::mask_where(current, mask, previous)
+    })
+}
+
+/// Computes the cumulative minimum of the elements of the input `tensor` along a given
+/// dimension.
+///
+/// # Arguments
+///
+/// * `tensor` - The input tensor.
+/// * `dim` - The axis along which to accumulate.
+///
+/// # Returns
+///
+/// A tensor with the same shape as the input tensor, where each element is the minimum of the
+/// elements up to its position along the dimension.
+///
+/// # Remarks
+///
+/// This is a fallback solution that used only when the backend doesn't have the corresponding implementation.
+/// Ideally, it is supposed to be implemented by the backend and the backend implementation will be resolved
+/// by static dispatch. It is not designed for direct usage by users, and not recommended to import
+/// or use this function directly.
+pub fn cummin<B: Backend, const D: usize, K: Numeric<B>>(
+    tensor: K::Primitive<D>,
+    dim: usize,
+) -> K::Primitive<D> {
+    scan::<B, D, K>(tensor, dim, |previous, current| {
+        let mask = K::lower(previous.clone(), current.clone());
+        K::mask_where(current, mask, previous)
+    })
+}
+
+/// Inclusive scan along a dimension with an associative operation, in `log2(n)` steps.
+///
+/// At each step, every element is combined with the element `offset` positions before it, the
+/// offset doubling from one step to the next (Hillis-Steele scan).
+fn scan<B: Backend, const D: usize, K: Numeric<B>>(
+    mut tensor: K::Primitive<D>,
+    dim: usize,
+    combine: impl Fn(K::Primitive<D>, K::Primitive<D>) -> K::Primitive<D>,
+) -> K::Primitive<D> {
+    let size = K::shape(&tensor).dims[dim];
+    let mut offset = 1;
+
+    while offset < size {
+        let head = narrow::<B, D, K>(tensor.clone(), dim, 0, offset);
+        let previous = narrow::<B, D, K>(tensor.clone(), dim, 0, size - offset);
+        let current = narrow::<B, D, K>(tensor, dim, offset, size - offset);
+
+        tensor = K::cat(vec![head, combine(previous, current)], dim);
+        offset *= 2;
+    }
+
+    tensor
+}
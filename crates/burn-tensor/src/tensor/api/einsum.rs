@@ -0,0 +1,554 @@
+use alloc::collections::BTreeMap;
+use alloc::format;
+use alloc::string::String;
+use alloc::vec::Vec;
+
+use crate::{backend::Backend, check, check::TensorCheck, Shape, Tensor};
+
+/// The rank of the tensors used during the contraction, whose trailing dimensions have a size
+/// of 1 when they have fewer labels, which is the highest rank supported by every backend.
+const RANK: usize = 6;
+
+/// The label of the first dimension covered by an ellipsis, counted from the last one, the
+/// letters being labeled by their ASCII code.
+const ELLIPSIS: usize = 128;
+
+/// An input of [Tensor::einsum], created from a float tensor of any rank up to 6, so that the
+/// inputs of an equation can have different ranks.
+#[derive(Clone, Debug)]
+pub struct EinsumOperand<B: Backend> {
+    tensor: Tensor<B, RANK>,
+    rank: usize,
+}
+
+impl<B: Backend, const D: usize> From<Tensor<B, D>> for EinsumOperand<B> {
+    fn from(tensor: Tensor<B, D>) -> Self {
+        check!(TensorCheck::einsum_operand::<D>(RANK));
+        let dims = padded(&tensor.dims());
+
+        Self {
+            tensor: tensor.reshape(dims),
+            rank: D,
+        }
+    }
+}
+
+impl<B: Backend> EinsumOperand<B> {
+    fn dims(&self) -> Vec<usize> {
+        self.tensor.dims()[..self.rank].to_vec()
+    }
+}
+
+impl<B: Backend, const D: usize> Tensor<B, D> {
+    /// Computes the Einstein summation of the tensors described by the equation.
+    ///
+    /// The equation lists the labels of the dimensions of each input, separated by commas, and
+    /// optionally the labels of the output after `->`, e.g. `bhqd,bhkd->bhqk` for the attention
+    /// scores. The inputs are multiplied together and the dimensions whose labels aren't in the
+    /// output are summed.
+    ///
+    /// * Labels are ASCII letters, and a label repeated in the same input takes its diagonal.
+    /// * An ellipsis `...` stands for the remaining dimensions of an input. The dimensions
+    ///   covered by the ellipsis of each input are aligned to the right.
+    /// * Dimensions of size 1 are broadcast to the size of the other dimensions with the same
+    ///   label.
+    /// * Without `->`, the output has the dimensions covered by the ellipsis, followed by the
+    ///   labels used only once, in alphabetical order.
+    ///
+    /// The contraction is computed with matrix multiplications, one input after the other.
+    ///
+    /// # Arguments
+    ///
+    /// * `equation` - The equation.
+    /// * `tensors` - The inputs, one for each comma-separated group of labels, e.g.
+    ///   `vec![matrix.into(), vector.into()]`.
+    ///
+    /// # Returns
+    ///
+    /// The output tensor of rank `D`, with a shape of `[1]` when all the dimensions are summed.
+    ///
+    /// # Panics
+    ///
+    /// If the equation is invalid or doesn't match the ranks of the tensors and of the output,
+    /// if dimensions with the same label have different sizes other than 1, or if a tensor or
+    /// an intermediate result of the contraction has more than 6 dimensions.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use burn_tensor::backend::Backend;
+    /// use burn_tensor::Tensor;
+    ///
+    /// fn example<B: Backend>() {
+    ///     let device = B::Device::default();
+    ///     let query = Tensor::<B, 4>::ones([2, 8, 10, 64], &device);
+    ///     let key = Tensor::<B, 4>::ones([2, 8, 12, 64], &device);
+    ///
+    ///     let scores: Tensor<B, 4> = Tensor::einsum("bhqd,bhkd->bhqk", vec![query.into(), key.into()]);
+    ///     println!("{:?}", scores.dims());
+    ///     // [2, 8, 10, 12]
+    ///
+    ///     let matrix = Tensor::<B, 2>::ones([3, 4], &device);
+    ///     let vector = Tensor::<B, 1>::ones([4], &device);
+    ///
+    ///     let product: Tensor<B, 1> = Tensor::einsum("ij,j->i", vec![matrix.into(), vector.into()]);
+    ///     println!("{:?}", product.dims());
+    ///     // [3]
+    /// }
+    /// ```
+    pub fn einsum(equation: &str, tensors: Vec<EinsumOperand<B>>) -> Self {
+        let shapes = tensors.iter().map(EinsumOperand::dims).collect::<Vec<_>>();
+        let parsed = Equation::parse(equation, &shapes, D);
+        check!(TensorCheck::einsum(equation, &parsed));
+
+        let Equation {
+            inputs,
+            output,
+            sizes,
+        } = parsed.unwrap();
+
+        // Operands are popped from the end, in the order of the equation
+        let mut operands = tensors
+            .into_iter()
+            .zip(inputs)
+            .map(|(tensor, labels)| Operand::new(tensor, labels))
+            .rev()
+            .collect::<Vec<_>>();
+        let mut result = operands.pop().unwrap();
+
+        while let Some(operand) = operands.pop() {
+            let mut keep = output.clone();
+            keep.extend(operands.iter().flat_map(|operand| operand.labels.iter()));
+
+            result = result.contract(operand, &keep, &sizes);
+        }
+
+        result.sum_except(&output).into_output(&output, &sizes)
+    }
+}
+
+/// The labels of the dimensions of the inputs and of the output of an equation, with the
+/// broadcast size of each label.
+struct Equation {
+    inputs: Vec<Vec<usize>>,
+    output: Vec<usize>,
+    sizes: BTreeMap<usize, usize>,
+}
+
+impl Equation {
+    fn parse(equation: &str, shapes: &[Vec<usize>], rank_output: usize) -> Result<Self, String> {
+        let equation = equation
+            .chars()
+            .filter(|c| !c.is_whitespace())
+            .collect::<String>();
+        let (inputs, output) = match equation.split_once("->") {
+            Some((inputs, output)) => (inputs, Some(output)),
+            None => (equation.as_str(), None),
+        };
+        let terms = inputs.split(',').collect::<Vec<_>>();
+
+        if terms.len() != shapes.len() {
+            return Err(format!(
+                "The equation has {} inputs, but {} tensors were given.",
+                terms.len(),
+                shapes.len()
+            ));
+        }
+
+        let mut inputs = Vec::with_capacity(terms.len());
+        let mut sizes = BTreeMap::new();
+        let mut ellipsis_rank = 0;
+
+        for (term, shape) in terms.into_iter().zip(shapes) {
+            let labels = parse_term(term, Some(shape.len()), 0)?;
+            let mut term_sizes = BTreeMap::new();
+
+            for (&label, &size) in labels.iter().zip(shape) {
+                if label >= ELLIPSIS {
+                    ellipsis_rank = ellipsis_rank.max(label - ELLIPSIS + 1);
+                }
+
+                // A repeated label takes the diagonal, which must be square
+                if let Some(&previous) = term_sizes.get(&label) {
+                    if previous != size {
+                        return Err(format!(
+                            "The dimensions of the repeated label '{}' in '{term}' have \
+                             different sizes {previous} and {size}.",
+                            display(label)
+                        ));
+                    }
+                }
+                term_sizes.insert(label, size);
+
+                let broadcast = sizes.entry(label).or_insert(size);
+                if *broadcast != size && *broadcast != 1 && size != 1 {
+                    return Err(format!(
+                        "The dimensions with the label '{}' have incompatible sizes {} and \
+                         {size}.",
+                        display(label),
+                        *broadcast
+                    ));
+                }
+                *broadcast = (*broadcast).max(size);
+            }
+
+            inputs.push(labels);
+        }
+
+        let output = match output {
+            Some(term) => {
+                let labels = parse_term(term, None, ellipsis_rank)?;
+
+                for (i, label) in labels.iter().enumerate() {
+                    if labels[..i].contains(label) {
+                        return Err(format!(
+                            "The label '{}' is repeated in the output.",
+                            display(*label)
+                        ));
+                    }
+                    if !sizes.contains_key(label) {
+                        return Err(format!(
+                            "The label '{}' of the output isn't in the inputs.",
+                            display(*label)
+                        ));
+                    }
+                }
+
+                labels
+            }
+            None => {
+                let mut counts = BTreeMap::new();
+                for &label in inputs.iter().flatten() {
+                    *counts.entry(label).or_insert(0) += 1;
+                }
+
+                (0..ellipsis_rank)
+                    .rev()
+                    .map(|k| ELLIPSIS + k)
+                    .chain(
+                        counts
+                            .into_iter()
+                            .filter(|&(label, count)| label < ELLIPSIS && count == 1)
+                            .map(|(label, _)| label),
+                    )
+                    .collect()
+            }
+        };
+
+        if output.len().max(1) != rank_output {
+            return Err(format!(
+                "The output has {} dimensions, but a tensor of rank {rank_output} was requested.",
+                output.len()
+            ));
+        }
+
+        let rank = max_rank(&inputs, &output);
+        if rank > RANK {
+            return Err(format!(
+                "The contraction needs tensors of rank {rank}, but at most {RANK} are supported."
+            ));
+        }
+
+        Ok(Self {
+            inputs,
+            output,
+            sizes,
+        })
+    }
+}
+
+/// Parses the labels of a term, where an ellipsis covers the dimensions of the given rank that
+/// aren't labeled, or `ellipsis_rank` dimensions when the rank isn't known.
+fn parse_term(term: &str, rank: Option<usize>, ellipsis_rank: usize) -> Result<Vec<usize>, String> {
+    let (before, after) = match term.split_once("...") {
+        Some((before, after)) => (before, Some(after)),
+        None => (term, None),
+    };
+
+    let letters = |part: &str| {
+        part.chars()
+            .map(|c| match c.is_ascii_alphabetic() {
+                true => Ok(c as usize),
+                false => Err(format!("Invalid character '{c}' in '{term}'.")),
+            })
+            .collect::<Result<Vec<_>, _>>()
+    };
+    let before = letters(before)?;
+    let after = after.map(letters).transpose()?;
+    let explicit = before.len() + after.as_ref().map(|after| after.len()).unwrap_or(0);
+
+    let covered = match (&after, rank) {
+        (None, Some(rank)) if rank != explicit => {
+            return Err(format!(
+                "The term '{term}' has {explicit} labels, but the tensor has a rank of {rank}."
+            ));
+        }
+        (None, _) => 0,
+        (Some(_), Some(rank)) => rank.checked_sub(explicit).ok_or_else(|| {
+            format!("The term '{term}' has {explicit} labels, but the tensor has a rank of {rank}.")
+        })?,
+        (Some(_), None) => ellipsis_rank,
+    };
+
+    Ok(before
+        .into_iter()
+        .chain((0..covered).rev().map(|k| ELLIPSIS + k))
+        .chain(after.into_iter().flatten())
+        .collect())
+}
+
+/// The highest rank of the inputs, the intermediate results and the output of the contraction.
+fn max_rank(inputs: &[Vec<usize>], output: &[usize]) -> usize {
+    let unique = |labels: &[usize]| {
+        labels
+            .iter()
+            .enumerate()
+            .filter(|(i, label)| !labels[..*i].contains(label))
+            .map(|(_, &label)| label)
+            .collect::<Vec<_>>()
+    };
+    let mut rank = inputs
+        .iter()
+        .map(Vec::len)
+        .max()
+        .unwrap_or(0)
+        .max(output.len());
+    let mut result = unique(&inputs[0]);
+
+    for (i, labels) in inputs.iter().enumerate().skip(1) {
+        let mut keep = output.to_vec();
+        keep.extend(inputs[i + 1..].iter().flatten());
+
+        result = Contraction::new(&result, &unique(labels), &keep).output();
+        rank = rank.max(result.len());
+    }
+
+    rank
+}
+
+/// The labels of the contraction of two operands into a matrix multiplication.
+struct Contraction {
+    /// The labels shared by both operands and kept, which are the batch dimensions.
+    batch: Vec<usize>,
+    /// The labels shared by both operands and summed.
+    contracted: Vec<usize>,
+    /// The labels of the left operand only.
+    lhs_free: Vec<usize>,
+    /// The labels of the right operand only.
+    rhs_free: Vec<usize>,
+}
+
+impl Contraction {
+    fn new(lhs: &[usize], rhs: &[usize], keep: &[usize]) -> Self {
+        // The labels of a single operand that aren't kept are summed beforehand
+        let lhs = lhs
+            .iter()
+            .filter(|label| keep.contains(label) || rhs.contains(label))
+            .copied()
+            .collect::<Vec<_>>();
+        let rhs = rhs
+            .iter()
+            .filter(|label| keep.contains(label) || lhs.contains(label))
+            .copied()
+            .collect::<Vec<_>>();
+
+        let (shared, lhs_free): (Vec<_>, Vec<_>) =
+            lhs.iter().partition(|label| rhs.contains(label));
+        let (batch, contracted) = shared.into_iter().partition(|label| keep.contains(label));
+        let rhs_free = rhs
+            .into_iter()
+            .filter(|label| !lhs.contains(label))
+            .collect();
+
+        Self {
+            batch,
+            contracted,
+            lhs_free,
+            rhs_free,
+        }
+    }
+
+    fn lhs(&self) -> Vec<usize> {
+        [self.batch.as_slice(), &self.lhs_free, &self.contracted].concat()
+    }
+
+    fn rhs(&self) -> Vec<usize> {
+        [self.batch.as_slice(), &self.contracted, &self.rhs_free].concat()
+    }
+
+    fn output(&self) -> Vec<usize> {
+        [self.batch.as_slice(), &self.lhs_free, &self.rhs_free].concat()
+    }
+}
+
+fn display(label: usize) -> String {
+    match label < ELLIPSIS {
+        true => format!("{}", label as u8 as char),
+        false => String::from("..."),
+    }
+}
+
+/// A tensor with a label for each of its leading dimensions.
+struct Operand<B: Backend> {
+    tensor: Tensor<B, RANK>,
+    labels: Vec<usize>,
+}
+
+impl<B: Backend> Operand<B> {
+    fn new(operand: EinsumOperand<B>, labels: Vec<usize>) -> Self {
+        let mut operand = Self {
+            tensor: operand.tensor,
+            labels,
+        };
+
+        // The diagonal of a repeated label is taken by masking the other elements
+        while let Some((first, second)) = operand.repeated() {
+            let size = operand.dims()[first];
+            let mut dims = [1; RANK];
+            dims[first] = size;
+            dims[second] = size;
+
+            let mask = Tensor::<B, 2>::eye(size, &operand.tensor.device()).reshape(dims);
+            operand.tensor = operand.tensor.mul(mask).sum_dim(second);
+            operand = operand.remove(&[second]);
+        }
+
+        operand
+    }
+
+    fn dims(&self) -> Vec<usize> {
+        self.tensor.dims()[..self.labels.len()].to_vec()
+    }
+
+    fn position(&self, label: usize) -> Option<usize> {
+        self.labels.iter().position(|&other| other == label)
+    }
+
+    fn repeated(&self) -> Option<(usize, usize)> {
+        self.labels.iter().enumerate().find_map(|(second, label)| {
+            self.labels[..second]
+                .iter()
+                .position(|other| other == label)
+                .map(|first| (first, second))
+        })
+    }
+
+    /// Removes the given dimensions, which must have a size of 1.
+    fn remove(self, positions: &[usize]) -> Self {
+        let dims = self.dims();
+        let (dims, labels): (Vec<_>, Vec<_>) = dims
+            .into_iter()
+            .zip(self.labels)
+            .enumerate()
+            .filter(|(position, _)| !positions.contains(position))
+            .map(|(_, pair)| pair)
+            .unzip();
+
+        Self {
+            tensor: self.tensor.reshape(padded(&dims)),
+            labels,
+        }
+    }
+
+    /// Sums the dimensions whose labels aren't kept.
+    fn sum_except(mut self, keep: &[usize]) -> Self {
+        let positions = (0..self.labels.len())
+            .filter(|&position| !keep.contains(&self.labels[position]))
+            .collect::<Vec<_>>();
+
+        if positions.is_empty() {
+            return self;
+        }
+
+        for &position in positions.iter() {
+            self.tensor = self.tensor.sum_dim(position);
+        }
+
+        self.remove(&positions)
+    }
+
+    /// Permutes the dimensions in the order of the labels, broadcasting the labels that must be
+    /// expanded to their full size.
+    fn arrange(self, labels: &[usize], expanded: &[usize], sizes: &BTreeMap<usize, usize>) -> Self {
+        let mut axes = [0; RANK];
+        for (axis, &label) in axes.iter_mut().zip(labels) {
+            *axis = self.position(label).unwrap() as isize;
+        }
+        for (axis, position) in axes.iter_mut().zip(0..RANK).skip(labels.len()) {
+            *axis = position as isize;
+        }
+
+        let tensor = self.tensor.permute(axes);
+        let mut dims = tensor.dims();
+        for (dim, label) in dims.iter_mut().zip(labels) {
+            if expanded.contains(label) {
+                *dim = sizes[label];
+            }
+        }
+
+        Self {
+            tensor: tensor.expand(Shape::new(dims)),
+            labels: labels.to_vec(),
+        }
+    }
+
+    /// Multiplies the operands and sums the dimensions whose labels aren't kept.
+    fn contract(self, other: Self, keep: &[usize], sizes: &BTreeMap<usize, usize>) -> Self {
+        let contraction = Contraction::new(&self.labels, &other.labels, keep);
+
+        // The shared dimensions are broadcast, so that they can be flattened together
+        let shared = [contraction.batch.as_slice(), &contraction.contracted].concat();
+        let lhs = self
+            .sum_except(&contraction.lhs())
+            .arrange(&contraction.lhs(), &shared, sizes);
+        let rhs = other
+            .sum_except(&contraction.rhs())
+            .arrange(&contraction.rhs(), &shared, sizes);
+
+        let lhs_dims = lhs.dims();
+        let rhs_dims = rhs.dims();
+        let (batch_dims, lhs_dims) = lhs_dims.split_at(contraction.batch.len());
+        let (lhs_free_dims, contracted_dims) = lhs_dims.split_at(contraction.lhs_free.len());
+        let rhs_free_dims = &rhs_dims[batch_dims.len() + contracted_dims.len()..];
+
+        let product = |dims: &[usize]| dims.iter().product::<usize>();
+        let output = lhs
+            .tensor
+            .reshape([
+                product(batch_dims),
+                product(lhs_free_dims),
+                product(contracted_dims),
+            ])
+            .matmul(rhs.tensor.reshape([
+                product(batch_dims),
+                product(contracted_dims),
+                product(rhs_free_dims),
+            ]));
+
+        Self {
+            tensor: output.reshape(padded(&[batch_dims, lhs_free_dims, rhs_free_dims].concat())),
+            labels: contraction.output(),
+        }
+    }
+
+    /// Permutes the dimensions in the order of the output and broadcasts them to their full size.
+    fn into_output<const D: usize>(
+        self,
+        output: &[usize],
+        sizes: &BTreeMap<usize, usize>,
+    ) -> Tensor<B, D> {
+        let operand = self.arrange(output, output, sizes);
+        let dims = operand.dims();
+        let mut shape = [1; D];
+        shape[..dims.len()].copy_from_slice(&dims);
+
+        operand.tensor.reshape(shape)
+    }
+}
+
+fn padded(dims: &[usize]) -> [usize; RANK] {
+    let mut padded = [1; RANK];
+    padded[..dims.len()].copy_from_slice(dims);
+
+    padded
+}
@@ -8,7 +8,7 @@ use crate::tensor::stats;
 use crate::tensor::{Distribution, Shape, TensorData};
 use crate::Tensor;
 use crate::{check, QuantizationStrategy};
-use crate::{Int, TensorPrimitive};
+use crate::{ElementConversion, Int, TensorPrimitive};
 
 impl<const D: usize, B> Tensor<B, D>
 where
@@ -103,6 +103,61 @@ where
         )))
     }
 
+    /// Rounds each element down to the nearest integer.
+    ///
+    /// `y = ⌊x⌋`
+    pub fn floor(self) -> Self {
+        Self::new(TensorPrimitive::Float(B::float_floor(
+            self.primitive.tensor(),
+        )))
+    }
+
+    /// Rounds each element up to the nearest integer.
+    ///
+    /// `y = ⌈x⌉`
+    pub fn ceil(self) -> Self {
+        Self::new(TensorPrimitive::Float(B::float_ceil(
+            self.primitive.tensor(),
+        )))
+    }
+
+    /// Rounds each element to the nearest integer, with halfway values rounded to the nearest
+    /// even integer.
+    pub fn round(self) -> Self {
+        Self::new(TensorPrimitive::Float(B::float_round(
+            self.primitive.tensor(),
+        )))
+    }
+
+    /// Rounds each element towards zero, keeping its integer part.
+    pub fn trunc(self) -> Self {
+        Self::new(TensorPrimitive::Float(B::float_trunc(
+            self.primitive.tensor(),
+        )))
+    }
+
+    /// Applies element wise remainder of the truncated division.
+    ///
+    /// `y = x1 - x2 * trunc(x1 / x2)`, which has the sign of `x1`. For a remainder with the
+    /// sign of the divisor, use [remainder_scalar](Tensor::remainder_scalar).
+    pub fn fmod(self, other: Self) -> Self {
+        check!(TensorCheck::binary_ops_ew("Fmod", &self, &other));
+        Self::new(TensorPrimitive::Float(B::float_fmod(
+            self.primitive.tensor(),
+            other.primitive.tensor(),
+        )))
+    }
+
+    /// Applies element wise remainder of the truncated division by a scalar.
+    ///
+    /// `y = x - s * trunc(x / s)`
+    pub fn fmod_scalar<E: ElementConversion>(self, other: E) -> Self {
+        Self::new(TensorPrimitive::Float(B::float_fmod_scalar(
+            self.primitive.tensor(),
+            other.elem(),
+        )))
+    }
+
     /// Create a tensor from floats (f32) on a given device.
     ///
     /// # Example
@@ -296,7 +351,13 @@ where
 
     /// Applies the relu function to the tensor.
     pub(crate) fn relu(self) -> Self {
-        Self::new(TensorPrimitive::Float(B::relu(self.primitive.tensor())))
+        match self.primitive {
+            TensorPrimitive::Float(tensor) => Self::new(TensorPrimitive::Float(B::relu(tensor))),
+            TensorPrimitive::QFloat { tensor, strategy } => Self::new(TensorPrimitive::QFloat {
+                tensor: B::q_relu(tensor, &strategy),
+                strategy,
+            }),
+        }
     }
 
     /// Calculate covaraince matrix between different entries alongside a given dimension.
@@ -331,6 +392,11 @@ where
         })
     }
 
+    /// Returns true if the tensor is quantized.
+    pub fn is_quantized(&self) -> bool {
+        matches!(self.primitive, TensorPrimitive::QFloat { .. })
+    }
+
     /// Convert the tensor back to a higher precision data type.
     ///
     /// If the tensor is not quantized, its value is simply returned.
@@ -341,4 +407,57 @@ where
     pub fn dequantize(self) -> Tensor<B, D> {
         Tensor::new(TensorPrimitive::Float(self.primitive.tensor()))
     }
+
+    /// Simulate the quantization of the tensor while keeping it in floating point precision.
+    ///
+    /// This is used during quantization aware training to model the quantization error in the
+    /// forward pass. In the backward pass, the gradient is passed straight through for the values
+    /// within the quantization range and is zero elsewhere.
+    ///
+    /// # Arguments
+    ///
+    /// * `strategy` - The quantization strategy.
+    ///
+    /// # Returns
+    ///
+    /// The fake quantized tensor.
+    pub fn fake_quantize(self, strategy: &QuantizationStrategy) -> Tensor<B, D> {
+        Tensor::new(TensorPrimitive::Float(B::fake_quantize(
+            self.primitive.tensor(),
+            strategy,
+        )))
+    }
+
+    /// Applies the matrix multiplication of two quantized tensors.
+    ///
+    /// When both tensors are quantized, the backend multiplies the quantized values directly (see
+    /// [QTensorOps::q_matmul](crate::ops::QTensorOps::q_matmul)). Otherwise, the float matrix
+    /// multiplication is performed before quantizing the result.
+    ///
+    /// # Arguments
+    ///
+    /// * `other` - The right hand side tensor.
+    /// * `strategy` - The quantization strategy of the output tensor.
+    ///
+    /// # Returns
+    ///
+    /// The quantized result of the matrix multiplication.
+    pub fn quantized_matmul(self, other: Self, strategy: QuantizationStrategy) -> Self {
+        match (self.primitive, other.primitive) {
+            (
+                TensorPrimitive::QFloat {
+                    tensor: lhs,
+                    strategy: lhs_strategy,
+                },
+                TensorPrimitive::QFloat {
+                    tensor: rhs,
+                    strategy: rhs_strategy,
+                },
+            ) => Tensor::new(TensorPrimitive::QFloat {
+                tensor: B::q_matmul(lhs, &lhs_strategy, rhs, &rhs_strategy, &strategy),
+                strategy,
+            }),
+            (lhs, rhs) => Tensor::new(lhs).matmul(Tensor::new(rhs)).quantize(strategy),
+        }
+    }
 }
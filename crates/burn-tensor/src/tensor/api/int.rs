@@ -1,4 +1,7 @@
-use crate::{backend::Backend, Float, Int, Shape, Tensor, TensorData, TensorPrimitive};
+use crate::{
+    backend::Backend, check, check::TensorCheck, ElementConversion, Float, Int, Shape, Tensor,
+    TensorData, TensorPrimitive,
+};
 
 use core::ops::Range;
 
@@ -68,6 +71,30 @@ where
         Tensor::new(TensorPrimitive::Float(B::int_into_float(self.primitive)))
     }
 
+    /// Applies element wise division rounding towards negative infinity.
+    ///
+    /// `y = ⌊x1 / x2⌋`
+    pub fn floor_div(self, other: Self) -> Self {
+        check!(TensorCheck::binary_ops_ew("FloorDiv", &self, &other));
+        Self::new(B::int_floor_div(self.primitive, other.primitive))
+    }
+
+    /// Applies element wise division with a scalar rounding towards negative infinity.
+    ///
+    /// `y = ⌊x / s⌋`
+    pub fn floor_div_scalar<E: ElementConversion>(self, other: E) -> Self {
+        Self::new(B::int_floor_div_scalar(self.primitive, other.elem()))
+    }
+
+    /// Applies element wise remainder of the division rounding towards negative infinity, which
+    /// has the sign of the divisor.
+    ///
+    /// `y = x1 - x2 ⌊x1 / x2⌋`
+    pub fn remainder(self, other: Self) -> Self {
+        check!(TensorCheck::binary_ops_ew("Remainder", &self, &other));
+        Self::new(B::int_remainder(self.primitive, other.primitive))
+    }
+
     /// Generates a cartesian grid for the given tensor shape on the specified device.
     /// The generated tensor is of dimension `D2 = D + 1`, where each element at dimension D contains the cartesian grid coordinates for that element.
     ///
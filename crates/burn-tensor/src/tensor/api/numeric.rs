@@ -155,6 +155,42 @@ where
         Self::new(K::prod_dim(self.primitive, dim))
     }
 
+    /// Computes the cumulative sum of the elements along the given *dimension* or *axis*.
+    ///
+    /// Each element of the output is the sum of the input elements up to its position along
+    /// the dimension, the output having the same shape as the input.
+    pub fn cumsum(self, dim: usize) -> Self {
+        check!(TensorCheck::dim_ops::<D>("Cumsum", dim));
+        Self::new(K::cumsum(self.primitive, dim))
+    }
+
+    /// Computes the cumulative product of the elements along the given *dimension* or *axis*.
+    ///
+    /// Each element of the output is the product of the input elements up to its position along
+    /// the dimension, the output having the same shape as the input.
+    pub fn cumprod(self, dim: usize) -> Self {
+        check!(TensorCheck::dim_ops::<D>("Cumprod", dim));
+        Self::new(K::cumprod(self.primitive, dim))
+    }
+
+    /// Computes the cumulative maximum of the elements along the given *dimension* or *axis*.
+    ///
+    /// Each element of the output is the maximum of the input elements up to its position along
+    /// the dimension, the output having the same shape as the input.
+    pub fn cummax(self, dim: usize) -> Self {
+        check!(TensorCheck::dim_ops::<D>("Cummax", dim));
+        Self::new(K::cummax(self.primitive, dim))
+    }
+
+    /// Computes the cumulative minimum of the elements along the given *dimension* or *axis*.
+    ///
+    /// Each element of the output is the minimum of the input elements up to its position along
+    /// the dimension, the output having the same shape as the input.
+    pub fn cummin(self, dim: usize) -> Self {
+        check!(TensorCheck::dim_ops::<D>("Cummin", dim));
+        Self::new(K::cummin(self.primitive, dim))
+    }
+
     /// Applies element wise equal comparison and returns a boolean tensor.
     pub fn equal_elem<E: Element>(self, other: E) -> Tensor<B, D, Bool> {
         K::equal_elem::<D>(self.primitive, other.elem())
@@ -1207,6 +1243,94 @@ where
     ///
     fn prod_dim<const D: usize>(tensor: Self::Primitive<D>, dim: usize) -> Self::Primitive<D>;
 
+    /// Computes the cumulative sum of the elements of the tensor along a dimension.
+    ///
+    /// # Arguments
+    ///
+    /// * `tensor` - The tensor to accumulate.
+    /// * `dim` - The dimension along which to accumulate.
+    ///
+    /// # Returns
+    ///
+    /// A tensor with the same shape as the input tensor, where each element is the sum of the
+    /// elements up to its position along the dimension.
+    ///
+    /// # Remarks
+    ///
+    /// This is a low-level function used internally by the library to call different backend functions
+    /// with static dispatch. It is not designed for direct usage by users, and not recommended to import
+    /// or use this function directly.
+    ///
+    /// For computing the cumulative sum of the elements of a tensor, users should prefer the
+    /// [Tensor::cumsum](Tensor::cumsum) function, which is more high-level and designed for public use.
+    fn cumsum<const D: usize>(tensor: Self::Primitive<D>, dim: usize) -> Self::Primitive<D>;
+
+    /// Computes the cumulative product of the elements of the tensor along a dimension.
+    ///
+    /// # Arguments
+    ///
+    /// * `tensor` - The tensor to accumulate.
+    /// * `dim` - The dimension along which to accumulate.
+    ///
+    /// # Returns
+    ///
+    /// A tensor with the same shape as the input tensor, where each element is the product of the
+    /// elements up to its position along the dimension.
+    ///
+    /// # Remarks
+    ///
+    /// This is a low-level function used internally by the library to call different backend functions
+    /// with static dispatch. It is not designed for direct usage by users, and not recommended to import
+    /// or use this function directly.
+    ///
+    /// For computing the cumulative product of the elements of a tensor, users should prefer the
+    /// [Tensor::cumprod](Tensor::cumprod) function, which is more high-level and designed for public use.
+    fn cumprod<const D: usize>(tensor: Self::Primitive<D>, dim: usize) -> Self::Primitive<D>;
+
+    /// Computes the cumulative maximum of the elements of the tensor along a dimension.
+    ///
+    /// # Arguments
+    ///
+    /// * `tensor` - The tensor to accumulate.
+    /// * `dim` - The dimension along which to accumulate.
+    ///
+    /// # Returns
+    ///
+    /// A tensor with the same shape as the input tensor, where each element is the maximum of the
+    /// elements up to its position along the dimension.
+    ///
+    /// # Remarks
+    ///
+    /// This is a low-level function used internally by the library to call different backend functions
+    /// with static dispatch. It is not designed for direct usage by users, and not recommended to import
+    /// or use this function directly.
+    ///
+    /// For computing the cumulative maximum of the elements of a tensor, users should prefer the
+    /// [Tensor::cummax](Tensor::cummax) function, which is more high-level and designed for public use.
+    fn cummax<const D: usize>(tensor: Self::Primitive<D>, dim: usize) -> Self::Primitive<D>;
+
+    /// Computes the cumulative minimum of the elements of the tensor along a dimension.
+    ///
+    /// # Arguments
+    ///
+    /// * `tensor` - The tensor to accumulate.
+    /// * `dim` - The dimension along which to accumulate.
+    ///
+    /// # Returns
+    ///
+    /// A tensor with the same shape as the input tensor, where each element is the minimum of the
+    /// elements up to its position along the dimension.
+    ///
+    /// # Remarks
+    ///
+    /// This is a low-level function used internally by the library to call different backend functions
+    /// with static dispatch. It is not designed for direct usage by users, and not recommended to import
+    /// or use this function directly.
+    ///
+    /// For computing the cumulative minimum of the elements of a tensor, users should prefer the
+    /// [Tensor::cummin](Tensor::cummin) function, which is more high-level and designed for public use.
+    fn cummin<const D: usize>(tensor: Self::Primitive<D>, dim: usize) -> Self::Primitive<D>;
+
     /// Computes the mean of all the elements of the tensor.
     ///
     /// # Arguments
@@ -2170,6 +2294,22 @@ impl<B: Backend> Numeric<B> for Int {
         B::int_prod_dim(tensor, dim)
     }
 
+    fn cumsum<const D: usize>(tensor: Self::Primitive<D>, dim: usize) -> Self::Primitive<D> {
+        B::int_cumsum(tensor, dim)
+    }
+
+    fn cumprod<const D: usize>(tensor: Self::Primitive<D>, dim: usize) -> Self::Primitive<D> {
+        B::int_cumprod(tensor, dim)
+    }
+
+    fn cummax<const D: usize>(tensor: Self::Primitive<D>, dim: usize) -> Self::Primitive<D> {
+        B::int_cummax(tensor, dim)
+    }
+
+    fn cummin<const D: usize>(tensor: Self::Primitive<D>, dim: usize) -> Self::Primitive<D> {
+        B::int_cummin(tensor, dim)
+    }
+
     fn mean<const D: usize>(tensor: Self::Primitive<D>) -> Self::Primitive<1> {
         B::int_mean(tensor)
     }
@@ -2512,6 +2652,22 @@ impl<B: Backend> Numeric<B> for Float {
         TensorPrimitive::Float(B::float_prod_dim(tensor.tensor(), dim))
     }
 
+    fn cumsum<const D: usize>(tensor: Self::Primitive<D>, dim: usize) -> Self::Primitive<D> {
+        TensorPrimitive::Float(B::float_cumsum(tensor.tensor(), dim))
+    }
+
+    fn cumprod<const D: usize>(tensor: Self::Primitive<D>, dim: usize) -> Self::Primitive<D> {
+        TensorPrimitive::Float(B::float_cumprod(tensor.tensor(), dim))
+    }
+
+    fn cummax<const D: usize>(tensor: Self::Primitive<D>, dim: usize) -> Self::Primitive<D> {
+        TensorPrimitive::Float(B::float_cummax(tensor.tensor(), dim))
+    }
+
+    fn cummin<const D: usize>(tensor: Self::Primitive<D>, dim: usize) -> Self::Primitive<D> {
+        TensorPrimitive::Float(B::float_cummin(tensor.tensor(), dim))
+    }
+
     fn mean<const D: usize>(tensor: Self::Primitive<D>) -> Self::Primitive<1> {
         TensorPrimitive::Float(B::float_mean(tensor.tensor()))
     }
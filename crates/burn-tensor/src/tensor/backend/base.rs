@@ -58,6 +58,8 @@ pub trait Backend:
     + IntTensorOps<Self>
     + ModuleOps<Self>
     + ActivationOps<Self>
+    + LinalgOps<Self>
+    + FftOps<Self>
     + QTensorOps<Self>
     + Clone
     + Sized
@@ -6,10 +6,7 @@ use alloc::string::String;
 use alloc::vec::Vec;
 use half::{bf16, f16};
 
-use crate::{
-    tensor::Shape, DType, Distribution, Element, ElementConversion, Quantization,
-    QuantizationStrategy,
-};
+use crate::{tensor::Shape, DType, Distribution, Element, ElementConversion, QuantizationStrategy};
 
 use num_traits::pow::Pow;
 
@@ -49,12 +46,34 @@ impl TensorData {
     }
 
     /// Creates a new quantized tensor data structure.
+    ///
+    /// The values must be in the storage format of the quantization strategy
+    /// (see [QuantizationStrategy::pack]). The parameters of the strategy are stored after the
+    /// values.
     pub fn quantized<E: Element, S: Into<Vec<usize>>>(
         value: Vec<E>,
         shape: S,
         strategy: QuantizationStrategy,
     ) -> Self {
-        Self::init(value, shape, DType::QFloat(strategy))
+        let mut data = Self::init(value, shape, DType::QFloat(strategy.kind()));
+        data.bytes.extend(strategy.params_to_bytes());
+        data
+    }
+
+    /// Returns the quantization strategy of quantized data, or `None` if the data is not quantized.
+    ///
+    /// # Panics
+    /// If the parameters stored after the quantized values are invalid.
+    pub fn quantization_strategy(&self) -> Option<QuantizationStrategy> {
+        match self.dtype {
+            DType::QFloat(kind) => {
+                let params = &self.bytes[kind.storage_len(self.num_elements())..];
+                let strategy = QuantizationStrategy::from_params_bytes(kind, params)
+                    .expect("Invalid quantization parameters");
+                Some(strategy)
+            }
+            _ => None,
+        }
     }
 
     /// Initializes a new tensor data structure from the provided values.
@@ -105,7 +124,7 @@ impl TensorData {
         if E::dtype() == self.dtype {
             Box::new(bytemuck::checked::cast_slice(&self.bytes).iter().copied())
         } else {
-            match self.dtype {
+            match &self.dtype {
                 DType::I8 => Box::new(
                     bytemuck::checked::cast_slice(&self.bytes)
                         .iter()
@@ -159,20 +178,12 @@ impl TensorData {
                 ),
                 // bool is a byte value equal to either 0 or 1
                 DType::Bool => Box::new(self.bytes.iter().map(|e| e.elem::<E>())),
-                DType::QFloat(q) => match q {
-                    // NOTE: we do not dequantize the values to iterate over
-                    QuantizationStrategy::PerTensorAffineInt8(_strategy) => Box::new(
-                        bytemuck::checked::cast_slice(&self.bytes)
-                            .iter()
-                            .map(|e: &i8| e.elem::<E>()),
-                    ),
-
-                    QuantizationStrategy::PerTensorSymmetricInt8(_strategy) => Box::new(
-                        bytemuck::checked::cast_slice(&self.bytes)
-                            .iter()
-                            .map(|e: &i8| e.elem::<E>()),
-                    ),
-                },
+                // NOTE: we do not dequantize the values to iterate over
+                DType::QFloat(q) => Box::new(
+                    q.unpack(&self.bytes, self.num_elements())
+                        .into_iter()
+                        .map(|e| e.elem::<E>()),
+                ),
             }
         }
     }
@@ -266,18 +277,8 @@ impl TensorData {
             DType::F32,
             "Only f32 data type can be quantized"
         );
-        match &quantization {
-            QuantizationStrategy::PerTensorAffineInt8(strategy) => TensorData::quantized(
-                strategy.quantize(self.as_slice().unwrap()),
-                self.shape,
-                quantization,
-            ),
-            QuantizationStrategy::PerTensorSymmetricInt8(strategy) => TensorData::quantized(
-                strategy.quantize(self.as_slice().unwrap()),
-                self.shape,
-                quantization,
-            ),
-        }
+        let values = quantization.quantize(self.as_slice().unwrap(), &self.shape);
+        TensorData::quantized(quantization.pack(&values), self.shape, quantization)
     }
 
     /// Asserts the data is approximately equal to another data.
@@ -318,7 +319,7 @@ impl TensorData {
             );
         }
 
-        match self.dtype {
+        match &self.dtype {
             DType::F64 => self.assert_eq_elem::<f64>(other),
             DType::F32 => self.assert_eq_elem::<f32>(other),
             DType::F16 => self.assert_eq_elem::<f16>(other),
@@ -331,9 +332,11 @@ impl TensorData {
             DType::U32 => self.assert_eq_elem::<u32>(other),
             DType::U8 => self.assert_eq_elem::<u8>(other),
             DType::Bool => self.assert_eq_elem::<bool>(other),
-            DType::QFloat(q) => {
+            DType::QFloat(_) => {
                 // Strict or not, it doesn't make sense to compare quantized data to not quantized data for equality
-                if let DType::QFloat(q_other) = other.dtype {
+                if let DType::QFloat(_) = &other.dtype {
+                    let q = self.quantization_strategy();
+                    let q_other = other.quantization_strategy();
                     assert_eq!(
                         q, q_other,
                         "Quantization strategies differ ({:?} != {:?})",
@@ -342,14 +345,7 @@ impl TensorData {
                 } else {
                     panic!("Quantized data differs from other not quantized data")
                 }
-                match q {
-                    QuantizationStrategy::PerTensorAffineInt8(_) => {
-                        self.assert_eq_elem::<i8>(other)
-                    }
-                    QuantizationStrategy::PerTensorSymmetricInt8(_) => {
-                        self.assert_eq_elem::<i8>(other)
-                    }
-                }
+                self.assert_eq_elem::<i32>(other)
             }
         }
     }
@@ -583,7 +579,7 @@ impl<
 
 impl core::fmt::Display for TensorData {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
-        let fmt = match self.dtype {
+        let fmt = match &self.dtype {
             DType::F64 => format!("{:?}", self.as_slice::<f64>().unwrap()),
             DType::F32 => format!("{:?}", self.as_slice::<f32>().unwrap()),
             DType::F16 => format!("{:?}", self.as_slice::<f16>().unwrap()),
@@ -596,14 +592,9 @@ impl core::fmt::Display for TensorData {
             DType::U32 => format!("{:?}", self.as_slice::<u32>().unwrap()),
             DType::U8 => format!("{:?}", self.as_slice::<u8>().unwrap()),
             DType::Bool => format!("{:?}", self.as_slice::<bool>().unwrap()),
-            DType::QFloat(q) => match &q {
-                QuantizationStrategy::PerTensorAffineInt8(_) => {
-                    format!("{:?} {q:?}", self.as_slice::<i8>().unwrap())
-                }
-                QuantizationStrategy::PerTensorSymmetricInt8(_) => {
-                    format!("{:?} {q:?}", self.as_slice::<i8>().unwrap())
-                }
-            },
+            DType::QFloat(q) => {
+                format!("{:?} {q:?}", q.unpack(&self.bytes, self.num_elements()))
+            }
         };
         f.write_str(fmt.as_str())
     }
@@ -0,0 +1,201 @@
+use crate::backend::Backend;
+use crate::check::TensorCheck;
+use crate::{check, Tensor, TensorPrimitive};
+
+/// Computes the inverse of the matrices stored in the last two dimensions of the tensor.
+///
+/// # Arguments
+///
+/// * `tensor` - The square matrices, with a shape of `[..., n, n]`.
+///
+/// # Returns
+///
+/// The inverse matrices, with the same shape as the input.
+pub fn inv<B: Backend, const D: usize>(tensor: Tensor<B, D>) -> Tensor<B, D> {
+    check!(TensorCheck::linalg_matrix::<D>(
+        "Inv",
+        &tensor.shape(),
+        true
+    ));
+
+    Tensor::new(TensorPrimitive::Float(B::linalg_inv(
+        tensor.primitive.tensor(),
+    )))
+}
+
+/// Computes the determinant of the matrices stored in the last two dimensions of the tensor.
+///
+/// # Arguments
+///
+/// * `tensor` - The square matrices, with a shape of `[..., n, n]`.
+///
+/// # Returns
+///
+/// The determinants, with a shape of `[..., 1, 1]`.
+pub fn det<B: Backend, const D: usize>(tensor: Tensor<B, D>) -> Tensor<B, D> {
+    check!(TensorCheck::linalg_matrix::<D>(
+        "Det",
+        &tensor.shape(),
+        true
+    ));
+
+    Tensor::new(TensorPrimitive::Float(B::linalg_det(
+        tensor.primitive.tensor(),
+    )))
+}
+
+/// Computes the sign and the natural logarithm of the absolute value of the determinant of the
+/// matrices stored in the last two dimensions of the tensor.
+///
+/// This is more accurate than [det] when the determinant is very small or very large.
+///
+/// # Arguments
+///
+/// * `tensor` - The square matrices, with a shape of `[..., n, n]`.
+///
+/// # Returns
+///
+/// A tuple `(sign, logabsdet)`, both with a shape of `[..., 1, 1]`. The sign is zero and the
+/// logarithm is negative infinity for singular matrices.
+pub fn slogdet<B: Backend, const D: usize>(tensor: Tensor<B, D>) -> (Tensor<B, D>, Tensor<B, D>) {
+    check!(TensorCheck::linalg_matrix::<D>(
+        "Slogdet",
+        &tensor.shape(),
+        true
+    ));
+
+    let (sign, logabsdet) = B::linalg_slogdet(tensor.primitive.tensor());
+
+    (
+        Tensor::new(TensorPrimitive::Float(sign)),
+        Tensor::new(TensorPrimitive::Float(logabsdet)),
+    )
+}
+
+/// Solves the linear systems `A X = B` for `X`.
+///
+/// # Arguments
+///
+/// * `lhs` - The square matrices `A`, with a shape of `[..., n, n]`.
+/// * `rhs` - The right-hand sides `B`, with a shape of `[..., n, k]`.
+///
+/// # Returns
+///
+/// The solutions `X`, with the same shape as the right-hand sides.
+pub fn solve<B: Backend, const D: usize>(lhs: Tensor<B, D>, rhs: Tensor<B, D>) -> Tensor<B, D> {
+    check!(TensorCheck::linalg_solve::<D>(&lhs.shape(), &rhs.shape()));
+
+    Tensor::new(TensorPrimitive::Float(B::linalg_solve(
+        lhs.primitive.tensor(),
+        rhs.primitive.tensor(),
+    )))
+}
+
+/// Computes the Cholesky decomposition `A = L L^T` of the symmetric positive-definite matrices
+/// stored in the last two dimensions of the tensor.
+///
+/// # Arguments
+///
+/// * `tensor` - The symmetric positive-definite matrices, with a shape of `[..., n, n]`.
+///
+/// # Returns
+///
+/// The lower triangular matrices `L`, with the same shape as the input. The result is undefined
+/// for matrices that aren't positive-definite.
+pub fn cholesky<B: Backend, const D: usize>(tensor: Tensor<B, D>) -> Tensor<B, D> {
+    check!(TensorCheck::linalg_matrix::<D>(
+        "Cholesky",
+        &tensor.shape(),
+        true
+    ));
+
+    Tensor::new(TensorPrimitive::Float(B::linalg_cholesky(
+        tensor.primitive.tensor(),
+    )))
+}
+
+/// Computes the reduced QR decomposition `A = Q R` of the matrices stored in the last two
+/// dimensions of the tensor.
+///
+/// # Arguments
+///
+/// * `tensor` - The matrices, with a shape of `[..., m, n]`.
+///
+/// # Returns
+///
+/// A tuple `(Q, R)` with `k = min(m, n)`, where `Q` has orthonormal columns with a shape of
+/// `[..., m, k]` and `R` is upper triangular with a shape of `[..., k, n]`.
+pub fn qr<B: Backend, const D: usize>(tensor: Tensor<B, D>) -> (Tensor<B, D>, Tensor<B, D>) {
+    check!(TensorCheck::linalg_matrix::<D>(
+        "QR",
+        &tensor.shape(),
+        false
+    ));
+
+    let (q, r) = B::linalg_qr(tensor.primitive.tensor());
+
+    (
+        Tensor::new(TensorPrimitive::Float(q)),
+        Tensor::new(TensorPrimitive::Float(r)),
+    )
+}
+
+/// Computes the reduced singular value decomposition `A = U diag(S) Vh` of the matrices stored
+/// in the last two dimensions of the tensor.
+///
+/// # Arguments
+///
+/// * `tensor` - The matrices, with a shape of `[..., m, n]`.
+///
+/// # Returns
+///
+/// A tuple `(U, S, Vh)` with `k = min(m, n)`, where `U` has orthonormal columns with a shape of
+/// `[..., m, k]`, `S` holds the singular values in descending order with a shape of `[..., 1, k]`
+/// and `Vh` has orthonormal rows with a shape of `[..., k, n]`.
+///
+/// The singular values are stored as a row, so the matrices are rebuilt with
+/// `u.mul(s).matmul(vh)`.
+pub fn svd<B: Backend, const D: usize>(
+    tensor: Tensor<B, D>,
+) -> (Tensor<B, D>, Tensor<B, D>, Tensor<B, D>) {
+    check!(TensorCheck::linalg_matrix::<D>(
+        "SVD",
+        &tensor.shape(),
+        false
+    ));
+
+    let (u, s, vh) = B::linalg_svd(tensor.primitive.tensor());
+
+    (
+        Tensor::new(TensorPrimitive::Float(u)),
+        Tensor::new(TensorPrimitive::Float(s)),
+        Tensor::new(TensorPrimitive::Float(vh)),
+    )
+}
+
+/// Computes the eigenvalues and the eigenvectors of the symmetric matrices stored in the last two
+/// dimensions of the tensor.
+///
+/// # Arguments
+///
+/// * `tensor` - The symmetric matrices, with a shape of `[..., n, n]`.
+///
+/// # Returns
+///
+/// A tuple `(eigenvalues, eigenvectors)`, where the eigenvalues are in ascending order with a
+/// shape of `[..., 1, n]` and the eigenvectors are the columns of matrices with a shape of
+/// `[..., n, n]`.
+pub fn eigh<B: Backend, const D: usize>(tensor: Tensor<B, D>) -> (Tensor<B, D>, Tensor<B, D>) {
+    check!(TensorCheck::linalg_matrix::<D>(
+        "Eigh",
+        &tensor.shape(),
+        true
+    ));
+
+    let (values, vectors) = B::linalg_eigh(tensor.primitive.tensor());
+
+    (
+        Tensor::new(TensorPrimitive::Float(values)),
+        Tensor::new(TensorPrimitive::Float(vectors)),
+    )
+}
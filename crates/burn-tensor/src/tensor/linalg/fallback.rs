@@ -0,0 +1,417 @@
+use core::ops::Range;
+
+use crate::{backend::Backend, Int, Shape, Tensor};
+
+/// Number of sweeps of the Jacobi algorithms used by [eigh] and [svd].
+///
+/// The convergence is quadratic once the off-diagonal elements are small, so a fixed number of
+/// sweeps is enough for matrices of moderate size, without reading the tensors back to check it.
+const JACOBI_SWEEPS: usize = 12;
+
+pub(crate) fn inv<B: Backend, const D: usize>(tensor: Tensor<B, D>) -> Tensor<B, D> {
+    let identity = identity(&tensor.shape(), &tensor.device());
+
+    solve(tensor, identity)
+}
+
+pub(crate) fn det<B: Backend, const D: usize>(tensor: Tensor<B, D>) -> Tensor<B, D> {
+    let (upper, _, sign) = eliminate(tensor, None);
+
+    product(diagonal(upper)).mul(sign)
+}
+
+pub(crate) fn slogdet<B: Backend, const D: usize>(
+    tensor: Tensor<B, D>,
+) -> (Tensor<B, D>, Tensor<B, D>) {
+    let (upper, _, sign) = eliminate(tensor, None);
+    let diagonal = diagonal(upper);
+
+    let sign = product(diagonal.clone().sign()).mul(sign);
+    let logabsdet = diagonal.abs().log().sum_dim(D - 1);
+
+    (sign, logabsdet)
+}
+
+pub(crate) fn solve<B: Backend, const D: usize>(
+    lhs: Tensor<B, D>,
+    rhs: Tensor<B, D>,
+) -> Tensor<B, D> {
+    let (upper, rhs, _) = eliminate(lhs, Some(rhs));
+
+    back_substitute(upper, rhs.unwrap())
+}
+
+pub(crate) fn cholesky<B: Backend, const D: usize>(tensor: Tensor<B, D>) -> Tensor<B, D> {
+    let shape = tensor.shape();
+    let n = shape.dims[D - 1];
+    let mut lower = Tensor::zeros(shape.clone(), &tensor.device());
+
+    for j in 0..n {
+        // The column of `A - L L^T` from the diagonal, with the columns of L computed so far
+        let mut column = tensor.clone().slice(ranges(&shape, j..n, j..j + 1));
+
+        if j > 0 {
+            let left = lower.clone().slice(ranges(&shape, j..n, 0..j));
+            let row = lower.clone().slice(ranges(&shape, j..j + 1, 0..j));
+            column = column.sub(left.matmul(row.transpose()));
+        }
+
+        let diagonal = column.clone().narrow(D - 2, 0, 1).sqrt();
+        lower = lower.slice_assign(ranges(&shape, j..n, j..j + 1), column.div(diagonal));
+    }
+
+    lower
+}
+
+pub(crate) fn qr<B: Backend, const D: usize>(tensor: Tensor<B, D>) -> (Tensor<B, D>, Tensor<B, D>) {
+    let shape = tensor.shape();
+    let (m, n) = (shape.dims[D - 2], shape.dims[D - 1]);
+    let k = m.min(n);
+
+    let mut shape_q = shape.clone();
+    shape_q.dims[D - 1] = m;
+
+    let mut r = tensor;
+    let mut q = identity(&shape_q, &r.device());
+
+    // Householder reflections zeroing the elements below the diagonal, one column at a time
+    for j in 0..k.min(m - 1) {
+        let x = r.clone().slice(ranges(&shape, j..m, j..j + 1));
+        let x_first = x.clone().narrow(D - 2, 0, 1);
+        let norm = x.clone().powf_scalar(2.0).sum_dim(D - 2).sqrt();
+
+        // v = x + sign(x_0) |x| e_0, the sign avoiding the cancellation
+        let sign = positive_sign(x_first.clone());
+        let v = x.slice_assign(
+            ranges(&x_first.shape(), 0..1, 0..1),
+            x_first.add(sign.mul(norm)),
+        );
+        let v = normalize(v, D - 2);
+
+        let block = r.clone().slice(ranges(&shape, j..m, 0..n));
+        let reflected = v.clone().transpose().matmul(block.clone());
+        let block = block.sub(v.clone().mul_scalar(2.0).matmul(reflected));
+        r = r.slice_assign(ranges(&shape, j..m, 0..n), block);
+
+        let block = q.clone().slice(ranges(&shape_q, 0..m, j..m));
+        let reflected = block.clone().matmul(v.clone()).mul_scalar(2.0);
+        let block = block.sub(reflected.matmul(v.transpose()));
+        q = q.slice_assign(ranges(&shape_q, 0..m, j..m), block);
+    }
+
+    let q = q.narrow(D - 1, 0, k);
+    let r = r.narrow(D - 2, 0, k).triu(0);
+
+    (q, r)
+}
+
+pub(crate) fn svd<B: Backend, const D: usize>(
+    tensor: Tensor<B, D>,
+) -> (Tensor<B, D>, Tensor<B, D>, Tensor<B, D>) {
+    let shape = tensor.shape();
+    let (m, n) = (shape.dims[D - 2], shape.dims[D - 1]);
+
+    if m < n {
+        // A^T = U S V^T, so A = V S U^T
+        let (u, s, vh) = svd(tensor.transpose());
+        return (vh.transpose(), s, u.transpose());
+    }
+
+    let mut shape_v = shape.clone();
+    shape_v.dims[D - 2] = n;
+
+    let mut u = tensor;
+    let mut v = identity(&shape_v, &u.device());
+
+    // One-sided Jacobi: the columns are rotated in pairs until they are orthogonal
+    for _ in 0..JACOBI_SWEEPS {
+        for p in 0..n {
+            for q in p + 1..n {
+                let column_p = u.clone().narrow(D - 1, p, 1);
+                let column_q = u.clone().narrow(D - 1, q, 1);
+
+                let alpha = column_p.clone().powf_scalar(2.0).sum_dim(D - 2);
+                let beta = column_q.clone().powf_scalar(2.0).sum_dim(D - 2);
+                let gamma = column_p.mul(column_q).sum_dim(D - 2);
+
+                let (cos, sin) = jacobi_rotation(alpha, beta, gamma);
+                u = rotate_columns(u, p, q, cos.clone(), sin.clone());
+                v = rotate_columns(v, p, q, cos, sin);
+            }
+        }
+    }
+
+    let singular_values = u.clone().powf_scalar(2.0).sum_dim(D - 2).sqrt();
+    let u = u.div(non_zero(singular_values.clone()));
+
+    let (singular_values, indices) = singular_values.sort_descending_with_indices(D - 1);
+    let u = u.gather(D - 1, indices.clone().expand(shape));
+    let v = v.gather(D - 1, indices.expand(shape_v));
+
+    (u, singular_values, v.transpose())
+}
+
+pub(crate) fn eigh<B: Backend, const D: usize>(
+    tensor: Tensor<B, D>,
+) -> (Tensor<B, D>, Tensor<B, D>) {
+    let shape = tensor.shape();
+    let n = shape.dims[D - 1];
+
+    let mut a = tensor;
+    let mut vectors = identity(&shape, &a.device());
+
+    // Cyclic Jacobi: each rotation zeroes an off-diagonal element of A
+    for _ in 0..JACOBI_SWEEPS {
+        for p in 0..n {
+            for q in p + 1..n {
+                let app = a.clone().slice(ranges(&shape, p..p + 1, p..p + 1));
+                let aqq = a.clone().slice(ranges(&shape, q..q + 1, q..q + 1));
+                let apq = a.clone().slice(ranges(&shape, p..p + 1, q..q + 1));
+
+                let (cos, sin) = jacobi_rotation(app, aqq, apq);
+                a = rotate_columns(a, p, q, cos.clone(), sin.clone());
+                a = rotate_columns(a.transpose(), p, q, cos.clone(), sin.clone()).transpose();
+                vectors = rotate_columns(vectors, p, q, cos, sin);
+            }
+        }
+    }
+
+    let (values, indices) = diagonal(a).sort_with_indices(D - 1);
+    let vectors = vectors.gather(D - 1, indices.expand(shape));
+
+    (values, vectors)
+}
+
+/// Gaussian elimination with partial pivoting, the same row operations being applied to the
+/// right-hand side.
+///
+/// Returns the upper triangular matrices, the transformed right-hand side and the sign of the
+/// row permutations, with a shape of `[..., 1, 1]`.
+fn eliminate<B: Backend, const D: usize>(
+    mut lhs: Tensor<B, D>,
+    mut rhs: Option<Tensor<B, D>>,
+) -> (Tensor<B, D>, Option<Tensor<B, D>>, Tensor<B, D>) {
+    let shape = lhs.shape();
+    let n = shape.dims[D - 1];
+    let mut sign = Tensor::ones(matrix_shape(&shape, 1, 1), &lhs.device());
+
+    for k in 0..n {
+        // The pivot is the largest element in absolute value of the column, from the diagonal
+        let column = lhs.clone().slice(ranges(&shape, k..n, k..k + 1));
+        let pivot = column.abs().argmax(D - 2).add_scalar(k as i64);
+
+        lhs = swap_rows(lhs, k, pivot.clone());
+        rhs = rhs.map(|rhs| swap_rows(rhs, k, pivot.clone()));
+        sign = sign
+            .clone()
+            .mask_where(pivot.not_equal_elem(k as i64), sign.neg());
+
+        if k + 1 == n {
+            break;
+        }
+
+        let row = lhs.clone().narrow(D - 2, k, 1);
+        let diagonal = row.clone().narrow(D - 1, k, 1);
+        // A zero pivot means that the column is already zero
+        let factors = lhs
+            .clone()
+            .slice(ranges(&shape, k + 1..n, k..k + 1))
+            .div(non_zero(diagonal));
+
+        let below = lhs.clone().slice(ranges(&shape, k + 1..n, 0..n));
+        lhs = lhs.slice_assign(
+            ranges(&shape, k + 1..n, 0..n),
+            below.sub(factors.clone().mul(row)),
+        );
+
+        rhs = rhs.map(|rhs| {
+            let shape = rhs.shape();
+            let cols = shape.dims[D - 1];
+            let row = rhs.clone().narrow(D - 2, k, 1);
+            let below = rhs.clone().slice(ranges(&shape, k + 1..n, 0..cols));
+
+            rhs.slice_assign(
+                ranges(&shape, k + 1..n, 0..cols),
+                below.sub(factors.mul(row)),
+            )
+        });
+    }
+
+    (lhs, rhs, sign)
+}
+
+/// Solve `U X = B` for upper triangular matrices, from the last row.
+fn back_substitute<B: Backend, const D: usize>(
+    upper: Tensor<B, D>,
+    rhs: Tensor<B, D>,
+) -> Tensor<B, D> {
+    let shape_upper = upper.shape();
+    let shape = rhs.shape();
+    let (n, cols) = (shape.dims[D - 2], shape.dims[D - 1]);
+    let mut solution = rhs;
+
+    for i in (0..n).rev() {
+        let mut row = solution.clone().narrow(D - 2, i, 1);
+
+        if i + 1 < n {
+            let coefficients = upper
+                .clone()
+                .slice(ranges(&shape_upper, i..i + 1, i + 1..n));
+            let known = solution.clone().narrow(D - 2, i + 1, n - i - 1);
+            row = row.sub(coefficients.matmul(known));
+        }
+
+        let diagonal = upper
+            .clone()
+            .slice(ranges(&shape_upper, i..i + 1, i..i + 1));
+        solution = solution.slice_assign(ranges(&shape, i..i + 1, 0..cols), row.div(diagonal));
+    }
+
+    solution
+}
+
+/// Swap the row `k` of each matrix with the row given by `pivot`, with a shape of `[..., 1, 1]`.
+fn swap_rows<B: Backend, const D: usize>(
+    tensor: Tensor<B, D>,
+    k: usize,
+    pivot: Tensor<B, D, Int>,
+) -> Tensor<B, D> {
+    let shape = tensor.shape();
+    let rows = shape.dims[D - 2];
+    let shape_index = matrix_shape(&shape, rows, 1);
+
+    let mut shape_arange = [1; D];
+    shape_arange[D - 2] = rows;
+    let index = Tensor::<B, 1, Int>::arange(0..rows as i64, &tensor.device())
+        .reshape(shape_arange)
+        .expand(shape_index.clone());
+    let pivot = pivot.expand(shape_index);
+
+    let source = index
+        .clone()
+        .mask_where(index.clone().equal_elem(k as i64), pivot.clone())
+        .mask_fill(index.equal(pivot), k as i64);
+
+    tensor.gather(D - 2, source.expand(shape))
+}
+
+/// Compute the rotation zeroing the off-diagonal element of the symmetric matrix
+/// `[[app, apq], [apq, aqq]]`, each element having a shape of `[..., 1, 1]`.
+fn jacobi_rotation<B: Backend, const D: usize>(
+    app: Tensor<B, D>,
+    aqq: Tensor<B, D>,
+    apq: Tensor<B, D>,
+) -> (Tensor<B, D>, Tensor<B, D>) {
+    let is_diagonal = apq.clone().equal_elem(0.0);
+
+    let theta = aqq.sub(app).div(non_zero(apq).mul_scalar(2.0));
+    // The smallest root of t^2 + 2 t theta - 1 = 0
+    let tan = positive_sign(theta.clone()).div(
+        theta
+            .clone()
+            .abs()
+            .add(theta.powf_scalar(2.0).add_scalar(1.0).sqrt()),
+    );
+    let tan = tan.mask_fill(is_diagonal, 0.0);
+
+    let cos = tan.clone().powf_scalar(2.0).add_scalar(1.0).sqrt().recip();
+    let sin = tan.mul(cos.clone());
+
+    (cos, sin)
+}
+
+/// Rotate the columns `p` and `q` of each matrix, with `cos` and `sin` of shape `[..., 1, 1]`.
+fn rotate_columns<B: Backend, const D: usize>(
+    tensor: Tensor<B, D>,
+    p: usize,
+    q: usize,
+    cos: Tensor<B, D>,
+    sin: Tensor<B, D>,
+) -> Tensor<B, D> {
+    let shape = tensor.shape();
+    let rows = shape.dims[D - 2];
+    let column_p = tensor.clone().narrow(D - 1, p, 1);
+    let column_q = tensor.clone().narrow(D - 1, q, 1);
+
+    let rotated_p = column_p
+        .clone()
+        .mul(cos.clone())
+        .sub(column_q.clone().mul(sin.clone()));
+    let rotated_q = column_p.mul(sin).add(column_q.mul(cos));
+
+    tensor
+        .slice_assign(ranges(&shape, 0..rows, p..p + 1), rotated_p)
+        .slice_assign(ranges(&shape, 0..rows, q..q + 1), rotated_q)
+}
+
+/// The diagonal of each matrix, with a shape of `[..., 1, n]`.
+fn diagonal<B: Backend, const D: usize>(tensor: Tensor<B, D>) -> Tensor<B, D> {
+    let identity = identity(&tensor.shape(), &tensor.device());
+
+    tensor.mul(identity).sum_dim(D - 2)
+}
+
+/// The product of the elements of the rows, with a shape of `[..., 1, 1]`.
+///
+/// The elements are multiplied one at a time, since the product along a dimension may not
+/// support negative elements.
+fn product<B: Backend, const D: usize>(tensor: Tensor<B, D>) -> Tensor<B, D> {
+    let n = tensor.dims()[D - 1];
+
+    (1..n).fold(tensor.clone().narrow(D - 1, 0, 1), |product, i| {
+        product.mul(tensor.clone().narrow(D - 1, i, 1))
+    })
+}
+
+/// Identity matrices with the batch and column dimensions of the given shape.
+fn identity<B: Backend, const D: usize>(shape: &Shape<D>, device: &B::Device) -> Tensor<B, D> {
+    let n = shape.dims[D - 1];
+    let mut shape_eye = [1; D];
+    shape_eye[D - 2] = n;
+    shape_eye[D - 1] = n;
+
+    let eye = Tensor::<B, 2>::eye(n, device).reshape(shape_eye);
+
+    Tensor::zeros(matrix_shape(shape, n, n), device).add(eye)
+}
+
+/// Normalize the vectors along the dimension, the zero vectors being left unchanged.
+fn normalize<B: Backend, const D: usize>(tensor: Tensor<B, D>, dim: usize) -> Tensor<B, D> {
+    let norm = tensor.clone().powf_scalar(2.0).sum_dim(dim).sqrt();
+
+    tensor.div(non_zero(norm))
+}
+
+/// Replace the zeros by ones, so that the tensor can be used as a divisor.
+fn non_zero<B: Backend, const D: usize>(tensor: Tensor<B, D>) -> Tensor<B, D> {
+    let mask = tensor.clone().equal_elem(0.0);
+
+    tensor.mask_fill(mask, 1.0)
+}
+
+/// The sign of the elements, zero being positive.
+fn positive_sign<B: Backend, const D: usize>(tensor: Tensor<B, D>) -> Tensor<B, D> {
+    let mask = tensor.clone().lower_elem(0.0);
+
+    tensor.ones_like().mask_fill(mask, -1.0)
+}
+
+/// The shape with the batch dimensions of the given shape and matrices of the given size.
+fn matrix_shape<const D: usize>(shape: &Shape<D>, rows: usize, cols: usize) -> Shape<D> {
+    let mut shape = shape.clone();
+    shape.dims[D - 2] = rows;
+    shape.dims[D - 1] = cols;
+    shape
+}
+
+/// The ranges selecting the given rows and columns of all the matrices.
+fn ranges<const D: usize>(
+    shape: &Shape<D>,
+    rows: Range<usize>,
+    cols: Range<usize>,
+) -> [Range<usize>; D] {
+    let mut ranges = shape.dims.map(|dim| 0..dim);
+    ranges[D - 2] = rows;
+    ranges[D - 1] = cols;
+    ranges
+}
@@ -0,0 +1,4 @@
+mod base;
+pub(crate) mod fallback;
+
+pub use base::*;
@@ -23,6 +23,9 @@ pub mod backend;
 /// The container module.
 pub mod container;
 
+/// The linear algebra module.
+pub mod linalg;
+
 /// The loss module.
 pub mod loss;
 
@@ -32,6 +35,9 @@ pub mod module;
 /// Operations on tensors module.
 pub mod ops;
 
+/// The signal processing module.
+pub mod signal;
+
 #[cfg(feature = "experimental-named-tensor")]
 mod named;
 #[cfg(feature = "experimental-named-tensor")]
@@ -1,7 +1,7 @@
 use crate::{
     backend::Backend,
     ops::{ConvOptions, ConvTransposeOptions, InterpolateOptions, UnfoldOptions},
-    Int, Tensor, TensorPrimitive,
+    Int, QuantizationStrategy, Tensor, TensorPrimitive,
 };
 
 /// Applies the [embedding module](crate::ops::ModuleOps::embedding).
@@ -51,6 +51,95 @@ where
     )))
 }
 
+/// Applies a [2D convolution](crate::ops::QTensorOps::q_conv2d) on quantized tensors.
+///
+/// When the input and the weight are not both quantized, the float convolution is performed
+/// before quantizing the result with the given strategy.
+pub fn quantized_conv2d<B>(
+    x: Tensor<B, 4>,
+    weight: Tensor<B, 4>,
+    bias: Option<Tensor<B, 1>>,
+    options: ConvOptions<2>,
+    strategy: QuantizationStrategy,
+) -> Tensor<B, 4>
+where
+    B: Backend,
+{
+    match (x.primitive, weight.primitive) {
+        (
+            TensorPrimitive::QFloat {
+                tensor: x,
+                strategy: x_strategy,
+            },
+            TensorPrimitive::QFloat {
+                tensor: weight,
+                strategy: weight_strategy,
+            },
+        ) => Tensor::new(TensorPrimitive::QFloat {
+            tensor: B::q_conv2d(
+                x,
+                &x_strategy,
+                weight,
+                &weight_strategy,
+                bias.map(|b| b.primitive.tensor()),
+                options,
+                &strategy,
+            ),
+            strategy,
+        }),
+        (x, weight) => {
+            conv2d(Tensor::new(x), Tensor::new(weight), bias, options).quantize(strategy)
+        }
+    }
+}
+
+/// Applies a [linear transformation](crate::ops::QTensorOps::q_linear) on quantized tensors.
+///
+/// The weight should be broadcastable to the input, e.g. of shape `[1, d_input, d_output]` for an
+/// input of shape `[batch_size, seq_length, d_input]`. When the input and the weight are not both
+/// quantized, the float operation is performed before quantizing the result with the given
+/// strategy.
+pub fn quantized_linear<B, const D: usize>(
+    x: Tensor<B, D>,
+    weight: Tensor<B, D>,
+    bias: Option<Tensor<B, 1>>,
+    strategy: QuantizationStrategy,
+) -> Tensor<B, D>
+where
+    B: Backend,
+{
+    match (x.primitive, weight.primitive) {
+        (
+            TensorPrimitive::QFloat {
+                tensor: x,
+                strategy: x_strategy,
+            },
+            TensorPrimitive::QFloat {
+                tensor: weight,
+                strategy: weight_strategy,
+            },
+        ) => Tensor::new(TensorPrimitive::QFloat {
+            tensor: B::q_linear(
+                x,
+                &x_strategy,
+                weight,
+                &weight_strategy,
+                bias.map(|b| b.primitive.tensor()),
+                &strategy,
+            ),
+            strategy,
+        }),
+        (x, weight) => {
+            let output = Tensor::new(x).matmul(Tensor::new(weight));
+            let output = match bias {
+                Some(bias) => output + bias.unsqueeze(),
+                None => output,
+            };
+            output.quantize(strategy)
+        }
+    }
+}
+
 /// Applies a [3D convolution](crate::ops::ModuleOps::conv3d).
 pub fn conv3d<B>(
     x: Tensor<B, 5>,
@@ -0,0 +1,38 @@
+use crate::{backend::Backend, signal::fallback, Tensor, TensorPrimitive};
+
+use super::FloatTensor;
+
+/// Fast Fourier transform operations.
+///
+/// The complex tensors are represented by pairs of float tensors holding the real and the
+/// imaginary parts.
+///
+/// This trait let backend implementations override the default implementation, which computes
+/// the discrete Fourier transform with matrix multiplications and is slow for long signals.
+pub trait FftOps<B: Backend> {
+    /// Computes the unnormalized discrete Fourier transform of complex tensors along a dimension.
+    ///
+    /// # Arguments
+    ///
+    /// * `re` - The real part of the input.
+    /// * `im` - The imaginary part of the input, with the same shape as the real part.
+    /// * `dim` - The dimension along which the transform is computed.
+    /// * `inverse` - If true, computes the inverse transform, i.e. with a positive exponent.
+    ///   The result isn't divided by the size of the dimension.
+    ///
+    /// # Returns
+    ///
+    /// The real and the imaginary parts of the transform, with the same shape as the input.
+    fn fft<const D: usize>(
+        re: FloatTensor<B, D>,
+        im: FloatTensor<B, D>,
+        dim: usize,
+        inverse: bool,
+    ) -> (FloatTensor<B, D>, FloatTensor<B, D>) {
+        let re = Tensor::<B, D>::new(TensorPrimitive::Float(re));
+        let im = Tensor::<B, D>::new(TensorPrimitive::Float(im));
+        let (re, im) = fallback::dft(re, im, dim, inverse);
+
+        (re.into_primitive().tensor(), im.into_primitive().tensor())
+    }
+}
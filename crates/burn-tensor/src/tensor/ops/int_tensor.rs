@@ -9,7 +9,7 @@ use alloc::vec::Vec;
 use core::future::Future;
 use core::ops::Range;
 
-use crate::{argsort, sort, sort_with_indices};
+use crate::{argsort, cummax, cummin, cumprod, cumsum, sort, sort_with_indices};
 
 /// Int Tensor API for basic and numeric operations, see [tensor](crate::Tensor)
 /// for documentation on each function.
@@ -659,6 +659,46 @@ pub trait IntTensorOps<B: Backend> {
     /// The result of the division.
     fn int_div_scalar<const D: usize>(lhs: IntTensor<B, D>, rhs: IntElem<B>) -> IntTensor<B, D>;
 
+    /// Element-wise division rounding towards negative infinity.
+    ///
+    /// # Arguments
+    ///
+    /// * `lhs` - The left hand side tensor.
+    /// * `rhs` - The right hand side tensor.
+    ///
+    /// # Returns
+    ///
+    /// The floor of the division.
+    fn int_floor_div<const D: usize>(lhs: IntTensor<B, D>, rhs: IntTensor<B, D>)
+        -> IntTensor<B, D>;
+
+    /// Element-wise division with a scalar rounding towards negative infinity.
+    ///
+    /// # Arguments
+    ///
+    /// * `lhs` - The left hand side tensor.
+    /// * `rhs` - The right hand side scalar.
+    ///
+    /// # Returns
+    ///
+    /// The floor of the division.
+    fn int_floor_div_scalar<const D: usize>(
+        lhs: IntTensor<B, D>,
+        rhs: IntElem<B>,
+    ) -> IntTensor<B, D>;
+
+    /// Element-wise modulus, with the sign of the divisor.
+    ///
+    /// # Arguments
+    /// * `lhs` - The left hand side tensor.
+    /// * `rhs` - The right hand side tensor.
+    ///
+    /// # Returns
+    ///
+    /// The remainder of the floor division of the tensors.
+    fn int_remainder<const D: usize>(lhs: IntTensor<B, D>, rhs: IntTensor<B, D>)
+        -> IntTensor<B, D>;
+
     /// Element-wise modulus with a scalar.
     ///
     /// # Arguments
@@ -1279,4 +1319,64 @@ pub trait IntTensorOps<B: Backend> {
     ) -> IntTensor<B, D> {
         argsort::<B, D, Int>(tensor, dim, descending)
     }
+
+    /// Computes the cumulative sum of the elements of the int `tensor` along a given dimension.
+    ///
+    /// # Arguments
+    ///
+    /// * `tensor` - The input tensor.
+    /// * `dim` - The axis along which to accumulate.
+    ///
+    /// # Returns
+    ///
+    /// A tensor with the same shape as the input tensor, where each element is the sum of the
+    /// elements up to its position along the dimension.
+    fn int_cumsum<const D: usize>(tensor: IntTensor<B, D>, dim: usize) -> IntTensor<B, D> {
+        cumsum::<B, D, Int>(tensor, dim)
+    }
+
+    /// Computes the cumulative product of the elements of the int `tensor` along a given dimension.
+    ///
+    /// # Arguments
+    ///
+    /// * `tensor` - The input tensor.
+    /// * `dim` - The axis along which to accumulate.
+    ///
+    /// # Returns
+    ///
+    /// A tensor with the same shape as the input tensor, where each element is the product of the
+    /// elements up to its position along the dimension.
+    fn int_cumprod<const D: usize>(tensor: IntTensor<B, D>, dim: usize) -> IntTensor<B, D> {
+        cumprod::<B, D, Int>(tensor, dim)
+    }
+
+    /// Computes the cumulative maximum of the elements of the int `tensor` along a given dimension.
+    ///
+    /// # Arguments
+    ///
+    /// * `tensor` - The input tensor.
+    /// * `dim` - The axis along which to accumulate.
+    ///
+    /// # Returns
+    ///
+    /// A tensor with the same shape as the input tensor, where each element is the maximum of the
+    /// elements up to its position along the dimension.
+    fn int_cummax<const D: usize>(tensor: IntTensor<B, D>, dim: usize) -> IntTensor<B, D> {
+        cummax::<B, D, Int>(tensor, dim)
+    }
+
+    /// Computes the cumulative minimum of the elements of the int `tensor` along a given dimension.
+    ///
+    /// # Arguments
+    ///
+    /// * `tensor` - The input tensor.
+    /// * `dim` - The axis along which to accumulate.
+    ///
+    /// # Returns
+    ///
+    /// A tensor with the same shape as the input tensor, where each element is the minimum of the
+    /// elements up to its position along the dimension.
+    fn int_cummin<const D: usize>(tensor: IntTensor<B, D>, dim: usize) -> IntTensor<B, D> {
+        cummin::<B, D, Int>(tensor, dim)
+    }
 }
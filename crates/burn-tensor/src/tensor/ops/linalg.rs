@@ -0,0 +1,163 @@
+use crate::{backend::Backend, linalg::fallback, Tensor, TensorPrimitive};
+
+use super::FloatTensor;
+
+/// Linear algebra operations.
+///
+/// The operations are applied to the matrices stored in the last two dimensions of the tensors,
+/// the other dimensions being batch dimensions.
+///
+/// This trait let backend implementations override the default implementations, which are built
+/// from the tensor operations and are slow for large matrices.
+pub trait LinalgOps<B: Backend> {
+    /// Computes the inverse of the matrices.
+    ///
+    /// # Arguments
+    ///
+    /// * `tensor` - The square matrices, with a shape of `[..., n, n]`.
+    ///
+    /// # Returns
+    ///
+    /// The inverse matrices.
+    fn linalg_inv<const D: usize>(tensor: FloatTensor<B, D>) -> FloatTensor<B, D> {
+        fallback::inv(float::<B, D>(tensor))
+            .into_primitive()
+            .tensor()
+    }
+
+    /// Computes the determinant of the matrices.
+    ///
+    /// # Arguments
+    ///
+    /// * `tensor` - The square matrices, with a shape of `[..., n, n]`.
+    ///
+    /// # Returns
+    ///
+    /// The determinants, with a shape of `[..., 1, 1]`.
+    fn linalg_det<const D: usize>(tensor: FloatTensor<B, D>) -> FloatTensor<B, D> {
+        fallback::det(float::<B, D>(tensor))
+            .into_primitive()
+            .tensor()
+    }
+
+    /// Computes the sign and the logarithm of the absolute value of the determinant of the
+    /// matrices.
+    ///
+    /// # Arguments
+    ///
+    /// * `tensor` - The square matrices, with a shape of `[..., n, n]`.
+    ///
+    /// # Returns
+    ///
+    /// The signs and the logarithms, both with a shape of `[..., 1, 1]`.
+    fn linalg_slogdet<const D: usize>(
+        tensor: FloatTensor<B, D>,
+    ) -> (FloatTensor<B, D>, FloatTensor<B, D>) {
+        let (sign, logabsdet) = fallback::slogdet(float::<B, D>(tensor));
+
+        (
+            sign.into_primitive().tensor(),
+            logabsdet.into_primitive().tensor(),
+        )
+    }
+
+    /// Solves the linear systems `A X = B`.
+    ///
+    /// # Arguments
+    ///
+    /// * `lhs` - The square matrices `A`, with a shape of `[..., n, n]`.
+    /// * `rhs` - The right-hand sides `B`, with a shape of `[..., n, k]`.
+    ///
+    /// # Returns
+    ///
+    /// The solutions `X`, with a shape of `[..., n, k]`.
+    fn linalg_solve<const D: usize>(
+        lhs: FloatTensor<B, D>,
+        rhs: FloatTensor<B, D>,
+    ) -> FloatTensor<B, D> {
+        fallback::solve(float::<B, D>(lhs), float::<B, D>(rhs))
+            .into_primitive()
+            .tensor()
+    }
+
+    /// Computes the Cholesky decomposition of symmetric positive-definite matrices.
+    ///
+    /// # Arguments
+    ///
+    /// * `tensor` - The symmetric positive-definite matrices, with a shape of `[..., n, n]`.
+    ///
+    /// # Returns
+    ///
+    /// The lower triangular matrices `L` such that `A = L L^T`.
+    fn linalg_cholesky<const D: usize>(tensor: FloatTensor<B, D>) -> FloatTensor<B, D> {
+        fallback::cholesky(float::<B, D>(tensor))
+            .into_primitive()
+            .tensor()
+    }
+
+    /// Computes the reduced QR decomposition of the matrices.
+    ///
+    /// # Arguments
+    ///
+    /// * `tensor` - The matrices, with a shape of `[..., m, n]`.
+    ///
+    /// # Returns
+    ///
+    /// The matrices `Q` with orthonormal columns, with a shape of `[..., m, k]`, and the upper
+    /// triangular matrices `R`, with a shape of `[..., k, n]`, where `k = min(m, n)`.
+    fn linalg_qr<const D: usize>(
+        tensor: FloatTensor<B, D>,
+    ) -> (FloatTensor<B, D>, FloatTensor<B, D>) {
+        let (q, r) = fallback::qr(float::<B, D>(tensor));
+
+        (q.into_primitive().tensor(), r.into_primitive().tensor())
+    }
+
+    /// Computes the reduced singular value decomposition of the matrices.
+    ///
+    /// # Arguments
+    ///
+    /// * `tensor` - The matrices, with a shape of `[..., m, n]`.
+    ///
+    /// # Returns
+    ///
+    /// The matrices `U` with orthonormal columns, with a shape of `[..., m, k]`, the singular
+    /// values in descending order, with a shape of `[..., 1, k]`, and the matrices `Vh` with
+    /// orthonormal rows, with a shape of `[..., k, n]`, where `k = min(m, n)`.
+    fn linalg_svd<const D: usize>(
+        tensor: FloatTensor<B, D>,
+    ) -> (FloatTensor<B, D>, FloatTensor<B, D>, FloatTensor<B, D>) {
+        let (u, s, vh) = fallback::svd(float::<B, D>(tensor));
+
+        (
+            u.into_primitive().tensor(),
+            s.into_primitive().tensor(),
+            vh.into_primitive().tensor(),
+        )
+    }
+
+    /// Computes the eigenvalues and the eigenvectors of symmetric matrices.
+    ///
+    /// # Arguments
+    ///
+    /// * `tensor` - The symmetric matrices, with a shape of `[..., n, n]`.
+    ///
+    /// # Returns
+    ///
+    /// The eigenvalues in ascending order, with a shape of `[..., 1, n]`, and the matrices of the
+    /// eigenvectors stored as columns, with a shape of `[..., n, n]`.
+    fn linalg_eigh<const D: usize>(
+        tensor: FloatTensor<B, D>,
+    ) -> (FloatTensor<B, D>, FloatTensor<B, D>) {
+        let (values, vectors) = fallback::eigh(float::<B, D>(tensor));
+
+        (
+            values.into_primitive().tensor(),
+            vectors.into_primitive().tensor(),
+        )
+    }
+}
+
+fn float<B: Backend, const D: usize>(tensor: FloatTensor<B, D>) -> Tensor<B, D> {
+    Tensor::new(TensorPrimitive::Float(tensor))
+}
@@ -1,7 +1,9 @@
 mod activation;
 mod alias;
 mod bool_tensor;
+mod fft;
 mod int_tensor;
+mod linalg;
 mod modules;
 mod qtensor;
 mod tensor;
@@ -9,7 +11,9 @@ mod tensor;
 pub use activation::*;
 pub use alias::*;
 pub use bool_tensor::*;
+pub use fft::*;
 pub use int_tensor::*;
+pub use linalg::*;
 pub use modules::*;
 pub use qtensor::*;
 pub use tensor::*;
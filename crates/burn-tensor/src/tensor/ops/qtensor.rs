@@ -2,7 +2,7 @@ use core::future::Future;
 
 use crate::{backend::Backend, Device, QuantizationStrategy, Shape, TensorData};
 
-use super::{FloatTensor, QuantizedTensor};
+use super::{ConvOptions, FloatTensor, QuantizedTensor};
 
 /// Quantized Tensor API for basic operations, see [tensor](crate::Tensor)
 /// for documentation on each function.
@@ -31,6 +31,28 @@ pub trait QTensorOps<B: Backend> {
         strategy: &QuantizationStrategy,
     ) -> FloatTensor<B, D>;
 
+    /// Simulate the quantization of the tensor in floating point precision.
+    ///
+    /// The values are quantized and dequantized right away, which models the quantization error
+    /// while keeping the tensor in floating point precision. Backends with gradient support should
+    /// use the straight-through estimator in the backward pass, so the gradient flows through the
+    /// values within the quantization range.
+    ///
+    /// # Arguments
+    ///
+    /// * `tensor` - The tensor to fake quantize.
+    /// * `strategy` - The quantization strategy.
+    ///
+    /// # Returns
+    ///
+    /// The tensor with the values rounded to the quantization grid.
+    fn fake_quantize<const D: usize>(
+        tensor: FloatTensor<B, D>,
+        strategy: &QuantizationStrategy,
+    ) -> FloatTensor<B, D> {
+        B::dequantize(B::quantize(tensor, strategy), strategy)
+    }
+
     /// Gets the shape of the tensor.
     ///
     /// # Arguments
@@ -82,6 +104,132 @@ pub trait QTensorOps<B: Backend> {
         strategy: QuantizationStrategy,
     ) -> impl Future<Output = TensorData> + Send;
 
+    /// Applies the matrix multiplication of two quantized tensors.
+    ///
+    /// Backends with integer support should accumulate the products of the quantized values in
+    /// `int32` before requantizing the result, which is only possible when the lhs parameters are
+    /// per-tensor (or per-row) and the rhs parameters are per-tensor (or per-column). By default,
+    /// the tensors are dequantized to perform the multiplication in floating point precision.
+    ///
+    /// # Arguments
+    ///
+    /// * `lhs` - The left hand side quantized tensor.
+    /// * `lhs_strategy` - The quantization strategy of the left hand side tensor.
+    /// * `rhs` - The right hand side quantized tensor.
+    /// * `rhs_strategy` - The quantization strategy of the right hand side tensor.
+    /// * `out_strategy` - The quantization strategy of the output tensor.
+    ///
+    /// # Returns
+    ///
+    /// The result of the matrix multiplication, quantized with the output strategy.
+    fn q_matmul<const D: usize>(
+        lhs: QuantizedTensor<B, D>,
+        lhs_strategy: &QuantizationStrategy,
+        rhs: QuantizedTensor<B, D>,
+        rhs_strategy: &QuantizationStrategy,
+        out_strategy: &QuantizationStrategy,
+    ) -> QuantizedTensor<B, D> {
+        let lhs = B::dequantize(lhs, lhs_strategy);
+        let rhs = B::dequantize(rhs, rhs_strategy);
+        B::quantize(B::float_matmul(lhs, rhs), out_strategy)
+    }
+
+    /// Applies a 2D convolution on quantized tensors.
+    ///
+    /// Backends with integer support should accumulate the products of the quantized values in
+    /// `int32` before requantizing the result, which is only possible when the input parameters
+    /// are per-tensor and the weight parameters are per-tensor (or per output channel). By default,
+    /// the tensors are dequantized to perform the convolution in floating point precision.
+    ///
+    /// # Arguments
+    ///
+    /// * `x` - The quantized input tensor.
+    /// * `x_strategy` - The quantization strategy of the input tensor.
+    /// * `weight` - The quantized weight tensor.
+    /// * `weight_strategy` - The quantization strategy of the weight tensor.
+    /// * `bias` - The optional (floating point) bias.
+    /// * `options` - The convolution options.
+    /// * `out_strategy` - The quantization strategy of the output tensor.
+    ///
+    /// # Returns
+    ///
+    /// The result of the convolution, quantized with the output strategy.
+    fn q_conv2d(
+        x: QuantizedTensor<B, 4>,
+        x_strategy: &QuantizationStrategy,
+        weight: QuantizedTensor<B, 4>,
+        weight_strategy: &QuantizationStrategy,
+        bias: Option<FloatTensor<B, 1>>,
+        options: ConvOptions<2>,
+        out_strategy: &QuantizationStrategy,
+    ) -> QuantizedTensor<B, 4> {
+        let x = B::dequantize(x, x_strategy);
+        let weight = B::dequantize(weight, weight_strategy);
+        B::quantize(B::conv2d(x, weight, bias, options), out_strategy)
+    }
+
+    /// Applies a linear transformation on quantized tensors.
+    ///
+    /// The weight should be broadcastable to the input, with the input features on its second to
+    /// last dimension. Without bias, this is the [quantized matrix multiplication](Self::q_matmul),
+    /// otherwise the tensors are dequantized by default to add the bias in floating point
+    /// precision.
+    ///
+    /// # Arguments
+    ///
+    /// * `x` - The quantized input tensor.
+    /// * `x_strategy` - The quantization strategy of the input tensor.
+    /// * `weight` - The quantized weight tensor.
+    /// * `weight_strategy` - The quantization strategy of the weight tensor.
+    /// * `bias` - The optional (floating point) bias.
+    /// * `out_strategy` - The quantization strategy of the output tensor.
+    ///
+    /// # Returns
+    ///
+    /// The result of the linear transformation, quantized with the output strategy.
+    fn q_linear<const D: usize>(
+        x: QuantizedTensor<B, D>,
+        x_strategy: &QuantizationStrategy,
+        weight: QuantizedTensor<B, D>,
+        weight_strategy: &QuantizationStrategy,
+        bias: Option<FloatTensor<B, 1>>,
+        out_strategy: &QuantizationStrategy,
+    ) -> QuantizedTensor<B, D> {
+        let Some(bias) = bias else {
+            return B::q_matmul(x, x_strategy, weight, weight_strategy, out_strategy);
+        };
+
+        let x = B::dequantize(x, x_strategy);
+        let weight = B::dequantize(weight, weight_strategy);
+        let mut dims = [1; D];
+        dims[D - 1] = B::float_shape(&bias).dims[0];
+        let bias = B::float_reshape(bias, Shape::new(dims));
+
+        B::quantize(B::float_add(B::float_matmul(x, weight), bias), out_strategy)
+    }
+
+    /// Applies the rectified linear unit function on a quantized tensor.
+    ///
+    /// Since the quantized value of zero is the offset of the quantization parameters, the
+    /// result keeps the strategy of the input. By default, the tensor is dequantized to apply the
+    /// function in floating point precision.
+    ///
+    /// # Arguments
+    ///
+    /// * `tensor` - The quantized tensor.
+    /// * `strategy` - The quantization strategy of the tensor.
+    ///
+    /// # Returns
+    ///
+    /// The quantized result, with the same strategy as the input.
+    fn q_relu<const D: usize>(
+        tensor: QuantizedTensor<B, D>,
+        strategy: &QuantizationStrategy,
+    ) -> QuantizedTensor<B, D> {
+        let tensor = B::dequantize(tensor, strategy);
+        B::quantize(B::relu(tensor), strategy)
+    }
+
     /// Sets the `require_grad` flag of a tensor.
     fn q_set_require_grad<const D: usize>(
         tensor: QuantizedTensor<B, D>,
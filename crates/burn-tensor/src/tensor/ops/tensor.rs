@@ -10,7 +10,7 @@ use alloc::vec::Vec;
 use core::future::Future;
 use core::ops::Range;
 
-use crate::{argsort, sort, sort_with_indices};
+use crate::{argsort, cummax, cummin, cumprod, cumsum, sort, sort_with_indices};
 
 /// Operations on float tensors.
 pub trait FloatTensorOps<B: Backend> {
@@ -371,6 +371,38 @@ pub trait FloatTensorOps<B: Backend> {
         rhs: FloatElem<B>,
     ) -> FloatTensor<B, D>;
 
+    /// Computes the element-wise remainder of the truncated division of two tensors.
+    ///
+    /// The result has the same sign as the dividend, like C's `fmod`.
+    ///
+    /// # Arguments
+    /// * `lhs` - The left hand side tensor.
+    /// * `rhs` - The right hand side tensor.
+    ///
+    /// # Returns
+    ///
+    /// The remainder of `lhs` divided by `rhs`.
+    fn float_fmod<const D: usize>(
+        lhs: FloatTensor<B, D>,
+        rhs: FloatTensor<B, D>,
+    ) -> FloatTensor<B, D>;
+
+    /// Computes the remainder of the truncated division of a tensor by a scalar.
+    ///
+    /// The result has the same sign as the dividend, like C's `fmod`.
+    ///
+    /// # Arguments
+    /// * `lhs` - The left hand side tensor.
+    /// * `rhs` - The right hand side scalar.
+    ///
+    /// # Returns
+    ///
+    /// The remainder of `lhs` divided by `rhs`.
+    fn float_fmod_scalar<const D: usize>(
+        lhs: FloatTensor<B, D>,
+        rhs: FloatElem<B>,
+    ) -> FloatTensor<B, D>;
+
     /// Multiplies two tensors together using matrix multiplication.
     ///
     /// # Arguments
@@ -1079,6 +1111,52 @@ pub trait FloatTensorOps<B: Backend> {
     /// A tensor with the same shape as `tensor` with error function values.
     fn float_erf<const D: usize>(tensor: FloatTensor<B, D>) -> FloatTensor<B, D>;
 
+    /// Returns a new tensor with the largest integer values less than or equal to each element.
+    ///
+    /// # Arguments
+    ///
+    /// * `tensor` - The tensor to round down.
+    ///
+    /// # Returns
+    ///
+    /// A tensor with the same shape as `tensor` with floor values.
+    fn float_floor<const D: usize>(tensor: FloatTensor<B, D>) -> FloatTensor<B, D>;
+
+    /// Returns a new tensor with the smallest integer values greater than or equal to each element.
+    ///
+    /// # Arguments
+    ///
+    /// * `tensor` - The tensor to round up.
+    ///
+    /// # Returns
+    ///
+    /// A tensor with the same shape as `tensor` with ceil values.
+    fn float_ceil<const D: usize>(tensor: FloatTensor<B, D>) -> FloatTensor<B, D>;
+
+    /// Returns a new tensor with each element rounded to the nearest integer.
+    ///
+    /// Halfway values are rounded to the nearest even integer.
+    ///
+    /// # Arguments
+    ///
+    /// * `tensor` - The tensor to round.
+    ///
+    /// # Returns
+    ///
+    /// A tensor with the same shape as `tensor` with rounded values.
+    fn float_round<const D: usize>(tensor: FloatTensor<B, D>) -> FloatTensor<B, D>;
+
+    /// Returns a new tensor with the integer part of each element, rounding towards zero.
+    ///
+    /// # Arguments
+    ///
+    /// * `tensor` - The tensor to truncate.
+    ///
+    /// # Returns
+    ///
+    /// A tensor with the same shape as `tensor` with truncated values.
+    fn float_trunc<const D: usize>(tensor: FloatTensor<B, D>) -> FloatTensor<B, D>;
+
     /// Concatenates tensors along a dimension.
     ///
     /// # Arguments
@@ -1439,4 +1517,64 @@ pub trait FloatTensorOps<B: Backend> {
     ) -> IntTensor<B, D> {
         argsort::<B, D, Float>(TensorPrimitive::Float(tensor), dim, descending)
     }
+
+    /// Computes the cumulative sum of the elements of the float `tensor` along a given dimension.
+    ///
+    /// # Arguments
+    ///
+    /// * `tensor` - The input tensor.
+    /// * `dim` - The axis along which to accumulate.
+    ///
+    /// # Returns
+    ///
+    /// A tensor with the same shape as the input tensor, where each element is the sum of the
+    /// elements up to its position along the dimension.
+    fn float_cumsum<const D: usize>(tensor: FloatTensor<B, D>, dim: usize) -> FloatTensor<B, D> {
+        cumsum::<B, D, Float>(TensorPrimitive::Float(tensor), dim).tensor()
+    }
+
+    /// Computes the cumulative product of the elements of the float `tensor` along a given dimension.
+    ///
+    /// # Arguments
+    ///
+    /// * `tensor` - The input tensor.
+    /// * `dim` - The axis along which to accumulate.
+    ///
+    /// # Returns
+    ///
+    /// A tensor with the same shape as the input tensor, where each element is the product of the
+    /// elements up to its position along the dimension.
+    fn float_cumprod<const D: usize>(tensor: FloatTensor<B, D>, dim: usize) -> FloatTensor<B, D> {
+        cumprod::<B, D, Float>(TensorPrimitive::Float(tensor), dim).tensor()
+    }
+
+    /// Computes the cumulative maximum of the elements of the float `tensor` along a given dimension.
+    ///
+    /// # Arguments
+    ///
+    /// * `tensor` - The input tensor.
+    /// * `dim` - The axis along which to accumulate.
+    ///
+    /// # Returns
+    ///
+    /// A tensor with the same shape as the input tensor, where each element is the maximum of the
+    /// elements up to its position along the dimension.
+    fn float_cummax<const D: usize>(tensor: FloatTensor<B, D>, dim: usize) -> FloatTensor<B, D> {
+        cummax::<B, D, Float>(TensorPrimitive::Float(tensor), dim).tensor()
+    }
+
+    /// Computes the cumulative minimum of the elements of the float `tensor` along a given dimension.
+    ///
+    /// # Arguments
+    ///
+    /// * `tensor` - The input tensor.
+    /// * `dim` - The axis along which to accumulate.
+    ///
+    /// # Returns
+    ///
+    /// A tensor with the same shape as the input tensor, where each element is the minimum of the
+    /// elements up to its position along the dimension.
+    fn float_cummin<const D: usize>(tensor: FloatTensor<B, D>, dim: usize) -> FloatTensor<B, D> {
+        cummin::<B, D, Float>(TensorPrimitive::Float(tensor), dim).tensor()
+    }
 }
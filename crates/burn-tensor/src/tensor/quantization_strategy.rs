@@ -4,17 +4,463 @@ use core::{
 };
 
 use alloc::vec::Vec;
-use burn_common::{iter_par, run_par};
 use num_traits::{Float, PrimInt};
 use serde::{Deserialize, Serialize};
 
 /// Quantization strategy.
-#[derive(Debug, Clone, Copy, Hash, PartialEq, Eq, Serialize, Deserialize)]
+#[derive(Debug, Clone, Hash, PartialEq, Eq, Serialize, Deserialize)]
 pub enum QuantizationStrategy {
     /// Per-tensor `int8` affine/asymmetric quantization.
     PerTensorAffineInt8(AffineQuantization<f32, i8, i32>),
     /// Per-tensor `int8` symmetric quantization.
     PerTensorSymmetricInt8(SymmetricQuantization<f32, i8>),
+    /// Per-tensor `uint8` affine/asymmetric quantization.
+    PerTensorAffineUInt8(AffineQuantization<f32, u8, i32>),
+    /// Per-channel `int8` affine/asymmetric quantization.
+    PerChannelAffineInt8(PerChannelQuantization<AffineQuantization<f32, i8, i32>>),
+    /// Per-channel `int8` symmetric quantization.
+    PerChannelSymmetricInt8(PerChannelQuantization<SymmetricQuantization<f32, i8>>),
+    /// Per-channel `uint8` affine/asymmetric quantization.
+    PerChannelAffineUInt8(PerChannelQuantization<AffineQuantization<f32, u8, i32>>),
+    /// Per-group `int8` affine/asymmetric quantization.
+    PerGroupAffineInt8(PerGroupQuantization<AffineQuantization<f32, i8, i32>>),
+    /// Per-group `int8` symmetric quantization.
+    PerGroupSymmetricInt8(PerGroupQuantization<SymmetricQuantization<f32, i8>>),
+    /// Per-group `uint8` affine/asymmetric quantization.
+    PerGroupAffineUInt8(PerGroupQuantization<AffineQuantization<f32, u8, i32>>),
+    /// Per-group `int4` affine/asymmetric quantization, packed two values per byte.
+    PerGroupAffineInt4(PerGroupQuantization<AffineQuantization<f32, i8, i32, 4>>),
+    /// Per-group `int4` symmetric quantization, packed two values per byte.
+    PerGroupSymmetricInt4(PerGroupQuantization<SymmetricQuantization<f32, i8, 4>>),
+    /// Per-group `uint4` affine/asymmetric quantization, packed two values per byte.
+    PerGroupAffineUInt4(PerGroupQuantization<AffineQuantization<f32, u8, i32, 4>>),
+}
+
+impl QuantizationStrategy {
+    /// Convert the values to a lower precision data type.
+    ///
+    /// The `shape` of the values is required to locate the channels of per-channel strategies.
+    pub fn quantize(&self, values: &[f32], shape: &[usize]) -> Vec<i32> {
+        match self {
+            Self::PerTensorAffineInt8(strategy) => to_i32(strategy.quantize(values)),
+            Self::PerTensorSymmetricInt8(strategy) => to_i32(strategy.quantize(values)),
+            Self::PerTensorAffineUInt8(strategy) => to_i32(strategy.quantize(values)),
+            Self::PerChannelAffineInt8(strategy) => to_i32(strategy.quantize(values, shape)),
+            Self::PerChannelSymmetricInt8(strategy) => to_i32(strategy.quantize(values, shape)),
+            Self::PerChannelAffineUInt8(strategy) => to_i32(strategy.quantize(values, shape)),
+            Self::PerGroupAffineInt8(strategy) => to_i32(strategy.quantize(values)),
+            Self::PerGroupSymmetricInt8(strategy) => to_i32(strategy.quantize(values)),
+            Self::PerGroupAffineUInt8(strategy) => to_i32(strategy.quantize(values)),
+            Self::PerGroupAffineInt4(strategy) => to_i32(strategy.quantize(values)),
+            Self::PerGroupSymmetricInt4(strategy) => to_i32(strategy.quantize(values)),
+            Self::PerGroupAffineUInt4(strategy) => to_i32(strategy.quantize(values)),
+        }
+    }
+
+    /// Convert the values back to a higher precision data type.
+    ///
+    /// The `shape` of the values is required to locate the channels of per-channel strategies.
+    pub fn dequantize(&self, values: &[i32], shape: &[usize]) -> Vec<f32> {
+        match self {
+            Self::PerTensorAffineInt8(strategy) => strategy.dequantize(&from_i32(values)),
+            Self::PerTensorSymmetricInt8(strategy) => strategy.dequantize(&from_i32(values)),
+            Self::PerTensorAffineUInt8(strategy) => strategy.dequantize(&from_i32(values)),
+            Self::PerChannelAffineInt8(strategy) => {
+                strategy.dequantize::<f32, i8>(&from_i32(values), shape)
+            }
+            Self::PerChannelSymmetricInt8(strategy) => {
+                strategy.dequantize::<f32, i8>(&from_i32(values), shape)
+            }
+            Self::PerChannelAffineUInt8(strategy) => {
+                strategy.dequantize::<f32, u8>(&from_i32(values), shape)
+            }
+            Self::PerGroupAffineInt8(strategy) => strategy.dequantize::<f32, i8>(&from_i32(values)),
+            Self::PerGroupSymmetricInt8(strategy) => {
+                strategy.dequantize::<f32, i8>(&from_i32(values))
+            }
+            Self::PerGroupAffineUInt8(strategy) => {
+                strategy.dequantize::<f32, u8>(&from_i32(values))
+            }
+            Self::PerGroupAffineInt4(strategy) => strategy.dequantize::<f32, i8>(&from_i32(values)),
+            Self::PerGroupSymmetricInt4(strategy) => {
+                strategy.dequantize::<f32, i8>(&from_i32(values))
+            }
+            Self::PerGroupAffineUInt4(strategy) => {
+                strategy.dequantize::<f32, u8>(&from_i32(values))
+            }
+        }
+    }
+
+    /// Returns the scale and zero-point offset of each channel along the given `axis` for 8-bit
+    /// per-tensor and per-channel strategies.
+    ///
+    /// Per-tensor strategies have a single channel, while per-channel strategies must be defined
+    /// along the given `axis`. Returns `None` for any other strategy, since its parameters cannot
+    /// be factored out of an integer accumulation along the other axes.
+    pub fn channel_params(&self, axis: usize) -> Option<(Vec<f32>, Vec<i32>)> {
+        fn affine<Q: PrimInt>(
+            channels: &[AffineQuantization<f32, Q, i32>],
+        ) -> (Vec<f32>, Vec<i32>) {
+            channels
+                .iter()
+                .map(|c| (c.scale, c.offset.to_i32().unwrap()))
+                .unzip()
+        }
+        fn symmetric(channels: &[SymmetricQuantization<f32, i8>]) -> (Vec<f32>, Vec<i32>) {
+            channels.iter().map(|c| (c.scale, 0)).unzip()
+        }
+
+        match self {
+            Self::PerTensorAffineInt8(q) => Some(affine(&[*q])),
+            Self::PerTensorSymmetricInt8(q) => Some(symmetric(&[*q])),
+            Self::PerTensorAffineUInt8(q) => Some(affine(&[*q])),
+            Self::PerChannelAffineInt8(q) if q.axis == axis => Some(affine(&q.channels)),
+            Self::PerChannelSymmetricInt8(q) if q.axis == axis => Some(symmetric(&q.channels)),
+            Self::PerChannelAffineUInt8(q) if q.axis == axis => Some(affine(&q.channels)),
+            _ => None,
+        }
+    }
+
+    /// The strategy of a tensor reshaped from the `from` shape to the `to` shape.
+    ///
+    /// The values keep their contiguous layout, so only the axis of per-channel strategies is
+    /// updated. Returns `None` when the reshape does not preserve the channels.
+    pub fn reshape(&self, from: &[usize], to: &[usize]) -> Option<Self> {
+        let axis = match self {
+            Self::PerChannelAffineInt8(q) => q.axis,
+            Self::PerChannelSymmetricInt8(q) => q.axis,
+            Self::PerChannelAffineUInt8(q) => q.axis,
+            _ => return Some(self.clone()),
+        };
+        let stride: usize = from[..axis].iter().product();
+
+        let mut product = 1;
+        let axis = to.iter().position(|dim| {
+            let found = product == stride && *dim == from[axis];
+            product *= dim;
+            found
+        })?;
+
+        let mut strategy = self.clone();
+        match &mut strategy {
+            Self::PerChannelAffineInt8(q) => q.axis = axis,
+            Self::PerChannelSymmetricInt8(q) => q.axis = axis,
+            Self::PerChannelAffineUInt8(q) => q.axis = axis,
+            _ => unreachable!(),
+        }
+        Some(strategy)
+    }
+
+    /// Returns the kind of the strategy, without its parameters.
+    pub fn kind(&self) -> QuantizationKind {
+        match self {
+            Self::PerTensorAffineInt8(_) => QuantizationKind::PerTensorAffineInt8,
+            Self::PerTensorSymmetricInt8(_) => QuantizationKind::PerTensorSymmetricInt8,
+            Self::PerTensorAffineUInt8(_) => QuantizationKind::PerTensorAffineUInt8,
+            Self::PerChannelAffineInt8(_) => QuantizationKind::PerChannelAffineInt8,
+            Self::PerChannelSymmetricInt8(_) => QuantizationKind::PerChannelSymmetricInt8,
+            Self::PerChannelAffineUInt8(_) => QuantizationKind::PerChannelAffineUInt8,
+            Self::PerGroupAffineInt8(_) => QuantizationKind::PerGroupAffineInt8,
+            Self::PerGroupSymmetricInt8(_) => QuantizationKind::PerGroupSymmetricInt8,
+            Self::PerGroupAffineUInt8(_) => QuantizationKind::PerGroupAffineUInt8,
+            Self::PerGroupAffineInt4(_) => QuantizationKind::PerGroupAffineInt4,
+            Self::PerGroupSymmetricInt4(_) => QuantizationKind::PerGroupSymmetricInt4,
+            Self::PerGroupAffineUInt4(_) => QuantizationKind::PerGroupAffineUInt4,
+        }
+    }
+
+    /// Returns the number of bits used to store each quantized value.
+    pub fn bits(&self) -> usize {
+        self.kind().bits()
+    }
+
+    /// Returns the range `[min, max]` of the quantized type.
+    pub fn value_range(&self) -> (i32, i32) {
+        self.kind().value_range()
+    }
+
+    /// Pack the quantized values into their storage format.
+    ///
+    /// 8-bit values are stored as one byte each, while 4-bit values are packed two per byte with
+    /// the first value in the low nibble.
+    pub fn pack(&self, values: &[i32]) -> Vec<u8> {
+        self.kind().pack(values)
+    }
+
+    /// Unpack the first `num_elements` quantized values from their storage format.
+    pub fn unpack(&self, bytes: &[u8], num_elements: usize) -> Vec<i32> {
+        self.kind().unpack(bytes, num_elements)
+    }
+
+    /// Serializes the parameters of the strategy (scales, offsets, axis and group size) as
+    /// little-endian bytes.
+    pub fn params_to_bytes(&self) -> Vec<u8> {
+        let mut bytes = Vec::new();
+        match self {
+            Self::PerTensorAffineInt8(q) => q.write(&mut bytes),
+            Self::PerTensorSymmetricInt8(q) => q.write(&mut bytes),
+            Self::PerTensorAffineUInt8(q) => q.write(&mut bytes),
+            Self::PerChannelAffineInt8(q) => q.write(&mut bytes),
+            Self::PerChannelSymmetricInt8(q) => q.write(&mut bytes),
+            Self::PerChannelAffineUInt8(q) => q.write(&mut bytes),
+            Self::PerGroupAffineInt8(q) => q.write(&mut bytes),
+            Self::PerGroupSymmetricInt8(q) => q.write(&mut bytes),
+            Self::PerGroupAffineUInt8(q) => q.write(&mut bytes),
+            Self::PerGroupAffineInt4(q) => q.write(&mut bytes),
+            Self::PerGroupSymmetricInt4(q) => q.write(&mut bytes),
+            Self::PerGroupAffineUInt4(q) => q.write(&mut bytes),
+        }
+        bytes
+    }
+
+    /// Deserializes a strategy of the given `kind` from the parameters written by
+    /// [params_to_bytes](Self::params_to_bytes).
+    ///
+    /// Returns `None` if the bytes do not hold the parameters of the strategy.
+    pub fn from_params_bytes(kind: QuantizationKind, bytes: &[u8]) -> Option<Self> {
+        let bytes = &mut &bytes[..];
+        let strategy = match kind {
+            QuantizationKind::PerTensorAffineInt8 => {
+                Self::PerTensorAffineInt8(Params::read(bytes)?)
+            }
+            QuantizationKind::PerTensorSymmetricInt8 => {
+                Self::PerTensorSymmetricInt8(Params::read(bytes)?)
+            }
+            QuantizationKind::PerTensorAffineUInt8 => {
+                Self::PerTensorAffineUInt8(Params::read(bytes)?)
+            }
+            QuantizationKind::PerChannelAffineInt8 => {
+                Self::PerChannelAffineInt8(Params::read(bytes)?)
+            }
+            QuantizationKind::PerChannelSymmetricInt8 => {
+                Self::PerChannelSymmetricInt8(Params::read(bytes)?)
+            }
+            QuantizationKind::PerChannelAffineUInt8 => {
+                Self::PerChannelAffineUInt8(Params::read(bytes)?)
+            }
+            QuantizationKind::PerGroupAffineInt8 => Self::PerGroupAffineInt8(Params::read(bytes)?),
+            QuantizationKind::PerGroupSymmetricInt8 => {
+                Self::PerGroupSymmetricInt8(Params::read(bytes)?)
+            }
+            QuantizationKind::PerGroupAffineUInt8 => {
+                Self::PerGroupAffineUInt8(Params::read(bytes)?)
+            }
+            QuantizationKind::PerGroupAffineInt4 => Self::PerGroupAffineInt4(Params::read(bytes)?),
+            QuantizationKind::PerGroupSymmetricInt4 => {
+                Self::PerGroupSymmetricInt4(Params::read(bytes)?)
+            }
+            QuantizationKind::PerGroupAffineUInt4 => {
+                Self::PerGroupAffineUInt4(Params::read(bytes)?)
+            }
+        };
+
+        bytes.is_empty().then_some(strategy)
+    }
+}
+
+/// The kind of a [quantization strategy](QuantizationStrategy), without its parameters.
+///
+/// This is the data type of quantized [tensor data](crate::TensorData), which stores the
+/// parameters of the strategy after the quantized values.
+#[derive(Debug, Clone, Copy, Hash, PartialEq, Eq, Serialize, Deserialize)]
+pub enum QuantizationKind {
+    /// Per-tensor `int8` affine/asymmetric quantization.
+    PerTensorAffineInt8,
+    /// Per-tensor `int8` symmetric quantization.
+    PerTensorSymmetricInt8,
+    /// Per-tensor `uint8` affine/asymmetric quantization.
+    PerTensorAffineUInt8,
+    /// Per-channel `int8` affine/asymmetric quantization.
+    PerChannelAffineInt8,
+    /// Per-channel `int8` symmetric quantization.
+    PerChannelSymmetricInt8,
+    /// Per-channel `uint8` affine/asymmetric quantization.
+    PerChannelAffineUInt8,
+    /// Per-group `int8` affine/asymmetric quantization.
+    PerGroupAffineInt8,
+    /// Per-group `int8` symmetric quantization.
+    PerGroupSymmetricInt8,
+    /// Per-group `uint8` affine/asymmetric quantization.
+    PerGroupAffineUInt8,
+    /// Per-group `int4` affine/asymmetric quantization, packed two values per byte.
+    PerGroupAffineInt4,
+    /// Per-group `int4` symmetric quantization, packed two values per byte.
+    PerGroupSymmetricInt4,
+    /// Per-group `uint4` affine/asymmetric quantization, packed two values per byte.
+    PerGroupAffineUInt4,
+}
+
+impl QuantizationKind {
+    /// Returns the number of bits used to store each quantized value.
+    pub fn bits(&self) -> usize {
+        match self {
+            Self::PerGroupAffineInt4 | Self::PerGroupSymmetricInt4 | Self::PerGroupAffineUInt4 => 4,
+            _ => 8,
+        }
+    }
+
+    /// Returns the range `[min, max]` of the quantized type.
+    pub fn value_range(&self) -> (i32, i32) {
+        let bits = self.bits() as u32;
+        match self {
+            Self::PerTensorAffineUInt8
+            | Self::PerChannelAffineUInt8
+            | Self::PerGroupAffineUInt8
+            | Self::PerGroupAffineUInt4 => (0, (1 << bits) - 1),
+            _ => (-(1 << (bits - 1)), (1 << (bits - 1)) - 1),
+        }
+    }
+
+    /// Returns the number of bytes used to store `num_elements` quantized values.
+    pub fn storage_len(&self, num_elements: usize) -> usize {
+        (num_elements * self.bits()).div_ceil(8)
+    }
+
+    /// Pack the quantized values into their storage format.
+    ///
+    /// 8-bit values are stored as one byte each, while 4-bit values are packed two per byte with
+    /// the first value in the low nibble.
+    pub fn pack(&self, values: &[i32]) -> Vec<u8> {
+        match self.bits() {
+            4 => values
+                .chunks(2)
+                .map(|pair| {
+                    let low = pair[0] as u8 & 0x0F;
+                    let high = pair.get(1).map_or(0, |x| *x as u8 & 0x0F);
+                    low | (high << 4)
+                })
+                .collect(),
+            _ => values.iter().map(|x| *x as u8).collect(),
+        }
+    }
+
+    /// Unpack the first `num_elements` quantized values from their storage format.
+    pub fn unpack(&self, bytes: &[u8], num_elements: usize) -> Vec<i32> {
+        let signed = self.value_range().0 < 0;
+        match self.bits() {
+            4 => bytes
+                .iter()
+                .flat_map(|x| [x & 0x0F, x >> 4])
+                .take(num_elements)
+                .map(|x| match signed {
+                    // Sign-extend the nibble
+                    true => ((x << 4) as i8 >> 4) as i32,
+                    false => x as i32,
+                })
+                .collect(),
+            _ => bytes
+                .iter()
+                .take(num_elements)
+                .map(|x| match signed {
+                    true => *x as i8 as i32,
+                    false => *x as i32,
+                })
+                .collect(),
+        }
+    }
+}
+
+/// Parameters of a quantization scheme that can be stored as bytes.
+trait Params: Sized {
+    fn write(&self, bytes: &mut Vec<u8>);
+    fn read(bytes: &mut &[u8]) -> Option<Self>;
+}
+
+fn read_bytes<const N: usize>(bytes: &mut &[u8]) -> Option<[u8; N]> {
+    if bytes.len() < N {
+        return None;
+    }
+    let (head, tail) = bytes.split_at(N);
+    *bytes = tail;
+    head.try_into().ok()
+}
+
+impl<Q: PrimInt, const B: usize> Params for AffineQuantization<f32, Q, i32, B> {
+    fn write(&self, bytes: &mut Vec<u8>) {
+        bytes.extend(self.scale.to_le_bytes());
+        bytes.extend(self.offset.to_i32().unwrap().to_le_bytes());
+    }
+
+    fn read(bytes: &mut &[u8]) -> Option<Self> {
+        let scale = f32::from_le_bytes(read_bytes(bytes)?);
+        let offset = Q::from(i32::from_le_bytes(read_bytes(bytes)?))?;
+        Some(Self::init(scale, offset))
+    }
+}
+
+impl<Q: PrimInt, const B: usize> Params for SymmetricQuantization<f32, Q, B> {
+    fn write(&self, bytes: &mut Vec<u8>) {
+        bytes.extend(self.scale.to_le_bytes());
+    }
+
+    fn read(bytes: &mut &[u8]) -> Option<Self> {
+        Some(Self::init(f32::from_le_bytes(read_bytes(bytes)?)))
+    }
+}
+
+/// Writes the size and the parameters of each slice of values.
+fn write_slices<S: Params>(size: usize, slices: &[S], bytes: &mut Vec<u8>) {
+    bytes.extend((size as u64).to_le_bytes());
+    bytes.extend((slices.len() as u64).to_le_bytes());
+    slices.iter().for_each(|s| s.write(bytes));
+}
+
+fn read_slices<S: Params>(bytes: &mut &[u8]) -> Option<(usize, Vec<S>)> {
+    let size = u64::from_le_bytes(read_bytes(bytes)?) as usize;
+    let len = u64::from_le_bytes(read_bytes(bytes)?) as usize;
+    let slices = (0..len).map(|_| S::read(bytes)).collect::<Option<_>>()?;
+    Some((size, slices))
+}
+
+impl<S: Params> Params for PerChannelQuantization<S> {
+    fn write(&self, bytes: &mut Vec<u8>) {
+        write_slices(self.axis, &self.channels, bytes)
+    }
+
+    fn read(bytes: &mut &[u8]) -> Option<Self> {
+        let (axis, channels) = read_slices(bytes)?;
+        Some(Self::new(axis, channels))
+    }
+}
+
+impl<S: Params> Params for PerGroupQuantization<S> {
+    fn write(&self, bytes: &mut Vec<u8>) {
+        write_slices(self.group_size, &self.groups, bytes)
+    }
+
+    fn read(bytes: &mut &[u8]) -> Option<Self> {
+        let (group_size, groups) = read_slices(bytes)?;
+        Some(Self::new(group_size, groups))
+    }
+}
+
+fn to_i32<Q: PrimInt>(values: Vec<Q>) -> Vec<i32> {
+    values.into_iter().map(|x| x.to_i32().unwrap()).collect()
+}
+
+fn from_i32<Q: PrimInt>(values: &[i32]) -> Vec<Q> {
+    values.iter().map(|x| Q::from(*x).unwrap()).collect()
+}
+
+/// Returns the range `[a, b]` of the values of a quantized type `Q` restricted to `B` bits.
+fn quantized_range<E: Float, Q: PrimInt, const B: usize>() -> (E, E) {
+    let bits = B.min(Q::zero().count_zeros() as usize);
+    if Q::min_value().is_zero() {
+        (E::zero(), E::from((1u64 << bits) - 1).unwrap())
+    } else {
+        let half = E::from(1u64 << (bits - 1)).unwrap();
+        (half.neg(), half - E::one())
+    }
+}
+
+/// Rounds to the nearest integer, with half-way cases rounded to the nearest even integer like the
+/// quantization kernels of the other backends.
+fn round_half_even<E: Float>(x: E) -> E {
+    if (x - x.trunc()).abs() == E::from(0.5).unwrap() {
+        (x / E::from(2).unwrap()).round() * E::from(2).unwrap()
+    } else {
+        x.round()
+    }
 }
 
 /// Quantization scheme to convert elements of a higher precision data type `E` to a lower precision
@@ -31,8 +477,9 @@ pub trait Quantization<E: Float, Q: PrimInt> {
 /// Affine quantization scheme.
 ///
 /// Note that the accumulation type `A` should have a bigger range than quantized type `Q`.
+/// The quantized values only use the `B` lower bits of `Q` (e.g., `B = 4` for `int4`).
 #[derive(Debug, Clone, Copy, Serialize, Deserialize)]
-pub struct AffineQuantization<E: Float, Q: PrimInt, A: PrimInt> {
+pub struct AffineQuantization<E: Float, Q: PrimInt, A: PrimInt, const B: usize = 8> {
     /// The scaling factor.
     pub scale: E,
     /// The zero-point offset.
@@ -41,14 +488,30 @@ pub struct AffineQuantization<E: Float, Q: PrimInt, A: PrimInt> {
     _a: PhantomData<A>,
 }
 
-impl<E: Float, Q: PrimInt, A: PrimInt> Quantization<E, Q> for AffineQuantization<E, Q, A> {
+impl<E: Float, Q: PrimInt, A: PrimInt, const B: usize> AffineQuantization<E, Q, A, B> {
+    /// Initialize an affine quantization scheme with an explicit scaling factor and zero-point offset.
+    pub fn init(scale: E, offset: Q) -> Self {
+        Self {
+            scale,
+            offset,
+            _a: PhantomData,
+        }
+    }
+}
+
+impl<E: Float, Q: PrimInt, A: PrimInt, const B: usize> Quantization<E, Q>
+    for AffineQuantization<E, Q, A, B>
+{
     fn new(alpha: E, beta: E) -> Self {
         // Q range `[a, b]`
-        let a = E::from(Q::min_value()).unwrap();
-        let b = E::from(Q::max_value()).unwrap();
+        let (a, b) = quantized_range::<E, Q, B>();
 
         // Compute scale and offset to convert a floating point value in range `[alpha, beta]` to the quantized range
         let range = beta - alpha;
+        if range.is_zero() {
+            // A constant range (e.g., a channel of zeros) would divide by zero
+            return Self::init(E::one(), Q::zero());
+        }
         Self {
             scale: range / (b - a),
             offset: Q::from(E::round(((beta * a) - (alpha * b)) / range)).unwrap(),
@@ -58,46 +521,55 @@ impl<E: Float, Q: PrimInt, A: PrimInt> Quantization<E, Q> for AffineQuantization
 
     fn quantize(&self, values: &[E]) -> Vec<Q> {
         // Quantized range `[a, b]`
-        let a = E::from(Q::min_value()).unwrap();
-        let b = E::from(Q::max_value()).unwrap();
+        let (a, b) = quantized_range::<E, Q, B>();
 
         // x_q = clamp(round(x / scale + offset), a, b)
         let z = E::from(self.offset).unwrap();
-        run_par!(|| {
-            iter_par!(values.iter())
-                .map(|x| Q::from(x.div(self.scale).add(z).round().clamp(a, b)).unwrap())
-                .collect()
-        })
+        values
+            .iter()
+            .map(|x| Q::from(round_half_even(x.div(self.scale).add(z)).clamp(a, b)).unwrap())
+            .collect()
     }
 
     fn dequantize(&self, values: &[Q]) -> Vec<E> {
         // x = scale * (x_q - offset)
-        run_par!(|| {
-            iter_par!(values.iter())
-                .map(|x_q| {
-                    self.scale
-                        * (E::from(
-                            A::from(*x_q)
-                                .unwrap()
-                                .saturating_sub(A::from(self.offset).unwrap()),
-                        )
-                        .unwrap())
-                })
-                .collect()
-        })
+        values
+            .iter()
+            .map(|x_q| {
+                self.scale
+                    * (E::from(
+                        A::from(*x_q)
+                            .unwrap()
+                            .saturating_sub(A::from(self.offset).unwrap()),
+                    )
+                    .unwrap())
+            })
+            .collect()
     }
 }
 
 /// Symmetric quantization scheme.
+///
+/// The quantized values only use the `B` lower bits of `Q` (e.g., `B = 4` for `int4`).
 #[derive(Debug, Clone, Copy, Serialize, Deserialize)]
-pub struct SymmetricQuantization<E: Float, Q: PrimInt> {
+pub struct SymmetricQuantization<E: Float, Q: PrimInt, const B: usize = 8> {
     /// The scaling factor.
     pub scale: E,
     /// The quantized type.
     _q: PhantomData<Q>,
 }
 
-impl<E: Float, Q: PrimInt> Quantization<E, Q> for SymmetricQuantization<E, Q> {
+impl<E: Float, Q: PrimInt, const B: usize> SymmetricQuantization<E, Q, B> {
+    /// Initialize a symmetric quantization scheme with an explicit scaling factor.
+    pub fn init(scale: E) -> Self {
+        Self {
+            scale,
+            _q: PhantomData,
+        }
+    }
+}
+
+impl<E: Float, Q: PrimInt, const B: usize> Quantization<E, Q> for SymmetricQuantization<E, Q, B> {
     fn new(alpha: E, beta: E) -> Self {
         assert!(
             !Q::min_value().is_zero(),
@@ -105,11 +577,15 @@ impl<E: Float, Q: PrimInt> Quantization<E, Q> for SymmetricQuantization<E, Q> {
         );
 
         // Quantized range `[a, b]`
-        let b = E::from(Q::max_value()).unwrap();
+        let (_, b) = quantized_range::<E, Q, B>();
         let a = b.neg();
 
         // Compute scale to convert a floating point value in range `[-alpha, alpha]` to the quantized range
         let alpha = alpha.abs().max(beta.abs());
+        if alpha.is_zero() {
+            // A range of zeros would divide by zero
+            return Self::init(E::one());
+        }
         Self {
             scale: (alpha + alpha) / (b - a),
             _q: PhantomData,
@@ -118,13 +594,13 @@ impl<E: Float, Q: PrimInt> Quantization<E, Q> for SymmetricQuantization<E, Q> {
 
     fn quantize(&self, values: &[E]) -> Vec<Q> {
         // Quantized range [a, b]
-        let b = E::from(Q::max_value()).unwrap();
+        let (_, b) = quantized_range::<E, Q, B>();
         let a = b.neg();
 
         // x_q = clamp(round(x / scale), a, b)
         values
             .iter()
-            .map(|x| Q::from(x.div(self.scale).round().clamp(a, b)).unwrap())
+            .map(|x| Q::from(round_half_even(x.div(self.scale)).clamp(a, b)).unwrap())
             .collect()
     }
 
@@ -137,6 +613,137 @@ impl<E: Float, Q: PrimInt> Quantization<E, Q> for SymmetricQuantization<E, Q> {
     }
 }
 
+/// Per-channel (per-axis) quantization scheme.
+///
+/// Each slice of the tensor along the quantization `axis` is mapped with its own quantization
+/// parameters `S`, which preserves much more precision than a single mapping for the whole tensor
+/// when the value ranges of the channels differ (e.g., the output channels of a weight tensor).
+#[derive(Debug, Clone, Hash, PartialEq, Eq, Serialize, Deserialize)]
+pub struct PerChannelQuantization<S> {
+    /// The axis of the channels.
+    pub axis: usize,
+    /// The quantization parameters of each channel.
+    pub channels: Vec<S>,
+}
+
+impl<S> PerChannelQuantization<S> {
+    /// Create a new per-channel quantization scheme from the quantization parameters of each
+    /// channel along the given axis.
+    pub fn new(axis: usize, channels: Vec<S>) -> Self {
+        Self { axis, channels }
+    }
+
+    /// Returns the number of contiguous values that belong to the same channel.
+    ///
+    /// # Panics
+    ///
+    /// If the shape does not match the number of channels along the quantization axis.
+    fn channel_size(&self, shape: &[usize]) -> usize {
+        assert!(
+            self.axis < shape.len(),
+            "Quantization axis {} is out of bounds for a tensor of rank {}",
+            self.axis,
+            shape.len()
+        );
+        assert_eq!(
+            shape[self.axis],
+            self.channels.len(),
+            "Expected {} channels along the quantization axis {}, got {}",
+            self.channels.len(),
+            self.axis,
+            shape[self.axis]
+        );
+        shape[self.axis + 1..].iter().product::<usize>().max(1)
+    }
+
+    /// Convert the values to a lower precision data type.
+    pub fn quantize<E: Float, Q: PrimInt>(&self, values: &[E], shape: &[usize]) -> Vec<Q>
+    where
+        S: Quantization<E, Q>,
+    {
+        let channel_size = self.channel_size(shape);
+        values
+            .chunks(channel_size)
+            .enumerate()
+            .flat_map(|(i, chunk)| self.channels[i % self.channels.len()].quantize(chunk))
+            .collect()
+    }
+
+    /// Convert the values back to a higher precision data type.
+    pub fn dequantize<E: Float, Q: PrimInt>(&self, values: &[Q], shape: &[usize]) -> Vec<E>
+    where
+        S: Quantization<E, Q>,
+    {
+        let channel_size = self.channel_size(shape);
+        values
+            .chunks(channel_size)
+            .enumerate()
+            .flat_map(|(i, chunk)| self.channels[i % self.channels.len()].dequantize(chunk))
+            .collect()
+    }
+}
+
+/// Per-group quantization scheme.
+///
+/// The flattened tensor values are split into contiguous groups of `group_size` values, each
+/// mapped with its own quantization parameters `S`. With a group size that divides the last
+/// dimension, the groups partition each row of a weight matrix, which keeps the error of low
+/// bit-width types (e.g., `int4`) small for large tensors.
+#[derive(Debug, Clone, Hash, PartialEq, Eq, Serialize, Deserialize)]
+pub struct PerGroupQuantization<S> {
+    /// The number of values in each group.
+    pub group_size: usize,
+    /// The quantization parameters of each group.
+    pub groups: Vec<S>,
+}
+
+impl<S> PerGroupQuantization<S> {
+    /// Create a new per-group quantization scheme from the quantization parameters of each group
+    /// of `group_size` values.
+    pub fn new(group_size: usize, groups: Vec<S>) -> Self {
+        assert!(group_size > 0, "Quantization group size must be positive");
+        Self { group_size, groups }
+    }
+
+    /// Asserts the number of groups matches the number of values.
+    fn check_groups(&self, num_elements: usize) {
+        assert_eq!(
+            num_elements.div_ceil(self.group_size),
+            self.groups.len(),
+            "Expected {} groups of {} values, got {} values",
+            self.groups.len(),
+            self.group_size,
+            num_elements
+        );
+    }
+
+    /// Convert the values to a lower precision data type.
+    pub fn quantize<E: Float, Q: PrimInt>(&self, values: &[E]) -> Vec<Q>
+    where
+        S: Quantization<E, Q>,
+    {
+        self.check_groups(values.len());
+        values
+            .chunks(self.group_size)
+            .zip(self.groups.iter())
+            .flat_map(|(chunk, group)| group.quantize(chunk))
+            .collect()
+    }
+
+    /// Convert the values back to a higher precision data type.
+    pub fn dequantize<E: Float, Q: PrimInt>(&self, values: &[Q]) -> Vec<E>
+    where
+        S: Quantization<E, Q>,
+    {
+        self.check_groups(values.len());
+        values
+            .chunks(self.group_size)
+            .zip(self.groups.iter())
+            .flat_map(|(chunk, group)| group.dequantize(chunk))
+            .collect()
+    }
+}
+
 // Masks for the parts of the IEEE 754 float
 const SIGN_MASK: u64 = 0x8000000000000000u64;
 const EXP_MASK: u64 = 0x7ff0000000000000u64;
@@ -160,7 +767,9 @@ fn canonicalize_signed_zero<T: Float>(x: T) -> T {
     x + T::zero()
 }
 
-impl<E: Float, Q: PrimInt + Hash, A: PrimInt> Hash for AffineQuantization<E, Q, A> {
+impl<E: Float, Q: PrimInt + Hash, A: PrimInt, const B: usize> Hash
+    for AffineQuantization<E, Q, A, B>
+{
     fn hash<H: Hasher>(&self, state: &mut H) {
         // Hash raw bits.
         let bits = raw_double_bits(&canonicalize_signed_zero(self.scale));
@@ -169,15 +778,17 @@ impl<E: Float, Q: PrimInt + Hash, A: PrimInt> Hash for AffineQuantization<E, Q,
     }
 }
 
-impl<E: Float, Q: PrimInt, A: PrimInt> PartialEq for AffineQuantization<E, Q, A> {
+impl<E: Float, Q: PrimInt, A: PrimInt, const B: usize> PartialEq
+    for AffineQuantization<E, Q, A, B>
+{
     fn eq(&self, other: &Self) -> bool {
         self.scale == other.scale && self.offset == other.offset
     }
 }
 
-impl<E: Float, Q: PrimInt, A: PrimInt> Eq for AffineQuantization<E, Q, A> {}
+impl<E: Float, Q: PrimInt, A: PrimInt, const B: usize> Eq for AffineQuantization<E, Q, A, B> {}
 
-impl<E: Float, Q: PrimInt> Hash for SymmetricQuantization<E, Q> {
+impl<E: Float, Q: PrimInt, const B: usize> Hash for SymmetricQuantization<E, Q, B> {
     fn hash<H: Hasher>(&self, state: &mut H) {
         // Hash raw bits.
         let bits = raw_double_bits(&canonicalize_signed_zero(self.scale));
@@ -185,13 +796,13 @@ impl<E: Float, Q: PrimInt> Hash for SymmetricQuantization<E, Q> {
     }
 }
 
-impl<E: Float, Q: PrimInt> PartialEq for SymmetricQuantization<E, Q> {
+impl<E: Float, Q: PrimInt, const B: usize> PartialEq for SymmetricQuantization<E, Q, B> {
     fn eq(&self, other: &Self) -> bool {
         self.scale == other.scale
     }
 }
 
-impl<E: Float, Q: PrimInt> Eq for SymmetricQuantization<E, Q> {}
+impl<E: Float, Q: PrimInt, const B: usize> Eq for SymmetricQuantization<E, Q, B> {}
 
 #[cfg(test)]
 mod tests {
@@ -214,6 +825,17 @@ mod tests {
         assert_eq!(d, expected_d);
     }
 
+    #[test]
+    fn test_int8_quantization_rounds_half_to_even() {
+        let x: [f32; 6] = [-2.5, -1.5, -0.5, 0.5, 1.5, 2.5];
+        let expected_q = vec![-2, -2, 0, 0, 2, 2];
+
+        let symmetric = SymmetricQuantization::<f32, i8>::init(1.0);
+
+        let q: Vec<i8> = symmetric.quantize(&x);
+        assert_eq!(q, expected_q);
+    }
+
     #[test]
     fn test_int8_symmetric_quantization() {
         let x: [f32; 4] = [-1.8, -1.0, 0.0, 0.5];
@@ -229,4 +851,260 @@ mod tests {
 
         assert_eq!(d, expected_d);
     }
+
+    #[test]
+    fn test_int8_per_channel_affine_quantization() {
+        // Channels along axis 0: [-1.8, -1.0, 0.0, 0.5] and [-0.5, 0.0, 1.0, 2.5]
+        let x: [f32; 8] = [-1.8, -1.0, 0.0, 0.5, -0.5, 0.0, 1.0, 2.5];
+        let shape = [2, 4];
+        // 2.5 is quantized to 126.5, which is rounded half to even
+        let expected_q = vec![-128, -39, 72, 127, -128, -86, -1, 126];
+        let expected_d = vec![
+            -1.8039216,
+            -1.0011765,
+            0.0,
+            0.49607843,
+            -0.49411765,
+            0.0,
+            1.0,
+            2.4941177,
+        ];
+
+        let per_channel = PerChannelQuantization::new(
+            0,
+            vec![
+                AffineQuantization::<f32, i8, i32>::new(-1.8, 0.5),
+                AffineQuantization::<f32, i8, i32>::new(-0.5, 2.5),
+            ],
+        );
+
+        let q: Vec<i8> = per_channel.quantize(&x, &shape);
+        assert_eq!(q, expected_q);
+
+        let d: Vec<f32> = per_channel.dequantize(&expected_q, &shape);
+        assert_eq!(d, expected_d);
+    }
+
+    #[test]
+    fn test_int8_per_channel_quantization_with_zero_channel() {
+        // Channels along axis 0: [-1.0, 0.5] and [0.0, 0.0]
+        let x: [f32; 4] = [-1.0, 0.5, 0.0, 0.0];
+        let shape = [2, 2];
+
+        let affine = PerChannelQuantization::new(
+            0,
+            vec![
+                AffineQuantization::<f32, i8, i32>::new(-1.0, 0.5),
+                AffineQuantization::<f32, i8, i32>::new(0.0, 0.0),
+            ],
+        );
+        let q: Vec<i8> = affine.quantize(&x, &shape);
+        assert_eq!(&q[2..], [0, 0]);
+        let d: Vec<f32> = affine.dequantize(&q, &shape);
+        assert_eq!(&d[2..], [0.0, 0.0]);
+
+        let symmetric = PerChannelQuantization::new(
+            0,
+            vec![
+                SymmetricQuantization::<f32, i8>::new(-1.0, 0.5),
+                SymmetricQuantization::<f32, i8>::new(0.0, 0.0),
+            ],
+        );
+        let q: Vec<i8> = symmetric.quantize(&x, &shape);
+        assert_eq!(&q[2..], [0, 0]);
+        let d: Vec<f32> = symmetric.dequantize(&q, &shape);
+        assert_eq!(&d[2..], [0.0, 0.0]);
+    }
+
+    #[test]
+    fn test_int8_per_channel_symmetric_quantization() {
+        // Channels along axis 1: [-1.8, 0.0] and [-1.0, 0.5]
+        let x: [f32; 4] = [-1.8, -1.0, 0.0, 0.5];
+        let shape = [2, 2];
+        let expected_q = vec![-127, -127, 0, 64];
+        let expected_d = vec![-1.8, -1.0, 0.0, 0.503937];
+
+        let per_channel = PerChannelQuantization::new(
+            1,
+            vec![
+                SymmetricQuantization::<f32, i8>::new(-1.8, 0.0),
+                SymmetricQuantization::<f32, i8>::new(-1.0, 0.5),
+            ],
+        );
+
+        let q: Vec<i8> = per_channel.quantize(&x, &shape);
+        assert_eq!(q, expected_q);
+
+        let d: Vec<f32> = per_channel.dequantize(&expected_q, &shape);
+        assert_eq!(d, expected_d);
+    }
+
+    #[test]
+    fn test_uint8_affine_quantization() {
+        let x: [f32; 4] = [-1.8, -1.0, 0.0, 0.5];
+        let expected_q = vec![0, 89, 200, 255];
+        let expected_d = vec![-1.8039216, -1.0011765, 0.0, 0.49607843];
+
+        let affine = AffineQuantization::<f32, u8, i32>::new(-1.8, 0.5);
+
+        let q = affine.quantize(&x);
+        assert_eq!(q, expected_q);
+
+        let d = affine.dequantize(&expected_q);
+
+        assert_eq!(d, expected_d);
+    }
+
+    #[test]
+    fn test_int4_affine_quantization() {
+        let x: [f32; 4] = [-1.8, -1.0, 0.0, 0.5];
+        let expected_q = vec![-8, -3, 4, 7];
+        let expected_d = vec![-1.84, -1.0733334, 0.0, 0.46];
+
+        let affine = AffineQuantization::<f32, i8, i32, 4>::new(-1.8, 0.5);
+
+        let q = affine.quantize(&x);
+        assert_eq!(q, expected_q);
+
+        let d = affine.dequantize(&expected_q);
+
+        assert_eq!(d, expected_d);
+    }
+
+    #[test]
+    fn test_int4_symmetric_quantization() {
+        let x: [f32; 4] = [-1.8, -1.0, 0.0, 0.5];
+        let expected_q = vec![-7, -4, 0, 2];
+        let expected_d = vec![-1.8, -1.0285714, 0.0, 0.5142857];
+
+        let symmetric = SymmetricQuantization::<f32, i8, 4>::new(-1.8, 0.5);
+
+        let q: Vec<i8> = symmetric.quantize(&x);
+        assert_eq!(q, expected_q);
+
+        let d = symmetric.dequantize(&expected_q);
+
+        assert_eq!(d, expected_d);
+    }
+
+    #[test]
+    fn test_uint4_per_group_affine_quantization() {
+        // Groups of 3 values: [-1.8, -1.0, 0.0] and [0.4, 1.6, 3.0]
+        let x: [f32; 6] = [-1.8, -1.0, 0.0, 0.4, 1.6, 3.0];
+        let expected_q = vec![0, 7, 15, 2, 8, 15];
+
+        let per_group = PerGroupQuantization::new(
+            3,
+            vec![
+                AffineQuantization::<f32, u8, i32, 4>::new(-1.8, 0.0),
+                AffineQuantization::<f32, u8, i32, 4>::new(0.0, 3.0),
+            ],
+        );
+
+        let q: Vec<u8> = per_group.quantize(&x);
+        assert_eq!(q, expected_q);
+
+        let d: Vec<f32> = per_group.dequantize(&q);
+        for (d, x) in d.iter().zip(x) {
+            assert!((d - x).abs() <= 0.1, "{d} != {x}");
+        }
+    }
+
+    #[test]
+    fn test_int4_per_group_quantization_with_zero_group() {
+        // Groups of 2 values: [0.0, 0.0] and [-1.0, 0.5]
+        let x: [f32; 4] = [0.0, 0.0, -1.0, 0.5];
+
+        let affine = PerGroupQuantization::new(
+            2,
+            vec![
+                AffineQuantization::<f32, i8, i32, 4>::new(0.0, 0.0),
+                AffineQuantization::<f32, i8, i32, 4>::new(-1.0, 0.5),
+            ],
+        );
+        let q: Vec<i8> = affine.quantize(&x);
+        assert_eq!(&q[..2], [0, 0]);
+        let d: Vec<f32> = affine.dequantize(&q);
+        assert_eq!(&d[..2], [0.0, 0.0]);
+
+        let symmetric = PerGroupQuantization::new(
+            2,
+            vec![
+                SymmetricQuantization::<f32, i8, 4>::new(0.0, 0.0),
+                SymmetricQuantization::<f32, i8, 4>::new(-1.0, 0.5),
+            ],
+        );
+        let q: Vec<i8> = symmetric.quantize(&x);
+        assert_eq!(&q[..2], [0, 0]);
+        let d: Vec<f32> = symmetric.dequantize(&q);
+        assert_eq!(&d[..2], [0.0, 0.0]);
+    }
+
+    #[test]
+    fn test_int4_pack_unpack() {
+        let strategy = QuantizationStrategy::PerGroupSymmetricInt4(PerGroupQuantization::new(
+            5,
+            vec![SymmetricQuantization::new(-1.0, 1.0)],
+        ));
+        let values = vec![-7, -1, 0, 3, 7];
+
+        let bytes = strategy.pack(&values);
+        assert_eq!(bytes, vec![0xF9, 0x30, 0x07]);
+        assert_eq!(strategy.unpack(&bytes, values.len()), values);
+    }
+
+    #[test]
+    fn test_uint8_pack_unpack() {
+        let strategy =
+            QuantizationStrategy::PerTensorAffineUInt8(AffineQuantization::new(-1.0, 1.0));
+        let values = vec![0, 127, 128, 255];
+
+        let bytes = strategy.pack(&values);
+        assert_eq!(bytes, vec![0, 127, 128, 255]);
+        assert_eq!(strategy.unpack(&bytes, values.len()), values);
+    }
+
+    #[test]
+    fn test_params_bytes_roundtrip() {
+        let strategies = [
+            QuantizationStrategy::PerTensorAffineUInt8(AffineQuantization::new(-1.0, 3.0)),
+            QuantizationStrategy::PerChannelAffineInt8(PerChannelQuantization::new(
+                1,
+                vec![
+                    AffineQuantization::new(-1.0, 1.0),
+                    AffineQuantization::new(-0.5, 2.0),
+                ],
+            )),
+            QuantizationStrategy::PerGroupSymmetricInt4(PerGroupQuantization::new(
+                8,
+                vec![SymmetricQuantization::new(-2.0, 2.0); 3],
+            )),
+        ];
+
+        for strategy in strategies {
+            let bytes = strategy.params_to_bytes();
+            let restored = QuantizationStrategy::from_params_bytes(strategy.kind(), &bytes);
+            assert_eq!(restored, Some(strategy.clone()));
+            assert_eq!(
+                QuantizationStrategy::from_params_bytes(strategy.kind(), &bytes[1..]),
+                None
+            );
+        }
+    }
+
+    #[test]
+    fn test_per_channel_reshape_should_update_axis() {
+        let strategy = QuantizationStrategy::PerChannelSymmetricInt8(PerChannelQuantization::new(
+            1,
+            vec![SymmetricQuantization::new(-1.0, 1.0); 5],
+        ));
+
+        let QuantizationStrategy::PerChannelSymmetricInt8(q) =
+            strategy.reshape(&[8, 5], &[1, 8, 5]).unwrap()
+        else {
+            unreachable!()
+        };
+        assert_eq!(q.axis, 2);
+        assert!(strategy.reshape(&[8, 5], &[40]).is_none());
+    }
 }
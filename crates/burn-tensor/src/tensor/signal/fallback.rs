@@ -0,0 +1,71 @@
+use alloc::vec::Vec;
+use core::f64::consts::PI;
+#[cfg(not(feature = "std"))]
+use num_traits::Float;
+
+use crate::{backend::Backend, Tensor, TensorData};
+
+/// Computes the unnormalized discrete Fourier transform along a dimension by multiplying the
+/// signal with the matrix of the transform, in `O(n^2)`.
+pub(crate) fn dft<B: Backend, const D: usize>(
+    re: Tensor<B, D>,
+    im: Tensor<B, D>,
+    dim: usize,
+    inverse: bool,
+) -> (Tensor<B, D>, Tensor<B, D>) {
+    let shape = re.shape();
+    let n = shape.dims[dim];
+    let (cos, sin) = dft_matrices::<B>(n, inverse, &re.device());
+
+    // The transform is applied to rows, with the other dimensions flattened
+    let re = re.swap_dims(dim, D - 1);
+    let im = im.swap_dims(dim, D - 1);
+    let shape_swapped = re.shape();
+    let rows = shape.num_elements() / n.max(1);
+    let re = re.reshape([rows, n]);
+    let im = im.reshape([rows, n]);
+
+    // (a + ib)(cos + i sin) = (a cos - b sin) + i(a sin + b cos)
+    let output_re = re
+        .clone()
+        .matmul(cos.clone())
+        .sub(im.clone().matmul(sin.clone()));
+    let output_im = re.matmul(sin).add(im.matmul(cos));
+
+    (
+        output_re
+            .reshape(shape_swapped.clone())
+            .swap_dims(dim, D - 1),
+        output_im.reshape(shape_swapped).swap_dims(dim, D - 1),
+    )
+}
+
+/// The real and imaginary parts of `exp(-2 pi i j k / n)`, or `exp(2 pi i j k / n)` for the
+/// inverse transform.
+fn dft_matrices<B: Backend>(
+    n: usize,
+    inverse: bool,
+    device: &B::Device,
+) -> (Tensor<B, 2>, Tensor<B, 2>) {
+    let sign = if inverse { 1.0 } else { -1.0 };
+    let mut cos = Vec::with_capacity(n * n);
+    let mut sin = Vec::with_capacity(n * n);
+
+    for j in 0..n {
+        for k in 0..n {
+            // The product is reduced first, so that the angle stays accurate
+            let angle = sign * 2.0 * PI * ((j * k) % n) as f64 / n as f64;
+            cos.push(angle.cos());
+            sin.push(angle.sin());
+        }
+    }
+
+    let matrix = |values: Vec<f64>| {
+        Tensor::from_data(
+            TensorData::new(values, [n, n]).convert::<B::FloatElem>(),
+            device,
+        )
+    };
+
+    (matrix(cos), matrix(sin))
+}
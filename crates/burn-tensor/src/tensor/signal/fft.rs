@@ -0,0 +1,235 @@
+use alloc::vec;
+
+use crate::backend::Backend;
+use crate::check::TensorCheck;
+use crate::{check, Tensor, TensorPrimitive};
+
+/// Computes the discrete Fourier transform of complex tensors along a dimension.
+///
+/// # Arguments
+///
+/// * `re` - The real part of the input.
+/// * `im` - The imaginary part of the input, with the same shape as the real part.
+/// * `dim` - The dimension along which the transform is computed.
+///
+/// # Returns
+///
+/// A tuple `(re, im)` with the real and the imaginary parts of the transform, with the same shape
+/// as the input.
+pub fn fft<B: Backend, const D: usize>(
+    re: Tensor<B, D>,
+    im: Tensor<B, D>,
+    dim: usize,
+) -> (Tensor<B, D>, Tensor<B, D>) {
+    check!(TensorCheck::fft::<D>("FFT", &re.shape(), &im.shape(), dim));
+
+    transform(re, im, dim, false)
+}
+
+/// Computes the inverse discrete Fourier transform of complex tensors along a dimension.
+///
+/// The result is divided by the size of the dimension, so that `ifft(fft(x)) == x`.
+///
+/// # Arguments
+///
+/// * `re` - The real part of the input.
+/// * `im` - The imaginary part of the input, with the same shape as the real part.
+/// * `dim` - The dimension along which the transform is computed.
+///
+/// # Returns
+///
+/// A tuple `(re, im)` with the real and the imaginary parts of the inverse transform, with the
+/// same shape as the input.
+pub fn ifft<B: Backend, const D: usize>(
+    re: Tensor<B, D>,
+    im: Tensor<B, D>,
+    dim: usize,
+) -> (Tensor<B, D>, Tensor<B, D>) {
+    check!(TensorCheck::fft::<D>("IFFT", &re.shape(), &im.shape(), dim));
+
+    let n = re.dims()[dim] as f64;
+    let (re, im) = transform(re, im, dim, true);
+
+    (re.div_scalar(n), im.div_scalar(n))
+}
+
+/// Computes the discrete Fourier transform of a real signal along a dimension.
+///
+/// The transform of a real signal is Hermitian-symmetric, so only the non-negative frequencies are
+/// returned.
+///
+/// # Arguments
+///
+/// * `signal` - The real signal.
+/// * `dim` - The dimension along which the transform is computed.
+///
+/// # Returns
+///
+/// A tuple `(re, im)` with the real and the imaginary parts of the transform, where the size of
+/// the dimension is `n / 2 + 1` for a signal of size `n`.
+pub fn rfft<B: Backend, const D: usize>(
+    signal: Tensor<B, D>,
+    dim: usize,
+) -> (Tensor<B, D>, Tensor<B, D>) {
+    check!(TensorCheck::dim_ops::<D>("RFFT", dim));
+
+    let bins = signal.dims()[dim] / 2 + 1;
+    let imaginary = signal.zeros_like();
+    let (re, im) = transform(signal, imaginary, dim, false);
+
+    (re.narrow(dim, 0, bins), im.narrow(dim, 0, bins))
+}
+
+/// Computes the inverse of [rfft], i.e. the real signal from the non-negative frequencies of its
+/// transform.
+///
+/// The imaginary parts of the zero frequency and, for even sizes, of the Nyquist frequency are
+/// ignored.
+///
+/// # Arguments
+///
+/// * `re` - The real part of the transform.
+/// * `im` - The imaginary part of the transform, with the same shape as the real part.
+/// * `dim` - The dimension along which the transform is computed, of size `n / 2 + 1`.
+/// * `n` - The size of the signal, which can't be deduced from the size of the transform.
+///
+/// # Returns
+///
+/// The real signal, where the size of the dimension is `n`.
+pub fn irfft<B: Backend, const D: usize>(
+    re: Tensor<B, D>,
+    im: Tensor<B, D>,
+    dim: usize,
+    n: usize,
+) -> Tensor<B, D> {
+    check!(TensorCheck::fft::<D>(
+        "IRFFT",
+        &re.shape(),
+        &im.shape(),
+        dim
+    ));
+    check!(TensorCheck::irfft::<D>(&re.shape(), dim, n));
+
+    // The negative frequencies are the conjugates of the positive ones
+    let mirrored = n - re.dims()[dim];
+    let (re, im) = match mirrored {
+        0 => (re, im),
+        _ => {
+            let re_mirror = re.clone().narrow(dim, 1, mirrored).flip([dim as isize]);
+            let im_mirror = im.clone().narrow(dim, 1, mirrored).flip([dim as isize]);
+
+            (
+                Tensor::cat(vec![re, re_mirror], dim),
+                Tensor::cat(vec![im, im_mirror.neg()], dim),
+            )
+        }
+    };
+
+    let (re, _im) = transform(re, im, dim, true);
+
+    re.div_scalar(n as f64)
+}
+
+/// Computes the two-dimensional discrete Fourier transform of complex tensors.
+///
+/// # Arguments
+///
+/// * `re` - The real part of the input.
+/// * `im` - The imaginary part of the input, with the same shape as the real part.
+/// * `dims` - The dimensions along which the transform is computed.
+///
+/// # Returns
+///
+/// A tuple `(re, im)` with the real and the imaginary parts of the transform, with the same shape
+/// as the input.
+pub fn fft2<B: Backend, const D: usize>(
+    re: Tensor<B, D>,
+    im: Tensor<B, D>,
+    dims: [usize; 2],
+) -> (Tensor<B, D>, Tensor<B, D>) {
+    let (re, im) = fft(re, im, dims[0]);
+
+    fft(re, im, dims[1])
+}
+
+/// Computes the two-dimensional inverse discrete Fourier transform of complex tensors.
+///
+/// # Arguments
+///
+/// * `re` - The real part of the input.
+/// * `im` - The imaginary part of the input, with the same shape as the real part.
+/// * `dims` - The dimensions along which the transform is computed.
+///
+/// # Returns
+///
+/// A tuple `(re, im)` with the real and the imaginary parts of the inverse transform, with the
+/// same shape as the input.
+pub fn ifft2<B: Backend, const D: usize>(
+    re: Tensor<B, D>,
+    im: Tensor<B, D>,
+    dims: [usize; 2],
+) -> (Tensor<B, D>, Tensor<B, D>) {
+    let (re, im) = ifft(re, im, dims[0]);
+
+    ifft(re, im, dims[1])
+}
+
+/// Computes the two-dimensional discrete Fourier transform of a real signal.
+///
+/// Only the non-negative frequencies of the last dimension of `dims` are returned.
+///
+/// # Arguments
+///
+/// * `signal` - The real signal.
+/// * `dims` - The dimensions along which the transform is computed.
+///
+/// # Returns
+///
+/// A tuple `(re, im)` with the real and the imaginary parts of the transform, where the size of
+/// the dimension `dims[1]` is `n / 2 + 1` for a signal of size `n`.
+pub fn rfft2<B: Backend, const D: usize>(
+    signal: Tensor<B, D>,
+    dims: [usize; 2],
+) -> (Tensor<B, D>, Tensor<B, D>) {
+    let (re, im) = rfft(signal, dims[1]);
+
+    fft(re, im, dims[0])
+}
+
+/// Computes the inverse of [rfft2].
+///
+/// # Arguments
+///
+/// * `re` - The real part of the transform.
+/// * `im` - The imaginary part of the transform, with the same shape as the real part.
+/// * `dims` - The dimensions along which the transform is computed, the dimension `dims[1]`
+///   holding the non-negative frequencies.
+/// * `n` - The size of the signal along the dimension `dims[1]`.
+///
+/// # Returns
+///
+/// The real signal, where the size of the dimension `dims[1]` is `n`.
+pub fn irfft2<B: Backend, const D: usize>(
+    re: Tensor<B, D>,
+    im: Tensor<B, D>,
+    dims: [usize; 2],
+    n: usize,
+) -> Tensor<B, D> {
+    let (re, im) = ifft(re, im, dims[0]);
+
+    irfft(re, im, dims[1], n)
+}
+
+fn transform<B: Backend, const D: usize>(
+    re: Tensor<B, D>,
+    im: Tensor<B, D>,
+    dim: usize,
+    inverse: bool,
+) -> (Tensor<B, D>, Tensor<B, D>) {
+    let (re, im) = B::fft(re.primitive.tensor(), im.primitive.tensor(), dim, inverse);
+
+    (
+        Tensor::new(TensorPrimitive::Float(re)),
+        Tensor::new(TensorPrimitive::Float(im)),
+    )
+}
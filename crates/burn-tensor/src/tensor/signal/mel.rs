@@ -0,0 +1,76 @@
+use alloc::vec::Vec;
+#[cfg(not(feature = "std"))]
+use num_traits::Float;
+
+use crate::{backend::Backend, Tensor, TensorData};
+
+/// Converts a frequency in hertz to the mel scale, using the HTK formula
+/// `2595 log10(1 + f / 700)`.
+pub fn hz_to_mel(frequency: f64) -> f64 {
+    2595.0 * (1.0 + frequency / 700.0).log10()
+}
+
+/// Converts a frequency on the mel scale to hertz, the inverse of [hz_to_mel].
+pub fn mel_to_hz(mel: f64) -> f64 {
+    700.0 * (10f64.powf(mel / 2595.0) - 1.0)
+}
+
+/// Creates a filterbank of triangular filters evenly spaced on the mel scale.
+///
+/// The mel spectrogram is the product of the power spectrogram computed by
+/// [stft](crate::signal::stft) with the filterbank.
+///
+/// # Arguments
+///
+/// * `n_freqs` - The number of frequency bins of the spectrogram, usually `n_fft / 2 + 1`.
+/// * `n_mels` - The number of filters.
+/// * `sample_rate` - The sample rate of the signals, in hertz.
+/// * `f_min` - The lowest frequency of the filters, in hertz.
+/// * `f_max` - The highest frequency of the filters, in hertz, usually `sample_rate / 2`.
+/// * `device` - The device on which the filterbank is created.
+///
+/// # Returns
+///
+/// The filterbank, with a shape of `[n_freqs, n_mels]`.
+pub fn mel_filterbank<B: Backend>(
+    n_freqs: usize,
+    n_mels: usize,
+    sample_rate: f64,
+    f_min: f64,
+    f_max: f64,
+    device: &B::Device,
+) -> Tensor<B, 2> {
+    let frequencies = linspace(0.0, sample_rate / 2.0, n_freqs);
+    let points = linspace(hz_to_mel(f_min), hz_to_mel(f_max), n_mels + 2)
+        .into_iter()
+        .map(mel_to_hz)
+        .collect::<Vec<_>>();
+
+    let mut values = Vec::with_capacity(n_freqs * n_mels);
+
+    for frequency in frequencies {
+        for m in 0..n_mels {
+            let (lower, center, upper) = (points[m], points[m + 1], points[m + 2]);
+            let rising = (frequency - lower) / (center - lower);
+            let falling = (upper - frequency) / (upper - center);
+
+            values.push(rising.min(falling).max(0.0));
+        }
+    }
+
+    Tensor::from_data(
+        TensorData::new(values, [n_freqs, n_mels]).convert::<B::FloatElem>(),
+        device,
+    )
+}
+
+/// `size` evenly spaced values from `start` to `end`, both included.
+fn linspace(start: f64, end: f64, size: usize) -> Vec<f64> {
+    match size {
+        0 => Vec::new(),
+        1 => alloc::vec![start],
+        _ => (0..size)
+            .map(|i| start + (end - start) * i as f64 / (size - 1) as f64)
+            .collect(),
+    }
+}
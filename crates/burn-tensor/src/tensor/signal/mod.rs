@@ -0,0 +1,11 @@
+mod fft;
+mod mel;
+mod stft;
+mod window;
+
+pub(crate) mod fallback;
+
+pub use fft::*;
+pub use mel::*;
+pub use stft::*;
+pub use window::*;
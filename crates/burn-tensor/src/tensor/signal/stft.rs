@@ -0,0 +1,173 @@
+use alloc::vec;
+
+use crate::backend::Backend;
+use crate::check::TensorCheck;
+use crate::signal::{irfft, rfft};
+use crate::{check, Int, Tensor};
+
+/// Computes the short-time Fourier transform of signals.
+///
+/// The signals are split into overlapping frames of `n_fft` samples, `hop_length` samples apart,
+/// which are multiplied by the window before computing their real Fourier transform.
+///
+/// # Arguments
+///
+/// * `signal` - The signals, with a shape of `[batch_size, length]`.
+/// * `n_fft` - The size of the frames.
+/// * `hop_length` - The distance between the starts of two consecutive frames.
+/// * `window` - The window, with a shape of `[n_fft]`. A rectangular window is used when `None`.
+/// * `center` - If true, the signals are padded on both sides with `n_fft / 2` reflected samples,
+///   so that the frame `t` is centered on the sample `t * hop_length`.
+///
+/// # Returns
+///
+/// A tuple `(re, im)` with the real and the imaginary parts of the transform, with a shape of
+/// `[batch_size, n_frames, n_fft / 2 + 1]`.
+pub fn stft<B: Backend>(
+    signal: Tensor<B, 2>,
+    n_fft: usize,
+    hop_length: usize,
+    window: Option<Tensor<B, 1>>,
+    center: bool,
+) -> (Tensor<B, 3>, Tensor<B, 3>) {
+    check!(TensorCheck::stft(
+        "STFT",
+        n_fft,
+        hop_length,
+        window.as_ref().map(|window| window.dims()[0])
+    ));
+    check!(TensorCheck::stft_signal(signal.dims()[1], n_fft, center));
+
+    let signal = match center {
+        true => reflect_pad(signal, n_fft / 2),
+        false => signal,
+    };
+    let [batch_size, length] = signal.dims();
+    let n_frames = 1 + (length - n_fft) / hop_length;
+    let indices = frame_indices::<B>(n_frames, n_fft, hop_length, &signal.device());
+
+    let frames = signal
+        .select(1, indices)
+        .reshape([batch_size, n_frames, n_fft]);
+    let frames = match window {
+        Some(window) => frames.mul(window.reshape([1, 1, n_fft])),
+        None => frames,
+    };
+
+    rfft(frames, 2)
+}
+
+/// Computes the inverse of [stft], rebuilding the signals by overlap-adding the frames.
+///
+/// # Arguments
+///
+/// * `re` - The real part of the transform, with a shape of `[batch_size, n_frames, n_fft / 2 + 1]`.
+/// * `im` - The imaginary part of the transform, with the same shape as the real part.
+/// * `n_fft` - The size of the frames.
+/// * `hop_length` - The distance between the starts of two consecutive frames.
+/// * `window` - The window used by the transform, with a shape of `[n_fft]`. A rectangular window
+///   is used when `None`.
+/// * `center` - If true, the `n_fft / 2` samples of padding added by the transform are removed.
+/// * `length` - The length of the signals, which are padded with zeros or trimmed to match it.
+///   When `None`, all the samples covered by the frames are returned, without the padding.
+///
+/// # Returns
+///
+/// The signals, with a shape of `[batch_size, length]`.
+///
+/// # Notes
+///
+/// The samples where the sum of the squared overlapping windows is close to zero can't be
+/// recovered and are left as computed, without normalization.
+pub fn istft<B: Backend>(
+    re: Tensor<B, 3>,
+    im: Tensor<B, 3>,
+    n_fft: usize,
+    hop_length: usize,
+    window: Option<Tensor<B, 1>>,
+    center: bool,
+    length: Option<usize>,
+) -> Tensor<B, 2> {
+    check!(TensorCheck::stft(
+        "ISTFT",
+        n_fft,
+        hop_length,
+        window.as_ref().map(|window| window.dims()[0])
+    ));
+
+    let device = re.device();
+    let [batch_size, n_frames, _] = re.dims();
+    let window = window.unwrap_or_else(|| Tensor::ones([n_fft], &device));
+    let window = window.reshape([1, 1, n_fft]);
+
+    let frames = irfft(re, im, 2, n_fft).mul(window.clone());
+
+    // Frames are summed where they overlap, then divided by the overlapping squared windows
+    let total = n_fft + hop_length * n_frames.saturating_sub(1);
+    let indices = frame_indices::<B>(n_frames, n_fft, hop_length, &device);
+    let signal = Tensor::zeros([batch_size, total], &device).select_assign(
+        1,
+        indices.clone(),
+        frames.reshape([batch_size, n_frames * n_fft]),
+    );
+    let envelope = Tensor::zeros([1, total], &device).select_assign(
+        1,
+        indices,
+        window
+            .powf_scalar(2.0)
+            .repeat(1, n_frames)
+            .reshape([1, n_frames * n_fft]),
+    );
+    let envelope = envelope
+        .clone()
+        .mask_fill(envelope.lower_equal_elem(1e-11), 1.0);
+    let signal = signal.div(envelope);
+
+    let start = if center { n_fft / 2 } else { 0 };
+    let length = length.unwrap_or(total - 2 * start);
+    let available = total - start;
+    let signal = signal.narrow(1, start, length.min(available));
+
+    match length > available {
+        true => Tensor::cat(
+            vec![
+                signal,
+                Tensor::zeros([batch_size, length - available], &device),
+            ],
+            1,
+        ),
+        false => signal,
+    }
+}
+
+/// Pads the signals on both sides with `padding` samples reflected around the edges, without
+/// repeating the edges.
+fn reflect_pad<B: Backend>(signal: Tensor<B, 2>, padding: usize) -> Tensor<B, 2> {
+    if padding == 0 {
+        return signal;
+    }
+
+    let length = signal.dims()[1];
+    let left = signal.clone().narrow(1, 1, padding).flip([1]);
+    let right = signal
+        .clone()
+        .narrow(1, length - 1 - padding, padding)
+        .flip([1]);
+
+    Tensor::cat(vec![left, signal, right], 1)
+}
+
+/// The indices of the samples of each frame, flattened frame by frame.
+fn frame_indices<B: Backend>(
+    n_frames: usize,
+    n_fft: usize,
+    hop_length: usize,
+    device: &B::Device,
+) -> Tensor<B, 1, Int> {
+    let offsets = Tensor::<B, 1, Int>::arange(0..n_fft as i64, device).reshape([1, n_fft]);
+    let starts = Tensor::<B, 1, Int>::arange(0..n_frames as i64, device)
+        .mul_scalar(hop_length as i64)
+        .reshape([n_frames, 1]);
+
+    starts.add(offsets).reshape([n_frames * n_fft])
+}
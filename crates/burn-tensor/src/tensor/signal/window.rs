@@ -0,0 +1,90 @@
+use core::f64::consts::PI;
+
+use crate::{backend::Backend, Int, Tensor};
+
+/// Creates a Hann window, `0.5 - 0.5 cos(2 pi n / N)`.
+///
+/// # Arguments
+///
+/// * `size` - The size of the window.
+/// * `periodic` - If true, the window is the first `size` values of a symmetric window of size
+///   `size + 1`, which is what spectral analysis with [stft](crate::signal::stft) expects.
+///   Otherwise, the window is symmetric, as used for filter design.
+/// * `device` - The device on which the window is created.
+///
+/// # Returns
+///
+/// The window, with a shape of `[size]`.
+pub fn hann_window<B: Backend>(size: usize, periodic: bool, device: &B::Device) -> Tensor<B, 1> {
+    cosine_window(size, periodic, &[0.5, 0.5], device)
+}
+
+/// Creates a Hamming window, `0.54 - 0.46 cos(2 pi n / N)`.
+///
+/// # Arguments
+///
+/// * `size` - The size of the window.
+/// * `periodic` - If true, the window is the first `size` values of a symmetric window of size
+///   `size + 1`. Otherwise, the window is symmetric.
+/// * `device` - The device on which the window is created.
+///
+/// # Returns
+///
+/// The window, with a shape of `[size]`.
+pub fn hamming_window<B: Backend>(size: usize, periodic: bool, device: &B::Device) -> Tensor<B, 1> {
+    cosine_window(size, periodic, &[0.54, 0.46], device)
+}
+
+/// Creates a Blackman window, `0.42 - 0.5 cos(2 pi n / N) + 0.08 cos(4 pi n / N)`.
+///
+/// # Arguments
+///
+/// * `size` - The size of the window.
+/// * `periodic` - If true, the window is the first `size` values of a symmetric window of size
+///   `size + 1`. Otherwise, the window is symmetric.
+/// * `device` - The device on which the window is created.
+///
+/// # Returns
+///
+/// The window, with a shape of `[size]`.
+pub fn blackman_window<B: Backend>(
+    size: usize,
+    periodic: bool,
+    device: &B::Device,
+) -> Tensor<B, 1> {
+    cosine_window(size, periodic, &[0.42, 0.5, 0.08], device)
+}
+
+/// The window `sum_k (-1)^k a_k cos(2 pi k n / N)`.
+fn cosine_window<B: Backend>(
+    size: usize,
+    periodic: bool,
+    coefficients: &[f64],
+    device: &B::Device,
+) -> Tensor<B, 1> {
+    let period = if periodic {
+        size
+    } else {
+        size.saturating_sub(1)
+    };
+
+    if period == 0 {
+        return Tensor::ones([size], device);
+    }
+
+    let positions = Tensor::<B, 1, Int>::arange(0..size as i64, device).float();
+    let mut window = Tensor::zeros([size], device);
+
+    for (k, coefficient) in coefficients.iter().enumerate() {
+        let sign = if k % 2 == 0 { 1.0 } else { -1.0 };
+        let term = positions
+            .clone()
+            .mul_scalar(2.0 * PI * k as f64 / period as f64)
+            .cos()
+            .mul_scalar(sign * coefficient);
+
+        window = window.add(term);
+    }
+
+    window
+}
@@ -0,0 +1,20 @@
+#[burn_tensor_testgen::testgen(elu)]
+mod tests {
+    use super::*;
+    use burn_tensor::{activation, Tensor, TensorData};
+
+    #[test]
+    fn test_elu_d2() {
+        let tensor = Tensor::<TestBackend, 2>::from([[-4.0, -1.0, 0.0], [0.5, 2.0, 3.5]]);
+
+        let output = activation::elu(tensor.clone(), 1.0);
+        let expected = TensorData::from([[-0.9817, -0.6321, 0.0], [0.5, 2.0, 3.5]]);
+
+        output.into_data().assert_approx_eq(&expected, 4);
+
+        let output = activation::elu(tensor, 0.5);
+        let expected = TensorData::from([[-0.4908, -0.3161, 0.0], [0.5, 2.0, 3.5]]);
+
+        output.into_data().assert_approx_eq(&expected, 4);
+    }
+}
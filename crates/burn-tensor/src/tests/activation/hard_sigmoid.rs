@@ -0,0 +1,15 @@
+#[burn_tensor_testgen::testgen(hard_sigmoid)]
+mod tests {
+    use super::*;
+    use burn_tensor::{activation, Tensor, TensorData};
+
+    #[test]
+    fn test_hard_sigmoid_d2() {
+        let tensor = Tensor::<TestBackend, 2>::from([[-4.0, -1.0, 0.0], [0.5, 2.0, 3.5]]);
+
+        let output = activation::hard_sigmoid(tensor, 0.2, 0.5);
+        let expected = TensorData::from([[0.0, 0.3, 0.5], [0.6, 0.9, 1.0]]);
+
+        output.into_data().assert_approx_eq(&expected, 4);
+    }
+}
@@ -0,0 +1,15 @@
+#[burn_tensor_testgen::testgen(hard_swish)]
+mod tests {
+    use super::*;
+    use burn_tensor::{activation, Tensor, TensorData};
+
+    #[test]
+    fn test_hard_swish_d2() {
+        let tensor = Tensor::<TestBackend, 2>::from([[-4.0, -1.0, 0.0], [0.5, 2.0, 3.5]]);
+
+        let output = activation::hard_swish(tensor);
+        let expected = TensorData::from([[0.0, -0.3333, 0.0], [0.2917, 1.6667, 3.5]]);
+
+        output.into_data().assert_approx_eq(&expected, 4);
+    }
+}
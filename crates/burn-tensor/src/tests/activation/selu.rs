@@ -0,0 +1,15 @@
+#[burn_tensor_testgen::testgen(selu)]
+mod tests {
+    use super::*;
+    use burn_tensor::{activation, Tensor, TensorData};
+
+    #[test]
+    fn test_selu_d2() {
+        let tensor = Tensor::<TestBackend, 2>::from([[-4.0, -1.0, 0.0], [0.5, 2.0, 3.5]]);
+
+        let output = activation::selu(tensor);
+        let expected = TensorData::from([[-1.7259, -1.1113, 0.0], [0.5254, 2.1014, 3.6775]]);
+
+        output.into_data().assert_approx_eq(&expected, 4);
+    }
+}
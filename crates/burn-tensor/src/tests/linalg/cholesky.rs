@@ -0,0 +1,42 @@
+#[burn_tensor_testgen::testgen(linalg_cholesky)]
+mod tests {
+    use super::*;
+    use burn_tensor::backend::Backend;
+    use burn_tensor::{linalg, Tensor, TensorData};
+
+    type FloatElem = <TestBackend as Backend>::FloatElem;
+
+    #[test]
+    fn should_support_cholesky() {
+        let device = Default::default();
+        let tensor = TestTensor::<2>::from_floats(
+            [
+                [4.0, 12.0, -16.0],
+                [12.0, 37.0, -43.0],
+                [-16.0, -43.0, 98.0],
+            ],
+            &device,
+        );
+
+        let output = linalg::cholesky(tensor);
+        let expected = TensorData::from([[2.0, 0.0, 0.0], [6.0, 1.0, 0.0], [-8.0, 5.0, 3.0]])
+            .convert::<FloatElem>();
+
+        output.into_data().assert_approx_eq(&expected, 3);
+    }
+
+    #[test]
+    fn should_support_cholesky_batch() {
+        let device = Default::default();
+        let tensor = TestTensor::<3>::from_floats(
+            [[[4.0, 2.0], [2.0, 2.0]], [[9.0, 0.0], [0.0, 1.0]]],
+            &device,
+        );
+
+        let output = linalg::cholesky(tensor);
+        let expected = TensorData::from([[[2.0, 0.0], [1.0, 1.0]], [[3.0, 0.0], [0.0, 1.0]]])
+            .convert::<FloatElem>();
+
+        output.into_data().assert_approx_eq(&expected, 3);
+    }
+}
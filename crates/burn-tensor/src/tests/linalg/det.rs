@@ -0,0 +1,63 @@
+#[burn_tensor_testgen::testgen(linalg_det)]
+mod tests {
+    use super::*;
+    use burn_tensor::backend::Backend;
+    use burn_tensor::{linalg, Tensor, TensorData};
+
+    type FloatElem = <TestBackend as Backend>::FloatElem;
+
+    #[test]
+    fn should_support_det() {
+        let device = Default::default();
+        let tensor = TestTensor::<2>::from_floats(
+            [[2.0, -1.0, 0.0], [-1.0, 2.0, -1.0], [0.0, -1.0, 2.0]],
+            &device,
+        );
+
+        let output = linalg::det(tensor);
+        let expected = TensorData::from([[4.0]]).convert::<FloatElem>();
+
+        output.into_data().assert_approx_eq(&expected, 3);
+    }
+
+    #[test]
+    fn should_support_det_batch() {
+        let device = Default::default();
+        let tensor = TestTensor::<3>::from_floats(
+            [
+                [[0.0, 1.0], [1.0, 0.0]],
+                [[4.0, 7.0], [2.0, 6.0]],
+                [[1.0, 2.0], [2.0, 4.0]],
+            ],
+            &device,
+        );
+
+        let output = linalg::det(tensor);
+        let expected = TensorData::from([[[-1.0]], [[10.0]], [[0.0]]]).convert::<FloatElem>();
+
+        output.into_data().assert_approx_eq(&expected, 3);
+    }
+
+    #[test]
+    fn should_support_slogdet() {
+        let device = Default::default();
+        let tensor = TestTensor::<3>::from_floats(
+            [
+                [[0.0, 1.0], [1.0, 0.0]],
+                [[4.0, 7.0], [2.0, 5.0]],
+                [[-3.0, 1.0], [1.0, 2.0]],
+            ],
+            &device,
+        );
+
+        let (sign, logabsdet) = linalg::slogdet(tensor);
+        let expected_sign = TensorData::from([[[-1.0]], [[1.0]], [[-1.0]]]).convert::<FloatElem>();
+        let expected_logabsdet =
+            TensorData::from([[[0.0]], [[1.791759]], [[1.94591]]]).convert::<FloatElem>();
+
+        sign.into_data().assert_approx_eq(&expected_sign, 3);
+        logabsdet
+            .into_data()
+            .assert_approx_eq(&expected_logabsdet, 3);
+    }
+}
@@ -0,0 +1,60 @@
+#[burn_tensor_testgen::testgen(linalg_eigh)]
+mod tests {
+    use super::*;
+    use burn_tensor::backend::Backend;
+    use burn_tensor::{linalg, Tensor, TensorData};
+
+    type FloatElem = <TestBackend as Backend>::FloatElem;
+
+    #[test]
+    fn should_support_eigh() {
+        let device = Default::default();
+        let tensor = TestTensor::<2>::from_floats(
+            [[2.0, -1.0, 0.0], [-1.0, 2.0, -1.0], [0.0, -1.0, 2.0]],
+            &device,
+        );
+
+        let (values, vectors) = linalg::eigh(tensor.clone());
+        let expected = TensorData::from([[0.585786, 2.0, 3.414214]]).convert::<FloatElem>();
+
+        values.clone().into_data().assert_approx_eq(&expected, 3);
+        assert_eigh(tensor, values, vectors);
+    }
+
+    #[test]
+    fn should_support_eigh_batch() {
+        let device = Default::default();
+        let tensor = TestTensor::<3>::from_floats(
+            [[[2.0, 1.0], [1.0, 2.0]], [[5.0, 0.0], [0.0, -1.0]]],
+            &device,
+        );
+
+        let (values, vectors) = linalg::eigh(tensor.clone());
+        let expected = TensorData::from([[[1.0, 3.0]], [[-1.0, 5.0]]]).convert::<FloatElem>();
+
+        values.clone().into_data().assert_approx_eq(&expected, 3);
+        assert_eigh(tensor, values, vectors);
+    }
+
+    fn assert_eigh<const D: usize>(
+        tensor: TestTensor<D>,
+        values: TestTensor<D>,
+        vectors: TestTensor<D>,
+    ) {
+        let n = values.dims()[D - 1];
+        let identity = Tensor::<TestBackend, 2>::eye(n, &values.device()).unsqueeze::<D>();
+
+        vectors
+            .clone()
+            .transpose()
+            .matmul(vectors.clone())
+            .into_data()
+            .assert_approx_eq(&identity.expand(tensor.shape()).into_data(), 3);
+        vectors
+            .clone()
+            .mul(values)
+            .matmul(vectors.transpose())
+            .into_data()
+            .assert_approx_eq(&tensor.into_data().convert::<FloatElem>(), 3);
+    }
+}
@@ -0,0 +1,58 @@
+#[burn_tensor_testgen::testgen(linalg_inv)]
+mod tests {
+    use super::*;
+    use burn_tensor::backend::Backend;
+    use burn_tensor::{linalg, Tensor, TensorData};
+
+    type FloatElem = <TestBackend as Backend>::FloatElem;
+
+    #[test]
+    fn should_support_inv_2x2() {
+        let device = Default::default();
+        let tensor = TestTensor::<2>::from_floats([[4.0, 7.0], [2.0, 6.0]], &device);
+
+        let output = linalg::inv(tensor);
+        let expected = TensorData::from([[0.6, -0.7], [-0.2, 0.4]]).convert::<FloatElem>();
+
+        output.into_data().assert_approx_eq(&expected, 3);
+    }
+
+    #[test]
+    fn should_support_inv_3x3() {
+        let device = Default::default();
+        let tensor = TestTensor::<2>::from_floats(
+            [[2.0, -1.0, 0.0], [-1.0, 2.0, -1.0], [0.0, -1.0, 2.0]],
+            &device,
+        );
+
+        let output = linalg::inv(tensor);
+        let expected = TensorData::from([[0.75, 0.5, 0.25], [0.5, 1.0, 0.5], [0.25, 0.5, 0.75]])
+            .convert::<FloatElem>();
+
+        output.into_data().assert_approx_eq(&expected, 3);
+    }
+
+    #[test]
+    fn should_support_inv_batch_with_pivoting() {
+        let device = Default::default();
+        let tensor = TestTensor::<3>::from_floats(
+            [[[0.0, 1.0], [1.0, 0.0]], [[1.0, 2.0], [3.0, 4.0]]],
+            &device,
+        );
+
+        let output = linalg::inv(tensor);
+        let expected = TensorData::from([[[0.0, 1.0], [1.0, 0.0]], [[-2.0, 1.0], [1.5, -0.5]]])
+            .convert::<FloatElem>();
+
+        output.into_data().assert_approx_eq(&expected, 3);
+    }
+
+    #[test]
+    #[should_panic]
+    fn should_panic_when_inv_of_non_square_matrix() {
+        let device = Default::default();
+        let tensor = TestTensor::<2>::from_floats([[1.0, 2.0, 3.0], [4.0, 5.0, 6.0]], &device);
+
+        let _output = linalg::inv(tensor);
+    }
+}
@@ -0,0 +1,7 @@
+mod cholesky;
+mod det;
+mod eigh;
+mod inv;
+mod qr;
+mod solve;
+mod svd;
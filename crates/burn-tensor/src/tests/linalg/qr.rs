@@ -0,0 +1,68 @@
+#[burn_tensor_testgen::testgen(linalg_qr)]
+mod tests {
+    use super::*;
+    use burn_tensor::backend::Backend;
+    use burn_tensor::{linalg, Tensor, TensorData};
+
+    type FloatElem = <TestBackend as Backend>::FloatElem;
+
+    #[test]
+    fn should_support_qr_square() {
+        let device = Default::default();
+        let tensor = TestTensor::<2>::from_floats(
+            [[12.0, -51.0, 4.0], [6.0, 167.0, -68.0], [-4.0, 24.0, -41.0]],
+            &device,
+        );
+
+        let (q, r) = linalg::qr(tensor.clone());
+
+        assert_eq!(q.dims(), [3, 3]);
+        assert_eq!(r.dims(), [3, 3]);
+        assert_qr(tensor, q, r);
+    }
+
+    #[test]
+    fn should_support_qr_tall() {
+        let device = Default::default();
+        let tensor = TestTensor::<3>::from_floats(
+            [
+                [[1.0, 2.0], [3.0, 4.0], [5.0, 6.0]],
+                [[2.0, 0.0], [0.0, 1.0], [1.0, 1.0]],
+            ],
+            &device,
+        );
+
+        let (q, r) = linalg::qr(tensor.clone());
+
+        assert_eq!(q.dims(), [2, 3, 2]);
+        assert_eq!(r.dims(), [2, 2, 2]);
+        assert_qr(tensor, q, r);
+    }
+
+    #[test]
+    fn should_support_qr_wide() {
+        let device = Default::default();
+        let tensor = TestTensor::<2>::from_floats([[1.0, 2.0, 3.0], [4.0, 5.0, 6.0]], &device);
+
+        let (q, r) = linalg::qr(tensor.clone());
+
+        assert_eq!(q.dims(), [2, 2]);
+        assert_eq!(r.dims(), [2, 3]);
+        assert_qr(tensor, q, r);
+    }
+
+    fn assert_qr<const D: usize>(tensor: TestTensor<D>, q: TestTensor<D>, r: TestTensor<D>) {
+        let k = q.dims()[D - 1];
+        let identity = Tensor::<TestBackend, 2>::eye(k, &q.device()).unsqueeze::<D>();
+        let orthogonality = q.clone().transpose().matmul(q.clone()).sub(identity);
+        let zeros = orthogonality.zeros_like().into_data();
+
+        orthogonality.into_data().assert_approx_eq(&zeros, 3);
+        r.clone()
+            .into_data()
+            .assert_approx_eq(&r.clone().triu(0).into_data(), 3);
+        q.matmul(r)
+            .into_data()
+            .assert_approx_eq(&tensor.into_data().convert::<FloatElem>(), 3);
+    }
+}
@@ -0,0 +1,58 @@
+#[burn_tensor_testgen::testgen(linalg_solve)]
+mod tests {
+    use super::*;
+    use burn_tensor::backend::Backend;
+    use burn_tensor::{linalg, Tensor, TensorData};
+
+    type FloatElem = <TestBackend as Backend>::FloatElem;
+
+    #[test]
+    fn should_support_solve() {
+        let device = Default::default();
+        let lhs = TestTensor::<2>::from_floats([[3.0, 1.0], [1.0, 2.0]], &device);
+        let rhs = TestTensor::<2>::from_floats([[9.0], [8.0]], &device);
+
+        let output = linalg::solve(lhs, rhs);
+        let expected = TensorData::from([[2.0], [3.0]]).convert::<FloatElem>();
+
+        output.into_data().assert_approx_eq(&expected, 3);
+    }
+
+    #[test]
+    fn should_support_solve_batch_with_multiple_rhs() {
+        let device = Default::default();
+        let lhs = TestTensor::<3>::from_floats(
+            [
+                [[0.0, 2.0, 1.0], [1.0, 1.0, 0.0], [2.0, 0.0, 3.0]],
+                [[1.0, 0.0, 0.0], [0.0, 2.0, 0.0], [0.0, 0.0, 4.0]],
+            ],
+            &device,
+        );
+        let rhs = TestTensor::<3>::from_floats(
+            [
+                [[3.0, 1.0], [2.0, 1.0], [5.0, 2.0]],
+                [[1.0, 2.0], [2.0, 4.0], [4.0, 8.0]],
+            ],
+            &device,
+        );
+
+        let output = linalg::solve(lhs, rhs);
+        let expected = TensorData::from([
+            [[1.0, 0.625], [1.0, 0.375], [1.0, 0.25]],
+            [[1.0, 2.0], [1.0, 2.0], [1.0, 2.0]],
+        ])
+        .convert::<FloatElem>();
+
+        output.into_data().assert_approx_eq(&expected, 3);
+    }
+
+    #[test]
+    #[should_panic]
+    fn should_panic_when_solve_with_mismatched_rhs() {
+        let device = Default::default();
+        let lhs = TestTensor::<2>::from_floats([[3.0, 1.0], [1.0, 2.0]], &device);
+        let rhs = TestTensor::<2>::from_floats([[9.0], [8.0], [7.0]], &device);
+
+        let _output = linalg::solve(lhs, rhs);
+    }
+}
@@ -0,0 +1,82 @@
+#[burn_tensor_testgen::testgen(linalg_svd)]
+mod tests {
+    use super::*;
+    use burn_tensor::backend::Backend;
+    use burn_tensor::{linalg, Tensor, TensorData};
+
+    type FloatElem = <TestBackend as Backend>::FloatElem;
+
+    #[test]
+    fn should_support_svd_square() {
+        let device = Default::default();
+        let tensor = TestTensor::<2>::from_floats([[3.0, 0.0], [4.0, 5.0]], &device);
+
+        let (u, s, vh) = linalg::svd(tensor.clone());
+        let expected = TensorData::from([[6.708204, 2.236068]]).convert::<FloatElem>();
+
+        s.clone().into_data().assert_approx_eq(&expected, 3);
+        assert_svd(tensor, u, s, vh);
+    }
+
+    #[test]
+    fn should_support_svd_tall_batch() {
+        let device = Default::default();
+        let tensor = TestTensor::<3>::from_floats(
+            [
+                [[1.0, 2.0], [3.0, 4.0], [5.0, 6.0]],
+                [[2.0, 0.0], [0.0, -3.0], [0.0, 0.0]],
+            ],
+            &device,
+        );
+
+        let (u, s, vh) = linalg::svd(tensor.clone());
+        let expected =
+            TensorData::from([[[9.525518, 0.514301]], [[3.0, 2.0]]]).convert::<FloatElem>();
+
+        assert_eq!(u.dims(), [2, 3, 2]);
+        assert_eq!(vh.dims(), [2, 2, 2]);
+        s.clone().into_data().assert_approx_eq(&expected, 3);
+        assert_svd(tensor, u, s, vh);
+    }
+
+    #[test]
+    fn should_support_svd_wide() {
+        let device = Default::default();
+        let tensor = TestTensor::<2>::from_floats([[3.0, 2.0, 2.0], [2.0, 3.0, -2.0]], &device);
+
+        let (u, s, vh) = linalg::svd(tensor.clone());
+        let expected = TensorData::from([[5.0, 3.0]]).convert::<FloatElem>();
+
+        assert_eq!(u.dims(), [2, 2]);
+        assert_eq!(vh.dims(), [2, 3]);
+        s.clone().into_data().assert_approx_eq(&expected, 3);
+        assert_svd(tensor, u, s, vh);
+    }
+
+    fn assert_svd<const D: usize>(
+        tensor: TestTensor<D>,
+        u: TestTensor<D>,
+        s: TestTensor<D>,
+        vh: TestTensor<D>,
+    ) {
+        let k = s.dims()[D - 1];
+        let identity = Tensor::<TestBackend, 2>::eye(k, &s.device()).unsqueeze::<D>();
+        let expected = identity
+            .clone()
+            .expand(u.clone().transpose().matmul(u.clone()).shape());
+
+        u.clone()
+            .transpose()
+            .matmul(u.clone())
+            .into_data()
+            .assert_approx_eq(&expected.clone().into_data(), 3);
+        vh.clone()
+            .matmul(vh.clone().transpose())
+            .into_data()
+            .assert_approx_eq(&expected.into_data(), 3);
+        u.mul(s)
+            .matmul(vh)
+            .into_data()
+            .assert_approx_eq(&tensor.into_data().convert::<FloatElem>(), 3);
+    }
+}
@@ -1,7 +1,9 @@
 mod activation;
 mod clone_invariance;
+mod linalg;
 mod module;
 mod ops;
+mod signal;
 mod stats;
 
 #[allow(missing_docs)]
@@ -13,10 +15,14 @@ macro_rules! testgen_all {
         burn_tensor::testgen_mish!();
         burn_tensor::testgen_relu!();
         burn_tensor::testgen_leaky_relu!();
+        burn_tensor::testgen_elu!();
+        burn_tensor::testgen_selu!();
         burn_tensor::testgen_softmax!();
         burn_tensor::testgen_softplus!();
         burn_tensor::testgen_sigmoid!();
         burn_tensor::testgen_log_sigmoid!();
+        burn_tensor::testgen_hard_sigmoid!();
+        burn_tensor::testgen_hard_swish!();
         burn_tensor::testgen_silu!();
         burn_tensor::testgen_tanh_activation!();
 
@@ -52,10 +58,14 @@ macro_rules! testgen_all {
         burn_tensor::testgen_close!();
         burn_tensor::testgen_cos!();
         burn_tensor::testgen_create_like!();
+        burn_tensor::testgen_cumulative!();
         burn_tensor::testgen_div!();
+        burn_tensor::testgen_einsum!();
         burn_tensor::testgen_erf!();
         burn_tensor::testgen_exp!();
         burn_tensor::testgen_flatten!();
+        burn_tensor::testgen_floor_div!();
+        burn_tensor::testgen_fmod!();
         burn_tensor::testgen_full!();
         burn_tensor::testgen_gather_scatter!();
         burn_tensor::testgen_init!();
@@ -75,6 +85,7 @@ macro_rules! testgen_all {
         burn_tensor::testgen_recip!();
         burn_tensor::testgen_repeat!();
         burn_tensor::testgen_reshape!();
+        burn_tensor::testgen_rounding!();
         burn_tensor::testgen_select!();
         burn_tensor::testgen_sin!();
         burn_tensor::testgen_slice!();
@@ -108,6 +119,21 @@ macro_rules! testgen_all {
         burn_tensor::testgen_eye!();
         burn_tensor::testgen_display!();
 
+        // test linalg
+        burn_tensor::testgen_linalg_inv!();
+        burn_tensor::testgen_linalg_det!();
+        burn_tensor::testgen_linalg_solve!();
+        burn_tensor::testgen_linalg_cholesky!();
+        burn_tensor::testgen_linalg_qr!();
+        burn_tensor::testgen_linalg_svd!();
+        burn_tensor::testgen_linalg_eigh!();
+
+        // test signal
+        burn_tensor::testgen_signal_fft!();
+        burn_tensor::testgen_signal_stft!();
+        burn_tensor::testgen_signal_window!();
+        burn_tensor::testgen_signal_mel!();
+
         // test clone invariance
         burn_tensor::testgen_clone_invariance!();
 
@@ -0,0 +1,126 @@
+#[burn_tensor_testgen::testgen(cumulative)]
+mod tests {
+    use super::*;
+    use burn_tensor::{Int, Tensor, TensorData};
+
+    #[test]
+    fn test_cumsum_float_dim_0() {
+        let tensor = TestTensor::<2>::from([[1.0, 2.0, 3.0], [4.0, 5.0, 6.0], [7.0, 8.0, 9.0]]);
+
+        let output = tensor.cumsum(0);
+        let expected = TensorData::from([[1.0, 2.0, 3.0], [5.0, 7.0, 9.0], [12.0, 15.0, 18.0]]);
+
+        output.into_data().assert_approx_eq(&expected, 3);
+    }
+
+    #[test]
+    fn test_cumsum_float_dim_1() {
+        let tensor =
+            TestTensor::<2>::from([[1.0, 2.0, 3.0, 4.0, 5.0], [-1.0, 0.5, 2.0, -3.0, 1.5]]);
+
+        let output = tensor.cumsum(1);
+        let expected =
+            TensorData::from([[1.0, 3.0, 6.0, 10.0, 15.0], [-1.0, -0.5, 1.5, -1.5, 0.0]]);
+
+        output.into_data().assert_approx_eq(&expected, 3);
+    }
+
+    #[test]
+    fn test_cumsum_int() {
+        let tensor = TestTensorInt::<3>::from([[[1, 2, 3], [4, 5, 6]], [[7, 8, 9], [10, 11, 12]]]);
+
+        let output = tensor.cumsum(1);
+        let expected = TensorData::from([[[1, 2, 3], [5, 7, 9]], [[7, 8, 9], [17, 19, 21]]]);
+
+        output.into_data().assert_eq(&expected, false);
+    }
+
+    #[test]
+    fn test_cumsum_single_element_dim() {
+        let tensor = TestTensor::<2>::from([[1.0], [2.0]]);
+
+        let output = tensor.clone().cumsum(1);
+
+        output.into_data().assert_eq(&tensor.into_data(), true);
+    }
+
+    #[test]
+    fn test_cumprod_float() {
+        let tensor = TestTensor::<2>::from([
+            [1.0, 2.0, 3.0, 4.0, 5.0, 6.0],
+            [2.0, 0.5, -1.0, 0.0, 3.0, 1.0],
+        ]);
+
+        let output = tensor.cumprod(1);
+        let expected = TensorData::from([
+            [1.0, 2.0, 6.0, 24.0, 120.0, 720.0],
+            [2.0, 1.0, -1.0, 0.0, 0.0, 0.0],
+        ]);
+
+        output.into_data().assert_approx_eq(&expected, 3);
+    }
+
+    #[test]
+    fn test_cumprod_int() {
+        let tensor = TestTensorInt::<2>::from([[1, 2, 3], [-2, 4, -1]]);
+
+        let output = tensor.cumprod(0);
+        let expected = TensorData::from([[1, 2, 3], [-2, 8, -3]]);
+
+        output.into_data().assert_eq(&expected, false);
+    }
+
+    #[test]
+    fn test_cummax_float() {
+        let tensor = TestTensor::<2>::from([
+            [1.0, 3.0, 2.0, 5.0, 4.0, 0.0, 6.0],
+            [-1.0, -3.0, -0.5, -2.0, 0.0, -1.0, 1.0],
+        ]);
+
+        let output = tensor.cummax(1);
+        let expected = TensorData::from([
+            [1.0, 3.0, 3.0, 5.0, 5.0, 5.0, 6.0],
+            [-1.0, -1.0, -0.5, -0.5, 0.0, 0.0, 1.0],
+        ]);
+
+        output.into_data().assert_approx_eq(&expected, 3);
+    }
+
+    #[test]
+    fn test_cummax_int() {
+        let tensor = TestTensorInt::<1>::from([2, 1, 4, 3, 4, 7, -1]);
+
+        let output = tensor.cummax(0);
+        let expected = TensorData::from([2, 2, 4, 4, 4, 7, 7]);
+
+        output.into_data().assert_eq(&expected, false);
+    }
+
+    #[test]
+    fn test_cummin_float() {
+        let tensor = TestTensor::<2>::from([[3.0, 1.0], [2.0, 4.0], [5.0, 0.0]]);
+
+        let output = tensor.cummin(0);
+        let expected = TensorData::from([[3.0, 1.0], [2.0, 1.0], [2.0, 0.0]]);
+
+        output.into_data().assert_approx_eq(&expected, 3);
+    }
+
+    #[test]
+    fn test_cummin_int() {
+        let tensor = TestTensorInt::<2>::from([[5, 3, 4, 1, 2], [0, 1, -2, 3, -1]]);
+
+        let output = tensor.cummin(1);
+        let expected = TensorData::from([[5, 3, 3, 1, 1], [0, 0, -2, -2, -2]]);
+
+        output.into_data().assert_eq(&expected, false);
+    }
+
+    #[test]
+    #[should_panic]
+    fn test_cumsum_invalid_dim() {
+        let tensor = TestTensor::<2>::from([[1.0, 2.0], [3.0, 4.0]]);
+
+        let _output = tensor.cumsum(2);
+    }
+}
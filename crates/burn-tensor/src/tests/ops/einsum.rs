@@ -0,0 +1,241 @@
+#[burn_tensor_testgen::testgen(einsum)]
+mod tests {
+    use super::*;
+    use burn_tensor::{Tensor, TensorData};
+
+    #[test]
+    fn should_support_einsum_matmul() {
+        let device = Default::default();
+        let tensor_1 = TestTensor::<2>::from_floats([[1.0, 7.0], [2.0, 3.0], [1.0, 5.0]], &device);
+        let tensor_2 = TestTensor::<2>::from_floats([[4.0, 7.0, 5.0], [2.0, 3.0, 5.0]], &device);
+
+        let explicit: Tensor<TestBackend, 2> = Tensor::einsum(
+            "ij,jk->ik",
+            vec![tensor_1.clone().into(), tensor_2.clone().into()],
+        );
+        let implicit: Tensor<TestBackend, 2> =
+            Tensor::einsum("ij,jk", vec![tensor_1.into(), tensor_2.into()]);
+        let expected =
+            TensorData::from([[18.0, 28.0, 40.0], [14.0, 23.0, 25.0], [14.0, 22.0, 30.0]]);
+
+        explicit.into_data().assert_approx_eq(&expected, 3);
+        implicit.into_data().assert_approx_eq(&expected, 3);
+    }
+
+    #[test]
+    fn should_support_einsum_attention() {
+        let device = Default::default();
+        let query = TestTensor::<4>::from_floats(
+            [[
+                [[1.0, 2.0], [0.0, -1.0], [3.0, 1.0]],
+                [[2.0, 0.5], [1.0, 1.0], [-2.0, 0.0]],
+            ]],
+            &device,
+        );
+        let key = TestTensor::<4>::from_floats(
+            [[[[0.5, 1.0], [2.0, -1.0]], [[1.0, 3.0], [0.0, 2.0]]]],
+            &device,
+        );
+
+        let scores: Tensor<TestBackend, 4> = Tensor::einsum(
+            "bhqd,bhkd->bhqk",
+            vec![query.clone().into(), key.clone().into()],
+        );
+        let expected = query.matmul(key.swap_dims(2, 3));
+
+        assert_eq!(scores.dims(), [1, 2, 3, 2]);
+        scores
+            .into_data()
+            .assert_approx_eq(&expected.into_data(), 3);
+    }
+
+    #[test]
+    fn should_support_einsum_transpose() {
+        let device = Default::default();
+        let tensor = TestTensor::<2>::from_floats([[1.0, 2.0, 3.0], [4.0, 5.0, 6.0]], &device);
+
+        let output: Tensor<TestBackend, 2> = Tensor::einsum("ij->ji", vec![tensor.into()]);
+        let expected = TensorData::from([[1.0, 4.0], [2.0, 5.0], [3.0, 6.0]]);
+
+        output.into_data().assert_approx_eq(&expected, 3);
+    }
+
+    #[test]
+    fn should_support_einsum_outer_product() {
+        let device = Default::default();
+        let tensor_1 = TestTensor::<1>::from_floats([1.0, 2.0], &device);
+        let tensor_2 = TestTensor::<1>::from_floats([3.0, 4.0, 5.0], &device);
+
+        let output: Tensor<TestBackend, 2> =
+            Tensor::einsum("i,j->ij", vec![tensor_1.into(), tensor_2.into()]);
+        let expected = TensorData::from([[3.0, 4.0, 5.0], [6.0, 8.0, 10.0]]);
+
+        output.into_data().assert_approx_eq(&expected, 3);
+    }
+
+    #[test]
+    fn should_support_einsum_sum_and_batch_dot() {
+        let device = Default::default();
+        let tensor_1 = TestTensor::<2>::from_floats([[1.0, 2.0], [3.0, 4.0]], &device);
+        let tensor_2 = TestTensor::<2>::from_floats([[5.0, 6.0], [7.0, 8.0]], &device);
+
+        let sum: Tensor<TestBackend, 1> = Tensor::einsum("ij->", vec![tensor_1.clone().into()]);
+        let dot: Tensor<TestBackend, 1> =
+            Tensor::einsum("bi,bi->b", vec![tensor_1.into(), tensor_2.into()]);
+
+        sum.into_data()
+            .assert_approx_eq(&TensorData::from([10.0]), 3);
+        dot.into_data()
+            .assert_approx_eq(&TensorData::from([17.0, 53.0]), 3);
+    }
+
+    #[test]
+    fn should_support_einsum_repeated_label() {
+        let device = Default::default();
+        let tensor = TestTensor::<2>::from_floats([[1.0, 2.0], [3.0, 4.0]], &device);
+
+        let trace: Tensor<TestBackend, 1> = Tensor::einsum("ii->", vec![tensor.clone().into()]);
+        let diagonal: Tensor<TestBackend, 1> = Tensor::einsum("ii->i", vec![tensor.into()]);
+
+        trace
+            .into_data()
+            .assert_approx_eq(&TensorData::from([5.0]), 3);
+        diagonal
+            .into_data()
+            .assert_approx_eq(&TensorData::from([1.0, 4.0]), 3);
+    }
+
+    #[test]
+    fn should_support_einsum_ellipsis_broadcast() {
+        let device = Default::default();
+        let tensor_1 = TestTensor::<3>::from_floats([[[1.0, 2.0]], [[3.0, 4.0]]], &device);
+        let tensor_2 = TestTensor::<3>::from_floats([[[5.0], [6.0]]], &device);
+
+        let explicit: Tensor<TestBackend, 3> = Tensor::einsum(
+            "...ij,...jk->...ik",
+            vec![tensor_1.clone().into(), tensor_2.clone().into()],
+        );
+        let implicit: Tensor<TestBackend, 3> =
+            Tensor::einsum("...ij,...jk", vec![tensor_1.into(), tensor_2.into()]);
+        let expected = TensorData::from([[[17.0]], [[39.0]]]);
+
+        explicit.into_data().assert_approx_eq(&expected, 3);
+        implicit.into_data().assert_approx_eq(&expected, 3);
+    }
+
+    #[test]
+    fn should_align_ellipsis_to_the_right() {
+        let device = Default::default();
+        let tensor_1 = TestTensor::<2>::from_floats([[1.0, 2.0, 3.0], [4.0, 5.0, 6.0]], &device);
+        let tensor_2 = TestTensor::<2>::from_floats([[1.0, 0.0], [0.0, 1.0], [1.0, 1.0]], &device);
+
+        let output: Tensor<TestBackend, 3> =
+            Tensor::einsum("...,...i->...i", vec![tensor_1.into(), tensor_2.into()]);
+        let expected = TensorData::from([
+            [[1.0, 0.0], [0.0, 2.0], [3.0, 3.0]],
+            [[4.0, 0.0], [0.0, 5.0], [6.0, 6.0]],
+        ]);
+
+        output.into_data().assert_approx_eq(&expected, 3);
+    }
+
+    #[test]
+    fn should_broadcast_einsum_dimensions_of_size_one() {
+        let device = Default::default();
+        let tensor_1 = TestTensor::<2>::from_floats([[2.0], [3.0]], &device);
+        let tensor_2 = TestTensor::<2>::from_floats([[1.0, 2.0, 3.0], [4.0, 5.0, 6.0]], &device);
+
+        let reduced: Tensor<TestBackend, 1> = Tensor::einsum(
+            "ij,ij->i",
+            vec![tensor_1.clone().into(), tensor_2.clone().into()],
+        );
+        let broadcast: Tensor<TestBackend, 2> =
+            Tensor::einsum("ij,ij->ij", vec![tensor_1.into(), tensor_2.into()]);
+
+        reduced
+            .into_data()
+            .assert_approx_eq(&TensorData::from([12.0, 45.0]), 3);
+        broadcast
+            .into_data()
+            .assert_approx_eq(&TensorData::from([[2.0, 4.0, 6.0], [12.0, 15.0, 18.0]]), 3);
+    }
+
+    #[test]
+    fn should_support_einsum_three_operands() {
+        let device = Default::default();
+        let tensor_1 = TestTensor::<2>::from_floats([[1.0, 2.0], [3.0, 4.0]], &device);
+        let tensor_2 = TestTensor::<2>::from_floats([[0.5, -1.0, 2.0], [1.0, 0.0, 1.0]], &device);
+        let tensor_3 = TestTensor::<2>::from_floats([[1.0], [2.0], [-1.0]], &device);
+
+        let output: Tensor<TestBackend, 2> = Tensor::einsum(
+            "ij,jk,kl->il",
+            vec![
+                tensor_1.clone().into(),
+                tensor_2.clone().into(),
+                tensor_3.clone().into(),
+            ],
+        );
+        let expected = tensor_1.matmul(tensor_2).matmul(tensor_3);
+
+        output
+            .into_data()
+            .assert_approx_eq(&expected.into_data(), 3);
+    }
+
+    #[test]
+    fn should_support_einsum_mixed_ranks() {
+        let device = Default::default();
+        let matrix = TestTensor::<2>::from_floats([[1.0, 2.0], [3.0, 4.0], [5.0, 6.0]], &device);
+        let vector = TestTensor::<1>::from_floats([1.0, -1.0], &device);
+        let batch = TestTensor::<3>::from_floats(
+            [
+                [[1.0, 2.0, 3.0], [4.0, 5.0, 6.0]],
+                [[-1.0, 0.0, 1.0], [2.0, 2.0, 2.0]],
+            ],
+            &device,
+        );
+
+        let product: Tensor<TestBackend, 1> =
+            Tensor::einsum("ij,j->i", vec![matrix.clone().into(), vector.into()]);
+        let batched: Tensor<TestBackend, 3> = Tensor::einsum(
+            "bij,jk->bik",
+            vec![batch.clone().into(), matrix.clone().into()],
+        );
+
+        product
+            .into_data()
+            .assert_approx_eq(&TensorData::from([-1.0, -1.0, -1.0]), 3);
+        batched
+            .into_data()
+            .assert_approx_eq(&batch.matmul(matrix.unsqueeze()).into_data(), 3);
+    }
+
+    #[test]
+    #[should_panic]
+    fn should_panic_when_einsum_sizes_mismatch() {
+        let device = Default::default();
+        let tensor_1 = TestTensor::<2>::zeros([2, 3], &device);
+        let tensor_2 = TestTensor::<2>::zeros([2, 3], &device);
+
+        let _: Tensor<TestBackend, 2> =
+            Tensor::einsum("ij,jk->ik", vec![tensor_1.into(), tensor_2.into()]);
+    }
+
+    #[test]
+    #[should_panic]
+    fn should_panic_when_einsum_output_rank_mismatch() {
+        let device = Default::default();
+        let tensor = TestTensor::<2>::zeros([2, 3], &device);
+
+        let _: Tensor<TestBackend, 3> = Tensor::einsum("ij->ji", vec![tensor.into()]);
+    }
+
+    #[test]
+    #[should_panic]
+    fn should_panic_when_einsum_term_rank_mismatch() {
+        let device = Default::default();
+        let tensor = TestTensor::<2>::zeros([2, 3], &device);
+
+        let _: Tensor<TestBackend, 1> = Tensor::einsum("ijk->i", vec![tensor.into()]);
+    }
+}
@@ -99,6 +99,17 @@ mod tests {
             .assert_eq(&TensorData::from([[1, 2, 3], [1, 2, 3], [1, 2, 3]]), false);
     }
 
+    #[test]
+    fn expand_int_keeps_values_when_followed_by_int_ops() {
+        let tensor = TestTensorInt::<2>::from([[0, -4], [7, 2]]);
+        let output = tensor.expand([2, 2, 2]).add_scalar(1);
+
+        output.into_data().assert_eq(
+            &TensorData::from([[[1, -3], [8, 3]], [[1, -3], [8, 3]]]),
+            false,
+        );
+    }
+
     #[test]
     fn should_all_negative_one() {
         let tensor = TestTensorInt::<1>::from([1, 2, 3]);
@@ -109,6 +120,16 @@ mod tests {
             .assert_eq(&TensorData::from([[1, 2, 3], [1, 2, 3]]), false);
     }
 
+    #[test]
+    fn should_support_i64_dims() {
+        let tensor = TestTensorInt::<1>::from([1, 2, 3]);
+        let output = tensor.expand([2i64, -1]);
+
+        output
+            .into_data()
+            .assert_eq(&TensorData::from([[1, 2, 3], [1, 2, 3]]), false);
+    }
+
     #[test]
     #[should_panic]
     fn should_panic_negative_one_on_non_existing_dim() {
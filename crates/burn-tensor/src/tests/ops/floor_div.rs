@@ -0,0 +1,52 @@
+#[burn_tensor_testgen::testgen(floor_div)]
+mod tests {
+    use super::*;
+    use burn_tensor::{Int, Tensor, TensorData};
+
+    #[test]
+    fn should_support_floor_div_ops() {
+        let device = Default::default();
+        let lhs =
+            Tensor::<TestBackend, 2, Int>::from_data(TensorData::from([[7, -7], [7, -7]]), &device);
+        let rhs =
+            Tensor::<TestBackend, 2, Int>::from_data(TensorData::from([[2, 2], [-2, -2]]), &device);
+
+        let output = lhs.floor_div(rhs);
+
+        output
+            .into_data()
+            .assert_eq(&TensorData::from([[3, -4], [-4, 3]]), false);
+    }
+
+    #[test]
+    fn should_support_floor_div_broadcast() {
+        let device = Default::default();
+        let lhs = Tensor::<TestBackend, 2, Int>::from_data(
+            TensorData::from([[6, -6, 5], [-5, 0, 9]]),
+            &device,
+        );
+        let rhs = Tensor::<TestBackend, 2, Int>::from_data(TensorData::from([[3, 4, -2]]), &device);
+
+        let output = lhs.floor_div(rhs);
+
+        output
+            .into_data()
+            .assert_eq(&TensorData::from([[2, -2, -3], [-2, 0, -5]]), false);
+    }
+
+    #[test]
+    fn should_support_floor_div_scalar() {
+        let data = TensorData::from([-7, -6, -1, 0, 1, 6, 7]);
+        let tensor = Tensor::<TestBackend, 1, Int>::from_data(data, &Default::default());
+
+        let output = tensor.clone().floor_div_scalar(3);
+        output
+            .into_data()
+            .assert_eq(&TensorData::from([-3, -2, -1, 0, 0, 2, 2]), false);
+
+        let output = tensor.floor_div_scalar(-3);
+        output
+            .into_data()
+            .assert_eq(&TensorData::from([2, 2, 0, 0, -1, -2, -3]), false);
+    }
+}
@@ -0,0 +1,63 @@
+#[burn_tensor_testgen::testgen(fmod)]
+mod tests {
+    use super::*;
+    use burn_tensor::{Tensor, TensorData};
+
+    /// From https://pytorch.org/docs/stable/generated/torch.fmod.html
+    #[test]
+    fn should_support_fmod_scalar() {
+        let data = TensorData::from([-3.0, -2.0, -1.0, 1.0, 2.0, 3.0]);
+        let tensor = Tensor::<TestBackend, 1>::from_data(data, &Default::default());
+
+        let output = tensor.fmod_scalar(2.0);
+        let expected = TensorData::from([-1.0, 0.0, -1.0, 1.0, 0.0, 1.0]);
+
+        output.into_data().assert_approx_eq(&expected, 3);
+    }
+
+    /// Also from https://pytorch.org/docs/stable/generated/torch.fmod.html
+    #[test]
+    fn should_support_fmod_scalar_negative_divisor() {
+        let data = TensorData::from([1.0, 2.0, 3.0, 4.0, 5.0]);
+        let tensor = Tensor::<TestBackend, 1>::from_data(data, &Default::default());
+
+        let output = tensor.fmod_scalar(-1.5);
+        let expected = TensorData::from([1.0, 0.5, 0.0, 1.0, 0.5]);
+
+        output.into_data().assert_approx_eq(&expected, 3);
+    }
+
+    #[test]
+    fn should_support_fmod_ops() {
+        let device = Default::default();
+        let lhs = Tensor::<TestBackend, 2>::from_data(
+            TensorData::from([[5.5, -5.5], [7.0, -7.0]]),
+            &device,
+        );
+        let rhs = Tensor::<TestBackend, 2>::from_data(
+            TensorData::from([[2.0, 2.0], [-3.0, -3.0]]),
+            &device,
+        );
+
+        let output = lhs.fmod(rhs);
+        let expected = TensorData::from([[1.5, -1.5], [1.0, -1.0]]);
+
+        output.into_data().assert_approx_eq(&expected, 3);
+    }
+
+    #[test]
+    fn should_support_fmod_broadcast() {
+        let device = Default::default();
+        let lhs = Tensor::<TestBackend, 2>::from_data(
+            TensorData::from([[-4.0, 5.0, 6.5], [4.0, -5.0, -6.5]]),
+            &device,
+        );
+        let rhs =
+            Tensor::<TestBackend, 2>::from_data(TensorData::from([[3.0, -2.0, 2.5]]), &device);
+
+        let output = lhs.fmod(rhs);
+        let expected = TensorData::from([[-1.0, 1.0, 1.5], [1.0, -1.0, -1.5]]);
+
+        output.into_data().assert_approx_eq(&expected, 3);
+    }
+}
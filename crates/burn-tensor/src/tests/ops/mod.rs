@@ -16,12 +16,16 @@ mod clamp;
 mod close;
 mod cos;
 mod create_like;
+mod cumulative;
 mod div;
+mod einsum;
 mod erf;
 mod exp;
 mod expand;
 mod flatten;
 mod flip;
+mod floor_div;
+mod fmod;
 mod full;
 mod gather_scatter;
 mod init;
@@ -46,6 +50,7 @@ mod recip;
 mod remainder;
 mod repeat;
 mod reshape;
+mod rounding;
 mod select;
 mod sign;
 mod sin;
@@ -100,4 +100,35 @@ mod tests {
 
         output.into_data().assert_approx_eq(&expected, 3);
     }
+
+    #[test]
+    fn should_support_int_remainder() {
+        let device = Default::default();
+        let lhs =
+            Tensor::<TestBackend, 2, Int>::from_data(TensorData::from([[7, -7], [7, -7]]), &device);
+        let rhs =
+            Tensor::<TestBackend, 2, Int>::from_data(TensorData::from([[2, 2], [-2, -2]]), &device);
+
+        let output = lhs.remainder(rhs);
+
+        output
+            .into_data()
+            .assert_eq(&TensorData::from([[1, 1], [-1, -1]]), false);
+    }
+
+    #[test]
+    fn should_support_int_remainder_broadcast() {
+        let device = Default::default();
+        let lhs = Tensor::<TestBackend, 2, Int>::from_data(
+            TensorData::from([[6, -6, 5], [-5, 0, 9]]),
+            &device,
+        );
+        let rhs = Tensor::<TestBackend, 2, Int>::from_data(TensorData::from([[3, 4, -2]]), &device);
+
+        let output = lhs.remainder(rhs);
+
+        output
+            .into_data()
+            .assert_eq(&TensorData::from([[0, 2, -1], [1, 0, -1]]), false);
+    }
 }
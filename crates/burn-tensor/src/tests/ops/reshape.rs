@@ -74,6 +74,16 @@ mod tests {
         assert_eq!(reshaped.shape(), [4, 3].into());
     }
 
+    #[test]
+    fn should_support_i64_dims() {
+        let tensor = TestTensor::<2>::from([[0.0, 1.0, 2.0], [3.0, 4.0, 5.0]]);
+
+        // Dims computed at runtime from another shape, as in imported models
+        let dims = tensor.dims();
+        let reshaped = tensor.reshape([dims[0] as i64, -1, 1]);
+        assert_eq!(reshaped.shape(), [2, 3, 1].into());
+    }
+
     #[test]
     fn should_not_corrupt_after_slice() {
         let zeros = Tensor::<TestBackend, 1>::zeros([2], &Default::default());
@@ -0,0 +1,60 @@
+#[burn_tensor_testgen::testgen(rounding)]
+mod tests {
+    use super::*;
+    use burn_tensor::{Tensor, TensorData};
+
+    #[test]
+    fn should_support_floor_ops() {
+        let data = TensorData::from([[-1.5, -0.5, 0.0], [0.5, 1.5, 2.7]]);
+        let tensor = Tensor::<TestBackend, 2>::from_data(data, &Default::default());
+
+        let output = tensor.floor();
+        let expected = TensorData::from([[-2.0, -1.0, 0.0], [0.0, 1.0, 2.0]]);
+
+        output.into_data().assert_approx_eq(&expected, 3);
+    }
+
+    #[test]
+    fn should_support_ceil_ops() {
+        let data = TensorData::from([[-1.5, -0.5, 0.0], [0.5, 1.5, 2.7]]);
+        let tensor = Tensor::<TestBackend, 2>::from_data(data, &Default::default());
+
+        let output = tensor.ceil();
+        let expected = TensorData::from([[-1.0, 0.0, 0.0], [1.0, 2.0, 3.0]]);
+
+        output.into_data().assert_approx_eq(&expected, 3);
+    }
+
+    #[test]
+    fn should_support_round_ops() {
+        let data = TensorData::from([[-2.6, -1.2, -0.4], [0.4, 1.2, 2.6]]);
+        let tensor = Tensor::<TestBackend, 2>::from_data(data, &Default::default());
+
+        let output = tensor.round();
+        let expected = TensorData::from([[-3.0, -1.0, 0.0], [0.0, 1.0, 3.0]]);
+
+        output.into_data().assert_approx_eq(&expected, 3);
+    }
+
+    #[test]
+    fn should_round_halfway_values_to_even() {
+        let data = TensorData::from([-2.5, -1.5, -0.5, 0.5, 1.5, 2.5]);
+        let tensor = Tensor::<TestBackend, 1>::from_data(data, &Default::default());
+
+        let output = tensor.round();
+        let expected = TensorData::from([-2.0, -2.0, 0.0, 0.0, 2.0, 2.0]);
+
+        output.into_data().assert_approx_eq(&expected, 3);
+    }
+
+    #[test]
+    fn should_support_trunc_ops() {
+        let data = TensorData::from([[-2.7, -1.5, -0.5], [0.5, 1.5, 2.7]]);
+        let tensor = Tensor::<TestBackend, 2>::from_data(data, &Default::default());
+
+        let output = tensor.trunc();
+        let expected = TensorData::from([[-2.0, -1.0, 0.0], [0.0, 1.0, 2.0]]);
+
+        output.into_data().assert_approx_eq(&expected, 3);
+    }
+}
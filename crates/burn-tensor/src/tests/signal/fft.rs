@@ -0,0 +1,140 @@
+#[burn_tensor_testgen::testgen(signal_fft)]
+mod tests {
+    use super::*;
+    use burn_tensor::backend::Backend;
+    use burn_tensor::{signal, Tensor, TensorData};
+
+    type FloatElem = <TestBackend as Backend>::FloatElem;
+
+    #[test]
+    fn should_support_fft() {
+        let device = Default::default();
+        let re = TestTensor::<1>::from_floats([1.0, 2.0, 3.0, 4.0], &device);
+        let im = TestTensor::<1>::zeros([4], &device);
+
+        let (re, im) = signal::fft(re, im, 0);
+        let expected_re = TensorData::from([10.0, -2.0, -2.0, -2.0]).convert::<FloatElem>();
+        let expected_im = TensorData::from([0.0, 2.0, 0.0, -2.0]).convert::<FloatElem>();
+
+        re.into_data().assert_approx_eq(&expected_re, 3);
+        im.into_data().assert_approx_eq(&expected_im, 3);
+    }
+
+    #[test]
+    fn should_support_fft_complex_dim() {
+        let device = Default::default();
+        let re = TestTensor::<2>::from_floats([[1.0, 2.0, 3.0], [0.0, -1.0, 0.0]], &device);
+        let im = TestTensor::<2>::from_floats([[0.0, 1.0, 0.0], [0.0, 0.0, 2.0]], &device);
+
+        let (re, im) = signal::fft(re, im, 1);
+        let expected_re = TensorData::from([[6.0, -0.634, -2.366], [-1.0, -1.2321, 2.2321]])
+            .convert::<FloatElem>();
+        let expected_im =
+            TensorData::from([[1.0, 0.366, -1.366], [2.0, -0.134, -1.866]]).convert::<FloatElem>();
+
+        re.into_data().assert_approx_eq(&expected_re, 3);
+        im.into_data().assert_approx_eq(&expected_im, 3);
+    }
+
+    #[test]
+    fn should_support_fft_first_dim() {
+        let device = Default::default();
+        let re = TestTensor::<2>::from_floats([[1.0, 2.0], [3.0, 4.0], [5.0, 6.0]], &device);
+        let im = TestTensor::<2>::zeros([3, 2], &device);
+
+        let (re, im) = signal::fft(re, im, 0);
+        let expected_re =
+            TensorData::from([[9.0, 12.0], [-3.0, -3.0], [-3.0, -3.0]]).convert::<FloatElem>();
+        let expected_im = TensorData::from([[0.0, 0.0], [1.7321, 1.7321], [-1.7321, -1.7321]])
+            .convert::<FloatElem>();
+
+        re.into_data().assert_approx_eq(&expected_re, 3);
+        im.into_data().assert_approx_eq(&expected_im, 3);
+    }
+
+    #[test]
+    fn should_support_ifft_round_trip() {
+        let device = Default::default();
+        let re = TestTensor::<2>::from_floats(
+            [
+                [1.0, -2.0, 0.5, 3.0, 0.0, 1.5],
+                [2.0, 0.0, -1.0, 1.0, 4.0, -3.0],
+            ],
+            &device,
+        );
+        let im = TestTensor::<2>::from_floats(
+            [
+                [0.0, 1.0, -1.0, 2.0, 0.5, 0.0],
+                [1.0, 1.0, 0.0, -2.0, 0.0, 3.0],
+            ],
+            &device,
+        );
+
+        let (output_re, output_im) = signal::fft(re.clone(), im.clone(), 1);
+        let (output_re, output_im) = signal::ifft(output_re, output_im, 1);
+
+        output_re.into_data().assert_approx_eq(&re.into_data(), 3);
+        output_im.into_data().assert_approx_eq(&im.into_data(), 3);
+    }
+
+    #[test]
+    fn should_support_rfft() {
+        let device = Default::default();
+        let signal = TestTensor::<1>::from_floats([1.0, 2.0, 3.0, 4.0, 5.0], &device);
+
+        let (re, im) = signal::rfft(signal, 0);
+        let expected_re = TensorData::from([15.0, -2.5, -2.5]).convert::<FloatElem>();
+        let expected_im = TensorData::from([0.0, 3.441, 0.8123]).convert::<FloatElem>();
+
+        re.into_data().assert_approx_eq(&expected_re, 3);
+        im.into_data().assert_approx_eq(&expected_im, 3);
+    }
+
+    #[test]
+    fn should_support_irfft_round_trip() {
+        let device = Default::default();
+
+        for n in [4, 5] {
+            let signal = TestTensor::<2>::from_floats(
+                [[1.0, -2.0, 0.5, 3.0, 2.0], [2.0, 0.0, -1.0, 1.0, 4.0]],
+                &device,
+            )
+            .narrow(1, 0, n);
+
+            let (re, im) = signal::rfft(signal.clone(), 1);
+            assert_eq!(re.dims(), [2, n / 2 + 1]);
+            let output = signal::irfft(re, im, 1, n);
+
+            output.into_data().assert_approx_eq(&signal.into_data(), 3);
+        }
+    }
+
+    #[test]
+    fn should_support_fft2() {
+        let device = Default::default();
+        let re = TestTensor::<2>::from_floats([[1.0, 2.0], [3.0, 4.0]], &device);
+        let im = TestTensor::<2>::zeros([2, 2], &device);
+
+        let (re, im) = signal::fft2(re, im, [0, 1]);
+        let expected_re = TensorData::from([[10.0, -2.0], [-4.0, 0.0]]).convert::<FloatElem>();
+        let expected_im = TensorData::from([[0.0, 0.0], [0.0, 0.0]]).convert::<FloatElem>();
+
+        re.into_data().assert_approx_eq(&expected_re, 3);
+        im.into_data().assert_approx_eq(&expected_im, 3);
+    }
+
+    #[test]
+    fn should_support_irfft2_round_trip() {
+        let device = Default::default();
+        let signal = TestTensor::<3>::from_floats(
+            [[[1.0, -2.0, 0.5], [3.0, 2.0, 0.0], [2.0, 0.0, -1.0]]],
+            &device,
+        );
+
+        let (re, im) = signal::rfft2(signal.clone(), [1, 2]);
+        assert_eq!(re.dims(), [1, 3, 2]);
+        let output = signal::irfft2(re, im, [1, 2], 3);
+
+        output.into_data().assert_approx_eq(&signal.into_data(), 3);
+    }
+}
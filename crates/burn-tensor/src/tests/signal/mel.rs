@@ -0,0 +1,33 @@
+#[burn_tensor_testgen::testgen(signal_mel)]
+mod tests {
+    use super::*;
+    use burn_tensor::backend::Backend;
+    use burn_tensor::{signal, TensorData};
+
+    type FloatElem = <TestBackend as Backend>::FloatElem;
+
+    #[test]
+    fn should_convert_between_hz_and_mel() {
+        let mel = signal::hz_to_mel(1000.0);
+
+        assert!((mel - 999.9855).abs() < 1e-3);
+        assert!((signal::mel_to_hz(mel) - 1000.0).abs() < 1e-6);
+    }
+
+    #[test]
+    fn should_support_mel_filterbank() {
+        let device = Default::default();
+
+        let filterbank = signal::mel_filterbank::<TestBackend>(5, 2, 16000.0, 0.0, 8000.0, &device);
+        let expected = TensorData::from([
+            [0.0, 0.0],
+            [0.4947, 0.5053],
+            [0.0, 0.809],
+            [0.0, 0.4045],
+            [0.0, 0.0],
+        ])
+        .convert::<FloatElem>();
+
+        filterbank.into_data().assert_approx_eq(&expected, 3);
+    }
+}
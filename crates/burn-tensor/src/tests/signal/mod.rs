@@ -0,0 +1,4 @@
+mod fft;
+mod mel;
+mod stft;
+mod window;
@@ -0,0 +1,92 @@
+#[burn_tensor_testgen::testgen(signal_stft)]
+mod tests {
+    use super::*;
+    use burn_tensor::backend::Backend;
+    use burn_tensor::{signal, Tensor, TensorData};
+
+    type FloatElem = <TestBackend as Backend>::FloatElem;
+
+    #[test]
+    fn should_support_stft() {
+        let device = Default::default();
+        let signal = TestTensor::<2>::from_floats([[1.0, 2.0, 3.0, 4.0, 5.0, 6.0]], &device);
+
+        let (re, im) = signal::stft(signal, 4, 2, None, false);
+        let expected_re =
+            TensorData::from([[[10.0, -2.0, -2.0], [18.0, -2.0, -2.0]]]).convert::<FloatElem>();
+        let expected_im =
+            TensorData::from([[[0.0, 2.0, 0.0], [0.0, 2.0, 0.0]]]).convert::<FloatElem>();
+
+        re.into_data().assert_approx_eq(&expected_re, 3);
+        im.into_data().assert_approx_eq(&expected_im, 3);
+    }
+
+    #[test]
+    fn should_support_stft_centered_with_window() {
+        let device = Default::default();
+        let signal =
+            TestTensor::<2>::from_floats([[1.0, 2.0, 3.0, 4.0, 5.0, 6.0, 7.0, 8.0]], &device);
+        let window = signal::hann_window::<TestBackend>(4, true, &device);
+
+        let (re, im) = signal::stft(signal, 4, 2, Some(window), true);
+        let expected_re = TensorData::from([[
+            [3.0, -1.0, -1.0],
+            [6.0, -3.0, 0.0],
+            [10.0, -5.0, 0.0],
+            [14.0, -7.0, 0.0],
+            [14.0, -7.0, 0.0],
+        ]])
+        .convert::<FloatElem>();
+        let expected_im = TensorData::from([[
+            [0.0, 0.0, 0.0],
+            [0.0, 1.0, 0.0],
+            [0.0, 1.0, 0.0],
+            [0.0, 1.0, 0.0],
+            [0.0, -1.0, 0.0],
+        ]])
+        .convert::<FloatElem>();
+
+        re.into_data().assert_approx_eq(&expected_re, 3);
+        im.into_data().assert_approx_eq(&expected_im, 3);
+    }
+
+    #[test]
+    fn should_support_istft_round_trip() {
+        let device = Default::default();
+        let signal = TestTensor::<2>::from_floats(
+            [
+                [1.0, -2.0, 0.5, 3.0, 2.0, 0.0, -1.0, 1.5, 0.5, -0.5],
+                [2.0, 0.0, -1.0, 1.0, 4.0, -3.0, 0.5, 0.0, 1.0, 2.0],
+            ],
+            &device,
+        );
+        let window = signal::hann_window::<TestBackend>(4, true, &device);
+
+        let (re, im) = signal::stft(signal.clone(), 4, 1, Some(window.clone()), true);
+        let output = signal::istft(re, im, 4, 1, Some(window), true, Some(10));
+
+        output.into_data().assert_approx_eq(&signal.into_data(), 3);
+    }
+
+    #[test]
+    fn should_support_istft_without_window() {
+        let device = Default::default();
+        let signal =
+            TestTensor::<2>::from_floats([[1.0, -2.0, 0.5, 3.0, 2.0, 0.0, -1.0, 1.5]], &device);
+
+        let (re, im) = signal::stft(signal.clone(), 4, 2, None, false);
+        let output = signal::istft(re, im, 4, 2, None, false, None);
+
+        output.into_data().assert_approx_eq(&signal.into_data(), 3);
+    }
+
+    #[test]
+    #[should_panic]
+    fn should_panic_when_window_size_differs_from_frames() {
+        let device = Default::default();
+        let signal = TestTensor::<2>::zeros([1, 8], &device);
+        let window = signal::hann_window::<TestBackend>(3, true, &device);
+
+        let _ = signal::stft(signal, 4, 2, Some(window), false);
+    }
+}
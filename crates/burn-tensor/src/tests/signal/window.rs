@@ -0,0 +1,60 @@
+#[burn_tensor_testgen::testgen(signal_window)]
+mod tests {
+    use super::*;
+    use burn_tensor::backend::Backend;
+    use burn_tensor::{signal, TensorData};
+
+    type FloatElem = <TestBackend as Backend>::FloatElem;
+
+    #[test]
+    fn should_support_hann_window() {
+        let device = Default::default();
+
+        let periodic = signal::hann_window::<TestBackend>(4, true, &device);
+        let symmetric = signal::hann_window::<TestBackend>(5, false, &device);
+
+        periodic.into_data().assert_approx_eq(
+            &TensorData::from([0.0, 0.5, 1.0, 0.5]).convert::<FloatElem>(),
+            3,
+        );
+        symmetric.into_data().assert_approx_eq(
+            &TensorData::from([0.0, 0.5, 1.0, 0.5, 0.0]).convert::<FloatElem>(),
+            3,
+        );
+    }
+
+    #[test]
+    fn should_support_hamming_window() {
+        let device = Default::default();
+
+        let window = signal::hamming_window::<TestBackend>(3, false, &device);
+
+        window.into_data().assert_approx_eq(
+            &TensorData::from([0.08, 1.0, 0.08]).convert::<FloatElem>(),
+            3,
+        );
+    }
+
+    #[test]
+    fn should_support_blackman_window() {
+        let device = Default::default();
+
+        let window = signal::blackman_window::<TestBackend>(4, true, &device);
+
+        window.into_data().assert_approx_eq(
+            &TensorData::from([0.0, 0.34, 1.0, 0.34]).convert::<FloatElem>(),
+            3,
+        );
+    }
+
+    #[test]
+    fn should_support_window_of_size_one() {
+        let device = Default::default();
+
+        let window = signal::hann_window::<TestBackend>(1, false, &device);
+
+        window
+            .into_data()
+            .assert_approx_eq(&TensorData::from([1.0]).convert::<FloatElem>(), 3);
+    }
+}
@@ -70,17 +70,62 @@ where
     }
 
     fn delete(&self, epoch: usize) -> Result<(), CheckpointerError> {
-        let file_to_remove = format!(
-            "{}.{}",
-            self.path_for_epoch(epoch).display(),
-            FR::file_extension(),
-        );
+        let file_path = self.path_for_epoch(epoch);
+        log::info!("Removing checkpoint {} at {}", epoch, file_path.display());
 
-        if std::path::Path::new(&file_to_remove).exists() {
-            log::info!("Removing checkpoint {}", file_to_remove);
-            std::fs::remove_file(file_to_remove).map_err(CheckpointerError::IOError)?;
-        }
+        // The recorder knows all the files of a checkpoint, e.g. the shards of a sharded record
+        self.recorder
+            .remove_files(file_path)
+            .map_err(CheckpointerError::RecorderError)?;
 
         Ok(())
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::TestBackend;
+    use burn_core::{
+        module::Module,
+        nn::{Linear, LinearConfig, LinearRecord},
+        record::{FullPrecisionSettings, ShardedFileRecorder},
+    };
+
+    #[test]
+    fn should_save_restore_and_delete_sharded_checkpoints() {
+        let device = Default::default();
+        let directory = std::env::temp_dir().join("burn_test_file_checkpointer_sharded");
+        let checkpointer = FileCheckpointer::new(
+            ShardedFileRecorder::<FullPrecisionSettings>::new().with_max_shard_size(64),
+            &directory,
+            "model",
+        );
+        let linear: Linear<TestBackend> = LinearConfig::new(8, 8).init(&device);
+
+        checkpointer.save(1, linear.clone().into_record()).unwrap();
+        let record: LinearRecord<TestBackend> = checkpointer.restore(1, &device).unwrap();
+        LinearConfig::new(8, 8)
+            .init::<TestBackend>(&device)
+            .load_record(record)
+            .weight
+            .val()
+            .into_data()
+            .assert_eq(&linear.weight.val().into_data(), true);
+
+        let checkpoint_files = || {
+            std::fs::read_dir(&directory)
+                .unwrap()
+                .filter(|entry| {
+                    let name = entry.as_ref().unwrap().file_name();
+                    name.to_string_lossy().starts_with("model-1")
+                })
+                .count()
+        };
+        // The index and a shard for each tensor
+        assert_eq!(checkpoint_files(), 3);
+
+        Checkpointer::<LinearRecord<TestBackend>, TestBackend>::delete(&checkpointer, 1).unwrap();
+        assert_eq!(checkpoint_files(), 0);
+    }
+}
@@ -227,6 +227,14 @@ pub enum Instruction {
         input: Variable,
         out: Variable,
     },
+    Round {
+        input: Variable,
+        out: Variable,
+    },
+    Trunc {
+        input: Variable,
+        out: Variable,
+    },
     Remainder {
         lhs: Variable,
         rhs: Variable,
@@ -506,6 +514,12 @@ for (var {i}: u32 = {start}; {i} < {end}; {i}++) {{
             Instruction::Ceil { input, out } => {
                 f.write_fmt(format_args!("{out} = ceil({input});\n"))
             }
+            Instruction::Round { input, out } => {
+                f.write_fmt(format_args!("{out} = round({input});\n"))
+            }
+            Instruction::Trunc { input, out } => {
+                f.write_fmt(format_args!("{out} = trunc({input});\n"))
+            }
             Instruction::Subgroup(op) => f.write_fmt(format_args!("{op}")),
         }
     }
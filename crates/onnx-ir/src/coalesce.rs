@@ -74,29 +74,31 @@ fn transpose_linear_node_weights(node: &mut Node) {
 
     let shape = weight.shape.unwrap();
 
-    match weight.data.expect("Tensor must have data") {
-        Data::Float32s(data) => {
-            let data_t = transpose_flattened(data, shape[0], shape[1]);
-            node.inputs[1].value = Some(Data::Float32s(data_t));
-        }
-        Data::Float64s(data) => {
-            let data_t = transpose_flattened(data, shape[0], shape[1]);
-            node.inputs[1].value = Some(Data::Float64s(data_t));
-        }
-        Data::Float16s(data) => {
-            let data_t = transpose_flattened(data, shape[0], shape[1]);
-            node.inputs[1].value = Some(Data::Float16s(data_t));
-        }
-        _ => panic!("Only float types are supported for Linear node"),
-    }
+    let data = weight.data.expect("Tensor must have data");
+    node.inputs[1].value = Some(transpose_weights(data, shape[0], shape[1]));
+
     let shape = Some(vec![shape[1], shape[0]]); // Transpose the shape
     node.inputs[1].ty = ArgType::Tensor(TensorType {
         shape,
+        symbolic_shape: None,
         elem_type: weight.elem_type,
         dim: 2,
     });
 }
 
+/// Transpose the weights of a Linear node, once they're read if they're stored externally.
+fn transpose_weights(data: Data, rows: usize, cols: usize) -> Data {
+    match data {
+        Data::Float32s(data) => Data::Float32s(transpose_flattened(data, rows, cols)),
+        Data::Float64s(data) => Data::Float64s(transpose_flattened(data, rows, cols)),
+        Data::Float16s(data) => Data::Float16s(transpose_flattened(data, rows, cols)),
+        Data::External(data) => {
+            Data::External(data.map(move |data| transpose_weights(data, rows, cols)))
+        }
+        _ => panic!("Only float types are supported for Linear node"),
+    }
+}
+
 fn transpose_flattened<T: Copy>(matrix: Vec<T>, rows: usize, cols: usize) -> Vec<T> {
     assert_eq!(matrix.len(), rows * cols, "Matrix must be flattened");
 
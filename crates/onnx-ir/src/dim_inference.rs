@@ -4,7 +4,7 @@ use core::panic;
 use protobuf::Enum;
 
 use crate::{
-    ir::{ArgType, AttributeValue, Data, ElementType, Node, NodeType, TensorType},
+    ir::{ArgType, Argument, AttributeValue, Data, ElementType, Node, NodeType, TensorType},
     protos::tensor_proto::DataType,
     util::{flatten_config, shape_config},
 };
@@ -12,7 +12,7 @@ use crate::{
 /// Infer the dimension of each output tensor and update them.
 pub fn dim_inference(node: &mut Node) {
     match node.node_type {
-        NodeType::Add => same_as_input(node),
+        NodeType::Add => arithmetic_update_outputs(node),
         NodeType::ArgMax => argmax_update_outputs(node),
         NodeType::AveragePool1d => same_as_input(node),
         NodeType::AveragePool2d => same_as_input(node),
@@ -24,7 +24,7 @@ pub fn dim_inference(node: &mut Node) {
         NodeType::Conv1d => conv1d_update_outputs(node),
         NodeType::Conv2d => conv2d_update_outputs(node),
         NodeType::Cos => same_as_input(node),
-        NodeType::Div => same_as_input(node),
+        NodeType::Div => arithmetic_update_outputs(node),
         NodeType::Dropout => same_as_input(node),
         NodeType::Equal => equal_update_outputs(node),
         NodeType::Erf => same_as_input(node),
@@ -45,7 +45,7 @@ pub fn dim_inference(node: &mut Node) {
         NodeType::Max => same_as_input(node),
         NodeType::MaxPool1d => same_as_input(node),
         NodeType::MaxPool2d => same_as_input(node),
-        NodeType::Mul => same_as_input(node),
+        NodeType::Mul => arithmetic_update_outputs(node),
         NodeType::Neg => same_as_input(node),
         NodeType::Not => same_as_input(node),
         NodeType::Greater => greater_update_outputs(node),
@@ -66,7 +66,7 @@ pub fn dim_inference(node: &mut Node) {
         NodeType::Sigmoid => same_as_input(node),
         NodeType::Sign => same_as_input(node),
         NodeType::Sin => same_as_input(node),
-        NodeType::Slice => same_as_input(node),
+        NodeType::Slice => slice_update_outputs(node),
         NodeType::Softmax => same_as_input(node),
         NodeType::Sqrt => same_as_input(node),
         NodeType::Sub => sub_update_outputs(node),
@@ -82,6 +82,27 @@ pub fn dim_inference(node: &mut Node) {
         NodeType::RandomUniform => random_update_output(node),
         NodeType::RandomNormal => random_update_output(node),
         NodeType::ConstantOfShape => constant_of_shape_update_output(node),
+        NodeType::If => if_update_outputs(node),
+        NodeType::Loop => loop_update_outputs(node),
+        NodeType::Scan => scan_update_outputs(node),
+        NodeType::LSTM => recurrent_update_outputs(node),
+        NodeType::GRU => recurrent_update_outputs(node),
+        NodeType::RNN => recurrent_update_outputs(node),
+        NodeType::Pad => same_rank_as_input(node),
+        NodeType::Split => same_rank_as_input(node),
+        NodeType::Tile => same_rank_as_input(node),
+        NodeType::TopK => top_k_update_outputs(node),
+        NodeType::Trilu => same_as_input(node),
+        NodeType::OneHot => one_hot_update_outputs(node),
+        NodeType::CumSum => same_as_input(node),
+        NodeType::InstanceNormalization => same_as_input(node),
+        NodeType::GroupNormalization => same_as_input(node),
+        NodeType::HardSigmoid => same_as_input(node),
+        NodeType::HardSwish => same_as_input(node),
+        NodeType::Elu => same_as_input(node),
+        NodeType::Selu => same_as_input(node),
+        NodeType::Softplus => same_as_input(node),
+        NodeType::NonZero => non_zero_update_outputs(node),
         // Intentionally letting outputs leave unchanged but issue a warning so IR file can be generated.
         _ => temporary_pass_through_stub(node),
     }
@@ -110,18 +131,21 @@ fn constant_update_outputs(node: &mut Node) {
                 elem_type: tensor.elem_type.clone(),
                 dim: tensor.dim,
                 shape: tensor.shape.clone(),
+                symbolic_shape: None,
             }),
             AttributeValue::Float32(_) => ArgType::Scalar(ElementType::Float32),
             AttributeValue::Float32s(value) => ArgType::Tensor(TensorType {
                 elem_type: ElementType::Float32,
                 dim: 1,
                 shape: Some(vec![value.len()]),
+                symbolic_shape: None,
             }),
             AttributeValue::Int64(_) => ArgType::Scalar(ElementType::Int64),
             AttributeValue::Int64s(value) => ArgType::Tensor(TensorType {
                 elem_type: ElementType::Int64,
                 dim: 1,
                 shape: Some(vec![value.len()]),
+                symbolic_shape: None,
             }),
             ty => panic!("Constant value of {:?} is not supported", ty),
         },
@@ -154,6 +178,7 @@ fn constant_of_shape_update_output(node: &mut Node) {
         elem_type: value_type,
         dim,
         shape: None,
+        symbolic_shape: None,
     });
 }
 
@@ -193,6 +218,7 @@ fn random_update_output(node: &mut Node) {
                 .collect::<Result<Vec<usize>, _>>()
                 .unwrap(),
         ),
+        symbolic_shape: None,
     })
 }
 
@@ -261,13 +287,17 @@ fn cast_update_outputs(node: &mut Node) {
                     elem_type,
                     dim: tensor.dim,
                     shape: tensor.shape.clone(),
+                    symbolic_shape: tensor.symbolic_shape.clone(),
                 });
             }
         }
         ArgType::Scalar(_scalar) => {
             output.ty = ArgType::Scalar(elem_type);
         }
-        _ => panic!("Cast: only scalar and tensor inputs are valid"),
+        // Shapes are always computed as int64 values
+        ArgType::Shape(dim) => {
+            output.ty = ArgType::Shape(dim);
+        }
     }
 
     log::debug!(
@@ -278,6 +308,16 @@ fn cast_update_outputs(node: &mut Node) {
 }
 
 fn concat_update_outputs(node: &mut Node) {
+    if node
+        .inputs
+        .iter()
+        .any(|input| matches!(input.ty, ArgType::Shape(_)))
+    {
+        let dim = node.inputs.iter().map(shape_operand_len).sum();
+        node.outputs[0].ty = ArgType::Shape(dim);
+        return;
+    }
+
     let tensor = node
         .inputs
         .iter()
@@ -292,9 +332,9 @@ fn concat_update_outputs(node: &mut Node) {
 
 fn reshape_update_outputs(node: &mut Node) {
     let shape = if node.inputs.len() == 2 {
-        match &node.inputs[1].value {
+        match node.inputs[1].value.clone().map(Data::load) {
             Some(value) => match value {
-                Data::Int64s(shape) => Some(shape.clone()),
+                Data::Int64s(shape) => Some(shape),
                 _ => panic!("Reshape: invalid input types"),
             },
             None => None,
@@ -308,6 +348,16 @@ fn reshape_update_outputs(node: &mut Node) {
         _ => panic!("Reshape: invalid output types"),
     };
 
+    if let Some(ArgType::Shape(dim)) = node.inputs.get(1).map(|input| &input.ty) {
+        node.outputs[0].ty = ArgType::Tensor(TensorType {
+            dim: *dim,
+            shape: None, // shape is calculated at runtime
+            symbolic_shape: None,
+            elem_type: input_elem_type(node),
+        });
+        return;
+    }
+
     if let Some(shape) = shape {
         node.outputs[0].ty = ArgType::Tensor(TensorType {
             dim: shape.len(),
@@ -352,7 +402,10 @@ fn greater_update_outputs(node: &mut Node) {
                 ..tensor.clone()
             });
         }
-        _ => panic!("Only tensor input is valid"),
+        ArgType::Scalar(_) => {
+            node.outputs[0].ty = ArgType::Scalar(ElementType::Bool);
+        }
+        _ => panic!("Only tensor or scalar input is valid"),
     }
 }
 
@@ -364,7 +417,10 @@ fn less_update_outputs(node: &mut Node) {
                 ..tensor.clone()
             });
         }
-        _ => panic!("Only tensor input is valid"),
+        ArgType::Scalar(_) => {
+            node.outputs[0].ty = ArgType::Scalar(ElementType::Bool);
+        }
+        _ => panic!("Only tensor or scalar input is valid"),
     }
 }
 
@@ -376,7 +432,10 @@ fn greater_or_equal_update_outputs(node: &mut Node) {
                 ..tensor.clone()
             });
         }
-        _ => panic!("Only tensor input is valid"),
+        ArgType::Scalar(_) => {
+            node.outputs[0].ty = ArgType::Scalar(ElementType::Bool);
+        }
+        _ => panic!("Only tensor or scalar input is valid"),
     }
 }
 
@@ -388,29 +447,21 @@ fn less_or_equal_update_outputs(node: &mut Node) {
                 ..tensor.clone()
             });
         }
-        _ => panic!("Only tensor input is valid"),
+        ArgType::Scalar(_) => {
+            node.outputs[0].ty = ArgType::Scalar(ElementType::Bool);
+        }
+        _ => panic!("Only tensor or scalar input is valid"),
     }
 }
 
 fn reduce_mean_update_outputs(node: &mut Node) {
-    if node.inputs.len() != 1 {
-        panic!("Mean: multiple inputs are not supported");
-    }
-
     let node_input = &mut node.inputs[0];
     let tensor = match node_input.clone().ty {
         ArgType::Tensor(tensor) => tensor,
         _ => panic!("Only tensor input is valid"),
     };
 
-    let dim_only = match node.attrs.get("axes") {
-        Some(value) => match &value {
-            AttributeValue::Int64(_) => true,
-            AttributeValue::Int64s(ints) => ints.len() == 1,
-            _ => false,
-        },
-        None => false,
-    };
+    let dim_only = constant_axes(node).is_some_and(|axes| axes.len() == 1);
 
     if dim_only {
         node.outputs[0].ty = ArgType::Tensor(tensor);
@@ -439,23 +490,14 @@ fn argmax_update_outputs(node: &mut Node) {
     node.outputs[0].ty = ArgType::Tensor(TensorType {
         dim: tensor.dim,
         shape: tensor.shape.clone(),
+        symbolic_shape: tensor.symbolic_shape.clone(),
         elem_type: ElementType::Int64,
     });
 }
 
 /// Update the output tensor dimension
 fn squeeze_update_output(node: &mut Node) {
-    let axes = if node.inputs.len() == 2 {
-        match &node.inputs[1].value {
-            Some(value) => match value {
-                Data::Int64s(axes) => Some(axes.clone()),
-                _ => panic!("Squeeze: invalid input types"),
-            },
-            None => None,
-        }
-    } else {
-        node.attrs.get("axes").cloned().map(|v| v.into_i64s())
-    };
+    let axes = constant_axes(node);
 
     if axes.is_none() {
         panic!("Squeeze must specify an axis");
@@ -479,11 +521,17 @@ fn squeeze_update_output(node: &mut Node) {
     node.outputs[0].ty = ArgType::Tensor(TensorType {
         dim: input_dim - 1,
         shape: None, // shape is tracked and calculated at runtime
+        symbolic_shape: None,
         elem_type: output_elem,
     });
 }
 
 fn sub_update_outputs(node: &mut Node) {
+    if let Some(dim) = shape_arithmetic_len(node) {
+        node.outputs[0].ty = ArgType::Shape(dim);
+        return;
+    }
+
     node.outputs[0].ty = match (node.inputs[0].ty.clone(), node.inputs[1].ty.clone()) {
         (ArgType::Scalar(_lhs), ArgType::Scalar(rhs)) => ArgType::Scalar(rhs),
         (ArgType::Scalar(_lhs), ArgType::Tensor(rhs)) => ArgType::Tensor(rhs),
@@ -497,24 +545,20 @@ fn sub_update_outputs(node: &mut Node) {
     };
 }
 
-/// Update the output tensor dimension based on the "axes" attribute or the second input
+/// Update the output tensor dimension based on the axes of the second input
 fn unsqueeze_update_output(node: &mut Node) {
-    let axes = if node.inputs.len() == 2 {
-        match &node.inputs[1].value {
-            Some(value) => match value {
-                Data::Int64s(axes) => Some(axes.clone()),
-                _ => panic!("Unsqueeze: invalid input types"),
-            },
-            None => None,
-        }
-    } else {
-        node.attrs.get("axes").cloned().map(|v| v.into_i64s())
-    };
+    let axes = constant_axes(node);
 
     if axes.is_none() {
         return;
     }
 
+    // The single values gathered from a shape are already kept as shapes of one element
+    if let ArgType::Shape(dim) = node.inputs[0].ty {
+        node.outputs[0].ty = ArgType::Shape(dim);
+        return;
+    }
+
     let input_dim = match &node.inputs[0].ty {
         ArgType::Tensor(tensor) => tensor.dim,
         ArgType::Scalar(_) => 0, // treat scalar as 0-dim tensor
@@ -531,6 +575,7 @@ fn unsqueeze_update_output(node: &mut Node) {
         node.outputs[0].ty = ArgType::Tensor(TensorType {
             dim: input_dim + axes.len(),
             shape: None, // shape is tracked and calculated at runtime
+            symbolic_shape: None,
             elem_type: output_elem,
         });
     }
@@ -540,6 +585,98 @@ fn same_as_input(node: &mut Node) {
     node.outputs[0].ty = node.inputs[0].ty.clone();
 }
 
+/// Infer the output of an elementwise arithmetic node, which is a shape if one of its operands is
+/// a runtime shape.
+fn arithmetic_update_outputs(node: &mut Node) {
+    match shape_arithmetic_len(node) {
+        Some(dim) => node.outputs[0].ty = ArgType::Shape(dim),
+        None => same_as_input(node),
+    }
+}
+
+/// The number of elements of the shape computed by an arithmetic node, if one of its operands is
+/// a runtime shape.
+fn shape_arithmetic_len(node: &Node) -> Option<usize> {
+    node.inputs
+        .iter()
+        .any(|input| matches!(input.ty, ArgType::Shape(_)))
+        .then(|| node.inputs.iter().map(shape_operand_len).max().unwrap())
+}
+
+/// The number of elements of an operand of a shape computation, e.g. the indices of a `Gather`.
+fn shape_operand_len(input: &Argument) -> usize {
+    match (&input.ty, input.value.clone().map(Data::load)) {
+        (ArgType::Shape(dim), _) => *dim,
+        (ArgType::Scalar(_), _) => 1,
+        (ArgType::Tensor(_), Some(Data::Int64s(values))) => values.len(),
+        (ArgType::Tensor(_), Some(Data::Int32s(values))) => values.len(),
+        (ArgType::Tensor(tensor), None) => match tensor.static_dim(0) {
+            Some(dim) if tensor.dim == 1 => dim,
+            _ => panic!("Shape operand {} must have a static length", input.name),
+        },
+        _ => panic!("Shape operand {} must be an integer", input.name),
+    }
+}
+
+/// The element type of the first input of the node.
+fn input_elem_type(node: &Node) -> ElementType {
+    match &node.inputs[0].ty {
+        ArgType::Tensor(tensor) => tensor.elem_type.clone(),
+        ArgType::Scalar(elem_type) => elem_type.clone(),
+        ArgType::Shape(_) => ElementType::Int64,
+    }
+}
+
+/// Infer the output of a Slice node, which is a shape of statically known length when slicing a
+/// runtime shape.
+fn slice_update_outputs(node: &mut Node) {
+    let dim = match node.inputs[0].ty {
+        ArgType::Shape(dim) => dim,
+        _ => return same_as_input(node),
+    };
+
+    let bound = |index: usize, attr: &str| -> i64 {
+        match node
+            .inputs
+            .get(index)
+            .and_then(|input| input.value.clone())
+            .map(Data::load)
+        {
+            Some(Data::Int64s(values)) => values[0],
+            Some(_) => panic!("Slice: the bounds must be int64 values"),
+            None => node
+                .attrs
+                .get(attr)
+                .cloned()
+                .map(|value| value.into_i64s()[0])
+                .unwrap_or_else(|| panic!("Slice: the {attr} of a shape must be constant")),
+        }
+    };
+    // Negative bounds are counted from the end, and the bounds are clamped to the shape
+    let clamp = |bound: i64| {
+        let bound = if bound < 0 { bound + dim as i64 } else { bound };
+        bound.clamp(0, dim as i64) as usize
+    };
+    let (start, end) = (clamp(bound(1, "starts")), clamp(bound(2, "ends")));
+
+    node.outputs[0].ty = ArgType::Shape(end.saturating_sub(start));
+}
+
+/// Set every output to a tensor with the rank and element type of the input, whose shape changes.
+fn same_rank_as_input(node: &mut Node) {
+    let tensor = match &node.inputs[0].ty {
+        ArgType::Tensor(tensor) => tensor.clone(),
+        _ => panic!("{}: the input must be a tensor", node.node_type),
+    };
+
+    for output in node.outputs.iter_mut() {
+        output.ty = ArgType::Tensor(TensorType {
+            shape: None,
+            ..tensor.clone()
+        });
+    }
+}
+
 /// Temporary pass-through stub for dimension inference so that we can export the IR model.
 fn temporary_pass_through_stub(node: &mut Node) {
     log::warn!("Must implement dimension inference for {:?}", node);
@@ -567,9 +704,9 @@ fn equal_update_outputs(node: &mut Node) {
 
 fn expand_update_outputs(node: &mut Node) {
     let shape = if node.inputs.len() == 2 {
-        match &node.inputs[1].value {
+        match node.inputs[1].value.clone().map(Data::load) {
             Some(value) => match value {
-                Data::Int64s(shape) => Some(shape.clone()),
+                Data::Int64s(shape) => Some(shape),
                 _ => panic!("Expand: invalid input types"),
             },
             None => None,
@@ -583,6 +720,16 @@ fn expand_update_outputs(node: &mut Node) {
         _ => panic!("Expand: invalid output types"),
     };
 
+    if let ArgType::Shape(dim) = &node.inputs[1].ty {
+        node.outputs[0].ty = ArgType::Tensor(TensorType {
+            dim: *dim,
+            shape: None, // shape is calculated at runtime
+            symbolic_shape: None,
+            elem_type: input_elem_type(node),
+        });
+        return;
+    }
+
     if let Some(shape) = shape {
         node.outputs[0].ty = ArgType::Tensor(TensorType {
             dim: shape.len(),
@@ -675,6 +822,7 @@ fn matmul_update_outputs(node: &mut Node) {
                 elem_type: a.elem_type.clone(),
                 dim: out_dim,
                 shape: a.shape.clone(),
+                symbolic_shape: None,
             });
         }
         _ => panic!("Only tensor input is valid"),
@@ -690,29 +838,19 @@ fn range_update_outputs(node: &mut Node) {
         elem_type: ElementType::Int64,
         dim: 1,
         shape: None,
+        symbolic_shape: None,
     });
 }
 
 /// Infers the shape of a ReduceMax node and replaces the shape of the output tensor.
 fn reduce_max_update_outputs(node: &mut Node) {
-    if node.inputs.len() != 1 {
-        panic!("ReduceMax: multiple inputs are not supported");
-    }
-
     let node_input = &mut node.inputs[0];
     let tensor = match node_input.clone().ty {
         ArgType::Tensor(tensor) => tensor,
         _ => panic!("Only tensor input is valid"),
     };
 
-    let dim_only = match node.attrs.get("axes") {
-        Some(value) => match &value {
-            AttributeValue::Int64(_) => true,
-            AttributeValue::Int64s(ints) => ints.len() == 1,
-            _ => false,
-        },
-        None => false,
-    };
+    let dim_only = constant_axes(node).is_some_and(|axes| axes.len() == 1);
 
     if dim_only {
         node.outputs[0].ty = ArgType::Tensor(tensor);
@@ -727,22 +865,12 @@ fn reduce_max_update_outputs(node: &mut Node) {
 }
 
 fn reduce_min_update_outputs(node: &mut Node) {
-    if node.inputs.len() != 1 {
-        panic!("ReduceMin: multiple inputs are not supported");
-    }
     let node_input = &mut node.inputs[0];
     let tensor = match node_input.clone().ty {
         ArgType::Tensor(tensor) => tensor,
         _ => panic!("Only tensor input is valid"),
     };
-    let dim_only = match node.attrs.get("axes") {
-        Some(value) => match &value {
-            AttributeValue::Int64(_) => true,
-            AttributeValue::Int64s(ints) => ints.len() == 1,
-            _ => false,
-        },
-        None => false,
-    };
+    let dim_only = constant_axes(node).is_some_and(|axes| axes.len() == 1);
     if dim_only {
         node.outputs[0].ty = ArgType::Tensor(tensor);
     } else {
@@ -752,23 +880,13 @@ fn reduce_min_update_outputs(node: &mut Node) {
 
 /// Infers the shape of a ReduceProd node and replaces the shape of the output tensor.
 fn reduce_prod_update_outputs(node: &mut Node) {
-    if node.inputs.len() != 1 {
-        panic!("ReduceProd: multiple inputs are not supported");
-    }
     let node_input = &mut node.inputs[0];
     let tensor = match node_input.clone().ty {
         ArgType::Tensor(tensor) => tensor,
         _ => panic!("Only tensor input is valid"),
     };
 
-    let dim_only = match node.attrs.get("axes") {
-        Some(value) => match &value {
-            AttributeValue::Int64(_) => true,
-            AttributeValue::Int64s(ints) => ints.len() == 1,
-            _ => false,
-        },
-        None => false,
-    };
+    let dim_only = constant_axes(node).is_some_and(|axes| axes.len() == 1);
 
     if dim_only {
         node.outputs[0].ty = ArgType::Tensor(tensor);
@@ -785,23 +903,7 @@ fn reduce_sum_update_outputs(node: &mut Node) {
         _ => panic!("Only tensor input is valid"),
     };
 
-    let dim_only = match node.attrs.get("axes") {
-        Some(value) => match &value {
-            AttributeValue::Int64(_) => true,
-            AttributeValue::Int64s(ints) => ints.len() == 1,
-            _ => false,
-        },
-        None => false,
-    };
-
-    let dim_only = match node.inputs.get(1).and_then(|arg| arg.value.as_ref()) {
-        Some(value) => match &value {
-            Data::Int64(_) => true,
-            Data::Int64s(ints) => ints.len() == 1,
-            _ => false,
-        },
-        None => dim_only,
-    };
+    let dim_only = constant_axes(node).is_some_and(|axes| axes.len() == 1);
 
     if dim_only {
         node.outputs[0].ty = ArgType::Tensor(tensor);
@@ -840,6 +942,12 @@ fn gather_update_outputs(node: &mut Node) {
 
     let input_tensor = match &node.inputs[0].ty {
         ArgType::Tensor(tensor) => tensor,
+        ArgType::Shape(_) => {
+            // A scalar index selects a single dimension, which is kept as a shape of one element
+            let dim = shape_operand_len(&node.inputs[1]);
+            node.outputs[0].ty = ArgType::Shape(dim);
+            return;
+        }
         _ => panic!("Only tensor input is valid"),
     };
 
@@ -858,6 +966,177 @@ fn gather_update_outputs(node: &mut Node) {
     node.outputs[0].ty = ArgType::Tensor(TensorType {
         dim: output_rank,
         shape: None,
+        symbolic_shape: None,
         elem_type: input_tensor.elem_type.clone(),
     });
 }
+
+/// Infer the output types of an If node from its `then_branch`.
+fn if_update_outputs(node: &mut Node) {
+    let then_branch = node
+        .attrs
+        .get("then_branch")
+        .cloned()
+        .expect("If: then_branch attribute not found")
+        .into_graph();
+
+    for (output, branch_output) in node.outputs.iter_mut().zip(then_branch.outputs) {
+        output.ty = branch_output.ty;
+    }
+}
+
+/// Infer the output types of a Loop node from its body.
+///
+/// The body outputs are the condition, the N loop carried values and the K scan outputs, which
+/// are stacked on a new first axis.
+fn loop_update_outputs(node: &mut Node) {
+    let body = node
+        .attrs
+        .get("body")
+        .cloned()
+        .expect("Loop: body attribute not found")
+        .into_graph();
+    // The body inputs are the iteration number, the condition and the loop carried values
+    let num_carried = body.inputs.len() - 2;
+
+    for (i, output) in node.outputs.iter_mut().enumerate() {
+        let body_output = &body.outputs[i + 1];
+        output.ty = if i < num_carried {
+            body_output.ty.clone()
+        } else {
+            scan_output_type(&body_output.ty)
+        };
+    }
+}
+
+/// Infer the output types of a Scan node from its body.
+///
+/// The body outputs are the N state variables and the K scan outputs, which are stacked along
+/// their scan axis.
+fn scan_update_outputs(node: &mut Node) {
+    let body = node
+        .attrs
+        .get("body")
+        .cloned()
+        .expect("Scan: body attribute not found")
+        .into_graph();
+    let num_scan_inputs = node
+        .attrs
+        .get("num_scan_inputs")
+        .cloned()
+        .expect("Scan: num_scan_inputs attribute not found")
+        .into_i64() as usize;
+    // The body inputs are the state variables and one element of each scan input
+    let num_states = body.inputs.len() - num_scan_inputs;
+
+    for (i, output) in node.outputs.iter_mut().enumerate() {
+        let body_output = &body.outputs[i];
+        output.ty = if i < num_states {
+            body_output.ty.clone()
+        } else {
+            scan_output_type(&body_output.ty)
+        };
+    }
+}
+
+/// The type of the values of each iteration stacked along a new axis.
+fn scan_output_type(ty: &ArgType) -> ArgType {
+    match ty {
+        ArgType::Tensor(tensor) => ArgType::Tensor(TensorType {
+            elem_type: tensor.elem_type.clone(),
+            dim: tensor.dim + 1,
+            shape: None,
+            symbolic_shape: None,
+        }),
+        ArgType::Scalar(elem_type) => ArgType::Tensor(TensorType {
+            elem_type: elem_type.clone(),
+            dim: 1,
+            shape: None,
+            symbolic_shape: None,
+        }),
+        ArgType::Shape(_) => panic!("Shapes can't be scan outputs"),
+    }
+}
+
+/// Infers the output types of the LSTM, GRU and RNN nodes.
+///
+/// The first output has the hidden state of each step, and the others the last hidden (and cell)
+/// states, for each direction.
+fn recurrent_update_outputs(node: &mut Node) {
+    let elem_type = match &node.inputs[0].ty {
+        ArgType::Tensor(tensor) if tensor.dim == 3 => tensor.elem_type.clone(),
+        _ => panic!("{}: the input must be a rank 3 tensor", node.node_type),
+    };
+
+    for (i, output) in node.outputs.iter_mut().enumerate() {
+        output.ty = ArgType::Tensor(TensorType {
+            elem_type: elem_type.clone(),
+            dim: if i == 0 { 4 } else { 3 },
+            shape: None,
+            symbolic_shape: None,
+        });
+    }
+}
+
+fn top_k_update_outputs(node: &mut Node) {
+    let tensor = match &node.inputs[0].ty {
+        ArgType::Tensor(tensor) => tensor.clone(),
+        _ => panic!("TopK: the input must be a tensor"),
+    };
+
+    // The values and their indices
+    node.outputs[0].ty = ArgType::Tensor(TensorType {
+        shape: None,
+        ..tensor.clone()
+    });
+    node.outputs[1].ty = ArgType::Tensor(TensorType {
+        elem_type: ElementType::Int64,
+        dim: tensor.dim,
+        shape: None,
+        symbolic_shape: None,
+    });
+}
+
+fn one_hot_update_outputs(node: &mut Node) {
+    let dim = match &node.inputs[0].ty {
+        ArgType::Tensor(tensor) => tensor.dim,
+        _ => panic!("OneHot: the indices must be a tensor"),
+    };
+    // The output has the element type of the off and on values
+    let elem_type = match &node.inputs[2].ty {
+        ArgType::Tensor(tensor) => tensor.elem_type.clone(),
+        _ => panic!("OneHot: the values must be a tensor"),
+    };
+
+    node.outputs[0].ty = ArgType::Tensor(TensorType {
+        elem_type,
+        dim: dim + 1,
+        shape: None,
+        symbolic_shape: None,
+    });
+}
+
+fn non_zero_update_outputs(node: &mut Node) {
+    // The indices of the non-zero elements are `[rank, num_non_zero]`
+    node.outputs[0].ty = ArgType::Tensor(TensorType {
+        elem_type: ElementType::Int64,
+        dim: 2,
+        shape: None,
+        symbolic_shape: None,
+    });
+}
+
+/// Get the constant axes given as the second input, e.g. of a reduction or of a `Squeeze` node.
+fn constant_axes(node: &Node) -> Option<Vec<i64>> {
+    match node
+        .inputs
+        .get(1)
+        .and_then(|input| input.value.clone())
+        .map(Data::load)
+    {
+        Some(Data::Int64s(axes)) => Some(axes),
+        Some(Data::Int64(axis)) => Some(vec![axis]),
+        Some(data) => panic!("{}: the axes must be int64 values, got {data:?}", node.name),
+        None => None,
+    }
+}
@@ -0,0 +1,241 @@
+use std::{
+    fmt,
+    fs::{self, File},
+    io::{Read, Seek, SeekFrom},
+    path::{Component, Path},
+    sync::Arc,
+};
+
+use super::from_onnx::OnnxParseError;
+use super::ir::Data;
+use super::proto_conversion::convert_tensor_data;
+use super::protos::{
+    attribute_proto::AttributeType, tensor_proto::DataLocation, GraphProto, StringStringEntryProto,
+    TensorProto,
+};
+
+/// The data of a tensor stored in an external file.
+///
+/// The data is only read when it's needed, e.g. when the record of the model is generated, so the
+/// weights of a large model are read one tensor at a time instead of all being loaded at once.
+#[derive(Clone)]
+pub struct ExternalData {
+    tensor: Arc<TensorProto>,
+    transform: Option<Arc<dyn Fn(Data) -> Data + Send + Sync>>,
+}
+
+impl ExternalData {
+    pub(crate) fn new(tensor: TensorProto) -> Self {
+        Self {
+            tensor: Arc::new(tensor),
+            transform: None,
+        }
+    }
+
+    /// Read the data from the external file.
+    pub fn read(&self) -> Result<Data, OnnxParseError> {
+        let mut tensor = TensorProto::clone(&self.tensor);
+        tensor.raw_data = read_external_data(&tensor)?;
+        let data = convert_tensor_data(tensor).map_err(|_| {
+            external_data_error(&self.tensor, "the data type isn't supported".to_string())
+        })?;
+
+        Ok(match &self.transform {
+            Some(transform) => transform(data),
+            None => data,
+        })
+    }
+
+    /// Transform the data once it's read, e.g. to transpose the weights of a node.
+    pub fn map<F>(self, func: F) -> Self
+    where
+        F: Fn(Data) -> Data + Send + Sync + 'static,
+    {
+        let transform: Arc<dyn Fn(Data) -> Data + Send + Sync> = match self.transform {
+            Some(previous) => Arc::new(move |data| func(previous(data))),
+            None => Arc::new(func),
+        };
+
+        Self {
+            tensor: self.tensor,
+            transform: Some(transform),
+        }
+    }
+}
+
+impl fmt::Debug for ExternalData {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let location = external_data_entry(&self.tensor, "location").unwrap_or_default();
+        write!(f, "External({location})")
+    }
+}
+
+/// Resolve the locations of the tensors stored as external data against the directory of the
+/// model, so they can be read when the tensors are converted.
+///
+/// Models over 2 GB can't be serialized in a single protobuf message, so their tensors are
+/// stored in separate files referenced by `TensorProto.external_data`. The locations are checked
+/// here, so a missing file or a location outside the directory of the model is reported when the
+/// model is parsed.
+pub(crate) fn resolve_external_data(
+    graph: &mut GraphProto,
+    base_dir: &Path,
+) -> Result<(), OnnxParseError> {
+    for tensor in graph.initializer.iter_mut() {
+        resolve_location(tensor, base_dir)?;
+    }
+
+    for node in graph.node.iter_mut() {
+        for attr in node.attribute.iter_mut() {
+            match attr.type_.enum_value() {
+                Ok(AttributeType::TENSOR) => {
+                    if let Some(tensor) = attr.t.as_mut() {
+                        resolve_location(tensor, base_dir)?;
+                    }
+                }
+                Ok(AttributeType::TENSORS) => {
+                    for tensor in attr.tensors.iter_mut() {
+                        resolve_location(tensor, base_dir)?;
+                    }
+                }
+                Ok(AttributeType::GRAPH) => {
+                    if let Some(graph) = attr.g.as_mut() {
+                        resolve_external_data(graph, base_dir)?;
+                    }
+                }
+                Ok(AttributeType::GRAPHS) => {
+                    for graph in attr.graphs.iter_mut() {
+                        resolve_external_data(graph, base_dir)?;
+                    }
+                }
+                _ => {}
+            }
+        }
+    }
+
+    Ok(())
+}
+
+/// Check if the data of the tensor is stored in an external file.
+pub(crate) fn is_external(tensor: &TensorProto) -> bool {
+    tensor.data_location.enum_value() == Ok(DataLocation::EXTERNAL)
+}
+
+/// Read the raw data of a tensor stored in an external file.
+///
+/// Only the `length` bytes of the tensor starting at its `offset` are read. Both are always set
+/// once the location is resolved.
+pub(crate) fn read_external_data(tensor: &TensorProto) -> Result<Vec<u8>, OnnxParseError> {
+    let error = |reason: String| external_data_error(tensor, reason);
+    let location = external_data_entry(tensor, "location")
+        .ok_or_else(|| error("the location is missing".to_string()))?;
+    let offset = external_data_number(tensor, "offset")?.unwrap_or(0);
+    let length = external_data_number(tensor, "length")?.unwrap_or(0);
+
+    let mut file =
+        File::open(location).map_err(|err| error(format!("unable to open {location}: {err}")))?;
+    file.seek(SeekFrom::Start(offset)).map_err(|err| {
+        error(format!(
+            "unable to seek to offset {offset} in {location}: {err}"
+        ))
+    })?;
+
+    let mut data = vec![0; length as usize];
+    file.read_exact(&mut data).map_err(|err| {
+        error(format!(
+            "unable to read {length} bytes at offset {offset} from {location}: {err}"
+        ))
+    })?;
+
+    Ok(data)
+}
+
+/// Replace the location of the external data, relative to the model, by its full path, and set
+/// the length of the data when it's implicit.
+fn resolve_location(tensor: &mut TensorProto, base_dir: &Path) -> Result<(), OnnxParseError> {
+    if !is_external(tensor) {
+        return Ok(());
+    }
+
+    let location = external_data_entry(tensor, "location")
+        .ok_or_else(|| external_data_error(tensor, "the location is missing".to_string()))?;
+
+    // The location must stay inside the directory of the model
+    let relative = Path::new(location);
+    if !relative
+        .components()
+        .all(|component| matches!(component, Component::Normal(_) | Component::CurDir))
+    {
+        return Err(external_data_error(
+            tensor,
+            format!("the location {location} must be relative to the model, without \"..\""),
+        ));
+    }
+
+    let path = base_dir.join(relative);
+    let file_len = fs::metadata(&path)
+        .map_err(|err| {
+            external_data_error(tensor, format!("unable to open {}: {err}", path.display()))
+        })?
+        .len();
+
+    let offset = external_data_number(tensor, "offset")?.unwrap_or(0);
+    let length = match external_data_number(tensor, "length")? {
+        Some(length) => length,
+        None => file_len.saturating_sub(offset),
+    };
+    if offset > file_len || length > file_len - offset {
+        return Err(external_data_error(
+            tensor,
+            format!(
+                "{length} bytes at offset {offset} are out of bounds of {} ({file_len} bytes)",
+                path.display()
+            ),
+        ));
+    }
+
+    set_external_data_entry(tensor, "location", path.to_string_lossy().into_owned());
+    set_external_data_entry(tensor, "length", length.to_string());
+
+    Ok(())
+}
+
+fn external_data_error(tensor: &TensorProto, reason: String) -> OnnxParseError {
+    OnnxParseError::ExternalData {
+        tensor: tensor.name.clone(),
+        reason,
+    }
+}
+
+fn external_data_entry<'a>(tensor: &'a TensorProto, key: &str) -> Option<&'a str> {
+    tensor
+        .external_data
+        .iter()
+        .find(|entry| entry.key == key)
+        .map(|entry| entry.value.as_str())
+}
+
+fn set_external_data_entry(tensor: &mut TensorProto, key: &str, value: String) {
+    match tensor
+        .external_data
+        .iter_mut()
+        .find(|entry| entry.key == key)
+    {
+        Some(entry) => entry.value = value,
+        None => tensor.external_data.push(StringStringEntryProto {
+            key: key.to_string(),
+            value,
+            ..Default::default()
+        }),
+    }
+}
+
+fn external_data_number(tensor: &TensorProto, key: &str) -> Result<Option<u64>, OnnxParseError> {
+    external_data_entry(tensor, key)
+        .map(|value| {
+            value.parse().map_err(|_| {
+                external_data_error(tensor, format!("the {key} {value} isn't a valid number"))
+            })
+        })
+        .transpose()
+}
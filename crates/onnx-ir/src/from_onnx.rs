@@ -8,9 +8,14 @@ use crate::node_remap::remap_node_type;
 
 use super::{
     coalesce::coalesce,
-    ir::{Data, OnnxGraph, TensorType},
-    proto_conversion::convert_node_proto,
-    protos::{ModelProto, NodeProto, TensorProto, ValueInfoProto},
+    external_data::resolve_external_data,
+    ir::{AttributeValue, Data, OnnxGraph, TensorType},
+    opset::{normalize_opset, opset_version},
+    proto_conversion::{convert_node_proto, is_graph_attribute},
+    protos::{
+        attribute_proto::AttributeType, GraphProto, ModelProto, NodeProto, TensorProto,
+        ValueInfoProto,
+    },
 };
 
 use super::dim_inference::dim_inference;
@@ -18,19 +23,35 @@ use super::ir::{ArgType, Argument, Node, NodeType};
 
 use protobuf::Message;
 
-const LIFT_CONSTANTS_FOR_NODE_TYPES: [NodeType; 12] = [
+pub(crate) const LIFT_CONSTANTS_FOR_NODE_TYPES: [NodeType; 28] = [
     NodeType::BatchNormalization,
     NodeType::Clip,
     NodeType::Conv1d,
     NodeType::Conv2d,
+    NodeType::CumSum,
     NodeType::Dropout,
     NodeType::Expand,
+    NodeType::GroupNormalization,
+    NodeType::GRU,
+    NodeType::InstanceNormalization,
+    NodeType::LSTM,
+    NodeType::OneHot,
+    NodeType::Pad,
+    NodeType::RNN,
     NodeType::Reshape,
     NodeType::Resize,
     NodeType::Unsqueeze,
+    NodeType::ReduceMax,
+    NodeType::ReduceMean,
+    NodeType::ReduceMin,
+    NodeType::ReduceProd,
     NodeType::ReduceSum,
     NodeType::Slice,
+    NodeType::Split,
     NodeType::Squeeze,
+    NodeType::Tile,
+    NodeType::TopK,
+    NodeType::Trilu,
 ];
 
 #[derive(Debug, Clone)]
@@ -52,6 +73,8 @@ pub struct GraphData {
     input_name_map: HashMap<String, IOEntry>,
     /// Maps the updated input name to the original input name. Required to check if the input is an initializer
     input_key_map: HashMap<String, String>,
+    /// The values of the enclosing graphs, by original name. Only used by sub-graphs
+    outer: HashMap<String, Argument>,
 }
 
 impl GraphData {
@@ -59,6 +82,33 @@ impl GraphData {
         inputs: &[ValueInfoProto],
         outputs: &[ValueInfoProto],
         initializers: &[TensorProto],
+    ) -> Self {
+        Self::with_scope(inputs, outputs, initializers, "input", HashMap::new())
+    }
+
+    /// Create the graph data of a sub-graph, which can use the values of the enclosing graphs.
+    ///
+    /// The inputs are named with the given prefix, to keep them unique across the graphs.
+    pub(crate) fn new_subgraph(
+        graph: &GraphProto,
+        input_prefix: &str,
+        outer: HashMap<String, Argument>,
+    ) -> Self {
+        Self::with_scope(
+            &graph.input,
+            &graph.output,
+            &graph.initializer,
+            input_prefix,
+            outer,
+        )
+    }
+
+    fn with_scope(
+        inputs: &[ValueInfoProto],
+        outputs: &[ValueInfoProto],
+        initializers: &[TensorProto],
+        input_prefix: &str,
+        outer: HashMap<String, Argument>,
     ) -> Self {
         let mut input_name_map = HashMap::new();
         let mut input_key_map = HashMap::new();
@@ -75,7 +125,7 @@ impl GraphData {
             .iter()
             .enumerate()
             .map(|(i, x)| {
-                let in_name = format!("input{}", i + 1);
+                let in_name = format!("{}{}", input_prefix, i + 1);
 
                 input_name_map.insert(x.name.clone(), IOEntry::In(i));
                 input_key_map.insert(in_name.clone(), x.name.clone());
@@ -99,6 +149,7 @@ impl GraphData {
             processed_nodes: Vec::new(),
             input_name_map,
             input_key_map,
+            outer,
         }
     }
 
@@ -110,6 +161,8 @@ impl GraphData {
                 //need to confirm) then we could pop the initializer from the map
                 if let Some(init_arg) = self.initializers.get(proto_str) {
                     init_arg.clone()
+                } else if let Some(outer_arg) = self.outer.get(proto_str) {
+                    outer_arg.clone()
                 } else {
                     log::warn!(
                         "Input {} not found, should only happen when peeking",
@@ -143,17 +196,20 @@ impl GraphData {
     /// This function does three things:
     ///     1. marks the inputs as passed
     ///     2. maps the old output names to the node output
-    ///     3. renames the node output
+    ///     3. renames the node output, unless it is omitted
     fn add_node(&mut self, mut node: Node) {
         log::debug!("adding node {:?}", &node.name);
         self.mark_input_passed(&node);
         let mut out_count = 1;
         for output in node.outputs.iter_mut() {
-            self.input_name_map.insert(
-                output.name.clone(),
-                IOEntry::Node(self.processed_nodes.len(), 0),
-            );
-            output.name = format!("{}_out{}", node.name, out_count);
+            // Omitted optional outputs keep their empty name
+            if !output.name.is_empty() {
+                self.input_name_map.insert(
+                    output.name.clone(),
+                    IOEntry::Node(self.processed_nodes.len(), out_count - 1),
+                );
+                output.name = format!("{}_out{}", node.name, out_count);
+            }
             out_count += 1;
         }
         self.processed_nodes.push(node);
@@ -173,6 +229,31 @@ impl GraphData {
         (self.processed_nodes, self.inputs, outputs)
     }
 
+    /// Consumes the sub-graph data and returns the processed nodes, inputs and outputs
+    ///
+    /// Unlike [consume](GraphData::consume), all the inputs are kept since they are positional, and
+    /// the outputs can be inputs of the sub-graph or values of the enclosing graphs.
+    fn consume_subgraph(self) -> (Vec<Node>, Vec<Argument>, Vec<Argument>) {
+        let outputs = self.outputs.iter().map(|x| self.init_in(&x.name)).collect();
+        (self.processed_nodes, self.inputs, outputs)
+    }
+
+    /// Get the values that can be used by a sub-graph of the current node, by original name
+    fn scope_values(&self) -> HashMap<String, Argument> {
+        let mut values = self.outer.clone();
+        values.extend(
+            self.initializers
+                .iter()
+                .map(|(name, arg)| (name.clone(), arg.clone())),
+        );
+        values.extend(
+            self.input_name_map
+                .keys()
+                .map(|name| (name.clone(), self.init_in(name))),
+        );
+        values
+    }
+
     /// Used to get the output of the graph by name. Only used to remap unsqueeze nodes
     pub fn get_graph_output(&self, name: &str) -> Option<&Argument> {
         self.outputs.iter().find(|x| x.name == name)
@@ -196,11 +277,14 @@ pub(crate) struct OnnxGraphBuilder {
     /// Map from identity node output names to indices of identity nodes
     identity_idx: HashMap<String, usize>,
     node_name_counter: HashMap<NodeType, usize>,
+    /// Version of the default ONNX opset, used to normalize the nodes of older opsets
+    opset_version: i64,
 }
 
 impl OnnxGraphBuilder {
-    pub(crate) fn build(mut self, model_proto: &ModelProto) -> OnnxGraph {
+    pub(crate) fn build(mut self, model_proto: &ModelProto) -> Result<OnnxGraph, OnnxParseError> {
         self.constants_types = LIFT_CONSTANTS_FOR_NODE_TYPES.into_iter().collect();
+        self.opset_version = opset_version(model_proto);
 
         let mut graph_data = GraphData::new(
             &model_proto.graph.input,
@@ -208,24 +292,7 @@ impl OnnxGraphBuilder {
             &model_proto.graph.initializer,
         );
 
-        let mut node_iter = model_proto.graph.node.iter().peekable();
-
-        while let Some(node_proto) = node_iter.next() {
-            let mut node = convert_node_proto(node_proto, &graph_data);
-
-            remap_node_type(&mut node);
-            self.handle_node_renaming(&mut node);
-            coalesce(&mut node, &mut node_iter, &graph_data);
-            self.handle_identity(&mut node, &graph_data);
-            self.check_constants(&mut node, &graph_data);
-            // NOTE: potential start of custom functions
-            // can filter, coalesce, or modify the nodes here
-            // args : node, peek_iter, graph_data
-            self.handle_unsqueeze(&mut node, &graph_data);
-
-            dim_inference(&mut node);
-            graph_data.add_node(node);
-        }
+        self.process_nodes(&model_proto.graph.node, &mut graph_data)?;
 
         let (mut processed_nodes, inputs, outputs) = graph_data.consume();
         // Remove the graph inputs/output that are not used by any node
@@ -240,11 +307,169 @@ impl OnnxGraphBuilder {
         // This is necessary for the graph to be valid
         // ConstantOfShape updates input to be Shape argument and output Tensor dim is updated
 
-        OnnxGraph {
+        Ok(OnnxGraph {
             nodes: processed_nodes,
             inputs,
             outputs,
+        })
+    }
+
+    /// Build a sub-graph, e.g. the branch of an `If` node or the body of a `Loop` node.
+    fn build_subgraph(
+        &mut self,
+        graph: &GraphProto,
+        input_prefix: &str,
+        outer: HashMap<String, Argument>,
+    ) -> Result<OnnxGraph, OnnxParseError> {
+        self.constants_types = LIFT_CONSTANTS_FOR_NODE_TYPES.into_iter().collect();
+
+        let mut graph_data = GraphData::new_subgraph(graph, input_prefix, outer);
+        self.process_nodes(&graph.node, &mut graph_data)?;
+
+        let (mut processed_nodes, inputs, mut outputs) = graph_data.consume_subgraph();
+
+        // The removed identity nodes can be outputs of the sub-graph, e.g. a branch returning a
+        // value of the enclosing graph
+        for output in outputs.iter_mut() {
+            if let Some(identity_idx) = self.identity_idx.get(&output.name) {
+                *output = processed_nodes[*identity_idx].inputs[0].clone();
+            }
         }
+
+        let mut i = 0;
+        processed_nodes.retain(|_| {
+            let keep = !self.nodes_to_remove.contains(&i);
+            i += 1;
+            keep
+        });
+
+        Ok(OnnxGraph {
+            nodes: processed_nodes,
+            inputs,
+            outputs,
+        })
+    }
+
+    fn process_nodes(
+        &mut self,
+        nodes: &[NodeProto],
+        graph_data: &mut GraphData,
+    ) -> Result<(), OnnxParseError> {
+        let mut node_iter = nodes.iter().peekable();
+
+        while let Some(node_proto) = node_iter.next() {
+            let mut node = convert_node_proto(node_proto, graph_data);
+
+            remap_node_type(&mut node);
+            self.handle_node_renaming(&mut node);
+            // The constant inputs promoted from attributes are named after the unique node name
+            normalize_opset(&mut node, self.opset_version)?;
+            self.handle_subgraphs(node_proto, &mut node, graph_data)?;
+            coalesce(&mut node, &mut node_iter, graph_data);
+            self.handle_identity(&mut node, graph_data);
+            self.check_constants(&mut node, graph_data);
+            // NOTE: potential start of custom functions
+            // can filter, coalesce, or modify the nodes here
+            // args : node, peek_iter, graph_data
+            self.handle_unsqueeze(&mut node, graph_data);
+
+            dim_inference(&mut node);
+            graph_data.add_node(node);
+        }
+
+        Ok(())
+    }
+
+    /// Convert the graph attributes of the node into sub-graphs.
+    ///
+    /// The values of the enclosing graphs used by the sub-graphs are appended to the inputs of the
+    /// node, so that they are marked as used and the generated code can capture them.
+    fn handle_subgraphs(
+        &mut self,
+        node_proto: &NodeProto,
+        node: &mut Node,
+        graph_data: &GraphData,
+    ) -> Result<(), OnnxParseError> {
+        let graph_attrs = node_proto
+            .attribute
+            .iter()
+            .filter(|attr| is_graph_attribute(attr))
+            .collect::<Vec<_>>();
+
+        if graph_attrs.is_empty() {
+            return Ok(());
+        }
+
+        // The removed identity nodes are replaced by their input
+        let mut outer = graph_data.scope_values();
+        for arg in outer.values_mut() {
+            if let Some(identity_idx) = self.identity_idx.get(&arg.name) {
+                *arg = graph_data.processed_nodes[*identity_idx].inputs[0].clone();
+            }
+        }
+        let mut captured = Vec::<Argument>::new();
+
+        for attr in graph_attrs {
+            let prefix = format!("{}_{}_in", node.name, attr.name);
+            let value = if attr.type_.enum_value() == Ok(AttributeType::GRAPH) {
+                let subgraph = self.subgraph(&attr.g, &prefix, &outer, &mut captured)?;
+                AttributeValue::Graph(subgraph)
+            } else {
+                let subgraphs = attr
+                    .graphs
+                    .iter()
+                    .enumerate()
+                    .map(|(i, graph)| {
+                        let prefix = format!("{prefix}{}_", i + 1);
+                        self.subgraph(graph, &prefix, &outer, &mut captured)
+                    })
+                    .collect::<Result<_, _>>()?;
+                AttributeValue::Graphs(subgraphs)
+            };
+            node.attrs.insert(attr.name.clone(), value);
+        }
+
+        node.inputs.extend(captured);
+
+        Ok(())
+    }
+
+    /// Build a sub-graph with a new builder sharing the node names, and collect the values of the
+    /// enclosing graphs it uses.
+    fn subgraph(
+        &mut self,
+        graph: &GraphProto,
+        input_prefix: &str,
+        outer: &HashMap<String, Argument>,
+        captured: &mut Vec<Argument>,
+    ) -> Result<OnnxGraph, OnnxParseError> {
+        let mut builder = OnnxGraphBuilder {
+            node_name_counter: std::mem::take(&mut self.node_name_counter),
+            opset_version: self.opset_version,
+            ..Default::default()
+        };
+        let subgraph = builder.build_subgraph(graph, input_prefix, outer.clone())?;
+        self.node_name_counter = builder.node_name_counter;
+
+        // Constant values are passed with the arguments, only the variables are captured
+        let outer_names = outer
+            .values()
+            .filter(|arg| arg.value.is_none())
+            .map(|arg| &arg.name)
+            .collect::<HashSet<_>>();
+        let used = subgraph
+            .nodes
+            .iter()
+            .flat_map(|node| node.inputs.iter())
+            .chain(subgraph.outputs.iter());
+
+        for arg in used {
+            if outer_names.contains(&arg.name) && !captured.iter().any(|x| x.name == arg.name) {
+                captured.push(arg.clone());
+            }
+        }
+
+        Ok(subgraph)
     }
 
     fn handle_node_renaming(&mut self, node: &mut Node) {
@@ -269,9 +494,15 @@ impl OnnxGraphBuilder {
                 format!("{}_out{}", &node.name, 1),
                 graph_data.get_current_index(),
             );
-        } else if self.constants_types.contains(&node.node_type) {
+        } else if self.constants_types.contains(&node.node_type) || is_shape_computation(node) {
             log::debug!("checking node {} for constants", &node.name);
-            for input in node.inputs.iter_mut().skip(1) {
+            // The operands of shape computations are all lifted, since they are evaluated in the
+            // generated code with the runtime shapes
+            let skip = match is_shape_computation(node) {
+                true => 0,
+                false => 1,
+            };
+            for input in node.inputs.iter_mut().skip(skip) {
                 log::debug!("checking input {:?} for const", input);
                 if let Some(const_idx) = self.constants_map.get(&input.name) {
                     let constant = &graph_data.processed_nodes[*const_idx];
@@ -329,6 +560,84 @@ impl OnnxGraphBuilder {
     }
 }
 
+/// Check if the node computes on the runtime shape of a tensor, e.g. a `Gather` or `Concat` of
+/// the output of a `Shape` node.
+pub(crate) fn is_shape_computation(node: &Node) -> bool {
+    let is_shape = |input: &Argument| matches!(input.ty, ArgType::Shape(_));
+
+    match node.node_type {
+        NodeType::Add | NodeType::Concat | NodeType::Div | NodeType::Mul | NodeType::Sub => {
+            node.inputs.iter().any(is_shape)
+        }
+        NodeType::Cast | NodeType::Gather | NodeType::Slice | NodeType::Unsqueeze => {
+            node.inputs.first().is_some_and(is_shape)
+        }
+        _ => false,
+    }
+}
+
+/// Error returned when an ONNX file can't be converted to a graph.
+#[derive(Debug)]
+pub enum OnnxParseError {
+    /// The file can't be opened.
+    Io(std::io::Error),
+    /// The file isn't a valid ONNX model.
+    Protobuf(protobuf::Error),
+    /// A node of an older opset has a legacy form that can't be converted.
+    UnsupportedOpset {
+        /// The type and the name of the node.
+        node: String,
+        /// The version of the default ONNX opset imported by the model.
+        opset_version: i64,
+        /// The legacy feature used by the node.
+        feature: String,
+    },
+    /// An attribute of a node has an invalid value.
+    InvalidAttribute {
+        /// The type and the name of the node.
+        node: String,
+        /// The name of the attribute.
+        attribute: String,
+        /// The reason the value is invalid.
+        reason: String,
+    },
+    /// The data of a tensor stored in an external file can't be read.
+    ExternalData {
+        /// The name of the tensor.
+        tensor: String,
+        /// The reason the data can't be read.
+        reason: String,
+    },
+}
+
+impl std::fmt::Display for OnnxParseError {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            Self::Io(err) => write!(f, "Unable to open file: {err}"),
+            Self::Protobuf(err) => write!(f, "Unable to parse ONNX file: {err}"),
+            Self::UnsupportedOpset {
+                node,
+                opset_version,
+                feature,
+            } => write!(
+                f,
+                "{node}: {feature} of opset {opset_version} can't be converted, please upgrade \
+                the model with the ONNX version converter"
+            ),
+            Self::InvalidAttribute {
+                node,
+                attribute,
+                reason,
+            } => write!(f, "{node}: invalid {attribute} attribute, {reason}"),
+            Self::ExternalData { tensor, reason } => {
+                write!(f, "Tensor {tensor}: invalid external data, {reason}")
+            }
+        }
+    }
+}
+
+impl std::error::Error for OnnxParseError {}
+
 /// Open an onnx file and convert it to a Graph (intermediate representation)
 ///
 /// # Arguments
@@ -339,18 +648,23 @@ impl OnnxGraphBuilder {
 ///
 /// * `OnnxGraph` - The graph representation of the onnx file
 ///
+/// Returns an error if the file cannot be opened or parsed, if it references external data that
+/// can't be read, or if it contains nodes of an older opset that can't be converted.
+///
 /// # Panics
 ///
-/// * If the file cannot be opened
-/// * If the file cannot be parsed
 /// * If the nodes are not topologically sorted
-pub fn parse_onnx(onnx_path: &Path) -> OnnxGraph {
+pub fn parse_onnx(onnx_path: &Path) -> Result<OnnxGraph, OnnxParseError> {
     log::info!("Parsing ONNX file: {}", onnx_path.display());
 
     // Open the file
-    let mut file = File::open(onnx_path).expect("Unable to open file");
-    let onnx_model: ModelProto =
-        Message::parse_from_reader(&mut file).expect("Unable to parse ONNX file");
+    let mut file = File::open(onnx_path).map_err(OnnxParseError::Io)?;
+    let mut onnx_model: ModelProto =
+        Message::parse_from_reader(&mut file).map_err(OnnxParseError::Protobuf)?;
+
+    // The external data is stored relative to the model
+    let base_dir = onnx_path.parent().unwrap_or_else(|| Path::new(""));
+    resolve_external_data(onnx_model.graph.mut_or_insert_default(), base_dir)?;
 
     // ONNX nodes must be topologically sorted per spec:
     // https://github.com/onnx/onnx/blob/main/docs/IR.md#graphs
@@ -368,11 +682,11 @@ pub fn parse_onnx(onnx_path: &Path) -> OnnxGraph {
 
     log::debug!("Number of outputs: {:?}", onnx_model.graph.output.len());
     let builder = OnnxGraphBuilder::default();
-    let graph = builder.build(&onnx_model);
+    let graph = builder.build(&onnx_model)?;
 
     log::info!("Finished parsing ONNX file: {}", onnx_path.display());
 
-    graph
+    Ok(graph)
 }
 
 /// Remap the unsqueeze node to a reshape node, Should only be called after
@@ -397,6 +711,7 @@ pub(crate) fn remap_unsqueeze_to_reshape(node: &mut Node, out_arg: &Argument) {
                 elem_type: super::ir::ElementType::Int64,
                 dim: 1,
                 shape: Some(vec![shape_len]),
+                symbolic_shape: None,
             }),
             value: new_rhs_value,
             passed: false,
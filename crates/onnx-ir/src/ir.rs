@@ -3,6 +3,7 @@ use half::f16;
 use std::{collections::HashMap, fmt::Formatter};
 use strum_macros::{Display, EnumString};
 
+pub use crate::external_data::ExternalData;
 use crate::protos::TensorProto;
 
 // TODO: Rename Dim to Rank
@@ -60,6 +61,7 @@ impl Argument {
                     elem_type: tensor.elem_type,
                     dim: tensor.dim,
                     shape: tensor.shape,
+                    symbolic_shape: None,
                 }),
                 value: tensor.data.clone(),
                 passed: false,
@@ -87,6 +89,8 @@ pub enum AttributeValue {
     Strings(Vec<String>),
     Tensor(Tensor),
     Tensors(Vec<Tensor>),
+    Graph(OnnxGraph),
+    Graphs(Vec<OnnxGraph>),
 }
 
 pub type Attributes = HashMap<String, AttributeValue>;
@@ -104,6 +108,7 @@ pub enum ElementType {
 }
 
 #[derive(Debug, Clone, Default)]
+#[non_exhaustive]
 pub struct TensorType {
     /// The type of the tensor.
     pub elem_type: ElementType,
@@ -113,7 +118,49 @@ pub struct TensorType {
     pub dim: Dim,
 
     /// The shape of the tensor.
+    ///
+    /// Only set when every dimension is known statically.
     pub shape: Option<Shape>,
+
+    /// The shape of the tensor, where dimensions may be named (e.g. `batch`) or unknown.
+    pub symbolic_shape: Option<Vec<SymbolicDim>>,
+}
+
+impl TensorType {
+    /// Creates a tensor type with the given element type, rank and static shape.
+    ///
+    /// The symbolic shape is left unknown.
+    pub fn new(elem_type: ElementType, dim: Dim, shape: Option<Shape>) -> Self {
+        Self {
+            elem_type,
+            dim,
+            shape,
+            symbolic_shape: None,
+        }
+    }
+
+    /// Returns the size of the given axis if it is known statically.
+    pub fn static_dim(&self, axis: usize) -> Option<usize> {
+        if let Some(shape) = self.shape.as_ref().filter(|shape| shape.len() == self.dim) {
+            return shape.get(axis).copied();
+        }
+
+        match self.symbolic_shape.as_ref()?.get(axis)? {
+            SymbolicDim::Static(size) => Some(*size),
+            _ => None,
+        }
+    }
+}
+
+/// A dimension of a tensor shape, as declared by the ONNX model.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum SymbolicDim {
+    /// A dimension with a known size.
+    Static(usize),
+    /// A dimension named by a `dim_param`, only known at runtime.
+    Param(String),
+    /// A dimension with neither a size nor a name.
+    Unknown,
 }
 
 impl Default for ElementType {
@@ -171,6 +218,8 @@ pub enum Data {
     Int64s(Vec<i64>),
     String(String),
     Strings(Vec<String>),
+    /// Data stored in an external file, only read when it's needed.
+    External(ExternalData),
 }
 
 /// ONNX graph representation
@@ -485,13 +534,26 @@ impl fmt::Debug for Data {
             Data::Int64(v) => write!(f, "Int64({})", v),
             Data::String(v) => write!(f, "String({})", v),
             Data::Bool(v) => write!(f, "Bool({})", v),
+            Data::External(v) => write!(f, "{:?}", v),
         }
     }
 }
 
 impl Data {
-    pub fn into_scalar(self) -> Self {
+    /// Read the data if it's stored in an external file.
+    ///
+    /// # Panics
+    ///
+    /// If the external data can't be read.
+    pub fn load(self) -> Self {
         match self {
+            Data::External(data) => data.read().unwrap_or_else(|err| panic!("{err}")),
+            _ => self,
+        }
+    }
+
+    pub fn into_scalar(self) -> Self {
+        match self.load() {
             Data::Float16s(data) => {
                 assert_eq!(data.len(), 1);
                 Data::Float16(data[0])
@@ -520,119 +582,105 @@ impl Data {
                 assert_eq!(data.len(), 1);
                 Data::String(data[0].clone())
             }
-            _ => self,
+            data => data,
         }
     }
 
     pub fn into_f16(self) -> f16 {
-        if let Data::Float16(elem) = self {
-            elem
-        } else {
-            panic!("Expected Float16, got {:?}", self);
+        match self.load() {
+            Data::Float16(elem) => elem,
+            data => panic!("Expected Float16, got {:?}", data),
         }
     }
 
     pub fn into_f32(self) -> f32 {
-        if let Data::Float32(elem) = self {
-            elem
-        } else {
-            panic!("Expected Float32, got {:?}", self);
+        match self.load() {
+            Data::Float32(elem) => elem,
+            data => panic!("Expected Float32, got {:?}", data),
         }
     }
 
     pub fn into_f64(self) -> f64 {
-        if let Data::Float64(elem) = self {
-            elem
-        } else {
-            panic!("Expected Float64, got {:?}", self);
+        match self.load() {
+            Data::Float64(elem) => elem,
+            data => panic!("Expected Float64, got {:?}", data),
         }
     }
 
     pub fn into_i32(self) -> i32 {
-        if let Data::Int32(elem) = self {
-            elem
-        } else {
-            panic!("Expected Int32, got {:?}", self);
+        match self.load() {
+            Data::Int32(elem) => elem,
+            data => panic!("Expected Int32, got {:?}", data),
         }
     }
 
     pub fn into_i64(self) -> i64 {
-        if let Data::Int64(elem) = self {
-            elem
-        } else {
-            panic!("Expected Int64, got {:?}", self);
+        match self.load() {
+            Data::Int64(elem) => elem,
+            data => panic!("Expected Int64, got {:?}", data),
         }
     }
 
     pub fn into_bool(self) -> bool {
-        if let Data::Bool(elem) = self {
-            elem
-        } else {
-            panic!("Expected Bool, got {:?}", self);
+        match self.load() {
+            Data::Bool(elem) => elem,
+            data => panic!("Expected Bool, got {:?}", data),
         }
     }
 
     pub fn into_string(self) -> String {
-        if let Data::String(elem) = self {
-            elem
-        } else {
-            panic!("Expected String, got {:?}", self);
+        match self.load() {
+            Data::String(elem) => elem,
+            data => panic!("Expected String, got {:?}", data),
         }
     }
 
     pub fn into_f16s(self) -> Vec<f16> {
-        if let Data::Float16s(elem) = self {
-            elem
-        } else {
-            panic!("Expected Float16s, got {:?}", self);
+        match self.load() {
+            Data::Float16s(elem) => elem,
+            data => panic!("Expected Float16s, got {:?}", data),
         }
     }
 
     pub fn into_f32s(self) -> Vec<f32> {
-        if let Data::Float32s(elem) = self {
-            elem
-        } else {
-            panic!("Expected Float32s, got {:?}", self);
+        match self.load() {
+            Data::Float32s(elem) => elem,
+            data => panic!("Expected Float32s, got {:?}", data),
         }
     }
 
     pub fn into_f64s(self) -> Vec<f64> {
-        if let Data::Float64s(elem) = self {
-            elem
-        } else {
-            panic!("Expected Float64s, got {:?}", self);
+        match self.load() {
+            Data::Float64s(elem) => elem,
+            data => panic!("Expected Float64s, got {:?}", data),
         }
     }
 
     pub fn into_i32s(self) -> Vec<i32> {
-        if let Data::Int32s(elem) = self {
-            elem
-        } else {
-            panic!("Expected Int32s, got {:?}", self);
+        match self.load() {
+            Data::Int32s(elem) => elem,
+            data => panic!("Expected Int32s, got {:?}", data),
         }
     }
 
     pub fn into_i64s(self) -> Vec<i64> {
-        if let Data::Int64s(elem) = self {
-            elem
-        } else {
-            panic!("Expected Int64s, got {:?}", self);
+        match self.load() {
+            Data::Int64s(elem) => elem,
+            data => panic!("Expected Int64s, got {:?}", data),
         }
     }
 
     pub fn into_bools(self) -> Vec<bool> {
-        if let Data::Bools(elem) = self {
-            elem
-        } else {
-            panic!("Expected Bools, got {:?}", self);
+        match self.load() {
+            Data::Bools(elem) => elem,
+            data => panic!("Expected Bools, got {:?}", data),
         }
     }
 
     pub fn into_strings(self) -> Vec<String> {
-        if let Data::Strings(elem) = self {
-            elem
-        } else {
-            panic!("Expected Strings, got {:?}", self);
+        match self.load() {
+            Data::Strings(elem) => elem,
+            data => panic!("Expected Strings, got {:?}", data),
         }
     }
 }
@@ -709,6 +757,22 @@ impl AttributeValue {
             panic!("Expected Tensors, got {:?}", self);
         }
     }
+
+    pub fn into_graph(self) -> OnnxGraph {
+        if let AttributeValue::Graph(elem) = self {
+            elem
+        } else {
+            panic!("Expected Graph, got {:?}", self);
+        }
+    }
+
+    pub fn into_graphs(self) -> Vec<OnnxGraph> {
+        if let AttributeValue::Graphs(elem) = self {
+            elem
+        } else {
+            panic!("Expected Graphs, got {:?}", self);
+        }
+    }
 }
 
 /// Convert AttributeValue to an Argument
@@ -729,6 +793,7 @@ impl From<AttributeValue> for Argument {
                     dim: 1,
                     elem_type: ElementType::Float32,
                     shape: Some(vec![values.len()]),
+                    symbolic_shape: None,
                 }),
                 name,
                 value: Some(Data::Float32s(values)),
@@ -745,6 +810,7 @@ impl From<AttributeValue> for Argument {
                     dim: 1,
                     elem_type: ElementType::Int64,
                     shape: Some(vec![values.len()]),
+                    symbolic_shape: None,
                 }),
                 name,
                 value: Some(Data::Int64s(values)),
@@ -761,6 +827,7 @@ impl From<AttributeValue> for Argument {
                     dim: 1,
                     elem_type: ElementType::String,
                     shape: Some(vec![values.len()]),
+                    symbolic_shape: None,
                 }),
                 name,
                 value: Some(Data::Strings(values)),
@@ -791,6 +858,7 @@ impl From<AttributeValue> for Argument {
                             dim: tensor.dim,
                             elem_type: tensor.elem_type,
                             shape: tensor.shape,
+                            symbolic_shape: None,
                         }),
                         name,
                         value: tensor.data,
@@ -1,12 +1,17 @@
 mod coalesce;
 mod dim_inference;
+mod external_data;
 mod from_onnx;
 pub mod ir;
 mod node_remap;
+mod opset;
+mod passes;
 mod proto_conversion;
-mod protos;
+/// The protobuf definitions of the ONNX format.
+pub mod protos;
 mod util;
 
 pub use from_onnx::convert_constant_value;
-pub use from_onnx::parse_onnx;
+pub use from_onnx::{parse_onnx, OnnxParseError};
 pub use ir::OnnxGraph;
+pub use passes::{optimize, Pass};
@@ -0,0 +1,211 @@
+use super::from_onnx::OnnxParseError;
+use super::ir::{ArgType, Argument, AttributeValue, Data, ElementType, Node, NodeType, TensorType};
+use super::protos::ModelProto;
+
+/// Get the version of the default ONNX operator set imported by the model.
+pub(crate) fn opset_version(model: &ModelProto) -> i64 {
+    let version = model
+        .opset_import
+        .iter()
+        .find(|opset| opset.domain.is_empty() || opset.domain == "ai.onnx")
+        .map(|opset| opset.version);
+
+    version.unwrap_or_else(|| {
+        log::warn!("The model does not import the default ONNX opset, assuming the latest");
+        i64::MAX
+    })
+}
+
+/// Rewrite a node of an older opset into the form it has in the latest opset.
+///
+/// Several operators moved some of their attributes into inputs between opsets, e.g. the axes of
+/// `Squeeze` and `Unsqueeze` in opset 13. The attributes are converted into constant inputs, so
+/// the rest of the pipeline only handles the latest form of the nodes.
+///
+/// Returns an error if the node has a legacy form that can't be converted, e.g. the
+/// `consumed_inputs` attribute removed in opset 6.
+pub(crate) fn normalize_opset(node: &mut Node, opset_version: i64) -> Result<(), OnnxParseError> {
+    if node.attrs.contains_key("consumed_inputs") {
+        return Err(unsupported(
+            node,
+            opset_version,
+            "the consumed_inputs attribute",
+        ));
+    }
+
+    match node.node_type {
+        // Before opset 7, the broadcasting is enabled with an attribute
+        NodeType::Add
+        | NodeType::And
+        | NodeType::Div
+        | NodeType::Equal
+        | NodeType::Greater
+        | NodeType::Less
+        | NodeType::Mul
+        | NodeType::Or
+        | NodeType::Pow
+        | NodeType::Sub
+        | NodeType::Xor
+            if opset_version < 7 =>
+        {
+            remove_legacy_broadcast(node, opset_version)?
+        }
+        // Before opset 9, the statistics can be computed per activation
+        NodeType::BatchNormalization if opset_version < 9 => {
+            node.attrs.remove("is_test");
+            if let Some(spatial) = node.attrs.remove("spatial") {
+                if spatial.into_i64() == 0 {
+                    return Err(unsupported(
+                        node,
+                        opset_version,
+                        "the non spatial normalization",
+                    ));
+                }
+            }
+        }
+        // Since opset 13, the axes are an input
+        NodeType::Squeeze | NodeType::Unsqueeze | NodeType::ReduceSum if opset_version < 13 => {
+            attribute_to_ints_input(node, "axes", 1)?
+        }
+        // Since opset 18, the axes of the other reductions are an input
+        NodeType::ReduceL1
+        | NodeType::ReduceL2
+        | NodeType::ReduceLogSum
+        | NodeType::ReduceLogSumExp
+        | NodeType::ReduceMax
+        | NodeType::ReduceMean
+        | NodeType::ReduceMin
+        | NodeType::ReduceProd
+        | NodeType::ReduceSumSquare
+            if opset_version < 18 =>
+        {
+            attribute_to_ints_input(node, "axes", 1)?
+        }
+        // Since opset 13, the sizes of the outputs are an input
+        NodeType::Split if opset_version < 13 => attribute_to_ints_input(node, "split", 1)?,
+        // Since opset 11, the bounds are optional inputs
+        NodeType::Clip if opset_version < 11 => {
+            attribute_to_float_input(node, "min", 1);
+            attribute_to_float_input(node, "max", 2);
+        }
+        // Since opset 11, the pads and the padding value are inputs
+        NodeType::Pad if opset_version < 11 => {
+            attribute_to_ints_input(node, "pads", 1)?;
+            attribute_to_float_input(node, "value", 2);
+        }
+        // Since opset 10, the bounds and the axes are inputs
+        NodeType::Slice if opset_version < 10 => {
+            attribute_to_ints_input(node, "starts", 1)?;
+            attribute_to_ints_input(node, "ends", 2)?;
+            attribute_to_ints_input(node, "axes", 3)?;
+        }
+        // Since opset 12, the ratio is an input, which defaults to 0.5
+        NodeType::Dropout if opset_version < 12 => {
+            node.attrs.remove("is_test");
+            let ratio = match node.attrs.remove("ratio") {
+                Some(ratio) => ratio.into_f32(),
+                None => 0.5,
+            };
+            let argument = scalar_argument(node, "ratio", Data::Float32(ratio));
+            set_input(node, 1, argument);
+        }
+        // Since opset 10, k is an input
+        NodeType::TopK if opset_version < 10 => attribute_to_ints_input(node, "k", 1)?,
+        _ => {}
+    }
+
+    Ok(())
+}
+
+/// Remove the legacy broadcasting attributes, which align the right operand with the trailing
+/// dimensions like the numpy broadcasting unless an axis is given.
+fn remove_legacy_broadcast(node: &mut Node, opset_version: i64) -> Result<(), OnnxParseError> {
+    node.attrs.remove("broadcast");
+
+    if node.attrs.contains_key("axis") {
+        return Err(unsupported(node, opset_version, "the broadcasting axis"));
+    }
+
+    Ok(())
+}
+
+fn unsupported(node: &Node, opset_version: i64, feature: &str) -> OnnxParseError {
+    OnnxParseError::UnsupportedOpset {
+        node: node_description(node),
+        opset_version,
+        feature: feature.to_string(),
+    }
+}
+
+/// The type and the name of the node, to report errors.
+fn node_description(node: &Node) -> String {
+    format!("{} {:?}", node.node_type, node.name)
+}
+
+/// Move an integer attribute into a constant input of rank 1.
+///
+/// Returns an error if the attribute isn't made of integers.
+fn attribute_to_ints_input(
+    node: &mut Node,
+    name: &str,
+    index: usize,
+) -> Result<(), OnnxParseError> {
+    let values = match node.attrs.remove(name) {
+        Some(AttributeValue::Int64s(values)) => values,
+        Some(AttributeValue::Int64(value)) => vec![value],
+        Some(value) => {
+            return Err(OnnxParseError::InvalidAttribute {
+                node: node_description(node),
+                attribute: name.to_string(),
+                reason: format!("expected integers, got {value:?}"),
+            })
+        }
+        None => return Ok(()),
+    };
+
+    let argument = Argument {
+        name: constant_name(node, name),
+        ty: ArgType::Tensor(TensorType::new(
+            ElementType::Int64,
+            1,
+            Some(vec![values.len()]),
+        )),
+        value: Some(Data::Int64s(values)),
+        passed: false,
+    };
+    set_input(node, index, argument);
+
+    Ok(())
+}
+
+/// Move a float attribute into a constant scalar input.
+fn attribute_to_float_input(node: &mut Node, name: &str, index: usize) {
+    if let Some(value) = node.attrs.remove(name) {
+        let argument = scalar_argument(node, name, Data::Float32(value.into_f32()));
+        set_input(node, index, argument);
+    }
+}
+
+fn scalar_argument(node: &Node, name: &str, value: Data) -> Argument {
+    Argument {
+        name: constant_name(node, name),
+        ty: ArgType::Scalar(ElementType::Float32),
+        value: Some(value),
+        passed: false,
+    }
+}
+
+/// The name of a constant input promoted from an attribute, which is unique in the graph like
+/// the name of the node.
+fn constant_name(node: &Node, attribute: &str) -> String {
+    format!("{}_{attribute}", node.name)
+}
+
+/// Set the input at the given index, the inputs in between being omitted.
+fn set_input(node: &mut Node, index: usize, argument: Argument) {
+    while node.inputs.len() <= index {
+        // Omitted optional inputs have an empty name
+        node.inputs.push(Argument::new(String::new()));
+    }
+    node.inputs[index] = argument;
+}
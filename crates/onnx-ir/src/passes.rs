@@ -0,0 +1,999 @@
+use std::collections::{HashMap, HashSet};
+
+use protobuf::Enum;
+
+use super::{
+    from_onnx::{convert_constant_value, is_shape_computation, LIFT_CONSTANTS_FOR_NODE_TYPES},
+    ir::{
+        ArgType, Argument, AttributeValue, Data, ElementType, Node, NodeType, OnnxGraph, Tensor,
+        TensorType,
+    },
+    protos::tensor_proto::DataType,
+    util::shape_config,
+};
+
+/// An optimization pass applied to the graph before the code generation.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum Pass {
+    /// Remove the `Identity` nodes and the `Dropout` nodes, which do nothing at inference.
+    IdentityRemoval,
+    /// Evaluate the computations on the shapes known statically, e.g. the `Shape`, `Gather` and
+    /// `Concat` nodes computing the target shape of a `Reshape`.
+    ShapeSimplification,
+    /// Evaluate the nodes whose inputs are all constants, and replace them by constants.
+    ConstantFolding,
+    /// Fold the `BatchNormalization` following a convolution into the weights and the bias of the
+    /// convolution.
+    ConvBatchNormFolding,
+    /// Remove the nodes whose outputs are not used.
+    DeadNodeElimination,
+}
+
+impl Pass {
+    /// All the passes, in the order they are applied.
+    pub const ALL: [Pass; 5] = [
+        Pass::IdentityRemoval,
+        Pass::ShapeSimplification,
+        Pass::ConstantFolding,
+        Pass::ConvBatchNormFolding,
+        Pass::DeadNodeElimination,
+    ];
+}
+
+/// Optimize the graph with the given passes.
+///
+/// The passes are applied in the order of [Pass::ALL] whatever the order they are given in, the
+/// sub-graphs being optimized first. The inputs and the outputs of the graphs are kept.
+///
+/// # Arguments
+///
+/// * `graph` - The graph to optimize
+/// * `passes` - The passes to apply
+///
+/// # Returns
+///
+/// * `OnnxGraph` - The optimized graph
+pub fn optimize(mut graph: OnnxGraph, passes: &[Pass]) -> OnnxGraph {
+    optimize_graph(&mut graph, passes);
+    graph
+}
+
+fn optimize_graph(graph: &mut OnnxGraph, passes: &[Pass]) {
+    for node in graph.nodes.iter_mut() {
+        for attr in node.attrs.values_mut() {
+            match attr {
+                AttributeValue::Graph(subgraph) => optimize_graph(subgraph, passes),
+                AttributeValue::Graphs(subgraphs) => subgraphs
+                    .iter_mut()
+                    .for_each(|subgraph| optimize_graph(subgraph, passes)),
+                _ => {}
+            }
+        }
+    }
+
+    for pass in Pass::ALL.into_iter().filter(|pass| passes.contains(pass)) {
+        log::debug!("Running the {pass:?} pass");
+        let num_nodes = graph.nodes.len();
+
+        match pass {
+            Pass::IdentityRemoval => remove_identities(graph),
+            Pass::ShapeSimplification => fold_nodes(graph, is_shape_node),
+            Pass::ConstantFolding => fold_nodes(graph, |node| !is_shape_node(node)),
+            Pass::ConvBatchNormFolding => fold_conv_batch_norms(graph),
+            Pass::DeadNodeElimination => remove_dead_nodes(graph),
+        }
+
+        log::debug!(
+            "The {pass:?} pass removed {} nodes",
+            num_nodes - graph.nodes.len()
+        );
+    }
+}
+
+/// Remove the `Identity` and `Dropout` nodes, their outputs being replaced by their input.
+///
+/// The nodes producing an output of the graph are kept, as well as the `Dropout` nodes whose mask
+/// is used.
+fn remove_identities(graph: &mut OnnxGraph) {
+    let graph_outputs = names(&graph.outputs);
+    let mut used = HashSet::new();
+    graph
+        .nodes
+        .iter()
+        .for_each(|node| collect_uses(node, &mut used));
+
+    let mut removed = HashSet::new();
+    for i in 0..graph.nodes.len() {
+        let node = &graph.nodes[i];
+        let removable = match node.node_type {
+            // The constant identities are folded with the constants
+            NodeType::Identity => node.inputs[0].value.is_none(),
+            NodeType::Dropout => node.outputs[1..]
+                .iter()
+                .all(|mask| mask.name.is_empty() || !used.contains(&mask.name)),
+            _ => false,
+        };
+        if !removable || graph_outputs.contains(&node.outputs[0].name) {
+            continue;
+        }
+
+        let input = node.inputs[0].clone();
+        let output = node.outputs[0].name.clone();
+        for node in graph.nodes[i + 1..].iter_mut() {
+            replace_uses(node, &output, &input);
+        }
+        removed.insert(i);
+    }
+
+    remove_nodes(graph, &removed);
+}
+
+/// Replace the uses of a value by another one, including the uses by the sub-graphs.
+fn replace_uses(node: &mut Node, name: &str, replacement: &Argument) {
+    for input in node.inputs.iter_mut().filter(|input| input.name == name) {
+        input.name.clone_from(&replacement.name);
+        input.value.clone_from(&replacement.value);
+    }
+
+    for subgraph in subgraphs_mut(node) {
+        for node in subgraph.nodes.iter_mut() {
+            replace_uses(node, name, replacement);
+        }
+        for output in subgraph
+            .outputs
+            .iter_mut()
+            .filter(|output| output.name == name)
+        {
+            output.name.clone_from(&replacement.name);
+            output.value.clone_from(&replacement.value);
+        }
+    }
+}
+
+/// Check if the node computes a runtime shape, which can be evaluated when the shapes of the
+/// tensors are known statically.
+fn is_shape_node(node: &Node) -> bool {
+    node.node_type == NodeType::Shape
+        || node
+            .outputs
+            .first()
+            .is_some_and(|output| matches!(output.ty, ArgType::Shape(_)))
+}
+
+/// Evaluate the nodes whose inputs are constants, in a single sweep of the graph.
+///
+/// The values are lifted into the nodes reading their constant inputs at import time, e.g. the
+/// target shape of a `Reshape`, like the builder does with the `Constant` nodes. The nodes
+/// evaluated to a tensor or a scalar are replaced by `Constant` nodes, while the nodes evaluated
+/// to a shape are left to the dead node elimination, since shapes can't be constants.
+fn fold_nodes(graph: &mut OnnxGraph, foldable: fn(&Node) -> bool) {
+    let mut values = HashMap::<String, Value>::new();
+
+    for node in graph.nodes.iter_mut() {
+        lift_values(node, &values);
+
+        if node.node_type == NodeType::Constant {
+            if let Some(value) = Value::from_argument(&convert_constant_value(node)) {
+                values.insert(node.outputs[0].name.clone(), value);
+            }
+            continue;
+        }
+        if node.outputs.len() != 1 || !foldable(node) {
+            continue;
+        }
+
+        let value = evaluate(node, &values).and_then(|value| value.with_type(&node.outputs[0].ty));
+        let Some(value) = value else {
+            continue;
+        };
+
+        log::debug!("Folded node {} into a constant", node.name);
+        values.insert(node.outputs[0].name.clone(), value.clone());
+        if !matches!(node.outputs[0].ty, ArgType::Shape(_)) {
+            *node = constant_node(node, &value);
+        }
+    }
+}
+
+/// Set the values of the constant inputs read at import time.
+fn lift_values(node: &mut Node, values: &HashMap<String, Value>) {
+    let is_lifted = |index: usize, input: &Argument| {
+        input.value.is_none() && values.contains_key(&input.name) && {
+            if is_shape_computation(node) {
+                // The operands of a shape computation are only lifted if it remains one, otherwise
+                // the node is folded
+                node.inputs.iter().any(|input| {
+                    matches!(input.ty, ArgType::Shape(_)) && !values.contains_key(&input.name)
+                })
+            } else {
+                index > 0 && LIFT_CONSTANTS_FOR_NODE_TYPES.contains(&node.node_type)
+            }
+        }
+    };
+    let lifted = node
+        .inputs
+        .iter()
+        .enumerate()
+        .filter(|(index, input)| is_lifted(*index, input))
+        .map(|(index, _)| index)
+        .collect::<Vec<_>>();
+
+    for index in lifted {
+        let input = &mut node.inputs[index];
+        let value = &values[&input.name];
+        input.value = Some(value.data());
+        input.ty = value.arg_type();
+    }
+}
+
+/// Replace the node by a `Constant` node with the same name and output.
+fn constant_node(node: &Node, value: &Value) -> Node {
+    let mut output = node.outputs[0].clone();
+    output.ty = value.arg_type();
+
+    let attr = match (&value.elements, value.shape.is_empty()) {
+        (Elements::Float32(elements), true) => AttributeValue::Float32(elements[0]),
+        (Elements::Int64(elements), true) => AttributeValue::Int64(elements[0]),
+        (_, false) => AttributeValue::Tensor(Tensor {
+            elem_type: value.elem_type(),
+            dim: value.shape.len(),
+            data: Some(value.data()),
+            shape: Some(value.shape.clone()),
+        }),
+    };
+
+    Node {
+        node_type: NodeType::Constant,
+        name: node.name.clone(),
+        inputs: vec![],
+        outputs: vec![output],
+        attrs: HashMap::from([("value".to_string(), attr)]),
+    }
+}
+
+/// Fold the `BatchNormalization` nodes into the convolutions they normalize.
+///
+/// The normalization computes `(x - mean) * scale / sqrt(var + epsilon) + bias` for each channel,
+/// which is an affine function merged into the weights and the bias of the convolution.
+fn fold_conv_batch_norms(graph: &mut OnnxGraph) {
+    let mut uses = HashMap::<String, usize>::new();
+    let mut count_use = |name: &str| *uses.entry(name.to_string()).or_default() += 1;
+    for node in graph.nodes.iter() {
+        let mut used = HashSet::new();
+        collect_uses(node, &mut used);
+        used.iter().for_each(|name| count_use(name));
+    }
+    graph
+        .outputs
+        .iter()
+        .for_each(|output| count_use(&output.name));
+
+    let producers = graph
+        .nodes
+        .iter()
+        .enumerate()
+        .flat_map(|(i, node)| {
+            node.outputs
+                .iter()
+                .map(move |output| (output.name.clone(), i))
+        })
+        .collect::<HashMap<_, _>>();
+
+    let mut removed = HashSet::new();
+    for i in 0..graph.nodes.len() {
+        let norm = &graph.nodes[i];
+        if norm.node_type != NodeType::BatchNormalization || norm.outputs.len() != 1 {
+            continue;
+        }
+        let Some(&conv_idx) = producers.get(&norm.inputs[0].name) else {
+            continue;
+        };
+        let conv = &graph.nodes[conv_idx];
+        let is_conv = matches!(
+            conv.node_type,
+            NodeType::Conv1d | NodeType::Conv2d | NodeType::Conv3d
+        );
+        if !is_conv || uses.get(&conv.outputs[0].name) != Some(&1) {
+            continue;
+        }
+
+        let Some((weight, bias)) = fold_batch_norm(conv, norm) else {
+            continue;
+        };
+        log::debug!("Folded node {} into node {}", norm.name, conv.name);
+        let output = norm.outputs[0].clone();
+
+        let conv = &mut graph.nodes[conv_idx];
+        conv.inputs[1].value = Some(Data::Float32s(weight));
+        let bias = Argument {
+            name: format!("{}_bias", conv.name),
+            ty: ArgType::Tensor(TensorType::new(
+                ElementType::Float32,
+                1,
+                Some(vec![bias.len()]),
+            )),
+            value: Some(Data::Float32s(bias)),
+            passed: false,
+        };
+        conv.inputs.truncate(2);
+        conv.inputs.push(bias);
+        conv.outputs[0] = output;
+        removed.insert(i);
+    }
+
+    remove_nodes(graph, &removed);
+}
+
+/// Compute the weights and the bias of the convolution followed by the batch normalization.
+fn fold_batch_norm(conv: &Node, norm: &Node) -> Option<(Vec<f32>, Vec<f32>)> {
+    let float_input =
+        |node: &Node, index: usize| match node.inputs.get(index)?.value.clone()?.load() {
+            Data::Float32s(values) => Some(values),
+            _ => None,
+        };
+    if norm
+        .attrs
+        .get("training_mode")
+        .is_some_and(|mode| mode.clone().into_i64() != 0)
+    {
+        return None;
+    }
+
+    let weight = float_input(conv, 1)?;
+    let [gamma, beta, mean, var] = [1, 2, 3, 4].map(|index| float_input(norm, index));
+    let (gamma, beta, mean, var) = (gamma?, beta?, mean?, var?);
+    let channels = gamma.len();
+    if [beta.len(), mean.len(), var.len()]
+        .iter()
+        .any(|len| *len != channels)
+        || channels == 0
+        || weight.len() % channels != 0
+    {
+        return None;
+    }
+    let bias = match conv.inputs.get(2).filter(|bias| !bias.name.is_empty()) {
+        Some(_) => float_input(conv, 2).filter(|bias| bias.len() == channels)?,
+        None => vec![0.0; channels],
+    };
+
+    let epsilon = norm
+        .attrs
+        .get("epsilon")
+        .map(|epsilon| epsilon.clone().into_f32())
+        .unwrap_or(1e-5);
+    let scale = gamma
+        .iter()
+        .zip(&var)
+        .map(|(gamma, var)| gamma / (var + epsilon).sqrt())
+        .collect::<Vec<_>>();
+
+    // The weights have the output channels as first axis
+    let kernel_size = weight.len() / channels;
+    let weight = weight
+        .iter()
+        .enumerate()
+        .map(|(i, weight)| weight * scale[i / kernel_size])
+        .collect();
+    let bias = (0..channels)
+        .map(|c| (bias[c] - mean[c]) * scale[c] + beta[c])
+        .collect();
+
+    Some((weight, bias))
+}
+
+/// Remove the nodes whose outputs are neither used by the other nodes nor outputs of the graph.
+fn remove_dead_nodes(graph: &mut OnnxGraph) {
+    let mut used = names(&graph.outputs);
+    let mut removed = HashSet::new();
+
+    for (i, node) in graph.nodes.iter().enumerate().rev() {
+        if node
+            .outputs
+            .iter()
+            .any(|output| used.contains(&output.name))
+        {
+            collect_uses(node, &mut used);
+        } else {
+            log::debug!("Removed dead node {}", node.name);
+            removed.insert(i);
+        }
+    }
+
+    remove_nodes(graph, &removed);
+}
+
+/// Collect the names of the values used by the node or its sub-graphs, except the constant
+/// inputs lifted into the node.
+fn collect_uses(node: &Node, used: &mut HashSet<String>) {
+    let lifts_constants = LIFT_CONSTANTS_FOR_NODE_TYPES.contains(&node.node_type);
+    let shape_computation = is_shape_computation(node);
+
+    for (i, input) in node.inputs.iter().enumerate() {
+        let lifted = input.value.is_some() && (shape_computation || (lifts_constants && i > 0));
+        if !lifted && !input.name.is_empty() {
+            used.insert(input.name.clone());
+        }
+    }
+
+    for subgraph in subgraphs(node) {
+        subgraph
+            .nodes
+            .iter()
+            .for_each(|node| collect_uses(node, used));
+        used.extend(names(&subgraph.outputs));
+    }
+}
+
+fn subgraphs(node: &Node) -> impl Iterator<Item = &OnnxGraph> {
+    node.attrs.values().flat_map(|attr| match attr {
+        AttributeValue::Graph(subgraph) => std::slice::from_ref(subgraph),
+        AttributeValue::Graphs(subgraphs) => subgraphs.as_slice(),
+        _ => &[],
+    })
+}
+
+fn subgraphs_mut(node: &mut Node) -> impl Iterator<Item = &mut OnnxGraph> {
+    node.attrs.values_mut().flat_map(|attr| match attr {
+        AttributeValue::Graph(subgraph) => std::slice::from_mut(subgraph),
+        AttributeValue::Graphs(subgraphs) => subgraphs.as_mut_slice(),
+        _ => &mut [],
+    })
+}
+
+fn names(args: &[Argument]) -> HashSet<String> {
+    args.iter().map(|arg| arg.name.clone()).collect()
+}
+
+fn remove_nodes(graph: &mut OnnxGraph, removed: &HashSet<usize>) {
+    let mut i = 0;
+    graph.nodes.retain(|_| {
+        let keep = !removed.contains(&i);
+        i += 1;
+        keep
+    });
+}
+
+/// A constant value evaluated at import time.
+#[derive(Debug, Clone)]
+struct Value {
+    elements: Elements,
+    shape: Vec<usize>,
+}
+
+/// The elements of a constant value, in row-major order.
+///
+/// Only the element types of the shape computations and of the float tensors are evaluated.
+#[derive(Debug, Clone)]
+enum Elements {
+    Float32(Vec<f32>),
+    Int64(Vec<i64>),
+}
+
+impl Value {
+    fn from_argument(arg: &Argument) -> Option<Self> {
+        let elements = match arg.value.clone()?.load() {
+            Data::Float32(value) => Elements::Float32(vec![value]),
+            Data::Float32s(values) => Elements::Float32(values),
+            Data::Int64(value) => Elements::Int64(vec![value]),
+            Data::Int64s(values) => Elements::Int64(values),
+            _ => return None,
+        };
+        let shape = match &arg.ty {
+            ArgType::Scalar(_) => vec![],
+            ArgType::Shape(_) => vec![elements.len()],
+            ArgType::Tensor(tensor) => match &tensor.shape {
+                Some(shape) if shape.len() == tensor.dim => shape.clone(),
+                _ if tensor.dim == 1 => vec![elements.len()],
+                _ => return None,
+            },
+        };
+
+        (num_elements(&shape) == elements.len()).then_some(Self { elements, shape })
+    }
+
+    /// Check the value against the type of the node output, shapes being kept with one axis.
+    fn with_type(self, ty: &ArgType) -> Option<Self> {
+        let same_elem_type = |elem_type: &ElementType| {
+            matches!(
+                (&self.elements, elem_type),
+                (Elements::Float32(_), ElementType::Float32)
+                    | (Elements::Int64(_), ElementType::Int64)
+            )
+        };
+
+        match ty {
+            ArgType::Shape(dim) => (self.elements.len() == *dim
+                && matches!(self.elements, Elements::Int64(_)))
+            .then(|| Self {
+                shape: vec![*dim],
+                ..self
+            }),
+            ArgType::Scalar(elem_type) => (self.elements.len() == 1 && same_elem_type(elem_type))
+                .then(|| Self {
+                    shape: vec![],
+                    ..self
+                }),
+            ArgType::Tensor(tensor) => (tensor.dim > 0
+                && tensor.dim == self.shape.len()
+                && same_elem_type(&tensor.elem_type))
+            .then_some(self),
+        }
+    }
+
+    fn elem_type(&self) -> ElementType {
+        match self.elements {
+            Elements::Float32(_) => ElementType::Float32,
+            Elements::Int64(_) => ElementType::Int64,
+        }
+    }
+
+    fn data(&self) -> Data {
+        match (&self.elements, self.shape.is_empty()) {
+            (Elements::Float32(elements), true) => Data::Float32(elements[0]),
+            (Elements::Float32(elements), false) => Data::Float32s(elements.clone()),
+            (Elements::Int64(elements), true) => Data::Int64(elements[0]),
+            (Elements::Int64(elements), false) => Data::Int64s(elements.clone()),
+        }
+    }
+
+    fn arg_type(&self) -> ArgType {
+        match self.shape.is_empty() {
+            true => ArgType::Scalar(self.elem_type()),
+            false => ArgType::Tensor(TensorType::new(
+                self.elem_type(),
+                self.shape.len(),
+                Some(self.shape.clone()),
+            )),
+        }
+    }
+
+    fn into_ints(self) -> Option<Vec<i64>> {
+        match self.elements {
+            Elements::Int64(elements) => Some(elements),
+            Elements::Float32(_) => None,
+        }
+    }
+}
+
+impl Elements {
+    fn len(&self) -> usize {
+        match self {
+            Elements::Float32(elements) => elements.len(),
+            Elements::Int64(elements) => elements.len(),
+        }
+    }
+
+    /// Select the elements at the given indices.
+    fn select(&self, indices: &[usize]) -> Self {
+        match self {
+            Elements::Float32(elements) => {
+                Elements::Float32(indices.iter().map(|i| elements[*i]).collect())
+            }
+            Elements::Int64(elements) => {
+                Elements::Int64(indices.iter().map(|i| elements[*i]).collect())
+            }
+        }
+    }
+
+    /// Append the elements of the parts, which must have the same element type.
+    fn concat(parts: Vec<Self>) -> Option<Self> {
+        let mut parts = parts.into_iter();
+        let mut elements = parts.next()?;
+        for part in parts {
+            match (&mut elements, part) {
+                (Elements::Float32(elements), Elements::Float32(part)) => elements.extend(part),
+                (Elements::Int64(elements), Elements::Int64(part)) => elements.extend(part),
+                _ => return None,
+            }
+        }
+        Some(elements)
+    }
+}
+
+/// Evaluate the node, if its inputs are constants and its operation is supported.
+fn evaluate(node: &Node, values: &HashMap<String, Value>) -> Option<Value> {
+    if node.node_type == NodeType::Shape {
+        return static_shape(node);
+    }
+
+    // The omitted optional inputs are `None`
+    let inputs = node
+        .inputs
+        .iter()
+        .map(|input| match values.get(&input.name) {
+            Some(value) => Some(Some(value.clone())),
+            None if input.value.is_some() => Value::from_argument(input).map(Some),
+            None if input.name.is_empty() => Some(None),
+            None => None,
+        })
+        .collect::<Option<Vec<_>>>()?;
+    let input = |index: usize| inputs.get(index).cloned().flatten();
+    let ints = |index: usize| input(index).and_then(Value::into_ints);
+    let attr_int = |name: &str, default: i64| {
+        node.attrs
+            .get(name)
+            .map_or(default, |value| value.clone().into_i64())
+    };
+
+    match node.node_type {
+        NodeType::Identity => input(0),
+        NodeType::Neg => {
+            let value = input(0)?;
+            let elements = match value.elements {
+                Elements::Float32(elements) => {
+                    Elements::Float32(elements.into_iter().map(|x| -x).collect())
+                }
+                Elements::Int64(elements) => {
+                    Elements::Int64(elements.into_iter().map(i64::wrapping_neg).collect())
+                }
+            };
+            Some(Value { elements, ..value })
+        }
+        NodeType::Cast => cast(input(0)?, attr_int("to", 0)),
+        NodeType::Add | NodeType::Sub | NodeType::Mul | NodeType::Div => {
+            binary(&node.node_type, &input(0)?, &input(1)?)
+        }
+        NodeType::Concat => {
+            let values = inputs.iter().cloned().collect::<Option<Vec<_>>>()?;
+            concat(values, attr_int("axis", 0))
+        }
+        NodeType::Gather => gather(input(0)?, input(1)?, attr_int("axis", 0)),
+        NodeType::Reshape => {
+            let shape = match node.attrs.get("shape") {
+                Some(shape) => shape.clone().into_i64s(),
+                None => ints(1)?,
+            };
+            reshape(input(0)?, &shape)
+        }
+        NodeType::Unsqueeze => unsqueeze(input(0)?, &ints(1)?),
+        NodeType::Squeeze => squeeze(input(0)?, input(1).and_then(Value::into_ints)),
+        NodeType::Slice => slice(input(0)?, &ints(1)?, &ints(2)?, ints(3), ints(4)),
+        _ => None,
+    }
+}
+
+/// The shape of the input of a `Shape` node, if it is known statically.
+fn static_shape(node: &Node) -> Option<Value> {
+    let ArgType::Tensor(tensor) = &node.inputs[0].ty else {
+        return None;
+    };
+    let shape = (0..tensor.dim)
+        .map(|axis| tensor.static_dim(axis).map(|dim| dim as i64))
+        .collect::<Option<Vec<_>>>()?;
+    let (start, end) = shape_config(node);
+    let dims = shape.get(start..end)?.to_vec();
+
+    Some(Value {
+        shape: vec![dims.len()],
+        elements: Elements::Int64(dims),
+    })
+}
+
+fn cast(value: Value, to: i64) -> Option<Value> {
+    let elements = match (value.elements, DataType::from_i32(to as i32)?) {
+        (Elements::Float32(elements), DataType::FLOAT) => Elements::Float32(elements),
+        (Elements::Int64(elements), DataType::FLOAT) => {
+            Elements::Float32(elements.into_iter().map(|x| x as f32).collect())
+        }
+        (Elements::Float32(elements), DataType::INT64) => {
+            Elements::Int64(elements.into_iter().map(|x| x as i64).collect())
+        }
+        (Elements::Int64(elements), DataType::INT64) => Elements::Int64(elements),
+        _ => return None,
+    };
+
+    Some(Value {
+        elements,
+        shape: value.shape,
+    })
+}
+
+/// Evaluate an arithmetic operation with the numpy broadcasting.
+fn binary(node_type: &NodeType, lhs: &Value, rhs: &Value) -> Option<Value> {
+    let rank = lhs.shape.len().max(rhs.shape.len());
+    let dim = |shape: &[usize], axis: usize| {
+        (axis + shape.len())
+            .checked_sub(rank)
+            .map_or(1, |axis| shape[axis])
+    };
+    let shape = (0..rank)
+        .map(
+            |axis| match (dim(&lhs.shape, axis), dim(&rhs.shape, axis)) {
+                (lhs, rhs) if lhs == rhs || rhs == 1 => Some(lhs),
+                (1, rhs) => Some(rhs),
+                _ => None,
+            },
+        )
+        .collect::<Option<Vec<_>>>()?;
+    let lhs_indices = broadcast_indices(&lhs.shape, &shape);
+    let rhs_indices = broadcast_indices(&rhs.shape, &shape);
+    let pairs = lhs_indices.iter().zip(&rhs_indices);
+
+    let elements = match (&lhs.elements, &rhs.elements) {
+        (Elements::Float32(lhs), Elements::Float32(rhs)) => {
+            let op: fn(f32, f32) -> f32 = match node_type {
+                NodeType::Add => |a, b| a + b,
+                NodeType::Sub => |a, b| a - b,
+                NodeType::Mul => |a, b| a * b,
+                _ => |a, b| a / b,
+            };
+            Elements::Float32(pairs.map(|(i, j)| op(lhs[*i], rhs[*j])).collect())
+        }
+        (Elements::Int64(lhs), Elements::Int64(rhs)) => {
+            let op: fn(i64, i64) -> Option<i64> = match node_type {
+                NodeType::Add => i64::checked_add,
+                NodeType::Sub => i64::checked_sub,
+                NodeType::Mul => i64::checked_mul,
+                _ => i64::checked_div,
+            };
+            Elements::Int64(
+                pairs
+                    .map(|(i, j)| op(lhs[*i], rhs[*j]))
+                    .collect::<Option<_>>()?,
+            )
+        }
+        _ => return None,
+    };
+
+    Some(Value { elements, shape })
+}
+
+fn concat(values: Vec<Value>, axis: i64) -> Option<Value> {
+    let first = values.first()?;
+    let axis = normalize_axis(axis, first.shape.len())?;
+    let same_dims = |value: &Value| {
+        value.shape.len() == first.shape.len()
+            && (0..first.shape.len()).all(|i| i == axis || value.shape[i] == first.shape[i])
+    };
+    if !values.iter().all(same_dims) {
+        return None;
+    }
+
+    // The values are appended, then their chunks along the axis are interleaved
+    let outer = num_elements(&first.shape[..axis]);
+    let chunks = values
+        .iter()
+        .map(|value| num_elements(&value.shape[axis..]))
+        .collect::<Vec<_>>();
+    let offsets = chunks
+        .iter()
+        .scan(0, |offset, chunk| {
+            let start = *offset;
+            *offset += chunk * outer;
+            Some(start)
+        })
+        .collect::<Vec<_>>();
+    let indices = (0..outer)
+        .flat_map(|o| {
+            chunks
+                .iter()
+                .zip(&offsets)
+                .flat_map(move |(chunk, offset)| offset + o * chunk..offset + (o + 1) * chunk)
+        })
+        .collect::<Vec<_>>();
+
+    let mut shape = first.shape.clone();
+    shape[axis] = values.iter().map(|value| value.shape[axis]).sum();
+    let elements = Elements::concat(values.into_iter().map(|value| value.elements).collect())?;
+
+    Some(Value {
+        elements: elements.select(&indices),
+        shape,
+    })
+}
+
+fn gather(data: Value, indices: Value, axis: i64) -> Option<Value> {
+    let axis = normalize_axis(axis, data.shape.len())?;
+    let dim = data.shape[axis] as i64;
+    let Elements::Int64(positions) = indices.elements else {
+        return None;
+    };
+    // Negative indices are counted from the end
+    let positions = positions
+        .into_iter()
+        .map(|i| {
+            let i = if i < 0 { i + dim } else { i };
+            (0..dim).contains(&i).then_some(i as usize)
+        })
+        .collect::<Option<Vec<_>>>()?;
+
+    let rank = indices.shape.len();
+    let shape = [&data.shape[..axis], &indices.shape, &data.shape[axis + 1..]].concat();
+    let index_strides = strides(&indices.shape);
+    let source = map_indices(&shape, &data.shape, |index| {
+        let position = dot(&index[axis..axis + rank], &index_strides);
+        [
+            &index[..axis],
+            &[positions[position]],
+            &index[axis + rank..],
+        ]
+        .concat()
+    });
+
+    Some(Value {
+        elements: data.elements.select(&source),
+        shape,
+    })
+}
+
+fn reshape(value: Value, shape: &[i64]) -> Option<Value> {
+    let mut inferred = None;
+    let mut dims = Vec::with_capacity(shape.len());
+    for (axis, dim) in shape.iter().enumerate() {
+        match *dim {
+            // A zero copies the dimension of the input
+            0 => dims.push(*value.shape.get(axis)?),
+            -1 if inferred.is_none() => {
+                inferred = Some(axis);
+                dims.push(1);
+            }
+            dim if dim > 0 => dims.push(dim as usize),
+            _ => return None,
+        }
+    }
+
+    let len = value.elements.len();
+    if let Some(axis) = inferred {
+        let known = num_elements(&dims);
+        if known == 0 || len % known != 0 {
+            return None;
+        }
+        dims[axis] = len / known;
+    }
+
+    (num_elements(&dims) == len).then_some(Value {
+        elements: value.elements,
+        shape: dims,
+    })
+}
+
+fn unsqueeze(value: Value, axes: &[i64]) -> Option<Value> {
+    let rank = value.shape.len() + axes.len();
+    let axes = axes
+        .iter()
+        .map(|axis| normalize_axis(*axis, rank))
+        .collect::<Option<HashSet<_>>>()?;
+    if axes.len() != rank - value.shape.len() {
+        return None;
+    }
+
+    let mut dims = value.shape.iter();
+    let shape = (0..rank)
+        .map(|axis| match axes.contains(&axis) {
+            true => 1,
+            false => *dims.next().unwrap(),
+        })
+        .collect();
+
+    Some(Value { shape, ..value })
+}
+
+fn squeeze(value: Value, axes: Option<Vec<i64>>) -> Option<Value> {
+    let rank = value.shape.len();
+    let axes = match axes {
+        Some(axes) => axes
+            .iter()
+            .map(|axis| normalize_axis(*axis, rank).filter(|axis| value.shape[*axis] == 1))
+            .collect::<Option<HashSet<_>>>()?,
+        None => (0..rank).filter(|axis| value.shape[*axis] == 1).collect(),
+    };
+    let shape = (0..rank)
+        .filter(|axis| !axes.contains(axis))
+        .map(|axis| value.shape[axis])
+        .collect();
+
+    Some(Value { shape, ..value })
+}
+
+fn slice(
+    value: Value,
+    starts: &[i64],
+    ends: &[i64],
+    axes: Option<Vec<i64>>,
+    steps: Option<Vec<i64>>,
+) -> Option<Value> {
+    let rank = value.shape.len();
+    let axes = axes.unwrap_or_else(|| (0..starts.len() as i64).collect());
+    let steps = steps.unwrap_or_else(|| vec![1; starts.len()]);
+    if ends.len() != starts.len() || axes.len() != starts.len() || steps.len() != starts.len() {
+        return None;
+    }
+
+    let mut begins = vec![0; rank];
+    let mut strides = vec![1; rank];
+    let mut shape = value.shape.clone();
+    for i in 0..starts.len() {
+        let axis = normalize_axis(axes[i], rank)?;
+        let (dim, step) = (value.shape[axis] as i64, steps[i]);
+        if dim == 0 || step == 0 {
+            return None;
+        }
+
+        // Negative bounds are counted from the end, and the bounds are clamped to the axis
+        let resolve = |bound: i64| if bound < 0 { bound + dim } else { bound };
+        let (begin, len) = if step > 0 {
+            let begin = resolve(starts[i]).clamp(0, dim);
+            let end = resolve(ends[i]).clamp(0, dim);
+            (begin, (end - begin + step - 1).max(0) / step)
+        } else {
+            let begin = resolve(starts[i]).clamp(0, dim - 1);
+            let end = resolve(ends[i]).clamp(-1, dim - 1);
+            (begin, (begin - end - step - 1).max(0) / -step)
+        };
+        begins[axis] = begin;
+        strides[axis] = step;
+        shape[axis] = len as usize;
+    }
+
+    let source = map_indices(&shape, &value.shape, |index| {
+        index
+            .iter()
+            .zip(begins.iter().zip(&strides))
+            .map(|(i, (begin, step))| (begin + *i as i64 * step) as usize)
+            .collect()
+    });
+
+    Some(Value {
+        elements: value.elements.select(&source),
+        shape,
+    })
+}
+
+fn normalize_axis(axis: i64, rank: usize) -> Option<usize> {
+    let axis = if axis < 0 { axis + rank as i64 } else { axis };
+    (0..rank as i64).contains(&axis).then_some(axis as usize)
+}
+
+fn num_elements(shape: &[usize]) -> usize {
+    shape.iter().product()
+}
+
+/// The strides of the axes of a tensor stored in row-major order.
+fn strides(shape: &[usize]) -> Vec<usize> {
+    let mut strides = vec![1; shape.len()];
+    for axis in (0..shape.len().saturating_sub(1)).rev() {
+        strides[axis] = strides[axis + 1] * shape[axis + 1];
+    }
+    strides
+}
+
+fn dot(index: &[usize], strides: &[usize]) -> usize {
+    index
+        .iter()
+        .zip(strides)
+        .map(|(i, stride)| i * stride)
+        .sum()
+}
+
+/// Map each element of the output, in row-major order, to the position of its source element.
+fn map_indices(
+    shape: &[usize],
+    source_shape: &[usize],
+    source_index: impl Fn(&[usize]) -> Vec<usize>,
+) -> Vec<usize> {
+    let source_strides = strides(source_shape);
+    let output_strides = strides(shape);
+
+    (0..num_elements(shape))
+        .map(|position| {
+            let index = shape
+                .iter()
+                .zip(&output_strides)
+                .map(|(dim, stride)| position / stride % dim)
+                .collect::<Vec<_>>();
+            dot(&source_index(&index), &source_strides)
+        })
+        .collect()
+}
+
+/// The positions of the elements of a value broadcast to the given shape.
+fn broadcast_indices(shape: &[usize], broadcast_shape: &[usize]) -> Vec<usize> {
+    let offset = broadcast_shape.len() - shape.len();
+    map_indices(broadcast_shape, shape, |index| {
+        index[offset..]
+            .iter()
+            .zip(shape)
+            .map(|(i, dim)| if *dim == 1 { 0 } else { *i })
+            .collect()
+    })
+}
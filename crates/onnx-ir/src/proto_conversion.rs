@@ -2,11 +2,12 @@ use std::str::{from_utf8, FromStr};
 
 use crate::ir::TensorType;
 
+use super::external_data::{is_external, ExternalData};
 use super::from_onnx::GraphData;
-use super::ir::Dim;
 use super::ir::{
     ArgType, Argument, AttributeValue, Attributes, Data, ElementType, Node, NodeType, Tensor,
 };
+use super::ir::{Dim, Shape, SymbolicDim};
 use super::protos::{
     attribute_proto::AttributeType, tensor_proto::DataType, tensor_shape_proto::dimension::Value,
     type_proto, AttributeProto, NodeProto, TensorProto, TensorShapeProto, ValueInfoProto,
@@ -25,57 +26,29 @@ pub enum ParseError {
 impl TryFrom<TensorProto> for Tensor {
     type Error = ParseError;
     fn try_from(tensor: TensorProto) -> Result<Tensor, Self::Error> {
-        let (elem_type, data) = match DataType::from_i32(tensor.data_type).unwrap() {
-            DataType::FLOAT => (
-                ElementType::Float32,
-                // Convert the raw data to a vector of floats
-                if !tensor.raw_data.is_empty() {
-                    Data::Float32s(cast_slice(&tensor.raw_data[..]).to_vec())
-                } else {
-                    Data::Float32s(tensor.float_data)
-                },
-            ),
+        let elem_type = match DataType::from_i32(tensor.data_type).unwrap() {
+            DataType::FLOAT => ElementType::Float32,
             DataType::INT16 => {
                 // TODO : Add support for int16 by converting to int32
                 todo!("Add support for int16");
             }
-            DataType::INT32 => (
-                ElementType::Int32,
-                // Convert the raw data to a vector of ints
-                if !tensor.raw_data.is_empty() {
-                    Data::Int32s(cast_slice(&tensor.raw_data[..]).to_vec())
-                } else {
-                    Data::Int32s(tensor.int32_data)
-                },
-            ),
-            DataType::INT64 => (
-                ElementType::Int64,
-                // Convert the raw data to a vector of ints
-                if !tensor.raw_data.is_empty() {
-                    Data::Int64s(cast_slice(&tensor.raw_data[..]).to_vec())
-                } else {
-                    Data::Int64s(tensor.int64_data)
-                },
-            ),
-            DataType::DOUBLE => (
-                ElementType::Float64,
-                // Convert the raw data to a vector of floats
-                if !tensor.raw_data.is_empty() {
-                    Data::Float64s(cast_slice(&tensor.raw_data[..]).to_vec())
-                } else {
-                    Data::Float64s(tensor.double_data)
-                },
-            ),
-            DataType::BOOL => (ElementType::Bool, {
-                assert!(!tensor.raw_data.is_empty());
-                Data::Bools(tensor.raw_data.iter().map(|x| *x != 0).collect())
-            }),
+            DataType::INT32 => ElementType::Int32,
+            DataType::INT64 => ElementType::Int64,
+            DataType::DOUBLE => ElementType::Float64,
+            DataType::BOOL => ElementType::Bool,
             // TODO : Add more types
             _ => {
                 return Err(ParseError::VariantNotFound);
             }
         };
-        let shape = convert_shape(tensor.dims);
+        let shape = convert_shape(tensor.dims.clone());
+
+        // The external data is read when it's needed
+        let data = if is_external(&tensor) {
+            Data::External(ExternalData::new(tensor))
+        } else {
+            convert_tensor_data(tensor)?
+        };
 
         Ok(Tensor {
             elem_type,
@@ -86,6 +59,53 @@ impl TryFrom<TensorProto> for Tensor {
     }
 }
 
+/// Convert the data of a tensor, stored in its raw data or in the field of its type.
+pub(crate) fn convert_tensor_data(tensor: TensorProto) -> Result<Data, ParseError> {
+    let data = match DataType::from_i32(tensor.data_type).unwrap() {
+        // Convert the raw data to a vector of floats
+        DataType::FLOAT => {
+            if !tensor.raw_data.is_empty() {
+                Data::Float32s(cast_slice(&tensor.raw_data[..]).to_vec())
+            } else {
+                Data::Float32s(tensor.float_data)
+            }
+        }
+        // Convert the raw data to a vector of ints
+        DataType::INT32 => {
+            if !tensor.raw_data.is_empty() {
+                Data::Int32s(cast_slice(&tensor.raw_data[..]).to_vec())
+            } else {
+                Data::Int32s(tensor.int32_data)
+            }
+        }
+        // Convert the raw data to a vector of ints
+        DataType::INT64 => {
+            if !tensor.raw_data.is_empty() {
+                Data::Int64s(cast_slice(&tensor.raw_data[..]).to_vec())
+            } else {
+                Data::Int64s(tensor.int64_data)
+            }
+        }
+        // Convert the raw data to a vector of floats
+        DataType::DOUBLE => {
+            if !tensor.raw_data.is_empty() {
+                Data::Float64s(cast_slice(&tensor.raw_data[..]).to_vec())
+            } else {
+                Data::Float64s(tensor.double_data)
+            }
+        }
+        DataType::BOOL => {
+            assert!(!tensor.raw_data.is_empty());
+            Data::Bools(tensor.raw_data.iter().map(|x| *x != 0).collect())
+        }
+        _ => {
+            return Err(ParseError::VariantNotFound);
+        }
+    };
+
+    Ok(data)
+}
+
 impl TryFrom<TensorShapeProto> for Vec<usize> {
     type Error = ParseError;
     fn try_from(shape: TensorShapeProto) -> Result<Vec<usize>, Self::Error> {
@@ -151,15 +171,13 @@ impl TryFrom<AttributeProto> for AttributeValue {
             // warning: tensor can be empty TODO: check if it is empty
             AttributeType::TENSOR => AttributeValue::Tensor(Tensor::try_from(attr.t.unwrap())?),
 
-            // Graph is not supported for now
-            // AttributeType::GRAPH => AttributeValue::Graph(attr.g),
+            // Graphs are converted along with the enclosing scope, see `OnnxGraphBuilder`
             AttributeType::FLOATS => AttributeValue::Float32s(attr.floats),
             AttributeType::INTS => AttributeValue::Int64s(attr.ints),
             AttributeType::STRINGS => AttributeValue::Strings(to_string_vec(attr.strings)),
             AttributeType::TENSORS => {
                 AttributeValue::Tensors(convert_vec_tensor_proto(attr.tensors)?)
             }
-            // AttributeType::GRAPHS => AttributeValue::Graphs(attr.graphs),
             // AttributeType::SPARSE_TENSORS => AttributeValue::SparseTensors(attr.sparse_tensors),
             // AttributeType::SPARSE_TENSOR => AttributeValue::SparseTensor(attr.sparse_tensor),
             _ => {
@@ -172,14 +190,28 @@ impl TryFrom<AttributeProto> for AttributeValue {
 }
 
 /// Convert a vector of AttributeProto to a HashMap of AttributeValue
+///
+/// The graph attributes are skipped, since the names used in a sub-graph can only be resolved
+/// with the enclosing graph.
 pub fn convert_vec_attrs_proto(attrs: Vec<AttributeProto>) -> Attributes {
     let mut result = Attributes::new();
     for attr in attrs {
+        if is_graph_attribute(&attr) {
+            continue;
+        }
         result.insert(attr.name.clone(), AttributeValue::try_from(attr).unwrap());
     }
     result
 }
 
+/// Check if the attribute holds one or more sub-graphs.
+pub fn is_graph_attribute(attr: &AttributeProto) -> bool {
+    matches!(
+        attr.type_.enum_value(),
+        Ok(AttributeType::GRAPH) | Ok(AttributeType::GRAPHS)
+    )
+}
+
 pub fn convert_node_proto(node: &NodeProto, graph_data: &GraphData) -> Node {
     let name = node.name.clone();
 
@@ -242,17 +274,33 @@ impl TryFrom<ValueInfoProto> for Argument {
             }
         };
 
+        let symbolic_shape: Vec<SymbolicDim> = tensor_proto
+            .shape
+            .dim
+            .iter()
+            .map(|x| match &x.value {
+                Some(Value::DimValue(value)) => SymbolicDim::Static(*value as Dim),
+                Some(Value::DimParam(param)) if !param.is_empty() => {
+                    SymbolicDim::Param(param.clone())
+                }
+                _ => SymbolicDim::Unknown,
+            })
+            .collect();
+
+        // The static shape is only known if no dimension is symbolic
+        let shape = symbolic_shape
+            .iter()
+            .map(|dim| match dim {
+                SymbolicDim::Static(size) => Some(*size),
+                _ => None,
+            })
+            .collect::<Option<Shape>>();
+
         let tensor_type = TensorType {
             dim: tensor_proto.shape.dim.len(),
             elem_type,
-            shape: Some(
-                tensor_proto
-                    .shape
-                    .dim
-                    .iter()
-                    .map(|x| x.dim_value() as Dim)
-                    .collect(),
-            ),
+            shape,
+            symbolic_shape: Some(symbolic_shape),
         };
 
         let ty = ArgType::Tensor(tensor_type);